@@ -0,0 +1,272 @@
+use crate::avm2::{request_sample_data, Activation as Avm2Activation, Avm2, Object as Avm2Object};
+use crate::backend::audio::{AudioBackend, SoundHandle, SoundInstanceHandle, SoundTransform};
+use crate::context::UpdateContext;
+use gc_arena::Collect;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A sound instance started by AS3 `Sound.play`.
+struct SoundInstance<'gc> {
+    /// The backend handle of the playing sound.
+    instance: SoundInstanceHandle,
+
+    /// The `SoundChannel` object that controls this sound.
+    channel: Avm2Object<'gc>,
+
+    /// The transform applied to this sound, before the global transform.
+    transform: SoundTransform,
+
+    /// The `Sound` object generating this sound's samples, if it is played
+    /// from `SampleDataEvent`s.
+    sample_source: Option<Avm2Object<'gc>>,
+
+    /// The number of sample frames requested from `sample_source` so far.
+    sample_position: u32,
+}
+
+/// Script-generated sounds request more samples whenever fewer than this many
+/// sample frames are left to play.
+const DYNAMIC_SOUND_MIN_BUFFERED: usize = 8192;
+
+/// The most `SampleDataEvent`s to fire for a single sound in one update.
+const MAX_SAMPLE_REQUESTS_PER_UPDATE: usize = 4;
+
+/// Script-generated sounds end after any request that supplies fewer than
+/// this many sample frames.
+const MIN_SAMPLE_DATA_FRAMES: usize = 2048;
+
+/// Tracks sounds played by AS3 so that their channels can be notified when
+/// they finish, and so that the global `SoundMixer` transform can be applied
+/// to them.
+pub struct AudioManager<'gc> {
+    /// The sounds that are currently playing.
+    sounds: Vec<SoundInstance<'gc>>,
+
+    /// The global sound transform set by `SoundMixer.soundTransform`.
+    global_transform: SoundTransform,
+
+    /// Sounds that have been decoded for `Sound.extract`.
+    decoded_sounds: HashMap<SoundHandle, Arc<Vec<[f32; 2]>>>,
+}
+
+impl<'gc> AudioManager<'gc> {
+    pub fn new() -> Self {
+        Self {
+            sounds: Vec::new(),
+            global_transform: SoundTransform::default(),
+            decoded_sounds: HashMap::new(),
+        }
+    }
+
+    /// Start tracking a sound played by AS3, and apply its transform.
+    pub fn start_sound(
+        &mut self,
+        audio: &mut dyn AudioBackend,
+        instance: SoundInstanceHandle,
+        channel: Avm2Object<'gc>,
+        transform: SoundTransform,
+    ) {
+        audio.set_sound_transform(instance, transform.concat(&self.global_transform));
+        self.sounds.push(SoundInstance {
+            instance,
+            channel,
+            transform,
+            sample_source: None,
+            sample_position: 0,
+        });
+    }
+
+    /// Start tracking a script-generated sound played by AS3, and apply its
+    /// transform.
+    ///
+    /// `SampleDataEvent`s are fired on `source` whenever the sound needs
+    /// more samples.
+    pub fn start_dynamic_sound(
+        &mut self,
+        audio: &mut dyn AudioBackend,
+        instance: SoundInstanceHandle,
+        channel: Avm2Object<'gc>,
+        source: Avm2Object<'gc>,
+        transform: SoundTransform,
+    ) {
+        audio.set_sound_transform(instance, transform.concat(&self.global_transform));
+        self.sounds.push(SoundInstance {
+            instance,
+            channel,
+            transform,
+            sample_source: Some(source),
+            sample_position: 0,
+        });
+    }
+
+    /// Get the samples of a sound decoded to 44.1kHz stereo.
+    ///
+    /// Sounds are decoded once and cached, as scripts tend to extract them a
+    /// little at a time.
+    pub fn sound_samples(
+        &mut self,
+        audio: &mut dyn AudioBackend,
+        sound: SoundHandle,
+    ) -> Option<Arc<Vec<[f32; 2]>>> {
+        if let Some(samples) = self.decoded_sounds.get(&sound) {
+            return Some(samples.clone());
+        }
+
+        let samples = Arc::new(audio.get_sound_samples(sound)?);
+        self.decoded_sounds.insert(sound, samples.clone());
+
+        Some(samples)
+    }
+
+    /// Stop a sound played by AS3. No-op if the sound is not playing.
+    pub fn stop_sound(&mut self, audio: &mut dyn AudioBackend, instance: SoundInstanceHandle) {
+        audio.stop_sound(instance);
+        self.sounds.retain(|sound| sound.instance != instance);
+    }
+
+    /// Stop all sounds, as by `SoundMixer.stopAll`.
+    pub fn stop_all_sounds(&mut self, audio: &mut dyn AudioBackend) {
+        audio.stop_all_sounds();
+        self.sounds.clear();
+    }
+
+    /// Change the transform of a sound played by AS3.
+    pub fn set_sound_transform(
+        &mut self,
+        audio: &mut dyn AudioBackend,
+        instance: SoundInstanceHandle,
+        transform: SoundTransform,
+    ) {
+        if let Some(sound) = self.sounds.iter_mut().find(|s| s.instance == instance) {
+            sound.transform = transform;
+            audio.set_sound_transform(instance, transform.concat(&self.global_transform));
+        }
+    }
+
+    /// The global sound transform.
+    pub fn global_transform(&self) -> SoundTransform {
+        self.global_transform
+    }
+
+    /// Change the global sound transform, and reapply it to every playing
+    /// sound.
+    pub fn set_global_transform(
+        &mut self,
+        audio: &mut dyn AudioBackend,
+        transform: SoundTransform,
+    ) {
+        self.global_transform = transform;
+
+        for sound in &self.sounds {
+            audio.set_sound_transform(sound.instance, sound.transform.concat(&transform));
+        }
+    }
+
+    /// Request more samples for any script-generated sounds that are running
+    /// low, then remove any sounds that have finished playing, and fire
+    /// `soundComplete` on their channels.
+    pub fn update_sounds(context: &mut UpdateContext<'_, 'gc, '_>) {
+        let dynamic_sounds: Vec<_> = context
+            .audio_manager
+            .sounds
+            .iter()
+            .filter_map(|sound| Some((sound.instance, sound.sample_source?)))
+            .collect();
+
+        for (instance, source) in dynamic_sounds {
+            Self::fill_dynamic_sound(context, instance, source);
+        }
+
+        let mut finished = Vec::new();
+        let audio = &context.audio;
+
+        context.audio_manager.sounds.retain(|sound| {
+            if audio.get_sound_position(sound.instance).is_some() {
+                true
+            } else {
+                finished.push(sound.channel);
+                false
+            }
+        });
+
+        for channel in finished {
+            let event_proto = context.avm2.prototypes().event;
+
+            if let Err(e) =
+                Avm2::dispatch_event(context, channel, event_proto, &["soundComplete".into()])
+            {
+                context.avm2.clear_error();
+                log::error!("Encountered AVM2 error when dispatching sound event: {}", e);
+            }
+        }
+    }
+
+    /// Fire `SampleDataEvent`s on the source of a script-generated sound
+    /// until it has enough samples queued, or the script stops supplying
+    /// them.
+    fn fill_dynamic_sound(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        instance: SoundInstanceHandle,
+        source: Avm2Object<'gc>,
+    ) {
+        for _ in 0..MAX_SAMPLE_REQUESTS_PER_UPDATE {
+            match context.audio.dynamic_sound_buffered(instance) {
+                Some(buffered) if buffered < DYNAMIC_SOUND_MIN_BUFFERED => {}
+                _ => return,
+            }
+
+            let position = match context
+                .audio_manager
+                .sounds
+                .iter()
+                .find(|sound| sound.instance == instance)
+            {
+                Some(sound) => sound.sample_position,
+                None => return,
+            };
+
+            let mut activation = Avm2Activation::from_nothing(context.reborrow());
+            let samples = match request_sample_data(&mut activation, source, position) {
+                Ok(samples) => samples,
+                Err(e) => {
+                    activation.context.avm2.clear_error();
+                    log::error!("Encountered AVM2 error when requesting sample data: {}", e);
+                    Vec::new()
+                }
+            };
+            let is_final = samples.len() < MIN_SAMPLE_DATA_FRAMES;
+
+            context
+                .audio
+                .queue_dynamic_sound_samples(instance, &samples, is_final);
+
+            if let Some(sound) = context
+                .audio_manager
+                .sounds
+                .iter_mut()
+                .find(|sound| sound.instance == instance)
+            {
+                sound.sample_position += samples.len() as u32;
+            }
+
+            if is_final {
+                return;
+            }
+        }
+    }
+}
+
+impl<'gc> Default for AudioManager<'gc> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<'gc> Collect for AudioManager<'gc> {
+    fn trace(&self, cc: gc_arena::CollectionContext) {
+        for sound in &self.sounds {
+            sound.channel.trace(cc);
+            sound.sample_source.trace(cc);
+        }
+    }
+}
@@ -17,7 +17,6 @@ pub mod activation;
 mod callable_value;
 pub mod debug;
 pub mod error;
-mod fscommand;
 #[macro_use]
 pub mod function;
 pub mod globals;
@@ -25,7 +24,6 @@ pub mod object;
 mod property;
 mod scope;
 mod string;
-mod timer;
 mod value;
 
 #[cfg(test)]
@@ -43,7 +41,6 @@ pub use object::{Object, ObjectPtr, TObject};
 use scope::Scope;
 use smallvec::alloc::borrow::Cow;
 pub use string::AvmString;
-pub use timer::Timers;
 pub use value::Value;
 
 macro_rules! avm_debug {
@@ -162,6 +159,11 @@ impl<'gc> Avm1<'gc> {
         }
     }
 
+    /// Set the Flash Player version we're emulating.
+    pub fn set_player_version(&mut self, player_version: u8) {
+        self.player_version = player_version;
+    }
+
     /// Add a stack frame that executes code in timeline scope
     ///
     /// This creates a new frame stack.
@@ -213,6 +213,20 @@ pub fn get_infinity<'gc>(
     }
 }
 
+/// Implements `getVersion`, which returns the same version string as
+/// `$version` and `System.capabilities.version`.
+pub fn get_version<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let version_string = activation
+        .context
+        .system
+        .get_version_string(activation.context.avm1.player_version);
+    Ok(AvmString::new(activation.context.gc_context, version_string).into())
+}
+
 pub fn get_nan<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     _this: Object<'gc>,
@@ -309,18 +323,17 @@ pub fn create_timer<'gc>(
     is_timeout: bool,
 ) -> Result<Value<'gc>, Error<'gc>> {
     // `setInterval` was added in Flash Player 6 but is not version-gated.
-    use crate::avm1::timer::TimerCallback;
-    let (callback, i) = match args.get(0) {
-        Some(Value::Object(o)) if o.as_executable().is_some() => (TimerCallback::Function(*o), 1),
+    use crate::timer::TimerCallback;
+    let (func, method_name, i) = match args.get(0) {
+        Some(Value::Object(o)) if o.as_executable().is_some() => (*o, None, 1),
         Some(Value::Object(o)) => (
-            TimerCallback::Method {
-                this: *o,
-                method_name: args
-                    .get(1)
+            *o,
+            Some(
+                args.get(1)
                     .unwrap_or(&Value::Undefined)
                     .coerce_to_string(activation)?
                     .to_string(),
-            },
+            ),
             2,
         ),
         _ => return Ok(Value::Undefined),
@@ -336,10 +349,19 @@ pub fn create_timer<'gc>(
         vec![]
     };
 
+    let callback = match method_name {
+        Some(method_name) => TimerCallback::Avm1Method {
+            this: func,
+            method_name,
+            params,
+        },
+        None => TimerCallback::Avm1Function { func, params },
+    };
+
     let id = activation
         .context
         .timers
-        .add_timer(callback, interval, params, is_timeout);
+        .add_timer(callback, interval, is_timeout);
 
     Ok(id.into())
 }
@@ -1116,6 +1138,13 @@ pub fn create_globals<'gc>(
         DontEnum,
         Some(function_proto),
     );
+    globals.force_set_function(
+        "getVersion",
+        get_version,
+        gc_context,
+        DontEnum,
+        Some(function_proto),
+    );
     globals.force_set_function("escape", escape, gc_context, DontEnum, Some(function_proto));
     globals.force_set_function(
         "unescape",
@@ -1,14 +1,79 @@
 //! flash.display.BitmapData object
 
+use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
 use crate::avm1::function::{Executable, FunctionObject};
-use crate::avm1::object::bitmap_data::{BitmapDataObject, ChannelOptions, Color};
-use crate::avm1::{activation::Activation, object::bitmap_data::BitmapData};
+use crate::avm1::globals::bitmap_filter::object_to_bitmap_filter;
+use crate::avm1::globals::matrix::object_to_matrix;
+use crate::avm1::object::bitmap_data::BitmapDataObject;
 use crate::avm1::{Object, TObject, Value};
+use crate::bitmap::bitmap_data::{
+    blend_mode_from_id, blend_mode_from_name, render_display_object, BitmapData, ChannelOptions,
+    Color, ThresholdOperation,
+};
 use crate::character::Character;
+use crate::color_transform::ColorTransform;
 use crate::display_object::TDisplayObject;
+use crate::transform::Transform;
 use enumset::EnumSet;
 use gc_arena::{GcCell, MutationContext};
+use swf::BlendMode;
+
+/// Reads the bounds of a `Rectangle` argument.
+fn rectangle_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Option<&Value<'gc>>,
+) -> Result<(i32, i32, i32, i32), Error<'gc>> {
+    let rectangle = value
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_object(activation);
+    let x = rectangle.get("x", activation)?.coerce_to_i32(activation)?;
+    let y = rectangle.get("y", activation)?.coerce_to_i32(activation)?;
+    let width = rectangle
+        .get("width", activation)?
+        .coerce_to_i32(activation)?;
+    let height = rectangle
+        .get("height", activation)?
+        .coerce_to_i32(activation)?;
+    Ok((x, y, width, height))
+}
+
+/// Reads the coordinates of a `Point` argument.
+fn point_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Option<&Value<'gc>>,
+) -> Result<(i32, i32), Error<'gc>> {
+    let point = value
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_object(activation);
+    let x = point.get("x", activation)?.coerce_to_i32(activation)?;
+    let y = point.get("y", activation)?.coerce_to_i32(activation)?;
+    Ok((x, y))
+}
+
+/// Reads a `paletteMap` channel array, falling back to copying the channel
+/// unchanged if it's missing.
+fn palette_array_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Option<&Value<'gc>>,
+    shift: u32,
+) -> Result<[u32; 256], Error<'gc>> {
+    let mut array = [0; 256];
+    match value {
+        None | Some(Value::Undefined) | Some(Value::Null) => {
+            for (i, entry) in array.iter_mut().enumerate() {
+                *entry = (i as u32) << shift;
+            }
+        }
+        Some(value) => {
+            let object = value.coerce_to_object(activation);
+            for (i, entry) in array.iter_mut().enumerate() {
+                *entry = object.array_element(i).coerce_to_u32(activation)?;
+            }
+        }
+    }
+    Ok(array)
+}
 
 pub fn constructor<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
@@ -467,22 +532,167 @@ pub fn noise<'gc>(
 }
 
 pub fn apply_filter<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    log::warn!("BitmapData.applyFilter - not yet implemented");
+    if let Some(bitmap_data) = this.as_bitmap_data_object() {
+        if !bitmap_data.disposed() {
+            let source_bitmap = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+            let source_rect = rectangle_arg(activation, args.get(1))?;
+            let dest_point = point_arg(activation, args.get(2))?;
+            let filter = args
+                .get(3)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+
+            if let (Some(src_bitmap), Some(filter)) = (
+                source_bitmap.as_bitmap_data_object(),
+                object_to_bitmap_filter(filter),
+            ) {
+                if !src_bitmap.disposed() {
+                    // dealing with object aliasing...
+                    let src_bitmap_clone: BitmapData; // only initialized if source is the same object as self
+                    let src_bitmap_data_cell = src_bitmap.bitmap_data();
+                    let src_bitmap_gc_ref; // only initialized if source is a different object than self
+                    let source_bitmap_ref = // holds the reference to either of the ones above
+                        if GcCell::ptr_eq(src_bitmap.bitmap_data(), bitmap_data.bitmap_data()) {
+                            src_bitmap_clone = src_bitmap_data_cell.read().clone();
+                            &src_bitmap_clone
+                        } else {
+                            src_bitmap_gc_ref = src_bitmap_data_cell.read();
+                            &src_bitmap_gc_ref
+                        };
+
+                    filter.apply(
+                        source_bitmap_ref,
+                        source_rect,
+                        &mut bitmap_data
+                            .bitmap_data()
+                            .write(activation.context.gc_context),
+                        dest_point,
+                    );
+
+                    return Ok(0.into());
+                }
+            }
+        }
+    }
+
     Ok((-1).into())
 }
 
 pub fn draw<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.draw - not yet implemented");
+            let source = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+
+            let matrix = match args.get(1) {
+                Some(Value::Object(matrix)) => object_to_matrix(*matrix, activation)?,
+                _ => Default::default(),
+            };
+
+            let color_transform = match args.get(2) {
+                Some(Value::Object(color_transform)) => color_transform
+                    .as_color_transform_object()
+                    .map(ColorTransform::from)
+                    .unwrap_or_default(),
+                _ => Default::default(),
+            };
+
+            let blend_mode = match args.get(3) {
+                Some(Value::String(name)) => blend_mode_from_name(name),
+                Some(Value::Number(id)) => blend_mode_from_id(*id as i32),
+                _ => None,
+            }
+            .unwrap_or(BlendMode::Normal);
+
+            let clip_rect = match args.get(4) {
+                Some(Value::Object(rectangle)) => {
+                    let x = rectangle.get("x", activation)?.coerce_to_i32(activation)?;
+                    let y = rectangle.get("y", activation)?.coerce_to_i32(activation)?;
+                    let width = rectangle
+                        .get("width", activation)?
+                        .coerce_to_i32(activation)?;
+                    let height = rectangle
+                        .get("height", activation)?
+                        .coerce_to_i32(activation)?;
+                    Some((x, y, width, height))
+                }
+                _ => None,
+            };
+
+            let smoothing = args
+                .get(5)
+                .unwrap_or(&Value::Undefined)
+                .as_bool(activation.current_swf_version());
+
+            let transform = Transform {
+                matrix,
+                color_transform,
+            };
+
+            if let Some(source_bitmap) = source.as_bitmap_data_object() {
+                if !source_bitmap.disposed() {
+                    // Drawing a bitmap onto itself needs a copy of the source.
+                    if GcCell::ptr_eq(source_bitmap.bitmap_data(), bitmap_data.bitmap_data()) {
+                        let source_data = source_bitmap.bitmap_data().read().clone();
+                        bitmap_data
+                            .bitmap_data()
+                            .write(activation.context.gc_context)
+                            .draw_bitmap_data(
+                                &source_data,
+                                &transform,
+                                blend_mode,
+                                clip_rect,
+                                smoothing,
+                            );
+                    } else {
+                        bitmap_data
+                            .bitmap_data()
+                            .write(activation.context.gc_context)
+                            .draw_bitmap_data(
+                                &source_bitmap.bitmap_data().read(),
+                                &transform,
+                                blend_mode,
+                                clip_rect,
+                                smoothing,
+                            );
+                    }
+                }
+            } else if let Some(source_object) = source.as_display_object() {
+                // As in Flash, `smoothing` only applies to BitmapData sources.
+                // Bitmaps inside a display object use their own smoothing.
+                let (width, height) = (
+                    bitmap_data.bitmap_data().read().width(),
+                    bitmap_data.bitmap_data().read().height(),
+                );
+
+                match render_display_object(
+                    &mut activation.context,
+                    source_object,
+                    &transform,
+                    width,
+                    height,
+                ) {
+                    Ok(layer) => bitmap_data
+                        .bitmap_data()
+                        .write(activation.context.gc_context)
+                        .composite(&layer, blend_mode, clip_rect),
+                    Err(e) => log::warn!("BitmapData.draw: Unable to render source: {}", e),
+                }
+            }
+
             return Ok(Value::Undefined);
         }
     }
@@ -491,14 +701,29 @@ pub fn draw<'gc>(
 }
 
 pub fn generate_filter_rect<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.generateFilterRect - not yet implemented");
-            return Ok(Value::Undefined);
+            let source_rect = rectangle_arg(activation, args.get(0))?;
+            let filter = args
+                .get(1)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+
+            let (x, y, width, height) = match object_to_bitmap_filter(filter) {
+                Some(filter) => filter.filter_rect(source_rect),
+                None => source_rect,
+            };
+
+            let proto = activation.context.avm1.prototypes.rectangle_constructor;
+            let rect = proto.construct(
+                activation,
+                &[x.into(), y.into(), width.into(), height.into()],
+            )?;
+            return Ok(rect);
         }
     }
 
@@ -540,7 +765,7 @@ pub fn color_transform<'gc>(
                 bitmap_data
                     .bitmap_data()
                     .write(activation.context.gc_context)
-                    .color_transform(min_x, min_y, end_x, end_y, color_transform);
+                    .color_transform(min_x, min_y, end_x, end_y, &color_transform.into());
             }
 
             return Ok(Value::Undefined);
@@ -583,13 +808,69 @@ pub fn get_color_bounds_rect<'gc>(
 }
 
 pub fn perlin_noise<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.perlinNoise - not yet implemented");
+            let base_x = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_f64(activation)?;
+            let base_y = args
+                .get(1)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_f64(activation)?;
+            let num_octaves = args
+                .get(2)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_u32(activation)? as usize;
+            let seed = args
+                .get(3)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_i32(activation)?;
+            let stitch = args
+                .get(4)
+                .unwrap_or(&Value::Bool(false))
+                .as_bool(activation.current_swf_version());
+            let fractal_noise = args
+                .get(5)
+                .unwrap_or(&Value::Bool(false))
+                .as_bool(activation.current_swf_version());
+            let channel_options = args
+                .get(6)
+                .unwrap_or(&Value::Number(ChannelOptions::rgb().0 as f64))
+                .coerce_to_u32(activation)?;
+            let grayscale = args
+                .get(7)
+                .unwrap_or(&Value::Bool(false))
+                .as_bool(activation.current_swf_version());
+
+            let mut offsets = Vec::new();
+            if let Some(Value::Object(offsets_array)) = args.get(8) {
+                for i in 0..offsets_array.length() {
+                    let offset = offsets_array.array_element(i).coerce_to_object(activation);
+                    let x = offset.get("x", activation)?.coerce_to_f64(activation)?;
+                    let y = offset.get("y", activation)?.coerce_to_f64(activation)?;
+                    offsets.push((x, y));
+                }
+            }
+
+            bitmap_data
+                .bitmap_data()
+                .write(activation.context.gc_context)
+                .perlin_noise(
+                    (base_x, base_y),
+                    num_octaves,
+                    seed,
+                    stitch,
+                    fractal_noise,
+                    channel_options.into(),
+                    grayscale,
+                    &offsets,
+                );
+
             return Ok(Value::Undefined);
         }
     }
@@ -598,14 +879,58 @@ pub fn perlin_noise<'gc>(
 }
 
 pub fn hit_test<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.hitTest - not yet implemented");
-            return Ok(Value::Undefined);
+            let (first_x, first_y) = point_arg(activation, args.get(0))?;
+            let first_threshold = args
+                .get(1)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_u32(activation)?
+                .min(255) as u8;
+            let compare_object = args
+                .get(2)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+
+            if let Some(other_bitmap) = compare_object.as_bitmap_data_object() {
+                if other_bitmap.disposed() {
+                    return Ok((-1).into());
+                }
+
+                let second_point = point_arg(activation, args.get(3))?;
+                let second_threshold = args
+                    .get(4)
+                    .unwrap_or(&Value::Number(1.0))
+                    .coerce_to_u32(activation)?
+                    .min(255) as u8;
+
+                let result = bitmap_data.bitmap_data().read().hit_test_bitmapdata(
+                    (first_x, first_y),
+                    first_threshold,
+                    &other_bitmap.bitmap_data().read(),
+                    second_point,
+                    second_threshold,
+                );
+                return Ok(result.into());
+            } else if compare_object.has_property(activation, "width") {
+                let (x, y, width, height) = rectangle_arg(activation, args.get(2))?;
+                let result = bitmap_data
+                    .bitmap_data()
+                    .read()
+                    .hit_test_rectangle(first_threshold, (x - first_x, y - first_y, width, height));
+                return Ok(result.into());
+            } else {
+                let (x, y) = point_arg(activation, args.get(2))?;
+                let result = bitmap_data
+                    .bitmap_data()
+                    .read()
+                    .hit_test_point(first_threshold, (x - first_x, y - first_y));
+                return Ok(result.into());
+            }
         }
     }
 
@@ -742,13 +1067,63 @@ pub fn copy_pixels<'gc>(
 }
 
 pub fn merge<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.merge - not yet implemented");
+            let source_bitmap = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+            let source_rect = rectangle_arg(activation, args.get(1))?;
+            let dest_point = point_arg(activation, args.get(2))?;
+
+            let red_mult = args
+                .get(3)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_i32(activation)?;
+            let green_mult = args
+                .get(4)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_i32(activation)?;
+            let blue_mult = args
+                .get(5)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_i32(activation)?;
+            let alpha_mult = args
+                .get(6)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_i32(activation)?;
+
+            if let Some(src_bitmap) = source_bitmap.as_bitmap_data_object() {
+                if !src_bitmap.disposed() {
+                    // dealing with object aliasing...
+                    let src_bitmap_clone: BitmapData; // only initialized if source is the same object as self
+                    let src_bitmap_data_cell = src_bitmap.bitmap_data();
+                    let src_bitmap_gc_ref; // only initialized if source is a different object than self
+                    let source_bitmap_ref = // holds the reference to either of the ones above
+                        if GcCell::ptr_eq(src_bitmap.bitmap_data(), bitmap_data.bitmap_data()) {
+                            src_bitmap_clone = src_bitmap_data_cell.read().clone();
+                            &src_bitmap_clone
+                        } else {
+                            src_bitmap_gc_ref = src_bitmap_data_cell.read();
+                            &src_bitmap_gc_ref
+                        };
+
+                    bitmap_data
+                        .bitmap_data()
+                        .write(activation.context.gc_context)
+                        .merge(
+                            source_bitmap_ref,
+                            source_rect,
+                            dest_point,
+                            (red_mult, green_mult, blue_mult, alpha_mult),
+                        );
+                }
+            }
+
             return Ok(Value::Undefined);
         }
     }
@@ -757,13 +1132,51 @@ pub fn merge<'gc>(
 }
 
 pub fn palette_map<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.paletteMap - not yet implemented");
+            let source_bitmap = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+            let source_rect = rectangle_arg(activation, args.get(1))?;
+            let dest_point = point_arg(activation, args.get(2))?;
+
+            let red_array = palette_array_arg(activation, args.get(3), 16)?;
+            let green_array = palette_array_arg(activation, args.get(4), 8)?;
+            let blue_array = palette_array_arg(activation, args.get(5), 0)?;
+            let alpha_array = palette_array_arg(activation, args.get(6), 24)?;
+
+            if let Some(src_bitmap) = source_bitmap.as_bitmap_data_object() {
+                if !src_bitmap.disposed() {
+                    // dealing with object aliasing...
+                    let src_bitmap_clone: BitmapData; // only initialized if source is the same object as self
+                    let src_bitmap_data_cell = src_bitmap.bitmap_data();
+                    let src_bitmap_gc_ref; // only initialized if source is a different object than self
+                    let source_bitmap_ref = // holds the reference to either of the ones above
+                        if GcCell::ptr_eq(src_bitmap.bitmap_data(), bitmap_data.bitmap_data()) {
+                            src_bitmap_clone = src_bitmap_data_cell.read().clone();
+                            &src_bitmap_clone
+                        } else {
+                            src_bitmap_gc_ref = src_bitmap_data_cell.read();
+                            &src_bitmap_gc_ref
+                        };
+
+                    bitmap_data
+                        .bitmap_data()
+                        .write(activation.context.gc_context)
+                        .palette_map(
+                            source_bitmap_ref,
+                            source_rect,
+                            dest_point,
+                            (&red_array, &green_array, &blue_array, &alpha_array),
+                        );
+                }
+            }
+
             return Ok(Value::Undefined);
         }
     }
@@ -772,13 +1185,65 @@ pub fn palette_map<'gc>(
 }
 
 pub fn pixel_dissolve<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.pixelDissolve - not yet implemented");
+            let source_bitmap = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+            let source_rect = rectangle_arg(activation, args.get(1))?;
+            let dest_point = point_arg(activation, args.get(2))?;
+            let random_seed = args
+                .get(3)
+                .unwrap_or(&Value::Number(0.0))
+                .coerce_to_i32(activation)?;
+            let num_pixels = args
+                .get(4)
+                .unwrap_or(&Value::Number(0.0))
+                .coerce_to_i32(activation)?;
+            let fill_color = args
+                .get(5)
+                .unwrap_or(&Value::Number(0.0))
+                .coerce_to_u32(activation)?;
+
+            if let Some(src_bitmap) = source_bitmap.as_bitmap_data_object() {
+                if !src_bitmap.disposed() {
+                    // Dissolving a bitmap into itself fills it with the fill color instead.
+                    let new_seed =
+                        if GcCell::ptr_eq(src_bitmap.bitmap_data(), bitmap_data.bitmap_data()) {
+                            bitmap_data
+                                .bitmap_data()
+                                .write(activation.context.gc_context)
+                                .pixel_dissolve(
+                                    None,
+                                    source_rect,
+                                    dest_point,
+                                    random_seed,
+                                    num_pixels,
+                                    fill_color,
+                                )
+                        } else {
+                            bitmap_data
+                                .bitmap_data()
+                                .write(activation.context.gc_context)
+                                .pixel_dissolve(
+                                    Some(&src_bitmap.bitmap_data().read()),
+                                    source_rect,
+                                    dest_point,
+                                    random_seed,
+                                    num_pixels,
+                                    fill_color,
+                                )
+                        };
+
+                    return Ok(new_seed.into());
+                }
+            }
+
             return Ok(Value::Undefined);
         }
     }
@@ -815,14 +1280,79 @@ pub fn scroll<'gc>(
 }
 
 pub fn threshold<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.threshold - not yet implemented");
-            return Ok(Value::Undefined);
+            let source_bitmap = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+            let source_rect = rectangle_arg(activation, args.get(1))?;
+            let dest_point = point_arg(activation, args.get(2))?;
+
+            let operation = args
+                .get(3)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_string(activation)?;
+            let operation = match ThresholdOperation::from_name(&operation) {
+                Some(operation) => operation,
+                None => return Ok(0.into()),
+            };
+
+            let threshold = args
+                .get(4)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_u32(activation)?;
+            let color = args
+                .get(5)
+                .unwrap_or(&Value::Number(0.0))
+                .coerce_to_u32(activation)?;
+            let mask = args
+                .get(6)
+                .unwrap_or(&Value::Number(f64::from(0xFFFFFFFFu32)))
+                .coerce_to_u32(activation)?;
+            let copy_source = args
+                .get(7)
+                .unwrap_or(&Value::Bool(false))
+                .as_bool(activation.current_swf_version());
+
+            if let Some(src_bitmap) = source_bitmap.as_bitmap_data_object() {
+                if !src_bitmap.disposed() {
+                    // dealing with object aliasing...
+                    let src_bitmap_clone: BitmapData; // only initialized if source is the same object as self
+                    let src_bitmap_data_cell = src_bitmap.bitmap_data();
+                    let src_bitmap_gc_ref; // only initialized if source is a different object than self
+                    let source_bitmap_ref = // holds the reference to either of the ones above
+                        if GcCell::ptr_eq(src_bitmap.bitmap_data(), bitmap_data.bitmap_data()) {
+                            src_bitmap_clone = src_bitmap_data_cell.read().clone();
+                            &src_bitmap_clone
+                        } else {
+                            src_bitmap_gc_ref = src_bitmap_data_cell.read();
+                            &src_bitmap_gc_ref
+                        };
+
+                    let count = bitmap_data
+                        .bitmap_data()
+                        .write(activation.context.gc_context)
+                        .threshold(
+                            source_bitmap_ref,
+                            source_rect,
+                            dest_point,
+                            operation,
+                            threshold,
+                            color,
+                            mask,
+                            copy_source,
+                        );
+
+                    return Ok(count.into());
+                }
+            }
+
+            return Ok(0.into());
         }
     }
 
@@ -1051,7 +1581,10 @@ pub fn load_bitmap<'gc>(
         .and_then(|l| l.character_by_export_name(name.as_str()));
 
     if let Some(Character::Bitmap(bitmap_object)) = character {
-        if let Some(bitmap) = renderer.get_bitmap_pixels(bitmap_object.bitmap_handle()) {
+        if let Some(bitmap) = bitmap_object
+            .bitmap_handle()
+            .and_then(|handle| renderer.get_bitmap_pixels(handle))
+        {
             let proto = activation.context.avm1.prototypes.bitmap_data_constructor;
             let new_bitmap =
                 proto.construct(activation, &[bitmap.width.into(), bitmap.height.into()])?;
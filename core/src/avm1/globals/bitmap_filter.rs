@@ -3,6 +3,10 @@
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
 use crate::avm1::{Object, ScriptObject, TObject, Value};
+use crate::bitmap::filters::{
+    BevelFilter, BitmapFilter, BlurFilter, ColorMatrixFilter, ConvolutionFilter,
+    DisplacementMapFilter, DropShadowFilter, GlowFilter, GradientFilter,
+};
 use enumset::EnumSet;
 use gc_arena::MutationContext;
 
@@ -75,10 +79,14 @@ pub fn clone<'gc>(
         let blur_y = this.get("blurY", activation)?;
         let strength = this.get("strength", activation)?;
         let quality = this.get("quality", activation)?;
+        let inner = this.get("inner", activation)?;
+        let knockout = this.get("knockout", activation)?;
 
         let cloned = proto.construct(
             activation,
-            &[color, alpha, blur_x, blur_y, strength, quality],
+            &[
+                color, alpha, blur_x, blur_y, strength, quality, inner, knockout,
+            ],
         )?;
         return Ok(cloned);
     }
@@ -268,6 +276,137 @@ pub fn clone<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Reads the parameters of an AVM1 filter object, if it is one.
+pub fn object_to_bitmap_filter<'gc>(object: Object<'gc>) -> Option<BitmapFilter> {
+    if let Some(filter) = object.as_bevel_filter_object() {
+        return Some(BitmapFilter::Bevel(BevelFilter {
+            distance: filter.distance(),
+            angle: filter.angle(),
+            highlight_color: filter.highlight_color(),
+            highlight_alpha: filter.highlight_alpha(),
+            shadow_color: filter.shadow_color(),
+            shadow_alpha: filter.shadow_alpha(),
+            blur_x: filter.blur_x(),
+            blur_y: filter.blur_y(),
+            strength: filter.strength(),
+            quality: filter.quality(),
+            type_: filter.get_type().into(),
+            knockout: filter.knockout(),
+        }));
+    }
+
+    if let Some(filter) = object.as_blur_filter_object() {
+        return Some(BitmapFilter::Blur(BlurFilter {
+            blur_x: filter.blur_x(),
+            blur_y: filter.blur_y(),
+            quality: filter.quality(),
+        }));
+    }
+
+    if let Some(filter) = object.as_color_matrix_filter_object() {
+        return Some(BitmapFilter::ColorMatrix(ColorMatrixFilter {
+            matrix: filter.matrix(),
+        }));
+    }
+
+    if let Some(filter) = object.as_convolution_filter_object() {
+        return Some(BitmapFilter::Convolution(ConvolutionFilter {
+            matrix_x: filter.matrix_x(),
+            matrix_y: filter.matrix_y(),
+            matrix: filter.matrix(),
+            divisor: filter.divisor(),
+            bias: filter.bias(),
+            preserve_alpha: filter.preserve_alpha(),
+            clamp: filter.clamp(),
+            color: filter.color(),
+            alpha: filter.alpha(),
+        }));
+    }
+
+    if let Some(filter) = object.as_displacement_map_filter_object() {
+        let map_bitmap = filter
+            .map_bitmap()
+            .and_then(|map| map.as_bitmap_data_object())
+            .filter(|map| !map.disposed())
+            .map(|map| map.bitmap_data().read().clone());
+
+        return Some(BitmapFilter::DisplacementMap(DisplacementMapFilter {
+            map_bitmap,
+            map_point: filter.map_point(),
+            component_x: filter.component_x(),
+            component_y: filter.component_y(),
+            scale_x: filter.scale_x(),
+            scale_y: filter.scale_y(),
+            mode: filter.mode().into(),
+            color: filter.color(),
+            alpha: filter.alpha(),
+        }));
+    }
+
+    if let Some(filter) = object.as_drop_shadow_filter_object() {
+        return Some(BitmapFilter::DropShadow(DropShadowFilter {
+            distance: filter.distance(),
+            angle: filter.angle(),
+            color: filter.color(),
+            alpha: filter.alpha(),
+            blur_x: filter.blur_x(),
+            blur_y: filter.blur_y(),
+            strength: filter.strength(),
+            quality: filter.quality(),
+            inner: filter.inner(),
+            knockout: filter.knockout(),
+            hide_object: filter.hide_object(),
+        }));
+    }
+
+    if let Some(filter) = object.as_glow_filter_object() {
+        return Some(BitmapFilter::Glow(GlowFilter {
+            color: filter.color() as u32,
+            alpha: filter.alpha(),
+            blur_x: filter.blur_x(),
+            blur_y: filter.blur_y(),
+            strength: filter.strength(),
+            quality: filter.quality(),
+            inner: filter.inner(),
+            knockout: filter.knockout(),
+        }));
+    }
+
+    if let Some(filter) = object.as_gradient_bevel_filter_object() {
+        return Some(BitmapFilter::GradientBevel(GradientFilter {
+            distance: filter.distance(),
+            angle: filter.angle(),
+            colors: filter.colors(),
+            alphas: filter.alphas(),
+            ratios: filter.ratios(),
+            blur_x: filter.blur_x(),
+            blur_y: filter.blur_y(),
+            strength: filter.strength(),
+            quality: filter.quality(),
+            type_: filter.get_type().into(),
+            knockout: filter.knockout(),
+        }));
+    }
+
+    if let Some(filter) = object.as_gradient_glow_filter_object() {
+        return Some(BitmapFilter::GradientGlow(GradientFilter {
+            distance: filter.distance(),
+            angle: filter.angle(),
+            colors: filter.colors(),
+            alphas: filter.alphas(),
+            ratios: filter.ratios(),
+            blur_x: filter.blur_x(),
+            blur_y: filter.blur_y(),
+            strength: filter.strength(),
+            quality: filter.quality(),
+            type_: filter.get_type().into(),
+            knockout: filter.knockout(),
+        }));
+    }
+
+    None
+}
+
 pub fn create_proto<'gc>(
     gc_context: MutationContext<'gc, '_>,
     proto: Object<'gc>,
@@ -19,6 +19,8 @@ pub fn constructor<'gc>(
     set_blur_y(activation, this, args.get(3..4).unwrap_or_default())?;
     set_strength(activation, this, args.get(4..5).unwrap_or_default())?;
     set_quality(activation, this, args.get(5..6).unwrap_or_default())?;
+    set_inner(activation, this, args.get(6..7).unwrap_or_default())?;
+    set_knockout(activation, this, args.get(7..8).unwrap_or_default())?;
 
     Ok(this.into())
 }
@@ -156,6 +156,38 @@ pub fn hit_test<'gc>(
     Ok(false.into())
 }
 
+fn set_mask<'gc>(
+    movie_clip: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mask = match args.get(0).cloned().unwrap_or(Value::Undefined) {
+        // Passing `null` or `undefined` removes the mask.
+        Value::Undefined | Value::Null => None,
+        mask => activation.resolve_target_display_object(movie_clip.into(), mask, false)?,
+    };
+
+    let gc_context = activation.context.gc_context;
+    match mask {
+        Some(mask) => {
+            // A clip can't mask itself, directly or through the masks of
+            // its mask, as the mask chain would then never end.
+            let mut masker = Some(mask);
+            while let Some(object) = masker {
+                if DisplayObject::ptr_eq(object, movie_clip.into()) {
+                    return Ok(false.into());
+                }
+                masker = object.masker();
+            }
+            movie_clip.set_masker(gc_context, Some(mask), true);
+            mask.set_maskee(gc_context, Some(movie_clip.into()), true);
+        }
+        None => movie_clip.set_masker(gc_context, None, true),
+    }
+
+    Ok(true.into())
+}
+
 pub fn create_proto<'gc>(
     gc_context: MutationContext<'gc, '_>,
     proto: Object<'gc>,
@@ -189,6 +221,7 @@ pub fn create_proto<'gc>(
         "nextFrame" => next_frame,
         "play" => play,
         "prevFrame" => prev_frame,
+        "setMask" => set_mask,
         "startDrag" => start_drag,
         "stop" => stop,
         "stopDrag" => stop_drag,
@@ -796,20 +829,33 @@ pub fn duplicate_movie_clip_with_bias<'gc>(
 
 fn get_bytes_loaded<'gc>(
     movie_clip: MovieClip<'gc>,
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    Ok(movie_clip
-        .movie()
-        .map(|mv| (mv.header().uncompressed_length).into())
-        .unwrap_or(Value::Undefined))
+    if let Some((bytes_loaded, _)) = activation
+        .context
+        .load_manager
+        .movie_load_progress(DisplayObject::MovieClip(movie_clip))
+    {
+        return Ok(bytes_loaded.into());
+    }
+
+    Ok(movie_clip.loaded_bytes().into())
 }
 
 fn get_bytes_total<'gc>(
     movie_clip: MovieClip<'gc>,
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some((_, bytes_total)) = activation
+        .context
+        .load_manager
+        .movie_load_progress(DisplayObject::MovieClip(movie_clip))
+    {
+        return Ok(bytes_total.into());
+    }
+
     Ok(movie_clip
         .movie()
         .map(|mv| (mv.header().uncompressed_length).into())
@@ -1123,13 +1169,17 @@ pub fn get_url<'gc>(
 
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    use crate::avm1::fscommand;
+    use crate::fscommand;
 
     //TODO: Error behavior if no arguments are present
     if let Some(url_val) = args.get(0) {
         let url = url_val.coerce_to_string(activation)?;
         if let Some(fscommand) = fscommand::parse(&url) {
-            fscommand::handle(fscommand, activation);
+            let args = match args.get(1) {
+                Some(args) => args.coerce_to_string(activation)?,
+                None => "".into(),
+            };
+            fscommand::handle(&mut activation.context, fscommand, &args);
             return Ok(Value::Undefined);
         }
 
@@ -1197,7 +1247,7 @@ fn load_movie<'gc>(
     let method = args.get(1).cloned().unwrap_or(Value::Undefined);
     let method = NavigationMethod::from_method_str(&method.coerce_to_string(activation)?);
     let (url, opts) = activation.locals_into_request_options(Cow::Borrowed(&url), method);
-    let fetch = activation.context.navigator.fetch(&url, opts);
+    let fetch = activation.context.navigator.fetch_stream(&url, opts);
     let process = activation.context.load_manager.load_movie_into_clip(
         activation.context.player.clone().unwrap(),
         DisplayObject::MovieClip(target),
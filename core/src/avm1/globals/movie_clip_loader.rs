@@ -49,7 +49,7 @@ pub fn load_clip<'gc>(
             let fetch = activation
                 .context
                 .navigator
-                .fetch(&url, RequestOptions::get());
+                .fetch_stream(&url, RequestOptions::get());
             let process = activation.context.load_manager.load_movie_into_clip(
                 activation.context.player.clone().unwrap(),
                 DisplayObject::MovieClip(movieclip),
@@ -101,23 +101,32 @@ pub fn get_progress<'gc>(
             .as_display_object()
             .and_then(|dobj| dobj.as_movie_clip())
         {
+            let (bytes_loaded, bytes_total) = match activation
+                .context
+                .load_manager
+                .movie_load_progress(DisplayObject::MovieClip(movieclip))
+            {
+                Some((bytes_loaded, bytes_total)) => (bytes_loaded.into(), bytes_total.into()),
+                None => {
+                    let bytes_total = movieclip
+                        .movie()
+                        .map(|mv| (mv.header().uncompressed_length).into())
+                        .unwrap_or(Value::Undefined);
+                    (movieclip.loaded_bytes().into(), bytes_total)
+                }
+            };
+
             let ret_obj = ScriptObject::object(activation.context.gc_context, None);
             ret_obj.define_value(
                 activation.context.gc_context,
                 "bytesLoaded",
-                movieclip
-                    .movie()
-                    .map(|mv| (mv.header().uncompressed_length).into())
-                    .unwrap_or(Value::Undefined),
+                bytes_loaded,
                 EnumSet::empty(),
             );
             ret_obj.define_value(
                 activation.context.gc_context,
                 "bytesTotal",
-                movieclip
-                    .movie()
-                    .map(|mv| (mv.header().uncompressed_length).into())
-                    .unwrap_or(Value::Undefined),
+                bytes_total,
                 EnumSet::empty(),
             );
 
@@ -189,13 +189,14 @@ fn to_string<'gc>(
         }
         let out: String = digits[..i].iter().rev().collect();
         Ok(AvmString::new(activation.context.gc_context, out).into())
-    } else {
-        // NaN or large numbers.
+    } else if this.is_nan() && activation.context.avm1.player_version >= 7 {
         // Player version specific behavior:
         // NaN.toString(x) will print completely garbage values in Flash Player 7+:
         // for example, NaN.toString(3) gives "-/.//./..././/0.0./0.".
-        // Flash Player 6 will print a much more sane value of 0, so let's go with that.
-        // TODO: Allow configuration of player version.
+        Ok(TO_STRING_NANS[radix as usize - 2].into())
+    } else {
+        // Large numbers, or NaN in Flash Player 6, which prints a much more
+        // sane value of 0.
         Ok("0".into())
     }
 }
@@ -215,45 +216,43 @@ fn value_of<'gc>(
 }
 
 // The values returned by `NaN.toString(radix)` in Flash Player v7+
-// for each radix from 2 to 36. Currently unused, but leaving it here
-// in case we want to emulate this behavior.
+// for each radix from 2 to 36.
 // This table was generated in Flash.
 // Not sure where the heck these values actually come from...!?
-// const TO_STRING_NANS: &[&str] = &[
-//     "-/0000000000000000000000000000000",
-//     "-/.//./..././/0.0./0.",
-//     "-.000000000000000",
-//     "-/--,..-,-,0,-",
-//     "-++-0-.00++-.",
-//     "-/0,/-,.///*.",
-//     "-.0000000000",
-//     "-+,)())-*).",
-//     "NaN",
-//     "-&0...0.(.",
-//     "-,%%.-0(&(",
-//     "-.(.%&,&&%",
-//     "-/*+.$&'-.",
-//     "-$()\x22**%(",
-//     "-(0000000",
-//     "-+- )!+,'",
-//     "--'.( -\x1F.",
-//     "-.)$+)\x1F--",
-//     "-/#%/!'.(",
-//     "-/,0\x1F.#'.",
-//     "-\x1E\x1C!+%!.",
-//     "-\x22%\x22\x1B!'*",
-//     "-%+  \x22+(",
-//     "-(\x1D\x1A#\x19\x1C\x19",
-//     "-*\x18\x1D(\x1E\x18\x18",
-//     "-+\x22\x1F\x19$\x1C%",
-//     "-,$\x1B\x1A'( ",
-//     "--\x1F\x1C)'((",
-//     "-.\x14%*$\x14(",
-//     "-.#0'\x12$.",
-//     "-.000000",
-//     "-/\x1B\x14\x16\x13\x1B.",
-//     "-/#(\x0F\x16\x15\x16",
-//     "-/+\x11..\x12\x19",
-//     "-\x0D\x1E\x1C0\x0D\x1C",
-// ];
-//
+const TO_STRING_NANS: &[&str] = &[
+    "-/0000000000000000000000000000000",
+    "-/.//./..././/0.0./0.",
+    "-.000000000000000",
+    "-/--,..-,-,0,-",
+    "-++-0-.00++-.",
+    "-/0,/-,.///*.",
+    "-.0000000000",
+    "-+,)())-*).",
+    "NaN",
+    "-&0...0.(.",
+    "-,%%.-0(&(",
+    "-.(.%&,&&%",
+    "-/*+.$&'-.",
+    "-$()\x22**%(",
+    "-(0000000",
+    "-+- )!+,'",
+    "--'.( -\x1F.",
+    "-.)$+)\x1F--",
+    "-/#%/!'.(",
+    "-/,0\x1F.#'.",
+    "-\x1E\x1C!+%!.",
+    "-\x22%\x22\x1B!'*",
+    "-%+  \x22+(",
+    "-(\x1D\x1A#\x19\x1C\x19",
+    "-*\x18\x1D(\x1E\x18\x18",
+    "-+\x22\x1F\x19$\x1C%",
+    "-,$\x1B\x1A'( ",
+    "--\x1F\x1C)'((",
+    "-.\x14%*$\x14(",
+    "-.#0'\x12$.",
+    "-.000000",
+    "-/\x1B\x14\x16\x13\x1B.",
+    "-/#(\x0F\x16\x15\x16",
+    "-/+\x11..\x12\x19",
+    "-\x0D\x1E\x1C0\x0D\x1C",
+];
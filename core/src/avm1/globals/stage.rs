@@ -1,13 +1,11 @@
 //! Stage object
-//!
-//! TODO: This is a very rough stub with not much implementation.
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
 use crate::avm1::function::{Executable, FunctionObject};
 use crate::avm1::globals::as_broadcaster::BroadcasterFunctions;
 use crate::avm1::property::Attribute;
-use crate::avm1::{Object, ScriptObject, TObject, Value};
-use crate::avm_warn;
+use crate::avm1::{AvmString, Object, ScriptObject, TObject, Value};
+use crate::config::{StageAlign, StageScaleMode};
 use gc_arena::MutationContext;
 
 pub fn create_stage_object<'gc>(
@@ -109,16 +107,20 @@ fn align<'gc>(
     _this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm_warn!(activation, "Stage.align: unimplemented");
-    Ok("".into())
+    let align = StageAlign::format(*activation.context.stage_align);
+    Ok(AvmString::new(activation.context.gc_context, align).into())
 }
 
 fn set_align<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm_warn!(activation, "Stage.align: unimplemented");
+    let align = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+    *activation.context.stage_align = StageAlign::parse(&align);
     Ok(Value::Undefined)
 }
 
@@ -127,7 +129,11 @@ fn height<'gc>(
     _this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    Ok(activation.context.stage_size.1.to_pixels().into())
+    if *activation.context.stage_scale_mode == StageScaleMode::NoScale {
+        Ok(activation.context.viewport_size.1.into())
+    } else {
+        Ok(activation.context.stage_size.1.to_pixels().into())
+    }
 }
 
 fn scale_mode<'gc>(
@@ -135,16 +141,25 @@ fn scale_mode<'gc>(
     _this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm_warn!(activation, "Stage.scaleMode: unimplemented");
-    Ok("noScale".into())
+    let scale_mode = activation.context.stage_scale_mode.to_string();
+    Ok(AvmString::new(activation.context.gc_context, scale_mode).into())
 }
 
 fn set_scale_mode<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm_warn!(activation, "Stage.scaleMode: unimplemented");
+    let scale_mode = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+
+    // Unknown scale modes are ignored.
+    if let Ok(scale_mode) = scale_mode.parse() {
+        *activation.context.stage_scale_mode = scale_mode;
+        *activation.context.stage_scale_mode_set_by_script = true;
+    }
     Ok(Value::Undefined)
 }
 
@@ -153,16 +168,19 @@ fn show_menu<'gc>(
     _this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm_warn!(activation, "Stage.showMenu: unimplemented");
-    Ok(true.into())
+    Ok((*activation.context.show_menu).into())
 }
 
 fn set_show_menu<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm_warn!(activation, "Stage.showMenu: unimplemented");
+    let show_menu = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .as_bool(activation.current_swf_version());
+    *activation.context.show_menu = show_menu;
     Ok(Value::Undefined)
 }
 
@@ -171,5 +189,9 @@ fn width<'gc>(
     _this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    Ok(activation.context.stage_size.0.to_pixels().into())
+    if *activation.context.stage_scale_mode == StageScaleMode::NoScale {
+        Ok(activation.context.viewport_size.0.into())
+    } else {
+        Ok(activation.context.stage_size.0.to_pixels().into())
+    }
 }
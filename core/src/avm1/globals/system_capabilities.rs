@@ -1,9 +1,9 @@
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
 use crate::avm1::function::{Executable, FunctionObject};
-use crate::avm1::globals::system::SystemCapabilities;
 use crate::avm1::object::Object;
 use crate::avm1::{AvmString, ScriptObject, TObject, Value};
+use crate::system_properties::SystemCapabilities;
 use enumset::EnumSet;
 use gc_arena::MutationContext;
 
@@ -184,7 +184,7 @@ pub fn get_version<'gc>(
         activation
             .context
             .system
-            .get_version_string(activation.context.avm1),
+            .get_version_string(activation.context.avm1.player_version),
     )
     .into())
 }
@@ -197,7 +197,7 @@ pub fn get_server_string<'gc>(
     let server_string = activation
         .context
         .system
-        .get_server_string(activation.context.avm1);
+        .get_server_string(activation.context.avm1.player_version);
     Ok(AvmString::new(activation.context.gc_context, server_string).into())
 }
 
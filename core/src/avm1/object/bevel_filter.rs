@@ -1,6 +1,7 @@
 use crate::add_field_accessors;
 use crate::avm1::error::Error;
 use crate::avm1::{Object, ScriptObject, TObject, Value};
+use crate::bitmap::filters::BevelType;
 use crate::impl_custom_object_without_set;
 use gc_arena::{Collect, GcCell, MutationContext};
 
@@ -36,6 +37,16 @@ impl From<BevelFilterType> for &str {
     }
 }
 
+impl From<BevelFilterType> for BevelType {
+    fn from(v: BevelFilterType) -> Self {
+        match v {
+            BevelFilterType::Inner => BevelType::Inner,
+            BevelFilterType::Outer => BevelType::Outer,
+            BevelFilterType::Full => BevelType::Full,
+        }
+    }
+}
+
 /// A BevelFilter
 #[derive(Clone, Copy, Collect)]
 #[collect(no_drop)]
@@ -5,6 +5,7 @@ use crate::impl_custom_object_without_set;
 use gc_arena::{Collect, GcCell, MutationContext};
 
 use crate::avm1::activation::Activation;
+use crate::color_transform::ColorTransform;
 use std::fmt;
 
 /// A ColorTransform
@@ -92,6 +93,21 @@ impl<'gc> ColorTransformObject<'gc> {
     );
 }
 
+impl From<ColorTransformObject<'_>> for ColorTransform {
+    fn from(object: ColorTransformObject) -> Self {
+        Self {
+            r_mult: object.get_red_multiplier() as f32,
+            g_mult: object.get_green_multiplier() as f32,
+            b_mult: object.get_blue_multiplier() as f32,
+            a_mult: object.get_alpha_multiplier() as f32,
+            r_add: object.get_red_offset() as f32 / 255.0,
+            g_add: object.get_green_offset() as f32 / 255.0,
+            b_add: object.get_blue_offset() as f32 / 255.0,
+            a_add: object.get_alpha_offset() as f32 / 255.0,
+        }
+    }
+}
+
 impl<'gc> TObject<'gc> for ColorTransformObject<'gc> {
     impl_custom_object_without_set!(base);
 
@@ -1,6 +1,7 @@
 use crate::add_field_accessors;
 use crate::avm1::error::Error;
 use crate::avm1::{Object, ScriptObject, TObject, Value};
+use crate::bitmap::filters::DisplacementMapMode;
 use crate::impl_custom_object_without_set;
 use gc_arena::{Collect, GcCell, MutationContext};
 
@@ -39,6 +40,17 @@ impl From<DisplacementMapFilterMode> for &str {
     }
 }
 
+impl From<DisplacementMapFilterMode> for DisplacementMapMode {
+    fn from(v: DisplacementMapFilterMode) -> Self {
+        match v {
+            DisplacementMapFilterMode::Wrap => DisplacementMapMode::Wrap,
+            DisplacementMapFilterMode::Clamp => DisplacementMapMode::Clamp,
+            DisplacementMapFilterMode::Ignore => DisplacementMapMode::Ignore,
+            DisplacementMapFilterMode::Color => DisplacementMapMode::Color,
+        }
+    }
+}
+
 impl From<DisplacementMapFilterMode> for String {
     fn from(v: DisplacementMapFilterMode) -> Self {
         Into::<&str>::into(v).to_string()
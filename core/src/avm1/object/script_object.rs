@@ -838,11 +838,11 @@ impl<'gc> TObject<'gc> for ScriptObject<'gc> {
 mod tests {
     use super::*;
 
+    use crate::audio_manager::AudioManager;
     use crate::avm1::activation::ActivationIdentifier;
     use crate::avm1::function::Executable;
-    use crate::avm1::globals::system::SystemProperties;
     use crate::avm1::property::Attribute::*;
-    use crate::avm1::{Avm1, Timers};
+    use crate::avm1::Avm1;
     use crate::avm2::Avm2;
     use crate::backend::audio::NullAudioBackend;
     use crate::backend::input::NullInputBackend;
@@ -851,13 +851,16 @@ mod tests {
     use crate::backend::navigator::NullNavigatorBackend;
     use crate::backend::render::NullRenderer;
     use crate::backend::storage::MemoryStorageBackend;
+    use crate::backend::ui::NullUiBackend;
     use crate::context::UpdateContext;
     use crate::display_object::MovieClip;
     use crate::focus_tracker::FocusTracker;
     use crate::library::Library;
     use crate::loader::LoadManager;
     use crate::prelude::*;
+    use crate::system_properties::SystemProperties;
     use crate::tag_utils::{SwfMovie, SwfSlice};
+    use crate::timer::Timers;
     use crate::vminterface::Instantiator;
     use gc_arena::rootless_arena;
     use instant::Instant;
@@ -898,10 +901,18 @@ mod tests {
                 renderer: &mut NullRenderer::new(),
                 locale: &mut NullLocaleBackend::new(),
                 log: &mut NullLogBackend::new(),
+                ui: &mut NullUiBackend::new(),
                 mouse_hovered_object: None,
                 mouse_position: &(Twips::new(0), Twips::new(0)),
                 drag_object: &mut None,
                 stage_size: (Twips::from_pixels(550.0), Twips::from_pixels(400.0)),
+                viewport_size: (550, 400),
+                stage_scale_mode: &mut Default::default(),
+                stage_scale_mode_set_by_script: &mut false,
+                stage_align: &mut Default::default(),
+                stage_quality: &mut Default::default(),
+                show_menu: &mut true,
+                frame_rate: &mut 12.0,
                 player: None,
                 load_manager: &mut LoadManager::new(),
                 system: &mut SystemProperties::default(),
@@ -910,6 +921,7 @@ mod tests {
                 shared_objects: &mut HashMap::new(),
                 unbound_text_fields: &mut Vec::new(),
                 timers: &mut Timers::new(),
+                audio_manager: &mut AudioManager::new(),
                 needs_render: &mut false,
                 avm1: &mut avm1,
                 avm2: &mut avm2,
@@ -1,7 +1,7 @@
+use crate::audio_manager::AudioManager;
 use crate::avm1::activation::{Activation, ActivationIdentifier};
 use crate::avm1::error::Error;
-use crate::avm1::globals::system::SystemProperties;
-use crate::avm1::{Avm1, Object, Timers, UpdateContext};
+use crate::avm1::{Avm1, Object, UpdateContext};
 use crate::avm2::Avm2;
 use crate::backend::audio::NullAudioBackend;
 use crate::backend::input::NullInputBackend;
@@ -10,13 +10,16 @@ use crate::backend::log::NullLogBackend;
 use crate::backend::navigator::NullNavigatorBackend;
 use crate::backend::render::NullRenderer;
 use crate::backend::storage::MemoryStorageBackend;
+use crate::backend::ui::NullUiBackend;
 use crate::context::ActionQueue;
 use crate::display_object::{MovieClip, TDisplayObject};
 use crate::focus_tracker::FocusTracker;
 use crate::library::Library;
 use crate::loader::LoadManager;
 use crate::prelude::*;
+use crate::system_properties::SystemProperties;
 use crate::tag_utils::{SwfMovie, SwfSlice};
+use crate::timer::Timers;
 use crate::vminterface::Instantiator;
 use gc_arena::{rootless_arena, MutationContext};
 use instant::Instant;
@@ -59,10 +62,18 @@ where
             renderer: &mut NullRenderer::new(),
             locale: &mut NullLocaleBackend::new(),
             log: &mut NullLogBackend::new(),
+            ui: &mut NullUiBackend::new(),
             mouse_hovered_object: None,
             mouse_position: &(Twips::new(0), Twips::new(0)),
             drag_object: &mut None,
             stage_size: (Twips::from_pixels(550.0), Twips::from_pixels(400.0)),
+            viewport_size: (550, 400),
+            stage_scale_mode: &mut Default::default(),
+            stage_scale_mode_set_by_script: &mut false,
+            stage_align: &mut Default::default(),
+            stage_quality: &mut Default::default(),
+            show_menu: &mut true,
+            frame_rate: &mut 12.0,
             player: None,
             load_manager: &mut LoadManager::new(),
             system: &mut SystemProperties::default(),
@@ -71,6 +82,7 @@ where
             shared_objects: &mut HashMap::new(),
             unbound_text_fields: &mut Vec::new(),
             timers: &mut Timers::new(),
+            audio_manager: &mut AudioManager::new(),
             needs_render: &mut false,
             avm1: &mut avm1,
             avm2: &mut avm2,
@@ -1,6 +1,6 @@
 //! ActionScript Virtual Machine 2 (AS3) support
 
-use crate::avm2::globals::SystemPrototypes;
+use crate::avm2::globals::{SystemClasses, SystemPrototypes};
 use crate::avm2::method::Method;
 use crate::avm2::script::{Script, TranslationUnit};
 use crate::context::UpdateContext;
@@ -20,9 +20,13 @@ macro_rules! avm_debug {
 
 mod activation;
 mod array;
+mod bytearray;
+mod call_stack;
 mod class;
 mod domain;
+mod e4x;
 mod events;
+mod exception;
 mod function;
 mod globals;
 mod method;
@@ -37,12 +41,21 @@ mod slot;
 mod string;
 mod traits;
 mod value;
+mod vector;
+mod weak;
 
 pub use crate::avm2::activation::Activation;
+pub use crate::avm2::call_stack::CallStack;
 pub use crate::avm2::domain::Domain;
+pub use crate::avm2::globals::flash::display::bitmapdata::bitmap_data_from_pixels;
+pub use crate::avm2::globals::flash::display::loaderinfo::{
+    set_loader_info_content, set_loader_info_progress,
+};
+pub use crate::avm2::globals::flash::media::sound::request_sample_data;
 pub use crate::avm2::names::{Namespace, QName};
 pub use crate::avm2::object::{Object, StageObject, TObject};
 pub use crate::avm2::value::Value;
+pub use crate::avm2::weak::WeakReferences;
 
 /// Boxed error alias.
 ///
@@ -63,6 +76,26 @@ pub struct Avm2<'gc> {
     /// System prototypes.
     system_prototypes: Option<SystemPrototypes<'gc>>,
 
+    /// System classes.
+    system_classes: Option<SystemClasses<'gc>>,
+
+    /// The `Stage` that every AVM2 display object is displayed on.
+    stage: Option<Object<'gc>>,
+
+    /// The bytecode methods currently being executed.
+    call_stack: CallStack<'gc>,
+
+    /// The stack trace of the error currently unwinding the call stack, if
+    /// any.
+    error_trace: Option<String>,
+
+    /// The value most recently thrown from ActionScript, which has not yet
+    /// been caught.
+    thrown: Option<Value<'gc>>,
+
+    /// Weak references held by AS3 objects.
+    weak_references: WeakReferences<'gc>,
+
     #[cfg(feature = "avm_debug")]
     pub debug_output: bool,
 }
@@ -76,6 +109,12 @@ impl<'gc> Avm2<'gc> {
             stack: Vec::new(),
             globals,
             system_prototypes: None,
+            system_classes: None,
+            stage: None,
+            call_stack: CallStack::new(),
+            error_trace: None,
+            thrown: None,
+            weak_references: WeakReferences::new(),
 
             #[cfg(feature = "avm_debug")]
             debug_output: false,
@@ -95,6 +134,103 @@ impl<'gc> Avm2<'gc> {
         self.system_prototypes.as_ref().unwrap()
     }
 
+    /// Return the current set of system classes.
+    ///
+    /// This function panics if the interpreter has not yet been initialized.
+    pub fn classes(&self) -> &SystemClasses<'gc> {
+        self.system_classes.as_ref().unwrap()
+    }
+
+    /// Return the player's stage object, if the interpreter has been
+    /// initialized.
+    pub fn stage(&self) -> Option<Object<'gc>> {
+        self.stage
+    }
+
+    /// Return the bytecode methods currently being executed.
+    pub fn call_stack(&self) -> &CallStack<'gc> {
+        &self.call_stack
+    }
+
+    /// Return the bytecode methods currently being executed.
+    pub fn call_stack_mut(&mut self) -> &mut CallStack<'gc> {
+        &mut self.call_stack
+    }
+
+    /// Record the stack trace of an error that was just raised, unless an
+    /// error is already unwinding the call stack.
+    pub fn capture_error_trace(&mut self) {
+        if self.error_trace.is_none() {
+            self.error_trace = Some(self.call_stack.format());
+        }
+    }
+
+    /// Forget the stack trace of an error that has been handled.
+    pub fn clear_error_trace(&mut self) {
+        self.error_trace = None;
+    }
+
+    /// Take the stack trace of the error currently unwinding the call stack.
+    pub fn take_error_trace(&mut self) -> Option<String> {
+        self.error_trace.take()
+    }
+
+    /// Hold onto a value that was just thrown, until it is caught.
+    fn set_thrown(&mut self, value: Value<'gc>) {
+        self.thrown = Some(value);
+    }
+
+    /// Take the value that is currently being thrown.
+    fn take_thrown(&mut self) -> Value<'gc> {
+        self.thrown.take().unwrap_or(Value::Undefined)
+    }
+
+    /// Forget the error currently unwinding the call stack, once native code
+    /// has handled it rather than passing it on.
+    ///
+    /// This must be called wherever native code swallows an error raised by
+    /// ActionScript, as scripts may still be running further up the call
+    /// stack, and would otherwise see the stale thrown value and trace.
+    pub fn clear_error(&mut self) {
+        self.error_trace = None;
+        self.thrown = None;
+    }
+
+    /// Construct an event and fire it on a target, as if by `dispatchEvent`.
+    ///
+    /// The event is constructed from the given prototype with the given
+    /// constructor arguments. Returns `false` if the event was cancelled.
+    pub fn dispatch_event(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        mut target: Object<'gc>,
+        mut event_proto: Object<'gc>,
+        args: &[Value<'gc>],
+    ) -> Result<bool, Error> {
+        let mut activation = Activation::from_nothing(context.reborrow());
+        let constructor = event_proto
+            .get_property(
+                event_proto,
+                &QName::new(Namespace::public_namespace(), "constructor"),
+                &mut activation,
+            )?
+            .coerce_to_object(&mut activation)?;
+        let event = event_proto.construct(&mut activation, args)?;
+
+        constructor.call(Some(event), args, &mut activation, Some(event_proto))?;
+
+        let dispatch_event = target
+            .get_property(
+                target,
+                &QName::new(Namespace::public_namespace(), "dispatchEvent"),
+                &mut activation,
+            )?
+            .coerce_to_object(&mut activation)?;
+
+        Ok(dispatch_event
+            .call(Some(target), &[event.into()], &mut activation, None)?
+            .coerce_to_boolean())
+    }
+
     /// Run a script's initializer method.
     pub fn run_script_initializer(
         script: Script<'gc>,
@@ -158,6 +294,16 @@ impl<'gc> Avm2<'gc> {
         Ok(())
     }
 
+    /// Get the weak references held by AS3 objects.
+    pub fn weak_references(&self) -> &WeakReferences<'gc> {
+        &self.weak_references
+    }
+
+    /// Get the weak references held by AS3 objects, mutably.
+    pub fn weak_references_mut(&mut self) -> &mut WeakReferences<'gc> {
+        &mut self.weak_references
+    }
+
     pub fn global_domain(&self) -> Domain<'gc> {
         self.globals
     }
@@ -182,6 +328,28 @@ impl<'gc> Avm2<'gc> {
         value
     }
 
+    /// Retrieve a value from the operand stack without removing it.
+    ///
+    /// An index of zero refers to the top-most value.
+    fn peek(&self, index: usize) -> Value<'gc> {
+        self.stack
+            .len()
+            .checked_sub(index + 1)
+            .and_then(|i| self.stack.get(i))
+            .cloned()
+            .unwrap_or(Value::Undefined)
+    }
+
+    /// Get the number of values currently on the operand stack.
+    fn stack_depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Discard every value above a given depth of the operand stack.
+    fn truncate_stack(&mut self, depth: usize) {
+        self.stack.truncate(depth);
+    }
+
     fn pop_args(&mut self, arg_count: u32) -> Vec<Value<'gc>> {
         let mut args = Vec::with_capacity(arg_count as usize);
         args.resize(arg_count as usize, Value::Undefined);
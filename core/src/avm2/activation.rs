@@ -2,10 +2,14 @@
 
 use crate::avm2::array::ArrayStorage;
 use crate::avm2::class::Class;
+use crate::avm2::e4x::{self, escape_attribute_value, escape_element_value};
+use crate::avm2::exception::{self, Exception, NativeError};
 use crate::avm2::method::BytecodeMethod;
 use crate::avm2::method::Method;
 use crate::avm2::names::{Multiname, Namespace, QName};
-use crate::avm2::object::{ArrayObject, FunctionObject, NamespaceObject, ScriptObject};
+use crate::avm2::object::{
+    ArrayObject, DictionaryObject, FunctionObject, NamespaceObject, ScriptObject,
+};
 use crate::avm2::object::{Object, TObject};
 use crate::avm2::scope::Scope;
 use crate::avm2::script::Script;
@@ -18,8 +22,8 @@ use smallvec::SmallVec;
 use std::io::Cursor;
 use swf::avm2::read::Reader;
 use swf::avm2::types::{
-    Class as AbcClass, Index, Method as AbcMethod, Multiname as AbcMultiname,
-    Namespace as AbcNamespace, Op,
+    Class as AbcClass, Exception as AbcException, Index, Method as AbcMethod,
+    Multiname as AbcMultiname, Namespace as AbcNamespace, Op,
 };
 
 /// Represents a particular register set.
@@ -102,6 +106,11 @@ pub struct Activation<'a, 'gc: 'a, 'gc_context: 'a> {
     /// This will not be available if this is not a method call.
     base_proto: Option<Object<'gc>>,
 
+    /// The default XML namespace, as set by `default xml namespace = ...`.
+    ///
+    /// E4X name lookups use this namespace in place of the public namespace.
+    default_xml_namespace: Option<Namespace<'gc>>,
+
     pub context: UpdateContext<'a, 'gc, 'gc_context>,
 }
 
@@ -126,6 +135,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
             local_scope: ScriptObject::bare_object(context.gc_context),
             scope: None,
             base_proto: None,
+            default_xml_namespace: None,
             context,
         }
     }
@@ -165,6 +175,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
             local_scope: ScriptObject::bare_object(context.gc_context),
             scope,
             base_proto: None,
+            default_xml_namespace: None,
             context,
         })
     }
@@ -213,6 +224,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
             local_scope: ScriptObject::bare_object(context.gc_context),
             scope,
             base_proto,
+            default_xml_namespace: None,
             context,
         };
 
@@ -286,6 +298,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
             local_scope: ScriptObject::bare_object(context.gc_context),
             scope,
             base_proto,
+            default_xml_namespace: None,
             context,
         })
     }
@@ -338,6 +351,45 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         function.call(Some(receiver), &args, self, Some(base_proto))
     }
 
+    /// Resolve a type name to the class it refers to.
+    ///
+    /// Parameterized type names (e.g. `Vector.<int>`) resolve to the
+    /// specialization of their base class with the given parameters. The any
+    /// type (`*`) resolves to `None`.
+    pub fn resolve_type(
+        &mut self,
+        type_name: &Multiname<'gc>,
+    ) -> Result<Option<Object<'gc>>, Error> {
+        if type_name.local_name().is_none() {
+            return Ok(None);
+        }
+
+        let base = if let Some(scope) = self.scope() {
+            scope
+                .write(self.context.gc_context)
+                .resolve(type_name, self)?
+        } else {
+            None
+        };
+        let base: Result<Value<'gc>, Error> = base
+            .ok_or_else(|| format!("Attempted to resolve nonexistent type {:?}", type_name).into());
+        let base = base?.coerce_to_object(self)?;
+
+        if type_name.params().is_empty() {
+            return Ok(Some(base));
+        }
+
+        let mut params = Vec::with_capacity(type_name.params().len());
+        for param in type_name.params() {
+            params.push(match self.resolve_type(param)? {
+                Some(param) => param.into(),
+                None => Value::Null,
+            });
+        }
+
+        Ok(Some(base.apply(self, &params[..])?))
+    }
+
     /// Attempts to lock the activation frame for execution.
     ///
     /// If this frame is already executing, that is an error condition.
@@ -409,6 +461,11 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         self.base_proto
     }
 
+    /// Get the default XML namespace of this activation, if one was set.
+    pub fn default_xml_namespace(&self) -> Option<Namespace<'gc>> {
+        self.default_xml_namespace.clone()
+    }
+
     /// Retrieve a int from the current constant pool.
     fn pool_int(
         &self,
@@ -476,6 +533,36 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         Multiname::from_abc_multiname_static(method.translation_unit(), index, mc)
     }
 
+    /// Pop a dictionary and an object key off the stack, if the property
+    /// access about to be made is keyed by one.
+    ///
+    /// `Dictionary` compares object keys by identity, so they must not be
+    /// coerced to strings during multiname resolution. The name is expected
+    /// on top of the stack, with the dictionary itself right below it.
+    fn pop_dictionary_key(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        index: &Index<AbcMultiname>,
+    ) -> Option<(DictionaryObject<'gc>, Object<'gc>)> {
+        if !Multiname::is_abc_late_name(method.translation_unit(), index.clone()) {
+            return None;
+        }
+
+        let key = match self.context.avm2.peek(0) {
+            Value::Object(key) => key,
+            _ => return None,
+        };
+        let dictionary = match self.context.avm2.peek(1) {
+            Value::Object(object) => object.as_dictionary_object()?,
+            _ => return None,
+        };
+
+        self.context.avm2.pop();
+        self.context.avm2.pop();
+
+        Some((dictionary, key))
+    }
+
     /// Retrieve a method entry from the current ABC file's method table.
     fn table_method(
         &mut self,
@@ -507,14 +594,128 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
             .ok_or_else(|| "Cannot execute non-native method without body".into());
         let mut read = Reader::new(Cursor::new(body?.code.as_ref()));
 
-        loop {
+        self.context.avm2.call_stack_mut().push(method);
+
+        let stack_depth = self.context.avm2.stack_depth();
+        let scope = self.scope();
+
+        let result = loop {
             let result = self.do_next_opcode(method, &mut read);
             match result {
                 Ok(FrameControl::Return(value)) => break Ok(value),
                 Ok(FrameControl::Continue) => {}
-                Err(e) => break Err(e),
+                Err(e) => match self.catch_exception(method, &mut read, &e, stack_depth, scope) {
+                    Ok(true) => {}
+                    Ok(false) => break Err(e),
+                    Err(e) => break Err(e),
+                },
+            }
+        };
+
+        self.context.avm2.call_stack_mut().pop();
+
+        // Errors that escape the outermost bytecode method are uncaught, so
+        // log them with the trace of where they were raised.
+        if self.context.avm2.call_stack().is_empty() {
+            let trace = self.context.avm2.take_error_trace().unwrap_or_default();
+            self.context.avm2.take_thrown();
+            if let Err(e) = &result {
+                log::error!("Uncaught AVM2 error: {}{}", e, trace);
+            }
+        }
+
+        result
+    }
+
+    /// Jump to the exception handler for a thrown value, if the op that
+    /// raised it is covered by one.
+    ///
+    /// Values thrown from ActionScript can be caught, as can `NativeError`s
+    /// raised by native code, which are turned into error objects first. Other internal errors always
+    /// unwind the whole call stack. If a handler is found, the operand and
+    /// scope stacks are reset to how they were when the method was entered,
+    /// the thrown value is pushed, and `true` is returned.
+    fn catch_exception(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        reader: &mut Reader<Cursor<&[u8]>>,
+        error: &Error,
+        stack_depth: usize,
+        scope: Option<GcCell<'gc, Scope<'gc>>>,
+    ) -> Result<bool, Error> {
+        let body: Result<_, Error> = method
+            .body()
+            .ok_or_else(|| "Cannot execute non-native method without body".into());
+        let body = body?;
+        let position = reader.seek(0)?;
+
+        // The reader is already past the op that threw, so step back into it.
+        let offset = position.saturating_sub(1) as u32;
+        let is_covered = |exception: &AbcException| {
+            offset >= exception.from_offset && offset < exception.to_offset
+        };
+
+        if !body.exceptions.iter().any(is_covered) {
+            return Ok(false);
+        }
+
+        self.context.avm2.truncate_stack(stack_depth);
+        self.set_scope(scope);
+
+        if !error.is::<Exception>() {
+            match exception::native_error_object(self, error)? {
+                Some(thrown) => self.context.avm2.set_thrown(thrown),
+                None => return Ok(false),
+            }
+        }
+
+        for exception in body.exceptions.iter() {
+            if !is_covered(exception) {
+                continue;
+            }
+
+            let thrown = self.context.avm2.take_thrown();
+            let caught = if exception.type_name.0 == 0 {
+                true
+            } else {
+                let type_name = self.pool_multiname_static(
+                    method,
+                    exception.type_name.clone(),
+                    self.context.gc_context,
+                )?;
+                let type_object = if let Some(scope) = scope {
+                    scope
+                        .write(self.context.gc_context)
+                        .resolve(&type_name, self)?
+                } else {
+                    None
+                };
+
+                // Primitives are checked the same way as by `istype`, so
+                // `throw 5` is caught by `catch (e:int)`.
+                match (type_object, thrown.clone()) {
+                    (Some(Value::Object(_)), Value::Undefined)
+                    | (Some(Value::Object(_)), Value::Null) => false,
+                    (Some(Value::Object(type_object)), thrown) => thrown
+                        .coerce_to_object(self)?
+                        .is_instance_of(self, type_object, true)?,
+                    _ => false,
+                }
+            };
+
+            if !caught {
+                self.context.avm2.set_thrown(thrown);
+                continue;
             }
+
+            self.context.avm2.clear_error_trace();
+            self.context.avm2.push(thrown);
+            reader.seek(exception.target_offset as i64 - position as i64)?;
+
+            return Ok(true);
         }
+
+        Ok(false)
     }
 
     /// Run a single action from a given action reader.
@@ -574,6 +775,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
                 Op::SetProperty { index } => self.op_set_property(method, index),
                 Op::InitProperty { index } => self.op_init_property(method, index),
                 Op::DeleteProperty { index } => self.op_delete_property(method, index),
+                Op::GetDescendants { index } => self.op_get_descendants(method, index),
                 Op::GetSuper { index } => self.op_get_super(method, index),
                 Op::SetSuper { index } => self.op_set_super(method, index),
                 Op::In => self.op_in(),
@@ -590,6 +792,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
                 Op::GetGlobalSlot { index } => self.op_get_global_slot(index),
                 Op::SetGlobalSlot { index } => self.op_set_global_slot(index),
                 Op::Construct { num_args } => self.op_construct(num_args),
+                Op::ApplyType { num_types } => self.op_apply_type(num_types),
                 Op::ConstructProp { index, num_args } => {
                     self.op_construct_prop(method, index, num_args)
                 }
@@ -665,6 +868,11 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
                 Op::IsType { index } => self.op_is_type(method, index),
                 Op::IsTypeLate => self.op_is_type_late(),
                 Op::InstanceOf => self.op_instance_of(),
+                Op::CheckFilter => self.op_check_filter(),
+                Op::EscXElem => self.op_esc_xelem(),
+                Op::EscXAttr => self.op_esc_xattr(),
+                Op::Dxns { index } => self.op_dxns(method, index),
+                Op::DxnsLate => self.op_dxns_late(),
                 Op::Label => Ok(FrameControl::Continue),
                 Op::Debug {
                     is_local_register,
@@ -673,13 +881,15 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
                 } => self.op_debug(method, is_local_register, register_name, register),
                 Op::DebugFile { file_name } => self.op_debug_file(method, file_name),
                 Op::DebugLine { line_num } => self.op_debug_line(line_num),
+                Op::Throw => self.op_throw(),
+                Op::NewCatch { index } => self.op_new_catch(method, index),
                 _ => self.unknown_op(op),
             };
 
-            if let Err(e) = result {
-                log::error!("AVM2 error: {}", e);
-                return Err(e);
+            if result.is_err() {
+                self.context.avm2.capture_error_trace();
             }
+
             result
         } else if let Ok(None) = op {
             log::error!("Unknown opcode!");
@@ -1025,9 +1235,23 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         method: Gc<'gc, BytecodeMethod<'gc>>,
         index: Index<AbcMultiname>,
     ) -> Result<FrameControl<'gc>, Error> {
+        if let Some((dictionary, key)) = self.pop_dictionary_key(method, &index) {
+            let value = dictionary.get_property_by_object(key);
+            self.context.avm2.push(value);
+
+            return Ok(FrameControl::Continue);
+        }
+
         let multiname = self.pool_multiname(method, index)?;
         let mut object = self.context.avm2.pop().coerce_to_object(self)?;
 
+        if let Some(nodes) = e4x::nodes_of(object) {
+            let value = e4x::get_value(self, &nodes, &multiname);
+            self.context.avm2.push(value);
+
+            return Ok(FrameControl::Continue);
+        }
+
         let name: Result<QName, Error> = object.resolve_multiname(&multiname)?.ok_or_else(|| {
             format!("Could not resolve property {:?}", multiname.local_name()).into()
         });
@@ -1056,9 +1280,22 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         index: Index<AbcMultiname>,
     ) -> Result<FrameControl<'gc>, Error> {
         let value = self.context.avm2.pop();
+
+        if let Some((dictionary, key)) = self.pop_dictionary_key(method, &index) {
+            dictionary.set_property_by_object(key, value, self.context.gc_context);
+
+            return Ok(FrameControl::Continue);
+        }
+
         let multiname = self.pool_multiname(method, index)?;
         let mut object = self.context.avm2.pop().coerce_to_object(self)?;
 
+        if e4x::nodes_of(object).is_some() {
+            e4x::set_value(self, object, &multiname, value)?;
+
+            return Ok(FrameControl::Continue);
+        }
+
         if let Some(name) = object.resolve_multiname(&multiname)? {
             object.set_property(object, &name, value, self)?;
         } else {
@@ -1103,9 +1340,23 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         method: Gc<'gc, BytecodeMethod<'gc>>,
         index: Index<AbcMultiname>,
     ) -> Result<FrameControl<'gc>, Error> {
+        if let Some((dictionary, key)) = self.pop_dictionary_key(method, &index) {
+            dictionary.delete_property_by_object(key, self.context.gc_context);
+            self.context.avm2.push(true);
+
+            return Ok(FrameControl::Continue);
+        }
+
         let multiname = self.pool_multiname(method, index)?;
         let object = self.context.avm2.pop().coerce_to_object(self)?;
 
+        if let Some(nodes) = e4x::nodes_of(object) {
+            e4x::delete_value(self, &nodes, &multiname)?;
+            self.context.avm2.push(true);
+
+            return Ok(FrameControl::Continue);
+        }
+
         if let Some(name) = object.resolve_multiname(&multiname)? {
             self.context
                 .avm2
@@ -1123,6 +1374,31 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         Ok(FrameControl::Continue)
     }
 
+    fn op_get_descendants(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        index: Index<AbcMultiname>,
+    ) -> Result<FrameControl<'gc>, Error> {
+        let multiname = self.pool_multiname(method, index)?;
+        let object = self.context.avm2.pop().coerce_to_object(self)?;
+
+        let nodes: Result<_, Error> = e4x::nodes_of(object).ok_or_else(|| {
+            NativeError::new(
+                "TypeError",
+                1016,
+                "Descendants operator (..) not supported on type",
+            )
+            .into()
+        });
+        let default_namespace = self.default_xml_namespace();
+        let descendants = e4x::get_descendants(&nodes?, &multiname, default_namespace.as_ref());
+        let value = e4x::build_xml_list(self, descendants);
+
+        self.context.avm2.push(value);
+
+        Ok(FrameControl::Continue)
+    }
+
     fn op_get_super(
         &mut self,
         method: Gc<'gc, BytecodeMethod<'gc>>,
@@ -1182,7 +1458,17 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
 
     fn op_in(&mut self) -> Result<FrameControl<'gc>, Error> {
         let obj = self.context.avm2.pop().coerce_to_object(self)?;
-        let name = self.context.avm2.pop().coerce_to_string(self)?;
+        let name_value = self.context.avm2.pop();
+
+        if let (Some(dictionary), Value::Object(key)) = (obj.as_dictionary_object(), &name_value) {
+            self.context
+                .avm2
+                .push(dictionary.has_property_by_object(*key));
+
+            return Ok(FrameControl::Continue);
+        }
+
+        let name = name_value.coerce_to_string(self)?;
 
         let qname = QName::new(Namespace::public_namespace(), name);
         let has_prop = obj.has_property(&qname)?;
@@ -1319,8 +1605,8 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
     }
 
     fn op_set_slot(&mut self, index: u32) -> Result<FrameControl<'gc>, Error> {
-        let object = self.context.avm2.pop().coerce_to_object(self)?;
         let value = self.context.avm2.pop();
+        let object = self.context.avm2.pop().coerce_to_object(self)?;
 
         object.set_slot(index, value, self.context.gc_context)?;
 
@@ -1367,6 +1653,16 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         Ok(FrameControl::Continue)
     }
 
+    fn op_apply_type(&mut self, num_types: u32) -> Result<FrameControl<'gc>, Error> {
+        let args = self.context.avm2.pop_args(num_types);
+        let base = self.context.avm2.pop().coerce_to_object(self)?;
+
+        let applied = base.apply(self, &args[..])?;
+        self.context.avm2.push(applied);
+
+        Ok(FrameControl::Continue)
+    }
+
     fn op_construct_prop(
         &mut self,
         method: Gc<'gc, BytecodeMethod<'gc>>,
@@ -1417,6 +1713,37 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         Ok(FrameControl::Continue)
     }
 
+    fn op_new_catch(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        index: Index<AbcException>,
+    ) -> Result<FrameControl<'gc>, Error> {
+        let exception: Result<_, Error> = method
+            .body()
+            .and_then(|body| body.exceptions.get(index.0 as usize))
+            .ok_or_else(|| format!("Unknown exception handler {}", index.0).into());
+        let variable_name = exception?.variable_name.0;
+
+        let mut catch_scope = ScriptObject::bare_object(self.context.gc_context);
+        if variable_name != 0 {
+            let name = self.pool_multiname_static(
+                method,
+                Index::new(variable_name),
+                self.context.gc_context,
+            )?;
+            let name = QName::new(
+                Namespace::public_namespace(),
+                name.local_name().unwrap_or_else(|| "".into()),
+            );
+
+            catch_scope.install_slot(self.context.gc_context, name, 1, Value::Undefined);
+        }
+
+        self.context.avm2.push(catch_scope);
+
+        Ok(FrameControl::Continue)
+    }
+
     fn op_new_object(&mut self, num_args: u32) -> Result<FrameControl<'gc>, Error> {
         let mut object = ScriptObject::object(
             self.context.gc_context,
@@ -1839,6 +2166,12 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         Ok(FrameControl::Continue)
     }
 
+    fn op_throw(&mut self) -> Result<FrameControl<'gc>, Error> {
+        let value = self.context.avm2.pop();
+
+        Err(exception::throw(self, value))
+    }
+
     fn op_jump(
         &mut self,
         offset: i32,
@@ -2135,7 +2468,22 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
 
         let next_index = cur_index + 1;
 
-        if object.get_enumerant_name(next_index).is_some() {
+        if let Some(nodes) = e4x::nodes_of(object) {
+            if (cur_index as usize) < nodes.len() {
+                self.context.avm2.push(next_index);
+            } else {
+                self.context.avm2.push(0.0);
+            }
+
+            return Ok(FrameControl::Continue);
+        }
+
+        let has_enumerant = match object.as_dictionary_object() {
+            Some(dictionary) => dictionary.get_enumerant_key(next_index).is_some(),
+            None => object.get_enumerant_name(next_index).is_some(),
+        };
+
+        if has_enumerant {
             self.context.avm2.push(next_index);
         } else {
             self.context.avm2.push(0.0);
@@ -2157,8 +2505,31 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
 
         let mut next_index = cur_index + 1;
 
+        // XML enumerates the nodes it contains, which are not properties.
+        if let Some(nodes) = object.and_then(e4x::nodes_of) {
+            if (cur_index as usize) >= nodes.len() {
+                next_index = 0;
+                object = None;
+            }
+
+            self.context.avm2.push(next_index != 0);
+            self.set_local_register(index_register, next_index, self.context.gc_context)?;
+            self.set_local_register(
+                object_register,
+                object.map(|v| v.into()).unwrap_or(Value::Null),
+                self.context.gc_context,
+            )?;
+
+            return Ok(FrameControl::Continue);
+        }
+
         while let Some(cur_object) = object {
-            if cur_object.get_enumerant_name(next_index).is_none() {
+            let has_enumerant = match cur_object.as_dictionary_object() {
+                Some(dictionary) => dictionary.get_enumerant_key(next_index).is_some(),
+                None => cur_object.get_enumerant_name(next_index).is_some(),
+            };
+
+            if !has_enumerant {
                 next_index = 1;
                 object = cur_object.proto();
             } else {
@@ -2185,6 +2556,23 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         let cur_index = self.context.avm2.pop().coerce_to_number(self)?;
         let object = self.context.avm2.pop().coerce_to_object(self)?;
 
+        if e4x::nodes_of(object).is_some() {
+            let name = AvmString::new(
+                self.context.gc_context,
+                (cur_index as u32).saturating_sub(1).to_string(),
+            );
+            self.context.avm2.push(name);
+
+            return Ok(FrameControl::Continue);
+        }
+
+        if let Some(dictionary) = object.as_dictionary_object() {
+            let key = dictionary.get_enumerant_key(cur_index as u32);
+            self.context.avm2.push(key.unwrap_or(Value::Undefined));
+
+            return Ok(FrameControl::Continue);
+        }
+
         let name = object
             .get_enumerant_name(cur_index as u32)
             .map(|n| n.local_name().into());
@@ -2198,6 +2586,26 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         let cur_index = self.context.avm2.pop().coerce_to_number(self)?;
         let mut object = self.context.avm2.pop().coerce_to_object(self)?;
 
+        if let Some(nodes) = e4x::nodes_of(object) {
+            let value = match (cur_index as usize)
+                .checked_sub(1)
+                .and_then(|i| nodes.get(i))
+            {
+                Some(node) => e4x::build_xml(self, node.clone()),
+                None => Value::Undefined,
+            };
+            self.context.avm2.push(value);
+
+            return Ok(FrameControl::Continue);
+        }
+
+        if let Some(dictionary) = object.as_dictionary_object() {
+            let value = dictionary.get_enumerant_value(cur_index as u32, self)?;
+            self.context.avm2.push(value);
+
+            return Ok(FrameControl::Continue);
+        }
+
         let name = object.get_enumerant_name(cur_index as u32);
         let value = if let Some(name) = name {
             object.get_property(object, &name, self)?
@@ -2219,22 +2627,12 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
 
         let type_name =
             self.pool_multiname_static(method, type_name_index, self.context.gc_context)?;
-        let type_object = if let Some(scope) = self.scope() {
-            scope.read().find(&type_name, self)?
-        } else {
-            None
+        let is_instance_of = match self.resolve_type(&type_name)? {
+            Some(type_object) => value.is_instance_of(self, type_object, true)?,
+            None => true,
         };
 
-        if let Some(type_object) = type_object {
-            let is_instance_of = value.is_instance_of(self, type_object, true)?;
-            self.context.avm2.push(is_instance_of);
-        } else {
-            return Err(format!(
-                "Attempted to check against nonexistent type {:?}",
-                type_name
-            )
-            .into());
-        }
+        self.context.avm2.push(is_instance_of);
 
         Ok(FrameControl::Continue)
     }
@@ -2261,6 +2659,75 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         Ok(FrameControl::Continue)
     }
 
+    fn op_check_filter(&mut self) -> Result<FrameControl<'gc>, Error> {
+        let value = self.context.avm2.pop();
+
+        let is_xml = matches!(&value, Value::Object(o) if e4x::nodes_of(*o).is_some());
+        if !is_xml {
+            return Err(NativeError::new(
+                "TypeError",
+                1123,
+                format!(
+                    "Filter operator not supported on type {}",
+                    value.coerce_to_debug_string(self)?
+                ),
+            )
+            .into());
+        }
+
+        self.context.avm2.push(value);
+
+        Ok(FrameControl::Continue)
+    }
+
+    fn op_esc_xelem(&mut self) -> Result<FrameControl<'gc>, Error> {
+        let value = self.context.avm2.pop();
+
+        let escaped = match &value {
+            Value::Object(o) if e4x::nodes_of(*o).is_some() => {
+                e4x::list_to_xml_string(&e4x::nodes_of(*o).unwrap_or_default())
+            }
+            value => escape_element_value(&value.coerce_to_string(self)?),
+        };
+
+        self.context
+            .avm2
+            .push(AvmString::new(self.context.gc_context, escaped));
+
+        Ok(FrameControl::Continue)
+    }
+
+    fn op_esc_xattr(&mut self) -> Result<FrameControl<'gc>, Error> {
+        let value = self.context.avm2.pop().coerce_to_string(self)?;
+        let escaped = escape_attribute_value(&value);
+
+        self.context
+            .avm2
+            .push(AvmString::new(self.context.gc_context, escaped));
+
+        Ok(FrameControl::Continue)
+    }
+
+    fn op_dxns(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        index: Index<String>,
+    ) -> Result<FrameControl<'gc>, Error> {
+        let uri = self.pool_string(&method, index, self.context.gc_context)?;
+
+        self.default_xml_namespace = Some(Namespace::Namespace(uri));
+
+        Ok(FrameControl::Continue)
+    }
+
+    fn op_dxns_late(&mut self) -> Result<FrameControl<'gc>, Error> {
+        let uri = self.context.avm2.pop().coerce_to_string(self)?;
+
+        self.default_xml_namespace = Some(Namespace::Namespace(uri));
+
+        Ok(FrameControl::Continue)
+    }
+
     #[allow(unused_variables)]
     #[cfg(avm_debug)]
     fn op_debug(
@@ -2294,34 +2761,25 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         Ok(FrameControl::Continue)
     }
 
-    #[allow(unused_variables)]
-    #[cfg(avm_debug)]
     fn op_debug_file(
         &mut self,
         method: Gc<'gc, BytecodeMethod<'gc>>,
         file_name: Index<String>,
     ) -> Result<FrameControl<'gc>, Error> {
-        let file_name = self.pool_string(method, file_name)?;
+        let file_name = self.pool_string(&method, file_name, self.context.gc_context)?;
 
         avm_debug!(self.avm2(), "File: {}", file_name);
 
-        Ok(FrameControl::Continue)
-    }
+        self.context.avm2.call_stack_mut().set_file(file_name);
 
-    #[allow(unused_variables)]
-    #[cfg(not(avm_debug))]
-    fn op_debug_file(
-        &mut self,
-        method: Gc<'gc, BytecodeMethod<'gc>>,
-        file_name: Index<String>,
-    ) -> Result<FrameControl<'gc>, Error> {
         Ok(FrameControl::Continue)
     }
 
-    #[allow(unused_variables)]
     fn op_debug_line(&mut self, line_num: u32) -> Result<FrameControl<'gc>, Error> {
         avm_debug!(self.avm2(), "Line: {}", line_num);
 
+        self.context.avm2.call_stack_mut().set_line(line_num);
+
         Ok(FrameControl::Continue)
     }
 }
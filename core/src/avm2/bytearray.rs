@@ -0,0 +1,215 @@
+//! ByteArray support types
+
+use crate::avm2::exception::NativeError;
+use crate::avm2::Error;
+use gc_arena::Collect;
+use std::convert::TryInto;
+
+/// The byte order used to read and write multi-byte values.
+#[derive(Clone, Collect, Copy, Debug, PartialEq, Eq)]
+#[collect(require_static)]
+pub enum Endian {
+    Big,
+    Little,
+}
+
+impl Endian {
+    /// The name of this byte order, as used by `flash.utils.Endian`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Endian::Big => "bigEndian",
+            Endian::Little => "littleEndian",
+        }
+    }
+
+    /// Parse the name of a byte order from `flash.utils.Endian`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "bigEndian" => Some(Endian::Big),
+            "littleEndian" => Some(Endian::Little),
+            _ => None,
+        }
+    }
+}
+
+/// The byte storage portion of a `ByteArray` object.
+///
+/// Reads and writes happen at the current `position`, which advances past
+/// whatever was read or written. Writing past the end of the array grows it.
+#[derive(Clone, Collect, Debug)]
+#[collect(require_static)]
+pub struct ByteArrayStorage {
+    /// The bytes in the array.
+    bytes: Vec<u8>,
+
+    /// The position that the next read or write happens at.
+    position: usize,
+
+    /// The byte order of multi-byte values.
+    endian: Endian,
+}
+
+impl ByteArrayStorage {
+    /// Construct an empty byte array.
+    pub fn new() -> Self {
+        Self::from_vec(Vec::new())
+    }
+
+    /// Construct a byte array holding the given bytes.
+    pub fn from_vec(bytes: Vec<u8>) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            endian: Endian::Big,
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn length(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Change the length of the array, zero-filling any new bytes.
+    ///
+    /// The position is moved back if it would be past the end of the array.
+    pub fn set_length(&mut self, length: usize) {
+        self.bytes.resize(length, 0);
+        self.position = self.position.min(length);
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: usize) {
+        self.position = position;
+    }
+
+    /// The number of bytes between the position and the end of the array.
+    pub fn bytes_available(&self) -> usize {
+        self.bytes.len().saturating_sub(self.position)
+    }
+
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian;
+    }
+
+    /// Remove all bytes from the array.
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+        self.position = 0;
+    }
+
+    /// Retrieve a byte by index, ignoring the position.
+    pub fn get(&self, index: usize) -> Option<u8> {
+        self.bytes.get(index).copied()
+    }
+
+    /// Set a byte by index, ignoring the position.
+    ///
+    /// The array grows to fit the index, if necessary.
+    pub fn set(&mut self, index: usize, byte: u8) {
+        if index >= self.bytes.len() {
+            self.bytes.resize(index + 1, 0);
+        }
+
+        self.bytes[index] = byte;
+    }
+
+    /// Read a number of bytes from the position.
+    pub fn read_bytes(&mut self, length: usize) -> Result<&[u8], Error> {
+        if self.bytes_available() < length {
+            return Err(NativeError::new("EOFError", 2030, "End of file was encountered.").into());
+        }
+
+        let start = self.position;
+        self.position += length;
+
+        Ok(&self.bytes[start..self.position])
+    }
+
+    /// Write bytes at the position, overwriting or extending the array.
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        let end = self.position + bytes.len();
+        if end > self.bytes.len() {
+            self.bytes.resize(end, 0);
+        }
+
+        self.bytes[self.position..end].copy_from_slice(bytes);
+        self.position = end;
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, Error> {
+        let bytes = self.read_bytes(2)?.try_into().unwrap();
+        Ok(match self.endian {
+            Endian::Big => u16::from_be_bytes(bytes),
+            Endian::Little => u16::from_le_bytes(bytes),
+        })
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.read_bytes(4)?.try_into().unwrap();
+        Ok(match self.endian {
+            Endian::Big => u32::from_be_bytes(bytes),
+            Endian::Little => u32::from_le_bytes(bytes),
+        })
+    }
+
+    pub fn read_f32(&mut self) -> Result<f32, Error> {
+        Ok(f32::from_bits(self.read_u32()?))
+    }
+
+    pub fn read_f64(&mut self) -> Result<f64, Error> {
+        let bytes = self.read_bytes(8)?.try_into().unwrap();
+        Ok(match self.endian {
+            Endian::Big => f64::from_be_bytes(bytes),
+            Endian::Little => f64::from_le_bytes(bytes),
+        })
+    }
+
+    pub fn write_u8(&mut self, value: u8) {
+        self.write_bytes(&[value]);
+    }
+
+    pub fn write_u16(&mut self, value: u16) {
+        match self.endian {
+            Endian::Big => self.write_bytes(&value.to_be_bytes()),
+            Endian::Little => self.write_bytes(&value.to_le_bytes()),
+        }
+    }
+
+    pub fn write_u32(&mut self, value: u32) {
+        match self.endian {
+            Endian::Big => self.write_bytes(&value.to_be_bytes()),
+            Endian::Little => self.write_bytes(&value.to_le_bytes()),
+        }
+    }
+
+    pub fn write_f32(&mut self, value: f32) {
+        self.write_u32(value.to_bits());
+    }
+
+    pub fn write_f64(&mut self, value: f64) {
+        match self.endian {
+            Endian::Big => self.write_bytes(&value.to_be_bytes()),
+            Endian::Little => self.write_bytes(&value.to_le_bytes()),
+        }
+    }
+}
+
+impl Default for ByteArrayStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
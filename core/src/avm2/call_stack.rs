@@ -0,0 +1,214 @@
+//! AVM2 call stack tracking, for stack traces
+
+use crate::avm2::method::BytecodeMethod;
+use crate::avm2::string::AvmString;
+use gc_arena::{Collect, Gc};
+use std::fmt::Write;
+use swf::avm2::types::{
+    AbcFile, Index, Multiname as AbcMultiname, Namespace as AbcNamespace, Trait as AbcTrait,
+    TraitKind as AbcTraitKind,
+};
+
+/// A bytecode method that is currently executing.
+#[derive(Clone, Collect, Debug)]
+#[collect(no_drop)]
+struct CallFrame<'gc> {
+    /// The method being executed.
+    method: Gc<'gc, BytecodeMethod<'gc>>,
+
+    /// The source file of the code being executed, as given by the last
+    /// `DebugFile` opcode.
+    file: Option<AvmString<'gc>>,
+
+    /// The source line of the code being executed, as given by the last
+    /// `DebugLine` opcode.
+    line: Option<u32>,
+}
+
+/// The stack of bytecode methods currently being executed.
+#[derive(Clone, Collect, Debug, Default)]
+#[collect(no_drop)]
+pub struct CallStack<'gc>(Vec<CallFrame<'gc>>);
+
+impl<'gc> CallStack<'gc> {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Enter a bytecode method.
+    pub fn push(&mut self, method: Gc<'gc, BytecodeMethod<'gc>>) {
+        self.0.push(CallFrame {
+            method,
+            file: None,
+            line: None,
+        });
+    }
+
+    /// Leave the most recently entered bytecode method.
+    pub fn pop(&mut self) {
+        self.0.pop();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Record the source file of the currently executing method.
+    pub fn set_file(&mut self, file: AvmString<'gc>) {
+        if let Some(frame) = self.0.last_mut() {
+            frame.file = Some(file);
+        }
+    }
+
+    /// Record the source line of the currently executing method.
+    pub fn set_line(&mut self, line: u32) {
+        if let Some(frame) = self.0.last_mut() {
+            frame.line = Some(line);
+        }
+    }
+
+    /// Format the call stack in the style of Flash Player's stack traces,
+    /// with the most recently entered method first.
+    ///
+    /// Each frame is printed on its own line, such as
+    /// `\tat com.foo::Bar/baz()[Bar.as:42]`.
+    pub fn format(&self) -> String {
+        let mut output = String::new();
+        for frame in self.0.iter().rev() {
+            let _ = write!(output, "\n\tat {}", frame.method.name);
+            if let Some(file) = frame.file {
+                // Compilers separate the source path from the package path
+                // with semicolons.
+                let separator = if file.contains('\\') { "\\" } else { "/" };
+                let file = file.replace(';', separator);
+                match frame.line {
+                    Some(line) => {
+                        let _ = write!(output, "[{}:{}]", file, line);
+                    }
+                    None => {
+                        let _ = write!(output, "[{}]", file);
+                    }
+                }
+            }
+        }
+        output
+    }
+}
+
+/// Look up a string in the ABC constant pool.
+fn pool_string<'a>(abc: &'a AbcFile, index: &Index<String>) -> &'a str {
+    match index.0 {
+        0 => "",
+        i => abc
+            .constant_pool
+            .strings
+            .get(i as usize - 1)
+            .map(String::as_str)
+            .unwrap_or(""),
+    }
+}
+
+/// Format a multiname from the ABC constant pool as a qualified name, such
+/// as `com.foo::Bar`.
+fn multiname(abc: &AbcFile, index: &Index<AbcMultiname>) -> String {
+    let abc_multiname = match index.0 {
+        0 => None,
+        i => abc.constant_pool.multinames.get(i as usize - 1),
+    };
+
+    match abc_multiname {
+        Some(AbcMultiname::QName { namespace, name })
+        | Some(AbcMultiname::QNameA { namespace, name }) => {
+            let package = match namespace.0 {
+                0 => None,
+                i => abc.constant_pool.namespaces.get(i as usize - 1),
+            };
+            let name = pool_string(abc, name);
+            match package {
+                Some(AbcNamespace::Package(package))
+                | Some(AbcNamespace::PackageInternal(package))
+                    if !pool_string(abc, package).is_empty() =>
+                {
+                    format!("{}::{}", pool_string(abc, package), name)
+                }
+                _ => name.to_string(),
+            }
+        }
+        Some(AbcMultiname::Multiname { name, .. })
+        | Some(AbcMultiname::MultinameA { name, .. })
+        | Some(AbcMultiname::RTQName { name })
+        | Some(AbcMultiname::RTQNameA { name }) => pool_string(abc, name).to_string(),
+        _ => "*".to_string(),
+    }
+}
+
+/// List the methods bound to a set of traits, alongside the names they are
+/// bound under, such as `get width`.
+fn trait_methods<'a>(
+    abc: &'a AbcFile,
+    traits: &'a [AbcTrait],
+) -> impl Iterator<Item = (u32, String)> + 'a {
+    traits.iter().filter_map(move |t| {
+        let (method, prefix) = match &t.kind {
+            AbcTraitKind::Method { method, .. } => (method, ""),
+            AbcTraitKind::Getter { method, .. } => (method, "get "),
+            AbcTraitKind::Setter { method, .. } => (method, "set "),
+            AbcTraitKind::Function { function, .. } => (function, ""),
+            _ => return None,
+        };
+
+        let name = multiname(abc, &t.name);
+        let local_name = name.rsplit("::").next().unwrap_or(&name);
+        Some((method.0, format!("{}{}", prefix, local_name)))
+    })
+}
+
+/// Name every method of an ABC file as Flash Player would print it in a
+/// stack trace, such as `com.foo::Bar/baz()`.
+///
+/// Methods are named after the class or script trait that they are bound to.
+/// The returned names are indexed by method.
+pub fn method_names(abc: &AbcFile) -> Vec<String> {
+    let mut names = vec![None; abc.methods.len()];
+    let mut name_method = |method: u32, name: String| {
+        if let Some(slot @ None) = names.get_mut(method as usize) {
+            *slot = Some(name);
+        }
+    };
+
+    for (instance, class) in abc.instances.iter().zip(abc.classes.iter()) {
+        let class_name = multiname(abc, &instance.name);
+        name_method(instance.init_method.0, format!("{}()", class_name));
+        name_method(class.init_method.0, format!("{}$cinit()", class_name));
+        for (method, name) in trait_methods(abc, &instance.traits) {
+            name_method(method, format!("{}/{}()", class_name, name));
+        }
+        for (method, name) in trait_methods(abc, &class.traits) {
+            name_method(method, format!("{}$/{}()", class_name, name));
+        }
+    }
+
+    for script in abc.scripts.iter() {
+        name_method(script.init_method.0, "global$init()".to_string());
+        for t in script.traits.iter() {
+            match &t.kind {
+                AbcTraitKind::Method { method, .. }
+                | AbcTraitKind::Function {
+                    function: method, ..
+                } => name_method(method.0, format!("{}()", multiname(abc, &t.name))),
+                _ => {}
+            }
+        }
+    }
+
+    names
+        .into_iter()
+        .zip(abc.methods.iter())
+        .map(|(name, method)| {
+            name.unwrap_or_else(|| match pool_string(abc, &method.name) {
+                "" => "Function/<anonymous>()".to_string(),
+                name => format!("{}()", name),
+            })
+        })
+        .collect()
+}
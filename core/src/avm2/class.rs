@@ -2,6 +2,7 @@
 
 use crate::avm2::method::Method;
 use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{Object, TObject};
 use crate::avm2::script::TranslationUnit;
 use crate::avm2::string::AvmString;
 use crate::avm2::traits::{Trait, TraitKind};
@@ -24,6 +25,10 @@ pub enum ClassAttributes {
 
     /// Class is an interface.
     Interface,
+
+    /// Class is a generic type, and must be specialized with type parameters
+    /// (e.g. with `applytype`) before it can be fully used.
+    Generic,
 }
 
 /// A loaded ABC Class which can be used to construct objects with.
@@ -69,6 +74,12 @@ pub struct Class<'gc> {
 
     /// Whether or not this `Class` has loaded its traits or not.
     traits_loaded: bool,
+
+    /// The type parameters this class was specialized with, if it is the
+    /// application of a generic class.
+    ///
+    /// `None` parameters indicate the any type (`*`).
+    params: Vec<Option<Object<'gc>>>,
 }
 
 /// Find traits in a list of traits matching a name.
@@ -136,6 +147,7 @@ impl<'gc> Class<'gc> {
                 class_init,
                 class_traits: Vec::new(),
                 traits_loaded: true,
+                params: Vec::new(),
             },
         )
     }
@@ -228,6 +240,7 @@ impl<'gc> Class<'gc> {
                 class_init,
                 class_traits: Vec::new(),
                 traits_loaded: false,
+                params: Vec::new(),
             },
         ))
     }
@@ -412,4 +425,49 @@ impl<'gc> Class<'gc> {
     pub fn is_sealed(&self) -> bool {
         self.attributes.0.contains(ClassAttributes::Sealed)
     }
+
+    /// Determine if this class is generic (must be specialized with type
+    /// parameters before use)
+    pub fn is_generic(&self) -> bool {
+        self.attributes.0.contains(ClassAttributes::Generic)
+    }
+
+    /// Get the type parameters this class was specialized with.
+    pub fn params(&self) -> &[Option<Object<'gc>>] {
+        &self.params[..]
+    }
+
+    /// Specialize a generic class with a given set of type parameters.
+    ///
+    /// The resulting class is named after the generic class and its
+    /// parameters (e.g. `Vector.<int>`), and declares the same traits. It is
+    /// not itself generic.
+    pub fn with_type_params(
+        &self,
+        params: &[Option<Object<'gc>>],
+        mc: MutationContext<'gc, '_>,
+    ) -> GcCell<'gc, Class<'gc>> {
+        let mut new_class = self.clone();
+
+        let param_names = params
+            .iter()
+            .map(|p| {
+                p.and_then(|p| p.as_class())
+                    .map(|c| c.read().name().local_name().to_string())
+                    .unwrap_or_else(|| "*".to_string())
+            })
+            .collect::<Vec<String>>();
+
+        new_class.name = QName::new(
+            self.name.namespace().clone(),
+            AvmString::new(
+                mc,
+                format!("{}.<{}>", self.name.local_name(), param_names.join(",")),
+            ),
+        );
+        new_class.attributes.0.remove(ClassAttributes::Generic);
+        new_class.params = params.to_vec();
+
+        GcCell::allocate(mc, new_class)
+    }
 }
@@ -0,0 +1,857 @@
+//! ECMAScript for XML (E4X) support types
+
+use crate::avm2::activation::Activation;
+use crate::avm2::exception::NativeError;
+use crate::avm2::names::{Multiname, Namespace};
+use crate::avm2::object::{Object, TObject, XmlListObject, XmlObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::xml::{XMLDocument, XMLName, XMLNode};
+use gc_arena::{Collect, MutationContext};
+
+/// The number of spaces each level of nesting is indented by in
+/// `toXMLString` output.
+const PRETTY_INDENT: usize = 2;
+
+/// A single node of an E4X `XML` object.
+///
+/// E4X nodes are views into the same XML tree that AVM1's `XMLNode` uses.
+/// Attributes are not nodes in that tree, so they are instead represented by
+/// their name and owning element.
+#[derive(Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub enum E4XNode<'gc> {
+    /// An element, text, or comment node.
+    Node(XMLNode<'gc>),
+
+    /// An attribute of an element node.
+    Attribute { parent: XMLNode<'gc>, name: XMLName },
+}
+
+/// Escape a string for use as the text content of an element.
+pub fn escape_element_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Escape a string for use as the value of an attribute.
+pub fn escape_attribute_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\n' => escaped.push_str("&#xA;"),
+            '\r' => escaped.push_str("&#xD;"),
+            '\t' => escaped.push_str("&#x9;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Check if an attribute is actually a namespace declaration.
+fn is_namespace_declaration(name: &XMLName) -> bool {
+    match name.prefix() {
+        Some(prefix) => prefix == "xmlns",
+        None => name.local_name() == "xmlns",
+    }
+}
+
+/// Check if a local name is the E4X wildcard name.
+fn is_any_name(multiname: &Multiname<'_>) -> bool {
+    multiname
+        .local_name()
+        .map(|name| &*name == "*")
+        .unwrap_or(true)
+}
+
+impl<'gc> E4XNode<'gc> {
+    /// Parse a string into a list of E4X nodes.
+    ///
+    /// Whitespace-only text, comments, and doctypes are discarded, matching
+    /// the default settings of `XML.ignoreWhitespace` and
+    /// `XML.ignoreComments`.
+    pub fn parse(mc: MutationContext<'gc, '_>, data: &str) -> Result<Vec<Self>, Error> {
+        let document = XMLDocument::new(mc);
+        let mut root = document.as_node();
+
+        root.replace_with_str(mc, data, true, true)
+            .map_err(|e| format!("TypeError: XML parser failure: {}", e))?;
+
+        Ok(Self::children_of(root))
+    }
+
+    /// Construct a new text node that does not belong to any element.
+    pub fn text(mc: MutationContext<'gc, '_>, contents: &str) -> Self {
+        Self::Node(XMLNode::new_text(mc, contents, XMLDocument::new(mc)))
+    }
+
+    /// List the children of an XML tree node that are visible to E4X.
+    fn children_of(node: XMLNode<'gc>) -> Vec<Self> {
+        node.children()
+            .map(|children| {
+                children
+                    .filter(|c| c.is_element() || c.is_text())
+                    .map(Self::Node)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get the underlying XML tree node, if this is not an attribute.
+    pub fn node(&self) -> Option<XMLNode<'gc>> {
+        match self {
+            Self::Node(node) => Some(*node),
+            Self::Attribute { .. } => None,
+        }
+    }
+
+    /// Check if two nodes refer to the same node of the same tree.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Node(a), Self::Node(b)) => XMLNode::ptr_eq(*a, *b),
+            (
+                Self::Attribute {
+                    parent: a_parent,
+                    name: a_name,
+                },
+                Self::Attribute {
+                    parent: b_parent,
+                    name: b_name,
+                },
+            ) => XMLNode::ptr_eq(*a_parent, *b_parent) && a_name == b_name,
+            _ => false,
+        }
+    }
+
+    /// Get the kind of this node, as reported by `XML.nodeKind`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Attribute { .. } => "attribute",
+            Self::Node(node) if node.is_element() => "element",
+            Self::Node(node) if node.is_comment() => "comment",
+            Self::Node(_) => "text",
+        }
+    }
+
+    pub fn is_element(&self) -> bool {
+        matches!(self, Self::Node(node) if node.is_element())
+    }
+
+    pub fn is_attribute(&self) -> bool {
+        matches!(self, Self::Attribute { .. })
+    }
+
+    /// Get the qualified name of this node, if it has one.
+    pub fn name(&self) -> Option<XMLName> {
+        match self {
+            Self::Node(node) => node.tag_name(),
+            Self::Attribute { name, .. } => Some(name.clone()),
+        }
+    }
+
+    /// Resolve the namespace URI of this node's name.
+    ///
+    /// Unprefixed elements are in the default namespace declared by the
+    /// nearest `xmlns` attribute. Unprefixed attributes are never in a
+    /// namespace.
+    pub fn namespace_uri(&self) -> Option<String> {
+        match self {
+            Self::Node(node) => {
+                let name = node.tag_name()?;
+
+                Some(
+                    node.lookup_uri_for_namespace(name.prefix().unwrap_or(""))
+                        .unwrap_or_default(),
+                )
+            }
+            Self::Attribute { parent, name } => Some(match name.prefix() {
+                Some(prefix) => parent.lookup_uri_for_namespace(prefix).unwrap_or_default(),
+                None => String::new(),
+            }),
+        }
+    }
+
+    /// Get the element which contains this node, if any.
+    pub fn parent(&self) -> Option<XMLNode<'gc>> {
+        match self {
+            Self::Node(node) => node
+                .parent()
+                .ok()
+                .flatten()
+                .filter(|parent| !parent.is_document_root()),
+            Self::Attribute { parent, .. } => Some(*parent),
+        }
+    }
+
+    /// List the children of this node.
+    pub fn children(&self) -> Vec<Self> {
+        match self {
+            Self::Node(node) => Self::children_of(*node),
+            Self::Attribute { .. } => Vec::new(),
+        }
+    }
+
+    /// List the attributes of this node.
+    ///
+    /// Namespace declarations are not considered attributes.
+    pub fn attributes(&self) -> Vec<Self> {
+        match self {
+            Self::Node(node) => node
+                .attribute_keys()
+                .iter()
+                .map(|key| XMLName::from_str(key))
+                .filter(|name| !is_namespace_declaration(name))
+                .map(|name| Self::Attribute {
+                    parent: *node,
+                    name,
+                })
+                .collect(),
+            Self::Attribute { .. } => Vec::new(),
+        }
+    }
+
+    /// Check if this node's name matches a multiname.
+    ///
+    /// The `default_namespace` is the default XML namespace in effect, if
+    /// any. It takes the place of the public namespace when matching element
+    /// names.
+    pub fn matches(
+        &self,
+        multiname: &Multiname<'gc>,
+        default_namespace: Option<&Namespace<'gc>>,
+    ) -> bool {
+        if self.is_attribute() != multiname.is_attribute() {
+            return false;
+        }
+
+        let name = match self.name() {
+            Some(name) => name,
+            None => return is_any_name(multiname),
+        };
+
+        if !is_any_name(multiname)
+            && multiname.local_name().map(|n| &*n == name.local_name()) != Some(true)
+        {
+            return false;
+        }
+
+        let uri = self.namespace_uri().unwrap_or_default();
+
+        multiname.namespace_set().any(|ns| match ns {
+            Namespace::Any => true,
+            ns if ns.is_public() => match default_namespace {
+                Some(default_namespace) if !self.is_attribute() => {
+                    *default_namespace.as_uri() == *uri
+                }
+                _ => uri.is_empty(),
+            },
+            Namespace::Namespace(ns_uri) | Namespace::Package(ns_uri) => **ns_uri == *uri,
+            _ => false,
+        })
+    }
+
+    /// Determine if this node has simple content.
+    ///
+    /// Elements have simple content if they have no element children.
+    /// Comments never have simple content.
+    pub fn has_simple_content(&self) -> bool {
+        match self {
+            Self::Node(node) if node.is_element() => {
+                !self.children().iter().any(|child| child.is_element())
+            }
+            Self::Node(node) => !node.is_comment(),
+            Self::Attribute { .. } => true,
+        }
+    }
+
+    /// Retrieve the text of a text node.
+    ///
+    /// Surrounding whitespace is ignored, as per `XML.ignoreWhitespace`.
+    fn text_value(node: XMLNode<'gc>) -> String {
+        node.node_value()
+            .map(|value| value.trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Convert this node to a string, as per the E4X `ToString` operation.
+    ///
+    /// Nodes with simple content yield their text; all other nodes yield
+    /// their XML source.
+    pub fn to_e4x_string(&self) -> String {
+        match self {
+            Self::Attribute { parent, name } => parent.attribute_value(name).unwrap_or_default(),
+            Self::Node(node) if node.is_text() => Self::text_value(*node),
+            Self::Node(_) if self.is_element() && self.has_simple_content() => self
+                .children()
+                .iter()
+                .map(|child| child.to_e4x_string())
+                .collect(),
+            Self::Node(_) => self.to_xml_string(),
+        }
+    }
+
+    /// Convert this node to its XML source, as per `XML.toXMLString`.
+    pub fn to_xml_string(&self) -> String {
+        let mut output = String::new();
+
+        self.write_xml(&mut output, 0);
+
+        output
+    }
+
+    /// Write the XML source of this node to a string.
+    ///
+    /// Output is pretty-printed: children are placed on their own, indented
+    /// lines unless the element contains only a single text node.
+    fn write_xml(&self, output: &mut String, indent: usize) {
+        let node = match self {
+            Self::Node(node) => *node,
+            Self::Attribute { .. } => {
+                output.push_str(&escape_element_value(&self.to_e4x_string()));
+                return;
+            }
+        };
+
+        if node.is_text() {
+            output.push_str(&escape_element_value(&Self::text_value(node)));
+            return;
+        }
+
+        if node.is_comment() {
+            output.push_str("<!--");
+            output.push_str(&node.node_value().unwrap_or_default());
+            output.push_str("-->");
+            return;
+        }
+
+        let tag_name = match node.tag_name() {
+            Some(tag_name) => tag_name,
+            None => return,
+        };
+
+        output.push('<');
+        output.push_str(&tag_name.node_name());
+
+        for key in node.attribute_keys() {
+            let value = node
+                .attribute_value(&XMLName::from_str(&key))
+                .unwrap_or_default();
+
+            output.push(' ');
+            output.push_str(&key);
+            output.push_str("=\"");
+            output.push_str(&escape_attribute_value(&value));
+            output.push('"');
+        }
+
+        let children = self.children();
+        if children.is_empty() {
+            output.push_str("/>");
+            return;
+        }
+
+        output.push('>');
+
+        let indent_children = children.len() > 1 || children.iter().any(|c| c.is_element());
+
+        for child in children.iter() {
+            if indent_children {
+                if matches!(child, Self::Node(n) if n.is_text() && Self::text_value(*n).is_empty())
+                {
+                    continue;
+                }
+
+                output.push('\n');
+                output.push_str(&" ".repeat(indent + PRETTY_INDENT));
+            }
+
+            child.write_xml(output, indent + PRETTY_INDENT);
+        }
+
+        if indent_children {
+            output.push('\n');
+            output.push_str(&" ".repeat(indent));
+        }
+
+        output.push_str("</");
+        output.push_str(&tag_name.node_name());
+        output.push('>');
+    }
+
+    /// Make a deep copy of this node that does not belong to any element.
+    ///
+    /// Attributes are copied as text nodes containing their value.
+    pub fn deep_copy(&self, mc: MutationContext<'gc, '_>) -> XMLNode<'gc> {
+        match self {
+            Self::Node(node) => node.duplicate(mc, true),
+            Self::Attribute { .. } => {
+                XMLNode::new_text(mc, &self.to_e4x_string(), XMLDocument::new(mc))
+            }
+        }
+    }
+
+    /// Replace all children of this element with copies of a list of nodes.
+    pub fn replace_children(
+        &self,
+        mc: MutationContext<'gc, '_>,
+        values: &[Self],
+    ) -> Result<(), Error> {
+        if let Self::Node(mut node) = self {
+            if !node.is_element() {
+                return Ok(());
+            }
+
+            let old_children: Vec<XMLNode<'gc>> =
+                node.children().map(|c| c.collect()).unwrap_or_default();
+            for child in old_children {
+                node.remove_child(mc, child)?;
+            }
+
+            self.append_children(mc, values)?;
+        }
+
+        Ok(())
+    }
+
+    /// Append copies of a list of nodes to the children of this element.
+    pub fn append_children(
+        &self,
+        mc: MutationContext<'gc, '_>,
+        values: &[Self],
+    ) -> Result<(), Error> {
+        if let Self::Node(mut node) = self {
+            if !node.is_element() {
+                return Err("TypeError: Only elements may have children appended".into());
+            }
+
+            for value in values {
+                node.append_child(mc, value.deep_copy(mc))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set the value of an attribute of this element.
+    ///
+    /// If an attribute matching the name already exists, it is replaced.
+    /// Otherwise a new, unprefixed attribute is created.
+    pub fn set_attribute(
+        &self,
+        mc: MutationContext<'gc, '_>,
+        multiname: &Multiname<'gc>,
+        value: &str,
+    ) -> Result<(), Error> {
+        if let Self::Node(node) = self {
+            let existing = self
+                .attributes()
+                .into_iter()
+                .find(|attr| attr.matches(multiname, None))
+                .and_then(|attr| attr.name());
+            let name = match existing {
+                Some(name) => name,
+                None if is_any_name(multiname) => {
+                    return Err(
+                        "TypeError: Cannot assign to all attributes of an XML object".into(),
+                    )
+                }
+                None => XMLName::from_str(&multiname.local_name().unwrap()),
+            };
+
+            node.set_attribute_value(mc, &name, value);
+        }
+
+        Ok(())
+    }
+
+    /// Assign a value to the children of this element that match a name.
+    ///
+    /// If the value contains elements, they replace the first matching child
+    /// and all other matching children are removed. Otherwise, the value
+    /// becomes the content of the first matching child, which is created if
+    /// it does not exist.
+    pub fn set_property(
+        &self,
+        mc: MutationContext<'gc, '_>,
+        multiname: &Multiname<'gc>,
+        values: &[Self],
+        default_namespace: Option<&Namespace<'gc>>,
+    ) -> Result<(), Error> {
+        if multiname.is_attribute() {
+            let value = values
+                .iter()
+                .map(|v| v.to_e4x_string())
+                .collect::<Vec<String>>()
+                .join(" ");
+
+            return self.set_attribute(mc, multiname, &value);
+        }
+
+        let mut node = match self {
+            Self::Node(node) if node.is_element() => *node,
+            _ => return Ok(()),
+        };
+
+        if is_any_name(multiname) {
+            return Err("TypeError: Cannot assign to all children of an XML object".into());
+        }
+
+        let mut matching: Vec<XMLNode<'gc>> = self
+            .children()
+            .into_iter()
+            .filter(|child| child.is_element() && child.matches(multiname, default_namespace))
+            .filter_map(|child| child.node())
+            .collect();
+
+        if values.iter().any(|value| value.is_element()) {
+            let position = matching
+                .first()
+                .and_then(|first| node.child_position(*first))
+                .unwrap_or_else(|| node.children_len());
+
+            for old_child in matching {
+                node.remove_child(mc, old_child)?;
+            }
+
+            for (i, value) in values.iter().enumerate() {
+                node.insert_child(mc, position + i, value.deep_copy(mc))?;
+            }
+        } else {
+            let element = if matching.is_empty() {
+                let element =
+                    XMLNode::new_element(mc, &multiname.local_name().unwrap(), node.document());
+                node.append_child(mc, element)?;
+
+                element
+            } else {
+                let first = matching.remove(0);
+                for old_child in matching {
+                    node.remove_child(mc, old_child)?;
+                }
+
+                first
+            };
+
+            Self::Node(element).replace_children(mc, values)?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove all children or attributes of this element that match a name.
+    pub fn delete_property(
+        &self,
+        mc: MutationContext<'gc, '_>,
+        multiname: &Multiname<'gc>,
+        default_namespace: Option<&Namespace<'gc>>,
+    ) -> Result<(), Error> {
+        if let Self::Node(mut node) = self {
+            if multiname.is_attribute() {
+                for attr in self.attributes() {
+                    if let (true, Some(name)) = (attr.matches(multiname, None), attr.name()) {
+                        node.delete_attribute(mc, &name);
+                    }
+                }
+            } else {
+                for child in self.children() {
+                    if let (true, Some(child)) =
+                        (child.matches(multiname, default_namespace), child.node())
+                    {
+                        node.remove_child(mc, child)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Collect the nodes represented by an `XML` or `XMLList` object.
+///
+/// Yields `None` if the object is neither.
+pub fn nodes_of<'gc>(object: Object<'gc>) -> Option<Vec<E4XNode<'gc>>> {
+    if let Some(node) = object.as_xml() {
+        return Some(vec![node.clone()]);
+    }
+
+    object.as_xml_list().map(|list| list.clone())
+}
+
+/// Find all children (or attributes) of a list of nodes that match a name.
+pub fn get_property<'gc>(
+    nodes: &[E4XNode<'gc>],
+    multiname: &Multiname<'gc>,
+    default_namespace: Option<&Namespace<'gc>>,
+) -> Vec<E4XNode<'gc>> {
+    let mut result = Vec::new();
+
+    for node in nodes {
+        let candidates = if multiname.is_attribute() {
+            node.attributes()
+        } else {
+            node.children()
+        };
+
+        result.extend(
+            candidates
+                .into_iter()
+                .filter(|candidate| candidate.matches(multiname, default_namespace)),
+        );
+    }
+
+    result
+}
+
+/// Find all descendants (or attributes of descendants) of a list of nodes
+/// that match a name, in document order.
+pub fn get_descendants<'gc>(
+    nodes: &[E4XNode<'gc>],
+    multiname: &Multiname<'gc>,
+    default_namespace: Option<&Namespace<'gc>>,
+) -> Vec<E4XNode<'gc>> {
+    fn walk<'gc>(
+        node: &E4XNode<'gc>,
+        multiname: &Multiname<'gc>,
+        default_namespace: Option<&Namespace<'gc>>,
+        result: &mut Vec<E4XNode<'gc>>,
+    ) {
+        if multiname.is_attribute() {
+            result.extend(
+                node.attributes()
+                    .into_iter()
+                    .filter(|attr| attr.matches(multiname, default_namespace)),
+            );
+        }
+
+        for child in node.children() {
+            if child.matches(multiname, default_namespace) {
+                result.push(child.clone());
+            }
+
+            walk(&child, multiname, default_namespace, result);
+        }
+    }
+
+    let mut result = Vec::new();
+
+    for node in nodes {
+        walk(node, multiname, default_namespace, &mut result);
+    }
+
+    result
+}
+
+/// Determine if a list of nodes has simple content.
+///
+/// Lists have simple content if they contain no elements, or if they consist
+/// of a single element that has simple content.
+pub fn list_has_simple_content(nodes: &[E4XNode<'_>]) -> bool {
+    match nodes {
+        [node] => node.has_simple_content(),
+        nodes => !nodes.iter().any(|node| node.is_element()),
+    }
+}
+
+/// Convert a list of nodes to a string, as per the E4X `ToString` operation.
+pub fn list_to_string(nodes: &[E4XNode<'_>]) -> String {
+    if list_has_simple_content(nodes) {
+        nodes
+            .iter()
+            .filter(|node| node.kind() != "comment")
+            .map(|node| node.to_e4x_string())
+            .collect()
+    } else {
+        list_to_xml_string(nodes)
+    }
+}
+
+/// Convert a list of nodes to XML source, as per `XMLList.toXMLString`.
+pub fn list_to_xml_string(nodes: &[E4XNode<'_>]) -> String {
+    nodes
+        .iter()
+        .map(|node| node.to_xml_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Box a node into a new `XML` object.
+pub fn build_xml<'gc>(activation: &mut Activation<'_, 'gc, '_>, node: E4XNode<'gc>) -> Value<'gc> {
+    XmlObject::from_node(
+        node,
+        activation.context.avm2.prototypes().xml,
+        activation.context.gc_context,
+    )
+    .into()
+}
+
+/// Box a list of nodes into a new `XMLList` object.
+pub fn build_xml_list<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    nodes: Vec<E4XNode<'gc>>,
+) -> Value<'gc> {
+    XmlListObject::from_nodes(
+        nodes,
+        activation.context.avm2.prototypes().xml_list,
+        activation.context.gc_context,
+    )
+    .into()
+}
+
+/// Convert a value into the nodes it represents.
+///
+/// `XML` and `XMLList` objects yield their own nodes. All other values are
+/// converted into a single text node.
+pub fn value_to_nodes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: &Value<'gc>,
+) -> Result<Vec<E4XNode<'gc>>, Error> {
+    if let Value::Object(object) = value {
+        if let Some(nodes) = nodes_of(*object) {
+            return Ok(nodes);
+        }
+    }
+
+    let text = value.coerce_to_string(activation)?;
+
+    Ok(vec![E4XNode::text(activation.context.gc_context, &text)])
+}
+
+/// Parse a numeric list index out of a name.
+fn list_index(multiname: &Multiname<'_>) -> Option<usize> {
+    if multiname.is_attribute() {
+        return None;
+    }
+
+    multiname.local_name()?.parse::<usize>().ok()
+}
+
+/// Retrieve a property of a list of nodes, as per the E4X `[[Get]]`
+/// operation.
+///
+/// Numeric names index into the list itself. All other names yield a list
+/// of the matching children or attributes.
+pub fn get_value<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    nodes: &[E4XNode<'gc>],
+    multiname: &Multiname<'gc>,
+) -> Value<'gc> {
+    if let Some(index) = list_index(multiname) {
+        return match nodes.get(index) {
+            Some(node) => build_xml(activation, node.clone()),
+            None => Value::Undefined,
+        };
+    }
+
+    let default_namespace = activation.default_xml_namespace();
+    let matching = get_property(nodes, multiname, default_namespace.as_ref());
+
+    build_xml_list(activation, matching)
+}
+
+/// Assign a property of an `XML` or `XMLList` object, as per the E4X
+/// `[[Put]]` operation.
+///
+/// Numeric names replace (or append) items of an `XMLList`. All other names
+/// assign to the children or attributes of the object's only node.
+pub fn set_value<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    multiname: &Multiname<'gc>,
+    value: Value<'gc>,
+) -> Result<(), Error> {
+    let values = value_to_nodes(activation, &value)?;
+    let default_namespace = activation.default_xml_namespace();
+    let mc = activation.context.gc_context;
+
+    if let Some(index) = list_index(multiname) {
+        let list: Result<_, Error> = object.as_xml_list_mut(mc).ok_or_else(|| {
+            NativeError::new(
+                "TypeError",
+                1087,
+                "Assignment to indexed XML is not allowed.",
+            )
+            .into()
+        });
+        let mut list = list?;
+
+        if index >= list.len() {
+            list.extend(values);
+            return Ok(());
+        }
+
+        let old_value = list[index].clone();
+        let new_values = match (old_value.parent(), old_value.node()) {
+            (Some(mut parent), Some(old_node)) => {
+                let position = parent
+                    .child_position(old_node)
+                    .unwrap_or_else(|| parent.children_len());
+                let mut copies = Vec::with_capacity(values.len());
+
+                parent.remove_child(mc, old_node)?;
+
+                for (i, value) in values.iter().enumerate() {
+                    let copy = value.deep_copy(mc);
+                    parent.insert_child(mc, position + i, copy)?;
+                    copies.push(E4XNode::Node(copy));
+                }
+
+                copies
+            }
+            _ => values,
+        };
+
+        list.remove(index);
+        for (i, value) in new_values.into_iter().enumerate() {
+            list.insert(index + i, value);
+        }
+
+        return Ok(());
+    }
+
+    match &nodes_of(object).unwrap_or_default()[..] {
+        [] => Ok(()),
+        [target] => target.set_property(mc, multiname, &values, default_namespace.as_ref()),
+        _ => Err(NativeError::new(
+            "TypeError",
+            1089,
+            "Assignment to lists with more than one item is not supported.",
+        )
+        .into()),
+    }
+}
+
+/// Remove the children or attributes of a list of nodes that match a name,
+/// as per the E4X `[[Delete]]` operation.
+pub fn delete_value<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    nodes: &[E4XNode<'gc>],
+    multiname: &Multiname<'gc>,
+) -> Result<(), Error> {
+    let default_namespace = activation.default_xml_namespace();
+
+    for node in nodes {
+        node.delete_property(
+            activation.context.gc_context,
+            multiname,
+            default_namespace.as_ref(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+//! AS3 exceptions
+
+use crate::avm2::activation::Activation;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::string::AvmString;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use std::error::Error as StdError;
+use std::fmt;
+
+/// An error raised by throwing a value from ActionScript.
+///
+/// The thrown value itself is garbage-collected, so it is held by the
+/// interpreter until a `catch` block claims it. This only records what the
+/// value looked like, for logging uncaught exceptions.
+#[derive(Debug)]
+pub struct Exception {
+    description: String,
+}
+
+impl fmt::Display for Exception {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.description)
+    }
+}
+
+impl StdError for Exception {}
+
+/// Throw a value, as if by the `throw` statement.
+///
+/// The returned error unwinds the call stack until a bytecode method with a
+/// matching exception handler is found.
+pub fn throw<'gc>(activation: &mut Activation<'_, 'gc, '_>, value: Value<'gc>) -> Error {
+    let description = match value.clone().coerce_to_string(activation) {
+        Ok(description) => description.to_string(),
+        Err(e) => return e,
+    };
+
+    activation.context.avm2.set_thrown(value);
+
+    Box::new(Exception { description })
+}
+
+/// An error raised by native code that scripts can catch, such as the
+/// `RangeError` thrown by `new Timer(-1)`.
+#[derive(Debug)]
+pub struct NativeError {
+    /// The name of the global error class to throw.
+    class: &'static str,
+
+    /// The Flash Player error code.
+    code: u32,
+
+    /// The error message, without its `Error #` prefix.
+    message: String,
+}
+
+impl NativeError {
+    pub fn new(class: &'static str, code: u32, message: impl Into<String>) -> Self {
+        Self {
+            class,
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for NativeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: Error #{}: {}", self.class, self.code, self.message)
+    }
+}
+
+impl StdError for NativeError {}
+
+/// Turn an error raised by native code into an error object that scripts can
+/// catch.
+///
+/// A `NativeError` becomes an instance of its error class, or an `Error` with
+/// that name if the class isn't implemented. Any other error is internal to
+/// Ruffle and can't be caught, so `None` is returned for it.
+pub fn native_error_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    error: &Error,
+) -> Result<Option<Value<'gc>>, Error> {
+    let error = match error.downcast_ref::<NativeError>() {
+        Some(error) => error,
+        None => return Ok(None),
+    };
+
+    let name: AvmString<'gc> = error.class.into();
+    let message = AvmString::new(
+        activation.context.gc_context,
+        format!("Error #{}: {}", error.code, error.message),
+    );
+    let (proto, is_named_class) = match error_class_proto(activation, name)? {
+        Some(proto) => (proto, true),
+        None => match error_class_proto(activation, "Error".into())? {
+            Some(proto) => (proto, false),
+            None => return Ok(None),
+        },
+    };
+
+    let mut error_object =
+        Object::construct_from_proto(activation, proto, &[message.into(), error.code.into()])?;
+    if !is_named_class {
+        error_object.set_property(
+            error_object,
+            &QName::new(Namespace::public_namespace(), "name"),
+            name.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Some(error_object.into()))
+}
+
+/// Look up the prototype of a global error class by name.
+fn error_class_proto<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    name: AvmString<'gc>,
+) -> Result<Option<Object<'gc>>, Error> {
+    let qname = QName::new(Namespace::public_namespace(), name);
+    let domain = activation.context.avm2.global_domain();
+    let (qname, mut script) = match domain.get_defining_script(&qname.into())? {
+        Some(definition) => definition,
+        None => return Ok(None),
+    };
+
+    let mut globals = script.globals(&mut activation.context)?;
+    let mut class = globals
+        .get_property(globals, &qname, activation)?
+        .coerce_to_object(activation)?;
+    let proto = class
+        .get_property(
+            class,
+            &QName::new(Namespace::public_namespace(), "prototype"),
+            activation,
+        )?
+        .coerce_to_object(activation)?;
+
+    Ok(Some(proto))
+}
@@ -6,8 +6,9 @@ use crate::avm2::domain::Domain;
 use crate::avm2::method::NativeMethod;
 use crate::avm2::names::{Namespace, QName};
 use crate::avm2::object::{
-    implicit_deriver, ArrayObject, DomainObject, FunctionObject, NamespaceObject, Object,
-    PrimitiveObject, ScriptObject, StageObject, TObject,
+    implicit_deriver, ArrayObject, BitmapDataObject, ByteArrayObject, DictionaryObject,
+    DomainObject, FunctionObject, NamespaceObject, Object, PrimitiveObject, ScriptObject,
+    SoundChannelObject, SoundObject, StageObject, TObject, VectorObject, XmlListObject, XmlObject,
 };
 use crate::avm2::scope::Scope;
 use crate::avm2::script::Script;
@@ -20,7 +21,8 @@ use std::f64::NAN;
 mod array;
 mod boolean;
 mod class;
-mod flash;
+mod error;
+pub(super) mod flash;
 mod function;
 mod global_scope;
 mod int;
@@ -28,8 +30,12 @@ mod math;
 mod namespace;
 mod number;
 mod object;
+mod reference_error;
 mod string;
 mod r#uint;
+mod vector;
+mod xml;
+mod xml_list;
 
 const NS_RUFFLE_INTERNAL: &str = "https://ruffle.rs/AS3/impl/";
 
@@ -91,11 +97,40 @@ pub struct SystemPrototypes<'gc> {
     pub uint: Object<'gc>,
     pub namespace: Object<'gc>,
     pub array: Object<'gc>,
+    pub xml: Object<'gc>,
+    pub xml_list: Object<'gc>,
+    pub reference_error: Object<'gc>,
     pub movieclip: Object<'gc>,
     pub framelabel: Object<'gc>,
     pub scene: Object<'gc>,
+    pub graphics: Object<'gc>,
+    pub bitmap: Object<'gc>,
+    pub bitmap_data: Object<'gc>,
     pub application_domain: Object<'gc>,
+    pub loader_info: Object<'gc>,
     pub event: Object<'gc>,
+    pub timer_event: Object<'gc>,
+    pub progress_event: Object<'gc>,
+    pub sample_data_event: Object<'gc>,
+    pub text_event: Object<'gc>,
+    pub http_status_event: Object<'gc>,
+    pub io_error_event: Object<'gc>,
+    pub mouse_event: Object<'gc>,
+    pub keyboard_event: Object<'gc>,
+    pub focus_event: Object<'gc>,
+    pub stage: Object<'gc>,
+    pub url_variables: Object<'gc>,
+    pub bytearray: Object<'gc>,
+    pub point: Object<'gc>,
+    pub rectangle: Object<'gc>,
+    pub matrix: Object<'gc>,
+    pub color_transform: Object<'gc>,
+    pub transform: Object<'gc>,
+    pub textfield: Object<'gc>,
+    pub textformat: Object<'gc>,
+    pub sound: Object<'gc>,
+    pub sound_channel: Object<'gc>,
+    pub sound_transform: Object<'gc>,
 }
 
 impl<'gc> SystemPrototypes<'gc> {
@@ -124,15 +159,57 @@ impl<'gc> SystemPrototypes<'gc> {
             uint: empty,
             namespace: empty,
             array: empty,
+            xml: empty,
+            xml_list: empty,
+            reference_error: empty,
             movieclip: empty,
             framelabel: empty,
             scene: empty,
+            graphics: empty,
+            bitmap: empty,
+            bitmap_data: empty,
             application_domain: empty,
+            loader_info: empty,
             event: empty,
+            timer_event: empty,
+            progress_event: empty,
+            sample_data_event: empty,
+            text_event: empty,
+            http_status_event: empty,
+            io_error_event: empty,
+            mouse_event: empty,
+            keyboard_event: empty,
+            focus_event: empty,
+            stage: empty,
+            url_variables: empty,
+            bytearray: empty,
+            point: empty,
+            rectangle: empty,
+            matrix: empty,
+            color_transform: empty,
+            transform: empty,
+            textfield: empty,
+            textformat: empty,
+            sound: empty,
+            sound_channel: empty,
+            sound_transform: empty,
         }
     }
 }
 
+/// This structure represents the builtin classes that the VM needs to tell
+/// apart from user classes of the same name.
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+pub struct SystemClasses<'gc> {
+    pub object: Object<'gc>,
+    pub string: Object<'gc>,
+    pub boolean: Object<'gc>,
+    pub number: Object<'gc>,
+    pub int: Object<'gc>,
+    pub uint: Object<'gc>,
+}
+
 /// Add a free-function builtin to the global scope.
 fn function<'gc>(
     mc: MutationContext<'gc, '_>,
@@ -246,6 +323,20 @@ where
         .coerce_to_object(activation)
 }
 
+/// Retrieve the class constructor of a builtin prototype.
+fn constructor_of<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut proto: Object<'gc>,
+) -> Result<Object<'gc>, Error> {
+    proto
+        .get_property(
+            proto,
+            &QName::new(Namespace::public_namespace(), "constructor"),
+            activation,
+        )?
+        .coerce_to_object(activation)
+}
+
 fn primitive_deriver<'gc>(
     base_proto: Object<'gc>,
     activation: &mut Activation<'_, 'gc, '_>,
@@ -273,6 +364,78 @@ fn array_deriver<'gc>(
     ArrayObject::derive(base_proto, activation.context.gc_context, class, scope)
 }
 
+fn vector_deriver<'gc>(
+    base_proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    class: GcCell<'gc, Class<'gc>>,
+    scope: Option<GcCell<'gc, Scope<'gc>>>,
+) -> Result<Object<'gc>, Error> {
+    VectorObject::derive(base_proto, activation, class, scope)
+}
+
+fn dictionary_deriver<'gc>(
+    base_proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    class: GcCell<'gc, Class<'gc>>,
+    scope: Option<GcCell<'gc, Scope<'gc>>>,
+) -> Result<Object<'gc>, Error> {
+    DictionaryObject::derive(base_proto, activation.context.gc_context, class, scope)
+}
+
+fn bytearray_deriver<'gc>(
+    base_proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    class: GcCell<'gc, Class<'gc>>,
+    scope: Option<GcCell<'gc, Scope<'gc>>>,
+) -> Result<Object<'gc>, Error> {
+    ByteArrayObject::derive(base_proto, activation.context.gc_context, class, scope)
+}
+
+fn bitmapdata_deriver<'gc>(
+    base_proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    class: GcCell<'gc, Class<'gc>>,
+    scope: Option<GcCell<'gc, Scope<'gc>>>,
+) -> Result<Object<'gc>, Error> {
+    BitmapDataObject::derive(base_proto, activation.context.gc_context, class, scope)
+}
+
+fn sound_deriver<'gc>(
+    base_proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    class: GcCell<'gc, Class<'gc>>,
+    scope: Option<GcCell<'gc, Scope<'gc>>>,
+) -> Result<Object<'gc>, Error> {
+    SoundObject::derive(base_proto, activation.context.gc_context, class, scope)
+}
+
+fn soundchannel_deriver<'gc>(
+    base_proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    class: GcCell<'gc, Class<'gc>>,
+    scope: Option<GcCell<'gc, Scope<'gc>>>,
+) -> Result<Object<'gc>, Error> {
+    SoundChannelObject::derive(base_proto, activation.context.gc_context, class, scope)
+}
+
+fn xml_deriver<'gc>(
+    base_proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    class: GcCell<'gc, Class<'gc>>,
+    scope: Option<GcCell<'gc, Scope<'gc>>>,
+) -> Result<Object<'gc>, Error> {
+    XmlObject::derive(base_proto, activation.context.gc_context, class, scope)
+}
+
+fn xml_list_deriver<'gc>(
+    base_proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    class: GcCell<'gc, Class<'gc>>,
+    scope: Option<GcCell<'gc, Scope<'gc>>>,
+) -> Result<Object<'gc>, Error> {
+    XmlListObject::derive(base_proto, activation.context.gc_context, class, scope)
+}
+
 fn stage_deriver<'gc>(
     base_proto: Object<'gc>,
     activation: &mut Activation<'_, 'gc, '_>,
@@ -424,7 +587,16 @@ pub fn load_player_globals<'gc>(
     // globals scope *before* the `Object` class
     gs.set_proto(mc, sp.global);
 
-    activation.context.avm2.system_prototypes = Some(sp);
+    activation.context.avm2.system_prototypes = Some(sp.clone());
+
+    activation.context.avm2.system_classes = Some(SystemClasses {
+        object: object_constr,
+        string: constructor_of(activation, sp.string)?,
+        boolean: constructor_of(activation, sp.boolean)?,
+        number: constructor_of(activation, sp.number)?,
+        int: constructor_of(activation, sp.int)?,
+        uint: constructor_of(activation, sp.uint)?,
+    });
 
     function(mc, "", "trace", trace, fn_proto, domain, script)?;
     function(mc, "", "isFinite", is_finite, fn_proto, domain, script)?;
@@ -441,6 +613,62 @@ pub fn load_player_globals<'gc>(
         domain,
         script,
     )?;
+    class(
+        activation,
+        error::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .reference_error = class(
+        activation,
+        reference_error::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+
+    // package `__AS3__.vec`
+    class(
+        activation,
+        vector::create_class(mc),
+        vector_deriver,
+        domain,
+        script,
+    )?;
+
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .xml = class(
+        activation,
+        xml::create_class(mc),
+        xml_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .xml_list = class(
+        activation,
+        xml_list::create_class(mc),
+        xml_list_deriver,
+        domain,
+        script,
+    )?;
 
     // package `flash.system`
     activation
@@ -456,6 +684,29 @@ pub fn load_player_globals<'gc>(
         domain,
         script,
     )?;
+    class(
+        activation,
+        flash::system::loadercontext::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::system::system_class::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    function(
+        mc,
+        "flash.system",
+        "fscommand",
+        flash::system::fscommand,
+        fn_proto,
+        domain,
+        script,
+    )?;
 
     // package `flash.events`
     activation
@@ -485,6 +736,130 @@ pub fn load_player_globals<'gc>(
         domain,
         script,
     )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .timer_event = class(
+        activation,
+        flash::events::timerevent::create_class(mc),
+        flash::events::event::event_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .progress_event = class(
+        activation,
+        flash::events::progressevent::create_class(mc),
+        flash::events::event::event_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .sample_data_event = class(
+        activation,
+        flash::events::sampledataevent::create_class(mc),
+        flash::events::event::event_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .http_status_event = class(
+        activation,
+        flash::events::httpstatusevent::create_class(mc),
+        flash::events::event::event_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .text_event = class(
+        activation,
+        flash::events::textevent::create_class(mc),
+        flash::events::event::event_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::events::errorevent::create_class(mc),
+        flash::events::event::event_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .io_error_event = class(
+        activation,
+        flash::events::ioerrorevent::create_class(mc),
+        flash::events::event::event_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .mouse_event = class(
+        activation,
+        flash::events::mouseevent::create_class(mc),
+        flash::events::event::event_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .keyboard_event = class(
+        activation,
+        flash::events::keyboardevent::create_class(mc),
+        flash::events::event::event_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .focus_event = class(
+        activation,
+        flash::events::focusevent::create_class(mc),
+        flash::events::event::event_deriver,
+        domain,
+        script,
+    )?;
 
     // package `flash.display`
     class(
@@ -508,6 +883,13 @@ pub fn load_player_globals<'gc>(
         domain,
         script,
     )?;
+    class(
+        activation,
+        flash::display::shape::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
     class(
         activation,
         flash::display::sprite::create_class(mc),
@@ -521,9 +903,16 @@ pub fn load_player_globals<'gc>(
         .system_prototypes
         .as_mut()
         .unwrap()
-        .movieclip = class(
+        .bitmap = class(
         activation,
-        flash::display::movieclip::create_class(mc),
+        flash::display::bitmap::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::display::loader::create_class(mc),
         implicit_deriver,
         domain,
         script,
@@ -534,9 +923,35 @@ pub fn load_player_globals<'gc>(
         .system_prototypes
         .as_mut()
         .unwrap()
-        .framelabel = class(
+        .loader_info = class(
         activation,
-        flash::display::framelabel::create_class(mc),
+        flash::display::loaderinfo::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .movieclip = class(
+        activation,
+        flash::display::movieclip::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .framelabel = class(
+        activation,
+        flash::display::framelabel::create_class(mc),
         implicit_deriver,
         domain,
         script,
@@ -554,6 +969,396 @@ pub fn load_player_globals<'gc>(
         domain,
         script,
     )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .stage = class(
+        activation,
+        flash::display::stage::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::display::stagealign::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::display::stagedisplaystate::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::display::stagequality::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::display::stagescalemode::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .graphics = class(
+        activation,
+        flash::display::graphics::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .bitmap_data = class(
+        activation,
+        flash::display::bitmapdata::create_class(mc),
+        bitmapdata_deriver,
+        domain,
+        script,
+    )?;
+
+    // package `flash.geom`
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .point = class(
+        activation,
+        flash::geom::point::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .rectangle = class(
+        activation,
+        flash::geom::rectangle::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .matrix = class(
+        activation,
+        flash::geom::matrix::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .color_transform = class(
+        activation,
+        flash::geom::colortransform::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .transform = class(
+        activation,
+        flash::geom::transform::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+
+    // package `flash.media`
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .sound = class(
+        activation,
+        flash::media::sound::create_class(mc),
+        sound_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .sound_channel = class(
+        activation,
+        flash::media::soundchannel::create_class(mc),
+        soundchannel_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .sound_transform = class(
+        activation,
+        flash::media::soundtransform::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::media::soundmixer::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+
+    // package `flash.text`
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .textfield = class(
+        activation,
+        flash::text::textfield::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::text::textfieldautosize::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::text::textfieldtype::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .textformat = class(
+        activation,
+        flash::text::textformat::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::text::textformatalign::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+
+    // package `flash.utils`
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .bytearray = class(
+        activation,
+        flash::utils::bytearray::create_class(mc),
+        bytearray_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::utils::dictionary::create_class(mc),
+        dictionary_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::utils::timer::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::utils::endian::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+
+    function(
+        mc,
+        "flash.utils",
+        "getTimer",
+        flash::utils::get_timer,
+        fn_proto,
+        domain,
+        script,
+    )?;
+    function(
+        mc,
+        "flash.utils",
+        "setTimeout",
+        flash::utils::set_timeout,
+        fn_proto,
+        domain,
+        script,
+    )?;
+    function(
+        mc,
+        "flash.utils",
+        "setInterval",
+        flash::utils::set_interval,
+        fn_proto,
+        domain,
+        script,
+    )?;
+    function(
+        mc,
+        "flash.utils",
+        "clearTimeout",
+        flash::utils::clear_timer,
+        fn_proto,
+        domain,
+        script,
+    )?;
+    function(
+        mc,
+        "flash.utils",
+        "clearInterval",
+        flash::utils::clear_timer,
+        fn_proto,
+        domain,
+        script,
+    )?;
+
+    // package `flash.net`
+    class(
+        activation,
+        flash::net::urlrequest::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::net::urlrequestheader::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::net::urlrequestmethod::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    activation
+        .context
+        .avm2
+        .system_prototypes
+        .as_mut()
+        .unwrap()
+        .url_variables = class(
+        activation,
+        flash::net::urlvariables::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::net::urlloaderdataformat::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+    class(
+        activation,
+        flash::net::urlloader::create_class(mc),
+        implicit_deriver,
+        domain,
+        script,
+    )?;
+
+    function(
+        mc,
+        "flash.net",
+        "navigateToURL",
+        flash::net::navigate_to_url,
+        fn_proto,
+        domain,
+        script,
+    )?;
+    function(
+        mc,
+        "flash.net",
+        "sendToURL",
+        flash::net::send_to_url,
+        fn_proto,
+        domain,
+        script,
+    )?;
+
+    // The stage exists for the lifetime of the player, so it can only be
+    // created once its class and all of its superclasses are available.
+    let stage_proto = activation.context.avm2.prototypes().stage;
+    activation.context.avm2.stage = Some(flash::display::stage::create_stage(
+        activation,
+        stage_proto,
+    )?);
 
     Ok(())
 }
@@ -246,7 +246,7 @@ pub fn value_of<'gc>(
 /// mutate the array under iteration. Normally, holding an `Iterator` on the
 /// array while this happens would cause a panic; this code exists to prevent
 /// that.
-struct ArrayIter<'gc> {
+pub struct ArrayIter<'gc> {
     array_object: Object<'gc>,
     index: u32,
     length: u32,
@@ -277,7 +277,7 @@ impl<'gc> ArrayIter<'gc> {
     ///
     /// Since this isn't a real iterator, this comes pre-enumerated; it yields
     /// a pair of the index and then the value.
-    fn next(
+    pub fn next(
         &mut self,
         activation: &mut Activation<'_, 'gc, '_>,
     ) -> Option<Result<(u32, Value<'gc>), Error>> {
@@ -779,7 +779,7 @@ pub fn splice<'gc>(
 ///
 /// These are provided as a number by the VM and converted into an enumset.
 #[derive(EnumSetType)]
-enum SortOptions {
+pub enum SortOptions {
     /// Request case-insensitive string value sort.
     CaseInsensitive,
 
@@ -798,7 +798,7 @@ enum SortOptions {
 
 /// Identity closure shim which exists purely to decorate closure types with
 /// the HRTB necessary to accept an activation.
-fn constrain<'a, 'gc, 'ctxt, F>(f: F) -> F
+pub fn constrain<'a, 'gc, 'ctxt, F>(f: F) -> F
 where
     F: FnMut(&mut Activation<'a, 'gc, 'ctxt>, Value<'gc>, Value<'gc>) -> Result<Ordering, Error>,
 {
@@ -818,7 +818,7 @@ where
 /// this case, you should cancel the in-place sorting operation and return 0 to
 /// the caller. In the event that this function yields a runtime error, the
 /// contents of the `values` array will be sorted in a random order.
-fn sort_inner<'a, 'gc, 'ctxt, C>(
+pub fn sort_inner<'a, 'gc, 'ctxt, C>(
     activation: &mut Activation<'a, 'gc, 'ctxt>,
     values: &mut [(usize, Value<'gc>)],
     options: EnumSet<SortOptions>,
@@ -862,7 +862,7 @@ where
     Ok(!options.contains(SortOptions::UniqueSort) || unique_sort_satisfied)
 }
 
-fn compare_string_case_sensitive<'gc>(
+pub fn compare_string_case_sensitive<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     a: Value<'gc>,
     b: Value<'gc>,
@@ -873,7 +873,7 @@ fn compare_string_case_sensitive<'gc>(
     Ok(string_a.cmp(&string_b))
 }
 
-fn compare_string_case_insensitive<'gc>(
+pub fn compare_string_case_insensitive<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     a: Value<'gc>,
     b: Value<'gc>,
@@ -884,7 +884,7 @@ fn compare_string_case_insensitive<'gc>(
     Ok(string_a.cmp(&string_b))
 }
 
-fn compare_numeric<'gc>(
+pub fn compare_numeric<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     a: Value<'gc>,
     b: Value<'gc>,
@@ -0,0 +1,201 @@
+//! `Error` impl
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::string::AvmString;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+const NS_ERROR: &str = "https://ruffle.rs/AS3/impl/Error/";
+
+/// Get the name of one of `Error`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_ERROR), name)
+}
+
+/// Implements `Error`'s instance constructor.
+///
+/// The call stack is captured here, so that `getStackTrace` reports where
+/// the error was constructed rather than where it was thrown.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let message = match args.get(0).cloned().unwrap_or(Value::Undefined) {
+            Value::Undefined => "".into(),
+            message => message.coerce_to_string(activation)?,
+        };
+        let error_id = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Number(0.0))
+            .coerce_to_i32(activation)?;
+        let stack_trace = AvmString::new(
+            activation.context.gc_context,
+            activation.context.avm2.call_stack().format(),
+        );
+
+        this.set_property(
+            this,
+            &QName::new(Namespace::public_namespace(), "message"),
+            message.into(),
+            activation,
+        )?;
+        this.init_property(this, &internal("errorID"), error_id.into(), activation)?;
+        this.init_property(
+            this,
+            &internal("stackTrace"),
+            stack_trace.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Error`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Format an error as `name: message`, or just `name` if it has no message.
+fn describe<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut this: Object<'gc>,
+) -> Result<String, Error> {
+    let name = this
+        .get_property(
+            this,
+            &QName::new(Namespace::public_namespace(), "name"),
+            activation,
+        )?
+        .coerce_to_string(activation)?;
+    let message = this
+        .get_property(
+            this,
+            &QName::new(Namespace::public_namespace(), "message"),
+            activation,
+        )?
+        .coerce_to_string(activation)?;
+
+    if message.is_empty() {
+        Ok(name.to_string())
+    } else {
+        Ok(format!("{}: {}", name, message))
+    }
+}
+
+/// Implements `Error.errorID`'s getter
+pub fn error_id<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("errorID"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Error.getStackTrace`
+///
+/// The trace is formatted as Flash Player's debugger does, with a line
+/// naming the error followed by a line for every method on the call stack.
+pub fn get_stack_trace<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let description = describe(activation, this)?;
+        let stack_trace = this
+            .get_property(this, &internal("stackTrace"), activation)?
+            .coerce_to_string(activation)?;
+
+        return Ok(AvmString::new(
+            activation.context.gc_context,
+            format!("{}{}", description, stack_trace),
+        )
+        .into());
+    }
+
+    Ok(Value::Null)
+}
+
+/// Implements `Error.toString`
+pub fn to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let description = describe(activation, this)?;
+
+        return Ok(AvmString::new(activation.context.gc_context, description).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `Error`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::public_namespace(), "Error"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_slot(
+        QName::new(Namespace::public_namespace(), "message"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("".into()),
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        QName::new(Namespace::public_namespace(), "name"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("Error".into()),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "errorID"),
+        Method::from_builtin(error_id),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "getStackTrace"),
+        Method::from_builtin(get_stack_trace),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "toString"),
+        Method::from_builtin(to_string),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("errorID"),
+        QName::new(Namespace::public_namespace(), "int").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("stackTrace"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        None,
+    ));
+
+    class
+}
@@ -0,0 +1,267 @@
+//! `flash.display.Bitmap` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::globals::flash::display::bitmapdata::{bitmap_data_from_symbol, bitmap_symbol};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::display_object::{Bitmap, TDisplayObject};
+use gc_arena::{GcCell, MutationContext};
+
+const NS_BITMAP: &str = "https://ruffle.rs/AS3/impl/Bitmap/";
+
+/// Get the name of one of `Bitmap`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_BITMAP), name)
+}
+
+/// Implements `flash.display.Bitmap`'s instance constructor.
+///
+/// Classes associated with an embedded bitmap display it when constructed
+/// without any bitmap data.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let mut bitmap_data = match args.get(0).cloned().unwrap_or(Value::Null) {
+            Value::Null | Value::Undefined => None,
+            value => Some(value.coerce_to_object(activation)?),
+        };
+
+        if bitmap_data.is_none() {
+            if let Some(symbol) = bitmap_symbol(activation, this)? {
+                let proto = activation.context.avm2.prototypes().bitmap_data;
+                let new_bitmap_data = proto.construct(activation, &[])?;
+                let pixels = bitmap_data_from_symbol(activation, symbol);
+
+                new_bitmap_data.init_bitmap_data(activation.context.gc_context, pixels);
+                bitmap_data = Some(new_bitmap_data);
+            }
+        }
+
+        let pixel_snapping = args
+            .get(1)
+            .cloned()
+            .unwrap_or_else(|| "auto".into())
+            .coerce_to_string(activation)?;
+        let smoothing = args
+            .get(2)
+            .cloned()
+            .unwrap_or_else(|| false.into())
+            .coerce_to_boolean();
+
+        if this.as_display_object().is_none() {
+            let new_do = Bitmap::new_with_avm2(
+                &mut activation.context,
+                this,
+                bitmap_data.and_then(|bd| bd.as_bitmap_data()),
+                smoothing,
+            );
+
+            this.init_display_object(activation.context.gc_context, new_do.into());
+        }
+
+        this.init_property(
+            this,
+            &internal("bitmap_data"),
+            bitmap_data.map(Value::from).unwrap_or(Value::Null),
+            activation,
+        )?;
+        this.init_property(
+            this,
+            &internal("pixel_snapping"),
+            pixel_snapping.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.display.Bitmap`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `Bitmap.bitmapData`'s getter.
+pub fn bitmap_data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("bitmap_data"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Bitmap.bitmapData`'s setter.
+pub fn set_bitmap_data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let bitmap_data = match args.get(0).cloned().unwrap_or(Value::Null) {
+            Value::Null | Value::Undefined => None,
+            value => Some(value.coerce_to_object(activation)?),
+        };
+
+        if let Some(bitmap) = this.as_display_object().and_then(|this| this.as_bitmap()) {
+            bitmap.set_bitmap_data(
+                &mut activation.context,
+                bitmap_data.and_then(|bd| bd.as_bitmap_data()),
+            );
+        }
+
+        this.set_property(
+            this,
+            &internal("bitmap_data"),
+            bitmap_data.map(Value::from).unwrap_or(Value::Null),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Bitmap.pixelSnapping`'s getter.
+pub fn pixel_snapping<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("pixel_snapping"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Bitmap.pixelSnapping`'s setter.
+///
+/// The value is stored, but pixel snapping is not yet implemented.
+pub fn set_pixel_snapping<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let pixel_snapping = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        this.set_property(
+            this,
+            &internal("pixel_snapping"),
+            pixel_snapping.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Bitmap.smoothing`'s getter.
+pub fn smoothing<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|this| this.as_bitmap())
+    {
+        return Ok(bitmap.smoothing().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Bitmap.smoothing`'s setter.
+pub fn set_smoothing<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|this| this.as_bitmap())
+    {
+        let smoothing = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        bitmap.set_smoothing(activation.context.gc_context, smoothing);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `Bitmap`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.display"), "Bitmap"),
+        Some(QName::new(Namespace::package("flash.display"), "DisplayObject").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "bitmapData"),
+        Method::from_builtin(bitmap_data),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "bitmapData"),
+        Method::from_builtin(set_bitmap_data),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "pixelSnapping"),
+        Method::from_builtin(pixel_snapping),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "pixelSnapping"),
+        Method::from_builtin(set_pixel_snapping),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "smoothing"),
+        Method::from_builtin(smoothing),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "smoothing"),
+        Method::from_builtin(set_smoothing),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("bitmap_data"),
+        QName::new(Namespace::package("flash.display"), "BitmapData").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("pixel_snapping"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        None,
+    ));
+
+    class
+}
@@ -0,0 +1,862 @@
+//! `flash.display.BitmapData` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::bytearray::ByteArrayStorage;
+use crate::avm2::class::Class;
+use crate::avm2::exception::NativeError;
+use crate::avm2::globals::flash::geom::colortransform::object_to_color_transform;
+use crate::avm2::globals::flash::geom::matrix::object_to_matrix;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{ByteArrayObject, Object, TObject, VectorObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::vector::VectorStorage;
+use crate::avm2::Error;
+use crate::backend::render::{Bitmap as RenderBitmap, BitmapFormat};
+use crate::bitmap::bitmap_data::{blend_mode_from_name, render_display_object, BitmapData, Color};
+use crate::character::Character;
+use crate::display_object::Bitmap;
+use crate::transform::Transform;
+use gc_arena::{GcCell, MutationContext};
+use swf::BlendMode;
+
+/// The largest width or height a `BitmapData` may have.
+const MAX_DIMENSION: i32 = 8191;
+
+/// The largest number of pixels a `BitmapData` may have.
+const MAX_PIXELS: i32 = 16_777_215;
+
+/// Find the library bitmap that the class of a newly-constructed object was
+/// associated with by a `SymbolClass` tag, if any.
+///
+/// Subclasses of an embedded bitmap's class share its symbol.
+pub fn bitmap_symbol<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+) -> Result<Option<Bitmap<'gc>>, Error> {
+    let mut proto = this.proto();
+
+    while let Some(mut p) = proto {
+        if let Value::Object(constr) = p.get_property(
+            p,
+            &QName::new(Namespace::public_namespace(), "constructor"),
+            activation,
+        )? {
+            if let Some(Character::Bitmap(bitmap)) = activation
+                .context
+                .library
+                .avm2_class_registry()
+                .class_symbol(constr)
+            {
+                return Ok(Some(*bitmap));
+            }
+        }
+
+        proto = p.proto();
+    }
+
+    Ok(None)
+}
+
+/// Copy the pixels of a library bitmap into new bitmap data.
+pub fn bitmap_data_from_symbol<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    symbol: Bitmap<'gc>,
+) -> GcCell<'gc, BitmapData> {
+    let pixels = symbol
+        .bitmap_handle()
+        .and_then(|handle| activation.context.renderer.get_bitmap_pixels(handle));
+
+    match pixels {
+        Some(pixels) => bitmap_data_from_pixels(activation, pixels),
+        None => GcCell::allocate(activation.context.gc_context, BitmapData::default()),
+    }
+}
+
+/// Copy decoded pixels, such as those of a loaded image, into new bitmap data.
+pub fn bitmap_data_from_pixels<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    pixels: RenderBitmap,
+) -> GcCell<'gc, BitmapData> {
+    let bitmap_data = GcCell::allocate(activation.context.gc_context, BitmapData::default());
+    let transparency = matches!(pixels.data, BitmapFormat::Rgba(_));
+    let colors: Vec<i32> = pixels.data.into();
+    let mut write = bitmap_data.write(activation.context.gc_context);
+
+    write.init_pixels(pixels.width, pixels.height, 0, transparency);
+    write.set_pixels(colors.into_iter().map(|p| p.into()).collect());
+    drop(write);
+
+    bitmap_data
+}
+
+/// Implements `flash.display.BitmapData`'s instance constructor.
+///
+/// Classes associated with an embedded bitmap take their size and content
+/// from it, ignoring the arguments.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        let bitmap_data = if let Some(symbol) = bitmap_symbol(activation, this)? {
+            bitmap_data_from_symbol(activation, symbol)
+        } else {
+            let width = args
+                .get(0)
+                .cloned()
+                .unwrap_or(Value::Undefined)
+                .coerce_to_i32(activation)?;
+            let height = args
+                .get(1)
+                .cloned()
+                .unwrap_or(Value::Undefined)
+                .coerce_to_i32(activation)?;
+            let transparency = args
+                .get(2)
+                .cloned()
+                .unwrap_or_else(|| true.into())
+                .coerce_to_boolean();
+            let fill_color = args
+                .get(3)
+                .cloned()
+                .unwrap_or_else(|| 0xFFFFFFFFu32.into())
+                .coerce_to_u32(activation)?;
+
+            if width <= 0
+                || height <= 0
+                || width > MAX_DIMENSION
+                || height > MAX_DIMENSION
+                || width * height > MAX_PIXELS
+            {
+                return Err(NativeError::new("ArgumentError", 2015, "Invalid BitmapData.").into());
+            }
+
+            let bitmap_data =
+                GcCell::allocate(activation.context.gc_context, BitmapData::default());
+            bitmap_data
+                .write(activation.context.gc_context)
+                .init_pixels(width as u32, height as u32, fill_color as i32, transparency);
+
+            bitmap_data
+        };
+
+        this.init_bitmap_data(activation.context.gc_context, bitmap_data);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.display.BitmapData`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Get the pixel data of a `BitmapData` that has not been disposed.
+///
+/// Bitmap data cannot be constructed with no pixels, so an empty bitmap is
+/// one that has been disposed.
+pub fn checked_bitmap_data<'gc>(this: Object<'gc>) -> Result<GcCell<'gc, BitmapData>, Error> {
+    match this.as_bitmap_data() {
+        Some(bitmap_data) if bitmap_data.read().width() > 0 => Ok(bitmap_data),
+        _ => Err(NativeError::new("ArgumentError", 2015, "Invalid BitmapData.").into()),
+    }
+}
+
+/// Read the integer value of a named property of an argument object.
+fn int_property<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut object: Object<'gc>,
+    name: &'static str,
+) -> Result<i32, Error> {
+    object
+        .get_property(
+            object,
+            &QName::new(Namespace::public_namespace(), name),
+            activation,
+        )?
+        .coerce_to_i32(activation)
+}
+
+/// Read a `Rectangle` argument as its position and size.
+fn rect_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    index: usize,
+) -> Result<(i32, i32, i32, i32), Error> {
+    let rect = args
+        .get(index)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_object(activation)?;
+
+    Ok((
+        int_property(activation, rect, "x")?,
+        int_property(activation, rect, "y")?,
+        int_property(activation, rect, "width")?,
+        int_property(activation, rect, "height")?,
+    ))
+}
+
+/// Read a `Point` argument as its position.
+fn point_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    index: usize,
+) -> Result<(i32, i32), Error> {
+    let point = args
+        .get(index)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_object(activation)?;
+
+    Ok((
+        int_property(activation, point, "x")?,
+        int_property(activation, point, "y")?,
+    ))
+}
+
+/// Implements `BitmapData.width`
+pub fn width<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(checked_bitmap_data(this)?.read().width().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.height`
+pub fn height<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(checked_bitmap_data(this)?.read().height().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.transparent`
+pub fn transparent<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(checked_bitmap_data(this)?.read().transparency().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.getPixel`
+pub fn get_pixel<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bitmap_data = checked_bitmap_data(this)?;
+        let (x, y) = (
+            args.get(0)
+                .cloned()
+                .unwrap_or(Value::Undefined)
+                .coerce_to_i32(activation)?,
+            args.get(1)
+                .cloned()
+                .unwrap_or(Value::Undefined)
+                .coerce_to_i32(activation)?,
+        );
+
+        return Ok((bitmap_data.read().get_pixel(x, y) as u32).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.getPixel32`
+pub fn get_pixel32<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bitmap_data = checked_bitmap_data(this)?;
+        let (x, y) = (
+            args.get(0)
+                .cloned()
+                .unwrap_or(Value::Undefined)
+                .coerce_to_i32(activation)?,
+            args.get(1)
+                .cloned()
+                .unwrap_or(Value::Undefined)
+                .coerce_to_i32(activation)?,
+        );
+        let color: u32 = bitmap_data.read().get_pixel32(x, y).into();
+
+        return Ok(color.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.setPixel`
+pub fn set_pixel<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bitmap_data = checked_bitmap_data(this)?;
+        let x = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let y = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let color = args
+            .get(2)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        if bitmap_data.read().is_point_in_bounds(x, y) {
+            bitmap_data.write(activation.context.gc_context).set_pixel(
+                x as u32,
+                y as u32,
+                color.into(),
+            );
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.setPixel32`
+pub fn set_pixel32<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bitmap_data = checked_bitmap_data(this)?;
+        let x = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let y = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let color = args
+            .get(2)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        bitmap_data
+            .write(activation.context.gc_context)
+            .set_pixel32(x, y, color.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.fillRect`
+pub fn fill_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bitmap_data = checked_bitmap_data(this)?;
+        let (x, y, width, height) = rect_arg(activation, args, 0)?;
+        let color = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        let (min_x, min_y) = (x.max(0), y.max(0));
+        let (max_x, max_y) = (x.saturating_add(width), y.saturating_add(height));
+
+        if max_x > min_x && max_y > min_y {
+            bitmap_data.write(activation.context.gc_context).fill_rect(
+                min_x as u32,
+                min_y as u32,
+                (max_x - min_x) as u32,
+                (max_y - min_y) as u32,
+                color.into(),
+            );
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.copyPixels`
+pub fn copy_pixels<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bitmap_data = checked_bitmap_data(this)?;
+        let source = checked_bitmap_data(
+            args.get(0)
+                .cloned()
+                .unwrap_or(Value::Undefined)
+                .coerce_to_object(activation)?,
+        )?;
+        let source_rect = rect_arg(activation, args, 1)?;
+        let dest_point = point_arg(activation, args, 2)?;
+        let alpha = match args.get(3).cloned().unwrap_or(Value::Null) {
+            Value::Null | Value::Undefined => None,
+            alpha_bitmap => {
+                let alpha_bitmap = checked_bitmap_data(alpha_bitmap.coerce_to_object(activation)?)?;
+                let alpha_point = match args.get(4).cloned().unwrap_or(Value::Null) {
+                    Value::Null | Value::Undefined => (0, 0),
+                    _ => point_arg(activation, args, 4)?,
+                };
+                let merge_alpha = args
+                    .get(5)
+                    .cloned()
+                    .unwrap_or_else(|| false.into())
+                    .coerce_to_boolean();
+
+                Some((alpha_bitmap, alpha_point, merge_alpha))
+            }
+        };
+
+        // Bitmaps copied from themselves must be copied out first, as we
+        // can't borrow them while writing to them.
+        let source_copy;
+        let source_ref;
+        let source_bitmap: &BitmapData = if GcCell::ptr_eq(source, bitmap_data) {
+            source_copy = source.read().clone();
+            &source_copy
+        } else {
+            source_ref = source.read();
+            &source_ref
+        };
+
+        let alpha_copy;
+        let alpha_ref;
+        let alpha_source = match &alpha {
+            Some((alpha_bitmap, alpha_point, merge_alpha)) => {
+                let alpha_bitmap: &BitmapData = if GcCell::ptr_eq(*alpha_bitmap, bitmap_data) {
+                    alpha_copy = alpha_bitmap.read().clone();
+                    &alpha_copy
+                } else {
+                    alpha_ref = alpha_bitmap.read();
+                    &alpha_ref
+                };
+
+                Some((alpha_bitmap, *alpha_point, *merge_alpha))
+            }
+            None => None,
+        };
+
+        bitmap_data
+            .write(activation.context.gc_context)
+            .copy_pixels(source_bitmap, source_rect, dest_point, alpha_source);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.draw`
+pub fn draw<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bitmap_data = checked_bitmap_data(this)?;
+        let source = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+
+        let matrix = match args.get(1).cloned().unwrap_or(Value::Null) {
+            Value::Null | Value::Undefined => Default::default(),
+            matrix => {
+                let matrix = matrix.coerce_to_object(activation)?;
+                object_to_matrix(activation, matrix)?
+            }
+        };
+        let color_transform = match args.get(2).cloned().unwrap_or(Value::Null) {
+            Value::Null | Value::Undefined => Default::default(),
+            color_transform => {
+                let color_transform = color_transform.coerce_to_object(activation)?;
+                object_to_color_transform(activation, color_transform)?
+            }
+        };
+        let blend_mode = match args.get(3).cloned().unwrap_or(Value::Null) {
+            Value::Null | Value::Undefined => BlendMode::Normal,
+            blend_mode => blend_mode_from_name(&blend_mode.coerce_to_string(activation)?)
+                .unwrap_or(BlendMode::Normal),
+        };
+        let clip_rect = match args.get(4).cloned().unwrap_or(Value::Null) {
+            Value::Null | Value::Undefined => None,
+            _ => Some(rect_arg(activation, args, 4)?),
+        };
+        let smoothing = args
+            .get(5)
+            .cloned()
+            .unwrap_or_else(|| false.into())
+            .coerce_to_boolean();
+
+        let transform = Transform {
+            matrix,
+            color_transform,
+        };
+
+        if let Some(source) = source.as_bitmap_data() {
+            // Drawing a bitmap onto itself needs a copy of the source.
+            if GcCell::ptr_eq(source, bitmap_data) {
+                let source = source.read().clone();
+                bitmap_data
+                    .write(activation.context.gc_context)
+                    .draw_bitmap_data(&source, &transform, blend_mode, clip_rect, smoothing);
+            } else {
+                bitmap_data
+                    .write(activation.context.gc_context)
+                    .draw_bitmap_data(&source.read(), &transform, blend_mode, clip_rect, smoothing);
+            }
+        } else if let Some(source) = source.as_display_object() {
+            // As in Flash, `smoothing` only applies to BitmapData sources.
+            // Bitmaps inside a display object use their own smoothing.
+            let (width, height) = {
+                let bitmap_data = bitmap_data.read();
+                (bitmap_data.width(), bitmap_data.height())
+            };
+            let layer =
+                render_display_object(&mut activation.context, source, &transform, width, height)
+                    .map_err(|e| format!("Unable to render source of BitmapData.draw: {}", e))?;
+
+            bitmap_data
+                .write(activation.context.gc_context)
+                .composite(&layer, blend_mode, clip_rect);
+        } else {
+            return Err(NativeError::new(
+                "TypeError",
+                1034,
+                "Type Coercion failed: source must be an IBitmapDrawable.",
+            )
+            .into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.lock`
+pub fn lock<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        checked_bitmap_data(this)?
+            .write(activation.context.gc_context)
+            .set_locked(true);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.unlock`
+///
+/// The entire bitmap is always updated, regardless of `changeRect`.
+pub fn unlock<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        checked_bitmap_data(this)?
+            .write(activation.context.gc_context)
+            .set_locked(false);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.getPixels`
+pub fn get_pixels<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bitmap_data = checked_bitmap_data(this)?;
+        let (x, y, width, height) = rect_arg(activation, args, 0)?;
+        let mut storage = ByteArrayStorage::new();
+
+        for color in bitmap_data.read().get_pixels_rect(x, y, width, height) {
+            storage.write_u32(color.into());
+        }
+
+        let proto = activation.context.avm2.prototypes().bytearray;
+
+        return Ok(
+            ByteArrayObject::from_storage(storage, proto, activation.context.gc_context).into(),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.setPixels`
+///
+/// Pixels are read from the byte array's current position, which is left
+/// after the last pixel read.
+pub fn set_pixels<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bitmap_data = checked_bitmap_data(this)?;
+        let (x, y, width, height) = rect_arg(activation, args, 0)?;
+        let input = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let mut bytes = input
+            .as_bytearray_mut(activation.context.gc_context)
+            .ok_or_else(|| {
+                NativeError::new(
+                    "TypeError",
+                    1034,
+                    "Type Coercion failed: expected a ByteArray.",
+                )
+            })?;
+
+        let mut ran_out = false;
+        let colors = std::iter::from_fn(|| match bytes.read_u32() {
+            Ok(color) => Some(Color::from(color as i32)),
+            Err(_) => {
+                ran_out = true;
+                None
+            }
+        });
+
+        bitmap_data
+            .write(activation.context.gc_context)
+            .set_pixels_rect(x, y, width, height, colors);
+
+        if ran_out {
+            return Err(NativeError::new("EOFError", 2030, "End of file was encountered.").into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.getVector`
+pub fn get_vector<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bitmap_data = checked_bitmap_data(this)?;
+        let (x, y, width, height) = rect_arg(activation, args, 0)?;
+        let values = bitmap_data
+            .read()
+            .get_pixels_rect(x, y, width, height)
+            .into_iter()
+            .map(|color| u32::from(color).into())
+            .collect();
+
+        let domain = activation.context.avm2.global_domain();
+        let vector_class = domain
+            .get_defined_value(
+                activation,
+                QName::new(Namespace::package("__AS3__.vec"), "Vector"),
+            )?
+            .coerce_to_object(activation)?;
+        let uint_class = domain
+            .get_defined_value(
+                activation,
+                QName::new(Namespace::public_namespace(), "uint"),
+            )?
+            .coerce_to_object(activation)?;
+        let mut uint_vector_class = vector_class.apply(activation, &[uint_class.into()])?;
+        let proto = uint_vector_class
+            .get_property(
+                uint_vector_class,
+                &QName::new(Namespace::public_namespace(), "prototype"),
+                activation,
+            )?
+            .coerce_to_object(activation)?;
+
+        let vector = VectorStorage::from_values(values, false, Some(uint_class), activation);
+
+        return Ok(VectorObject::from_vector(vector, proto, activation.context.gc_context).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.setVector`
+///
+/// Writing stops early if the vector has fewer values than the rectangle has
+/// pixels.
+pub fn set_vector<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bitmap_data = checked_bitmap_data(this)?;
+        let (x, y, width, height) = rect_arg(activation, args, 0)?;
+        let input = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let values: Vec<Value<'gc>> = input
+            .as_vector_storage()
+            .map(|v| v.iter().collect())
+            .ok_or_else(|| {
+                NativeError::new(
+                    "TypeError",
+                    1034,
+                    "Type Coercion failed: expected a Vector.",
+                )
+            })?;
+
+        let mut colors = Vec::with_capacity(values.len());
+        for value in values {
+            colors.push(Color::from(value.coerce_to_u32(activation)? as i32));
+        }
+
+        bitmap_data
+            .write(activation.context.gc_context)
+            .set_pixels_rect(x, y, width, height, colors);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.dispose`
+pub fn dispose<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|this| this.as_bitmap_data()) {
+        bitmap_data.write(activation.context.gc_context).dispose();
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `BitmapData`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.display"), "BitmapData"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "width"),
+        Method::from_builtin(width),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "height"),
+        Method::from_builtin(height),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "transparent"),
+        Method::from_builtin(transparent),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "getPixel"),
+        Method::from_builtin(get_pixel),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "getPixel32"),
+        Method::from_builtin(get_pixel32),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "setPixel"),
+        Method::from_builtin(set_pixel),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "setPixel32"),
+        Method::from_builtin(set_pixel32),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "fillRect"),
+        Method::from_builtin(fill_rect),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "copyPixels"),
+        Method::from_builtin(copy_pixels),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "draw"),
+        Method::from_builtin(draw),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "lock"),
+        Method::from_builtin(lock),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "unlock"),
+        Method::from_builtin(unlock),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "getPixels"),
+        Method::from_builtin(get_pixels),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "setPixels"),
+        Method::from_builtin(set_pixels),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "getVector"),
+        Method::from_builtin(get_vector),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "setVector"),
+        Method::from_builtin(set_vector),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "dispose"),
+        Method::from_builtin(dispose),
+    ));
+
+    class
+}
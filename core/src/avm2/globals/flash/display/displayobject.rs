@@ -2,6 +2,12 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::Class;
+use crate::avm2::globals::flash::display::stage::stage_of;
+use crate::avm2::globals::flash::geom::colortransform::object_to_color_transform;
+use crate::avm2::globals::flash::geom::matrix::object_to_matrix;
+use crate::avm2::globals::flash::geom::point::{create_point, point_coords};
+use crate::avm2::globals::flash::geom::rectangle::create_rectangle;
+use crate::avm2::globals::flash::geom::transform::create_transform;
 use crate::avm2::method::Method;
 use crate::avm2::names::{Namespace, QName};
 use crate::avm2::object::{Object, TObject};
@@ -9,10 +15,11 @@ use crate::avm2::string::AvmString;
 use crate::avm2::traits::Trait;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
-use crate::display_object::TDisplayObject;
+use crate::bounding_box::BoundingBox;
+use crate::display_object::{DisplayObject, TDisplayObject};
 use crate::types::{Degrees, Percent};
 use gc_arena::{GcCell, MutationContext};
-use swf::Twips;
+use swf::{Matrix, Twips};
 
 /// Implements `flash.display.DisplayObject`'s instance constructor.
 pub fn instance_init<'gc>(
@@ -362,13 +369,22 @@ pub fn parent<'gc>(
 
 /// Implements `root`.
 pub fn root<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        // Objects that aren't attached to a movie's display list have no root.
         return Ok(dobj
             .root()
+            .filter(|root| {
+                root.parent().is_some()
+                    || activation
+                        .context
+                        .levels
+                        .values()
+                        .any(|level| DisplayObject::ptr_eq(*level, *root))
+            })
             .map(|root| root.object2())
             .unwrap_or(Value::Null));
     }
@@ -376,6 +392,21 @@ pub fn root<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `stage`.
+pub fn stage<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        return Ok(stage_of(&activation.context, dobj)
+            .map(Value::from)
+            .unwrap_or(Value::Null));
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Implements `visible`'s getter.
 pub fn visible<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
@@ -494,6 +525,179 @@ pub fn hit_test_object<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `transform`'s getter.
+pub fn transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if this.as_display_object().is_some() {
+            return create_transform(activation, this);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `transform`'s setter.
+///
+/// The matrix and color transform of the given `Transform` are copied onto
+/// this object; the two objects are not linked afterwards.
+pub fn set_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let mut transform = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let matrix = transform
+            .get_property(
+                transform,
+                &QName::new(Namespace::public_namespace(), "matrix"),
+                activation,
+            )?
+            .coerce_to_object(activation)?;
+        let matrix = object_to_matrix(activation, matrix)?;
+        let color_transform = transform
+            .get_property(
+                transform,
+                &QName::new(Namespace::public_namespace(), "colorTransform"),
+                activation,
+            )?
+            .coerce_to_object(activation)?;
+        let color_transform = object_to_color_transform(activation, color_transform)?;
+
+        dobj.set_matrix(activation.context.gc_context, &matrix);
+        dobj.set_color_transform(activation.context.gc_context, &color_transform);
+        dobj.set_transformed_by_script(activation.context.gc_context, true);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `localToGlobal`.
+pub fn local_to_global<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let point = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let (x, y) = point_coords(activation, point)?;
+        let (global_x, global_y) =
+            dobj.local_to_global((Twips::from_pixels(x), Twips::from_pixels(y)));
+
+        return create_point(activation, (global_x.to_pixels(), global_y.to_pixels()));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `globalToLocal`.
+pub fn global_to_local<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let point = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let (x, y) = point_coords(activation, point)?;
+        let (local_x, local_y) =
+            dobj.global_to_local((Twips::from_pixels(x), Twips::from_pixels(y)));
+
+        return create_point(activation, (local_x.to_pixels(), local_y.to_pixels()));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Measure a display object in the coordinate space of the target display
+/// object given in `args`, and return the result as a `Rectangle`.
+///
+/// A null target measures the object in its own coordinate space.
+fn rect_in_target_space<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+    bounds_with_transform: fn(DisplayObject<'gc>, &Matrix) -> BoundingBox,
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let target = match args.get(0).cloned().unwrap_or(Value::Null) {
+            Value::Null | Value::Undefined => dobj,
+            target => target
+                .coerce_to_object(activation)?
+                .as_display_object()
+                .unwrap_or(dobj),
+        };
+
+        let bounds = bounds_with_transform(dobj, &Matrix::default());
+        let out_bounds = if DisplayObject::ptr_eq(dobj, target) {
+            bounds
+        } else {
+            // As in AVM1, the bounds are transformed as a box, which is looser
+            // than transforming the underlying shapes.
+            let bounds_transform = target.global_to_local_matrix() * dobj.local_to_global_matrix();
+            bounds.transform(&bounds_transform)
+        };
+
+        if !out_bounds.valid {
+            return create_rectangle(activation, (0.0, 0.0, 0.0, 0.0));
+        }
+
+        return create_rectangle(
+            activation,
+            (
+                out_bounds.x_min.to_pixels(),
+                out_bounds.y_min.to_pixels(),
+                out_bounds.width().to_pixels(),
+                out_bounds.height().to_pixels(),
+            ),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `getBounds`.
+///
+/// The bounds are given in the coordinate space of the target display object.
+/// A null target measures this object in its own coordinate space.
+pub fn get_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    rect_in_target_space(activation, this, args, |dobj, matrix| {
+        dobj.bounds_with_transform(matrix)
+    })
+}
+
+/// Implements `getRect`.
+///
+/// Like `getBounds`, but leaves out the width of any strokes.
+pub fn get_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    rect_in_target_space(activation, this, args, |dobj, matrix| {
+        dobj.edge_bounds_with_transform(matrix)
+    })
+}
+
 /// Construct `DisplayObject`'s class.
 pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
     let class = Class::new(
@@ -586,6 +790,10 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         QName::new(Namespace::package(""), "root"),
         Method::from_builtin(root),
     ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::package(""), "stage"),
+        Method::from_builtin(stage),
+    ));
     write.define_instance_trait(Trait::from_getter(
         QName::new(Namespace::package(""), "visible"),
         Method::from_builtin(visible),
@@ -610,6 +818,30 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         QName::new(Namespace::package(""), "hitTestObject"),
         Method::from_builtin(hit_test_object),
     ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::package(""), "transform"),
+        Method::from_builtin(transform),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::package(""), "transform"),
+        Method::from_builtin(set_transform),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::package(""), "localToGlobal"),
+        Method::from_builtin(local_to_global),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::package(""), "globalToLocal"),
+        Method::from_builtin(global_to_local),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::package(""), "getBounds"),
+        Method::from_builtin(get_bounds),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::package(""), "getRect"),
+        Method::from_builtin(get_rect),
+    ));
 
     class
 }
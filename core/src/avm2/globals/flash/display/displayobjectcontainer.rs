@@ -1,10 +1,12 @@
 //! `flash.display.DisplayObjectContainer` builtin/prototype
 
 use crate::avm2::activation::Activation;
+use crate::avm2::array::ArrayStorage;
 use crate::avm2::class::Class;
+use crate::avm2::globals::flash::geom::point::point_coords;
 use crate::avm2::method::Method;
 use crate::avm2::names::{Namespace, QName};
-use crate::avm2::object::{Object, TObject};
+use crate::avm2::object::{ArrayObject, Object, TObject};
 use crate::avm2::traits::Trait;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
@@ -13,6 +15,7 @@ use crate::display_object::{DisplayObject, TDisplayObject, TDisplayObjectContain
 use enumset::EnumSet;
 use gc_arena::{GcCell, MutationContext};
 use std::cmp::min;
+use swf::Twips;
 
 /// Implements `flash.display.DisplayObjectContainer`'s instance constructor.
 pub fn instance_init<'gc>(
@@ -270,6 +273,38 @@ pub fn num_children<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `DisplayObjectContainer.mouseChildren`'s getter.
+pub fn mouse_children<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        return Ok(dobj.mouse_children().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `DisplayObjectContainer.mouseChildren`'s setter.
+pub fn set_mouse_children<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let value = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        dobj.set_mouse_children(activation.context.gc_context, value);
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Implements `DisplayObjectContainer.contains`
 pub fn contains<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
@@ -554,13 +589,69 @@ pub fn stop_all_movie_clips<'gc>(
     Ok(Value::Undefined)
 }
 
-/// Stubs `DisplayObjectContainer.getObjectsUnderPoint`
+/// Collect the AVM2 objects of every descendant of a container that lies
+/// under a stage position, from back to front.
+///
+/// Clips are listed if their own drawing is hit; children are tested on
+/// their own.
+fn objects_under_point<'gc>(
+    context: &mut UpdateContext<'_, 'gc, '_>,
+    container: DisplayObject<'gc>,
+    point: (Twips, Twips),
+    objects: &mut Vec<Option<Value<'gc>>>,
+) {
+    let children = match container.as_container() {
+        Some(container) => container.iter_render_list(),
+        None => return,
+    };
+
+    for child in children {
+        let is_hit = match child.as_movie_clip() {
+            Some(clip) => clip.hit_test_drawing(point),
+            None => child.hit_test_shape(context, point),
+        };
+
+        if is_hit {
+            if let Value::Object(object) = child.object2() {
+                objects.push(Some(object.into()));
+            }
+        }
+
+        objects_under_point(context, child, point, objects);
+    }
+}
+
+/// Implements `DisplayObjectContainer.getObjectsUnderPoint`
 pub fn get_objects_under_point<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("DisplayObjectContainer.getObjectsUnderPoint not yet implemented".into())
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let point = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let (x, y) = point_coords(activation, point)?;
+        let mut objects = Vec::new();
+
+        objects_under_point(
+            &mut activation.context,
+            dobj,
+            (Twips::from_pixels(x), Twips::from_pixels(y)),
+            &mut objects,
+        );
+
+        return Ok(ArrayObject::from_array(
+            ArrayStorage::from_storage(objects),
+            activation.context.avm2.prototypes().array,
+            activation.context.gc_context,
+        )
+        .into());
+    }
+
+    Ok(Value::Undefined)
 }
 
 /// Stubs `DisplayObjectContainer.areInaccessibleObjectsUnderPoint`
@@ -599,6 +690,14 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         QName::new(Namespace::public_namespace(), "numChildren"),
         Method::from_builtin(num_children),
     ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "mouseChildren"),
+        Method::from_builtin(mouse_children),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "mouseChildren"),
+        Method::from_builtin(set_mouse_children),
+    ));
     write.define_instance_trait(Trait::from_method(
         QName::new(Namespace::public_namespace(), "addChild"),
         Method::from_builtin(add_child),
@@ -0,0 +1,947 @@
+//! `flash.display.Graphics` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::exception::NativeError;
+use crate::avm2::globals::flash::geom::matrix::object_to_matrix;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::display_object::{DisplayObject, TDisplayObject};
+use crate::drawing::Drawing;
+use crate::shape_utils::DrawCommand;
+use gc_arena::{GcCell, MutationContext};
+use std::f64::consts::PI;
+use swf::{
+    Color, FillStyle, Gradient, GradientInterpolation, GradientRecord, GradientSpread,
+    LineCapStyle, LineJoinStyle, LineStyle, Matrix, Twips,
+};
+
+const NS_GRAPHICS: &str = "https://ruffle.rs/AS3/impl/Graphics/";
+
+/// Get the name of one of `Graphics`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_GRAPHICS), name)
+}
+
+/// Implements `flash.display.Graphics`'s instance constructor.
+///
+/// The display object being drawn on is passed as the only argument; scripts
+/// obtain `Graphics` objects from `Sprite.graphics` or `Shape.graphics`.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let owner = args.get(0).cloned().unwrap_or(Value::Null);
+        this.init_property(this, &internal("owner"), owner, activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.display.Graphics`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Get the `Graphics` object for a display object, creating it if needed.
+///
+/// The object is cached in `slot` on the display object's AVM2 object, so
+/// that repeated accesses return the same `Graphics`.
+pub fn graphics_for<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut owner: Object<'gc>,
+    slot: &QName<'gc>,
+) -> Result<Value<'gc>, Error> {
+    if let Value::Object(graphics) = owner.get_property(owner, slot, activation)? {
+        return Ok(graphics.into());
+    }
+
+    let proto = activation.context.avm2.prototypes().graphics;
+    let graphics = Object::construct_from_proto(activation, proto, &[owner.into()])?;
+
+    owner.set_property(owner, slot, graphics.into(), activation)?;
+
+    Ok(graphics.into())
+}
+
+/// Get the display object that a `Graphics` object draws on.
+fn owner<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+) -> Result<Option<DisplayObject<'gc>>, Error> {
+    if let Some(mut this) = this {
+        if let Value::Object(owner) = this.get_property(this, &internal("owner"), activation)? {
+            return Ok(owner.as_display_object());
+        }
+    }
+
+    Ok(None)
+}
+
+/// Read an `Array` or `Vector` argument into a list of values.
+///
+/// Any other value is treated as an empty list.
+fn list_values<'gc>(value: &Value<'gc>) -> Vec<Value<'gc>> {
+    if let Value::Object(object) = value {
+        if let Some(array) = object.as_array_storage() {
+            return (0..array.length())
+                .map(|i| array.get(i).unwrap_or(Value::Undefined))
+                .collect();
+        }
+
+        if let Some(vector) = object.as_vector_storage() {
+            return (0..vector.length())
+                .map(|i| vector.get(i).unwrap_or(Value::Undefined))
+                .collect();
+        }
+    }
+
+    Vec::new()
+}
+
+/// Convert an AS3 color and alpha into a drawing color.
+///
+/// Alpha values range from 0 to 1.
+fn color_from_args(rgb: u32, alpha: f64) -> Color {
+    Color::from_rgb(rgb, (alpha.max(0.0).min(1.0) * 255.0) as u8)
+}
+
+/// Read a gradient from the arguments to `beginGradientFill` or
+/// `lineGradientStyle`.
+///
+/// Returns `None` if the gradient type is invalid.
+fn gradient_from_args<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+) -> Result<Option<FillStyle>, Error> {
+    let gradient_type = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_string(activation)?;
+    let colors = list_values(args.get(1).unwrap_or(&Value::Undefined));
+    let alphas = list_values(args.get(2).unwrap_or(&Value::Undefined));
+    let ratios = list_values(args.get(3).unwrap_or(&Value::Undefined));
+
+    if colors.len() != alphas.len() || colors.len() != ratios.len() {
+        log::warn!(
+            "Graphics: gradient received different sized arrays for colors, alphas and ratios"
+        );
+        return Ok(None);
+    }
+
+    let mut records = Vec::with_capacity(colors.len());
+    for ((color, alpha), ratio) in colors.iter().zip(alphas.iter()).zip(ratios.iter()) {
+        let rgb = color.coerce_to_u32(activation)?;
+        let alpha = alpha.coerce_to_number(activation)?;
+        let ratio = ratio.coerce_to_number(activation)?.max(0.0).min(255.0);
+
+        records.push(GradientRecord {
+            ratio: ratio as u8,
+            color: color_from_args(rgb, alpha),
+        });
+    }
+
+    // Gradient matrices map the gradient square onto the shape, so they only
+    // need the properties of a `flash.geom.Matrix`.
+    let matrix = match args.get(4) {
+        Some(Value::Object(matrix)) => {
+            let mut matrix = *matrix;
+            let mut get = |name: &'static str| -> Result<f64, Error> {
+                matrix
+                    .get_property(
+                        matrix,
+                        &QName::new(Namespace::public_namespace(), name),
+                        activation,
+                    )?
+                    .coerce_to_number(activation)
+            };
+
+            Matrix {
+                a: get("a")? as f32,
+                b: get("b")? as f32,
+                c: get("c")? as f32,
+                d: get("d")? as f32,
+                tx: Twips::from_pixels(get("tx")?),
+                ty: Twips::from_pixels(get("ty")?),
+            }
+        }
+        _ => Matrix::identity(),
+    };
+
+    let spread = match args.get(5) {
+        Some(Value::Undefined) | Some(Value::Null) | None => GradientSpread::Pad,
+        Some(spread) => match spread.coerce_to_string(activation)?.as_str() {
+            "reflect" => GradientSpread::Reflect,
+            "repeat" => GradientSpread::Repeat,
+            _ => GradientSpread::Pad,
+        },
+    };
+    let interpolation = match args.get(6) {
+        Some(Value::Undefined) | Some(Value::Null) | None => GradientInterpolation::RGB,
+        Some(interpolation) => match interpolation.coerce_to_string(activation)?.as_str() {
+            "linearRGB" => GradientInterpolation::LinearRGB,
+            _ => GradientInterpolation::RGB,
+        },
+    };
+    let focal_point = match args.get(7) {
+        Some(focal_point) => focal_point.coerce_to_number(activation)?,
+        None => 0.0,
+    };
+
+    let gradient = Gradient {
+        matrix,
+        spread,
+        interpolation,
+        records,
+    };
+
+    Ok(match gradient_type.as_str() {
+        "linear" => Some(FillStyle::LinearGradient(gradient)),
+        "radial" if focal_point == 0.0 => Some(FillStyle::RadialGradient(gradient)),
+        "radial" => Some(FillStyle::FocalGradient {
+            gradient,
+            focal_point: focal_point.max(-1.0).min(1.0) as f32,
+        }),
+        other => {
+            log::warn!("Graphics: invalid gradient type {:?}", other);
+            None
+        }
+    })
+}
+
+/// Read a numeric argument, using a default if it was not given.
+fn number_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    index: usize,
+    default: f64,
+) -> Result<f64, Error> {
+    match args.get(index) {
+        Some(value) => value.coerce_to_number(activation),
+        None => Ok(default),
+    }
+}
+
+fn draw_move_to(drawing: &mut Drawing, x: f64, y: f64) {
+    drawing.draw_command(DrawCommand::MoveTo {
+        x: Twips::from_pixels(x),
+        y: Twips::from_pixels(y),
+    });
+}
+
+fn draw_line_to(drawing: &mut Drawing, x: f64, y: f64) {
+    drawing.draw_command(DrawCommand::LineTo {
+        x: Twips::from_pixels(x),
+        y: Twips::from_pixels(y),
+    });
+}
+
+fn draw_curve_to(drawing: &mut Drawing, x1: f64, y1: f64, x2: f64, y2: f64) {
+    drawing.draw_command(DrawCommand::CurveTo {
+        x1: Twips::from_pixels(x1),
+        y1: Twips::from_pixels(y1),
+        x2: Twips::from_pixels(x2),
+        y2: Twips::from_pixels(y2),
+    });
+}
+
+/// Draw a cubic Bezier curve from the current pen position.
+///
+/// The drawing API only has quadratic curves, so the cubic is split into
+/// several parts, each of which is approximated by a quadratic.
+fn draw_cubic_curve_to(
+    drawing: &mut Drawing,
+    control1: (f64, f64),
+    control2: (f64, f64),
+    anchor: (f64, f64),
+) {
+    const SEGMENTS: usize = 4;
+
+    let (x, y) = drawing.cursor();
+    let start = (x.to_pixels(), y.to_pixels());
+    let point_at = |t: f64| {
+        let s = 1.0 - t;
+        let (a, b, c, d) = (s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t);
+        (
+            a * start.0 + b * control1.0 + c * control2.0 + d * anchor.0,
+            a * start.1 + b * control1.1 + c * control2.1 + d * anchor.1,
+        )
+    };
+    let tangent_at = |t: f64| {
+        let s = 1.0 - t;
+        let (a, b, c) = (3.0 * s * s, 6.0 * s * t, 3.0 * t * t);
+        (
+            a * (control1.0 - start.0)
+                + b * (control2.0 - control1.0)
+                + c * (anchor.0 - control2.0),
+            a * (control1.1 - start.1)
+                + b * (control2.1 - control1.1)
+                + c * (anchor.1 - control2.1),
+        )
+    };
+
+    let step = 1.0 / SEGMENTS as f64;
+    for i in 0..SEGMENTS {
+        let t0 = step * i as f64;
+        let t1 = t0 + step;
+        let (p0, p1) = (point_at(t0), point_at(t1));
+        let (d0, d1) = (tangent_at(t0), tangent_at(t1));
+
+        // Control points of this part as a cubic of its own, merged into a
+        // single quadratic control point that matches the cubic's midpoint.
+        let c0 = (p0.0 + d0.0 * step / 3.0, p0.1 + d0.1 * step / 3.0);
+        let c1 = (p1.0 - d1.0 * step / 3.0, p1.1 - d1.1 * step / 3.0);
+        let control = (
+            (3.0 * (c0.0 + c1.0) - p0.0 - p1.0) / 4.0,
+            (3.0 * (c0.1 + c1.1) - p0.1 - p1.1) / 4.0,
+        );
+
+        draw_curve_to(drawing, control.0, control.1, p1.0, p1.1);
+    }
+}
+
+/// Draw an elliptical arc with quadratic curves, from the current pen
+/// position.
+///
+/// Angles are in radians, measured clockwise from the positive X axis. The
+/// pen is expected to already be at the start of the arc.
+fn draw_arc(
+    drawing: &mut Drawing,
+    center: (f64, f64),
+    radius: (f64, f64),
+    start_angle: f64,
+    sweep: f64,
+) {
+    // Each curve covers at most 45 degrees, to keep the approximation tight.
+    let segments = (sweep.abs() / (PI / 4.0)).ceil().max(1.0) as usize;
+    let step = sweep / segments as f64;
+    let control_scale = 1.0 / (step / 2.0).cos();
+
+    for i in 0..segments {
+        let angle = start_angle + step * i as f64;
+        let control_angle = angle + step / 2.0;
+        let end_angle = angle + step;
+
+        draw_curve_to(
+            drawing,
+            center.0 + radius.0 * control_scale * control_angle.cos(),
+            center.1 + radius.1 * control_scale * control_angle.sin(),
+            center.0 + radius.0 * end_angle.cos(),
+            center.1 + radius.1 * end_angle.sin(),
+        );
+    }
+}
+
+/// Draw a closed ellipse given its bounding box.
+fn draw_ellipse_in(drawing: &mut Drawing, x: f64, y: f64, width: f64, height: f64) {
+    let radius = (width / 2.0, height / 2.0);
+    let center = (x + radius.0, y + radius.1);
+
+    draw_move_to(drawing, center.0 + radius.0, center.1);
+    draw_arc(drawing, center, radius, 0.0, 2.0 * PI);
+}
+
+/// Implements `Graphics.beginFill`.
+pub fn begin_fill<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let rgb = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+        let alpha = number_arg(activation, args, 1, 1.0)?;
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            drawing.set_fill_style(Some(FillStyle::Color(color_from_args(rgb, alpha))));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.beginGradientFill`.
+pub fn begin_gradient_fill<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let style = gradient_from_args(activation, args)?;
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            drawing.set_fill_style(style);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.beginBitmapFill`.
+///
+/// The fill shows the bitmap's pixels as of the time of this call.
+pub fn begin_bitmap_fill<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let bitmap_data = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?
+            .as_bitmap_data()
+            .ok_or_else(|| {
+                NativeError::new(
+                    "TypeError",
+                    1034,
+                    "Type Coercion failed: cannot convert to BitmapData",
+                )
+            })?;
+
+        // Bitmap fill matrices are scaled so that one bitmap pixel is one
+        // twip, as they are in SWF shape tags.
+        let matrix = match args.get(1) {
+            Some(Value::Object(matrix)) => object_to_matrix(activation, *matrix)?,
+            _ => Matrix::identity(),
+        };
+        let matrix = Matrix {
+            a: matrix.a * Twips::TWIPS_PER_PIXEL as f32,
+            b: matrix.b * Twips::TWIPS_PER_PIXEL as f32,
+            c: matrix.c * Twips::TWIPS_PER_PIXEL as f32,
+            d: matrix.d * Twips::TWIPS_PER_PIXEL as f32,
+            ..matrix
+        };
+        let is_repeating = match args.get(2) {
+            Some(value) => value.coerce_to_boolean(),
+            None => true,
+        };
+        let is_smoothed = match args.get(3) {
+            Some(value) => value.coerce_to_boolean(),
+            None => false,
+        };
+
+        let handle = {
+            let mut bitmap_data = bitmap_data.write(activation.context.gc_context);
+            let handle = bitmap_data.bitmap_handle(activation.context.renderer);
+            if let (true, Some(handle)) = (bitmap_data.dirty(), handle) {
+                let _ = activation.context.renderer.update_texture(
+                    handle,
+                    bitmap_data.width(),
+                    bitmap_data.height(),
+                    bitmap_data.pixels_rgba(),
+                );
+                bitmap_data.set_dirty(false);
+            }
+
+            handle
+        };
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            let style = handle.map(|handle| FillStyle::Bitmap {
+                id: drawing.add_bitmap(handle),
+                matrix,
+                is_smoothed,
+                is_repeating,
+            });
+
+            drawing.set_fill_style(style);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.lineStyle`.
+pub fn line_style<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let thickness = number_arg(activation, args, 0, f64::NAN)?;
+        if thickness.is_nan() {
+            if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+                drawing.set_line_style(None);
+            }
+
+            return Ok(Value::Undefined);
+        }
+
+        let rgb = args
+            .get(1)
+            .cloned()
+            .unwrap_or_else(|| 0.into())
+            .coerce_to_u32(activation)?;
+        let alpha = number_arg(activation, args, 2, 1.0)?;
+        let is_pixel_hinted = args
+            .get(3)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+        let scale_mode = match args.get(4) {
+            Some(Value::Undefined) | Some(Value::Null) | None => "normal".into(),
+            Some(scale_mode) => scale_mode.coerce_to_string(activation)?,
+        };
+        let (allow_scale_x, allow_scale_y) = match scale_mode.as_str() {
+            "normal" => (true, true),
+            "vertical" => (true, false),
+            "horizontal" => (false, true),
+            _ => (false, false),
+        };
+        let caps = match args.get(5) {
+            Some(Value::Undefined) | Some(Value::Null) | None => LineCapStyle::Round,
+            Some(caps) => match caps.coerce_to_string(activation)?.as_str() {
+                "none" => LineCapStyle::None,
+                "square" => LineCapStyle::Square,
+                _ => LineCapStyle::Round,
+            },
+        };
+        let join_style = match args.get(6) {
+            Some(Value::Undefined) | Some(Value::Null) | None => LineJoinStyle::Round,
+            Some(joints) => match joints.coerce_to_string(activation)?.as_str() {
+                "miter" => {
+                    let limit = number_arg(activation, args, 7, 3.0)?;
+                    LineJoinStyle::Miter(limit.max(1.0).min(255.0) as f32)
+                }
+                "bevel" => LineJoinStyle::Bevel,
+                _ => LineJoinStyle::Round,
+            },
+        };
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            drawing.set_line_style(Some(LineStyle {
+                width: Twips::from_pixels(thickness.max(0.0).min(255.0)),
+                color: color_from_args(rgb, alpha),
+                start_cap: caps,
+                end_cap: caps,
+                join_style,
+                fill_style: None,
+                allow_scale_x,
+                allow_scale_y,
+                is_pixel_hinted,
+                allow_close: false,
+            }));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.lineGradientStyle`.
+///
+/// This only affects lines drawn after a call to `lineStyle`.
+pub fn line_gradient_style<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        if let Some(style) = gradient_from_args(activation, args)? {
+            if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+                drawing.set_line_fill_style(style);
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.moveTo`.
+pub fn move_to<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let x = number_arg(activation, args, 0, 0.0)?;
+        let y = number_arg(activation, args, 1, 0.0)?;
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            draw_move_to(&mut drawing, x, y);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.lineTo`.
+pub fn line_to<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let x = number_arg(activation, args, 0, 0.0)?;
+        let y = number_arg(activation, args, 1, 0.0)?;
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            draw_line_to(&mut drawing, x, y);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.curveTo`.
+pub fn curve_to<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let control_x = number_arg(activation, args, 0, 0.0)?;
+        let control_y = number_arg(activation, args, 1, 0.0)?;
+        let anchor_x = number_arg(activation, args, 2, 0.0)?;
+        let anchor_y = number_arg(activation, args, 3, 0.0)?;
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            draw_curve_to(&mut drawing, control_x, control_y, anchor_x, anchor_y);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.cubicCurveTo`.
+pub fn cubic_curve_to<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let control1_x = number_arg(activation, args, 0, 0.0)?;
+        let control1_y = number_arg(activation, args, 1, 0.0)?;
+        let control2_x = number_arg(activation, args, 2, 0.0)?;
+        let control2_y = number_arg(activation, args, 3, 0.0)?;
+        let anchor_x = number_arg(activation, args, 4, 0.0)?;
+        let anchor_y = number_arg(activation, args, 5, 0.0)?;
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            draw_cubic_curve_to(
+                &mut drawing,
+                (control1_x, control1_y),
+                (control2_x, control2_y),
+                (anchor_x, anchor_y),
+            );
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.drawRect`.
+pub fn draw_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let x = number_arg(activation, args, 0, 0.0)?;
+        let y = number_arg(activation, args, 1, 0.0)?;
+        let width = number_arg(activation, args, 2, 0.0)?;
+        let height = number_arg(activation, args, 3, 0.0)?;
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            draw_move_to(&mut drawing, x, y);
+            draw_line_to(&mut drawing, x + width, y);
+            draw_line_to(&mut drawing, x + width, y + height);
+            draw_line_to(&mut drawing, x, y + height);
+            draw_line_to(&mut drawing, x, y);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.drawRoundRect`.
+pub fn draw_round_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let x = number_arg(activation, args, 0, 0.0)?;
+        let y = number_arg(activation, args, 1, 0.0)?;
+        let width = number_arg(activation, args, 2, 0.0)?;
+        let height = number_arg(activation, args, 3, 0.0)?;
+        let ellipse_width = number_arg(activation, args, 4, 0.0)?;
+        let ellipse_height = number_arg(activation, args, 5, f64::NAN)?;
+        let ellipse_height = if ellipse_height.is_nan() {
+            ellipse_width
+        } else {
+            ellipse_height
+        };
+
+        let rx = (ellipse_width / 2.0).max(0.0).min(width.abs() / 2.0);
+        let ry = (ellipse_height / 2.0).max(0.0).min(height.abs() / 2.0);
+        let right = x + width;
+        let bottom = y + height;
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            draw_move_to(&mut drawing, right, bottom - ry);
+            draw_arc(
+                &mut drawing,
+                (right - rx, bottom - ry),
+                (rx, ry),
+                0.0,
+                PI / 2.0,
+            );
+            draw_line_to(&mut drawing, x + rx, bottom);
+            draw_arc(
+                &mut drawing,
+                (x + rx, bottom - ry),
+                (rx, ry),
+                PI / 2.0,
+                PI / 2.0,
+            );
+            draw_line_to(&mut drawing, x, y + ry);
+            draw_arc(&mut drawing, (x + rx, y + ry), (rx, ry), PI, PI / 2.0);
+            draw_line_to(&mut drawing, right - rx, y);
+            draw_arc(
+                &mut drawing,
+                (right - rx, y + ry),
+                (rx, ry),
+                PI * 1.5,
+                PI / 2.0,
+            );
+            draw_line_to(&mut drawing, right, bottom - ry);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.drawCircle`.
+pub fn draw_circle<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let x = number_arg(activation, args, 0, 0.0)?;
+        let y = number_arg(activation, args, 1, 0.0)?;
+        let radius = number_arg(activation, args, 2, 0.0)?;
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            draw_ellipse_in(
+                &mut drawing,
+                x - radius,
+                y - radius,
+                radius * 2.0,
+                radius * 2.0,
+            );
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.drawEllipse`.
+pub fn draw_ellipse<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let x = number_arg(activation, args, 0, 0.0)?;
+        let y = number_arg(activation, args, 1, 0.0)?;
+        let width = number_arg(activation, args, 2, 0.0)?;
+        let height = number_arg(activation, args, 3, 0.0)?;
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            draw_ellipse_in(&mut drawing, x, y, width, height);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.drawPath`.
+///
+/// The winding rule is ignored; drawings are always filled even-odd.
+pub fn draw_path<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        let commands = list_values(args.get(0).unwrap_or(&Value::Undefined));
+        let data = list_values(args.get(1).unwrap_or(&Value::Undefined));
+
+        let mut coords = Vec::with_capacity(data.len());
+        for value in data.iter() {
+            coords.push(value.coerce_to_number(activation)?);
+        }
+
+        let mut path = Vec::with_capacity(commands.len());
+        for command in commands.iter() {
+            path.push(command.coerce_to_i32(activation)?);
+        }
+
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            let mut coords = coords.iter().copied();
+            for command in path {
+                // Commands stop at the first one without enough data.
+                let needed = match command {
+                    1 | 2 => 2,
+                    3 | 4 | 5 => 4,
+                    6 => 6,
+                    _ => 0,
+                };
+                let points: Vec<f64> = coords.by_ref().take(needed).collect();
+                if points.len() < needed {
+                    break;
+                }
+
+                match command {
+                    1 => draw_move_to(&mut drawing, points[0], points[1]),
+                    2 => draw_line_to(&mut drawing, points[0], points[1]),
+                    3 => draw_curve_to(&mut drawing, points[0], points[1], points[2], points[3]),
+                    4 => draw_move_to(&mut drawing, points[2], points[3]),
+                    5 => draw_line_to(&mut drawing, points[2], points[3]),
+                    6 => draw_cubic_curve_to(
+                        &mut drawing,
+                        (points[0], points[1]),
+                        (points[2], points[3]),
+                        (points[4], points[5]),
+                    ),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.clear`.
+pub fn clear<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            drawing.clear();
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Graphics.endFill`.
+pub fn end_fill<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = owner(activation, this)? {
+        if let Some(mut drawing) = dobj.as_drawing(activation.context.gc_context) {
+            drawing.set_fill_style(None);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `Graphics`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.display"), "Graphics"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::Final | ClassAttributes::Sealed);
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "beginFill"),
+        Method::from_builtin(begin_fill),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "beginGradientFill"),
+        Method::from_builtin(begin_gradient_fill),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "beginBitmapFill"),
+        Method::from_builtin(begin_bitmap_fill),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "lineStyle"),
+        Method::from_builtin(line_style),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "lineGradientStyle"),
+        Method::from_builtin(line_gradient_style),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "moveTo"),
+        Method::from_builtin(move_to),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "lineTo"),
+        Method::from_builtin(line_to),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "curveTo"),
+        Method::from_builtin(curve_to),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "cubicCurveTo"),
+        Method::from_builtin(cubic_curve_to),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "drawRect"),
+        Method::from_builtin(draw_rect),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "drawRoundRect"),
+        Method::from_builtin(draw_round_rect),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "drawCircle"),
+        Method::from_builtin(draw_circle),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "drawEllipse"),
+        Method::from_builtin(draw_ellipse),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "drawPath"),
+        Method::from_builtin(draw_path),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "clear"),
+        Method::from_builtin(clear),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "endFill"),
+        Method::from_builtin(end_fill),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(internal("owner"), Multiname::any(), None));
+
+    class
+}
@@ -0,0 +1,364 @@
+//! `flash.display.Loader` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::domain::Domain;
+use crate::avm2::exception::NativeError;
+use crate::avm2::globals::flash::display::loaderinfo::{
+    init_loader_info, set_loader_info_content, set_loader_info_progress,
+};
+use crate::avm2::globals::flash::net::request_into_fetch_params;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::{Avm2, Error};
+use crate::backend::navigator::{BufferedResponseStream, ResponseStream};
+use crate::display_object::{MovieClip, TDisplayObject, TDisplayObjectContainer};
+use crate::loader::Error as LoaderError;
+use crate::property_map::PropertyMap;
+use crate::tag_utils::{SwfMovie, SwfSlice};
+use enumset::EnumSet;
+use gc_arena::{GcCell, MutationContext};
+use std::sync::Arc;
+
+const NS_LOADER: &str = "https://ruffle.rs/AS3/impl/Loader/";
+
+/// Get the name of one of `Loader`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_LOADER), name)
+}
+
+/// Implements `flash.display.Loader`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        // Loaders hold their content in an empty movie clip, as sprites do.
+        if this.as_display_object().is_none() {
+            let movie = Arc::new(SwfMovie::empty(activation.context.swf.version()));
+            let new_do = MovieClip::new(SwfSlice::empty(movie), activation.context.gc_context);
+
+            new_do.set_object2(activation.context.gc_context, this);
+            this.init_display_object(activation.context.gc_context, new_do.into());
+        }
+
+        let loader_info_proto = activation.context.avm2.prototypes().loader_info;
+        let loader_info = Object::construct_from_proto(activation, loader_info_proto, &[])?;
+
+        init_loader_info(activation, loader_info, this)?;
+        this.init_property(
+            this,
+            &internal("contentLoaderInfo"),
+            loader_info.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.display.Loader`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Get the `LoaderInfo` of a `Loader`.
+fn content_loader_info<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut this: Object<'gc>,
+) -> Result<Object<'gc>, Error> {
+    this.get_property(this, &internal("contentLoaderInfo"), activation)?
+        .coerce_to_object(activation)
+}
+
+/// Determine which application domain a load should place its code in.
+///
+/// A `LoaderContext` may name the domain to load into. Otherwise, the loaded
+/// movie gets a new child domain of the domain of the calling code.
+fn application_domain_for_load<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    context: Value<'gc>,
+) -> Result<Domain<'gc>, Error> {
+    if let Value::Object(mut context) = context {
+        let domain = context
+            .get_property(
+                context,
+                &QName::new(Namespace::public_namespace(), "applicationDomain"),
+                activation,
+            )?
+            .coerce_to_object(activation)
+            .ok()
+            .and_then(|domain| domain.as_application_domain());
+
+        if let Some(domain) = domain {
+            return Ok(domain);
+        }
+    }
+
+    let current_domain = activation
+        .scope()
+        .and_then(|s| s.read().globals().as_application_domain())
+        .unwrap_or_else(|| activation.context.avm2.global_domain());
+
+    Ok(Domain::movie_domain(
+        activation.context.gc_context,
+        current_domain,
+    ))
+}
+
+/// Remove any content from a `Loader` and cancel any load in progress.
+///
+/// Returns `true` if there was content to remove.
+fn unload_content<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+) -> Result<bool, Error> {
+    activation.context.load_manager.cancel_avm2_loader(this);
+
+    let mut had_content = false;
+
+    if let Some(mut ctr) = this
+        .as_display_object()
+        .and_then(|this| this.as_container())
+    {
+        let children: Vec<_> = ctr.iter_execution_list().collect();
+
+        for child in children {
+            child.unload(&mut activation.context);
+            ctr.remove_child(&mut activation.context, child, EnumSet::all());
+            had_content = true;
+        }
+    }
+
+    let loader_info = content_loader_info(activation, this)?;
+
+    set_loader_info_progress(activation, loader_info, 0, 0)?;
+    set_loader_info_content(
+        activation,
+        loader_info,
+        "",
+        Value::Null,
+        None,
+        &PropertyMap::new(),
+    )?;
+
+    Ok(had_content)
+}
+
+/// Implements `Loader.content`'s getter.
+pub fn content<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let mut loader_info = content_loader_info(activation, this)?;
+
+        return loader_info.get_property(
+            loader_info,
+            &QName::new(Namespace::public_namespace(), "content"),
+            activation,
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Loader.contentLoaderInfo`'s getter.
+pub fn content_loader_info_getter<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(content_loader_info(activation, this)?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Loader.load`
+///
+/// Any content already loaded is removed first. The `contentLoaderInfo`'s
+/// events are fired from the `LoadManager` once the fetch completes.
+pub fn load<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let request = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let (url, request_options) = request_into_fetch_params(activation, request)?;
+        let domain =
+            application_domain_for_load(activation, args.get(1).cloned().unwrap_or(Value::Null))?;
+
+        unload_content(activation, this)?;
+
+        let loader_info = content_loader_info(activation, this)?;
+        let fetch = activation
+            .context
+            .navigator
+            .fetch_stream(&url, request_options);
+        let process = activation.context.load_manager.load_movie_into_avm2_loader(
+            activation.context.player.clone().unwrap(),
+            this,
+            loader_info,
+            domain,
+            fetch,
+            url,
+        );
+
+        activation.context.navigator.spawn_future(process);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Loader.loadBytes`
+///
+/// The bytes are loaded as if they had been fetched from the URL of the
+/// calling movie.
+pub fn load_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bytes = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?
+            .as_bytearray()
+            .map(|bytes| bytes.bytes().to_vec())
+            .ok_or_else(|| {
+                NativeError::new("TypeError", 1034, "Type Coercion failed: not a ByteArray")
+            })?;
+        let domain =
+            application_domain_for_load(activation, args.get(1).cloned().unwrap_or(Value::Null))?;
+
+        unload_content(activation, this)?;
+
+        let loader_info = content_loader_info(activation, this)?;
+        let url = activation.context.swf.url().unwrap_or_default().to_string();
+        let fetch = Box::pin(async move {
+            let stream: Box<dyn ResponseStream> = Box::new(BufferedResponseStream::new(bytes));
+            Ok::<_, LoaderError>(stream)
+        });
+        let process = activation.context.load_manager.load_movie_into_avm2_loader(
+            activation.context.player.clone().unwrap(),
+            this,
+            loader_info,
+            domain,
+            fetch,
+            url,
+        );
+
+        activation.context.navigator.spawn_future(process);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Loader.unload`
+pub fn unload<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if unload_content(activation, this)? {
+            let loader_info = content_loader_info(activation, this)?;
+            let event_proto = activation.context.avm2.prototypes().event;
+
+            Avm2::dispatch_event(
+                &mut activation.context,
+                loader_info,
+                event_proto,
+                &["unload".into()],
+            )?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Loader.close`
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.context.load_manager.cancel_avm2_loader(this);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `Loader`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.display"), "Loader"),
+        Some(
+            QName::new(
+                Namespace::package("flash.display"),
+                "DisplayObjectContainer",
+            )
+            .into(),
+        ),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "content"),
+        Method::from_builtin(content),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "contentLoaderInfo"),
+        Method::from_builtin(content_loader_info_getter),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "load"),
+        Method::from_builtin(load),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "loadBytes"),
+        Method::from_builtin(load_bytes),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "unload"),
+        Method::from_builtin(unload),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "close"),
+        Method::from_builtin(close),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("contentLoaderInfo"),
+        QName::new(Namespace::package("flash.display"), "LoaderInfo").into(),
+        None,
+    ));
+
+    class
+}
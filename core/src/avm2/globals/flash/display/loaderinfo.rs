@@ -0,0 +1,293 @@
+//! `flash.display.LoaderInfo` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::domain::Domain;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{DomainObject, Object, ScriptObject, TObject};
+use crate::avm2::string::AvmString;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::property_map::PropertyMap;
+use gc_arena::{GcCell, MutationContext};
+
+const NS_LOADER_INFO: &str = "https://ruffle.rs/AS3/impl/LoaderInfo/";
+
+/// Get the name of one of `LoaderInfo`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_LOADER_INFO), name)
+}
+
+/// Implements `flash.display.LoaderInfo`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let parameters = ScriptObject::object(
+            activation.context.gc_context,
+            activation.context.avm2.prototypes().object,
+        );
+
+        this.init_property(this, &internal("parameters"), parameters.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.display.LoaderInfo`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Associate a `LoaderInfo` with the `Loader` that owns it.
+pub fn init_loader_info<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut loader_info: Object<'gc>,
+    loader: Object<'gc>,
+) -> Result<(), Error> {
+    loader_info.init_property(loader_info, &internal("loader"), loader.into(), activation)
+}
+
+/// Record the progress of a load on a `LoaderInfo`.
+pub fn set_loader_info_progress<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut loader_info: Object<'gc>,
+    bytes_loaded: usize,
+    bytes_total: usize,
+) -> Result<(), Error> {
+    loader_info.set_property(
+        loader_info,
+        &internal("bytesLoaded"),
+        (bytes_loaded as u32).into(),
+        activation,
+    )?;
+    loader_info.set_property(
+        loader_info,
+        &internal("bytesTotal"),
+        (bytes_total as u32).into(),
+        activation,
+    )
+}
+
+/// Record the content of a completed load on a `LoaderInfo`.
+///
+/// `content` should be `null` if the load was cancelled or failed. The
+/// `domain` is the application domain that the loaded movie's code was
+/// placed into.
+pub fn set_loader_info_content<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut loader_info: Object<'gc>,
+    url: &str,
+    content: Value<'gc>,
+    domain: Option<Domain<'gc>>,
+    parameters: &PropertyMap<String>,
+) -> Result<(), Error> {
+    let url = AvmString::new(activation.context.gc_context, url.to_string());
+    let domain = domain
+        .map(|domain| {
+            DomainObject::from_domain(
+                activation.context.gc_context,
+                Some(activation.context.avm2.prototypes().application_domain),
+                domain,
+            )
+            .into()
+        })
+        .unwrap_or(Value::Null);
+    let mut parameters_object = ScriptObject::object(
+        activation.context.gc_context,
+        activation.context.avm2.prototypes().object,
+    );
+
+    for (key, value) in parameters.iter() {
+        parameters_object.set_property(
+            parameters_object,
+            &QName::new(
+                Namespace::public_namespace(),
+                AvmString::new(activation.context.gc_context, key.to_string()),
+            ),
+            AvmString::new(activation.context.gc_context, value.to_string()).into(),
+            activation,
+        )?;
+    }
+
+    loader_info.set_property(loader_info, &internal("url"), url.into(), activation)?;
+    loader_info.set_property(loader_info, &internal("content"), content, activation)?;
+    loader_info.set_property(
+        loader_info,
+        &internal("applicationDomain"),
+        domain,
+        activation,
+    )?;
+    loader_info.set_property(
+        loader_info,
+        &internal("parameters"),
+        parameters_object.into(),
+        activation,
+    )
+}
+
+/// Read one of `LoaderInfo`'s internal slots.
+fn get_internal<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    name: &'static str,
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal(name), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `LoaderInfo.applicationDomain`'s getter.
+pub fn application_domain<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    get_internal(activation, this, "applicationDomain")
+}
+
+/// Implements `LoaderInfo.bytesLoaded`'s getter.
+pub fn bytes_loaded<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    get_internal(activation, this, "bytesLoaded")
+}
+
+/// Implements `LoaderInfo.bytesTotal`'s getter.
+pub fn bytes_total<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    get_internal(activation, this, "bytesTotal")
+}
+
+/// Implements `LoaderInfo.content`'s getter.
+pub fn content<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    get_internal(activation, this, "content")
+}
+
+/// Implements `LoaderInfo.loader`'s getter.
+pub fn loader<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    get_internal(activation, this, "loader")
+}
+
+/// Implements `LoaderInfo.parameters`'s getter.
+pub fn parameters<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    get_internal(activation, this, "parameters")
+}
+
+/// Implements `LoaderInfo.url`'s getter.
+pub fn url<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    get_internal(activation, this, "url")
+}
+
+/// Construct `LoaderInfo`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.display"), "LoaderInfo"),
+        Some(QName::new(Namespace::package("flash.events"), "EventDispatcher").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "applicationDomain"),
+        Method::from_builtin(application_domain),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "bytesLoaded"),
+        Method::from_builtin(bytes_loaded),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "bytesTotal"),
+        Method::from_builtin(bytes_total),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "content"),
+        Method::from_builtin(content),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "loader"),
+        Method::from_builtin(loader),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "parameters"),
+        Method::from_builtin(parameters),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "url"),
+        Method::from_builtin(url),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("applicationDomain"),
+        Multiname::any(),
+        Some(Value::Null),
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("bytesLoaded"),
+        QName::new(Namespace::public_namespace(), "uint").into(),
+        Some(0.into()),
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("bytesTotal"),
+        QName::new(Namespace::public_namespace(), "uint").into(),
+        Some(0.into()),
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("content"),
+        Multiname::any(),
+        Some(Value::Null),
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("loader"),
+        Multiname::any(),
+        Some(Value::Null),
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("parameters"),
+        Multiname::any(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("url"),
+        Multiname::any(),
+        Some(Value::Null),
+    ));
+
+    class
+}
@@ -0,0 +1,399 @@
+//! `flash.display.Stage` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::exception::NativeError;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::string::AvmString;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::config::{StageAlign, StageDisplayState, StageQuality, StageScaleMode};
+use crate::context::UpdateContext;
+use crate::display_object::{DisplayObject, TDisplayObject};
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.display.Stage`'s instance constructor.
+///
+/// The stage is a singleton created by the player; scripts may not construct
+/// another one.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if activation.context.avm2.stage.is_some() {
+        return Err(
+            NativeError::new("ArgumentError", 2012, "Stage class cannot be instantiated.").into(),
+        );
+    }
+
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.display.Stage`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Construct the player's stage object.
+///
+/// This must be called exactly once, after the `Stage` class has been
+/// registered.
+pub fn create_stage<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    proto: Object<'gc>,
+) -> Result<Object<'gc>, Error> {
+    Object::construct_from_proto(activation, proto, &[])
+}
+
+/// Get the stage that a display object is being displayed on, if any.
+///
+/// Only objects on the display list of one of the player's levels are on the
+/// stage. Objects that have not been added to the display list have no stage.
+pub fn stage_of<'gc>(
+    context: &UpdateContext<'_, 'gc, '_>,
+    dobj: DisplayObject<'gc>,
+) -> Option<Object<'gc>> {
+    let mut top = dobj;
+    while let Some(parent) = top.parent() {
+        top = parent;
+    }
+
+    if context
+        .levels
+        .values()
+        .any(|level| DisplayObject::ptr_eq(*level, top))
+    {
+        context.avm2.stage
+    } else {
+        None
+    }
+}
+
+/// Implements `Stage.focus`'s getter
+pub fn focus<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    match activation
+        .context
+        .focus_tracker
+        .get()
+        .map(|focus| focus.object2())
+    {
+        Some(Value::Object(focus)) => Ok(focus.into()),
+        _ => Ok(Value::Null),
+    }
+}
+
+/// Implements `Stage.focus`'s setter
+pub fn set_focus<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let focus = match args.get(0).cloned().unwrap_or(Value::Null) {
+        Value::Undefined | Value::Null => None,
+        focus => focus.coerce_to_object(activation)?.as_display_object(),
+    };
+    let focus_tracker = activation.context.focus_tracker;
+
+    focus_tracker.set(focus, &mut activation.context);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Stage.stageWidth`'s getter
+pub fn stage_width<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if *activation.context.stage_scale_mode == StageScaleMode::NoScale {
+        Ok(activation.context.viewport_size.0.into())
+    } else {
+        Ok(activation.context.stage_size.0.to_pixels().into())
+    }
+}
+
+/// Implements `Stage.stageHeight`'s getter
+pub fn stage_height<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if *activation.context.stage_scale_mode == StageScaleMode::NoScale {
+        Ok(activation.context.viewport_size.1.into())
+    } else {
+        Ok(activation.context.stage_size.1.to_pixels().into())
+    }
+}
+
+/// Implements `Stage.scaleMode`'s getter
+pub fn scale_mode<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let scale_mode = activation.context.stage_scale_mode.to_string();
+
+    Ok(AvmString::new(activation.context.gc_context, scale_mode).into())
+}
+
+/// Implements `Stage.scaleMode`'s setter
+pub fn set_scale_mode<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let scale_mode = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_string(activation)?;
+
+    *activation.context.stage_scale_mode = scale_mode.parse().map_err(|_| {
+        NativeError::new(
+            "ArgumentError",
+            2008,
+            "Parameter scaleMode must be one of the accepted values.",
+        )
+    })?;
+    *activation.context.stage_scale_mode_set_by_script = true;
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Stage.align`'s getter
+pub fn align<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let align = StageAlign::format(*activation.context.stage_align);
+
+    Ok(AvmString::new(activation.context.gc_context, align).into())
+}
+
+/// Implements `Stage.align`'s setter
+pub fn set_align<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let align = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_string(activation)?;
+
+    *activation.context.stage_align = StageAlign::parse(&align);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Stage.frameRate`'s getter
+pub fn frame_rate<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok((*activation.context.frame_rate).into())
+}
+
+/// Implements `Stage.frameRate`'s setter
+pub fn set_frame_rate<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let frame_rate = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_number(activation)?;
+
+    // Flash Player silently clamps the frame rate to its supported range.
+    if !frame_rate.is_nan() {
+        let frame_rate = frame_rate.max(0.01).min(1000.0);
+        *activation.context.frame_rate = frame_rate;
+        activation.context.audio.set_frame_rate(frame_rate);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Stage.quality`'s getter
+pub fn quality<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    // Flash Player reports the quality in upper case, unlike the
+    // `StageQuality` constants.
+    let quality = activation.context.stage_quality.to_string().to_uppercase();
+
+    Ok(AvmString::new(activation.context.gc_context, quality).into())
+}
+
+/// Implements `Stage.quality`'s setter
+pub fn set_quality<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let quality = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_string(activation)?;
+
+    // Unknown qualities are ignored.
+    if let Ok(quality) = quality.parse::<StageQuality>() {
+        *activation.context.stage_quality = quality;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Stage.displayState`'s getter
+pub fn display_state<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let display_state = if activation.context.ui.is_fullscreen() {
+        StageDisplayState::FullScreen
+    } else {
+        StageDisplayState::Normal
+    };
+
+    Ok(AvmString::new(activation.context.gc_context, display_state.to_string()).into())
+}
+
+/// Implements `Stage.displayState`'s setter
+pub fn set_display_state<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let display_state = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_string(activation)?;
+    let display_state: StageDisplayState = display_state.parse().map_err(|_| {
+        NativeError::new(
+            "ArgumentError",
+            2008,
+            "Parameter displayState must be one of the accepted values.",
+        )
+    })?;
+
+    let is_full = display_state != StageDisplayState::Normal;
+    if is_full != activation.context.ui.is_fullscreen() {
+        if let Err(e) = activation.context.ui.set_fullscreen(is_full) {
+            log::warn!("Unable to change display state: {}", e);
+            return Err(NativeError::new(
+                "SecurityError",
+                2152,
+                "Full screen mode is not allowed.",
+            )
+            .into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `Stage`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.display"), "Stage"),
+        Some(
+            QName::new(
+                Namespace::package("flash.display"),
+                "DisplayObjectContainer",
+            )
+            .into(),
+        ),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::Sealed.into());
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "focus"),
+        Method::from_builtin(focus),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "focus"),
+        Method::from_builtin(set_focus),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "stageWidth"),
+        Method::from_builtin(stage_width),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "stageHeight"),
+        Method::from_builtin(stage_height),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "scaleMode"),
+        Method::from_builtin(scale_mode),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "scaleMode"),
+        Method::from_builtin(set_scale_mode),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "align"),
+        Method::from_builtin(align),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "align"),
+        Method::from_builtin(set_align),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "frameRate"),
+        Method::from_builtin(frame_rate),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "frameRate"),
+        Method::from_builtin(set_frame_rate),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "quality"),
+        Method::from_builtin(quality),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "quality"),
+        Method::from_builtin(set_quality),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "displayState"),
+        Method::from_builtin(display_state),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "displayState"),
+        Method::from_builtin(set_display_state),
+    ));
+
+    class
+}
@@ -0,0 +1,87 @@
+//! `flash.display.StageAlign` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::Object;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.display.StageAlign`'s instance constructor.
+pub fn instance_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.display.StageAlign`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Construct `StageAlign`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.display"), "StageAlign"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::Final | ClassAttributes::Sealed);
+
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "BOTTOM"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("B".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "BOTTOM_LEFT"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("BL".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "BOTTOM_RIGHT"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("BR".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "LEFT"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("L".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "RIGHT"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("R".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "TOP"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("T".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "TOP_LEFT"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("TL".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "TOP_RIGHT"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("TR".into()),
+    ));
+
+    class
+}
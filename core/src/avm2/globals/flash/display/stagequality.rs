@@ -0,0 +1,87 @@
+//! `flash.display.StageQuality` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::Object;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.display.StageQuality`'s instance constructor.
+pub fn instance_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.display.StageQuality`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Construct `StageQuality`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.display"), "StageQuality"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::Final | ClassAttributes::Sealed);
+
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "BEST"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("best".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "HIGH"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("high".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "HIGH_16X16"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("16x16".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "HIGH_16X16_LINEAR"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("16x16linear".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "HIGH_8X8"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("8x8".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "HIGH_8X8_LINEAR"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("8x8linear".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "LOW"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("low".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "MEDIUM"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("medium".into()),
+    ));
+
+    class
+}
@@ -1,5 +1,15 @@
 //! `flash.events` namespace
 
+pub mod errorevent;
 pub mod event;
 pub mod eventdispatcher;
+pub mod focusevent;
+pub mod httpstatusevent;
 pub mod ieventdispatcher;
+pub mod ioerrorevent;
+pub mod keyboardevent;
+pub mod mouseevent;
+pub mod progressevent;
+pub mod sampledataevent;
+pub mod textevent;
+pub mod timerevent;
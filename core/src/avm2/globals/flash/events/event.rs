@@ -260,6 +260,20 @@ pub fn to_string<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `updateAfterEvent`
+///
+/// Shared by the event classes that offer it, such as `MouseEvent`,
+/// `KeyboardEvent` and `TimerEvent`.
+pub fn update_after_event<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    *activation.context.needs_render = true;
+
+    Ok(Value::Undefined)
+}
+
 /// Construct `Event`'s class.
 pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
     let class = Class::new(
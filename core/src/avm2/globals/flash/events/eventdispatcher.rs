@@ -3,6 +3,7 @@
 use crate::avm2::activation::Activation;
 use crate::avm2::class::{Class, ClassAttributes};
 use crate::avm2::events::EventPhase;
+use crate::avm2::globals::flash::display::stage::stage_of;
 use crate::avm2::globals::NS_RUFFLE_INTERNAL;
 use crate::avm2::method::Method;
 use crate::avm2::names::{Namespace, QName};
@@ -164,12 +165,19 @@ pub fn has_event_listener<'gc>(
 /// indicate ancestry. Instead, only specific event targets provide a hierarchy
 /// to traverse. If no hierarchy is available, this returns `None`, as if the
 /// target had no parent.
-fn parent_of(target: Object<'_>) -> Option<Object<'_>> {
+///
+/// Display objects at the root of a level are parented to the stage.
+fn parent_of<'gc>(
+    activation: &Activation<'_, 'gc, '_>,
+    target: Object<'gc>,
+) -> Option<Object<'gc>> {
     if let Some(dobj) = target.as_display_object() {
         if let Some(dparent) = dobj.parent() {
             if let Value::Object(parent) = dparent.object2() {
                 return Some(parent);
             }
+        } else {
+            return stage_of(&activation.context, dobj);
         }
     }
 
@@ -214,7 +222,7 @@ pub fn will_trigger<'gc>(
             .ok()
             .unwrap_or(this);
 
-        if let Some(parent) = parent_of(target) {
+        if let Some(parent) = parent_of(activation, target) {
             return will_trigger(activation, Some(parent), args);
         }
     }
@@ -303,10 +311,10 @@ pub fn dispatch_event<'gc>(
             .unwrap_or(this);
 
         let mut ancestor_list = Vec::new();
-        let mut parent = parent_of(target);
+        let mut parent = parent_of(activation, target);
         while let Some(par) = parent {
             ancestor_list.push(par);
-            parent = parent_of(par);
+            parent = parent_of(activation, par);
         }
 
         let mut evtmut = event.as_event_mut(activation.context.gc_context).unwrap();
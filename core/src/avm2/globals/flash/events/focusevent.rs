@@ -0,0 +1,240 @@
+//! `flash.events.FocusEvent` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+const NS_FOCUS_EVENT: &str = "https://ruffle.rs/AS3/impl/FocusEvent/";
+
+/// Get the name of one of `FocusEvent`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_FOCUS_EVENT), name)
+}
+
+/// Implements `flash.events.FocusEvent`'s instance constructor.
+///
+/// Unlike `Event`, a `FocusEvent` bubbles by default.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let event_type = args.get(0).cloned().unwrap_or(Value::Undefined);
+        let bubbles = args.get(1).cloned().unwrap_or(Value::Bool(true));
+        let cancelable = args.get(2).cloned().unwrap_or(Value::Bool(false));
+        activation.super_init(this, &[event_type, bubbles, cancelable])?;
+
+        let related_object = args.get(3).cloned().unwrap_or(Value::Null);
+        let shift_key = args
+            .get(4)
+            .cloned()
+            .unwrap_or(Value::Bool(false))
+            .coerce_to_boolean();
+        let key_code = args
+            .get(5)
+            .cloned()
+            .unwrap_or(Value::Unsigned(0))
+            .coerce_to_u32(activation)?;
+
+        this.init_property(
+            this,
+            &internal("related_object"),
+            related_object,
+            activation,
+        )?;
+        this.init_property(this, &internal("shift_key"), shift_key.into(), activation)?;
+        this.init_property(this, &internal("key_code"), key_code.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.events.FocusEvent`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `FocusEvent.relatedObject`'s getter
+pub fn related_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("related_object"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `FocusEvent.relatedObject`'s setter
+pub fn set_related_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let related_object = args.get(0).cloned().unwrap_or(Value::Null);
+
+        this.set_property(
+            this,
+            &internal("related_object"),
+            related_object,
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `FocusEvent.shiftKey`'s getter
+pub fn shift_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("shift_key"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `FocusEvent.shiftKey`'s setter
+pub fn set_shift_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let shift_key = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_property(this, &internal("shift_key"), shift_key.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `FocusEvent.keyCode`'s getter
+pub fn key_code<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("key_code"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `FocusEvent.keyCode`'s setter
+pub fn set_key_code<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let key_code = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+
+        this.set_property(this, &internal("key_code"), key_code.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `FocusEvent`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.events"), "FocusEvent"),
+        Some(QName::new(Namespace::package("flash.events"), "Event").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "relatedObject"),
+        Method::from_builtin(related_object),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "relatedObject"),
+        Method::from_builtin(set_related_object),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "shiftKey"),
+        Method::from_builtin(shift_key),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "shiftKey"),
+        Method::from_builtin(set_shift_key),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "keyCode"),
+        Method::from_builtin(key_code),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "keyCode"),
+        Method::from_builtin(set_key_code),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("related_object"),
+        QName::new(Namespace::package("flash.display"), "InteractiveObject").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("shift_key"),
+        QName::new(Namespace::public_namespace(), "Boolean").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("key_code"),
+        QName::new(Namespace::public_namespace(), "uint").into(),
+        None,
+    ));
+
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "FOCUS_IN"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("focusIn".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "FOCUS_OUT"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("focusOut".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "KEY_FOCUS_CHANGE"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("keyFocusChange".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "MOUSE_FOCUS_CHANGE"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("mouseFocusChange".into()),
+    ));
+
+    class
+}
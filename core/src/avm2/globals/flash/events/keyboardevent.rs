@@ -0,0 +1,397 @@
+//! `flash.events.KeyboardEvent` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::globals::flash::events::event::update_after_event;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+const NS_KEYBOARD_EVENT: &str = "https://ruffle.rs/AS3/impl/KeyboardEvent/";
+
+/// Get the name of one of `KeyboardEvent`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_KEYBOARD_EVENT), name)
+}
+
+/// Implements `flash.events.KeyboardEvent`'s instance constructor.
+///
+/// Unlike `Event`, a `KeyboardEvent` bubbles by default.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let event_type = args.get(0).cloned().unwrap_or(Value::Undefined);
+        let bubbles = args.get(1).cloned().unwrap_or(Value::Bool(true));
+        let cancelable = args.get(2).cloned().unwrap_or(Value::Bool(false));
+        activation.super_init(this, &[event_type, bubbles, cancelable])?;
+
+        let char_code = args
+            .get(3)
+            .cloned()
+            .unwrap_or(Value::Unsigned(0))
+            .coerce_to_u32(activation)?;
+        let key_code = args
+            .get(4)
+            .cloned()
+            .unwrap_or(Value::Unsigned(0))
+            .coerce_to_u32(activation)?;
+        let key_location = args
+            .get(5)
+            .cloned()
+            .unwrap_or(Value::Unsigned(0))
+            .coerce_to_u32(activation)?;
+        let ctrl_key = args
+            .get(6)
+            .cloned()
+            .unwrap_or(Value::Bool(false))
+            .coerce_to_boolean();
+        let alt_key = args
+            .get(7)
+            .cloned()
+            .unwrap_or(Value::Bool(false))
+            .coerce_to_boolean();
+        let shift_key = args
+            .get(8)
+            .cloned()
+            .unwrap_or(Value::Bool(false))
+            .coerce_to_boolean();
+
+        this.init_property(this, &internal("char_code"), char_code.into(), activation)?;
+        this.init_property(this, &internal("key_code"), key_code.into(), activation)?;
+        this.init_property(
+            this,
+            &internal("key_location"),
+            key_location.into(),
+            activation,
+        )?;
+        this.init_property(this, &internal("ctrl_key"), ctrl_key.into(), activation)?;
+        this.init_property(this, &internal("alt_key"), alt_key.into(), activation)?;
+        this.init_property(this, &internal("shift_key"), shift_key.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.events.KeyboardEvent`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.charCode`'s getter
+pub fn char_code<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("char_code"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.charCode`'s setter
+pub fn set_char_code<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let char_code = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+
+        this.set_property(this, &internal("char_code"), char_code.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.keyCode`'s getter
+pub fn key_code<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("key_code"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.keyCode`'s setter
+pub fn set_key_code<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let key_code = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+
+        this.set_property(this, &internal("key_code"), key_code.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.keyLocation`'s getter
+pub fn key_location<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("key_location"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.keyLocation`'s setter
+pub fn set_key_location<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let key_location = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+
+        this.set_property(
+            this,
+            &internal("key_location"),
+            key_location.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.ctrlKey`'s getter
+pub fn ctrl_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("ctrl_key"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.ctrlKey`'s setter
+pub fn set_ctrl_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let ctrl_key = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_property(this, &internal("ctrl_key"), ctrl_key.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.altKey`'s getter
+pub fn alt_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("alt_key"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.altKey`'s setter
+pub fn set_alt_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let alt_key = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_property(this, &internal("alt_key"), alt_key.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.shiftKey`'s getter
+pub fn shift_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("shift_key"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `KeyboardEvent.shiftKey`'s setter
+pub fn set_shift_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let shift_key = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_property(this, &internal("shift_key"), shift_key.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `KeyboardEvent`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.events"), "KeyboardEvent"),
+        Some(QName::new(Namespace::package("flash.events"), "Event").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "charCode"),
+        Method::from_builtin(char_code),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "charCode"),
+        Method::from_builtin(set_char_code),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "keyCode"),
+        Method::from_builtin(key_code),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "keyCode"),
+        Method::from_builtin(set_key_code),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "keyLocation"),
+        Method::from_builtin(key_location),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "keyLocation"),
+        Method::from_builtin(set_key_location),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "ctrlKey"),
+        Method::from_builtin(ctrl_key),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "ctrlKey"),
+        Method::from_builtin(set_ctrl_key),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "altKey"),
+        Method::from_builtin(alt_key),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "altKey"),
+        Method::from_builtin(set_alt_key),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "shiftKey"),
+        Method::from_builtin(shift_key),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "shiftKey"),
+        Method::from_builtin(set_shift_key),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "updateAfterEvent"),
+        Method::from_builtin(update_after_event),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("char_code"),
+        QName::new(Namespace::public_namespace(), "uint").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("key_code"),
+        QName::new(Namespace::public_namespace(), "uint").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("key_location"),
+        QName::new(Namespace::public_namespace(), "uint").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("ctrl_key"),
+        QName::new(Namespace::public_namespace(), "Boolean").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("alt_key"),
+        QName::new(Namespace::public_namespace(), "Boolean").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("shift_key"),
+        QName::new(Namespace::public_namespace(), "Boolean").into(),
+        None,
+    ));
+
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "KEY_DOWN"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("keyDown".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "KEY_UP"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("keyUp".into()),
+    ));
+
+    class
+}
@@ -0,0 +1,606 @@
+//! `flash.events.MouseEvent` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::globals::flash::events::event::update_after_event;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::display_object::TDisplayObject;
+use gc_arena::{GcCell, MutationContext};
+use swf::Twips;
+
+const NS_MOUSE_EVENT: &str = "https://ruffle.rs/AS3/impl/MouseEvent/";
+
+/// Get the name of one of `MouseEvent`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_MOUSE_EVENT), name)
+}
+
+/// Implements `flash.events.MouseEvent`'s instance constructor.
+///
+/// Unlike `Event`, a `MouseEvent` bubbles by default.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let event_type = args.get(0).cloned().unwrap_or(Value::Undefined);
+        let bubbles = args.get(1).cloned().unwrap_or(Value::Bool(true));
+        let cancelable = args.get(2).cloned().unwrap_or(Value::Bool(false));
+        activation.super_init(this, &[event_type, bubbles, cancelable])?;
+
+        let local_x = args
+            .get(3)
+            .cloned()
+            .unwrap_or(Value::Number(f64::NAN))
+            .coerce_to_number(activation)?;
+        let local_y = args
+            .get(4)
+            .cloned()
+            .unwrap_or(Value::Number(f64::NAN))
+            .coerce_to_number(activation)?;
+        let related_object = args.get(5).cloned().unwrap_or(Value::Null);
+        let ctrl_key = args
+            .get(6)
+            .cloned()
+            .unwrap_or(Value::Bool(false))
+            .coerce_to_boolean();
+        let alt_key = args
+            .get(7)
+            .cloned()
+            .unwrap_or(Value::Bool(false))
+            .coerce_to_boolean();
+        let shift_key = args
+            .get(8)
+            .cloned()
+            .unwrap_or(Value::Bool(false))
+            .coerce_to_boolean();
+        let button_down = args
+            .get(9)
+            .cloned()
+            .unwrap_or(Value::Bool(false))
+            .coerce_to_boolean();
+        let delta = args
+            .get(10)
+            .cloned()
+            .unwrap_or(Value::Integer(0))
+            .coerce_to_i32(activation)?;
+
+        this.init_property(this, &internal("local_x"), local_x.into(), activation)?;
+        this.init_property(this, &internal("local_y"), local_y.into(), activation)?;
+        this.init_property(
+            this,
+            &internal("related_object"),
+            related_object,
+            activation,
+        )?;
+        this.init_property(this, &internal("ctrl_key"), ctrl_key.into(), activation)?;
+        this.init_property(this, &internal("alt_key"), alt_key.into(), activation)?;
+        this.init_property(this, &internal("shift_key"), shift_key.into(), activation)?;
+        this.init_property(
+            this,
+            &internal("button_down"),
+            button_down.into(),
+            activation,
+        )?;
+        this.init_property(this, &internal("delta"), delta.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.events.MouseEvent`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.localX`'s getter
+pub fn local_x<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("local_x"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.localX`'s setter
+pub fn set_local_x<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let local_x = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_number(activation)?;
+
+        this.set_property(this, &internal("local_x"), local_x.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.localY`'s getter
+pub fn local_y<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("local_y"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.localY`'s setter
+pub fn set_local_y<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let local_y = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_number(activation)?;
+
+        this.set_property(this, &internal("local_y"), local_y.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.relatedObject`'s getter
+pub fn related_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("related_object"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.relatedObject`'s setter
+pub fn set_related_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let related_object = args.get(0).cloned().unwrap_or(Value::Null);
+
+        this.set_property(
+            this,
+            &internal("related_object"),
+            related_object,
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.ctrlKey`'s getter
+pub fn ctrl_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("ctrl_key"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.ctrlKey`'s setter
+pub fn set_ctrl_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let ctrl_key = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_property(this, &internal("ctrl_key"), ctrl_key.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.altKey`'s getter
+pub fn alt_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("alt_key"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.altKey`'s setter
+pub fn set_alt_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let alt_key = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_property(this, &internal("alt_key"), alt_key.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.shiftKey`'s getter
+pub fn shift_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("shift_key"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.shiftKey`'s setter
+pub fn set_shift_key<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let shift_key = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_property(this, &internal("shift_key"), shift_key.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.buttonDown`'s getter
+pub fn button_down<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("button_down"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.buttonDown`'s setter
+pub fn set_button_down<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let button_down = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_property(
+            this,
+            &internal("button_down"),
+            button_down.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.delta`'s getter
+pub fn delta<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("delta"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.delta`'s setter
+pub fn set_delta<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let delta = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        this.set_property(this, &internal("delta"), delta.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Convert the event's local position into stage coordinates.
+///
+/// The local position is relative to the event's target. Events without a
+/// display object target are treated as if they were fired on the stage.
+fn stage_position<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut this: Object<'gc>,
+) -> Result<(f64, f64), Error> {
+    let local_x = this
+        .get_property(this, &internal("local_x"), activation)?
+        .coerce_to_number(activation)?;
+    let local_y = this
+        .get_property(this, &internal("local_y"), activation)?
+        .coerce_to_number(activation)?;
+    let target = this
+        .as_event()
+        .and_then(|event| event.target())
+        .and_then(|target| target.as_display_object());
+
+    if let Some(target) = target {
+        let (x, y) =
+            target.local_to_global((Twips::from_pixels(local_x), Twips::from_pixels(local_y)));
+
+        return Ok((x.to_pixels(), y.to_pixels()));
+    }
+
+    Ok((local_x, local_y))
+}
+
+/// Implements `MouseEvent.stageX`'s getter
+pub fn stage_x<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(stage_position(activation, this)?.0.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `MouseEvent.stageY`'s getter
+pub fn stage_y<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(stage_position(activation, this)?.1.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `MouseEvent`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.events"), "MouseEvent"),
+        Some(QName::new(Namespace::package("flash.events"), "Event").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "localX"),
+        Method::from_builtin(local_x),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "localX"),
+        Method::from_builtin(set_local_x),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "localY"),
+        Method::from_builtin(local_y),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "localY"),
+        Method::from_builtin(set_local_y),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "relatedObject"),
+        Method::from_builtin(related_object),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "relatedObject"),
+        Method::from_builtin(set_related_object),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "ctrlKey"),
+        Method::from_builtin(ctrl_key),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "ctrlKey"),
+        Method::from_builtin(set_ctrl_key),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "altKey"),
+        Method::from_builtin(alt_key),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "altKey"),
+        Method::from_builtin(set_alt_key),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "shiftKey"),
+        Method::from_builtin(shift_key),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "shiftKey"),
+        Method::from_builtin(set_shift_key),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "buttonDown"),
+        Method::from_builtin(button_down),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "buttonDown"),
+        Method::from_builtin(set_button_down),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "delta"),
+        Method::from_builtin(delta),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "delta"),
+        Method::from_builtin(set_delta),
+    ));
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "stageX"),
+        Method::from_builtin(stage_x),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "stageY"),
+        Method::from_builtin(stage_y),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "updateAfterEvent"),
+        Method::from_builtin(update_after_event),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("local_x"),
+        QName::new(Namespace::public_namespace(), "Number").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("local_y"),
+        QName::new(Namespace::public_namespace(), "Number").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("related_object"),
+        QName::new(Namespace::package("flash.display"), "InteractiveObject").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("ctrl_key"),
+        QName::new(Namespace::public_namespace(), "Boolean").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("alt_key"),
+        QName::new(Namespace::public_namespace(), "Boolean").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("shift_key"),
+        QName::new(Namespace::public_namespace(), "Boolean").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("button_down"),
+        QName::new(Namespace::public_namespace(), "Boolean").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("delta"),
+        QName::new(Namespace::public_namespace(), "int").into(),
+        None,
+    ));
+
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "CLICK"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("click".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "DOUBLE_CLICK"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("doubleClick".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "MOUSE_DOWN"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("mouseDown".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "MOUSE_MOVE"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("mouseMove".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "MOUSE_OUT"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("mouseOut".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "MOUSE_OVER"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("mouseOver".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "MOUSE_UP"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("mouseUp".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "MOUSE_WHEEL"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("mouseWheel".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "ROLL_OUT"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("rollOut".into()),
+    ));
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "ROLL_OVER"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("rollOver".into()),
+    ));
+
+    class
+}
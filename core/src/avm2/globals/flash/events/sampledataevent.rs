@@ -0,0 +1,159 @@
+//! `flash.events.SampleDataEvent` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+const NS_SAMPLE_DATA_EVENT: &str = "https://ruffle.rs/AS3/impl/SampleDataEvent/";
+
+/// Get the name of one of `SampleDataEvent`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_SAMPLE_DATA_EVENT), name)
+}
+
+/// Implements `flash.events.SampleDataEvent`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, args.get(0..3).unwrap_or(args))?;
+
+        let position = args
+            .get(3)
+            .cloned()
+            .unwrap_or(Value::Number(0.0))
+            .coerce_to_number(activation)?;
+        let data = args.get(4).cloned().unwrap_or(Value::Null);
+
+        this.init_property(this, &internal("position"), position.into(), activation)?;
+        this.init_property(this, &internal("data"), data, activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.events.SampleDataEvent`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `SampleDataEvent.position`'s getter
+pub fn position<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("position"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `SampleDataEvent.position`'s setter
+pub fn set_position<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let position = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_number(activation)?;
+
+        this.set_property(this, &internal("position"), position.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `SampleDataEvent.data`'s getter
+pub fn data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("data"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `SampleDataEvent.data`'s setter
+pub fn set_data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let data = args.get(0).cloned().unwrap_or(Value::Null);
+
+        this.set_property(this, &internal("data"), data, activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `SampleDataEvent`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.events"), "SampleDataEvent"),
+        Some(QName::new(Namespace::package("flash.events"), "Event").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "position"),
+        Method::from_builtin(position),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "position"),
+        Method::from_builtin(set_position),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "data"),
+        Method::from_builtin(data),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "data"),
+        Method::from_builtin(set_data),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("position"),
+        QName::new(Namespace::public_namespace(), "Number").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("data"),
+        QName::new(Namespace::package("flash.utils"), "ByteArray").into(),
+        None,
+    ));
+
+    write.define_class_trait(Trait::from_const(
+        QName::new(Namespace::public_namespace(), "SAMPLE_DATA"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        Some("sampleData".into()),
+    ));
+
+    class
+}
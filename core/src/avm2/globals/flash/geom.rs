@@ -0,0 +1,96 @@
+//! `flash.geom` namespace
+
+use crate::avm2::activation::Activation;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::string::AvmString;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+
+pub mod colortransform;
+pub mod matrix;
+pub mod point;
+pub mod rectangle;
+pub mod transform;
+
+/// Coerce an argument to a number, substituting a default if it was omitted.
+fn number_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    index: usize,
+    default: f64,
+) -> Result<f64, Error> {
+    args.get(index)
+        .cloned()
+        .unwrap_or_else(|| default.into())
+        .coerce_to_number(activation)
+}
+
+/// Coerce an argument to an object, such as another `Point` or `Rectangle`.
+fn object_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    index: usize,
+) -> Result<Object<'gc>, Error> {
+    args.get(index)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_object(activation)
+}
+
+/// Read one of the public numeric fields of a geometry object.
+fn get_number<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut object: Object<'gc>,
+    name: &'static str,
+) -> Result<f64, Error> {
+    object
+        .get_property(
+            object,
+            &QName::new(Namespace::public_namespace(), name),
+            activation,
+        )?
+        .coerce_to_number(activation)
+}
+
+/// Write one of the public numeric fields of a geometry object.
+fn set_number<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut object: Object<'gc>,
+    name: &'static str,
+    value: f64,
+) -> Result<(), Error> {
+    object.set_property(
+        object,
+        &QName::new(Namespace::public_namespace(), name),
+        value.into(),
+        activation,
+    )
+}
+
+/// Format the public numeric fields of a geometry object for `toString`,
+/// e.g. `(x=1, y=2)`.
+fn fields_to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut object: Object<'gc>,
+    fields: &[(&'static str, &'static str)],
+) -> Result<Value<'gc>, Error> {
+    let mut parts = Vec::with_capacity(fields.len());
+    for (label, name) in fields {
+        let value = object
+            .get_property(
+                object,
+                &QName::new(Namespace::public_namespace(), *name),
+                activation,
+            )?
+            .coerce_to_string(activation)?;
+
+        parts.push(format!("{}={}", label, value));
+    }
+
+    Ok(AvmString::new(
+        activation.context.gc_context,
+        format!("({})", parts.join(", ")),
+    )
+    .into())
+}
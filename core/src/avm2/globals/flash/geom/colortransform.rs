@@ -0,0 +1,349 @@
+//! `flash.geom.ColorTransform` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::globals::flash::geom::{
+    fields_to_string, get_number, number_arg, object_arg, set_number,
+};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::Object;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::color_transform::ColorTransform;
+use gc_arena::{GcCell, MutationContext};
+
+/// The components of a `ColorTransform` object.
+///
+/// Offsets are kept in the script-visible range of -255 to 255, rather than
+/// the fractional range used by the player's own `ColorTransform` type.
+#[derive(Clone, Copy, Debug)]
+struct Components {
+    red_multiplier: f64,
+    green_multiplier: f64,
+    blue_multiplier: f64,
+    alpha_multiplier: f64,
+    red_offset: f64,
+    green_offset: f64,
+    blue_offset: f64,
+    alpha_offset: f64,
+}
+
+impl Components {
+    /// Read the components of a `ColorTransform` object.
+    fn read<'gc>(
+        activation: &mut Activation<'_, 'gc, '_>,
+        color_transform: Object<'gc>,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            red_multiplier: get_number(activation, color_transform, "redMultiplier")?,
+            green_multiplier: get_number(activation, color_transform, "greenMultiplier")?,
+            blue_multiplier: get_number(activation, color_transform, "blueMultiplier")?,
+            alpha_multiplier: get_number(activation, color_transform, "alphaMultiplier")?,
+            red_offset: get_number(activation, color_transform, "redOffset")?,
+            green_offset: get_number(activation, color_transform, "greenOffset")?,
+            blue_offset: get_number(activation, color_transform, "blueOffset")?,
+            alpha_offset: get_number(activation, color_transform, "alphaOffset")?,
+        })
+    }
+
+    /// Write these components into a `ColorTransform` object.
+    fn write<'gc>(
+        self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        color_transform: Object<'gc>,
+    ) -> Result<(), Error> {
+        set_number(
+            activation,
+            color_transform,
+            "redMultiplier",
+            self.red_multiplier,
+        )?;
+        set_number(
+            activation,
+            color_transform,
+            "greenMultiplier",
+            self.green_multiplier,
+        )?;
+        set_number(
+            activation,
+            color_transform,
+            "blueMultiplier",
+            self.blue_multiplier,
+        )?;
+        set_number(
+            activation,
+            color_transform,
+            "alphaMultiplier",
+            self.alpha_multiplier,
+        )?;
+        set_number(activation, color_transform, "redOffset", self.red_offset)?;
+        set_number(
+            activation,
+            color_transform,
+            "greenOffset",
+            self.green_offset,
+        )?;
+        set_number(activation, color_transform, "blueOffset", self.blue_offset)?;
+        set_number(
+            activation,
+            color_transform,
+            "alphaOffset",
+            self.alpha_offset,
+        )
+    }
+}
+
+impl From<ColorTransform> for Components {
+    fn from(color_transform: ColorTransform) -> Self {
+        Self {
+            red_multiplier: color_transform.r_mult.into(),
+            green_multiplier: color_transform.g_mult.into(),
+            blue_multiplier: color_transform.b_mult.into(),
+            alpha_multiplier: color_transform.a_mult.into(),
+            red_offset: f64::from(color_transform.r_add) * 255.0,
+            green_offset: f64::from(color_transform.g_add) * 255.0,
+            blue_offset: f64::from(color_transform.b_add) * 255.0,
+            alpha_offset: f64::from(color_transform.a_add) * 255.0,
+        }
+    }
+}
+
+impl From<Components> for ColorTransform {
+    fn from(components: Components) -> Self {
+        Self {
+            r_mult: components.red_multiplier as f32,
+            g_mult: components.green_multiplier as f32,
+            b_mult: components.blue_multiplier as f32,
+            a_mult: components.alpha_multiplier as f32,
+            r_add: (components.red_offset / 255.0) as f32,
+            g_add: (components.green_offset / 255.0) as f32,
+            b_add: (components.blue_offset / 255.0) as f32,
+            a_add: (components.alpha_offset / 255.0) as f32,
+        }
+    }
+}
+
+/// Construct a new `ColorTransform` object from a display object's color
+/// transform.
+pub fn color_transform_to_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    color_transform: ColorTransform,
+) -> Result<Value<'gc>, Error> {
+    let components = Components::from(color_transform);
+    let proto = activation.context.avm2.prototypes().color_transform;
+    let args = [
+        components.red_multiplier.into(),
+        components.green_multiplier.into(),
+        components.blue_multiplier.into(),
+        components.alpha_multiplier.into(),
+        components.red_offset.into(),
+        components.green_offset.into(),
+        components.blue_offset.into(),
+        components.alpha_offset.into(),
+    ];
+
+    Ok(Object::construct_from_proto(activation, proto, &args)?.into())
+}
+
+/// Read a `ColorTransform` object as a display object color transform.
+pub fn object_to_color_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    color_transform: Object<'gc>,
+) -> Result<ColorTransform, Error> {
+    Ok(Components::read(activation, color_transform)?.into())
+}
+
+/// Implements `flash.geom.ColorTransform`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        let components = Components {
+            red_multiplier: number_arg(activation, args, 0, 1.0)?,
+            green_multiplier: number_arg(activation, args, 1, 1.0)?,
+            blue_multiplier: number_arg(activation, args, 2, 1.0)?,
+            alpha_multiplier: number_arg(activation, args, 3, 1.0)?,
+            red_offset: number_arg(activation, args, 4, 0.0)?,
+            green_offset: number_arg(activation, args, 5, 0.0)?,
+            blue_offset: number_arg(activation, args, 6, 0.0)?,
+            alpha_offset: number_arg(activation, args, 7, 0.0)?,
+        };
+
+        components.write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.geom.ColorTransform`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `ColorTransform.color`'s getter.
+///
+/// The color is made up of the red, green and blue offsets.
+pub fn color<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let components = Components::read(activation, this)?;
+        let red = components.red_offset as i32 & 0xFF;
+        let green = components.green_offset as i32 & 0xFF;
+        let blue = components.blue_offset as i32 & 0xFF;
+
+        return Ok((((red << 16) | (green << 8) | blue) as u32).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `ColorTransform.color`'s setter.
+///
+/// Setting a color replaces the red, green and blue channels with that color
+/// entirely, leaving alpha alone.
+pub fn set_color<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let color = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+
+        set_number(activation, this, "redMultiplier", 0.0)?;
+        set_number(activation, this, "greenMultiplier", 0.0)?;
+        set_number(activation, this, "blueMultiplier", 0.0)?;
+        set_number(activation, this, "redOffset", ((color >> 16) & 0xFF).into())?;
+        set_number(
+            activation,
+            this,
+            "greenOffset",
+            ((color >> 8) & 0xFF).into(),
+        )?;
+        set_number(activation, this, "blueOffset", (color & 0xFF).into())?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `ColorTransform.concat`.
+///
+/// The second transform is applied first, followed by this one.
+pub fn concat<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let second = object_arg(activation, args, 0)?;
+        let second = Components::read(activation, second)?;
+        let first = Components::read(activation, this)?;
+
+        let concatenated = Components {
+            red_multiplier: first.red_multiplier * second.red_multiplier,
+            green_multiplier: first.green_multiplier * second.green_multiplier,
+            blue_multiplier: first.blue_multiplier * second.blue_multiplier,
+            alpha_multiplier: first.alpha_multiplier * second.alpha_multiplier,
+            red_offset: first.red_offset + first.red_multiplier * second.red_offset,
+            green_offset: first.green_offset + first.green_multiplier * second.green_offset,
+            blue_offset: first.blue_offset + first.blue_multiplier * second.blue_offset,
+            alpha_offset: first.alpha_offset + first.alpha_multiplier * second.alpha_offset,
+        };
+
+        concatenated.write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `ColorTransform.toString`.
+pub fn to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return fields_to_string(
+            activation,
+            this,
+            &[
+                ("redMultiplier", "redMultiplier"),
+                ("greenMultiplier", "greenMultiplier"),
+                ("blueMultiplier", "blueMultiplier"),
+                ("alphaMultiplier", "alphaMultiplier"),
+                ("redOffset", "redOffset"),
+                ("greenOffset", "greenOffset"),
+                ("blueOffset", "blueOffset"),
+                ("alphaOffset", "alphaOffset"),
+            ],
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `ColorTransform`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.geom"), "ColorTransform"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    for name in &[
+        "redMultiplier",
+        "greenMultiplier",
+        "blueMultiplier",
+        "alphaMultiplier",
+        "redOffset",
+        "greenOffset",
+        "blueOffset",
+        "alphaOffset",
+    ] {
+        write.define_instance_trait(Trait::from_slot(
+            QName::new(Namespace::public_namespace(), *name),
+            QName::new(Namespace::public_namespace(), "Number").into(),
+            None,
+        ));
+    }
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "color"),
+        Method::from_builtin(color),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "color"),
+        Method::from_builtin(set_color),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "concat"),
+        Method::from_builtin(concat),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "toString"),
+        Method::from_builtin(to_string),
+    ));
+
+    class
+}
@@ -0,0 +1,552 @@
+//! `flash.geom.Matrix` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::globals::flash::geom::point::{create_point, point_arg};
+use crate::avm2::globals::flash::geom::{
+    fields_to_string, get_number, number_arg, object_arg, set_number,
+};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::Object;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+use swf::{Matrix, Twips};
+
+/// The components of a `Matrix` object.
+///
+/// Scripts see matrices at full `Number` precision, so all arithmetic on them
+/// happens here rather than on the player's own `Matrix` type.
+#[derive(Clone, Copy, Debug)]
+struct Components {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    tx: f64,
+    ty: f64,
+}
+
+impl Components {
+    const IDENTITY: Self = Self {
+        a: 1.0,
+        b: 0.0,
+        c: 0.0,
+        d: 1.0,
+        tx: 0.0,
+        ty: 0.0,
+    };
+
+    /// Read the components of a `Matrix` object.
+    fn read<'gc>(
+        activation: &mut Activation<'_, 'gc, '_>,
+        matrix: Object<'gc>,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            a: get_number(activation, matrix, "a")?,
+            b: get_number(activation, matrix, "b")?,
+            c: get_number(activation, matrix, "c")?,
+            d: get_number(activation, matrix, "d")?,
+            tx: get_number(activation, matrix, "tx")?,
+            ty: get_number(activation, matrix, "ty")?,
+        })
+    }
+
+    /// Write these components into a `Matrix` object.
+    fn write<'gc>(
+        self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        matrix: Object<'gc>,
+    ) -> Result<(), Error> {
+        set_number(activation, matrix, "a", self.a)?;
+        set_number(activation, matrix, "b", self.b)?;
+        set_number(activation, matrix, "c", self.c)?;
+        set_number(activation, matrix, "d", self.d)?;
+        set_number(activation, matrix, "tx", self.tx)?;
+        set_number(activation, matrix, "ty", self.ty)
+    }
+
+    /// Apply `other` after this matrix.
+    fn concat(self, other: Self) -> Self {
+        Self {
+            a: self.a * other.a + self.b * other.c,
+            b: self.a * other.b + self.b * other.d,
+            c: self.c * other.a + self.d * other.c,
+            d: self.c * other.b + self.d * other.d,
+            tx: self.tx * other.a + self.ty * other.c + other.tx,
+            ty: self.tx * other.b + self.ty * other.d + other.ty,
+        }
+    }
+
+    /// Compute the inverse of this matrix.
+    ///
+    /// Matrices that cannot be inverted become the identity matrix.
+    fn invert(self) -> Self {
+        let determinant = self.a * self.d - self.b * self.c;
+        if determinant == 0.0 {
+            return Self::IDENTITY;
+        }
+
+        Self {
+            a: self.d / determinant,
+            b: -self.b / determinant,
+            c: -self.c / determinant,
+            d: self.a / determinant,
+            tx: (self.c * self.ty - self.d * self.tx) / determinant,
+            ty: (self.b * self.tx - self.a * self.ty) / determinant,
+        }
+    }
+
+    /// Construct a scale, rotation and translation in one matrix.
+    fn create_box(scale_x: f64, scale_y: f64, rotation: f64, tx: f64, ty: f64) -> Self {
+        let (sin, cos) = rotation.sin_cos();
+
+        Self {
+            a: cos * scale_x,
+            b: sin * scale_y,
+            c: -sin * scale_x,
+            d: cos * scale_y,
+            tx,
+            ty,
+        }
+    }
+}
+
+impl From<Matrix> for Components {
+    fn from(matrix: Matrix) -> Self {
+        Self {
+            a: matrix.a.into(),
+            b: matrix.b.into(),
+            c: matrix.c.into(),
+            d: matrix.d.into(),
+            tx: matrix.tx.to_pixels(),
+            ty: matrix.ty.to_pixels(),
+        }
+    }
+}
+
+impl From<Components> for Matrix {
+    fn from(components: Components) -> Self {
+        Self {
+            a: components.a as f32,
+            b: components.b as f32,
+            c: components.c as f32,
+            d: components.d as f32,
+            tx: Twips::from_pixels(components.tx),
+            ty: Twips::from_pixels(components.ty),
+        }
+    }
+}
+
+/// Construct a new `Matrix` object from a display object matrix.
+pub fn matrix_to_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    matrix: Matrix,
+) -> Result<Value<'gc>, Error> {
+    create_matrix(activation, matrix.into())
+}
+
+/// Read a `Matrix` object as a display object matrix.
+pub fn object_to_matrix<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    matrix: Object<'gc>,
+) -> Result<Matrix, Error> {
+    Ok(Components::read(activation, matrix)?.into())
+}
+
+/// Construct a new `Matrix` object with the given components.
+fn create_matrix<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    matrix: Components,
+) -> Result<Value<'gc>, Error> {
+    let proto = activation.context.avm2.prototypes().matrix;
+    let args = [
+        matrix.a.into(),
+        matrix.b.into(),
+        matrix.c.into(),
+        matrix.d.into(),
+        matrix.tx.into(),
+        matrix.ty.into(),
+    ];
+
+    Ok(Object::construct_from_proto(activation, proto, &args)?.into())
+}
+
+/// Implements `flash.geom.Matrix`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        let matrix = Components {
+            a: number_arg(activation, args, 0, 1.0)?,
+            b: number_arg(activation, args, 1, 0.0)?,
+            c: number_arg(activation, args, 2, 0.0)?,
+            d: number_arg(activation, args, 3, 1.0)?,
+            tx: number_arg(activation, args, 4, 0.0)?,
+            ty: number_arg(activation, args, 5, 0.0)?,
+        };
+
+        matrix.write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.geom.Matrix`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.clone`.
+pub fn clone<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let matrix = Components::read(activation, this)?;
+
+        return create_matrix(activation, matrix);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.concat`.
+pub fn concat<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let other = object_arg(activation, args, 0)?;
+        let other = Components::read(activation, other)?;
+        let matrix = Components::read(activation, this)?;
+
+        matrix.concat(other).write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.copyFrom`.
+pub fn copy_from<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let other = object_arg(activation, args, 0)?;
+
+        Components::read(activation, other)?.write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.createBox`.
+pub fn create_box<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let scale_x = number_arg(activation, args, 0, f64::NAN)?;
+        let scale_y = number_arg(activation, args, 1, f64::NAN)?;
+        let rotation = number_arg(activation, args, 2, 0.0)?;
+        let tx = number_arg(activation, args, 3, 0.0)?;
+        let ty = number_arg(activation, args, 4, 0.0)?;
+
+        Components::create_box(scale_x, scale_y, rotation, tx, ty).write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.createGradientBox`.
+///
+/// Gradients are defined in a 1638.4 pixel square centered on the origin, so
+/// the box is scaled down to that size and moved to the center of the area.
+pub fn create_gradient_box<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let width = number_arg(activation, args, 0, f64::NAN)?;
+        let height = number_arg(activation, args, 1, f64::NAN)?;
+        let rotation = number_arg(activation, args, 2, 0.0)?;
+        let tx = number_arg(activation, args, 3, 0.0)?;
+        let ty = number_arg(activation, args, 4, 0.0)?;
+
+        Components::create_box(
+            width / 1638.4,
+            height / 1638.4,
+            rotation,
+            tx + width / 2.0,
+            ty + height / 2.0,
+        )
+        .write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.deltaTransformPoint`.
+pub fn delta_transform_point<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (x, y) = point_arg(activation, args, 0)?;
+        let matrix = Components::read(activation, this)?;
+
+        return create_point(
+            activation,
+            (matrix.a * x + matrix.c * y, matrix.b * x + matrix.d * y),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.identity`.
+pub fn identity<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        Components::IDENTITY.write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.invert`.
+pub fn invert<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let matrix = Components::read(activation, this)?;
+
+        matrix.invert().write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.rotate`.
+pub fn rotate<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let angle = number_arg(activation, args, 0, f64::NAN)?;
+        let matrix = Components::read(activation, this)?;
+        let rotation = Components::create_box(1.0, 1.0, angle, 0.0, 0.0);
+
+        matrix.concat(rotation).write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.scale`.
+pub fn scale<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let scale_x = number_arg(activation, args, 0, f64::NAN)?;
+        let scale_y = number_arg(activation, args, 1, f64::NAN)?;
+        let matrix = Components::read(activation, this)?;
+        let scale = Components::create_box(scale_x, scale_y, 0.0, 0.0, 0.0);
+
+        matrix.concat(scale).write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.setTo`.
+pub fn set_to<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let matrix = Components {
+            a: number_arg(activation, args, 0, f64::NAN)?,
+            b: number_arg(activation, args, 1, f64::NAN)?,
+            c: number_arg(activation, args, 2, f64::NAN)?,
+            d: number_arg(activation, args, 3, f64::NAN)?,
+            tx: number_arg(activation, args, 4, f64::NAN)?,
+            ty: number_arg(activation, args, 5, f64::NAN)?,
+        };
+
+        matrix.write(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.transformPoint`.
+pub fn transform_point<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (x, y) = point_arg(activation, args, 0)?;
+        let matrix = Components::read(activation, this)?;
+
+        return create_point(
+            activation,
+            (
+                matrix.a * x + matrix.c * y + matrix.tx,
+                matrix.b * x + matrix.d * y + matrix.ty,
+            ),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.translate`.
+pub fn translate<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let dx = number_arg(activation, args, 0, f64::NAN)?;
+        let dy = number_arg(activation, args, 1, f64::NAN)?;
+        let tx = get_number(activation, this, "tx")?;
+        let ty = get_number(activation, this, "ty")?;
+
+        set_number(activation, this, "tx", tx + dx)?;
+        set_number(activation, this, "ty", ty + dy)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Matrix.toString`.
+pub fn to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return fields_to_string(
+            activation,
+            this,
+            &[
+                ("a", "a"),
+                ("b", "b"),
+                ("c", "c"),
+                ("d", "d"),
+                ("tx", "tx"),
+                ("ty", "ty"),
+            ],
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `Matrix`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.geom"), "Matrix"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    for name in &["a", "b", "c", "d", "tx", "ty"] {
+        write.define_instance_trait(Trait::from_slot(
+            QName::new(Namespace::public_namespace(), *name),
+            QName::new(Namespace::public_namespace(), "Number").into(),
+            None,
+        ));
+    }
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "clone"),
+        Method::from_builtin(clone),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "concat"),
+        Method::from_builtin(concat),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "copyFrom"),
+        Method::from_builtin(copy_from),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "createBox"),
+        Method::from_builtin(create_box),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "createGradientBox"),
+        Method::from_builtin(create_gradient_box),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "deltaTransformPoint"),
+        Method::from_builtin(delta_transform_point),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "identity"),
+        Method::from_builtin(identity),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "invert"),
+        Method::from_builtin(invert),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "rotate"),
+        Method::from_builtin(rotate),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "scale"),
+        Method::from_builtin(scale),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "setTo"),
+        Method::from_builtin(set_to),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "transformPoint"),
+        Method::from_builtin(transform_point),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "translate"),
+        Method::from_builtin(translate),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "toString"),
+        Method::from_builtin(to_string),
+    ));
+
+    class
+}
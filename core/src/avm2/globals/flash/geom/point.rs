@@ -0,0 +1,363 @@
+//! `flash.geom.Point` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::globals::flash::geom::{
+    fields_to_string, get_number, number_arg, object_arg, set_number,
+};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::Object;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// Construct a new `Point` with the given coordinates.
+pub fn create_point<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    (x, y): (f64, f64),
+) -> Result<Value<'gc>, Error> {
+    let proto = activation.context.avm2.prototypes().point;
+    let args = [x.into(), y.into()];
+
+    Ok(Object::construct_from_proto(activation, proto, &args)?.into())
+}
+
+/// Read the coordinates of a `Point`.
+pub fn point_coords<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    point: Object<'gc>,
+) -> Result<(f64, f64), Error> {
+    Ok((
+        get_number(activation, point, "x")?,
+        get_number(activation, point, "y")?,
+    ))
+}
+
+/// Set the coordinates of a `Point`.
+fn set_point_coords<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    point: Object<'gc>,
+    (x, y): (f64, f64),
+) -> Result<(), Error> {
+    set_number(activation, point, "x", x)?;
+    set_number(activation, point, "y", y)
+}
+
+/// Read the coordinates of a `Point` passed as an argument.
+pub fn point_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    index: usize,
+) -> Result<(f64, f64), Error> {
+    let point = object_arg(activation, args, index)?;
+
+    point_coords(activation, point)
+}
+
+/// Implements `flash.geom.Point`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        let x = number_arg(activation, args, 0, 0.0)?;
+        let y = number_arg(activation, args, 1, 0.0)?;
+
+        set_point_coords(activation, this, (x, y))?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.geom.Point`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `Point.length`'s getter.
+pub fn length<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (x, y) = point_coords(activation, this)?;
+
+        return Ok(x.hypot(y).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Point.add`.
+pub fn add<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (x, y) = point_coords(activation, this)?;
+        let (other_x, other_y) = point_arg(activation, args, 0)?;
+
+        return create_point(activation, (x + other_x, y + other_y));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Point.subtract`.
+pub fn subtract<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (x, y) = point_coords(activation, this)?;
+        let (other_x, other_y) = point_arg(activation, args, 0)?;
+
+        return create_point(activation, (x - other_x, y - other_y));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Point.clone`.
+pub fn clone<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let coords = point_coords(activation, this)?;
+
+        return create_point(activation, coords);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Point.copyFrom`.
+pub fn copy_from<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let coords = point_arg(activation, args, 0)?;
+
+        set_point_coords(activation, this, coords)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Point.equals`.
+pub fn equals<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let coords = point_coords(activation, this)?;
+        let other = point_arg(activation, args, 0)?;
+
+        return Ok((coords == other).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Point.normalize`.
+///
+/// Points at the origin cannot be scaled, and are left untouched.
+pub fn normalize<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let thickness = number_arg(activation, args, 0, f64::NAN)?;
+        let (x, y) = point_coords(activation, this)?;
+        let length = x.hypot(y);
+
+        if length > 0.0 {
+            let scale = thickness / length;
+
+            set_point_coords(activation, this, (x * scale, y * scale))?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Point.offset`.
+pub fn offset<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let dx = number_arg(activation, args, 0, f64::NAN)?;
+        let dy = number_arg(activation, args, 1, f64::NAN)?;
+        let (x, y) = point_coords(activation, this)?;
+
+        set_point_coords(activation, this, (x + dx, y + dy))?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Point.setTo`.
+pub fn set_to<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let x = number_arg(activation, args, 0, f64::NAN)?;
+        let y = number_arg(activation, args, 1, f64::NAN)?;
+
+        set_point_coords(activation, this, (x, y))?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Point.toString`.
+pub fn to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return fields_to_string(activation, this, &[("x", "x"), ("y", "y")]);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Point.distance`.
+pub fn distance<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let (x1, y1) = point_arg(activation, args, 0)?;
+    let (x2, y2) = point_arg(activation, args, 1)?;
+
+    Ok((x2 - x1).hypot(y2 - y1).into())
+}
+
+/// Implements `Point.interpolate`.
+///
+/// A fraction of 1 yields the first point, and 0 yields the second.
+pub fn interpolate<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let (x1, y1) = point_arg(activation, args, 0)?;
+    let (x2, y2) = point_arg(activation, args, 1)?;
+    let f = number_arg(activation, args, 2, f64::NAN)?;
+
+    create_point(activation, (x2 + f * (x1 - x2), y2 + f * (y1 - y2)))
+}
+
+/// Implements `Point.polar`.
+pub fn polar<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let length = number_arg(activation, args, 0, f64::NAN)?;
+    let angle = number_arg(activation, args, 1, f64::NAN)?;
+
+    create_point(activation, (length * angle.cos(), length * angle.sin()))
+}
+
+/// Construct `Point`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.geom"), "Point"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    for name in &["x", "y"] {
+        write.define_instance_trait(Trait::from_slot(
+            QName::new(Namespace::public_namespace(), *name),
+            QName::new(Namespace::public_namespace(), "Number").into(),
+            None,
+        ));
+    }
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "length"),
+        Method::from_builtin(length),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "add"),
+        Method::from_builtin(add),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "subtract"),
+        Method::from_builtin(subtract),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "clone"),
+        Method::from_builtin(clone),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "copyFrom"),
+        Method::from_builtin(copy_from),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "equals"),
+        Method::from_builtin(equals),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "normalize"),
+        Method::from_builtin(normalize),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "offset"),
+        Method::from_builtin(offset),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "setTo"),
+        Method::from_builtin(set_to),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "toString"),
+        Method::from_builtin(to_string),
+    ));
+
+    write.define_class_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "distance"),
+        Method::from_builtin(distance),
+    ));
+    write.define_class_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "interpolate"),
+        Method::from_builtin(interpolate),
+    ));
+    write.define_class_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "polar"),
+        Method::from_builtin(polar),
+    ));
+
+    class
+}
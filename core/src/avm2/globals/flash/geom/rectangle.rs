@@ -0,0 +1,805 @@
+//! `flash.geom.Rectangle` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::globals::flash::geom::point::{create_point, point_arg};
+use crate::avm2::globals::flash::geom::{
+    fields_to_string, get_number, number_arg, object_arg, set_number,
+};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::Object;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// The position and size of a rectangle, in `(x, y, width, height)` order.
+type Rect = (f64, f64, f64, f64);
+
+/// Construct a new `Rectangle` with the given position and size.
+pub fn create_rectangle<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    (x, y, width, height): Rect,
+) -> Result<Value<'gc>, Error> {
+    let proto = activation.context.avm2.prototypes().rectangle;
+    let args = [x.into(), y.into(), width.into(), height.into()];
+
+    Ok(Object::construct_from_proto(activation, proto, &args)?.into())
+}
+
+/// Read the position and size of a `Rectangle`.
+pub fn rectangle_coords<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    rectangle: Object<'gc>,
+) -> Result<Rect, Error> {
+    Ok((
+        get_number(activation, rectangle, "x")?,
+        get_number(activation, rectangle, "y")?,
+        get_number(activation, rectangle, "width")?,
+        get_number(activation, rectangle, "height")?,
+    ))
+}
+
+/// Set the position and size of a `Rectangle`.
+fn set_rectangle_coords<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    rectangle: Object<'gc>,
+    (x, y, width, height): Rect,
+) -> Result<(), Error> {
+    set_number(activation, rectangle, "x", x)?;
+    set_number(activation, rectangle, "y", y)?;
+    set_number(activation, rectangle, "width", width)?;
+    set_number(activation, rectangle, "height", height)
+}
+
+/// Read the position and size of a `Rectangle` passed as an argument.
+fn rectangle_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    index: usize,
+) -> Result<Rect, Error> {
+    let rectangle = object_arg(activation, args, index)?;
+
+    rectangle_coords(activation, rectangle)
+}
+
+/// Determine if a rectangle has no area.
+///
+/// Rectangles with a `NaN` size are not considered empty.
+fn is_rect_empty((_, _, width, height): Rect) -> bool {
+    width <= 0.0 || height <= 0.0
+}
+
+/// Implements `flash.geom.Rectangle`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        let x = number_arg(activation, args, 0, 0.0)?;
+        let y = number_arg(activation, args, 1, 0.0)?;
+        let width = number_arg(activation, args, 2, 0.0)?;
+        let height = number_arg(activation, args, 3, 0.0)?;
+
+        set_rectangle_coords(activation, this, (x, y, width, height))?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.geom.Rectangle`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.left`'s getter.
+pub fn left<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(get_number(activation, this, "x")?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.left`'s setter.
+///
+/// The right edge of the rectangle stays where it is.
+pub fn set_left<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let left = number_arg(activation, args, 0, f64::NAN)?;
+        let (x, y, width, height) = rectangle_coords(activation, this)?;
+
+        set_rectangle_coords(activation, this, (left, y, width + x - left, height))?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.top`'s getter.
+pub fn top<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(get_number(activation, this, "y")?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.top`'s setter.
+///
+/// The bottom edge of the rectangle stays where it is.
+pub fn set_top<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let top = number_arg(activation, args, 0, f64::NAN)?;
+        let (x, y, width, height) = rectangle_coords(activation, this)?;
+
+        set_rectangle_coords(activation, this, (x, top, width, height + y - top))?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.right`'s getter.
+pub fn right<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (x, _, width, _) = rectangle_coords(activation, this)?;
+
+        return Ok((x + width).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.right`'s setter.
+pub fn set_right<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let right = number_arg(activation, args, 0, f64::NAN)?;
+        let x = get_number(activation, this, "x")?;
+
+        set_number(activation, this, "width", right - x)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.bottom`'s getter.
+pub fn bottom<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (_, y, _, height) = rectangle_coords(activation, this)?;
+
+        return Ok((y + height).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.bottom`'s setter.
+pub fn set_bottom<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bottom = number_arg(activation, args, 0, f64::NAN)?;
+        let y = get_number(activation, this, "y")?;
+
+        set_number(activation, this, "height", bottom - y)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.size`'s getter.
+pub fn size<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (_, _, width, height) = rectangle_coords(activation, this)?;
+
+        return create_point(activation, (width, height));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.size`'s setter.
+pub fn set_size<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (width, height) = point_arg(activation, args, 0)?;
+
+        set_number(activation, this, "width", width)?;
+        set_number(activation, this, "height", height)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.topLeft`'s getter.
+pub fn top_left<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (x, y, _, _) = rectangle_coords(activation, this)?;
+
+        return create_point(activation, (x, y));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.topLeft`'s setter.
+///
+/// The bottom-right corner of the rectangle stays where it is.
+pub fn set_top_left<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (left, top) = point_arg(activation, args, 0)?;
+        let (x, y, width, height) = rectangle_coords(activation, this)?;
+
+        set_rectangle_coords(
+            activation,
+            this,
+            (left, top, width + x - left, height + y - top),
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.bottomRight`'s getter.
+pub fn bottom_right<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (x, y, width, height) = rectangle_coords(activation, this)?;
+
+        return create_point(activation, (x + width, y + height));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.bottomRight`'s setter.
+pub fn set_bottom_right<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (right, bottom) = point_arg(activation, args, 0)?;
+        let (x, y, _, _) = rectangle_coords(activation, this)?;
+
+        set_number(activation, this, "width", right - x)?;
+        set_number(activation, this, "height", bottom - y)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.clone`.
+pub fn clone<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let rect = rectangle_coords(activation, this)?;
+
+        return create_rectangle(activation, rect);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.contains`.
+pub fn contains<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let point_x = number_arg(activation, args, 0, f64::NAN)?;
+        let point_y = number_arg(activation, args, 1, f64::NAN)?;
+        let (x, y, width, height) = rectangle_coords(activation, this)?;
+
+        return Ok(
+            (point_x >= x && point_y >= y && point_x < x + width && point_y < y + height).into(),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.containsPoint`.
+pub fn contains_point<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (point_x, point_y) = point_arg(activation, args, 0)?;
+        let (x, y, width, height) = rectangle_coords(activation, this)?;
+
+        return Ok(
+            (point_x >= x && point_y >= y && point_x < x + width && point_y < y + height).into(),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.containsRect`.
+///
+/// Empty rectangles are never contained by anything, since none of their
+/// edges are inside another rectangle.
+pub fn contains_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (other_x, other_y, other_width, other_height) = rectangle_arg(activation, args, 0)?;
+        let (x, y, width, height) = rectangle_coords(activation, this)?;
+        let (right, bottom) = (x + width, y + height);
+        let (other_right, other_bottom) = (other_x + other_width, other_y + other_height);
+
+        return Ok((other_x >= x
+            && other_x < right
+            && other_y >= y
+            && other_y < bottom
+            && other_right > x
+            && other_right <= right
+            && other_bottom > y
+            && other_bottom <= bottom)
+            .into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.equals`.
+pub fn equals<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let other = rectangle_arg(activation, args, 0)?;
+        let rect = rectangle_coords(activation, this)?;
+
+        return Ok((rect == other).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.inflate`.
+pub fn inflate<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let dx = number_arg(activation, args, 0, f64::NAN)?;
+        let dy = number_arg(activation, args, 1, f64::NAN)?;
+        let (x, y, width, height) = rectangle_coords(activation, this)?;
+
+        set_rectangle_coords(
+            activation,
+            this,
+            (x - dx, y - dy, width + 2.0 * dx, height + 2.0 * dy),
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.inflatePoint`.
+pub fn inflate_point<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (dx, dy) = point_arg(activation, args, 0)?;
+        let (x, y, width, height) = rectangle_coords(activation, this)?;
+
+        set_rectangle_coords(
+            activation,
+            this,
+            (x - dx, y - dy, width + 2.0 * dx, height + 2.0 * dy),
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.intersection`.
+///
+/// Rectangles that do not overlap have an empty intersection at the origin.
+pub fn intersection<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (other_x, other_y, other_width, other_height) = rectangle_arg(activation, args, 0)?;
+        let (x, y, width, height) = rectangle_coords(activation, this)?;
+
+        let left = x.max(other_x);
+        let top = y.max(other_y);
+        let right = (x + width).min(other_x + other_width);
+        let bottom = (y + height).min(other_y + other_height);
+
+        if right <= left || bottom <= top {
+            return create_rectangle(activation, (0.0, 0.0, 0.0, 0.0));
+        }
+
+        return create_rectangle(activation, (left, top, right - left, bottom - top));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.intersects`.
+pub fn intersects<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (other_x, other_y, other_width, other_height) = rectangle_arg(activation, args, 0)?;
+        let (x, y, width, height) = rectangle_coords(activation, this)?;
+
+        let left = x.max(other_x);
+        let top = y.max(other_y);
+        let right = (x + width).min(other_x + other_width);
+        let bottom = (y + height).min(other_y + other_height);
+
+        return Ok((right > left && bottom > top).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.isEmpty`.
+pub fn is_empty<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let rect = rectangle_coords(activation, this)?;
+
+        return Ok(is_rect_empty(rect).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.offset`.
+pub fn offset<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let dx = number_arg(activation, args, 0, f64::NAN)?;
+        let dy = number_arg(activation, args, 1, f64::NAN)?;
+        let (x, y, _, _) = rectangle_coords(activation, this)?;
+
+        set_number(activation, this, "x", x + dx)?;
+        set_number(activation, this, "y", y + dy)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.offsetPoint`.
+pub fn offset_point<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (dx, dy) = point_arg(activation, args, 0)?;
+        let (x, y, _, _) = rectangle_coords(activation, this)?;
+
+        set_number(activation, this, "x", x + dx)?;
+        set_number(activation, this, "y", y + dy)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.setEmpty`.
+pub fn set_empty<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        set_rectangle_coords(activation, this, (0.0, 0.0, 0.0, 0.0))?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.setTo`.
+pub fn set_to<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let x = number_arg(activation, args, 0, f64::NAN)?;
+        let y = number_arg(activation, args, 1, f64::NAN)?;
+        let width = number_arg(activation, args, 2, f64::NAN)?;
+        let height = number_arg(activation, args, 3, f64::NAN)?;
+
+        set_rectangle_coords(activation, this, (x, y, width, height))?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.copyFrom`.
+pub fn copy_from<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let rect = rectangle_arg(activation, args, 0)?;
+
+        set_rectangle_coords(activation, this, rect)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.union`.
+///
+/// Empty rectangles do not contribute to the union, so the union of an empty
+/// rectangle with another is a copy of the other rectangle.
+pub fn union<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let other = rectangle_arg(activation, args, 0)?;
+        let rect = rectangle_coords(activation, this)?;
+
+        if is_rect_empty(rect) {
+            return create_rectangle(activation, other);
+        } else if is_rect_empty(other) {
+            return create_rectangle(activation, rect);
+        }
+
+        let (x, y, width, height) = rect;
+        let (other_x, other_y, other_width, other_height) = other;
+        let left = x.min(other_x);
+        let top = y.min(other_y);
+        let right = (x + width).max(other_x + other_width);
+        let bottom = (y + height).max(other_y + other_height);
+
+        return create_rectangle(activation, (left, top, right - left, bottom - top));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Rectangle.toString`.
+pub fn to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return fields_to_string(
+            activation,
+            this,
+            &[("x", "x"), ("y", "y"), ("w", "width"), ("h", "height")],
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `Rectangle`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.geom"), "Rectangle"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    for name in &["x", "y", "width", "height"] {
+        write.define_instance_trait(Trait::from_slot(
+            QName::new(Namespace::public_namespace(), *name),
+            QName::new(Namespace::public_namespace(), "Number").into(),
+            None,
+        ));
+    }
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "left"),
+        Method::from_builtin(left),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "left"),
+        Method::from_builtin(set_left),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "top"),
+        Method::from_builtin(top),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "top"),
+        Method::from_builtin(set_top),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "right"),
+        Method::from_builtin(right),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "right"),
+        Method::from_builtin(set_right),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "bottom"),
+        Method::from_builtin(bottom),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "bottom"),
+        Method::from_builtin(set_bottom),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "size"),
+        Method::from_builtin(size),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "size"),
+        Method::from_builtin(set_size),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "topLeft"),
+        Method::from_builtin(top_left),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "topLeft"),
+        Method::from_builtin(set_top_left),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "bottomRight"),
+        Method::from_builtin(bottom_right),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "bottomRight"),
+        Method::from_builtin(set_bottom_right),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "clone"),
+        Method::from_builtin(clone),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "contains"),
+        Method::from_builtin(contains),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "containsPoint"),
+        Method::from_builtin(contains_point),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "containsRect"),
+        Method::from_builtin(contains_rect),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "equals"),
+        Method::from_builtin(equals),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "inflate"),
+        Method::from_builtin(inflate),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "inflatePoint"),
+        Method::from_builtin(inflate_point),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "intersection"),
+        Method::from_builtin(intersection),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "intersects"),
+        Method::from_builtin(intersects),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "isEmpty"),
+        Method::from_builtin(is_empty),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "offset"),
+        Method::from_builtin(offset),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "offsetPoint"),
+        Method::from_builtin(offset_point),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "setEmpty"),
+        Method::from_builtin(set_empty),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "setTo"),
+        Method::from_builtin(set_to),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "copyFrom"),
+        Method::from_builtin(copy_from),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "union"),
+        Method::from_builtin(union),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "toString"),
+        Method::from_builtin(to_string),
+    ));
+
+    class
+}
@@ -0,0 +1,265 @@
+//! `flash.geom.Transform` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::exception::NativeError;
+use crate::avm2::globals::flash::geom::colortransform::{
+    color_transform_to_object, object_to_color_transform,
+};
+use crate::avm2::globals::flash::geom::matrix::{matrix_to_object, object_to_matrix};
+use crate::avm2::globals::flash::geom::object_arg;
+use crate::avm2::globals::flash::geom::rectangle::create_rectangle;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::display_object::{DisplayObject, TDisplayObject};
+use gc_arena::{GcCell, MutationContext};
+
+const NS_TRANSFORM: &str = "https://ruffle.rs/AS3/impl/Transform/";
+
+/// Get the name of one of `Transform`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_TRANSFORM), name)
+}
+
+/// Construct a new `Transform` for a display object.
+pub fn create_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    display_object: Object<'gc>,
+) -> Result<Value<'gc>, Error> {
+    let proto = activation.context.avm2.prototypes().transform;
+
+    Ok(Object::construct_from_proto(activation, proto, &[display_object.into()])?.into())
+}
+
+/// Get the display object that a `Transform` object manipulates.
+fn display_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+) -> Result<Option<DisplayObject<'gc>>, Error> {
+    if let Some(mut this) = this {
+        if let Value::Object(dobj) =
+            this.get_property(this, &internal("display_object"), activation)?
+        {
+            return Ok(dobj.as_display_object());
+        }
+    }
+
+    Ok(None)
+}
+
+/// Implements `flash.geom.Transform`'s instance constructor.
+///
+/// The display object being transformed is passed as the only argument.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let display_object = object_arg(activation, args, 0)?;
+        if display_object.as_display_object().is_none() {
+            return Err(NativeError::new(
+                "TypeError",
+                2007,
+                "Parameter displayObject must be non-null.",
+            )
+            .into());
+        }
+
+        this.init_property(
+            this,
+            &internal("display_object"),
+            display_object.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.geom.Transform`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `Transform.matrix`'s getter.
+pub fn matrix<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        let matrix = *dobj.matrix();
+
+        return matrix_to_object(activation, matrix);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Transform.matrix`'s setter.
+pub fn set_matrix<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        let matrix = object_arg(activation, args, 0)?;
+        let matrix = object_to_matrix(activation, matrix)?;
+
+        dobj.set_matrix(activation.context.gc_context, &matrix);
+        dobj.set_transformed_by_script(activation.context.gc_context, true);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Transform.colorTransform`'s getter.
+pub fn color_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        let color_transform = *dobj.color_transform();
+
+        return color_transform_to_object(activation, color_transform);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Transform.colorTransform`'s setter.
+pub fn set_color_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        let color_transform = object_arg(activation, args, 0)?;
+        let color_transform = object_to_color_transform(activation, color_transform)?;
+
+        dobj.set_color_transform(activation.context.gc_context, &color_transform);
+        dobj.set_transformed_by_script(activation.context.gc_context, true);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Transform.concatenatedMatrix`'s getter.
+pub fn concatenated_matrix<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        return matrix_to_object(activation, dobj.local_to_global_matrix());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Transform.concatenatedColorTransform`'s getter.
+pub fn concatenated_color_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        let mut color_transform = *dobj.color_transform();
+        let mut node = dobj.parent();
+        while let Some(parent) = node {
+            color_transform = *parent.color_transform() * color_transform;
+            node = parent.parent();
+        }
+
+        return color_transform_to_object(activation, color_transform);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Transform.pixelBounds`'s getter.
+///
+/// This is the bounding box of the display object on the stage.
+pub fn pixel_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        let bounds = dobj.bounds_with_transform(&dobj.local_to_global_matrix());
+
+        return create_rectangle(
+            activation,
+            (
+                bounds.x_min.to_pixels(),
+                bounds.y_min.to_pixels(),
+                bounds.width().to_pixels(),
+                bounds.height().to_pixels(),
+            ),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `Transform`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.geom"), "Transform"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("display_object"),
+        QName::new(Namespace::package("flash.display"), "DisplayObject").into(),
+        None,
+    ));
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "matrix"),
+        Method::from_builtin(matrix),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "matrix"),
+        Method::from_builtin(set_matrix),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "colorTransform"),
+        Method::from_builtin(color_transform),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "colorTransform"),
+        Method::from_builtin(set_color_transform),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "concatenatedMatrix"),
+        Method::from_builtin(concatenated_matrix),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "concatenatedColorTransform"),
+        Method::from_builtin(concatenated_color_transform),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "pixelBounds"),
+        Method::from_builtin(pixel_bounds),
+    ));
+
+    class
+}
@@ -0,0 +1,385 @@
+//! `flash.media.Sound` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::bytearray::Endian;
+use crate::avm2::class::Class;
+use crate::avm2::exception::NativeError;
+use crate::avm2::globals::flash::media::soundchannel::start_sound_channel;
+use crate::avm2::globals::flash::media::soundtransform::object_to_sound_transform;
+use crate::avm2::globals::flash::net::request_into_fetch_params;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::{Avm2, Error};
+use crate::backend::audio::{swf, SoundHandle, SoundTransform};
+use crate::character::Character;
+use gc_arena::{GcCell, MutationContext};
+use std::convert::TryInto;
+
+const NS_SOUND: &str = "https://ruffle.rs/AS3/impl/Sound/";
+
+/// The most sample frames a single `SampleDataEvent` may supply.
+const MAX_SAMPLE_DATA_FRAMES: usize = 8192;
+
+/// Get the name of one of `Sound`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_SOUND), name)
+}
+
+/// Find the embedded sound that a `Sound` subclass was bound to with a
+/// `SymbolClass` tag, if any.
+fn sound_symbol<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+) -> Result<Option<SoundHandle>, Error> {
+    let mut proto = this.proto();
+
+    while let Some(mut p) = proto {
+        if let Value::Object(constr) = p.get_property(
+            p,
+            &QName::new(Namespace::public_namespace(), "constructor"),
+            activation,
+        )? {
+            if let Some(Character::Sound(sound)) = activation
+                .context
+                .library
+                .avm2_class_registry()
+                .class_symbol(constr)
+            {
+                return Ok(Some(*sound));
+            }
+        }
+
+        proto = p.proto();
+    }
+
+    Ok(None)
+}
+
+/// Determine if a `Sound` has a `sampleData` listener to generate its
+/// samples with.
+fn has_sample_data_listener<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut this: Object<'gc>,
+) -> Result<bool, Error> {
+    let has_event_listener = this
+        .get_property(
+            this,
+            &QName::new(Namespace::public_namespace(), "hasEventListener"),
+            activation,
+        )?
+        .coerce_to_object(activation)?;
+
+    Ok(has_event_listener
+        .call(Some(this), &["sampleData".into()], activation, None)?
+        .coerce_to_boolean())
+}
+
+/// Fire a `SampleDataEvent` on a script-generated `Sound`, and read back the
+/// 44.1kHz stereo samples that its listeners wrote.
+///
+/// `position` is the index of the first sample frame being requested.
+pub fn request_sample_data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    source: Object<'gc>,
+    position: u32,
+) -> Result<Vec<[f32; 2]>, Error> {
+    let bytearray_proto = activation.context.avm2.prototypes().bytearray;
+    let sample_data_event_proto = activation.context.avm2.prototypes().sample_data_event;
+    let data = Object::construct_from_proto(activation, bytearray_proto, &[])?;
+
+    Avm2::dispatch_event(
+        &mut activation.context,
+        source,
+        sample_data_event_proto,
+        &[
+            "sampleData".into(),
+            false.into(),
+            false.into(),
+            position.into(),
+            data.into(),
+        ],
+    )?;
+
+    let bytes = data
+        .as_bytearray()
+        .ok_or("SampleDataEvent data should be a ByteArray")?;
+    let read_f32 = |chunk: &[u8]| {
+        let chunk = chunk.try_into().unwrap();
+        match bytes.endian() {
+            Endian::Big => f32::from_be_bytes(chunk),
+            Endian::Little => f32::from_le_bytes(chunk),
+        }
+    };
+
+    Ok(bytes
+        .bytes()
+        .chunks_exact(8)
+        .take(MAX_SAMPLE_DATA_FRAMES)
+        .map(|frame| [read_f32(&frame[0..4]), read_f32(&frame[4..8])])
+        .collect())
+}
+
+/// Implements `flash.media.Sound`'s instance constructor.
+///
+/// Subclasses bound to an embedded sound play that sound. Otherwise, a URL
+/// may be given to load the sound from, as if by `load`.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        if let Some(sound) = sound_symbol(activation, this)? {
+            this.set_sound(activation.context.gc_context, sound);
+        } else if let Some(Value::Object(_)) = args.get(0) {
+            load(activation, Some(this), args)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.media.Sound`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `Sound.length`'s getter.
+pub fn length<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let duration = this
+            .as_sound()
+            .and_then(|sound| activation.context.audio.get_sound_duration(sound))
+            .unwrap_or(0);
+
+        return Ok(f64::from(duration).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Sound.extract`
+///
+/// The sound is decoded to 44.1kHz stereo samples, which are written to the
+/// target `ByteArray` as pairs of floats. A negative start position
+/// continues from where the last extraction ended.
+pub fn extract<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let target = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let length = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_number(activation)?
+            .max(0.0) as usize;
+        let start_position = args
+            .get(2)
+            .cloned()
+            .unwrap_or_else(|| (-1).into())
+            .coerce_to_number(activation)?;
+        let start_position = if start_position < 0.0 {
+            this.get_property(this, &internal("extractPosition"), activation)?
+                .coerce_to_number(activation)?
+        } else {
+            start_position
+        };
+
+        let samples = match this.as_sound().and_then(|sound| {
+            activation
+                .context
+                .audio_manager
+                .sound_samples(activation.context.audio, sound)
+        }) {
+            Some(samples) => samples,
+            None => return Ok(0.into()),
+        };
+
+        let start = (start_position as usize).min(samples.len());
+        let end = start.saturating_add(length).min(samples.len());
+
+        if let Some(mut bytes) = target.as_bytearray_mut(activation.context.gc_context) {
+            for [left, right] in &samples[start..end] {
+                bytes.write_f32(*left);
+                bytes.write_f32(*right);
+            }
+        } else {
+            return Err(NativeError::new(
+                "TypeError",
+                1034,
+                "Type Coercion failed: not a ByteArray",
+            )
+            .into());
+        }
+
+        this.set_property(
+            this,
+            &internal("extractPosition"),
+            (end as f64).into(),
+            activation,
+        )?;
+
+        return Ok(((end - start) as f64).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Sound.load`
+///
+/// Only MP3 files are supported.
+pub fn load<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let request = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let (url, request_options) = request_into_fetch_params(activation, request)?;
+        let fetch = activation.context.navigator.fetch(&url, request_options);
+        let process = activation.context.load_manager.load_sound_into_avm2_sound(
+            activation.context.player.clone().unwrap(),
+            this,
+            fetch,
+            url,
+        );
+
+        activation.context.navigator.spawn_future(process);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Sound.play`
+///
+/// A `Sound` with neither an embedded nor a loaded sound plays samples
+/// generated by its `sampleData` listeners instead.
+///
+/// Returns a new `SoundChannel` controlling the playing sound, or `null` if
+/// the sound has nothing to play or could not be played.
+pub fn play<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let start_time = args
+            .get(0)
+            .cloned()
+            .unwrap_or_else(|| 0.into())
+            .coerce_to_number(activation)?;
+        let loops = args
+            .get(1)
+            .cloned()
+            .unwrap_or_else(|| 0.into())
+            .coerce_to_i32(activation)?;
+        let transform = match args.get(2) {
+            Some(Value::Object(object)) => object_to_sound_transform(activation, *object)?,
+            _ => SoundTransform::default(),
+        };
+
+        // Start times are given in milliseconds, while the audio backend
+        // expects a sample offset at 44.1kHz.
+        let in_sample = if start_time > 0.0 {
+            Some((start_time * 44.1) as u32)
+        } else {
+            None
+        };
+        let sound_info = swf::SoundInfo {
+            event: swf::SoundEvent::Start,
+            in_sample,
+            out_sample: None,
+            num_loops: loops.max(1).min(i32::from(u16::MAX)) as u16,
+            envelope: None,
+        };
+
+        let (instance, sample_source) = if let Some(sound) = this.as_sound() {
+            (
+                activation.context.audio.start_sound(sound, &sound_info),
+                None,
+            )
+        } else if has_sample_data_listener(activation, this)? {
+            (activation.context.audio.start_dynamic_sound(), Some(this))
+        } else {
+            return Ok(Value::Null);
+        };
+        let instance = match instance {
+            Ok(instance) => instance,
+            Err(e) => {
+                log::error!("Sound.play: Unable to start sound: {}", e);
+                return Ok(Value::Null);
+            }
+        };
+
+        let sound_channel_proto = activation.context.avm2.prototypes().sound_channel;
+        let channel = Object::construct_from_proto(activation, sound_channel_proto, &[])?;
+
+        start_sound_channel(activation, channel, instance, sample_source, transform)?;
+
+        return Ok(channel.into());
+    }
+
+    Ok(Value::Null)
+}
+
+/// Construct `Sound`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.media"), "Sound"),
+        Some(QName::new(Namespace::package("flash.events"), "EventDispatcher").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "length"),
+        Method::from_builtin(length),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "extract"),
+        Method::from_builtin(extract),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "load"),
+        Method::from_builtin(load),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "play"),
+        Method::from_builtin(play),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("extractPosition"),
+        QName::new(Namespace::public_namespace(), "Number").into(),
+        Some(0.0.into()),
+    ));
+
+    class
+}
@@ -0,0 +1,234 @@
+//! `flash.media.SoundChannel` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::globals::flash::media::soundtransform::{
+    create_sound_transform, object_to_sound_transform,
+};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::backend::audio::{SoundInstanceHandle, SoundTransform};
+use gc_arena::{GcCell, MutationContext};
+
+const NS_SOUND_CHANNEL: &str = "https://ruffle.rs/AS3/impl/SoundChannel/";
+
+/// Get the name of one of `SoundChannel`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_SOUND_CHANNEL), name)
+}
+
+/// Associate a newly started sound instance with a `SoundChannel`.
+///
+/// The channel's transform is applied to the sound, and the sound is tracked
+/// so that the channel is notified when it completes. Script-generated sounds
+/// also name the `Sound` that generates their samples.
+pub fn start_sound_channel<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut channel: Object<'gc>,
+    instance: SoundInstanceHandle,
+    sample_source: Option<Object<'gc>>,
+    transform: SoundTransform,
+) -> Result<(), Error> {
+    let transform_object = create_sound_transform(activation, &transform)?;
+
+    channel.set_property(
+        channel,
+        &internal("soundTransform"),
+        transform_object.into(),
+        activation,
+    )?;
+    channel.set_sound_instance(activation.context.gc_context, instance);
+
+    if let Some(source) = sample_source {
+        activation.context.audio_manager.start_dynamic_sound(
+            activation.context.audio,
+            instance,
+            channel,
+            source,
+            transform,
+        );
+    } else {
+        activation.context.audio_manager.start_sound(
+            activation.context.audio,
+            instance,
+            channel,
+            transform,
+        );
+    }
+
+    Ok(())
+}
+
+/// Implements `flash.media.SoundChannel`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.media.SoundChannel`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `SoundChannel.position`'s getter.
+///
+/// Once the sound has been stopped, this is the position it stopped at.
+pub fn position<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let position = this
+            .as_sound_instance()
+            .and_then(|instance| activation.context.audio.get_sound_position(instance));
+
+        if let Some(position) = position {
+            return Ok(f64::from(position).into());
+        }
+
+        return this.get_property(this, &internal("position"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `SoundChannel.stop`.
+pub fn stop<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        if let Some(instance) = this.as_sound_instance() {
+            if let Some(position) = activation.context.audio.get_sound_position(instance) {
+                this.set_property(
+                    this,
+                    &internal("position"),
+                    f64::from(position).into(),
+                    activation,
+                )?;
+            }
+
+            activation
+                .context
+                .audio_manager
+                .stop_sound(activation.context.audio, instance);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `SoundChannel.soundTransform`'s getter.
+///
+/// This returns a copy of the channel's transform; changes to it have no
+/// effect until it is assigned back to the channel.
+pub fn sound_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let transform = match this.get_property(this, &internal("soundTransform"), activation)? {
+            Value::Object(object) => object_to_sound_transform(activation, object)?,
+            _ => SoundTransform::default(),
+        };
+
+        return Ok(create_sound_transform(activation, &transform)?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `SoundChannel.soundTransform`'s setter.
+pub fn set_sound_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let object = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let transform = object_to_sound_transform(activation, object)?;
+        let transform_object = create_sound_transform(activation, &transform)?;
+
+        this.set_property(
+            this,
+            &internal("soundTransform"),
+            transform_object.into(),
+            activation,
+        )?;
+
+        if let Some(instance) = this.as_sound_instance() {
+            activation.context.audio_manager.set_sound_transform(
+                activation.context.audio,
+                instance,
+                transform,
+            );
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `SoundChannel`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.media"), "SoundChannel"),
+        Some(QName::new(Namespace::package("flash.events"), "EventDispatcher").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "position"),
+        Method::from_builtin(position),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "stop"),
+        Method::from_builtin(stop),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "soundTransform"),
+        Method::from_builtin(sound_transform),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "soundTransform"),
+        Method::from_builtin(set_sound_transform),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("position"),
+        QName::new(Namespace::public_namespace(), "Number").into(),
+        Some(0.0.into()),
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("soundTransform"),
+        Multiname::any(),
+        Some(Value::Null),
+    ));
+
+    class
+}
@@ -0,0 +1,164 @@
+//! `flash.media.SoundMixer` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::exception::NativeError;
+use crate::avm2::globals::flash::media::soundtransform::{
+    create_sound_transform, object_to_sound_transform,
+};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::backend::audio::spectrum::{self, SPECTRUM_HISTORY_LENGTH};
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.media.SoundMixer`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.media.SoundMixer`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `SoundMixer.stopAll`
+pub fn stop_all<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    activation
+        .context
+        .audio_manager
+        .stop_all_sounds(activation.context.audio);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `SoundMixer.computeSpectrum`
+///
+/// The target `ByteArray` is overwritten with 256 floats for the left channel
+/// followed by 256 for the right, and rewound so they can be read back.
+pub fn compute_spectrum<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let target = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_object(activation)?;
+    let fft_mode = args
+        .get(1)
+        .cloned()
+        .unwrap_or_else(|| false.into())
+        .coerce_to_boolean();
+    let stretch_factor = args
+        .get(2)
+        .cloned()
+        .unwrap_or_else(|| 0.into())
+        .coerce_to_i32(activation)?
+        .max(0) as u32;
+
+    let mut samples = vec![[0.0; 2]; SPECTRUM_HISTORY_LENGTH];
+    activation.context.audio.get_output_samples(&mut samples);
+    let values = spectrum::compute_spectrum(&samples, fft_mode, stretch_factor);
+
+    let mut bytes = target
+        .as_bytearray_mut(activation.context.gc_context)
+        .ok_or_else(|| {
+            NativeError::new("TypeError", 1034, "Type Coercion failed: not a ByteArray")
+        })?;
+
+    bytes.clear();
+    for value in values {
+        bytes.write_f32(value);
+    }
+    bytes.set_position(0);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `SoundMixer.soundTransform`'s getter.
+///
+/// This returns a copy of the global transform; changes to it have no effect
+/// until it is assigned back to the mixer.
+pub fn sound_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let transform = activation.context.audio_manager.global_transform();
+
+    Ok(create_sound_transform(activation, &transform)?.into())
+}
+
+/// Implements `SoundMixer.soundTransform`'s setter.
+pub fn set_sound_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let object = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_object(activation)?;
+    let transform = object_to_sound_transform(activation, object)?;
+
+    activation
+        .context
+        .audio_manager
+        .set_global_transform(activation.context.audio, transform);
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `SoundMixer`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.media"), "SoundMixer"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_class_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "stopAll"),
+        Method::from_builtin(stop_all),
+    ));
+    write.define_class_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "computeSpectrum"),
+        Method::from_builtin(compute_spectrum),
+    ));
+    write.define_class_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "soundTransform"),
+        Method::from_builtin(sound_transform),
+    ));
+    write.define_class_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "soundTransform"),
+        Method::from_builtin(set_sound_transform),
+    ));
+
+    class
+}
@@ -0,0 +1,196 @@
+//! `flash.media.SoundTransform` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::backend::audio::SoundTransform;
+use gc_arena::{GcCell, MutationContext};
+
+/// Read one of the public numeric fields of a `SoundTransform`.
+fn get_number<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut object: Object<'gc>,
+    name: &'static str,
+) -> Result<f32, Error> {
+    Ok(object
+        .get_property(
+            object,
+            &QName::new(Namespace::public_namespace(), name),
+            activation,
+        )?
+        .coerce_to_number(activation)? as f32)
+}
+
+/// Write one of the public numeric fields of a `SoundTransform`.
+fn set_number<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut object: Object<'gc>,
+    name: &'static str,
+    value: f32,
+) -> Result<(), Error> {
+    object.set_property(
+        object,
+        &QName::new(Namespace::public_namespace(), name),
+        f64::from(value).into(),
+        activation,
+    )
+}
+
+/// Read the volume and panning of a `SoundTransform` object.
+pub fn object_to_sound_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+) -> Result<SoundTransform, Error> {
+    Ok(SoundTransform {
+        volume: get_number(activation, object, "volume")?,
+        left_to_left: get_number(activation, object, "leftToLeft")?,
+        left_to_right: get_number(activation, object, "leftToRight")?,
+        right_to_left: get_number(activation, object, "rightToLeft")?,
+        right_to_right: get_number(activation, object, "rightToRight")?,
+    })
+}
+
+/// Write the volume and panning of a `SoundTransform` object.
+fn set_sound_transform_fields<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    transform: &SoundTransform,
+) -> Result<(), Error> {
+    set_number(activation, object, "volume", transform.volume)?;
+    set_number(activation, object, "leftToLeft", transform.left_to_left)?;
+    set_number(activation, object, "leftToRight", transform.left_to_right)?;
+    set_number(activation, object, "rightToLeft", transform.right_to_left)?;
+    set_number(activation, object, "rightToRight", transform.right_to_right)
+}
+
+/// Construct a new `SoundTransform` object with the given volume and panning.
+pub fn create_sound_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    transform: &SoundTransform,
+) -> Result<Object<'gc>, Error> {
+    let proto = activation.context.avm2.prototypes().sound_transform;
+    let object = Object::construct_from_proto(activation, proto, &[])?;
+
+    set_sound_transform_fields(activation, object, transform)?;
+
+    Ok(object)
+}
+
+/// Implements `flash.media.SoundTransform`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        let volume = args
+            .get(0)
+            .cloned()
+            .unwrap_or_else(|| 1.into())
+            .coerce_to_number(activation)?;
+        let pan = args
+            .get(1)
+            .cloned()
+            .unwrap_or_else(|| 0.into())
+            .coerce_to_number(activation)?;
+
+        set_sound_transform_fields(
+            activation,
+            this,
+            &SoundTransform::new(volume as f32, pan as f32),
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.media.SoundTransform`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `SoundTransform.pan`'s getter.
+pub fn pan<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let transform = object_to_sound_transform(activation, this)?;
+
+        return Ok(f64::from(transform.pan()).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `SoundTransform.pan`'s setter.
+///
+/// Setting the panning discards any cross-channel mixing.
+pub fn set_pan<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let pan = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_number(activation)?;
+        let volume = get_number(activation, this, "volume")?;
+
+        set_sound_transform_fields(activation, this, &SoundTransform::new(volume, pan as f32))?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `SoundTransform`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.media"), "SoundTransform"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "pan"),
+        Method::from_builtin(pan),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "pan"),
+        Method::from_builtin(set_pan),
+    ));
+
+    for (name, default) in &[
+        ("volume", 1.0),
+        ("leftToLeft", 1.0),
+        ("leftToRight", 0.0),
+        ("rightToLeft", 0.0),
+        ("rightToRight", 1.0),
+    ] {
+        write.define_instance_trait(Trait::from_slot(
+            QName::new(Namespace::public_namespace(), *name),
+            QName::new(Namespace::public_namespace(), "Number").into(),
+            Some((*default).into()),
+        ));
+    }
+
+    class
+}
@@ -0,0 +1,227 @@
+//! `flash.net` namespace
+
+use crate::avm2::activation::Activation;
+use crate::avm2::exception::NativeError;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::backend::navigator::{NavigationMethod, RequestOptions};
+use indexmap::IndexMap;
+use url::form_urlencoded;
+
+pub mod urlloader;
+pub mod urlloaderdataformat;
+pub mod urlrequest;
+pub mod urlrequestheader;
+pub mod urlrequestmethod;
+pub mod urlvariables;
+
+/// The parts of a `URLRequest` needed to act on it.
+struct RequestParams {
+    url: String,
+    method: NavigationMethod,
+    content_type: String,
+    headers: Vec<(String, String)>,
+    variables: Option<Vec<(String, String)>>,
+    body: Option<String>,
+}
+
+/// Read the parts of a `URLRequest` needed to act on it.
+///
+/// `URLVariables` data is encoded as form values; any other data is sent as
+/// a string.
+fn request_params<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut request: Object<'gc>,
+) -> Result<RequestParams, Error> {
+    let url = match request.get_property(
+        request,
+        &QName::new(Namespace::public_namespace(), "url"),
+        activation,
+    )? {
+        Value::Undefined | Value::Null => {
+            return Err(
+                NativeError::new("TypeError", 2007, "Parameter url must be non-null.").into(),
+            )
+        }
+        url => url.coerce_to_string(activation)?.to_string(),
+    };
+    let method = request
+        .get_property(
+            request,
+            &QName::new(Namespace::public_namespace(), "method"),
+            activation,
+        )?
+        .coerce_to_string(activation)?;
+    let method = NavigationMethod::from_method_str(&method).unwrap_or(NavigationMethod::GET);
+    let content_type = request
+        .get_property(
+            request,
+            &QName::new(Namespace::public_namespace(), "contentType"),
+            activation,
+        )?
+        .coerce_to_string(activation)?
+        .to_string();
+
+    let header_objects: Vec<Value<'gc>> = match request.get_property(
+        request,
+        &QName::new(Namespace::public_namespace(), "requestHeaders"),
+        activation,
+    )? {
+        Value::Object(request_headers) => request_headers
+            .as_array_storage()
+            .map(|storage| storage.iter().flatten().collect())
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+    let mut headers = Vec::with_capacity(header_objects.len());
+    for header in header_objects {
+        let mut header = header.coerce_to_object(activation)?;
+        let name = header
+            .get_property(
+                header,
+                &QName::new(Namespace::public_namespace(), "name"),
+                activation,
+            )?
+            .coerce_to_string(activation)?
+            .to_string();
+        let value = header
+            .get_property(
+                header,
+                &QName::new(Namespace::public_namespace(), "value"),
+                activation,
+            )?
+            .coerce_to_string(activation)?
+            .to_string();
+
+        headers.push((name, value));
+    }
+
+    let url_variables_proto = activation.context.avm2.prototypes().url_variables;
+    let data = request.get_property(
+        request,
+        &QName::new(Namespace::public_namespace(), "data"),
+        activation,
+    )?;
+    let (variables, body) = match data {
+        Value::Undefined | Value::Null => (None, None),
+        Value::Object(data) if data.has_prototype_in_chain(url_variables_proto, false)? => {
+            let pairs = urlvariables::object_into_form_pairs(activation, data)?;
+            let body = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(pairs.iter())
+                .finish();
+
+            (Some(pairs), Some(body))
+        }
+        data => {
+            let body = data.coerce_to_string(activation)?.to_string();
+            let pairs = form_urlencoded::parse(body.as_bytes())
+                .into_owned()
+                .collect();
+
+            (Some(pairs), Some(body))
+        }
+    };
+
+    Ok(RequestParams {
+        url,
+        method,
+        content_type,
+        headers,
+        variables,
+        body,
+    })
+}
+
+/// Resolve a `URLRequest` into the URL to fetch and the options to fetch it
+/// with.
+///
+/// `GET` requests carry their data in the query string, and `POST` requests
+/// carry it in the request body. Any `requestHeaders` are sent with either.
+pub fn request_into_fetch_params<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    request: Object<'gc>,
+) -> Result<(String, RequestOptions), Error> {
+    let RequestParams {
+        url,
+        method,
+        content_type,
+        headers,
+        body,
+        ..
+    } = request_params(activation, request)?;
+
+    let (url, options) = match (method, body) {
+        (NavigationMethod::GET, None) => (url, RequestOptions::get()),
+        (NavigationMethod::GET, Some(query)) if url.find('?').is_none() => {
+            (format!("{}?{}", url, query), RequestOptions::get())
+        }
+        (NavigationMethod::GET, Some(query)) => {
+            (format!("{}&{}", url, query), RequestOptions::get())
+        }
+        (NavigationMethod::POST, body) => (
+            url,
+            RequestOptions::post(body.map(|body| (body.into_bytes(), content_type))),
+        ),
+    };
+
+    Ok((url, options.with_headers(headers)))
+}
+
+/// Implements `flash.net.navigateToURL`
+pub fn navigate_to_url<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let request = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_object(activation)?;
+    let window = match args.get(1) {
+        None | Some(Value::Undefined) | Some(Value::Null) => "_blank".to_string(),
+        Some(window) => window.coerce_to_string(activation)?.to_string(),
+    };
+
+    let RequestParams {
+        url,
+        method,
+        variables,
+        ..
+    } = request_params(activation, request)?;
+    let vars_method =
+        variables.map(|variables| (method, variables.into_iter().collect::<IndexMap<_, _>>()));
+
+    activation
+        .context
+        .navigator
+        .navigate_to_url(url, Some(window), vars_method);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.sendToURL`
+///
+/// The request is made, but any response is ignored.
+pub fn send_to_url<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let request = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_object(activation)?;
+    let (url, request_options) = request_into_fetch_params(activation, request)?;
+    let fetch = activation.context.navigator.fetch(&url, request_options);
+
+    activation
+        .context
+        .navigator
+        .spawn_future(Box::pin(async move { fetch.await.map(|_| ()) }));
+
+    Ok(Value::Undefined)
+}
@@ -0,0 +1,317 @@
+//! `flash.net.URLRequest` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::exception::NativeError;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{ArrayObject, Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::backend::navigator::NavigationMethod;
+use gc_arena::{GcCell, MutationContext};
+
+const NS_URL_REQUEST: &str = "https://ruffle.rs/AS3/impl/URLRequest/";
+
+/// Get the name of one of `URLRequest`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_URL_REQUEST), name)
+}
+
+/// Implements `flash.net.URLRequest`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let url = match args.get(0) {
+            None | Some(Value::Undefined) | Some(Value::Null) => Value::Null,
+            Some(url) => url.coerce_to_string(activation)?.into(),
+        };
+        let request_headers = ArrayObject::from_array(
+            ArrayStorage::new(0),
+            activation.context.avm2.prototypes().array,
+            activation.context.gc_context,
+        );
+
+        this.init_property(this, &internal("url"), url, activation)?;
+        this.init_property(this, &internal("method"), "GET".into(), activation)?;
+        this.init_property(this, &internal("data"), Value::Null, activation)?;
+        this.init_property(
+            this,
+            &internal("content_type"),
+            "application/x-www-form-urlencoded".into(),
+            activation,
+        )?;
+        this.init_property(
+            this,
+            &internal("request_headers"),
+            request_headers.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.URLRequest`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLRequest.url`'s getter
+pub fn url<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("url"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLRequest.url`'s setter
+pub fn set_url<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let url = match args.get(0) {
+            None | Some(Value::Undefined) | Some(Value::Null) => Value::Null,
+            Some(url) => url.coerce_to_string(activation)?.into(),
+        };
+
+        this.set_property(this, &internal("url"), url, activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLRequest.method`'s getter
+pub fn method<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("method"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLRequest.method`'s setter
+///
+/// Only `GET` and `POST` are accepted; method names are case-insensitive.
+pub fn set_method<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let method = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?
+            .to_ascii_uppercase();
+
+        if NavigationMethod::from_method_str(&method).is_none() {
+            return Err(NativeError::new(
+                "ArgumentError",
+                2008,
+                "Parameter method must be one of the accepted values.",
+            )
+            .into());
+        }
+
+        let method = if method == "GET" { "GET" } else { "POST" };
+        this.set_property(this, &internal("method"), method.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLRequest.data`'s getter
+pub fn data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("data"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLRequest.data`'s setter
+pub fn set_data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let data = args.get(0).cloned().unwrap_or(Value::Null);
+
+        this.set_property(this, &internal("data"), data, activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLRequest.contentType`'s getter
+pub fn content_type<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("content_type"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLRequest.contentType`'s setter
+pub fn set_content_type<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let content_type = match args.get(0) {
+            None | Some(Value::Undefined) | Some(Value::Null) => Value::Null,
+            Some(content_type) => content_type.coerce_to_string(activation)?.into(),
+        };
+
+        this.set_property(this, &internal("content_type"), content_type, activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLRequest.requestHeaders`'s getter
+pub fn request_headers<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("request_headers"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLRequest.requestHeaders`'s setter
+pub fn set_request_headers<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let request_headers = args.get(0).cloned().unwrap_or(Value::Null);
+
+        this.set_property(
+            this,
+            &internal("request_headers"),
+            request_headers,
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `URLRequest`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.net"), "URLRequest"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::Final | ClassAttributes::Sealed);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "url"),
+        Method::from_builtin(url),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "url"),
+        Method::from_builtin(set_url),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "method"),
+        Method::from_builtin(method),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "method"),
+        Method::from_builtin(set_method),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "data"),
+        Method::from_builtin(data),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "data"),
+        Method::from_builtin(set_data),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "contentType"),
+        Method::from_builtin(content_type),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "contentType"),
+        Method::from_builtin(set_content_type),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "requestHeaders"),
+        Method::from_builtin(request_headers),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "requestHeaders"),
+        Method::from_builtin(set_request_headers),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("url"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("method"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(internal("data"), Multiname::any(), None));
+    write.define_instance_trait(Trait::from_slot(
+        internal("content_type"),
+        QName::new(Namespace::public_namespace(), "String").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("request_headers"),
+        QName::new(Namespace::public_namespace(), "Array").into(),
+        None,
+    ));
+
+    class
+}
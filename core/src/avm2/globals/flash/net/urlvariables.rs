@@ -0,0 +1,176 @@
+//! `flash.net.URLVariables` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::class::Class;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{ArrayObject, Object, TObject};
+use crate::avm2::string::AvmString;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+use url::form_urlencoded;
+
+/// Implements `flash.net.URLVariables`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        match args.get(0) {
+            None | Some(Value::Undefined) | Some(Value::Null) => {}
+            Some(_) => {
+                decode(activation, Some(this), args)?;
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.URLVariables`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Collect the public dynamic properties of an object as name/value pairs.
+///
+/// Array-valued properties produce one pair per element, which is how
+/// variables with repeated names are encoded.
+pub fn object_into_form_pairs<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut object: Object<'gc>,
+) -> Result<Vec<(String, String)>, Error> {
+    let mut pairs = Vec::new();
+    let mut index = 1;
+
+    while let Some(name) = object.get_enumerant_name(index) {
+        index += 1;
+
+        if !name.namespace().is_public() {
+            continue;
+        }
+
+        let value = object.get_property(object, &name, activation)?;
+        let elements = if let Value::Object(o) = &value {
+            o.as_array_storage()
+                .map(|array| array.iter().collect::<Vec<_>>())
+        } else {
+            None
+        };
+
+        match elements {
+            Some(elements) => {
+                for element in elements {
+                    let element = element
+                        .unwrap_or(Value::Undefined)
+                        .coerce_to_string(activation)?;
+                    pairs.push((name.local_name().to_string(), element.to_string()));
+                }
+            }
+            None => {
+                let value = value.coerce_to_string(activation)?;
+                pairs.push((name.local_name().to_string(), value.to_string()));
+            }
+        }
+    }
+
+    Ok(pairs)
+}
+
+/// Implements `URLVariables.decode`
+///
+/// Variables that occur more than once are collected into an array.
+pub fn decode<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let source = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        for (name, value) in form_urlencoded::parse(source.as_bytes()) {
+            let name = QName::dynamic_name(AvmString::new(activation.context.gc_context, name));
+            let value: Value<'gc> = AvmString::new(activation.context.gc_context, value).into();
+
+            if this.has_own_property(&name)? {
+                let existing = this.get_property(this, &name, activation)?;
+
+                if let Value::Object(existing) = existing {
+                    if let Some(mut array) =
+                        existing.as_array_storage_mut(activation.context.gc_context)
+                    {
+                        array.push(value);
+                        continue;
+                    }
+                }
+
+                let array = ArrayObject::from_array(
+                    ArrayStorage::from_args(&[existing, value]),
+                    activation.context.avm2.prototypes().array,
+                    activation.context.gc_context,
+                );
+                this.set_property(this, &name, array.into(), activation)?;
+            } else {
+                this.set_property(this, &name, value, activation)?;
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLVariables.toString`
+pub fn to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let pairs = object_into_form_pairs(activation, this)?;
+        let encoded = form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(pairs.iter())
+            .finish();
+
+        return Ok(AvmString::new(activation.context.gc_context, encoded).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `URLVariables`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.net"), "URLVariables"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "decode"),
+        Method::from_builtin(decode),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "toString"),
+        Method::from_builtin(to_string),
+    ));
+
+    class
+}
@@ -1,3 +1,32 @@
 //! `flash.system` namespace
 
+use crate::avm2::activation::Activation;
+use crate::avm2::object::Object;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::fscommand;
+
 pub mod application_domain;
+pub mod loadercontext;
+pub mod system_class;
+
+/// Implements `flash.system.fscommand`
+pub fn fscommand<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let command = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_string(activation)?;
+    let args = match args.get(1).cloned().unwrap_or(Value::Undefined) {
+        Value::Undefined => "".into(),
+        args => args.coerce_to_string(activation)?,
+    };
+
+    fscommand::handle(&mut activation.context, &command, &args);
+
+    Ok(Value::Undefined)
+}
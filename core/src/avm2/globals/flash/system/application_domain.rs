@@ -2,9 +2,11 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::Class;
+use crate::avm2::exception::NativeError;
 use crate::avm2::method::Method;
 use crate::avm2::names::{Namespace, QName};
 use crate::avm2::object::{DomainObject, Object, TObject};
+use crate::avm2::string::AvmString;
 use crate::avm2::traits::Trait;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
@@ -72,6 +74,26 @@ pub fn parent_domain<'gc>(
     Ok(Value::Null)
 }
 
+/// Parse the name of a definition, as accepted by `getDefinition`.
+///
+/// Names may be qualified with their package, either as `package.Name` or
+/// `package::Name`.
+fn definition_name<'gc>(activation: &mut Activation<'_, 'gc, '_>, name: &str) -> QName<'gc> {
+    let mc = activation.context.gc_context;
+    let (package, local_name) = if let Some(index) = name.rfind("::") {
+        (&name[..index], &name[index + 2..])
+    } else if let Some(index) = name.rfind('.') {
+        (&name[..index], &name[index + 1..])
+    } else {
+        ("", name)
+    };
+
+    QName::new(
+        Namespace::package(AvmString::new(mc, package.to_string())),
+        AvmString::new(mc, local_name.to_string()),
+    )
+}
+
 /// `getDefinition` method
 pub fn get_definition<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
@@ -79,16 +101,22 @@ pub fn get_definition<'gc>(
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(appdomain) = this.and_then(|this| this.as_application_domain()) {
-        let local_name = args
+        let name = args
             .get(0)
             .cloned()
             .unwrap_or_else(|| "".into())
             .coerce_to_string(activation)?;
-        let qname = QName::new(Namespace::public_namespace(), local_name);
+        let qname = definition_name(activation, &name);
 
         let (qname, mut defined_script) = appdomain
             .get_defining_script(&qname.into())?
-            .ok_or_else(|| format!("No definition called {} exists", local_name))?;
+            .ok_or_else(|| {
+                NativeError::new(
+                    "ReferenceError",
+                    1065,
+                    format!("Variable {} is not defined.", name),
+                )
+            })?;
         let mut globals = defined_script.globals(&mut activation.context)?;
         let definition = globals.get_property(globals, &qname, activation)?;
 
@@ -105,12 +133,12 @@ pub fn has_definition<'gc>(
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(appdomain) = this.and_then(|this| this.as_application_domain()) {
-        let local_name = args
+        let name = args
             .get(0)
             .cloned()
             .unwrap_or_else(|| "".into())
             .coerce_to_string(activation)?;
-        let qname = QName::new(Namespace::public_namespace(), local_name);
+        let qname = definition_name(activation, &name);
 
         return Ok(appdomain.has_definition(qname).into());
     }
@@ -134,15 +162,15 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         QName::new(Namespace::public_namespace(), "currentDomain"),
         Method::from_builtin(current_domain),
     ));
-    write.define_class_trait(Trait::from_getter(
+    write.define_instance_trait(Trait::from_getter(
         QName::new(Namespace::public_namespace(), "parentDomain"),
         Method::from_builtin(parent_domain),
     ));
-    write.define_class_trait(Trait::from_method(
+    write.define_instance_trait(Trait::from_method(
         QName::new(Namespace::public_namespace(), "getDefinition"),
         Method::from_builtin(get_definition),
     ));
-    write.define_class_trait(Trait::from_method(
+    write.define_instance_trait(Trait::from_method(
         QName::new(Namespace::public_namespace(), "hasDefinition"),
         Method::from_builtin(has_definition),
     ));
@@ -0,0 +1,7 @@
+//! `flash.text` namespace
+
+pub mod textfield;
+pub mod textfieldautosize;
+pub mod textfieldtype;
+pub mod textformat;
+pub mod textformatalign;
@@ -0,0 +1,1021 @@
+//! `flash.text.TextField` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::exception::NativeError;
+use crate::avm2::globals::flash::text::textformat::{object_to_textformat, textformat_to_object};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::string::AvmString;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::display_object::{AutoSizeMode, EditText, TDisplayObject, TextSelection};
+use crate::html::TextFormat;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.text.TextField`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        if this.as_display_object().is_none() {
+            let movie = activation.context.swf.clone();
+            let new_do = EditText::new(&mut activation.context, movie, 0.0, 0.0, 100.0, 100.0);
+
+            new_do.set_object2(activation.context.gc_context, this);
+            this.init_display_object(activation.context.gc_context, new_do.into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.text.TextField`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Get the `EditText` that a `TextField` object represents.
+fn edit_text<'gc>(this: Option<Object<'gc>>) -> Option<EditText<'gc>> {
+    this.and_then(|this| this.as_display_object())
+        .and_then(|dobj| dobj.as_edit_text())
+}
+
+/// Resolve the `beginIndex` and `endIndex` arguments accepted by the
+/// `TextFormat` methods of `TextField`.
+///
+/// A negative begin index selects the whole text, and a negative end index
+/// selects the single character at the begin index.
+fn format_range<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    text_field: EditText<'gc>,
+    begin_index: Option<&Value<'gc>>,
+    end_index: Option<&Value<'gc>>,
+) -> Result<(usize, usize), Error> {
+    let begin_index = begin_index
+        .cloned()
+        .unwrap_or_else(|| (-1).into())
+        .coerce_to_i32(activation)?;
+    let end_index = end_index
+        .cloned()
+        .unwrap_or_else(|| (-1).into())
+        .coerce_to_i32(activation)?;
+
+    Ok(if begin_index < 0 {
+        (0, text_field.text_length())
+    } else if end_index < 0 {
+        (begin_index as usize, begin_index as usize + 1)
+    } else {
+        (begin_index as usize, end_index as usize)
+    })
+}
+
+/// Implements `TextField.text`'s getter.
+pub fn text<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(AvmString::new(activation.context.gc_context, this.text()).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.text`'s setter.
+pub fn set_text<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let text = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        this.set_text(text.to_string(), &mut activation.context)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.htmlText`'s getter.
+pub fn html_text<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let html_text = this.html_text(&mut activation.context)?;
+
+        return Ok(AvmString::new(activation.context.gc_context, html_text).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.htmlText`'s setter.
+///
+/// Unlike AVM1, setting HTML text always switches the field into HTML mode.
+pub fn set_html_text<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let html_text = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        this.set_is_html(&mut activation.context, true);
+        this.set_html_text(html_text.to_string(), &mut activation.context)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.length`'s getter.
+pub fn length<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.text_length().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.appendText`.
+pub fn append_text<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let text = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+        let length = this.text_length();
+
+        this.replace_text(length, length, &text, &mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.replaceText`.
+pub fn replace_text<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let begin_index = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+        let end_index = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+        let text = args
+            .get(2)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        this.replace_text(
+            begin_index as usize,
+            end_index as usize,
+            &text,
+            &mut activation.context,
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.replaceSelectedText`.
+pub fn replace_selected_text<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let text = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+        let selection = this
+            .selection()
+            .unwrap_or_else(|| TextSelection::for_position(0));
+
+        this.replace_text(
+            selection.start(),
+            selection.end(),
+            &text,
+            &mut activation.context,
+        );
+        this.set_selection(
+            Some(TextSelection::for_position(selection.start() + text.len())),
+            activation.context.gc_context,
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.defaultTextFormat`'s getter.
+pub fn default_text_format<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return textformat_to_object(activation, &this.new_text_format());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.defaultTextFormat`'s setter.
+pub fn set_default_text_format<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let format = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let format = object_to_textformat(activation, format)?;
+
+        this.set_new_text_format(format, &mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.getTextFormat`.
+pub fn get_text_format<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let (from, to) = format_range(activation, this, args.get(0), args.get(1))?;
+
+        return textformat_to_object(activation, &this.text_format(from, to));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.setTextFormat`.
+pub fn set_text_format<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let format = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let format = object_to_textformat(activation, format)?;
+        let (from, to) = format_range(activation, this, args.get(1), args.get(2))?;
+
+        this.set_text_format(from, to, format, &mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.textColor`'s getter.
+pub fn text_color<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        if let Some(color) = this.new_text_format().color {
+            return Ok(color.to_rgb().into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.textColor`'s setter.
+pub fn set_text_color<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let rgb = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+        let tf = TextFormat {
+            color: Some(swf::Color::from_rgb(rgb, 0xFF)),
+            ..TextFormat::default()
+        };
+
+        this.set_text_format(0, this.text_length(), tf.clone(), &mut activation.context);
+        this.set_new_text_format(tf, &mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.type`'s getter.
+pub fn type_<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(if this.is_editable() {
+            "input".into()
+        } else {
+            "dynamic".into()
+        });
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.type`'s setter.
+pub fn set_type<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let field_type = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        match field_type.as_str() {
+            "input" => this.set_editable(true, &mut activation.context),
+            "dynamic" => this.set_editable(false, &mut activation.context),
+            _ => {
+                return Err(NativeError::new(
+                    "ArgumentError",
+                    2008,
+                    "Parameter type must be one of the accepted values.",
+                )
+                .into())
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.autoSize`'s getter.
+pub fn auto_size<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(match this.autosize() {
+            AutoSizeMode::None => "none".into(),
+            AutoSizeMode::Left => "left".into(),
+            AutoSizeMode::Center => "center".into(),
+            AutoSizeMode::Right => "right".into(),
+        });
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.autoSize`'s setter.
+pub fn set_auto_size<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let auto_size = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        let auto_size = match auto_size.as_str() {
+            "none" => AutoSizeMode::None,
+            "left" => AutoSizeMode::Left,
+            "center" => AutoSizeMode::Center,
+            "right" => AutoSizeMode::Right,
+            _ => {
+                return Err(NativeError::new(
+                    "ArgumentError",
+                    2008,
+                    "Parameter autoSize must be one of the accepted values.",
+                )
+                .into())
+            }
+        };
+
+        this.set_autosize(auto_size, &mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.wordWrap`'s getter.
+pub fn word_wrap<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.is_word_wrap().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.wordWrap`'s setter.
+pub fn set_word_wrap<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let word_wrap = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_word_wrap(word_wrap, &mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.multiline`'s getter.
+pub fn multiline<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.is_multiline().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.multiline`'s setter.
+pub fn set_multiline<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let multiline = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_multiline(multiline, &mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.selectable`'s getter.
+pub fn selectable<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.is_selectable().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.selectable`'s setter.
+pub fn set_selectable<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let selectable = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_selectable(selectable, &mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.border`'s getter.
+pub fn border<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.has_border().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.border`'s setter.
+pub fn set_border<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let border = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        this.set_has_border(activation.context.gc_context, border);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.borderColor`'s getter.
+pub fn border_color<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.border_color().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.borderColor`'s setter.
+pub fn set_border_color<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let border_color = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+
+        this.set_border_color(activation.context.gc_context, border_color & 0xFFFFFF);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.backgroundColor`'s getter.
+pub fn background_color<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.background_color().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.backgroundColor`'s setter.
+pub fn set_background_color<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let background_color = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+
+        this.set_background_color(activation.context.gc_context, background_color & 0xFFFFFF);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.textWidth`'s getter.
+pub fn text_width<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let metrics = this.measure_text(&mut activation.context);
+
+        return Ok(metrics.0.to_pixels().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.textHeight`'s getter.
+pub fn text_height<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let metrics = this.measure_text(&mut activation.context);
+
+        return Ok(metrics.1.to_pixels().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.scrollV`'s getter.
+pub fn scroll_v<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.scroll_v().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.scrollV`'s setter.
+pub fn set_scroll_v<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let scroll_v = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        this.set_scroll_v(scroll_v.max(1) as usize, activation.context.gc_context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.maxScrollV`'s getter.
+pub fn max_scroll_v<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.max_scroll_v().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.scrollH`'s getter.
+pub fn scroll_h<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.scroll_h().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.scrollH`'s setter.
+pub fn set_scroll_h<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let scroll_h = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_number(activation)?;
+
+        this.set_scroll_h(scroll_h, activation.context.gc_context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.maxScrollH`'s getter.
+pub fn max_scroll_h<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.max_scroll_h().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.selectionBeginIndex`'s getter.
+pub fn selection_begin_index<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.selection().map(|s| s.start()).unwrap_or(0).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.selectionEndIndex`'s getter.
+pub fn selection_end_index<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.selection().map(|s| s.end()).unwrap_or(0).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.caretIndex`'s getter.
+pub fn caret_index<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        return Ok(this.selection().map(|s| s.to()).unwrap_or(0).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `TextField.setSelection`.
+pub fn set_selection<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = edit_text(this) {
+        let begin_index = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let end_index = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        this.set_selection(
+            Some(TextSelection::for_range(
+                begin_index.max(0) as usize,
+                end_index.max(0) as usize,
+            )),
+            activation.context.gc_context,
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `TextField`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.text"), "TextField"),
+        Some(QName::new(Namespace::package("flash.display"), "InteractiveObject").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "text"),
+        Method::from_builtin(text),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "text"),
+        Method::from_builtin(set_text),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "htmlText"),
+        Method::from_builtin(html_text),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "htmlText"),
+        Method::from_builtin(set_html_text),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "length"),
+        Method::from_builtin(length),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "defaultTextFormat"),
+        Method::from_builtin(default_text_format),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "defaultTextFormat"),
+        Method::from_builtin(set_default_text_format),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "textColor"),
+        Method::from_builtin(text_color),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "textColor"),
+        Method::from_builtin(set_text_color),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "type"),
+        Method::from_builtin(type_),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "type"),
+        Method::from_builtin(set_type),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "autoSize"),
+        Method::from_builtin(auto_size),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "autoSize"),
+        Method::from_builtin(set_auto_size),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "wordWrap"),
+        Method::from_builtin(word_wrap),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "wordWrap"),
+        Method::from_builtin(set_word_wrap),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "multiline"),
+        Method::from_builtin(multiline),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "multiline"),
+        Method::from_builtin(set_multiline),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "selectable"),
+        Method::from_builtin(selectable),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "selectable"),
+        Method::from_builtin(set_selectable),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "border"),
+        Method::from_builtin(border),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "border"),
+        Method::from_builtin(set_border),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "borderColor"),
+        Method::from_builtin(border_color),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "borderColor"),
+        Method::from_builtin(set_border_color),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "backgroundColor"),
+        Method::from_builtin(background_color),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "backgroundColor"),
+        Method::from_builtin(set_background_color),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "textWidth"),
+        Method::from_builtin(text_width),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "textHeight"),
+        Method::from_builtin(text_height),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "scrollV"),
+        Method::from_builtin(scroll_v),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "scrollV"),
+        Method::from_builtin(set_scroll_v),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "maxScrollV"),
+        Method::from_builtin(max_scroll_v),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "scrollH"),
+        Method::from_builtin(scroll_h),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "scrollH"),
+        Method::from_builtin(set_scroll_h),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "maxScrollH"),
+        Method::from_builtin(max_scroll_h),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "selectionBeginIndex"),
+        Method::from_builtin(selection_begin_index),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "selectionEndIndex"),
+        Method::from_builtin(selection_end_index),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "caretIndex"),
+        Method::from_builtin(caret_index),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "appendText"),
+        Method::from_builtin(append_text),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "replaceText"),
+        Method::from_builtin(replace_text),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "replaceSelectedText"),
+        Method::from_builtin(replace_selected_text),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "getTextFormat"),
+        Method::from_builtin(get_text_format),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "setTextFormat"),
+        Method::from_builtin(set_text_format),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "setSelection"),
+        Method::from_builtin(set_selection),
+    ));
+
+    class
+}
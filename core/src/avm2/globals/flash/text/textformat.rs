@@ -0,0 +1,321 @@
+//! `flash.text.TextFormat` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::class::Class;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{ArrayObject, Object, TObject};
+use crate::avm2::string::AvmString;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::html::TextFormat;
+use gc_arena::{GcCell, MutationContext};
+
+/// The names of each property of a `TextFormat`, in the order they are
+/// accepted by its constructor.
+const CONSTRUCTOR_PROPERTIES: &[&str] = &[
+    "font",
+    "size",
+    "color",
+    "bold",
+    "italic",
+    "underline",
+    "url",
+    "target",
+    "align",
+    "leftMargin",
+    "rightMargin",
+    "indent",
+    "leading",
+];
+
+/// The names of the properties of a `TextFormat` that cannot be set by its
+/// constructor.
+const OTHER_PROPERTIES: &[&str] = &[
+    "blockIndent",
+    "bullet",
+    "kerning",
+    "letterSpacing",
+    "tabStops",
+];
+
+/// Read one of the properties of a `TextFormat` object.
+///
+/// Properties that are `null` or `undefined` are unset, and will be returned
+/// as `None`.
+fn get_field<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut object: Object<'gc>,
+    name: &'static str,
+) -> Result<Option<Value<'gc>>, Error> {
+    match object.get_property(
+        object,
+        &QName::new(Namespace::public_namespace(), name),
+        activation,
+    )? {
+        Value::Undefined | Value::Null => Ok(None),
+        value => Ok(Some(value)),
+    }
+}
+
+fn get_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<Option<String>, Error> {
+    match get_field(activation, object, name)? {
+        Some(value) => Ok(Some(value.coerce_to_string(activation)?.to_string())),
+        None => Ok(None),
+    }
+}
+
+fn get_number<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<Option<f64>, Error> {
+    match get_field(activation, object, name)? {
+        Some(value) => Ok(Some(value.coerce_to_number(activation)?)),
+        None => Ok(None),
+    }
+}
+
+fn get_bool<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<Option<bool>, Error> {
+    Ok(get_field(activation, object, name)?.map(|value| value.coerce_to_boolean()))
+}
+
+#[allow(clippy::map_clone)] //You can't clone `Option<Ref<T>>` without it
+fn get_number_array<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<Option<Vec<f64>>, Error> {
+    let array = match get_field(activation, object, name)? {
+        Some(value) => value
+            .coerce_to_object(activation)?
+            .as_array_storage()
+            .map(|a| a.clone()),
+        None => None,
+    };
+
+    if let Some(array) = array {
+        let mut output = Vec::with_capacity(array.length());
+        for value in array.iter() {
+            output.push(
+                value
+                    .unwrap_or(Value::Undefined)
+                    .coerce_to_number(activation)?,
+            );
+        }
+
+        return Ok(Some(output));
+    }
+
+    Ok(None)
+}
+
+/// Write one of the properties of a `TextFormat` object, leaving it `null`
+/// if unset.
+fn set_field<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut object: Object<'gc>,
+    name: &'static str,
+    value: Option<Value<'gc>>,
+) -> Result<(), Error> {
+    object.set_property(
+        object,
+        &QName::new(Namespace::public_namespace(), name),
+        value.unwrap_or(Value::Null),
+        activation,
+    )
+}
+
+/// Read a `TextFormat` object as a text span format.
+pub fn object_to_textformat<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+) -> Result<TextFormat, Error> {
+    Ok(TextFormat {
+        font: get_string(activation, object, "font")?,
+        size: get_number(activation, object, "size")?,
+        color: get_number(activation, object, "color")?
+            .map(|v| swf::Color::from_rgb(v as u32, 0xFF)),
+        align: get_string(activation, object, "align")?.and_then(|v| {
+            match v.to_lowercase().as_str() {
+                "left" => Some(swf::TextAlign::Left),
+                "center" => Some(swf::TextAlign::Center),
+                "right" => Some(swf::TextAlign::Right),
+                "justify" => Some(swf::TextAlign::Justify),
+                _ => None,
+            }
+        }),
+        bold: get_bool(activation, object, "bold")?,
+        italic: get_bool(activation, object, "italic")?,
+        underline: get_bool(activation, object, "underline")?,
+        left_margin: get_number(activation, object, "leftMargin")?,
+        right_margin: get_number(activation, object, "rightMargin")?,
+        indent: get_number(activation, object, "indent")?,
+        block_indent: get_number(activation, object, "blockIndent")?,
+        kerning: get_bool(activation, object, "kerning")?,
+        leading: get_number(activation, object, "leading")?,
+        letter_spacing: get_number(activation, object, "letterSpacing")?,
+        tab_stops: get_number_array(activation, object, "tabStops")?,
+        bullet: get_bool(activation, object, "bullet")?,
+        url: get_string(activation, object, "url")?,
+        target: get_string(activation, object, "target")?,
+    })
+}
+
+/// Construct a new `TextFormat` object from a text span format.
+pub fn textformat_to_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    tf: &TextFormat,
+) -> Result<Value<'gc>, Error> {
+    let proto = activation.context.avm2.prototypes().textformat;
+    let object = Object::construct_from_proto(activation, proto, &[])?;
+    let mc = activation.context.gc_context;
+    let string = |v: &Option<String>| v.clone().map(|v| AvmString::new(mc, v).into());
+
+    set_field(activation, object, "font", string(&tf.font))?;
+    set_field(activation, object, "size", tf.size.map(Value::from))?;
+    set_field(
+        activation,
+        object,
+        "color",
+        tf.color
+            .as_ref()
+            .map(|v| (((v.r as u32) << 16) + ((v.g as u32) << 8) + v.b as u32).into()),
+    )?;
+    set_field(
+        activation,
+        object,
+        "align",
+        tf.align.map(|v| {
+            match v {
+                swf::TextAlign::Left => "left",
+                swf::TextAlign::Center => "center",
+                swf::TextAlign::Right => "right",
+                swf::TextAlign::Justify => "justify",
+            }
+            .into()
+        }),
+    )?;
+    set_field(activation, object, "bold", tf.bold.map(Value::from))?;
+    set_field(activation, object, "italic", tf.italic.map(Value::from))?;
+    set_field(
+        activation,
+        object,
+        "underline",
+        tf.underline.map(Value::from),
+    )?;
+    set_field(
+        activation,
+        object,
+        "leftMargin",
+        tf.left_margin.map(Value::from),
+    )?;
+    set_field(
+        activation,
+        object,
+        "rightMargin",
+        tf.right_margin.map(Value::from),
+    )?;
+    set_field(activation, object, "indent", tf.indent.map(Value::from))?;
+    set_field(
+        activation,
+        object,
+        "blockIndent",
+        tf.block_indent.map(Value::from),
+    )?;
+    set_field(activation, object, "kerning", tf.kerning.map(Value::from))?;
+    set_field(activation, object, "leading", tf.leading.map(Value::from))?;
+    set_field(
+        activation,
+        object,
+        "letterSpacing",
+        tf.letter_spacing.map(Value::from),
+    )?;
+    set_field(activation, object, "bullet", tf.bullet.map(Value::from))?;
+    set_field(activation, object, "url", string(&tf.url))?;
+    set_field(activation, object, "target", string(&tf.target))?;
+
+    let tab_stops = tf.tab_stops.as_ref().map(|tab_stops| {
+        let tab_stops: Vec<Value<'gc>> = tab_stops.iter().map(|v| (*v).into()).collect();
+
+        ArrayObject::from_array(
+            ArrayStorage::from_args(&tab_stops),
+            activation.context.avm2.prototypes().array,
+            activation.context.gc_context,
+        )
+        .into()
+    });
+    set_field(activation, object, "tabStops", tab_stops)?;
+
+    Ok(object.into())
+}
+
+/// Implements `flash.text.TextFormat`'s instance constructor.
+///
+/// Properties that are omitted or `undefined` are left unset.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        for (i, name) in CONSTRUCTOR_PROPERTIES.iter().enumerate() {
+            let value = match args.get(i) {
+                Some(Value::Undefined) | Some(Value::Null) | None => None,
+                Some(value) => Some(value.clone()),
+            };
+
+            set_field(activation, this, *name, value)?;
+        }
+
+        for name in OTHER_PROPERTIES {
+            set_field(activation, this, *name, None)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.text.TextFormat`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Construct `TextFormat`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.text"), "TextFormat"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    for name in CONSTRUCTOR_PROPERTIES.iter().chain(OTHER_PROPERTIES.iter()) {
+        write.define_instance_trait(Trait::from_slot(
+            QName::new(Namespace::public_namespace(), *name),
+            Multiname::any(),
+            None,
+        ));
+    }
+
+    class
+}
@@ -0,0 +1,87 @@
+//! `flash.utils` namespace
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::Object;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::timer::TimerCallback;
+
+pub mod bytearray;
+pub mod dictionary;
+pub mod endian;
+pub mod timer;
+
+/// Implements `flash.utils.getTimer`
+pub fn get_timer<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let time = activation.context.navigator.time_since_launch().as_millis() as u32;
+
+    Ok(time.into())
+}
+
+/// Register a timer for `setTimeout` or `setInterval`.
+fn create_timer<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    is_timeout: bool,
+) -> Result<Value<'gc>, Error> {
+    let callable = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_object(activation)?;
+    let interval = args
+        .get(1)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_i32(activation)?;
+    let params = args.get(2..).unwrap_or_default().to_vec();
+
+    let id = activation.context.timers.add_timer(
+        TimerCallback::Avm2Callable { callable, params },
+        interval,
+        is_timeout,
+    );
+
+    Ok((id as u32).into())
+}
+
+/// Implements `flash.utils.setTimeout`
+pub fn set_timeout<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    create_timer(activation, args, true)
+}
+
+/// Implements `flash.utils.setInterval`
+pub fn set_interval<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    create_timer(activation, args, false)
+}
+
+/// Implements `flash.utils.clearTimeout` and `flash.utils.clearInterval`
+pub fn clear_timer<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let id = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_i32(activation)?;
+
+    if !activation.context.timers.remove(id) {
+        log::info!("clearInterval: Timer {} does not exist", id);
+    }
+
+    Ok(Value::Undefined)
+}
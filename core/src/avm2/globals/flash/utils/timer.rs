@@ -0,0 +1,399 @@
+//! `flash.utils.Timer` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::events::Event;
+use crate::avm2::exception::NativeError;
+use crate::avm2::globals::flash::events::eventdispatcher::dispatch_event;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{EventObject, FunctionObject, Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::timer::TimerCallback;
+use gc_arena::{GcCell, MutationContext};
+
+const NS_TIMER: &str = "https://ruffle.rs/AS3/impl/Timer/";
+
+/// Get the name of one of `Timer`'s internal slots.
+fn internal<'gc>(name: &'static str) -> QName<'gc> {
+    QName::new(Namespace::private(NS_TIMER), name)
+}
+
+/// Validate a timer delay, in milliseconds.
+fn check_delay(delay: f64) -> Result<f64, Error> {
+    if delay < 0.0 || !delay.is_finite() {
+        return Err(NativeError::new(
+            "RangeError",
+            2066,
+            "The Timer delay specified is out of range.",
+        )
+        .into());
+    }
+
+    Ok(delay)
+}
+
+/// Implements `flash.utils.Timer`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let delay = check_delay(
+            args.get(0)
+                .cloned()
+                .unwrap_or(Value::Undefined)
+                .coerce_to_number(activation)?,
+        )?;
+        let repeat_count = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Integer(0))
+            .coerce_to_i32(activation)?;
+
+        this.init_property(this, &internal("delay"), delay.into(), activation)?;
+        this.init_property(
+            this,
+            &internal("repeat_count"),
+            repeat_count.into(),
+            activation,
+        )?;
+        this.init_property(this, &internal("current_count"), 0.into(), activation)?;
+        this.init_property(this, &internal("timer_id"), Value::Null, activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.utils.Timer`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Fire a `TimerEvent` of a given type on a timer.
+fn dispatch_timer_event<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    event_type: &'static str,
+) -> Result<(), Error> {
+    let event_proto = activation.context.avm2.prototypes().timer_event;
+    let event = EventObject::from_event(
+        activation.context.gc_context,
+        Some(event_proto),
+        Event::new(event_type),
+    );
+
+    dispatch_event(activation, Some(this), &[event.into()])?;
+
+    Ok(())
+}
+
+/// Called by the player's timer queue each time a running `Timer` ticks.
+fn tick<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let current_count = this
+            .get_property(this, &internal("current_count"), activation)?
+            .coerce_to_i32(activation)?
+            .wrapping_add(1);
+        this.set_property(
+            this,
+            &internal("current_count"),
+            current_count.into(),
+            activation,
+        )?;
+
+        dispatch_timer_event(activation, this, "timer")?;
+
+        let repeat_count = this
+            .get_property(this, &internal("repeat_count"), activation)?
+            .coerce_to_i32(activation)?;
+        let is_running = !matches!(
+            this.get_property(this, &internal("timer_id"), activation)?,
+            Value::Null
+        );
+
+        if is_running && repeat_count > 0 && current_count >= repeat_count {
+            stop(activation, Some(this), &[])?;
+            dispatch_timer_event(activation, this, "timerComplete")?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.start`
+pub fn start<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        if !matches!(
+            this.get_property(this, &internal("timer_id"), activation)?,
+            Value::Null
+        ) {
+            return Ok(Value::Undefined);
+        }
+
+        let delay = this
+            .get_property(this, &internal("delay"), activation)?
+            .coerce_to_number(activation)?;
+        let fn_proto = activation.context.avm2.prototypes().function;
+        let callable = FunctionObject::from_method(
+            activation.context.gc_context,
+            Method::from_builtin(tick),
+            None,
+            fn_proto,
+            Some(this),
+        );
+        let id = activation.context.timers.add_timer(
+            TimerCallback::Avm2Callable {
+                callable,
+                params: Vec::new(),
+            },
+            delay as i32,
+            false,
+        );
+
+        this.set_property(this, &internal("timer_id"), id.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.stop`
+pub fn stop<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let timer_id = this.get_property(this, &internal("timer_id"), activation)?;
+
+        if !matches!(timer_id, Value::Null) {
+            let id = timer_id.coerce_to_i32(activation)?;
+            activation.context.timers.remove(id);
+            this.set_property(this, &internal("timer_id"), Value::Null, activation)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.reset`
+pub fn reset<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        stop(activation, Some(this), &[])?;
+        this.set_property(this, &internal("current_count"), 0.into(), activation)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.currentCount`'s getter
+pub fn current_count<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("current_count"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.delay`'s getter
+pub fn delay<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("delay"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.delay`'s setter
+///
+/// Running timers are restarted with the new delay.
+pub fn set_delay<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let delay = check_delay(
+            args.get(0)
+                .cloned()
+                .unwrap_or(Value::Undefined)
+                .coerce_to_number(activation)?,
+        )?;
+
+        this.set_property(this, &internal("delay"), delay.into(), activation)?;
+
+        if !matches!(
+            this.get_property(this, &internal("timer_id"), activation)?,
+            Value::Null
+        ) {
+            stop(activation, Some(this), &[])?;
+            start(activation, Some(this), &[])?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.repeatCount`'s getter
+pub fn repeat_count<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        return this.get_property(this, &internal("repeat_count"), activation);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.repeatCount`'s setter
+///
+/// Lowering the repeat count below the number of ticks that have already
+/// happened stops the timer.
+pub fn set_repeat_count<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let repeat_count = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Integer(0))
+            .coerce_to_i32(activation)?;
+
+        this.set_property(
+            this,
+            &internal("repeat_count"),
+            repeat_count.into(),
+            activation,
+        )?;
+
+        let current_count = this
+            .get_property(this, &internal("current_count"), activation)?
+            .coerce_to_i32(activation)?;
+        if repeat_count > 0 && current_count >= repeat_count {
+            stop(activation, Some(this), &[])?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.running`'s getter
+pub fn running<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let timer_id = this.get_property(this, &internal("timer_id"), activation)?;
+
+        return Ok((!matches!(timer_id, Value::Null)).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `Timer`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.utils"), "Timer"),
+        Some(QName::new(Namespace::package("flash.events"), "EventDispatcher").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "start"),
+        Method::from_builtin(start),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "stop"),
+        Method::from_builtin(stop),
+    ));
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "reset"),
+        Method::from_builtin(reset),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "currentCount"),
+        Method::from_builtin(current_count),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "delay"),
+        Method::from_builtin(delay),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "delay"),
+        Method::from_builtin(set_delay),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "repeatCount"),
+        Method::from_builtin(repeat_count),
+    ));
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "repeatCount"),
+        Method::from_builtin(set_repeat_count),
+    ));
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "running"),
+        Method::from_builtin(running),
+    ));
+
+    write.define_instance_trait(Trait::from_slot(
+        internal("delay"),
+        QName::new(Namespace::public_namespace(), "Number").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("repeat_count"),
+        QName::new(Namespace::public_namespace(), "int").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("current_count"),
+        QName::new(Namespace::public_namespace(), "int").into(),
+        None,
+    ));
+    write.define_instance_trait(Trait::from_slot(
+        internal("timer_id"),
+        Multiname::any(),
+        None,
+    ));
+
+    class
+}
@@ -2,6 +2,7 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::exception::NativeError;
 use crate::avm2::method::Method;
 use crate::avm2::names::{Multiname, Namespace, QName};
 use crate::avm2::object::Object;
@@ -60,8 +61,7 @@ pub fn instance_init<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    // TODO: Replace with actual error type.
-    Err("TypeError: Error #1076: Math is not a constructor.".into())
+    Err(NativeError::new("TypeError", 1076, "Math is not a constructor.").into())
 }
 
 /// Implements `Math`'s class initializer.
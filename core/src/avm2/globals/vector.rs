@@ -0,0 +1,872 @@
+//! `Vector` class
+
+use crate::avm2::activation::Activation;
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::globals::array::{
+    build_array, compare_numeric, compare_string_case_insensitive, compare_string_case_sensitive,
+    constrain, every, for_each, resolve_index, some, sort_inner, ArrayIter, SortOptions,
+};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject, VectorObject};
+use crate::avm2::string::AvmString;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::vector::VectorStorage;
+use crate::avm2::Error;
+use enumset::EnumSet;
+use gc_arena::{GcCell, MutationContext};
+use std::cmp::{min, Ordering};
+
+/// Implements `Vector`'s instance initializer.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        let length = args
+            .get(0)
+            .cloned()
+            .unwrap_or_else(|| 0.into())
+            .coerce_to_u32(activation)? as usize;
+        let is_fixed = args
+            .get(1)
+            .cloned()
+            .unwrap_or_else(|| false.into())
+            .coerce_to_boolean();
+
+        if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+            vector.set_is_fixed(false);
+            vector.resize(length)?;
+            vector.set_is_fixed(is_fixed);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector`'s class initializer.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Get the value type of a vector object.
+fn value_type_of<'gc>(this: Object<'gc>) -> Option<Object<'gc>> {
+    this.as_vector_storage().and_then(|v| v.value_type())
+}
+
+/// Copy out the values of a vector object.
+///
+/// This allows operating on the contents of a vector while also running user
+/// code that may access the vector.
+fn values_of<'gc>(this: Object<'gc>) -> Option<Vec<Value<'gc>>> {
+    this.as_vector_storage().map(|v| v.iter().collect())
+}
+
+/// Bundle a list of already-coerced values in an `Object` of the same vector
+/// type as `this`.
+fn build_vector_like<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    values: Vec<Value<'gc>>,
+    value_type: Option<Object<'gc>>,
+) -> Result<Value<'gc>, Error> {
+    let proto: Result<Object<'gc>, Error> = this
+        .proto()
+        .ok_or_else(|| "Vector instance has no prototype".into());
+    let vector = VectorStorage::from_values(values, false, value_type, activation);
+
+    Ok(VectorObject::from_vector(vector, proto?, activation.context.gc_context).into())
+}
+
+/// Implements `Vector.length`'s getter
+pub fn length<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(vector) = this.as_vector_storage() {
+            return Ok(vector.length().into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.length`'s setter
+pub fn set_length<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let new_length = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+
+        if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+            vector.resize(new_length as usize)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.fixed`'s getter
+pub fn fixed<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(vector) = this.as_vector_storage() {
+            return Ok(vector.is_fixed().into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.fixed`'s setter
+pub fn set_fixed<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let is_fixed = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+
+        if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+            vector.set_is_fixed(is_fixed);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.concat`
+pub fn concat<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(mut values) = values_of(this) {
+            let value_type = value_type_of(this);
+
+            for arg in args {
+                let other_values = values_of(arg.coerce_to_object(activation)?);
+                let other_values: Result<Vec<Value<'gc>>, Error> = other_values
+                    .ok_or_else(|| "TypeError: Vector.concat only accepts Vectors".into());
+
+                for value in other_values? {
+                    values.push(VectorStorage::coerce(value, value_type, activation)?);
+                }
+            }
+
+            return build_vector_like(activation, this, values, value_type);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn join_inner<'gc, 'a, 'ctxt, C>(
+    activation: &mut Activation<'a, 'gc, 'ctxt>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+    mut conv: C,
+) -> Result<Value<'gc>, Error>
+where
+    C: for<'b> FnMut(Value<'gc>, &'b mut Activation<'a, 'gc, 'ctxt>) -> Result<Value<'gc>, Error>,
+{
+    let mut separator = args.get(0).cloned().unwrap_or(Value::Undefined);
+    if separator == Value::Undefined {
+        separator = ",".into();
+    }
+
+    if let Some(this) = this {
+        if let Some(values) = values_of(this) {
+            let string_separator = separator.coerce_to_string(activation)?;
+            let mut accum = Vec::with_capacity(values.len());
+
+            for item in values {
+                if matches!(item, Value::Undefined) || matches!(item, Value::Null) {
+                    accum.push("".into());
+                } else {
+                    accum.push(
+                        conv(item, activation)?
+                            .coerce_to_string(activation)?
+                            .to_string(),
+                    );
+                }
+            }
+
+            return Ok(AvmString::new(
+                activation.context.gc_context,
+                accum.join(&string_separator),
+            )
+            .into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.join`
+pub fn join<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    join_inner(activation, this, args, |v, _act| Ok(v))
+}
+
+/// Implements `Vector.toString`
+pub fn to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    join_inner(activation, this, &[",".into()], |v, _act| Ok(v))
+}
+
+/// Implements `Vector.toLocaleString`
+pub fn to_locale_string<'gc>(
+    act: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    join_inner(act, this, &[",".into()], |v, activation| {
+        let mut o = v.coerce_to_object(activation)?;
+
+        let tls = o.get_property(
+            o,
+            &QName::new(Namespace::public_namespace(), "toLocaleString"),
+            activation,
+        )?;
+
+        tls.coerce_to_object(activation)?
+            .call(Some(o), &[], activation, o.proto())
+    })
+}
+
+/// Implements `Vector.map`
+pub fn map<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let callback = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let receiver = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Null)
+            .coerce_to_object(activation)
+            .ok();
+        let value_type = value_type_of(this);
+        let mut new_values = Vec::new();
+        let mut iter = ArrayIter::new(activation, this)?;
+
+        while let Some(r) = iter.next(activation) {
+            let (i, item) = r?;
+            let new_item = callback.call(
+                receiver,
+                &[item, i.into(), this.into()],
+                activation,
+                receiver.and_then(|r| r.proto()),
+            )?;
+
+            new_values.push(VectorStorage::coerce(new_item, value_type, activation)?);
+        }
+
+        return build_vector_like(activation, this, new_values, value_type);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.filter`
+pub fn filter<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let callback = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+        let receiver = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Null)
+            .coerce_to_object(activation)
+            .ok();
+        let value_type = value_type_of(this);
+        let mut new_values = Vec::new();
+        let mut iter = ArrayIter::new(activation, this)?;
+
+        while let Some(r) = iter.next(activation) {
+            let (i, item) = r?;
+            let is_allowed = callback
+                .call(
+                    receiver,
+                    &[item.clone(), i.into(), this.into()],
+                    activation,
+                    receiver.and_then(|r| r.proto()),
+                )?
+                .coerce_to_boolean();
+
+            if is_allowed {
+                new_values.push(item);
+            }
+        }
+
+        return build_vector_like(activation, this, new_values, value_type);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.indexOf`
+pub fn index_of<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(values) = values_of(this) {
+            let search_val = VectorStorage::coerce(
+                args.get(0).cloned().unwrap_or(Value::Undefined),
+                value_type_of(this),
+                activation,
+            )?;
+            let from = resolve_index(
+                activation,
+                args.get(1).cloned().unwrap_or_else(|| 0.into()),
+                values.len(),
+            )?;
+
+            for (i, val) in values.iter().enumerate().skip(from) {
+                if *val == search_val {
+                    return Ok(i.into());
+                }
+            }
+
+            return Ok((-1).into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.lastIndexOf`
+pub fn last_index_of<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(values) = values_of(this) {
+            let search_val = VectorStorage::coerce(
+                args.get(0).cloned().unwrap_or(Value::Undefined),
+                value_type_of(this),
+                activation,
+            )?;
+            let from = resolve_index(
+                activation,
+                args.get(1).cloned().unwrap_or_else(|| i32::MAX.into()),
+                values.len(),
+            )?;
+
+            for (i, val) in values.iter().enumerate().rev() {
+                if i <= from && *val == search_val {
+                    return Ok(i.into());
+                }
+            }
+
+            return Ok((-1).into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.pop`
+pub fn pop<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+            return vector.pop();
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.push`
+pub fn push<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let value_type = value_type_of(this);
+
+        for arg in args {
+            let value = VectorStorage::coerce(arg.clone(), value_type, activation)?;
+
+            if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+                vector.push(value)?;
+            }
+        }
+
+        return length(activation, Some(this), &[]);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.shift`
+pub fn shift<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+            return vector.shift();
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.unshift`
+pub fn unshift<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let value_type = value_type_of(this);
+
+        for arg in args.iter().rev() {
+            let value = VectorStorage::coerce(arg.clone(), value_type, activation)?;
+
+            if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+                vector.unshift(value)?;
+            }
+        }
+
+        return length(activation, Some(this), &[]);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.insertAt`
+pub fn insert_at<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(vector_length) = this.as_vector_storage().map(|v| v.length()) {
+            let index = resolve_index(
+                activation,
+                args.get(0).cloned().unwrap_or_else(|| 0.into()),
+                vector_length,
+            )?;
+            let value = VectorStorage::coerce(
+                args.get(1).cloned().unwrap_or(Value::Undefined),
+                value_type_of(this),
+                activation,
+            )?;
+
+            if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+                vector.insert(index, value)?;
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.removeAt`
+pub fn remove_at<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(vector_length) = this.as_vector_storage().map(|v| v.length()) {
+            let index = resolve_index(
+                activation,
+                args.get(0).cloned().unwrap_or_else(|| 0.into()),
+                vector_length,
+            )?;
+
+            if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+                return vector.remove(index);
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.reverse`
+pub fn reverse<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+            let values = vector.iter().rev().collect();
+
+            vector.replace_values(values);
+
+            return Ok(this.into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.slice`
+pub fn slice<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(values) = values_of(this) {
+            let actual_start = resolve_index(
+                activation,
+                args.get(0).cloned().unwrap_or_else(|| 0.into()),
+                values.len(),
+            )?;
+            let actual_end = resolve_index(
+                activation,
+                args.get(1).cloned().unwrap_or_else(|| 0xFFFFFF.into()),
+                values.len(),
+            )?;
+            let actual_end = min(actual_end, values.len());
+            let new_values = if actual_start < actual_end {
+                values[actual_start..actual_end].to_vec()
+            } else {
+                Vec::new()
+            };
+
+            return build_vector_like(activation, this, new_values, value_type_of(this));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.splice`
+pub fn splice<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(mut values) = values_of(this) {
+            let value_type = value_type_of(this);
+            let actual_start = min(
+                resolve_index(
+                    activation,
+                    args.get(0).cloned().unwrap_or_else(|| 0.into()),
+                    values.len(),
+                )?,
+                values.len(),
+            );
+            let delete_count = args
+                .get(1)
+                .cloned()
+                .unwrap_or_else(|| values.len().into())
+                .coerce_to_u32(activation)? as usize;
+            let actual_end = min(values.len(), actual_start.saturating_add(delete_count));
+
+            let mut insertions = Vec::new();
+            for arg in args.iter().skip(2) {
+                insertions.push(VectorStorage::coerce(arg.clone(), value_type, activation)?);
+            }
+
+            let is_fixed = this
+                .as_vector_storage()
+                .map(|v| v.is_fixed())
+                .unwrap_or(false);
+            if is_fixed && insertions.len() != actual_end - actual_start {
+                return Err("RangeError: Vector is fixed".into());
+            }
+
+            let removed = values
+                .splice(actual_start..actual_end, insertions)
+                .collect::<Vec<Value<'gc>>>();
+
+            if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+                vector.replace_values(values);
+            }
+
+            return build_vector_like(activation, this, removed, value_type);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Vector.sort`
+pub fn sort<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let (compare_fnc, options) = match args.get(0) {
+            Some(Value::Object(o)) if o.as_executable().is_some() => (Some(*o), EnumSet::empty()),
+            Some(v) => (None, v.coerce_to_enumset(activation)?),
+            None => (None, EnumSet::empty()),
+        };
+
+        let mut values = if let Some(values) = values_of(this) {
+            values
+                .into_iter()
+                .enumerate()
+                .collect::<Vec<(usize, Value<'gc>)>>()
+        } else {
+            return Ok(0.into());
+        };
+
+        let unique_satisfied = if let Some(v) = compare_fnc {
+            sort_inner(
+                activation,
+                &mut values,
+                options,
+                constrain(|activation, a, b| {
+                    let order = v
+                        .call(None, &[a, b], activation, None)?
+                        .coerce_to_number(activation)?;
+
+                    if order > 0.0 {
+                        Ok(Ordering::Greater)
+                    } else if order < 0.0 {
+                        Ok(Ordering::Less)
+                    } else {
+                        Ok(Ordering::Equal)
+                    }
+                }),
+            )?
+        } else if options.contains(SortOptions::Numeric) {
+            sort_inner(activation, &mut values, options, compare_numeric)?
+        } else if options.contains(SortOptions::CaseInsensitive) {
+            sort_inner(
+                activation,
+                &mut values,
+                options,
+                compare_string_case_insensitive,
+            )?
+        } else {
+            sort_inner(
+                activation,
+                &mut values,
+                options,
+                compare_string_case_sensitive,
+            )?
+        };
+
+        if !unique_satisfied {
+            return Ok(0.into());
+        }
+
+        if options.contains(SortOptions::ReturnIndexedArray) {
+            return build_array(
+                activation,
+                ArrayStorage::from_storage(
+                    values.iter().map(|(i, _v)| Some((*i).into())).collect(),
+                ),
+            );
+        }
+
+        if let Some(mut vector) = this.as_vector_storage_mut(activation.context.gc_context) {
+            vector.replace_values(values.into_iter().map(|(_i, v)| v).collect());
+        }
+
+        return Ok(this.into());
+    }
+
+    Ok(0.into())
+}
+
+/// Construct `Vector`'s class.
+///
+/// This is the generic `Vector` class; AS3 code is expected to apply a type
+/// parameter to it (e.g. `Vector.<int>`) before use.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("__AS3__.vec"), "Vector"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::Generic.into());
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "length"),
+        Method::from_builtin(length),
+    ));
+
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "length"),
+        Method::from_builtin(set_length),
+    ));
+
+    write.define_instance_trait(Trait::from_getter(
+        QName::new(Namespace::public_namespace(), "fixed"),
+        Method::from_builtin(fixed),
+    ));
+
+    write.define_instance_trait(Trait::from_setter(
+        QName::new(Namespace::public_namespace(), "fixed"),
+        Method::from_builtin(set_fixed),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "concat"),
+        Method::from_builtin(concat),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "join"),
+        Method::from_builtin(join),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "toString"),
+        Method::from_builtin(to_string),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "toLocaleString"),
+        Method::from_builtin(to_locale_string),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "forEach"),
+        Method::from_builtin(for_each),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "map"),
+        Method::from_builtin(map),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "filter"),
+        Method::from_builtin(filter),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "every"),
+        Method::from_builtin(every),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "some"),
+        Method::from_builtin(some),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "indexOf"),
+        Method::from_builtin(index_of),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "lastIndexOf"),
+        Method::from_builtin(last_index_of),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "pop"),
+        Method::from_builtin(pop),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "push"),
+        Method::from_builtin(push),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "shift"),
+        Method::from_builtin(shift),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "unshift"),
+        Method::from_builtin(unshift),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "insertAt"),
+        Method::from_builtin(insert_at),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "removeAt"),
+        Method::from_builtin(remove_at),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "reverse"),
+        Method::from_builtin(reverse),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "slice"),
+        Method::from_builtin(slice),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "splice"),
+        Method::from_builtin(splice),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "sort"),
+        Method::from_builtin(sort),
+    ));
+
+    class
+}
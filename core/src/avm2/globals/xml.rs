@@ -0,0 +1,472 @@
+//! `XML` class
+
+use crate::avm1::AvmString;
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::e4x::{self, E4XNode};
+use crate::avm2::exception::NativeError;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `XML`'s instance initializer.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        let value = args.get(0).cloned().unwrap_or(Value::Undefined);
+        let nodes = match &value {
+            Value::Undefined | Value::Null => Vec::new(),
+            Value::Object(o) if e4x::nodes_of(*o).is_some() => e4x::nodes_of(*o)
+                .unwrap_or_default()
+                .iter()
+                .map(|node| E4XNode::Node(node.deep_copy(activation.context.gc_context)))
+                .collect(),
+            value => {
+                let source = value.coerce_to_string(activation)?;
+                E4XNode::parse(activation.context.gc_context, &source)?
+            }
+        };
+
+        if nodes.len() > 1 {
+            return Err(NativeError::new(
+                "TypeError",
+                1088,
+                "The markup in the document following the root element must be well-formed.",
+            )
+            .into());
+        }
+
+        let node = match nodes.first() {
+            Some(node) => node.clone(),
+            None => E4XNode::text(activation.context.gc_context, ""),
+        };
+
+        if let Some(mut this_node) = this.as_xml_mut(activation.context.gc_context) {
+            *this_node = node;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XML`'s class initializer.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Copy out the nodes of an `XML` or `XMLList` object.
+///
+/// All E4X methods below are shared between `XML` and `XMLList`; a lone XML
+/// node behaves as a list of one node.
+fn nodes_of<'gc>(this: Option<Object<'gc>>) -> Vec<E4XNode<'gc>> {
+    this.and_then(e4x::nodes_of).unwrap_or_default()
+}
+
+/// Resolve a name argument of an E4X method into a multiname.
+///
+/// Absent names match everything.
+fn name_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    is_attribute: bool,
+) -> Result<Multiname<'gc>, Error> {
+    let name = match args.get(0) {
+        None | Some(Value::Undefined) => "*".into(),
+        Some(name) => name.coerce_to_string(activation)?,
+    };
+
+    Ok(Multiname::from_xml_name(name, is_attribute))
+}
+
+/// Implements `XML.toString` and `XMLList.toString`
+pub fn to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let string = e4x::list_to_string(&nodes_of(this));
+
+    Ok(AvmString::new(activation.context.gc_context, string).into())
+}
+
+/// Implements `XML.toXMLString` and `XMLList.toXMLString`
+pub fn to_xml_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let string = e4x::list_to_xml_string(&nodes_of(this));
+
+    Ok(AvmString::new(activation.context.gc_context, string).into())
+}
+
+/// Implements `XML.length` and `XMLList.length`
+pub fn length<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(nodes_of(this).len().into())
+}
+
+/// Implements `XML.children` and `XMLList.children`
+pub fn children<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let children = nodes_of(this)
+        .iter()
+        .flat_map(|node| node.children())
+        .collect();
+
+    Ok(e4x::build_xml_list(activation, children))
+}
+
+/// Implements `XML.child` and `XMLList.child`
+pub fn child<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let nodes = nodes_of(this);
+    let name = name_arg(activation, args, false)?;
+
+    if let Some(index) = name.local_name().and_then(|n| n.parse::<usize>().ok()) {
+        let children = nodes
+            .iter()
+            .filter_map(|node| node.children().get(index).cloned())
+            .collect();
+
+        return Ok(e4x::build_xml_list(activation, children));
+    }
+
+    let children = e4x::get_property(&nodes, &name, None);
+
+    Ok(e4x::build_xml_list(activation, children))
+}
+
+/// Implements `XML.elements` and `XMLList.elements`
+pub fn elements<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let name = name_arg(activation, args, false)?;
+    let elements = e4x::get_property(&nodes_of(this), &name, None)
+        .into_iter()
+        .filter(|node| node.is_element())
+        .collect();
+
+    Ok(e4x::build_xml_list(activation, elements))
+}
+
+/// Implements `XML.attributes` and `XMLList.attributes`
+pub fn attributes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let attributes = nodes_of(this)
+        .iter()
+        .flat_map(|node| node.attributes())
+        .collect();
+
+    Ok(e4x::build_xml_list(activation, attributes))
+}
+
+/// Implements `XML.attribute` and `XMLList.attribute`
+pub fn attribute<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let name = name_arg(activation, args, true)?;
+    let attributes = e4x::get_property(&nodes_of(this), &name, None);
+
+    Ok(e4x::build_xml_list(activation, attributes))
+}
+
+/// Implements `XML.descendants` and `XMLList.descendants`
+pub fn descendants<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let name = name_arg(activation, args, false)?;
+    let descendants = e4x::get_descendants(&nodes_of(this), &name, None);
+
+    Ok(e4x::build_xml_list(activation, descendants))
+}
+
+/// Implements `XML.text` and `XMLList.text`
+pub fn text<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let text = nodes_of(this)
+        .iter()
+        .flat_map(|node| node.children())
+        .filter(|node| node.kind() == "text")
+        .collect();
+
+    Ok(e4x::build_xml_list(activation, text))
+}
+
+/// Implements `XML.parent` and `XMLList.parent`
+///
+/// Lists only have a parent if all of their nodes share the same one.
+pub fn parent<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let nodes = nodes_of(this);
+    let mut parents = nodes.iter().map(|node| node.parent());
+    let parent = match parents.next() {
+        Some(Some(parent)) => parent,
+        _ => return Ok(Value::Undefined),
+    };
+
+    for other in parents {
+        match other {
+            Some(other) if other.ptr_eq(parent) => {}
+            _ => return Ok(Value::Undefined),
+        }
+    }
+
+    Ok(e4x::build_xml(activation, E4XNode::Node(parent)))
+}
+
+/// Implements `XML.hasSimpleContent` and `XMLList.hasSimpleContent`
+pub fn has_simple_content<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(e4x::list_has_simple_content(&nodes_of(this)).into())
+}
+
+/// Implements `XML.hasComplexContent` and `XMLList.hasComplexContent`
+pub fn has_complex_content<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let nodes = nodes_of(this);
+    let is_complex = match &nodes[..] {
+        [node] => node.is_element() && !node.has_simple_content(),
+        nodes => nodes.iter().any(|node| node.is_element()),
+    };
+
+    Ok(is_complex.into())
+}
+
+/// Implements `XML.copy` and `XMLList.copy`
+pub fn copy<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let copies: Vec<E4XNode<'gc>> = nodes_of(this)
+        .iter()
+        .map(|node| E4XNode::Node(node.deep_copy(activation.context.gc_context)))
+        .collect();
+
+    match this.map(|this| this.as_xml().is_some()) {
+        Some(true) => Ok(copies
+            .into_iter()
+            .next()
+            .map(|node| e4x::build_xml(activation, node))
+            .unwrap_or(Value::Undefined)),
+        _ => Ok(e4x::build_xml_list(activation, copies)),
+    }
+}
+
+/// Get the only node of an `XML` object.
+fn this_node<'gc>(this: Option<Object<'gc>>) -> Option<E4XNode<'gc>> {
+    this.and_then(|this| this.as_xml().map(|node| node.clone()))
+}
+
+/// Implements `XML.name`
+pub fn name<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    match this_node(this).and_then(|node| node.name()) {
+        Some(name) => {
+            Ok(AvmString::new(activation.context.gc_context, name.node_name().into_owned()).into())
+        }
+        None => Ok(Value::Null),
+    }
+}
+
+/// Implements `XML.localName`
+pub fn local_name<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    match this_node(this).and_then(|node| node.name()) {
+        Some(name) => {
+            Ok(AvmString::new(activation.context.gc_context, name.local_name().to_string()).into())
+        }
+        None => Ok(Value::Null),
+    }
+}
+
+/// Implements `XML.nodeKind`
+pub fn node_kind<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    match this_node(this) {
+        Some(node) => Ok(node.kind().into()),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/// Implements `XML.appendChild`
+pub fn append_child<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(node) = this_node(this) {
+        let value = args.get(0).cloned().unwrap_or(Value::Undefined);
+        let is_xml = matches!(&value, Value::Object(o) if e4x::nodes_of(*o).is_some());
+        let children = if is_xml {
+            e4x::value_to_nodes(activation, &value)?
+        } else {
+            let source = value.coerce_to_string(activation)?;
+            E4XNode::parse(activation.context.gc_context, &source)?
+        };
+
+        node.append_children(activation.context.gc_context, &children)?;
+    }
+
+    Ok(this.map(|this| this.into()).unwrap_or(Value::Undefined))
+}
+
+/// Construct `XML`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::public_namespace(), "XML"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::Final.into());
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "toString"),
+        Method::from_builtin(to_string),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "toXMLString"),
+        Method::from_builtin(to_xml_string),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "length"),
+        Method::from_builtin(length),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "children"),
+        Method::from_builtin(children),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "child"),
+        Method::from_builtin(child),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "elements"),
+        Method::from_builtin(elements),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "attributes"),
+        Method::from_builtin(attributes),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "attribute"),
+        Method::from_builtin(attribute),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "descendants"),
+        Method::from_builtin(descendants),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "text"),
+        Method::from_builtin(text),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "parent"),
+        Method::from_builtin(parent),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "hasSimpleContent"),
+        Method::from_builtin(has_simple_content),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "hasComplexContent"),
+        Method::from_builtin(has_complex_content),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "copy"),
+        Method::from_builtin(copy),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "name"),
+        Method::from_builtin(name),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "localName"),
+        Method::from_builtin(local_name),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "nodeKind"),
+        Method::from_builtin(node_kind),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "appendChild"),
+        Method::from_builtin(append_child),
+    ));
+
+    class
+}
@@ -0,0 +1,140 @@
+//! `XMLList` class
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::e4x::{self, E4XNode};
+use crate::avm2::globals::xml;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `XMLList`'s instance initializer.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        let value = args.get(0).cloned().unwrap_or(Value::Undefined);
+        let nodes = match &value {
+            Value::Undefined | Value::Null => Vec::new(),
+            Value::Object(o) if e4x::nodes_of(*o).is_some() => e4x::nodes_of(*o)
+                .unwrap_or_default()
+                .iter()
+                .map(|node| E4XNode::Node(node.deep_copy(activation.context.gc_context)))
+                .collect(),
+            value => {
+                let source = value.coerce_to_string(activation)?;
+                E4XNode::parse(activation.context.gc_context, &source)?
+            }
+        };
+
+        if let Some(mut list) = this.as_xml_list_mut(activation.context.gc_context) {
+            *list = nodes;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XMLList`'s class initializer.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Construct `XMLList`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::public_namespace(), "XMLList"),
+        Some(QName::new(Namespace::public_namespace(), "Object").into()),
+        Method::from_builtin(instance_init),
+        Method::from_builtin(class_init),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::Final.into());
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::public_namespace(), "toString"),
+        Method::from_builtin(xml::to_string),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "toXMLString"),
+        Method::from_builtin(xml::to_xml_string),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "length"),
+        Method::from_builtin(xml::length),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "children"),
+        Method::from_builtin(xml::children),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "child"),
+        Method::from_builtin(xml::child),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "elements"),
+        Method::from_builtin(xml::elements),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "attributes"),
+        Method::from_builtin(xml::attributes),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "attribute"),
+        Method::from_builtin(xml::attribute),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "descendants"),
+        Method::from_builtin(xml::descendants),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "text"),
+        Method::from_builtin(xml::text),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "parent"),
+        Method::from_builtin(xml::parent),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "hasSimpleContent"),
+        Method::from_builtin(xml::has_simple_content),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "hasComplexContent"),
+        Method::from_builtin(xml::has_complex_content),
+    ));
+
+    write.define_instance_trait(Trait::from_method(
+        QName::new(Namespace::as3_namespace(), "copy"),
+        Method::from_builtin(xml::copy),
+    ));
+
+    class
+}
@@ -3,6 +3,7 @@
 use crate::avm2::activation::Activation;
 use crate::avm2::object::Object;
 use crate::avm2::script::TranslationUnit;
+use crate::avm2::string::AvmString;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::collect::CollectWrapper;
@@ -46,6 +47,9 @@ pub struct BytecodeMethod<'gc> {
 
     /// The ABC method body this function uses.
     pub abc_method_body: Option<u32>,
+
+    /// The name of this method, as printed in stack traces.
+    pub name: AvmString<'gc>,
 }
 
 impl<'gc> BytecodeMethod<'gc> {
@@ -59,6 +63,7 @@ impl<'gc> BytecodeMethod<'gc> {
         mc: MutationContext<'gc, '_>,
     ) -> Option<Gc<'gc, Self>> {
         let abc = txunit.abc();
+        let name = AvmString::new(mc, txunit.method_name(abc_method.0));
 
         if abc.methods.get(abc_method.0 as usize).is_some() {
             for (index, method_body) in abc.method_bodies.iter().enumerate() {
@@ -70,6 +75,7 @@ impl<'gc> BytecodeMethod<'gc> {
                             abc: CollectWrapper(txunit.abc()),
                             abc_method: abc_method.0,
                             abc_method_body: Some(index as u32),
+                            name,
                         },
                     ));
                 }
@@ -83,6 +89,7 @@ impl<'gc> BytecodeMethod<'gc> {
                 abc: CollectWrapper(txunit.abc()),
                 abc_method: abc_method.0,
                 abc_method_body: None,
+                name,
             },
         ))
     }
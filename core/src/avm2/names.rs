@@ -206,11 +206,19 @@ impl<'gc> QName<'gc> {
 /// process consists of searching each name space for a given name.
 ///
 /// The existence of a `name` of `None` indicates the `Any` name.
+///
+/// Multinames that refer to parameterized types (e.g. `Vector.<int>`) also
+/// carry the type parameters applied to the base type's name.
+///
+/// Attribute multinames (e.g. `@id`) are only meaningful to E4X objects; all
+/// other objects resolve them as if they were ordinary names.
 #[derive(Clone, Debug, Collect)]
 #[collect(no_drop)]
 pub struct Multiname<'gc> {
     ns: Vec<Namespace<'gc>>,
     name: Option<AvmString<'gc>>,
+    params: Vec<Multiname<'gc>>,
+    is_attribute: bool,
 }
 
 impl<'gc> Multiname<'gc> {
@@ -266,7 +274,8 @@ impl<'gc> Multiname<'gc> {
             .get(actual_index)
             .ok_or_else(|| format!("Unknown multiname constant {}", multiname_index.0).into());
 
-        Ok(match abc_multiname? {
+        let abc_multiname = abc_multiname?;
+        let mut multiname = match abc_multiname {
             AbcMultiname::QName { namespace, name } | AbcMultiname::QNameA { namespace, name } => {
                 Self {
                     ns: vec![Namespace::from_abc_namespace(
@@ -276,6 +285,8 @@ impl<'gc> Multiname<'gc> {
                     )?],
                     name: translation_unit
                         .pool_string_option(name.0, activation.context.gc_context)?,
+                    params: Vec::new(),
+                    is_attribute: false,
                 }
             }
             AbcMultiname::RTQName { name } | AbcMultiname::RTQNameA { name } => {
@@ -284,6 +295,8 @@ impl<'gc> Multiname<'gc> {
                     ns: vec![ns],
                     name: translation_unit
                         .pool_string_option(name.0, activation.context.gc_context)?,
+                    params: Vec::new(),
+                    is_attribute: false,
                 }
             }
             AbcMultiname::RTQNameL | AbcMultiname::RTQNameLA => {
@@ -292,6 +305,8 @@ impl<'gc> Multiname<'gc> {
                 Self {
                     ns: vec![ns],
                     name: Some(name),
+                    params: Vec::new(),
+                    is_attribute: false,
                 }
             }
             AbcMultiname::Multiname {
@@ -308,6 +323,8 @@ impl<'gc> Multiname<'gc> {
                     activation.context.gc_context,
                 )?,
                 name: translation_unit.pool_string_option(name.0, activation.context.gc_context)?,
+                params: Vec::new(),
+                is_attribute: false,
             },
             AbcMultiname::MultinameL { namespace_set }
             | AbcMultiname::MultinameLA { namespace_set } => {
@@ -319,9 +336,20 @@ impl<'gc> Multiname<'gc> {
                         activation.context.gc_context,
                     )?,
                     name: Some(name),
+                    params: Vec::new(),
+                    is_attribute: false,
                 }
             }
-        })
+            AbcMultiname::TypeName { .. } => Self::from_abc_multiname_static(
+                translation_unit,
+                multiname_index,
+                activation.context.gc_context,
+            )?,
+        };
+
+        multiname.is_attribute = Self::is_abc_attribute(abc_multiname);
+
+        Ok(multiname)
     }
 
     /// Read a static multiname from the ABC constant pool
@@ -345,7 +373,8 @@ impl<'gc> Multiname<'gc> {
             .get(actual_index)
             .ok_or_else(|| format!("Unknown multiname constant {}", multiname_index.0).into());
 
-        Ok(match abc_multiname? {
+        let abc_multiname = abc_multiname?;
+        let mut multiname = match abc_multiname {
             AbcMultiname::QName { namespace, name } | AbcMultiname::QNameA { namespace, name } => {
                 Self {
                     ns: vec![Namespace::from_abc_namespace(
@@ -354,6 +383,8 @@ impl<'gc> Multiname<'gc> {
                         mc,
                     )?],
                     name: translation_unit.pool_string_option(name.0, mc)?,
+                    params: Vec::new(),
+                    is_attribute: false,
                 }
             }
             AbcMultiname::Multiname {
@@ -366,9 +397,76 @@ impl<'gc> Multiname<'gc> {
             } => Self {
                 ns: Self::abc_namespace_set(translation_unit, namespace_set.clone(), mc)?,
                 name: translation_unit.pool_string_option(name.0, mc)?,
+                params: Vec::new(),
+                is_attribute: false,
             },
+            AbcMultiname::TypeName {
+                base_type,
+                parameters,
+            } => {
+                let mut base =
+                    Self::from_abc_multiname_static(translation_unit, base_type.clone(), mc)?;
+
+                if parameters.len() > 1 {
+                    return Err(format!(
+                        "VerifyError: Multiname {} has {} type parameters, expected one",
+                        multiname_index.0,
+                        parameters.len()
+                    )
+                    .into());
+                }
+
+                for param in parameters {
+                    base.params.push(if param.0 == 0 {
+                        Self::any()
+                    } else {
+                        Self::from_abc_multiname_static(translation_unit, param.clone(), mc)?
+                    });
+                }
+
+                base
+            }
             _ => return Err(format!("Multiname {} is not static", multiname_index.0).into()),
-        })
+        };
+
+        multiname.is_attribute = Self::is_abc_attribute(abc_multiname);
+
+        Ok(multiname)
+    }
+
+    /// Determine if an ABC multiname refers to an XML attribute.
+    fn is_abc_attribute(abc_multiname: &AbcMultiname) -> bool {
+        matches!(
+            abc_multiname,
+            AbcMultiname::QNameA { .. }
+                | AbcMultiname::RTQNameA { .. }
+                | AbcMultiname::RTQNameLA
+                | AbcMultiname::MultinameA { .. }
+                | AbcMultiname::MultinameLA { .. }
+        )
+    }
+
+    /// Determine if a multiname constant takes its local name from the
+    /// operand stack, without resolving it.
+    ///
+    /// Only `MultinameL` names are considered, as those are what property
+    /// accesses with a computed key compile to.
+    pub fn is_abc_late_name(
+        translation_unit: TranslationUnit<'gc>,
+        multiname_index: Index<AbcMultiname>,
+    ) -> bool {
+        let abc = translation_unit.abc();
+
+        (multiname_index.0 as usize)
+            .checked_sub(1)
+            .and_then(|actual_index| abc.constant_pool.multinames.get(actual_index))
+            .map(|abc_multiname| {
+                matches!(
+                    abc_multiname,
+                    AbcMultiname::MultinameL { .. } | AbcMultiname::MultinameLA { .. }
+                )
+            })
+            .unwrap_or(false)
     }
 
     /// Indicates the any type (any name in any namespace).
@@ -376,6 +474,27 @@ impl<'gc> Multiname<'gc> {
         Self {
             ns: vec![Namespace::Any],
             name: None,
+            params: Vec::new(),
+            is_attribute: false,
+        }
+    }
+
+    /// Construct a multiname for a name passed to an E4X method, such as
+    /// `XML.child`.
+    ///
+    /// The name `*` matches any name in any namespace.
+    pub fn from_xml_name(name: AvmString<'gc>, is_attribute: bool) -> Self {
+        let (ns, name) = if &*name == "*" {
+            (Namespace::Any, None)
+        } else {
+            (Namespace::public_namespace(), Some(name))
+        };
+
+        Self {
+            ns: vec![ns],
+            name,
+            params: Vec::new(),
+            is_attribute,
         }
     }
 
@@ -387,6 +506,16 @@ impl<'gc> Multiname<'gc> {
         self.name
     }
 
+    /// List the type parameters applied to this name, if any.
+    pub fn params(&self) -> &[Multiname<'gc>] {
+        &self.params[..]
+    }
+
+    /// Indicates whether or not this name refers to an XML attribute.
+    pub fn is_attribute(&self) -> bool {
+        self.is_attribute
+    }
+
     pub fn includes_dynamic_namespace(&self) -> bool {
         for ns in self.ns.iter() {
             if ns.is_dynamic() {
@@ -403,6 +532,8 @@ impl<'gc> From<QName<'gc>> for Multiname<'gc> {
         Self {
             ns: vec![q.ns],
             name: Some(q.name),
+            params: Vec::new(),
+            is_attribute: false,
         }
     }
 }
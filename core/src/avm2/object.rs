@@ -2,8 +2,10 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::array::ArrayStorage;
+use crate::avm2::bytearray::ByteArrayStorage;
 use crate::avm2::class::Class;
 use crate::avm2::domain::Domain;
+use crate::avm2::e4x::E4XNode;
 use crate::avm2::events::{DispatchList, Event};
 use crate::avm2::function::Executable;
 use crate::avm2::names::{Multiname, Namespace, QName};
@@ -11,15 +13,23 @@ use crate::avm2::scope::Scope;
 use crate::avm2::string::AvmString;
 use crate::avm2::traits::{Trait, TraitKind};
 use crate::avm2::value::{Hint, Value};
+use crate::avm2::vector::VectorStorage;
 use crate::avm2::Error;
+use crate::backend::audio::{SoundHandle, SoundInstanceHandle};
+use crate::bitmap::bitmap_data::BitmapData;
 use crate::display_object::DisplayObject;
 use gc_arena::{Collect, GcCell, MutationContext};
 use ruffle_macros::enum_trait_object;
 use std::cell::{Ref, RefMut};
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::rc::Weak;
 
 mod array_object;
+mod bitmapdata_object;
+mod bytearray_object;
 mod custom_object;
+mod dictionary_object;
 mod dispatch_object;
 mod domain_object;
 mod event_object;
@@ -27,9 +37,17 @@ mod function_object;
 mod namespace_object;
 mod primitive_object;
 mod script_object;
+mod sound_object;
+mod soundchannel_object;
 mod stage_object;
+mod vector_object;
+mod xml_list_object;
+mod xml_object;
 
 pub use crate::avm2::object::array_object::ArrayObject;
+pub use crate::avm2::object::bitmapdata_object::BitmapDataObject;
+pub use crate::avm2::object::bytearray_object::ByteArrayObject;
+pub use crate::avm2::object::dictionary_object::DictionaryObject;
 pub use crate::avm2::object::dispatch_object::DispatchObject;
 pub use crate::avm2::object::domain_object::DomainObject;
 pub use crate::avm2::object::event_object::EventObject;
@@ -37,7 +55,12 @@ pub use crate::avm2::object::function_object::{implicit_deriver, FunctionObject}
 pub use crate::avm2::object::namespace_object::NamespaceObject;
 pub use crate::avm2::object::primitive_object::PrimitiveObject;
 pub use crate::avm2::object::script_object::ScriptObject;
+pub use crate::avm2::object::sound_object::SoundObject;
+pub use crate::avm2::object::soundchannel_object::SoundChannelObject;
 pub use crate::avm2::object::stage_object::StageObject;
+pub use crate::avm2::object::vector_object::VectorObject;
+pub use crate::avm2::object::xml_list_object::XmlListObject;
+pub use crate::avm2::object::xml_object::XmlObject;
 
 /// Represents an object that can be directly interacted with by the AVM2
 /// runtime.
@@ -53,7 +76,15 @@ pub use crate::avm2::object::stage_object::StageObject;
         StageObject(StageObject<'gc>),
         DomainObject(DomainObject<'gc>),
         EventObject(EventObject<'gc>),
-        DispatchObject(DispatchObject<'gc>)
+        DispatchObject(DispatchObject<'gc>),
+        VectorObject(VectorObject<'gc>),
+        XmlObject(XmlObject<'gc>),
+        XmlListObject(XmlListObject<'gc>),
+        DictionaryObject(DictionaryObject<'gc>),
+        ByteArrayObject(ByteArrayObject<'gc>),
+        BitmapDataObject(BitmapDataObject<'gc>),
+        SoundObject(SoundObject<'gc>),
+        SoundChannelObject(SoundChannelObject<'gc>)
     }
 )]
 pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy {
@@ -684,6 +715,22 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
         scope: Option<GcCell<'gc, Scope<'gc>>>,
     ) -> Result<Object<'gc>, Error>;
 
+    /// Apply type parameters to this object, which should be a generic class.
+    ///
+    /// The result is a specialized class object that can be used to construct
+    /// objects of the parameterized type (e.g. `Vector.<int>`). Repeated
+    /// applications of the same parameters should yield the same class.
+    ///
+    /// Values in `params` must be either class objects or `null` (indicating
+    /// the any type).
+    fn apply(
+        &self,
+        _activation: &mut Activation<'_, 'gc, '_>,
+        _params: &[Value<'gc>],
+    ) -> Result<Object<'gc>, Error> {
+        Err("TypeError: Only generic classes may have type parameters applied".into())
+    }
+
     /// Determine the type of primitive coercion this object would prefer, in
     /// the case that there is no obvious reason to prefer one type over the
     /// other.
@@ -797,6 +844,18 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
     /// Get a raw pointer value for this object.
     fn as_ptr(&self) -> *const ObjectPtr;
 
+    /// Get a handle that expires once this object has been collected.
+    ///
+    /// This is what weak references to the object are built on; see
+    /// `avm2::weak` for details.
+    fn liveness(&self, mc: MutationContext<'gc, '_>) -> Weak<()>;
+
+    /// Remove any weak references to collected objects from this object.
+    ///
+    /// This is only called on objects that registered themselves as weak
+    /// containers.
+    fn purge_weak_references(&self, _mc: MutationContext<'gc, '_>) {}
+
     /// Get this object's `Class`, if it has one.
     fn as_class(&self) -> Option<GcCell<'gc, Class<'gc>>>;
 
@@ -840,6 +899,95 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
         None
     }
 
+    /// Unwrap this object as vector storage.
+    fn as_vector_storage(&self) -> Option<Ref<VectorStorage<'gc>>> {
+        None
+    }
+
+    /// Unwrap this object as mutable vector storage.
+    fn as_vector_storage_mut(
+        &self,
+        _mc: MutationContext<'gc, '_>,
+    ) -> Option<RefMut<VectorStorage<'gc>>> {
+        None
+    }
+
+    /// Unwrap this object's XML node, if it is an `XML` object.
+    fn as_xml(&self) -> Option<Ref<E4XNode<'gc>>> {
+        None
+    }
+
+    /// Unwrap this object's XML node as mutable, if it is an `XML` object.
+    fn as_xml_mut(&self, _mc: MutationContext<'gc, '_>) -> Option<RefMut<E4XNode<'gc>>> {
+        None
+    }
+
+    /// Unwrap this object's list of XML nodes, if it is an `XMLList` object.
+    fn as_xml_list(&self) -> Option<Ref<Vec<E4XNode<'gc>>>> {
+        None
+    }
+
+    /// Unwrap this object's list of XML nodes as mutable, if it is an
+    /// `XMLList` object.
+    fn as_xml_list_mut(&self, _mc: MutationContext<'gc, '_>) -> Option<RefMut<Vec<E4XNode<'gc>>>> {
+        None
+    }
+
+    /// Unwrap this object as a dictionary.
+    fn as_dictionary_object(&self) -> Option<DictionaryObject<'gc>> {
+        None
+    }
+
+    /// Unwrap this object as byte array storage.
+    fn as_bytearray(&self) -> Option<Ref<ByteArrayStorage>> {
+        None
+    }
+
+    /// Unwrap this object as mutable byte array storage.
+    fn as_bytearray_mut(&self, _mc: MutationContext<'gc, '_>) -> Option<RefMut<ByteArrayStorage>> {
+        None
+    }
+
+    /// Unwrap this object's bitmap data, if it is a constructed `BitmapData`.
+    fn as_bitmap_data(&self) -> Option<GcCell<'gc, BitmapData>> {
+        None
+    }
+
+    /// Associate bitmap data with this object, if it can support such an
+    /// association.
+    ///
+    /// If not, then this function does nothing.
+    fn init_bitmap_data(
+        &self,
+        _mc: MutationContext<'gc, '_>,
+        _new_bitmap: GcCell<'gc, BitmapData>,
+    ) {
+    }
+
+    /// Unwrap this object's sound handle, if it is a `Sound` with an embedded
+    /// or loaded sound.
+    fn as_sound(&self) -> Option<SoundHandle> {
+        None
+    }
+
+    /// Associate a sound with this object, if it can support such an
+    /// association.
+    ///
+    /// If not, then this function does nothing.
+    fn set_sound(&self, _mc: MutationContext<'gc, '_>, _sound: SoundHandle) {}
+
+    /// Unwrap this object's sound instance handle, if it is a `SoundChannel`
+    /// that has started playing.
+    fn as_sound_instance(&self) -> Option<SoundInstanceHandle> {
+        None
+    }
+
+    /// Associate a playing sound instance with this object, if it can
+    /// support such an association.
+    ///
+    /// If not, then this function does nothing.
+    fn set_sound_instance(&self, _mc: MutationContext<'gc, '_>, _instance: SoundInstanceHandle) {}
+
     /// Get this object's `DisplayObject`, if it has one.
     fn as_display_object(&self) -> Option<DisplayObject<'gc>> {
         None
@@ -883,4 +1031,42 @@ impl<'gc> Object<'gc> {
     pub fn ptr_eq(a: Object<'gc>, b: Object<'gc>) -> bool {
         a.as_ptr() == b.as_ptr()
     }
+
+    /// Construct an instance of a class given its prototype, as if by the
+    /// `new` operator.
+    ///
+    /// The class's constructor is looked up through the prototype's
+    /// `constructor` property and run on the new instance.
+    pub fn construct_from_proto(
+        activation: &mut Activation<'_, 'gc, '_>,
+        mut proto: Object<'gc>,
+        args: &[Value<'gc>],
+    ) -> Result<Object<'gc>, Error> {
+        let constructor = proto
+            .get_property(
+                proto,
+                &QName::new(Namespace::public_namespace(), "constructor"),
+                activation,
+            )?
+            .coerce_to_object(activation)?;
+        let instance = proto.construct(activation, args)?;
+
+        constructor.call(Some(instance), args, activation, Some(proto))?;
+
+        Ok(instance)
+    }
+}
+
+impl<'gc> PartialEq for Object<'gc> {
+    fn eq(&self, other: &Self) -> bool {
+        Object::ptr_eq(*self, *other)
+    }
+}
+
+impl<'gc> Eq for Object<'gc> {}
+
+impl<'gc> Hash for Object<'gc> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ptr().hash(state);
+    }
 }
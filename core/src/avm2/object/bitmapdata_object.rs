@@ -0,0 +1,103 @@
+//! Object representation for `flash.display.BitmapData`
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::script_object::{ScriptObjectClass, ScriptObjectData};
+use crate::avm2::object::{Object, ObjectPtr, TObject};
+use crate::avm2::scope::Scope;
+use crate::avm2::string::AvmString;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::bitmap::bitmap_data::BitmapData;
+use crate::{impl_avm2_custom_object, impl_avm2_custom_object_properties};
+use gc_arena::{Collect, GcCell, MutationContext};
+
+/// An object that holds a reference to shared bitmap pixel data.
+///
+/// The pixel data is shared with any `Bitmap` display objects showing it, so
+/// that drawing to the `BitmapData` updates them all.
+#[derive(Collect, Debug, Clone, Copy)]
+#[collect(no_drop)]
+pub struct BitmapDataObject<'gc>(GcCell<'gc, BitmapDataObjectData<'gc>>);
+
+#[derive(Collect, Debug, Clone)]
+#[collect(no_drop)]
+pub struct BitmapDataObjectData<'gc> {
+    /// Base script object
+    base: ScriptObjectData<'gc>,
+
+    /// The pixel data, once the object has been constructed.
+    bitmap_data: Option<GcCell<'gc, BitmapData>>,
+}
+
+impl<'gc> BitmapDataObject<'gc> {
+    /// Construct a bitmap data subclass.
+    pub fn derive(
+        base_proto: Object<'gc>,
+        mc: MutationContext<'gc, '_>,
+        class: GcCell<'gc, Class<'gc>>,
+        scope: Option<GcCell<'gc, Scope<'gc>>>,
+    ) -> Result<Object<'gc>, Error> {
+        let base = ScriptObjectData::base_new(
+            Some(base_proto),
+            ScriptObjectClass::InstancePrototype(class, scope),
+        );
+
+        Ok(BitmapDataObject(GcCell::allocate(
+            mc,
+            BitmapDataObjectData {
+                base,
+                bitmap_data: None,
+            },
+        ))
+        .into())
+    }
+}
+
+impl<'gc> TObject<'gc> for BitmapDataObject<'gc> {
+    impl_avm2_custom_object!(base);
+    impl_avm2_custom_object_properties!(base);
+
+    fn value_of(&self, _mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
+        Ok(Object::from(*self).into())
+    }
+
+    fn as_bitmap_data(&self) -> Option<GcCell<'gc, BitmapData>> {
+        self.0.read().bitmap_data
+    }
+
+    fn init_bitmap_data(&self, mc: MutationContext<'gc, '_>, new_bitmap: GcCell<'gc, BitmapData>) {
+        self.0.write(mc).bitmap_data = Some(new_bitmap);
+    }
+
+    fn construct(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        _args: &[Value<'gc>],
+    ) -> Result<Object<'gc>, Error> {
+        let this: Object<'gc> = Object::BitmapDataObject(*self);
+        let base = ScriptObjectData::base_new(Some(this), ScriptObjectClass::NoClass);
+
+        Ok(BitmapDataObject(GcCell::allocate(
+            activation.context.gc_context,
+            BitmapDataObjectData {
+                base,
+                bitmap_data: None,
+            },
+        ))
+        .into())
+    }
+
+    fn derive(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        class: GcCell<'gc, Class<'gc>>,
+        scope: Option<GcCell<'gc, Scope<'gc>>>,
+    ) -> Result<Object<'gc>, Error> {
+        let this: Object<'gc> = Object::BitmapDataObject(*self);
+
+        BitmapDataObject::derive(this, activation.context.gc_context, class, scope)
+    }
+}
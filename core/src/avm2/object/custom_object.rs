@@ -188,6 +188,10 @@ macro_rules! impl_avm2_custom_object {
             self.0.as_ptr() as *const ObjectPtr
         }
 
+        fn liveness(&self, mc: MutationContext<'gc, '_>) -> std::rc::Weak<()> {
+            self.0.write(mc).$field.liveness()
+        }
+
         fn as_class(&self) -> Option<GcCell<'gc, Class<'gc>>> {
             self.0.read().base.as_class()
         }
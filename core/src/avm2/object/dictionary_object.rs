@@ -0,0 +1,468 @@
+//! Object representation for `flash.utils.Dictionary`
+
+use crate::avm1::AvmString;
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::script_object::{ScriptObjectClass, ScriptObjectData};
+use crate::avm2::object::{Object, ObjectPtr, TObject};
+use crate::avm2::scope::Scope;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::weak::WeakObject;
+use crate::avm2::Error;
+use crate::{impl_avm2_custom_object, impl_avm2_custom_object_properties};
+use fnv::FnvBuildHasher;
+use gc_arena::{Collect, CollectionContext, GcCell, MutationContext};
+use indexmap::IndexMap;
+
+type FnvIndexMap<K, V> = IndexMap<K, V, FnvBuildHasher>;
+
+/// An object that can have arbitrary objects as keys.
+///
+/// Primitive keys are coerced to strings and stored as ordinary dynamic
+/// properties, just like any other object. Object keys are compared by
+/// identity and kept in a separate map.
+///
+/// Dictionaries constructed with `weakKeys` do not keep their object keys
+/// alive; see `avm2::weak` for how entries get released.
+#[derive(Collect, Debug, Clone, Copy)]
+#[collect(no_drop)]
+pub struct DictionaryObject<'gc>(GcCell<'gc, DictionaryObjectData<'gc>>);
+
+#[derive(Debug, Clone)]
+pub struct DictionaryObjectData<'gc> {
+    /// Base script object
+    base: ScriptObjectData<'gc>,
+
+    /// Object key storage, in insertion order.
+    ///
+    /// Entries are indexed by the key's address rather than the key itself,
+    /// as the memory of a collected weak key must never be touched again.
+    object_space: FnvIndexMap<*const ObjectPtr, DictionaryEntry<'gc>>,
+
+    /// Whether this dictionary holds its object keys weakly.
+    weak_keys: bool,
+}
+
+/// An object key of a dictionary.
+#[derive(Debug, Clone)]
+enum DictionaryKey<'gc> {
+    Strong(Object<'gc>),
+
+    /// A key that may be collected while it is still in the dictionary.
+    Weak(WeakObject<'gc>),
+}
+
+impl<'gc> DictionaryKey<'gc> {
+    /// Get the key object, if it has not been collected.
+    fn object(&self) -> Option<Object<'gc>> {
+        match self {
+            Self::Strong(object) => Some(*object),
+            Self::Weak(weak) => weak.upgrade(),
+        }
+    }
+
+    /// Check if this key has not been collected.
+    fn is_alive(&self) -> bool {
+        match self {
+            Self::Strong(_) => true,
+            Self::Weak(weak) => weak.is_alive(),
+        }
+    }
+}
+
+/// A value stored under an object key.
+#[derive(Debug, Clone)]
+struct DictionaryEntry<'gc> {
+    key: DictionaryKey<'gc>,
+    value: Value<'gc>,
+}
+
+// SAFETY: Everything this dictionary keeps alive is traced: its base object,
+// its strong keys, and every value. Weak keys are deliberately not traced, so
+// the collector may free one while its entry is still in the dictionary.
+// That is sound because:
+//
+//  * A freed key is never dereferenced. Entries are indexed by the key's
+//    address, `DictionaryKey::object` checks the key's liveness token before
+//    handing it out, and the token is dropped together with the key.
+//  * A live key is never handed out while a sweep is in progress, when the
+//    collector could free it even though a script has just stored it
+//    somewhere. The player finishes every sweep before scripts run again; see
+//    `avm2::weak`. Outside of a sweep, storing the key anywhere goes through a
+//    write barrier, which makes the collector mark it.
+//  * The entries of freed keys are removed by `purge_weak_references` right
+//    after the sweep that freed them, before any script runs. A new object
+//    allocated at a freed key's address therefore never finds its entry.
+unsafe impl<'gc> Collect for DictionaryObjectData<'gc> {
+    fn trace(&self, cc: CollectionContext) {
+        self.base.trace(cc);
+
+        for entry in self.object_space.values() {
+            if let DictionaryKey::Strong(key) = &entry.key {
+                key.trace(cc);
+            }
+
+            entry.value.trace(cc);
+        }
+    }
+}
+
+impl<'gc> DictionaryObject<'gc> {
+    /// Construct a dictionary subclass.
+    pub fn derive(
+        base_proto: Object<'gc>,
+        mc: MutationContext<'gc, '_>,
+        class: GcCell<'gc, Class<'gc>>,
+        scope: Option<GcCell<'gc, Scope<'gc>>>,
+    ) -> Result<Object<'gc>, Error> {
+        let base = ScriptObjectData::base_new(
+            Some(base_proto),
+            ScriptObjectClass::InstancePrototype(class, scope),
+        );
+
+        Ok(DictionaryObject(GcCell::allocate(
+            mc,
+            DictionaryObjectData {
+                base,
+                object_space: FnvIndexMap::default(),
+                weak_keys: false,
+            },
+        ))
+        .into())
+    }
+
+    /// Make this dictionary hold its object keys weakly.
+    ///
+    /// The caller must also register this dictionary with the player's
+    /// `WeakReferences`, so that it gets purged of collected keys.
+    pub fn set_weak_keys(self, mc: MutationContext<'gc, '_>) {
+        self.0.write(mc).weak_keys = true;
+    }
+
+    /// Retrieve a value in the dictionary's object space.
+    pub fn get_property_by_object(self, name: Object<'gc>) -> Value<'gc> {
+        self.0
+            .read()
+            .object_space
+            .get(&name.as_ptr())
+            .map(|entry| entry.value.clone())
+            .unwrap_or(Value::Undefined)
+    }
+
+    /// Set a value in the dictionary's object space.
+    pub fn set_property_by_object(
+        self,
+        name: Object<'gc>,
+        value: Value<'gc>,
+        mc: MutationContext<'gc, '_>,
+    ) {
+        let key = if self.0.read().weak_keys {
+            DictionaryKey::Weak(WeakObject::new(name, mc))
+        } else {
+            DictionaryKey::Strong(name)
+        };
+
+        self.0
+            .write(mc)
+            .object_space
+            .insert(name.as_ptr(), DictionaryEntry { key, value });
+    }
+
+    /// Delete a value from the dictionary's object space.
+    pub fn delete_property_by_object(self, name: Object<'gc>, mc: MutationContext<'gc, '_>) {
+        self.0.write(mc).object_space.shift_remove(&name.as_ptr());
+    }
+
+    /// Check if a key exists in the dictionary's object space.
+    pub fn has_property_by_object(self, name: Object<'gc>) -> bool {
+        self.0.read().object_space.contains_key(&name.as_ptr())
+    }
+
+    /// Retrieve a given enumerable key by index.
+    ///
+    /// Object keys are enumerated first, followed by the dictionary's
+    /// ordinary dynamic properties. As with `get_enumerant_name`, indices
+    /// start from one.
+    pub fn get_enumerant_key(self, index: u32) -> Option<Value<'gc>> {
+        let read = self.0.read();
+        let true_index = (index as usize).checked_sub(1)?;
+
+        if let Some((_, entry)) = read.object_space.get_index(true_index) {
+            return Some(
+                entry
+                    .key
+                    .object()
+                    .map(|key| key.into())
+                    .unwrap_or(Value::Undefined),
+            );
+        }
+
+        let base_index = index - read.object_space.len() as u32;
+
+        read.base
+            .get_enumerant_name(base_index)
+            .map(|name| name.local_name().into())
+    }
+
+    /// Retrieve the value of a given enumerable key by index.
+    pub fn get_enumerant_value(
+        self,
+        index: u32,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        let object_len = self.0.read().object_space.len();
+        let true_index = match (index as usize).checked_sub(1) {
+            Some(true_index) => true_index,
+            None => return Ok(Value::Undefined),
+        };
+
+        if true_index < object_len {
+            return Ok(self
+                .0
+                .read()
+                .object_space
+                .get_index(true_index)
+                .map(|(_, entry)| entry.value.clone())
+                .unwrap_or(Value::Undefined));
+        }
+
+        let name = self
+            .0
+            .read()
+            .base
+            .get_enumerant_name(index - object_len as u32);
+        let mut this: Object<'gc> = Object::DictionaryObject(self);
+
+        match name {
+            Some(name) => this.get_property(this, &name, activation),
+            None => Ok(Value::Undefined),
+        }
+    }
+}
+
+impl<'gc> TObject<'gc> for DictionaryObject<'gc> {
+    impl_avm2_custom_object!(base);
+    impl_avm2_custom_object_properties!(base);
+
+    fn value_of(&self, _mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
+        Ok(Object::from(*self).into())
+    }
+
+    fn as_dictionary_object(&self) -> Option<DictionaryObject<'gc>> {
+        Some(*self)
+    }
+
+    fn purge_weak_references(&self, mc: MutationContext<'gc, '_>) {
+        self.0
+            .write(mc)
+            .object_space
+            .retain(|_, entry| entry.key.is_alive());
+    }
+
+    fn construct(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        _args: &[Value<'gc>],
+    ) -> Result<Object<'gc>, Error> {
+        let this: Object<'gc> = Object::DictionaryObject(*self);
+        let base = ScriptObjectData::base_new(Some(this), ScriptObjectClass::NoClass);
+
+        Ok(DictionaryObject(GcCell::allocate(
+            activation.context.gc_context,
+            DictionaryObjectData {
+                base,
+                object_space: FnvIndexMap::default(),
+                weak_keys: false,
+            },
+        ))
+        .into())
+    }
+
+    fn derive(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        class: GcCell<'gc, Class<'gc>>,
+        scope: Option<GcCell<'gc, Scope<'gc>>>,
+    ) -> Result<Object<'gc>, Error> {
+        let this: Object<'gc> = Object::DictionaryObject(*self);
+
+        DictionaryObject::derive(this, activation.context.gc_context, class, scope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm2::object::ScriptObject;
+    use crate::avm2::weak::WeakReferences;
+    use gc_arena::{make_arena, ArenaParameters};
+
+    #[derive(Collect)]
+    #[collect(no_drop)]
+    struct TestRoot<'gc> {
+        dictionary: DictionaryObject<'gc>,
+        held_keys: GcCell<'gc, Vec<Object<'gc>>>,
+        live_objects: GcCell<'gc, Vec<Object<'gc>>>,
+        weak_references: GcCell<'gc, WeakReferences<'gc>>,
+    }
+
+    make_arena!(TestArena, TestRoot);
+
+    fn new_arena() -> TestArena {
+        TestArena::new(ArenaParameters::default(), |mc| {
+            let dictionary = DictionaryObject(GcCell::allocate(
+                mc,
+                DictionaryObjectData {
+                    base: ScriptObjectData::base_new(None, ScriptObjectClass::NoClass),
+                    object_space: FnvIndexMap::default(),
+                    weak_keys: false,
+                },
+            ));
+            dictionary.set_weak_keys(mc);
+
+            let mut weak_references = WeakReferences::new();
+            weak_references.register_container(dictionary.into(), mc);
+
+            // A large heap makes every collection cycle span several steps.
+            let live_objects = (0..2000).map(|_| ScriptObject::bare_object(mc)).collect();
+
+            TestRoot {
+                dictionary,
+                held_keys: GcCell::allocate(mc, Vec::new()),
+                live_objects: GcCell::allocate(mc, live_objects),
+                weak_references: GcCell::allocate(mc, weak_references),
+            }
+        })
+    }
+
+    /// Allocate some garbage and collect it incrementally, the way the player
+    /// does at the end of each update. Returns whether a collection cycle is
+    /// still underway afterwards.
+    fn step(arena: &mut TestArena) -> bool {
+        let sweeping = arena.mutate(|mc, root| {
+            for _ in 0..50 {
+                ScriptObject::bare_object(mc);
+            }
+
+            root.weak_references.read().prepare_collection(mc)
+        });
+        let sweeping = sweeping.expect("the dictionary is registered");
+
+        arena.collect_debt();
+
+        if sweeping.get() {
+            arena.mutate(|mc, root| {
+                root.weak_references.read().prepare_collection(mc);
+            });
+            if arena.allocation_debt() > 0.0 {
+                arena.collect_all();
+            }
+
+            arena.mutate(|mc, root| {
+                root.weak_references.write(mc).finish_collection(mc);
+            });
+        }
+
+        // The collector only takes on debt for new allocations while it is
+        // awake.
+        arena.mutate(|mc, _root| {
+            ScriptObject::bare_object(mc);
+        });
+        arena.allocation_debt() > 0.0
+    }
+
+    /// Run steps until a collection cycle is underway.
+    fn step_into_cycle(arena: &mut TestArena) {
+        for _ in 0..1000 {
+            if step(arena) {
+                return;
+            }
+        }
+
+        panic!("no collection cycle started");
+    }
+
+    fn dictionary_len(arena: &mut TestArena) -> usize {
+        arena.mutate(|_mc, root| root.dictionary.0.read().object_space.len())
+    }
+
+    #[test]
+    fn weak_key_collected_incrementally() {
+        let mut arena = new_arena();
+
+        arena.mutate(|mc, root| {
+            let held = ScriptObject::bare_object(mc);
+            let dropped = ScriptObject::bare_object(mc);
+            root.dictionary
+                .set_property_by_object(held, Value::Number(1.0), mc);
+            root.dictionary
+                .set_property_by_object(dropped, Value::Number(2.0), mc);
+            root.held_keys.write(mc).extend(vec![held, dropped]);
+        });
+
+        step_into_cycle(&mut arena);
+        arena.mutate(|mc, root| {
+            root.held_keys.write(mc).pop();
+        });
+
+        let mut steps = 0;
+        while dictionary_len(&mut arena) > 1 {
+            assert!(steps < 1000, "the dropped key was never collected");
+            step(&mut arena);
+            steps += 1;
+        }
+        assert!(steps > 1, "the key was collected in a single step");
+
+        arena.mutate(|_mc, root| {
+            let held = root.held_keys.read()[0];
+            assert!(root.dictionary.has_property_by_object(held));
+            assert_eq!(
+                root.dictionary.get_property_by_object(held),
+                Value::Number(1.0)
+            );
+            assert_eq!(root.live_objects.read().len(), 2000);
+        });
+    }
+
+    #[test]
+    fn weak_key_read_during_incremental_collection_survives() {
+        let mut arena = new_arena();
+
+        arena.mutate(|mc, root| {
+            let key = ScriptObject::bare_object(mc);
+            root.dictionary
+                .set_property_by_object(key, Value::Number(1.0), mc);
+            root.held_keys.write(mc).push(key);
+        });
+
+        // Hand the key back out of the dictionary mid-cycle, after the
+        // collector may already have traced the roots.
+        step_into_cycle(&mut arena);
+        arena.mutate(|mc, root| {
+            root.held_keys.write(mc).clear();
+
+            let key = match root.dictionary.get_enumerant_key(1) {
+                Some(Value::Object(key)) => key,
+                _ => panic!("the key is still alive"),
+            };
+            root.held_keys.write(mc).push(key);
+        });
+
+        // Run until a few more cycles have completed.
+        for _ in 0..3 {
+            while step(&mut arena) {}
+            step_into_cycle(&mut arena);
+        }
+
+        assert_eq!(dictionary_len(&mut arena), 1);
+        arena.mutate(|_mc, root| {
+            let key = root.held_keys.read()[0];
+            assert_eq!(
+                root.dictionary.get_property_by_object(key),
+                Value::Number(1.0)
+            );
+        });
+    }
+}
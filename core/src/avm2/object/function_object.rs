@@ -28,6 +28,12 @@ pub struct FunctionObjectData<'gc> {
 
     /// Executable code
     exec: Option<Executable<'gc>>,
+
+    /// Specialized classes created by applying type parameters to this class.
+    ///
+    /// Each application is keyed by its type parameter, which is `None` for
+    /// the any type.
+    applications: Vec<(Option<Object<'gc>>, Object<'gc>)>,
 }
 
 pub fn implicit_deriver<'gc>(
@@ -174,6 +180,7 @@ impl<'gc> FunctionObject<'gc> {
                     None,
                     activation.context.gc_context,
                 )),
+                applications: Vec::new(),
             },
         ))
         .into();
@@ -219,6 +226,7 @@ impl<'gc> FunctionObject<'gc> {
             FunctionObjectData {
                 base: ScriptObjectData::base_new(Some(fn_proto), ScriptObjectClass::NoClass),
                 exec,
+                applications: Vec::new(),
             },
         ))
         .into()
@@ -235,6 +243,7 @@ impl<'gc> FunctionObject<'gc> {
             FunctionObjectData {
                 base: ScriptObjectData::base_new(Some(fn_proto), ScriptObjectClass::NoClass),
                 exec: Some(Executable::from_method(nf.into(), None, None, mc)),
+                applications: Vec::new(),
             },
         ))
         .into()
@@ -257,6 +266,7 @@ impl<'gc> FunctionObject<'gc> {
             FunctionObjectData {
                 base: ScriptObjectData::base_new(Some(fn_proto), class),
                 exec: Some(Executable::from_method(constr.into(), None, None, mc)),
+                applications: Vec::new(),
             },
         ))
         .into();
@@ -314,6 +324,86 @@ impl<'gc> TObject<'gc> for FunctionObject<'gc> {
         }
     }
 
+    fn apply(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        params: &[Value<'gc>],
+    ) -> Result<Object<'gc>, Error> {
+        let (class, scope) = match self.0.read().base.class() {
+            ScriptObjectClass::ClassConstructor(class, scope) => (*class, *scope),
+            _ => return Err("TypeError: Only classes may have type parameters applied".into()),
+        };
+
+        if !class.read().is_generic() {
+            return Err(format!(
+                "TypeError: Class {} is not generic",
+                class.read().name().local_name()
+            )
+            .into());
+        }
+
+        if params.len() != 1 {
+            return Err(format!(
+                "TypeError: Class {} expects one type parameter, got {}",
+                class.read().name().local_name(),
+                params.len()
+            )
+            .into());
+        }
+
+        let param = match &params[0] {
+            Value::Undefined | Value::Null => None,
+            Value::Object(param) if param.as_class().is_some() => Some(*param),
+            _ => return Err("TypeError: Type parameters must be classes".into()),
+        };
+
+        for (application_param, application) in self.0.read().applications.iter() {
+            let is_match = match (application_param, param) {
+                (None, None) => true,
+                (Some(a), Some(b)) => Object::ptr_eq(*a, b),
+                _ => false,
+            };
+
+            if is_match {
+                return Ok(*application);
+            }
+        }
+
+        let specialized_class = class
+            .read()
+            .with_type_params(&[param], activation.context.gc_context);
+
+        // Specializations are siblings of the generic class rather than
+        // subclasses of it: they share its host object type and superclass,
+        // but not its prototype.
+        let mut generic_constr: Object<'gc> = (*self).into();
+        let generic_proto = generic_constr
+            .get_property(
+                generic_constr,
+                &QName::new(Namespace::public_namespace(), "prototype"),
+                activation,
+            )?
+            .coerce_to_object(activation)?;
+        let class_proto = generic_proto.derive(activation, specialized_class, scope)?;
+        if let Some(super_proto) = generic_proto.proto() {
+            class_proto.set_proto(activation.context.gc_context, super_proto);
+        }
+
+        let (application, _cinit) = FunctionObject::from_class_and_proto(
+            activation,
+            specialized_class,
+            class_proto,
+            scope,
+        )?;
+
+        self.0
+            .write(activation.context.gc_context)
+            .applications
+            .push((param, application));
+
+        Ok(application)
+    }
+
     fn construct(
         &self,
         activation: &mut Activation<'_, 'gc, '_>,
@@ -324,7 +414,11 @@ impl<'gc> TObject<'gc> for FunctionObject<'gc> {
 
         Ok(FunctionObject(GcCell::allocate(
             activation.context.gc_context,
-            FunctionObjectData { base, exec: None },
+            FunctionObjectData {
+                base,
+                exec: None,
+                applications: Vec::new(),
+            },
         ))
         .into())
     }
@@ -343,7 +437,11 @@ impl<'gc> TObject<'gc> for FunctionObject<'gc> {
 
         Ok(FunctionObject(GcCell::allocate(
             activation.context.gc_context,
-            FunctionObjectData { base, exec: None },
+            FunctionObjectData {
+                base,
+                exec: None,
+                applications: Vec::new(),
+            },
         ))
         .into())
     }
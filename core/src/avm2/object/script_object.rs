@@ -12,10 +12,12 @@ use crate::avm2::slot::Slot;
 use crate::avm2::string::AvmString;
 use crate::avm2::traits::Trait;
 use crate::avm2::value::Value;
+use crate::avm2::weak::LivenessToken;
 use crate::avm2::Error;
 use gc_arena::{Collect, GcCell, MutationContext};
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::rc::Weak;
 
 /// Default implementation of `avm2::Object`.
 #[derive(Clone, Collect, Debug, Copy)]
@@ -71,6 +73,9 @@ pub struct ScriptObjectData<'gc> {
 
     /// Interfaces implemented by this object. (prototypes only)
     interfaces: Vec<Object<'gc>>,
+
+    /// Token that expires when this object is collected.
+    liveness: LivenessToken,
 }
 
 impl<'gc> TObject<'gc> for ScriptObject<'gc> {
@@ -242,6 +247,10 @@ impl<'gc> TObject<'gc> for ScriptObject<'gc> {
         self.0.as_ptr() as *const ObjectPtr
     }
 
+    fn liveness(&self, mc: MutationContext<'gc, '_>) -> Weak<()> {
+        self.0.write(mc).liveness()
+    }
+
     fn construct(
         &self,
         activation: &mut Activation<'_, 'gc, '_>,
@@ -417,6 +426,7 @@ impl<'gc> ScriptObjectData<'gc> {
             class: trait_source,
             enumerants: Vec::new(),
             interfaces: Vec::new(),
+            liveness: LivenessToken::default(),
         }
     }
 
@@ -921,6 +931,11 @@ impl<'gc> ScriptObjectData<'gc> {
         self.interfaces = iface_list;
     }
 
+    /// Get a handle that expires once this object has been collected.
+    pub fn liveness(&mut self) -> Weak<()> {
+        self.liveness.handle()
+    }
+
     /// Get the class for this object, if it has one.
     pub fn as_class(&self) -> Option<GcCell<'gc, Class<'gc>>> {
         match self.class {
@@ -0,0 +1,95 @@
+//! Object representation for `flash.media.Sound`
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::script_object::{ScriptObjectClass, ScriptObjectData};
+use crate::avm2::object::{Object, ObjectPtr, TObject};
+use crate::avm2::scope::Scope;
+use crate::avm2::string::AvmString;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::backend::audio::SoundHandle;
+use crate::{impl_avm2_custom_object, impl_avm2_custom_object_properties};
+use gc_arena::{Collect, GcCell, MutationContext};
+
+/// An object that holds a sound registered with the audio backend.
+#[derive(Collect, Debug, Clone, Copy)]
+#[collect(no_drop)]
+pub struct SoundObject<'gc>(GcCell<'gc, SoundObjectData<'gc>>);
+
+#[derive(Debug, Clone)]
+pub struct SoundObjectData<'gc> {
+    /// Base script object
+    base: ScriptObjectData<'gc>,
+
+    /// The sound this object plays, once it has been embedded or loaded.
+    sound: Option<SoundHandle>,
+}
+
+unsafe impl<'gc> Collect for SoundObjectData<'gc> {
+    fn trace(&self, cc: gc_arena::CollectionContext) {
+        self.base.trace(cc);
+    }
+}
+
+impl<'gc> SoundObject<'gc> {
+    /// Construct a sound subclass.
+    pub fn derive(
+        base_proto: Object<'gc>,
+        mc: MutationContext<'gc, '_>,
+        class: GcCell<'gc, Class<'gc>>,
+        scope: Option<GcCell<'gc, Scope<'gc>>>,
+    ) -> Result<Object<'gc>, Error> {
+        let base = ScriptObjectData::base_new(
+            Some(base_proto),
+            ScriptObjectClass::InstancePrototype(class, scope),
+        );
+
+        Ok(SoundObject(GcCell::allocate(mc, SoundObjectData { base, sound: None })).into())
+    }
+}
+
+impl<'gc> TObject<'gc> for SoundObject<'gc> {
+    impl_avm2_custom_object!(base);
+    impl_avm2_custom_object_properties!(base);
+
+    fn value_of(&self, _mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
+        Ok(Object::from(*self).into())
+    }
+
+    fn as_sound(&self) -> Option<SoundHandle> {
+        self.0.read().sound
+    }
+
+    fn set_sound(&self, mc: MutationContext<'gc, '_>, sound: SoundHandle) {
+        self.0.write(mc).sound = Some(sound);
+    }
+
+    fn construct(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        _args: &[Value<'gc>],
+    ) -> Result<Object<'gc>, Error> {
+        let this: Object<'gc> = Object::SoundObject(*self);
+        let base = ScriptObjectData::base_new(Some(this), ScriptObjectClass::NoClass);
+
+        Ok(SoundObject(GcCell::allocate(
+            activation.context.gc_context,
+            SoundObjectData { base, sound: None },
+        ))
+        .into())
+    }
+
+    fn derive(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        class: GcCell<'gc, Class<'gc>>,
+        scope: Option<GcCell<'gc, Scope<'gc>>>,
+    ) -> Result<Object<'gc>, Error> {
+        let this: Object<'gc> = Object::SoundObject(*self);
+
+        SoundObject::derive(this, activation.context.gc_context, class, scope)
+    }
+}
@@ -0,0 +1,105 @@
+//! Object representation for `flash.media.SoundChannel`
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::script_object::{ScriptObjectClass, ScriptObjectData};
+use crate::avm2::object::{Object, ObjectPtr, TObject};
+use crate::avm2::scope::Scope;
+use crate::avm2::string::AvmString;
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::backend::audio::SoundInstanceHandle;
+use crate::{impl_avm2_custom_object, impl_avm2_custom_object_properties};
+use gc_arena::{Collect, GcCell, MutationContext};
+
+/// An object that controls a sound playing in the audio backend.
+#[derive(Collect, Debug, Clone, Copy)]
+#[collect(no_drop)]
+pub struct SoundChannelObject<'gc>(GcCell<'gc, SoundChannelObjectData<'gc>>);
+
+#[derive(Debug, Clone)]
+pub struct SoundChannelObjectData<'gc> {
+    /// Base script object
+    base: ScriptObjectData<'gc>,
+
+    /// The sound instance this channel controls, once it has started playing.
+    instance: Option<SoundInstanceHandle>,
+}
+
+unsafe impl<'gc> Collect for SoundChannelObjectData<'gc> {
+    fn trace(&self, cc: gc_arena::CollectionContext) {
+        self.base.trace(cc);
+    }
+}
+
+impl<'gc> SoundChannelObject<'gc> {
+    /// Construct a sound channel subclass.
+    pub fn derive(
+        base_proto: Object<'gc>,
+        mc: MutationContext<'gc, '_>,
+        class: GcCell<'gc, Class<'gc>>,
+        scope: Option<GcCell<'gc, Scope<'gc>>>,
+    ) -> Result<Object<'gc>, Error> {
+        let base = ScriptObjectData::base_new(
+            Some(base_proto),
+            ScriptObjectClass::InstancePrototype(class, scope),
+        );
+
+        Ok(SoundChannelObject(GcCell::allocate(
+            mc,
+            SoundChannelObjectData {
+                base,
+                instance: None,
+            },
+        ))
+        .into())
+    }
+}
+
+impl<'gc> TObject<'gc> for SoundChannelObject<'gc> {
+    impl_avm2_custom_object!(base);
+    impl_avm2_custom_object_properties!(base);
+
+    fn value_of(&self, _mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
+        Ok(Object::from(*self).into())
+    }
+
+    fn as_sound_instance(&self) -> Option<SoundInstanceHandle> {
+        self.0.read().instance
+    }
+
+    fn set_sound_instance(&self, mc: MutationContext<'gc, '_>, instance: SoundInstanceHandle) {
+        self.0.write(mc).instance = Some(instance);
+    }
+
+    fn construct(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        _args: &[Value<'gc>],
+    ) -> Result<Object<'gc>, Error> {
+        let this: Object<'gc> = Object::SoundChannelObject(*self);
+        let base = ScriptObjectData::base_new(Some(this), ScriptObjectClass::NoClass);
+
+        Ok(SoundChannelObject(GcCell::allocate(
+            activation.context.gc_context,
+            SoundChannelObjectData {
+                base,
+                instance: None,
+            },
+        ))
+        .into())
+    }
+
+    fn derive(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        class: GcCell<'gc, Class<'gc>>,
+        scope: Option<GcCell<'gc, Scope<'gc>>>,
+    ) -> Result<Object<'gc>, Error> {
+        let this: Object<'gc> = Object::SoundChannelObject(*self);
+
+        SoundChannelObject::derive(this, activation.context.gc_context, class, scope)
+    }
+}
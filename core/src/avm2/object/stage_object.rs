@@ -13,6 +13,7 @@ use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::display_object::DisplayObject;
 use gc_arena::{Collect, GcCell, MutationContext};
+use std::rc::Weak;
 
 #[derive(Clone, Collect, Debug, Copy)]
 #[collect(no_drop)]
@@ -244,6 +245,10 @@ impl<'gc> TObject<'gc> for StageObject<'gc> {
         self.0.as_ptr() as *const ObjectPtr
     }
 
+    fn liveness(&self, mc: MutationContext<'gc, '_>) -> Weak<()> {
+        self.0.write(mc).base.liveness()
+    }
+
     fn as_executable(&self) -> Option<Executable<'gc>> {
         None
     }
@@ -1,6 +1,7 @@
 //! Represents AVM2 scope chain resolution.
 
 use crate::avm2::activation::Activation;
+use crate::avm2::e4x;
 use crate::avm2::names::Multiname;
 use crate::avm2::object::{Object, TObject};
 use crate::avm2::value::Value;
@@ -118,6 +119,20 @@ impl<'gc> Scope<'gc> {
             }
         }
 
+        // XML in a `with` scope (such as in an E4X filter) provides its
+        // attributes and children as properties.
+        if self.class == ScopeClass::With {
+            if let Some(nodes) = e4x::nodes_of(*self.locals()) {
+                let default_namespace = activation.default_xml_namespace();
+
+                if name.is_attribute()
+                    || !e4x::get_property(&nodes, name, default_namespace.as_ref()).is_empty()
+                {
+                    return Ok(Some(*self.locals()));
+                }
+            }
+        }
+
         if let Some(scope) = self.parent() {
             return scope.find(name, activation);
         }
@@ -1,6 +1,7 @@
 //! Whole script representation
 
 use crate::avm2::activation::Activation;
+use crate::avm2::call_stack;
 use crate::avm2::class::Class;
 use crate::avm2::domain::Domain;
 use crate::avm2::method::{BytecodeMethod, Method};
@@ -55,6 +56,10 @@ pub struct TranslationUnitData<'gc> {
 
     /// All strings loaded from the ABC's strings list.
     strings: FnvHashMap<u32, AvmString<'gc>>,
+
+    /// The names of all methods in the ABC's method list, as printed in
+    /// stack traces.
+    method_names: Vec<String>,
 }
 
 impl<'gc> TranslationUnit<'gc> {
@@ -65,6 +70,7 @@ impl<'gc> TranslationUnit<'gc> {
             mc,
             TranslationUnitData {
                 domain,
+                method_names: call_stack::method_names(&abc),
                 abc: CollectWrapper(abc),
                 classes: FnvHashMap::default(),
                 methods: FnvHashMap::default(),
@@ -79,6 +85,16 @@ impl<'gc> TranslationUnit<'gc> {
         self.0.read().abc.0.clone()
     }
 
+    /// Get the name of a method in the ABC file, as printed in stack traces.
+    pub fn method_name(self, method_index: u32) -> String {
+        self.0
+            .read()
+            .method_names
+            .get(method_index as usize)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     /// Load a method from the ABC file and return its method definition.
     pub fn load_method(
         self,
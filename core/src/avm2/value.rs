@@ -134,7 +134,15 @@ impl PartialEq for Value<'_> {
             (Value::Integer(a), Value::Unsigned(b)) => *a as i64 == *b as i64,
             (Value::Integer(a), Value::Integer(b)) => a == b,
             (Value::String(a), Value::String(b)) => a == b,
-            (Value::Object(a), Value::Object(b)) => Object::ptr_eq(*a, *b),
+            (Value::Object(a), Value::Object(b)) => {
+                // `XML` objects are boxed afresh whenever a node is accessed,
+                // so they are identical if they box the same node.
+                Object::ptr_eq(*a, *b)
+                    || match (a.as_xml(), b.as_xml()) {
+                        (Some(a), Some(b)) => a.ptr_eq(&b),
+                        _ => false,
+                    }
+            }
             _ => false,
         }
     }
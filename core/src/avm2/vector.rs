@@ -0,0 +1,404 @@
+//! Vector support types
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::Collect;
+use std::cmp::min;
+
+/// The element storage of a vector.
+///
+/// Vectors of `int`, `uint`, and `Number` store their contents unboxed. All
+/// other vectors store `Value`s which have already been coerced to the
+/// vector's value type.
+#[derive(Clone, Collect, Debug)]
+#[collect(no_drop)]
+enum VectorData<'gc> {
+    Int(Vec<i32>),
+    Uint(Vec<u32>),
+    Number(Vec<f64>),
+    Any(Vec<Value<'gc>>),
+}
+
+/// Extract a numeric value that has already been coerced by `VectorStorage`.
+fn coerced_number(value: &Value<'_>) -> f64 {
+    match value {
+        Value::Number(n) => *n,
+        Value::Integer(i) => *i as f64,
+        Value::Unsigned(u) => *u as f64,
+        _ => f64::NAN,
+    }
+}
+
+/// A builtin class that vectors store or coerce their values as.
+#[derive(Clone, Collect, Copy, Debug, PartialEq, Eq)]
+#[collect(require_static)]
+enum BuiltinType {
+    Int,
+    Uint,
+    Number,
+    Boolean,
+    String,
+    Object,
+}
+
+/// Determine which builtin class, if any, the given class object is.
+///
+/// User classes may share a local name with a builtin, so this compares
+/// against the builtin class objects themselves.
+fn builtin_type<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value_type: Object<'gc>,
+) -> Option<BuiltinType> {
+    let classes = activation.avm2().classes();
+
+    [
+        (classes.int, BuiltinType::Int),
+        (classes.uint, BuiltinType::Uint),
+        (classes.number, BuiltinType::Number),
+        (classes.boolean, BuiltinType::Boolean),
+        (classes.string, BuiltinType::String),
+        (classes.object, BuiltinType::Object),
+    ]
+    .iter()
+    .find(|(class, _)| Object::ptr_eq(*class, value_type))
+    .map(|(_, builtin)| *builtin)
+}
+
+/// The vector storage portion of a vector object.
+///
+/// Vector values are strictly typed: every value written into the vector is
+/// coerced to the vector's value type first. Unlike arrays, vectors have no
+/// holes; a vector may also be `fixed`, in which case it cannot change
+/// length.
+#[derive(Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub struct VectorStorage<'gc> {
+    /// The elements of the vector.
+    storage: VectorData<'gc>,
+
+    /// Whether or not the vector is prohibited from changing length.
+    is_fixed: bool,
+
+    /// The class of all values stored in the vector.
+    ///
+    /// `None` indicates the any type (`Vector.<*>`).
+    value_type: Option<Object<'gc>>,
+
+    /// The builtin class the value type is, if any.
+    builtin_type: Option<BuiltinType>,
+}
+
+impl<'gc> VectorStorage<'gc> {
+    /// Construct new vector storage.
+    ///
+    /// The length parameter indicates how big the vector storage should start
+    /// out as. All elements start out as the default value of the value type.
+    pub fn new(
+        length: usize,
+        is_fixed: bool,
+        value_type: Option<Object<'gc>>,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Self {
+        let builtin_type = value_type.and_then(|vt| builtin_type(activation, vt));
+        let storage = match builtin_type {
+            Some(BuiltinType::Int) => VectorData::Int(vec![0; length]),
+            Some(BuiltinType::Uint) => VectorData::Uint(vec![0; length]),
+            Some(BuiltinType::Number) => VectorData::Number(vec![0.0; length]),
+            _ => VectorData::Any(vec![Self::default_for(value_type, builtin_type); length]),
+        };
+
+        Self {
+            storage,
+            is_fixed,
+            value_type,
+            builtin_type,
+        }
+    }
+
+    /// Construct new vector storage from a list of already-coerced values.
+    pub fn from_values(
+        values: Vec<Value<'gc>>,
+        is_fixed: bool,
+        value_type: Option<Object<'gc>>,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Self {
+        let mut new_storage = Self::new(0, false, value_type, activation);
+
+        new_storage.replace_values(values);
+        new_storage.is_fixed = is_fixed;
+
+        new_storage
+    }
+
+    /// Get the default value for a given vector value type.
+    fn default_for(
+        value_type: Option<Object<'gc>>,
+        builtin_type: Option<BuiltinType>,
+    ) -> Value<'gc> {
+        if value_type.is_none() {
+            return Value::Undefined;
+        }
+
+        match builtin_type {
+            Some(BuiltinType::Int) | Some(BuiltinType::Uint) | Some(BuiltinType::Number) => {
+                0.into()
+            }
+            Some(BuiltinType::Boolean) => false.into(),
+            _ => Value::Null,
+        }
+    }
+
+    /// Get the default value for this vector's value type.
+    pub fn default_value(&self) -> Value<'gc> {
+        Self::default_for(self.value_type, self.builtin_type)
+    }
+
+    /// Get the class of values in this vector.
+    pub fn value_type(&self) -> Option<Object<'gc>> {
+        self.value_type
+    }
+
+    /// Check if the vector is fixed-length.
+    pub fn is_fixed(&self) -> bool {
+        self.is_fixed
+    }
+
+    /// Set or clear the fixed-length flag of this vector.
+    pub fn set_is_fixed(&mut self, is_fixed: bool) {
+        self.is_fixed = is_fixed;
+    }
+
+    /// Get the length of the vector.
+    pub fn length(&self) -> usize {
+        match &self.storage {
+            VectorData::Int(v) => v.len(),
+            VectorData::Uint(v) => v.len(),
+            VectorData::Number(v) => v.len(),
+            VectorData::Any(v) => v.len(),
+        }
+    }
+
+    /// Yield an error if this vector's length cannot change.
+    fn check_fixed(&self) -> Result<(), Error> {
+        if self.is_fixed {
+            return Err("RangeError: Vector is fixed".into());
+        }
+
+        Ok(())
+    }
+
+    /// Set the length of the vector.
+    ///
+    /// New elements are filled with the default value of the value type.
+    pub fn resize(&mut self, new_length: usize) -> Result<(), Error> {
+        self.check_fixed()?;
+
+        let default = self.default_value();
+
+        match &mut self.storage {
+            VectorData::Int(v) => v.resize(new_length, 0),
+            VectorData::Uint(v) => v.resize(new_length, 0),
+            VectorData::Number(v) => v.resize(new_length, 0.0),
+            VectorData::Any(v) => v.resize(new_length, default),
+        }
+
+        Ok(())
+    }
+
+    /// Coerce a value into a given vector value type.
+    ///
+    /// All values must be coerced with this function before they are stored
+    /// into a vector with that value type. This does not borrow the vector,
+    /// as coercion may run user code which accesses it.
+    pub fn coerce(
+        value: Value<'gc>,
+        value_type: Option<Object<'gc>>,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        let value_type = match value_type {
+            Some(value_type) => value_type,
+            None => return Ok(value),
+        };
+
+        match builtin_type(activation, value_type) {
+            Some(BuiltinType::Int) => return Ok(value.coerce_to_i32(activation)?.into()),
+            Some(BuiltinType::Uint) => return Ok(value.coerce_to_u32(activation)?.into()),
+            Some(BuiltinType::Number) => return Ok(value.coerce_to_number(activation)?.into()),
+            Some(BuiltinType::Boolean) => return Ok(value.coerce_to_boolean().into()),
+            Some(BuiltinType::String) => {
+                return match value {
+                    Value::Undefined | Value::Null => Ok(Value::Null),
+                    value => Ok(value.coerce_to_string(activation)?.into()),
+                }
+            }
+            Some(BuiltinType::Object) => {
+                return match value {
+                    Value::Undefined => Ok(Value::Null),
+                    value => Ok(value),
+                }
+            }
+            _ => {}
+        };
+
+        match value {
+            Value::Undefined | Value::Null => Ok(Value::Null),
+            Value::Object(object) if object.is_instance_of(activation, value_type, true)? => {
+                Ok(value)
+            }
+            value => {
+                let type_name = value_type
+                    .as_class()
+                    .map(|c| c.read().name().local_name().to_string())
+                    .unwrap_or_else(|| "*".to_string());
+
+                Err(format!(
+                    "TypeError: Type Coercion failed: cannot convert {} to {}",
+                    value.coerce_to_debug_string(activation)?,
+                    type_name
+                )
+                .into())
+            }
+        }
+    }
+
+    /// Retrieve a value from vector storage by index.
+    ///
+    /// Out of bounds indices yield `None`.
+    pub fn get(&self, pos: usize) -> Option<Value<'gc>> {
+        match &self.storage {
+            VectorData::Int(v) => v.get(pos).map(|i| (*i).into()),
+            VectorData::Uint(v) => v.get(pos).map(|u| (*u).into()),
+            VectorData::Number(v) => v.get(pos).map(|n| (*n).into()),
+            VectorData::Any(v) => v.get(pos).cloned(),
+        }
+    }
+
+    /// Store an already-coerced value in the vector.
+    ///
+    /// Writing to the index just past the end of a non-fixed vector appends
+    /// the value. Any other out of bounds write is an error.
+    pub fn set(&mut self, pos: usize, value: Value<'gc>) -> Result<(), Error> {
+        let length = self.length();
+
+        if pos > length || (pos == length && self.is_fixed) {
+            return Err(format!(
+                "RangeError: Index {} is out of range for a vector of length {}",
+                pos, length
+            )
+            .into());
+        }
+
+        if pos == length {
+            return self.push(value);
+        }
+
+        match &mut self.storage {
+            VectorData::Int(v) => v[pos] = coerced_number(&value) as i32,
+            VectorData::Uint(v) => v[pos] = coerced_number(&value) as u32,
+            VectorData::Number(v) => v[pos] = coerced_number(&value),
+            VectorData::Any(v) => v[pos] = value,
+        };
+
+        Ok(())
+    }
+
+    /// Insert an already-coerced value at a given position in the vector.
+    pub fn insert(&mut self, pos: usize, value: Value<'gc>) -> Result<(), Error> {
+        self.check_fixed()?;
+
+        let pos = min(pos, self.length());
+
+        match &mut self.storage {
+            VectorData::Int(v) => v.insert(pos, coerced_number(&value) as i32),
+            VectorData::Uint(v) => v.insert(pos, coerced_number(&value) as u32),
+            VectorData::Number(v) => v.insert(pos, coerced_number(&value)),
+            VectorData::Any(v) => v.insert(pos, value),
+        };
+
+        Ok(())
+    }
+
+    /// Remove a value from a given position in the vector.
+    pub fn remove(&mut self, pos: usize) -> Result<Value<'gc>, Error> {
+        self.check_fixed()?;
+
+        if pos >= self.length() {
+            return Err(format!("RangeError: Index {} is out of range", pos).into());
+        }
+
+        Ok(match &mut self.storage {
+            VectorData::Int(v) => v.remove(pos).into(),
+            VectorData::Uint(v) => v.remove(pos).into(),
+            VectorData::Number(v) => v.remove(pos).into(),
+            VectorData::Any(v) => v.remove(pos),
+        })
+    }
+
+    /// Push an already-coerced value onto the end of the vector.
+    pub fn push(&mut self, value: Value<'gc>) -> Result<(), Error> {
+        let length = self.length();
+
+        self.insert(length, value)
+    }
+
+    /// Pop a value from the back of the vector.
+    ///
+    /// Popping from an empty vector yields the default value of the value
+    /// type.
+    pub fn pop(&mut self) -> Result<Value<'gc>, Error> {
+        self.check_fixed()?;
+
+        match self.length() {
+            0 => Ok(self.default_value()),
+            length => self.remove(length - 1),
+        }
+    }
+
+    /// Shift a value from the front of the vector.
+    ///
+    /// Shifting from an empty vector yields the default value of the value
+    /// type.
+    pub fn shift(&mut self) -> Result<Value<'gc>, Error> {
+        self.check_fixed()?;
+
+        match self.length() {
+            0 => Ok(self.default_value()),
+            _ => self.remove(0),
+        }
+    }
+
+    /// Unshift an already-coerced value onto the start of the vector.
+    pub fn unshift(&mut self, value: Value<'gc>) -> Result<(), Error> {
+        self.insert(0, value)
+    }
+
+    /// Replace the contents of the vector with a list of already-coerced
+    /// values.
+    ///
+    /// This ignores the fixed-length flag, and is intended for operations
+    /// which rearrange, but do not add or remove, values.
+    pub fn replace_values(&mut self, values: Vec<Value<'gc>>) {
+        self.storage = match &self.storage {
+            VectorData::Int(_) => {
+                VectorData::Int(values.iter().map(|v| coerced_number(v) as i32).collect())
+            }
+            VectorData::Uint(_) => {
+                VectorData::Uint(values.iter().map(|v| coerced_number(v) as u32).collect())
+            }
+            VectorData::Number(_) => {
+                VectorData::Number(values.iter().map(coerced_number).collect())
+            }
+            VectorData::Any(_) => VectorData::Any(values),
+        };
+    }
+
+    /// Iterate over vector values.
+    pub fn iter<'a>(
+        &'a self,
+    ) -> impl DoubleEndedIterator<Item = Value<'gc>> + ExactSizeIterator<Item = Value<'gc>> + 'a
+    {
+        (0..self.length()).map(move |i| self.get(i).unwrap())
+    }
+}
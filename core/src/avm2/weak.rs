@@ -0,0 +1,252 @@
+//! Weak references to AVM2 objects
+//!
+//! `gc_arena` has no weak pointers of its own, so weak references are built
+//! out of two pieces:
+//!
+//!  * Every object owns a `LivenessToken`. Weak references hold a `Weak`
+//!    handle to it, which expires when the collector frees the object and
+//!    drops the token along with it. This lets a weak reference tell that
+//!    its object is gone without ever touching the object's memory.
+//!  * Weak containers never trace their referents, so an object that is only
+//!    weakly reachable is freed by the next ordinary collection cycle.
+//!
+//! The one thing the collector must never do is free an object that a weak
+//! reference handed out during the current cycle. While the collector is
+//! marking, anything a script does with such an object goes through a write
+//! barrier and gets it marked. Once the collector starts sweeping, every
+//! unmarked object is doomed, so the player never lets scripts run while a
+//! sweep is in progress: it finishes any sweep that it sees start, and then
+//! purges the weak containers before running anything else.
+//!
+//! To see a sweep start, a `SweepCanary` is allocated right before each bit
+//! of incremental collection. Nothing references it, and as the newest
+//! allocation it is the very first object a sweep frees.
+
+use crate::avm2::object::{Object, ObjectPtr, TObject};
+use gc_arena::{Collect, CollectionContext, Gc, MutationContext};
+use std::cell::Cell;
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+/// A token that expires when the object owning it is collected.
+#[derive(Collect, Debug, Default)]
+#[collect(require_static)]
+pub struct LivenessToken(Option<Rc<()>>);
+
+impl LivenessToken {
+    /// Get a handle that expires once this token is dropped.
+    pub fn handle(&mut self) -> Weak<()> {
+        Rc::downgrade(self.0.get_or_insert_with(|| Rc::new(())))
+    }
+}
+
+impl Clone for LivenessToken {
+    /// A copy of an object's data belongs to a different object, so it gets
+    /// its own token.
+    fn clone(&self) -> Self {
+        Self(None)
+    }
+}
+
+/// An unreachable allocation that raises a flag once the collector frees it.
+#[derive(Collect)]
+#[collect(require_static)]
+struct SweepCanary(Rc<Cell<bool>>);
+
+impl Drop for SweepCanary {
+    fn drop(&mut self) {
+        self.0.set(true);
+    }
+}
+
+/// A reference to an object that does not keep it alive.
+///
+/// The object's memory may be freed while this reference still exists, so
+/// the object handle is only ever handed out after the liveness token has
+/// been checked. Everything else, including the `Debug` output, works from
+/// the object's address alone.
+#[derive(Clone)]
+pub struct WeakObject<'gc> {
+    ptr: *const ObjectPtr,
+    object: Object<'gc>,
+    liveness: Weak<()>,
+}
+
+impl<'gc> WeakObject<'gc> {
+    /// Create a weak reference to an object.
+    pub fn new(object: Object<'gc>, mc: MutationContext<'gc, '_>) -> Self {
+        Self {
+            ptr: object.as_ptr(),
+            object,
+            liveness: object.liveness(mc),
+        }
+    }
+
+    /// Check if the referenced object has not been collected.
+    pub fn is_alive(&self) -> bool {
+        self.liveness.strong_count() > 0
+    }
+
+    /// Get the referenced object, if it has not been collected.
+    pub fn upgrade(&self) -> Option<Object<'gc>> {
+        if self.is_alive() {
+            Some(self.object)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'gc> fmt::Debug for WeakObject<'gc> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakObject")
+            .field("ptr", &self.ptr)
+            .field("alive", &self.is_alive())
+            .finish()
+    }
+}
+
+/// The player-wide state of weak references.
+#[derive(Debug)]
+pub struct WeakReferences<'gc> {
+    /// Set once the collector has freed a `SweepCanary`, which means that it
+    /// has started sweeping.
+    sweeping: Rc<Cell<bool>>,
+
+    /// Whether script code asked for a full collection.
+    requested: bool,
+
+    /// Every container that holds weak references and has to be purged
+    /// after each sweep.
+    containers: Vec<WeakObject<'gc>>,
+}
+
+// SAFETY: Tracing nothing is sound because nothing in here is ever used
+// without checking that it is still alive:
+//
+//  * `containers` only hold weak references, which the collector is free to
+//    release. A released container's pointer is never dereferenced, as
+//    `WeakObject::upgrade` checks the container's liveness token first, and
+//    the token is dropped together with the container's memory.
+//  * The containers are only upgraded by `finish_collection`, which runs
+//    after a sweep has finished, so they are never handed out while the
+//    collector could still free them.
+//  * `sweeping` holds no `Gc` pointers.
+unsafe impl<'gc> Collect for WeakReferences<'gc> {
+    fn trace(&self, _cc: CollectionContext) {}
+}
+
+impl<'gc> WeakReferences<'gc> {
+    pub fn new() -> Self {
+        Self {
+            sweeping: Rc::new(Cell::new(false)),
+            requested: false,
+            containers: Vec::new(),
+        }
+    }
+
+    /// Register a container that holds weak references.
+    ///
+    /// The container will be purged after each sweep by calling
+    /// `TObject::purge_weak_references` on it.
+    pub fn register_container(&mut self, container: Object<'gc>, mc: MutationContext<'gc, '_>) {
+        self.containers.push(WeakObject::new(container, mc));
+    }
+
+    /// Ask for a full collection to run at the end of the current update.
+    pub fn request_collection(&mut self) {
+        self.requested = true;
+    }
+
+    /// Check if script code asked for a full collection.
+    pub fn is_collection_requested(&self) -> bool {
+        self.requested
+    }
+
+    /// Get ready for the collector to do some work.
+    ///
+    /// This must be the last allocation before the collector runs. Returns a
+    /// flag that is set if the collector starts sweeping, in which case the
+    /// sweep has to be finished and `finish_collection` called before any
+    /// script code runs again. Returns `None`, without allocating anything,
+    /// if there are no weak references to look after and no collection was
+    /// requested.
+    pub fn prepare_collection(&self, mc: MutationContext<'gc, '_>) -> Option<Rc<Cell<bool>>> {
+        if self.containers.is_empty() && !self.requested {
+            return None;
+        }
+
+        Gc::allocate(mc, SweepCanary(self.sweeping.clone()));
+
+        Some(self.sweeping.clone())
+    }
+
+    /// Purge every container of references to collected objects.
+    ///
+    /// This must run after a sweep has finished, before any other code gets
+    /// a chance to look at the containers.
+    pub fn finish_collection(&mut self, mc: MutationContext<'gc, '_>) {
+        self.sweeping.set(false);
+        self.requested = false;
+        self.containers
+            .retain(|container| container.upgrade().is_some());
+
+        for container in self.containers.iter() {
+            if let Some(container) = container.upgrade() {
+                container.purge_weak_references(mc);
+            }
+        }
+    }
+}
+
+impl<'gc> Default for WeakReferences<'gc> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm2::object::ScriptObject;
+    use gc_arena::{make_arena, ArenaParameters, GcCell};
+
+    #[derive(Collect)]
+    #[collect(no_drop)]
+    struct TestRoot<'gc> {
+        object: GcCell<'gc, Option<Object<'gc>>>,
+        weak_references: GcCell<'gc, WeakReferences<'gc>>,
+    }
+
+    make_arena!(TestArena, TestRoot);
+
+    #[test]
+    fn weak_object_outlives_object() {
+        let mut arena = TestArena::new(ArenaParameters::default(), |mc| {
+            let object = ScriptObject::bare_object(mc);
+            let mut weak_references = WeakReferences::new();
+            weak_references.register_container(object, mc);
+
+            TestRoot {
+                object: GcCell::allocate(mc, Some(object)),
+                weak_references: GcCell::allocate(mc, weak_references),
+            }
+        });
+
+        arena.mutate(|mc, root| {
+            let weak_references = root.weak_references.read();
+            assert!(weak_references.containers[0].upgrade().is_some());
+            assert!(format!("{:?}", weak_references).contains("alive: true"));
+
+            *root.object.write(mc) = None;
+        });
+
+        arena.collect_all();
+
+        arena.mutate(|_mc, root| {
+            let weak_references = root.weak_references.read();
+            assert!(weak_references.containers[0].upgrade().is_none());
+            assert!(format!("{:?}", weak_references).contains("alive: false"));
+        });
+    }
+}
@@ -2,6 +2,7 @@ use downcast_rs::Downcast;
 use generational_arena::{Arena, Index};
 
 pub mod decoders;
+pub mod spectrum;
 pub mod swf {
     pub use swf::{
         read, AudioCompression, CharacterId, Sound, SoundEnvelope, SoundEnvelopePoint, SoundEvent,
@@ -75,6 +76,75 @@ pub trait AudioBackend: Downcast {
     /// Returns `None` if sound is not registered.
     fn get_sound_duration(&self, sound: SoundHandle) -> Option<u32>;
 
+    /// Get the playback position of a sound instance in milliseconds.
+    /// Returns `None` if the sound instance has finished playing or was stopped.
+    fn get_sound_position(&self, instance: SoundInstanceHandle) -> Option<u32>;
+
+    /// Set the volume and panning of a playing sound instance.
+    /// No-op if the sound instance is not playing.
+    fn set_sound_transform(&mut self, _instance: SoundInstanceHandle, _transform: SoundTransform) {}
+
+    /// Starts playing a sound whose samples are generated by script, as with
+    /// AS3's `SampleDataEvent`.
+    ///
+    /// The sound plays samples queued with `queue_dynamic_sound_samples`,
+    /// and plays silence if it runs out before the final samples are queued.
+    fn start_dynamic_sound(&mut self) -> Result<SoundInstanceHandle, Error> {
+        Err("Script-generated sounds are not supported by this audio backend".into())
+    }
+
+    /// Queue 44.1kHz stereo samples to be played by a script-generated sound.
+    ///
+    /// If `is_final` is set, the sound ends once these samples have played.
+    fn queue_dynamic_sound_samples(
+        &mut self,
+        _instance: SoundInstanceHandle,
+        _samples: &[[f32; 2]],
+        _is_final: bool,
+    ) {
+    }
+
+    /// Get the number of queued sample frames of a script-generated sound
+    /// that have yet to be played.
+    /// Returns `None` if the sound has finished playing or was stopped.
+    fn dynamic_sound_buffered(&self, _instance: SoundInstanceHandle) -> Option<usize> {
+        None
+    }
+
+    /// Decode a registered sound to 44.1kHz stereo samples, as used by AS3's
+    /// `Sound.extract`.
+    /// Returns `None` if the sound is not registered or cannot be decoded.
+    fn get_sound_samples(&mut self, _sound: SoundHandle) -> Option<Vec<[f32; 2]>> {
+        None
+    }
+
+    /// Fill `output` with the most recent sample frames of the mixed audio
+    /// output, as used by AS3's `SoundMixer.computeSpectrum`.
+    ///
+    /// Backends that cannot capture their output leave it silent.
+    fn get_output_samples(&self, _output: &mut [[f32; 2]]) {}
+
+    /// Register an MP3 file, such as one loaded by AS3 `Sound.load`.
+    ///
+    /// Unlike the MP3 data of a `DefineSound` tag, the file has no seek
+    /// header and its format has to be determined from its frame headers.
+    fn register_mp3(&mut self, data: &[u8]) -> Result<SoundHandle, Error> {
+        let (format, num_samples) =
+            decoders::mp3_metadata(data).ok_or("Could not find any MP3 frames in sound data")?;
+
+        // Event sound MP3 data begins with a seek samples field.
+        let mut sound_data = Vec::with_capacity(data.len() + 2);
+        sound_data.extend_from_slice(&[0, 0]);
+        sound_data.extend_from_slice(data);
+
+        self.register_sound(&swf::Sound {
+            id: 0,
+            format,
+            num_samples,
+            data: sound_data,
+        })
+    }
+
     // TODO: Eventually remove this/move it to library.
     fn is_loading_complete(&self) -> bool {
         true
@@ -93,15 +163,39 @@ impl_downcast!(AudioBackend);
 
 /// Audio backend that ignores all audio.
 pub struct NullAudioBackend {
-    sounds: Arena<()>,
+    sounds: Arena<NullSound>,
+    sound_instances: Arena<NullSoundInstance>,
     streams: Arena<()>,
 }
 
+/// A sound registered with the `NullAudioBackend`.
+///
+/// The sound is never played, but scripts can still query its duration and
+/// extract its samples.
+struct NullSound {
+    format: swf::SoundFormat,
+    data: Vec<u8>,
+    num_sample_frames: u32,
+    skip_sample_frames: u16,
+
+    /// The duration of the sound in milliseconds.
+    duration: u32,
+}
+
+/// A sound instance started on the `NullAudioBackend`.
+struct NullSoundInstance {
+    /// The number of sample frames queued for a script-generated sound.
+    ///
+    /// As nothing is played, all of them stay buffered.
+    queued_frames: Option<usize>,
+}
+
 impl NullAudioBackend {
     pub fn new() -> NullAudioBackend {
         NullAudioBackend {
             streams: Arena::new(),
             sounds: Arena::new(),
+            sound_instances: Arena::new(),
         }
     }
 }
@@ -109,8 +203,29 @@ impl NullAudioBackend {
 impl AudioBackend for NullAudioBackend {
     fn play(&mut self) {}
     fn pause(&mut self) {}
-    fn register_sound(&mut self, _sound: &swf::Sound) -> Result<SoundHandle, Error> {
-        Ok(self.sounds.insert(()))
+    fn register_sound(&mut self, sound: &swf::Sound) -> Result<SoundHandle, Error> {
+        let duration = if sound.format.sample_rate > 0 {
+            (f64::from(sound.num_samples) * 1000.0 / f64::from(sound.format.sample_rate)) as u32
+        } else {
+            0
+        };
+
+        // Slice off latency seek for MP3 data.
+        let (skip_sample_frames, data) =
+            if sound.format.compression == swf::AudioCompression::Mp3 && sound.data.len() >= 2 {
+                let skip_sample_frames = u16::from(sound.data[0]) | (u16::from(sound.data[1]) << 8);
+                (skip_sample_frames, &sound.data[2..])
+            } else {
+                (0, &sound.data[..])
+            };
+
+        Ok(self.sounds.insert(NullSound {
+            format: sound.format.clone(),
+            data: data.to_vec(),
+            num_sample_frames: sound.num_samples,
+            skip_sample_frames,
+            duration,
+        }))
     }
 
     fn start_sound(
@@ -118,7 +233,9 @@ impl AudioBackend for NullAudioBackend {
         _sound: SoundHandle,
         _sound_info: &swf::SoundInfo,
     ) -> Result<SoundInstanceHandle, Error> {
-        Ok(SoundInstanceHandle::from_raw_parts(0, 0))
+        Ok(self.sound_instances.insert(NullSoundInstance {
+            queued_frames: None,
+        }))
     }
 
     fn start_stream(
@@ -131,20 +248,69 @@ impl AudioBackend for NullAudioBackend {
         Ok(self.streams.insert(()))
     }
 
-    fn stop_sound(&mut self, _sound: SoundInstanceHandle) {}
+    fn stop_sound(&mut self, sound: SoundInstanceHandle) {
+        self.sound_instances.remove(sound);
+    }
 
     fn stop_stream(&mut self, stream: AudioStreamHandle) {
         self.streams.remove(stream);
     }
-    fn stop_all_sounds(&mut self) {}
+    fn stop_all_sounds(&mut self) {
+        self.sound_instances.clear();
+    }
     fn stop_sounds_with_handle(&mut self, _handle: SoundHandle) {}
     fn is_sound_playing_with_handle(&mut self, _handle: SoundHandle) -> bool {
         false
     }
 
-    fn get_sound_duration(&self, _sound: SoundHandle) -> Option<u32> {
+    fn get_sound_duration(&self, sound: SoundHandle) -> Option<u32> {
+        self.sounds.get(sound).map(|sound| sound.duration)
+    }
+
+    fn get_sound_position(&self, _instance: SoundInstanceHandle) -> Option<u32> {
         None
     }
+
+    fn start_dynamic_sound(&mut self) -> Result<SoundInstanceHandle, Error> {
+        Ok(self.sound_instances.insert(NullSoundInstance {
+            queued_frames: Some(0),
+        }))
+    }
+
+    fn queue_dynamic_sound_samples(
+        &mut self,
+        instance: SoundInstanceHandle,
+        samples: &[[f32; 2]],
+        _is_final: bool,
+    ) {
+        if let Some(queued_frames) = self
+            .sound_instances
+            .get_mut(instance)
+            .and_then(|instance| instance.queued_frames.as_mut())
+        {
+            *queued_frames += samples.len();
+        }
+    }
+
+    fn dynamic_sound_buffered(&self, instance: SoundInstanceHandle) -> Option<usize> {
+        self.sound_instances.get(instance)?.queued_frames
+    }
+
+    fn get_sound_samples(&mut self, sound: SoundHandle) -> Option<Vec<[f32; 2]>> {
+        let sound = self.sounds.get(sound)?;
+        match decoders::decode_to_script_samples(
+            &sound.format,
+            &sound.data,
+            sound.num_sample_frames,
+            sound.skip_sample_frames,
+        ) {
+            Ok(samples) => Some(samples),
+            Err(e) => {
+                log::error!("Unable to decode sound: {}", e);
+                None
+            }
+        }
+    }
 }
 
 impl Default for NullAudioBackend {
@@ -152,3 +318,78 @@ impl Default for NullAudioBackend {
         NullAudioBackend::new()
     }
 }
+
+/// The volume and channel mixing applied to a playing sound.
+///
+/// This corresponds to AS3's `flash.media.SoundTransform`. Each output
+/// channel is the sum of both input channels scaled by the matching
+/// coefficients, and the result is scaled by `volume`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoundTransform {
+    pub volume: f32,
+    pub left_to_left: f32,
+    pub left_to_right: f32,
+    pub right_to_left: f32,
+    pub right_to_right: f32,
+}
+
+impl SoundTransform {
+    /// Create a transform with the given volume and panning, where a `pan`
+    /// of -1 plays only in the left speaker and 1 only in the right.
+    pub fn new(volume: f32, pan: f32) -> Self {
+        let (left_to_left, right_to_right) = if pan >= 0.0 {
+            (1.0 - pan, 1.0)
+        } else {
+            (1.0, 1.0 + pan)
+        };
+
+        Self {
+            volume,
+            left_to_left,
+            left_to_right: 0.0,
+            right_to_left: 0.0,
+            right_to_right,
+        }
+    }
+
+    /// The panning of this transform, from -1 (left) to 1 (right).
+    pub fn pan(&self) -> f32 {
+        self.right_to_right - self.left_to_left
+    }
+
+    /// Apply this transform to a stereo sample frame.
+    pub fn apply(&self, [left, right]: [f32; 2]) -> [f32; 2] {
+        [
+            (left * self.left_to_left + right * self.right_to_left) * self.volume,
+            (left * self.left_to_right + right * self.right_to_right) * self.volume,
+        ]
+    }
+
+    /// Combine this transform with an outer one, such as the global
+    /// `SoundMixer` transform.
+    pub fn concat(&self, outer: &SoundTransform) -> Self {
+        Self {
+            volume: self.volume * outer.volume,
+            left_to_left: self.left_to_left * outer.left_to_left
+                + self.left_to_right * outer.right_to_left,
+            left_to_right: self.left_to_left * outer.left_to_right
+                + self.left_to_right * outer.right_to_right,
+            right_to_left: self.right_to_left * outer.left_to_left
+                + self.right_to_right * outer.right_to_left,
+            right_to_right: self.right_to_left * outer.left_to_right
+                + self.right_to_right * outer.right_to_right,
+        }
+    }
+}
+
+impl Default for SoundTransform {
+    fn default() -> Self {
+        Self {
+            volume: 1.0,
+            left_to_left: 1.0,
+            left_to_right: 0.0,
+            right_to_left: 0.0,
+            right_to_right: 1.0,
+        }
+    }
+}
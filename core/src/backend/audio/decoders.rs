@@ -5,7 +5,7 @@ mod mp3;
 mod pcm;
 
 pub use adpcm::AdpcmDecoder;
-pub use mp3::Mp3Decoder;
+pub use mp3::{mp3_metadata, Mp3Decoder};
 pub use pcm::PcmDecoder;
 
 use crate::tag_utils::SwfSlice;
@@ -68,6 +68,57 @@ pub fn make_decoder<'a, R: 'a + Send + Read>(
     Ok(decoder)
 }
 
+/// The sample rate of the audio produced and consumed by AS3 scripts, as in
+/// `Sound.extract` and `SampleDataEvent`.
+pub const SCRIPT_SAMPLE_RATE: u32 = 44100;
+
+/// Decode a whole sound to floating point stereo samples at 44.1kHz.
+///
+/// `skip_sample_frames` frames of encoder delay are dropped from the start of
+/// the sound, and the output is limited to `num_sample_frames` frames of the
+/// original sound.
+pub fn decode_to_script_samples(
+    format: &SoundFormat,
+    data: &[u8],
+    num_sample_frames: u32,
+    skip_sample_frames: u16,
+) -> Result<Vec<[f32; 2]>, Error> {
+    let decoder = make_decoder(format, Cursor::new(data))?;
+    let frames: Vec<[f32; 2]> = decoder
+        .skip(skip_sample_frames.into())
+        .take(num_sample_frames as usize)
+        .map(|[left, right]| [f32::from(left) / 32768.0, f32::from(right) / 32768.0])
+        .collect();
+
+    Ok(resample_to_script_rate(&frames, format.sample_rate.into()))
+}
+
+/// Linearly resample floating point stereo samples to 44.1kHz.
+pub fn resample_to_script_rate(frames: &[[f32; 2]], sample_rate: u32) -> Vec<[f32; 2]> {
+    if sample_rate == SCRIPT_SAMPLE_RATE || sample_rate == 0 || frames.is_empty() {
+        return frames.to_vec();
+    }
+
+    let step = f64::from(sample_rate) / f64::from(SCRIPT_SAMPLE_RATE);
+    let num_output_frames =
+        (frames.len() as u64 * u64::from(SCRIPT_SAMPLE_RATE) / u64::from(sample_rate)) as usize;
+    let last = frames.len() - 1;
+
+    (0..num_output_frames)
+        .map(|i| {
+            let position = i as f64 * step;
+            let index = (position as usize).min(last);
+            let next = (index + 1).min(last);
+            let t = (position - index as f64) as f32;
+
+            [
+                frames[index][0] + (frames[next][0] - frames[index][0]) * t,
+                frames[index][1] + (frames[next][1] - frames[index][1]) * t,
+            ]
+        })
+        .collect()
+}
+
 /// A "stream" sound is a sound that has its data distributed across `SoundStreamBlock` tags,
 /// one per each frame of a MovieClip. The sound is synced to the MovieClip's timeline, and will
 /// stop/seek as the MovieClip stops/seeks.
@@ -260,20 +311,16 @@ impl Iterator for StreamTagReader {
                     // TODO: Implement index ops on `SwfSlice`.
                     let pos = reader.get_ref().position() as usize;
                     let pos = reader.get_ref().get_ref().start + pos;
-                    found = true;
-                    if tag_len >= skip_len {
-                        *audio_data = SwfSlice {
-                            movie: std::sync::Arc::clone(&reader.get_ref().get_ref().movie),
-                            start: pos + skip_len,
-                            end: pos + tag_len,
-                        };
+                    let slice = reader.get_ref().get_ref();
+                    let start = if tag_len >= skip_len {
+                        pos + skip_len
                     } else {
-                        *audio_data = SwfSlice {
-                            movie: std::sync::Arc::clone(&reader.get_ref().get_ref().movie),
-                            start: pos,
-                            end: pos + tag_len,
-                        };
+                        pos
                     };
+                    *audio_data = slice
+                        .to_movie_range(start, pos + tag_len)
+                        .ok_or("Unexpected end of tag")?;
+                    found = true;
                     Ok(())
                 }
                 _ => Ok(()),
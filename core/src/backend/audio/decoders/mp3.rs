@@ -1,5 +1,6 @@
 use super::{Decoder, SeekableDecoder};
 use std::io::{Cursor, Read};
+use swf::{AudioCompression, SoundFormat};
 
 #[cfg(feature = "minimp3")]
 #[allow(dead_code)]
@@ -170,3 +171,110 @@ impl<R: AsRef<[u8]> + Default> SeekableDecoder for Mp3Decoder<Cursor<R>> {
         *self = Mp3Decoder::new(self.num_channels, self.sample_rate, cursor);
     }
 }
+
+/// Bitrates of MPEG-1 Layer III frames, in kbps, by bitrate index.
+const MPEG1_BITRATES: [u32; 15] = [
+    0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+];
+
+/// Bitrates of MPEG-2 and MPEG-2.5 Layer III frames, in kbps, by bitrate index.
+const MPEG2_BITRATES: [u32; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+
+/// The header of a single MP3 frame.
+struct FrameHeader {
+    sample_rate: u32,
+    is_stereo: bool,
+    num_samples: u32,
+    frame_len: usize,
+}
+
+impl FrameHeader {
+    /// Parse an MP3 frame header, returning `None` if the bytes are not the
+    /// start of a valid MPEG Layer III frame.
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 4 || bytes[0] != 0xFF || bytes[1] & 0xE0 != 0xE0 {
+            return None;
+        }
+
+        let version = (bytes[1] >> 3) & 0b11;
+        let layer = (bytes[1] >> 1) & 0b11;
+        let bitrate_index = usize::from(bytes[2] >> 4);
+        let sample_rate_index = usize::from((bytes[2] >> 2) & 0b11);
+        let padding = usize::from((bytes[2] >> 1) & 0b1);
+        let is_stereo = bytes[3] >> 6 != 0b11;
+
+        // Only Layer III is supported, and the reserved version and
+        // "free" and "bad" bitrates are invalid.
+        if layer != 0b01 || version == 0b01 || bitrate_index == 0 || bitrate_index == 15 {
+            return None;
+        }
+
+        let (bitrate, sample_rates, num_samples, slot_factor) = match version {
+            0b11 => (
+                MPEG1_BITRATES[bitrate_index],
+                [44100, 48000, 32000],
+                1152,
+                144,
+            ),
+            0b10 => (
+                MPEG2_BITRATES[bitrate_index],
+                [22050, 24000, 16000],
+                576,
+                72,
+            ),
+            _ => (MPEG2_BITRATES[bitrate_index], [11025, 12000, 8000], 576, 72),
+        };
+        let sample_rate = *sample_rates.get(sample_rate_index)?;
+        let frame_len = (slot_factor * bitrate * 1000 / sample_rate) as usize + padding;
+
+        Some(Self {
+            sample_rate,
+            is_stereo,
+            num_samples,
+            frame_len,
+        })
+    }
+}
+
+/// Determine the format and length in sample frames of a raw MP3 file.
+///
+/// Any ID3v2 tag at the start of the file is skipped. Returns `None` if no
+/// MP3 frames could be found.
+pub fn mp3_metadata(data: &[u8]) -> Option<(SoundFormat, u32)> {
+    let mut pos = 0;
+
+    if data.len() >= 10 && &data[0..3] == b"ID3" {
+        // The tag size is a 28-bit "synchsafe" integer.
+        let size = data[6..10]
+            .iter()
+            .fold(0, |size, byte| (size << 7) | usize::from(byte & 0x7F));
+        let footer_len = if data[5] & 0x10 != 0 { 10 } else { 0 };
+
+        pos = 10 + size + footer_len;
+    }
+
+    let mut format = None;
+    let mut num_samples = 0;
+
+    while pos + 4 <= data.len() {
+        match FrameHeader::parse(&data[pos..]) {
+            Some(header) => {
+                if format.is_none() {
+                    format = Some(SoundFormat {
+                        compression: AudioCompression::Mp3,
+                        sample_rate: header.sample_rate as u16,
+                        is_stereo: header.is_stereo,
+                        is_16_bit: true,
+                    });
+                }
+
+                num_samples += header.num_samples;
+                pos += header.frame_len;
+            }
+            // Skip any junk until the next frame.
+            None => pos += 1,
+        }
+    }
+
+    format.map(|format| (format, num_samples))
+}
@@ -0,0 +1,93 @@
+//! Waveform and frequency spectrum snapshots of the mixer output, as used by
+//! AS3's `SoundMixer.computeSpectrum`.
+
+/// The number of values computed for each channel.
+pub const SPECTRUM_LENGTH: usize = 256;
+
+/// The largest supported `stretchFactor`.
+const MAX_STRETCH_FACTOR: u32 = 4;
+
+/// The number of output sample frames needed to compute a spectrum with any
+/// stretch factor.
+pub const SPECTRUM_HISTORY_LENGTH: usize = (2 * SPECTRUM_LENGTH) << MAX_STRETCH_FACTOR;
+
+/// Compute a snapshot of the given output samples, which should be the most
+/// recent `SPECTRUM_HISTORY_LENGTH` sample frames of mixer output.
+///
+/// Returns `SPECTRUM_LENGTH` values for the left channel, followed by as many
+/// for the right channel. If `fft_mode` is set, these are the magnitudes of
+/// the frequency bands of the output from low to high; otherwise they are
+/// the raw waveform. The samples are first reduced to every
+/// `2^stretch_factor`th frame.
+pub fn compute_spectrum(samples: &[[f32; 2]], fft_mode: bool, stretch_factor: u32) -> Vec<f32> {
+    let step = 1 << stretch_factor.min(MAX_STRETCH_FACTOR);
+    let window_len = if fft_mode {
+        2 * SPECTRUM_LENGTH
+    } else {
+        SPECTRUM_LENGTH
+    };
+
+    let mut output = Vec::with_capacity(2 * SPECTRUM_LENGTH);
+
+    for channel in 0..2 {
+        // Take the most recent frames, padding with silence if there are too few.
+        let mut window = vec![0.0; window_len];
+        let needed = window_len * step;
+        let skip = samples.len().saturating_sub(needed);
+        let pad = needed.saturating_sub(samples.len()) / step;
+
+        for (value, frame) in window[pad..]
+            .iter_mut()
+            .zip(samples[skip..].iter().step_by(step))
+        {
+            *value = frame[channel];
+        }
+
+        if fft_mode {
+            output.extend(fft_magnitudes(&window));
+        } else {
+            output.extend(window);
+        }
+    }
+
+    output
+}
+
+/// Compute the magnitudes of the lower half of the discrete Fourier
+/// transform of `samples`, whose length must be a power of two.
+fn fft_magnitudes(samples: &[f32]) -> Vec<f32> {
+    let n = samples.len();
+    let bits = n.trailing_zeros();
+
+    // Load the input in bit-reversed order for the in-place transform.
+    let mut re: Vec<f32> = (0..n)
+        .map(|i| {
+            let reversed = (0..bits).fold(0, |acc, bit| (acc << 1) | ((i >> bit) & 1));
+            samples[reversed]
+        })
+        .collect();
+    let mut im = vec![0.0f32; n];
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * std::f32::consts::PI / len as f32;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f32).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let t_re = re[b] * cos - im[b] * sin;
+                let t_im = re[b] * sin + im[b] * cos;
+
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+        }
+        len *= 2;
+    }
+
+    (0..n / 2)
+        .map(|i| re[i].hypot(im[i]) * 2.0 / n as f32)
+        .collect()
+}
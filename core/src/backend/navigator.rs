@@ -107,6 +107,9 @@ pub struct RequestOptions {
     ///
     /// The body consists of data and a mime type.
     body: Option<(Vec<u8>, String)>,
+
+    /// Additional headers to send with the request, as name/value pairs.
+    headers: Vec<(String, String)>,
 }
 
 impl RequestOptions {
@@ -115,6 +118,7 @@ impl RequestOptions {
         Self {
             method: NavigationMethod::GET,
             body: None,
+            headers: Vec::new(),
         }
     }
 
@@ -123,9 +127,16 @@ impl RequestOptions {
         Self {
             method: NavigationMethod::POST,
             body,
+            headers: Vec::new(),
         }
     }
 
+    /// Attach additional headers to these request options.
+    pub fn with_headers(mut self, headers: Vec<(String, String)>) -> Self {
+        self.headers = headers;
+        self
+    }
+
     /// Retrieve the navigation method for this request.
     pub fn method(&self) -> NavigationMethod {
         self.method
@@ -135,12 +146,54 @@ impl RequestOptions {
     pub fn body(&self) -> &Option<(Vec<u8>, String)> {
         &self.body
     }
+
+    /// Retrieve the additional headers of this request.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
 }
 
 /// Type alias for pinned, boxed, and owned futures that output a falliable
 /// result of type `Result<T, E>`.
 pub type OwnedFuture<T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + 'static>>;
 
+/// A future yielding the next chunk of a `ResponseStream`.
+pub type ChunkFuture<'a> = Pin<Box<dyn Future<Output = Result<Option<Vec<u8>>, Error>> + 'a>>;
+
+/// The body of a fetched resource, delivered in chunks as it arrives.
+pub trait ResponseStream {
+    /// The length of the whole body in bytes, if the server reported it.
+    fn total_length(&self) -> Option<usize>;
+
+    /// Wait for the next chunk of the body.
+    ///
+    /// Yields `None` once the entire body has been received.
+    fn next_chunk(&mut self) -> ChunkFuture<'_>;
+}
+
+/// A `ResponseStream` for a body that has already been received in full.
+///
+/// The whole body is yielded as a single chunk.
+pub struct BufferedResponseStream(Option<Vec<u8>>, usize);
+
+impl BufferedResponseStream {
+    pub fn new(data: Vec<u8>) -> Self {
+        let length = data.len();
+        Self(Some(data), length)
+    }
+}
+
+impl ResponseStream for BufferedResponseStream {
+    fn total_length(&self) -> Option<usize> {
+        Some(self.1)
+    }
+
+    fn next_chunk(&mut self) -> ChunkFuture<'_> {
+        let chunk = self.0.take();
+        Box::pin(async move { Ok(chunk) })
+    }
+}
+
 /// A backend interacting with a browser environment.
 pub trait NavigatorBackend {
     /// Cause a browser navigation to a given URL.
@@ -178,6 +231,25 @@ pub trait NavigatorBackend {
     /// Fetch data at a given URL and return it some time in the future.
     fn fetch(&self, url: &str, request_options: RequestOptions) -> OwnedFuture<Vec<u8>, Error>;
 
+    /// Fetch data at a given URL, yielding the response body in chunks as it
+    /// arrives.
+    ///
+    /// This is used by loads which report their progress, such as movie
+    /// loads. Backends which cannot stream responses may keep the default
+    /// implementation, which yields the result of `fetch` as a single chunk.
+    fn fetch_stream(
+        &self,
+        url: &str,
+        request_options: RequestOptions,
+    ) -> OwnedFuture<Box<dyn ResponseStream>, Error> {
+        let fetch = self.fetch(url, request_options);
+        Box::pin(async move {
+            let stream: Box<dyn ResponseStream> =
+                Box::new(BufferedResponseStream::new(fetch.await?));
+            Ok(stream)
+        })
+    }
+
     /// Get the amount of time since the SWF was launched.
     /// Used by the `getTimer` ActionScript call.
     fn time_since_launch(&mut self) -> Duration;
@@ -365,8 +437,11 @@ impl NavigatorBackend for NullNavigatorBackend {
     fn run_script(&self, _js_code: &str) {}
 
     fn fetch(&self, url: &str, _opts: RequestOptions) -> OwnedFuture<Vec<u8>, Error> {
-        let mut path = self.relative_base_path.clone();
-        path.push(url);
+        // URLs resolved against a local movie point straight at the file.
+        let path = match Url::parse(url).ok().and_then(|url| url.to_file_path().ok()) {
+            Some(path) => path,
+            None => self.relative_base_path.join(url),
+        };
 
         Box::pin(async move { fs::read(path).map_err(Error::NetworkError) })
     }
@@ -1,22 +1,24 @@
+pub mod software;
+
 use crate::shape_utils::DistilledShape;
 pub use crate::{library::MovieLibrary, transform::Transform, Color};
 use downcast_rs::Downcast;
 use gc_arena::Collect;
 use std::io::Read;
 pub use swf;
-use swf::Matrix;
+use swf::{CharacterId, Matrix};
 
 pub trait RenderBackend: Downcast {
     fn set_viewport_dimensions(&mut self, width: u32, height: u32);
     fn register_shape(
         &mut self,
         shape: DistilledShape,
-        library: Option<&MovieLibrary<'_>>,
+        bitmap_source: Option<&dyn BitmapSource>,
     ) -> ShapeHandle;
     fn replace_shape(
         &mut self,
         shape: DistilledShape,
-        library: Option<&MovieLibrary<'_>>,
+        bitmap_source: Option<&dyn BitmapSource>,
         handle: ShapeHandle,
     );
     fn register_glyph_shape(&mut self, shape: &swf::Glyph) -> ShapeHandle;
@@ -46,6 +48,20 @@ pub trait RenderBackend: Downcast {
     fn deactivate_mask(&mut self);
     fn pop_mask(&mut self);
 
+    /// Renders into an offscreen target of the given size and reads the
+    /// result back.
+    ///
+    /// `commands` is called with the backend in a state where the usual
+    /// drawing and masking methods target the offscreen surface, which starts
+    /// out fully transparent. The returned bitmap is RGBA with premultiplied
+    /// alpha. This is used by `BitmapData.draw`.
+    fn render_offscreen(
+        &mut self,
+        width: u32,
+        height: u32,
+        commands: &mut dyn FnMut(&mut dyn RenderBackend),
+    ) -> Result<Bitmap, Error>;
+
     fn get_bitmap_pixels(&mut self, bitmap: BitmapHandle) -> Option<Bitmap>;
     fn register_bitmap_raw(
         &mut self,
@@ -72,6 +88,14 @@ pub struct ShapeHandle(pub usize);
 #[collect(no_drop)]
 pub struct BitmapHandle(pub usize);
 
+/// Looks up the bitmaps used by the bitmap fills of a shape.
+///
+/// Shapes from SWF tags refer to bitmaps by their character ID in the movie's
+/// library; shapes drawn by script refer to bitmaps by their own IDs.
+pub trait BitmapSource {
+    fn bitmap_handle(&self, id: CharacterId) -> Option<BitmapHandle>;
+}
+
 /// Info returned by the `register_bitmap` methods.
 #[derive(Copy, Clone, Debug)]
 pub struct BitmapInfo {
@@ -80,11 +104,35 @@ pub struct BitmapInfo {
     pub height: u16,
 }
 
-pub struct NullRenderer;
+/// A renderer that doesn't draw anything to the screen.
+///
+/// Bitmaps and shapes are still decoded and kept around, so that offscreen
+/// rendering (such as `BitmapData.draw`) works when running headless.
+pub struct NullRenderer {
+    bitmaps: Vec<software::LazyBitmap>,
+    shapes: Vec<software::LazyShape>,
+    offscreen: Option<software::OffscreenTarget>,
+}
 
 impl NullRenderer {
     pub fn new() -> Self {
-        Self
+        Self {
+            bitmaps: Vec::new(),
+            shapes: Vec::new(),
+            offscreen: None,
+        }
+    }
+
+    fn register_bitmap(&mut self, bitmap: software::LazyBitmap) -> BitmapInfo {
+        let handle = BitmapHandle(self.bitmaps.len());
+        let width = bitmap.width() as u16;
+        let height = bitmap.height() as u16;
+        self.bitmaps.push(bitmap);
+        BitmapInfo {
+            handle,
+            width,
+            height,
+        }
     }
 }
 
@@ -98,90 +146,181 @@ impl RenderBackend for NullRenderer {
     fn set_viewport_dimensions(&mut self, _width: u32, _height: u32) {}
     fn register_shape(
         &mut self,
-        _shape: DistilledShape,
-        _library: Option<&MovieLibrary<'_>>,
+        shape: DistilledShape,
+        bitmap_source: Option<&dyn BitmapSource>,
     ) -> ShapeHandle {
-        ShapeHandle(0)
+        let handle = ShapeHandle(self.shapes.len());
+        self.shapes
+            .push(software::LazyShape::new(shape, bitmap_source));
+        handle
     }
     fn replace_shape(
         &mut self,
-        _shape: DistilledShape,
-        _library: Option<&MovieLibrary<'_>>,
-        _handle: ShapeHandle,
+        shape: DistilledShape,
+        bitmap_source: Option<&dyn BitmapSource>,
+        handle: ShapeHandle,
     ) {
+        if let Some(entry) = self.shapes.get_mut(handle.0) {
+            *entry = software::LazyShape::new(shape, bitmap_source);
+        }
     }
-    fn register_glyph_shape(&mut self, _shape: &swf::Glyph) -> ShapeHandle {
-        ShapeHandle(0)
+    fn register_glyph_shape(&mut self, glyph: &swf::Glyph) -> ShapeHandle {
+        let shape = crate::shape_utils::swf_glyph_to_shape(glyph);
+        self.register_shape((&shape).into(), None)
     }
     fn register_bitmap_jpeg(
         &mut self,
-        _data: &[u8],
-        _jpeg_tables: Option<&[u8]>,
+        data: &[u8],
+        jpeg_tables: Option<&[u8]>,
     ) -> Result<BitmapInfo, Error> {
-        Ok(BitmapInfo {
-            handle: BitmapHandle(0),
-            width: 0,
-            height: 0,
-        })
-    }
-    fn register_bitmap_jpeg_2(&mut self, _data: &[u8]) -> Result<BitmapInfo, Error> {
-        Ok(BitmapInfo {
-            handle: BitmapHandle(0),
-            width: 0,
-            height: 0,
-        })
+        let data = glue_tables_to_jpeg(data, jpeg_tables);
+        self.register_bitmap_jpeg_2(&data[..])
+    }
+    fn register_bitmap_jpeg_2(&mut self, data: &[u8]) -> Result<BitmapInfo, Error> {
+        Ok(
+            self.register_bitmap(software::LazyBitmap::new(software::EncodedBitmap::Jpeg {
+                data: data.to_vec(),
+                alpha_data: None,
+            })),
+        )
     }
     fn register_bitmap_jpeg_3(
         &mut self,
-        _data: &[u8],
-        _alpha_data: &[u8],
+        data: &[u8],
+        alpha_data: &[u8],
     ) -> Result<BitmapInfo, Error> {
-        Ok(BitmapInfo {
-            handle: BitmapHandle(0),
-            width: 0,
-            height: 0,
-        })
+        Ok(
+            self.register_bitmap(software::LazyBitmap::new(software::EncodedBitmap::Jpeg {
+                data: data.to_vec(),
+                alpha_data: Some(alpha_data.to_vec()),
+            })),
+        )
     }
     fn register_bitmap_png(
         &mut self,
-        _swf_tag: &swf::DefineBitsLossless,
+        swf_tag: &swf::DefineBitsLossless,
     ) -> Result<BitmapInfo, Error> {
-        Ok(BitmapInfo {
-            handle: BitmapHandle(0),
-            width: 0,
-            height: 0,
-        })
+        Ok(self.register_bitmap(software::LazyBitmap::new(
+            software::EncodedBitmap::Lossless(swf_tag.clone()),
+        )))
     }
     fn begin_frame(&mut self, _clear: Color) {}
     fn end_frame(&mut self) {}
-    fn render_bitmap(&mut self, _bitmap: BitmapHandle, _transform: &Transform, _smoothing: bool) {}
-    fn render_shape(&mut self, _shape: ShapeHandle, _transform: &Transform) {}
-    fn draw_rect(&mut self, _color: Color, _matrix: &Matrix) {}
-    fn push_mask(&mut self) {}
-    fn activate_mask(&mut self) {}
-    fn deactivate_mask(&mut self) {}
-    fn pop_mask(&mut self) {}
+    fn render_bitmap(&mut self, bitmap: BitmapHandle, transform: &Transform, smoothing: bool) {
+        if let Some(offscreen) = &mut self.offscreen {
+            if let Some(bitmap) = self
+                .bitmaps
+                .get_mut(bitmap.0)
+                .and_then(software::LazyBitmap::decode)
+            {
+                offscreen.render_bitmap(bitmap, transform, smoothing);
+            }
+        }
+    }
+    fn render_shape(&mut self, shape: ShapeHandle, transform: &Transform) {
+        let offscreen = match &mut self.offscreen {
+            Some(offscreen) => offscreen,
+            None => return,
+        };
+        let shape = match self.shapes.get_mut(shape.0) {
+            Some(shape) => shape.prepare(),
+            None => return,
+        };
+        for handle in shape.bitmap_handles() {
+            if let Some(bitmap) = self.bitmaps.get_mut(handle.0) {
+                bitmap.decode();
+            }
+        }
 
-    fn get_bitmap_pixels(&mut self, _bitmap: BitmapHandle) -> Option<Bitmap> {
-        None
+        let bitmaps = &self.bitmaps;
+        offscreen.render_shape(shape, transform, |bitmap| {
+            bitmaps
+                .get(bitmap.0)
+                .and_then(software::LazyBitmap::decoded)
+        });
+    }
+    fn draw_rect(&mut self, color: Color, matrix: &Matrix) {
+        if let Some(offscreen) = &mut self.offscreen {
+            offscreen.draw_rect(color, matrix);
+        }
+    }
+    fn push_mask(&mut self) {
+        if let Some(offscreen) = &mut self.offscreen {
+            offscreen.push_mask();
+        }
+    }
+    fn activate_mask(&mut self) {
+        if let Some(offscreen) = &mut self.offscreen {
+            offscreen.activate_mask();
+        }
+    }
+    fn deactivate_mask(&mut self) {
+        if let Some(offscreen) = &mut self.offscreen {
+            offscreen.deactivate_mask();
+        }
+    }
+    fn pop_mask(&mut self) {
+        if let Some(offscreen) = &mut self.offscreen {
+            offscreen.pop_mask();
+        }
+    }
+
+    fn render_offscreen(
+        &mut self,
+        width: u32,
+        height: u32,
+        commands: &mut dyn FnMut(&mut dyn RenderBackend),
+    ) -> Result<Bitmap, Error> {
+        let previous = self
+            .offscreen
+            .replace(software::OffscreenTarget::new(width, height));
+        commands(self);
+        let target = std::mem::replace(&mut self.offscreen, previous);
+        Ok(target.ok_or("Offscreen target went missing")?.into_bitmap())
+    }
+
+    fn get_bitmap_pixels(&mut self, bitmap: BitmapHandle) -> Option<Bitmap> {
+        self.bitmaps
+            .get_mut(bitmap.0)
+            .and_then(software::LazyBitmap::decode)
+            .cloned()
     }
     fn register_bitmap_raw(
         &mut self,
-        _width: u32,
-        _height: u32,
-        _rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+        rgba: Vec<u8>,
     ) -> Result<BitmapHandle, Error> {
-        Ok(BitmapHandle(0))
+        Ok(self
+            .register_bitmap(
+                Bitmap {
+                    width,
+                    height,
+                    data: BitmapFormat::Rgba(rgba),
+                }
+                .into(),
+            )
+            .handle)
     }
 
     fn update_texture(
         &mut self,
-        _bitmap: BitmapHandle,
-        _width: u32,
-        _height: u32,
-        _rgba: Vec<u8>,
+        bitmap: BitmapHandle,
+        width: u32,
+        height: u32,
+        rgba: Vec<u8>,
     ) -> Result<BitmapHandle, Error> {
-        Ok(BitmapHandle(0))
+        let entry = self
+            .bitmaps
+            .get_mut(bitmap.0)
+            .ok_or("update_texture: Bitmap is not registered")?;
+        *entry = Bitmap {
+            width,
+            height,
+            data: BitmapFormat::Rgba(rgba),
+        }
+        .into();
+        Ok(bitmap)
     }
 }
 
@@ -247,6 +386,7 @@ pub fn determine_jpeg_tag_format(data: &[u8]) -> JpegTagFormat {
         [0xff, 0xd8, ..] => JpegTagFormat::Jpeg,
         [0xff, 0xd9, 0xff, 0xd8, ..] => JpegTagFormat::Jpeg, // erroneous header in SWF
         [0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, ..] => JpegTagFormat::Png,
+        [0x47, 0x49, 0x46, 0x38, 0x37, 0x61, ..] => JpegTagFormat::Gif,
         [0x47, 0x49, 0x46, 0x38, 0x39, 0x61, ..] => JpegTagFormat::Gif,
         _ => JpegTagFormat::Unknown,
     }
@@ -268,6 +408,29 @@ pub fn decode_define_bits_jpeg(data: &[u8], alpha_data: Option<&[u8]>) -> Result
     }
 }
 
+/// Reads the dimensions of the image data in a DefineBitsJPEG2/3 tag without
+/// decoding the image itself.
+pub fn decode_define_bits_jpeg_dimensions(data: &[u8]) -> Result<(u32, u32), Error> {
+    match determine_jpeg_tag_format(data) {
+        JpegTagFormat::Jpeg => {
+            let data = remove_invalid_jpeg_data(data);
+            let mut decoder = jpeg_decoder::Decoder::new(&data[..]);
+            decoder.read_info()?;
+            let metadata = decoder.info().ok_or("Unable to get image info")?;
+            Ok((metadata.width.into(), metadata.height.into()))
+        }
+        JpegTagFormat::Png => {
+            let (info, _reader) = png::Decoder::new(data).read_info()?;
+            Ok((info.width, info.height))
+        }
+        JpegTagFormat::Gif => {
+            let reader = gif::DecodeOptions::new().read_info(data)?;
+            Ok((reader.width().into(), reader.height().into()))
+        }
+        JpegTagFormat::Unknown => Err("Unknown bitmap data format".into()),
+    }
+}
+
 pub fn glue_swf_jpeg_to_tables(jpeg_tables: &[u8], jpeg_data: &[u8]) -> Vec<u8> {
     let mut full_jpeg = Vec::with_capacity(jpeg_tables.len() + jpeg_data.len() - 4);
     full_jpeg.extend_from_slice(&jpeg_tables[..jpeg_tables.len() - 2]);
@@ -532,19 +695,39 @@ pub fn decode_png(data: &[u8]) -> Result<Bitmap, Error> {
     })
 }
 
-/// Decodes the bitmap data in DefineBitsLossless tag into RGBA.
-/// DefineBitsLossless is Zlib encoded pixel data (similar to PNG), possibly
-/// palletized.
+/// Decodes the first frame of a GIF image into RGBA.
+/// The bitmap has the size of the GIF's logical screen, with the frame drawn
+/// at its offset and the rest left transparent.
 pub fn decode_gif(data: &[u8]) -> Result<Bitmap, Error> {
     let mut decode_options = gif::DecodeOptions::new();
     decode_options.set_color_output(gif::ColorOutput::RGBA);
     let mut reader = decode_options.read_info(data)?;
+    let width = usize::from(reader.width());
+    let height = usize::from(reader.height());
     let frame = reader.read_next_frame()?.ok_or("No frames in GIF")?;
 
+    let mut out_data = vec![0; width * height * 4];
+    let left = usize::from(frame.left);
+    let top = usize::from(frame.top);
+    let frame_width = usize::from(frame.width);
+    // Frames may extend past the logical screen, so clip them to it.
+    let copy_width = frame_width.min(width.saturating_sub(left));
+    if copy_width > 0 {
+        for (y, row) in frame
+            .buffer
+            .chunks_exact(frame_width * 4)
+            .enumerate()
+            .take(height.saturating_sub(top))
+        {
+            let out_start = ((top + y) * width + left) * 4;
+            out_data[out_start..out_start + copy_width * 4].copy_from_slice(&row[..copy_width * 4]);
+        }
+    }
+
     Ok(Bitmap {
-        width: frame.width.into(),
-        height: frame.height.into(),
-        data: BitmapFormat::Rgba(frame.buffer.to_vec()),
+        width: width as u32,
+        height: height as u32,
+        data: BitmapFormat::Rgba(out_data),
     })
 }
 
@@ -561,6 +744,18 @@ pub fn unmultiply_alpha_rgba(rgba: &mut [u8]) {
     })
 }
 
+/// Converts standard RGBA to RGBA premultiplied alpha.
+pub fn premultiply_alpha_rgba(rgba: &mut [u8]) {
+    rgba.chunks_exact_mut(4).for_each(|rgba| {
+        if rgba[3] < 255 {
+            let a = f32::from(rgba[3]) / 255.0;
+            rgba[0] = (f32::from(rgba[0]) * a).round() as u8;
+            rgba[1] = (f32::from(rgba[1]) * a).round() as u8;
+            rgba[2] = (f32::from(rgba[2]) * a).round() as u8;
+        }
+    })
+}
+
 /// Converts an RGBA color from sRGB space to linear color space.
 pub fn srgb_to_linear(color: [f32; 4]) -> [f32; 4] {
     fn to_linear_channel(n: f32) -> f32 {
@@ -578,6 +773,23 @@ pub fn srgb_to_linear(color: [f32; 4]) -> [f32; 4] {
     ]
 }
 
+/// Converts an RGBA color from linear color space to sRGB space.
+pub fn linear_to_srgb(color: [f32; 4]) -> [f32; 4] {
+    fn to_srgb_channel(n: f32) -> f32 {
+        if n <= 0.0031308 {
+            n * 12.92
+        } else {
+            1.055 * f32::powf(n, 1.0 / 2.4) - 0.055
+        }
+    }
+    [
+        to_srgb_channel(color[0]),
+        to_srgb_channel(color[1]),
+        to_srgb_channel(color[2]),
+        color[3],
+    ]
+}
+
 /// Decodes zlib-compressed data.
 fn decompress_zlib(data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
     let mut out_data = Vec::new();
@@ -586,3 +798,53 @@ fn decompress_zlib(data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
     out_data.shrink_to_fit();
     Ok(out_data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 1x1 DefineBitsLossless tag with a single opaque red pixel.
+    fn red_pixel_tag() -> swf::DefineBitsLossless {
+        swf::DefineBitsLossless {
+            version: 2,
+            id: 1,
+            format: swf::BitmapFormat::Rgb32,
+            width: 1,
+            height: 1,
+            num_colors: 0,
+            // A zlib stream with a single stored block.
+            data: vec![
+                0x78, 0x01, 0x01, 0x04, 0x00, 0xfb, 0xff, 0xff, 0xff, 0x00, 0x00, 0x06, 0xfd, 0x01,
+                0xff,
+            ],
+        }
+    }
+
+    #[test]
+    fn null_renderer_decodes_bitmaps_lazily() {
+        let mut renderer = NullRenderer::new();
+        let info = renderer.register_bitmap_png(&red_pixel_tag()).unwrap();
+        assert_eq!((info.width, info.height), (1, 1));
+        assert!(renderer.bitmaps[info.handle.0].decoded().is_none());
+
+        let bitmap = renderer
+            .render_offscreen(1, 1, &mut |renderer| {
+                renderer.render_bitmap(info.handle, &Transform::default(), false)
+            })
+            .unwrap();
+        assert!(renderer.bitmaps[info.handle.0].decoded().is_some());
+        match bitmap.data {
+            BitmapFormat::Rgba(data) => assert_eq!(data, vec![0xff, 0x00, 0x00, 0xff]),
+            BitmapFormat::Rgb(_) => panic!("Offscreen targets should be RGBA"),
+        }
+    }
+
+    #[test]
+    fn null_renderer_registers_undecodable_bitmaps() {
+        let mut renderer = NullRenderer::new();
+        let png_signature = [0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a];
+        let info = renderer.register_bitmap_jpeg_2(&png_signature).unwrap();
+        assert_eq!((info.width, info.height), (0, 0));
+        assert!(renderer.get_bitmap_pixels(info.handle).is_none());
+    }
+}
@@ -0,0 +1,1355 @@
+//! Software rasterization of offscreen render targets.
+//!
+//! Backends that have no way of rendering into an offscreen surface, such as
+//! `NullRenderer`, keep an `OffscreenTarget` around while inside
+//! `RenderBackend::render_offscreen` and forward their drawing calls to it.
+//! Shapes and bitmaps are registered with such a backend as `LazyShape`s and
+//! `LazyBitmap`s, which keep the source data around and only prepare it for
+//! rasterization once it is first drawn.
+
+use crate::backend::render::{
+    decode_define_bits_jpeg, decode_define_bits_jpeg_dimensions, decode_define_bits_lossless,
+    linear_to_srgb, srgb_to_linear, Bitmap, BitmapFormat, BitmapHandle, BitmapSource, Color, Error,
+    Transform,
+};
+use crate::color_transform::ColorTransform;
+use crate::shape_utils::{DistilledShape, DrawCommand, DrawPath};
+use swf::{
+    FillStyle, GradientInterpolation, GradientSpread, LineCapStyle, LineJoinStyle, LineStyle,
+    Matrix,
+};
+
+/// The number of rows of samples taken for each row of pixels when computing
+/// the coverage of a shape.
+const SUBSCANLINES: u32 = 4;
+
+/// The maximum distance between a curve and the line segments it's
+/// approximated by, in pixels.
+const FLATTEN_TOLERANCE: f64 = 0.1;
+
+type Point = (f64, f64);
+
+/// A CPU-side render target with premultiplied RGBA pixels.
+pub struct OffscreenTarget {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+    mask_state: MaskState,
+
+    /// The coverage of each active mask, already clipped by the masks below
+    /// it.
+    masks: Vec<Vec<u8>>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum MaskState {
+    NoMask,
+    DrawMaskStencil,
+    DrawMaskedContent,
+    ClearMaskStencil,
+}
+
+impl OffscreenTarget {
+    /// Creates a fully transparent target of the given size.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+            mask_state: MaskState::NoMask,
+            masks: Vec::new(),
+        }
+    }
+
+    /// Consumes the target, returning its contents.
+    pub fn into_bitmap(self) -> Bitmap {
+        Bitmap {
+            width: self.width,
+            height: self.height,
+            data: BitmapFormat::Rgba(self.pixels),
+        }
+    }
+
+    pub fn render_bitmap(&mut self, bitmap: &Bitmap, transform: &Transform, smoothing: bool) {
+        let color_transform = &transform.color_transform;
+        let sampler = BitmapSampler {
+            bitmap,
+            repeating: false,
+        };
+        self.fill_quad(
+            &transform.matrix,
+            bitmap.width as f64,
+            bitmap.height as f64,
+            |x, y| {
+                let color = if smoothing {
+                    sampler.sample_bilinear(x, y)
+                } else {
+                    sampler.sample_nearest(x, y)
+                };
+                apply_color_transform(color, color_transform)
+            },
+        );
+    }
+
+    /// Rasterizes a shape. `bitmaps` looks up the bitmaps used by bitmap fills.
+    pub fn render_shape<'a, F>(&mut self, shape: &SoftwareShape, transform: &Transform, bitmaps: F)
+    where
+        F: Fn(BitmapHandle) -> Option<&'a Bitmap>,
+    {
+        let matrix = Affine::from(&transform.matrix);
+        let inverse = match matrix.inverse() {
+            Some(inverse) => inverse,
+            None => return,
+        };
+        let color_transform = &transform.color_transform;
+
+        for path in &shape.paths {
+            match path {
+                ShapePath::Fill { fill, commands } => {
+                    let mut edges = edges(&flatten(commands, &matrix, FLATTEN_TOLERANCE));
+                    match fill {
+                        Fill::Color(color) => {
+                            let color = apply_color_transform(*color, color_transform);
+                            self.fill_edges(&mut edges, FillRule::EvenOdd, |_, _| color);
+                        }
+                        Fill::Gradient(gradient) => {
+                            self.fill_edges(&mut edges, FillRule::EvenOdd, |x, y| {
+                                let color = gradient.sample(inverse.transform((x, y)));
+                                apply_color_transform(color, color_transform)
+                            });
+                        }
+                        Fill::Bitmap {
+                            handle,
+                            matrix: bitmap_matrix,
+                            is_smoothed,
+                            is_repeating,
+                        } => {
+                            let bitmap = match bitmaps(*handle) {
+                                Some(bitmap) => bitmap,
+                                None => continue,
+                            };
+                            let sampler = BitmapSampler {
+                                bitmap,
+                                repeating: *is_repeating,
+                            };
+                            let bitmap_matrix = inverse.then(bitmap_matrix);
+                            self.fill_edges(&mut edges, FillRule::EvenOdd, |x, y| {
+                                let (x, y) = bitmap_matrix.transform((x, y));
+                                let color = if *is_smoothed {
+                                    sampler.sample_bilinear(x, y)
+                                } else {
+                                    sampler.sample_nearest(x, y)
+                                };
+                                apply_color_transform(color, color_transform)
+                            });
+                        }
+                    }
+                }
+                ShapePath::Stroke {
+                    color,
+                    stroke,
+                    commands,
+                } => {
+                    // Strokes are built at their own scale, but need to be
+                    // smooth at the scale they're drawn at.
+                    let scale = matrix.scale();
+                    if scale <= 0.0 {
+                        continue;
+                    }
+                    let mut outline = stroke.outline(commands, FLATTEN_TOLERANCE / scale);
+                    for point in outline.iter_mut().flatten() {
+                        *point = matrix.transform(*point);
+                    }
+                    let color = apply_color_transform(*color, color_transform);
+                    self.fill_edges(&mut edges(&outline), FillRule::NonZero, |_, _| color);
+                }
+            }
+        }
+    }
+
+    pub fn draw_rect(&mut self, color: Color, matrix: &Matrix) {
+        let color = premultiply(&color);
+        self.fill_quad(matrix, 1.0, 1.0, |_, _| color);
+    }
+
+    pub fn push_mask(&mut self) {
+        self.masks
+            .push(vec![0; self.width as usize * self.height as usize]);
+        self.mask_state = MaskState::DrawMaskStencil;
+    }
+
+    pub fn activate_mask(&mut self) {
+        self.mask_state = MaskState::DrawMaskedContent;
+    }
+
+    pub fn deactivate_mask(&mut self) {
+        self.mask_state = MaskState::ClearMaskStencil;
+    }
+
+    pub fn pop_mask(&mut self) {
+        self.masks.pop();
+        self.mask_state = if self.masks.is_empty() {
+            MaskState::NoMask
+        } else {
+            MaskState::DrawMaskedContent
+        };
+    }
+
+    /// Composites the quad `(0, 0)..(width, height)`, transformed by `matrix`,
+    /// over the target. `shade` is called with the local coordinates of each
+    /// covered pixel center and returns a premultiplied RGBA color.
+    fn fill_quad<F>(&mut self, matrix: &Matrix, width: f64, height: f64, mut shade: F)
+    where
+        F: FnMut(f64, f64) -> [f32; 4],
+    {
+        let matrix = Affine::from(matrix);
+        let inverse = match matrix.inverse() {
+            Some(inverse) => inverse,
+            None => return,
+        };
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        // Find the area of the target covered by the quad.
+        let corners = [(0.0, 0.0), (width, 0.0), (0.0, height), (width, height)];
+        let mut min_x = f64::INFINITY;
+        let mut min_y = f64::INFINITY;
+        let mut max_x = f64::NEG_INFINITY;
+        let mut max_y = f64::NEG_INFINITY;
+        for &corner in &corners {
+            let (out_x, out_y) = matrix.transform(corner);
+            min_x = min_x.min(out_x);
+            min_y = min_y.min(out_y);
+            max_x = max_x.max(out_x);
+            max_y = max_y.max(out_y);
+        }
+        let min_x = min_x.floor().max(0.0) as u32;
+        let min_y = min_y.floor().max(0.0) as u32;
+        let max_x = (max_x.ceil().max(0.0) as u32).min(self.width);
+        let max_y = (max_y.ceil().max(0.0) as u32).min(self.height);
+
+        for out_y in min_y..max_y {
+            for out_x in min_x..max_x {
+                // Map the pixel center back into the quad.
+                let (x, y) = inverse.transform((f64::from(out_x) + 0.5, f64::from(out_y) + 0.5));
+                if x < 0.0 || y < 0.0 || x >= width || y >= height {
+                    continue;
+                }
+
+                self.blend(out_x, out_y, shade(x, y), 1.0);
+            }
+        }
+    }
+
+    /// Composites the area enclosed by `edges` over the target, with
+    /// antialiased edges. `shade` is called with the coordinates of each
+    /// covered pixel center and returns a premultiplied RGBA color.
+    fn fill_edges<F>(&mut self, edges: &mut [Edge], fill_rule: FillRule, mut shade: F)
+    where
+        F: FnMut(f64, f64) -> [f32; 4],
+    {
+        if edges.is_empty() {
+            return;
+        }
+
+        let mut min_x = f64::INFINITY;
+        let mut min_y = f64::INFINITY;
+        let mut max_x = f64::NEG_INFINITY;
+        let mut max_y = f64::NEG_INFINITY;
+        for edge in edges.iter() {
+            min_x = min_x.min(edge.x0).min(edge.x1);
+            max_x = max_x.max(edge.x0).max(edge.x1);
+            min_y = min_y.min(edge.y0);
+            max_y = max_y.max(edge.y1);
+        }
+        let min_x = min_x.floor().max(0.0).min(f64::from(self.width)) as u32;
+        let min_y = min_y.floor().max(0.0).min(f64::from(self.height)) as u32;
+        let max_x = max_x.ceil().max(0.0).min(f64::from(self.width)) as u32;
+        let max_y = max_y.ceil().max(0.0).min(f64::from(self.height)) as u32;
+        if min_x >= max_x || min_y >= max_y {
+            return;
+        }
+
+        // Edges become active once the scanline reaches their top.
+        edges.sort_by(|a, b| a.y0.partial_cmp(&b.y0).unwrap_or(std::cmp::Ordering::Equal));
+        let mut next_edge = 0;
+        let mut active_edges: Vec<usize> = Vec::new();
+        let mut crossings: Vec<(f64, i32)> = Vec::new();
+        let mut coverage = vec![0.0f32; (max_x - min_x) as usize];
+        let weight = 1.0 / SUBSCANLINES as f32;
+
+        // Skip the edges entirely above the target.
+        let first_sample_y = f64::from(min_y) + 0.5 / f64::from(SUBSCANLINES);
+        while next_edge < edges.len() && edges[next_edge].y1 <= first_sample_y {
+            next_edge += 1;
+        }
+
+        for y in min_y..max_y {
+            for value in coverage.iter_mut() {
+                *value = 0.0;
+            }
+
+            for sample in 0..SUBSCANLINES {
+                let sample_y = f64::from(y) + (f64::from(sample) + 0.5) / f64::from(SUBSCANLINES);
+                while next_edge < edges.len() && edges[next_edge].y0 <= sample_y {
+                    active_edges.push(next_edge);
+                    next_edge += 1;
+                }
+                active_edges.retain(|&i| edges[i].y1 > sample_y);
+
+                crossings.clear();
+                crossings.extend(active_edges.iter().map(|&i| {
+                    let edge = &edges[i];
+                    let x =
+                        edge.x0 + (sample_y - edge.y0) * (edge.x1 - edge.x0) / (edge.y1 - edge.y0);
+                    (x, edge.winding)
+                }));
+                crossings
+                    .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+                let mut winding = 0;
+                for (i, &(x, edge_winding)) in crossings.iter().enumerate() {
+                    winding += edge_winding;
+                    let inside = match fill_rule {
+                        FillRule::EvenOdd => winding % 2 != 0,
+                        FillRule::NonZero => winding != 0,
+                    };
+                    if let (true, Some(&(next_x, _))) = (inside, crossings.get(i + 1)) {
+                        let offset = f64::from(min_x);
+                        add_span(&mut coverage, x - offset, next_x - offset, weight);
+                    }
+                }
+            }
+
+            for (i, &value) in coverage.iter().enumerate() {
+                if value > 0.0 {
+                    let x = min_x + i as u32;
+                    let color = shade(f64::from(x) + 0.5, f64::from(y) + 0.5);
+                    self.blend(x, y, color, value.min(1.0));
+                }
+            }
+        }
+    }
+
+    /// Blends a premultiplied color covering the given fraction of a pixel
+    /// into the target, or into the current mask.
+    fn blend(&mut self, x: u32, y: u32, color: [f32; 4], coverage: f32) {
+        let i = y as usize * self.width as usize + x as usize;
+        let coverage = match self.mask_state {
+            MaskState::NoMask => coverage,
+            MaskState::DrawMaskStencil => {
+                // Only the area of a mask matters, and only within the masks
+                // below it.
+                let num_masks = self.masks.len();
+                let parent_coverage = if num_masks >= 2 {
+                    f32::from(self.masks[num_masks - 2][i]) / 255.0
+                } else {
+                    1.0
+                };
+                if let Some(mask) = self.masks.last_mut() {
+                    let value = (coverage * parent_coverage * 255.0).round() as u8;
+                    mask[i] = mask[i].max(value);
+                }
+                return;
+            }
+            MaskState::DrawMaskedContent => match self.masks.last() {
+                Some(mask) => coverage * f32::from(mask[i]) / 255.0,
+                None => coverage,
+            },
+            MaskState::ClearMaskStencil => return,
+        };
+        if coverage <= 0.0 {
+            return;
+        }
+
+        let inv_alpha = 1.0 - color[3] * coverage / 255.0;
+        for (dst, src) in self.pixels[i * 4..i * 4 + 4].iter_mut().zip(&color) {
+            let value = src * coverage + f32::from(*dst) * inv_alpha;
+            *dst = value.round().max(0.0).min(255.0) as u8;
+        }
+    }
+}
+
+/// The encoded data of a bitmap character.
+#[derive(Debug)]
+pub enum EncodedBitmap {
+    /// Image data from a DefineBitsJPEG tag, which may also be PNG or GIF
+    /// data, with the JPEG tables already applied.
+    Jpeg {
+        data: Vec<u8>,
+        alpha_data: Option<Vec<u8>>,
+    },
+    Lossless(swf::DefineBitsLossless),
+}
+
+impl EncodedBitmap {
+    /// Reads the size of the bitmap without decoding it.
+    fn dimensions(&self) -> Result<(u32, u32), Error> {
+        match self {
+            EncodedBitmap::Jpeg { data, .. } => decode_define_bits_jpeg_dimensions(data),
+            EncodedBitmap::Lossless(tag) => Ok((tag.width.into(), tag.height.into())),
+        }
+    }
+
+    fn decode(&self) -> Result<Bitmap, Error> {
+        match self {
+            EncodedBitmap::Jpeg { data, alpha_data } => {
+                decode_define_bits_jpeg(data, alpha_data.as_deref())
+            }
+            EncodedBitmap::Lossless(tag) => decode_define_bits_lossless(tag),
+        }
+    }
+}
+
+/// A bitmap registered with a software-rendered backend.
+///
+/// Registering an encoded bitmap only reads its size; the image itself is
+/// decoded the first time it is needed.
+#[derive(Debug)]
+pub struct LazyBitmap {
+    width: u32,
+    height: u32,
+    encoded: Option<EncodedBitmap>,
+    bitmap: Option<Bitmap>,
+}
+
+impl LazyBitmap {
+    pub fn new(encoded: EncodedBitmap) -> Self {
+        let (width, height) = encoded.dimensions().unwrap_or_else(|e| {
+            log::warn!("Unable to read bitmap dimensions: {}", e);
+            (0, 0)
+        });
+
+        Self {
+            width,
+            height,
+            encoded: Some(encoded),
+            bitmap: None,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Decodes the bitmap, if it hasn't been already.
+    ///
+    /// Yields `None` if the bitmap could not be decoded.
+    pub fn decode(&mut self) -> Option<&Bitmap> {
+        if let Some(encoded) = self.encoded.take() {
+            match encoded.decode() {
+                Ok(bitmap) => self.bitmap = Some(bitmap),
+                Err(e) => log::warn!("Unable to decode bitmap: {}", e),
+            }
+        }
+
+        self.bitmap.as_ref()
+    }
+
+    /// Gets the bitmap, if it has already been decoded.
+    pub fn decoded(&self) -> Option<&Bitmap> {
+        self.bitmap.as_ref()
+    }
+}
+
+impl From<Bitmap> for LazyBitmap {
+    fn from(bitmap: Bitmap) -> Self {
+        Self {
+            width: bitmap.width,
+            height: bitmap.height,
+            encoded: None,
+            bitmap: Some(bitmap),
+        }
+    }
+}
+
+/// A shape registered with a software-rendered backend.
+///
+/// Registering a shape only takes ownership of its outlines; they are
+/// converted into a `SoftwareShape` the first time the shape is drawn.
+#[derive(Debug)]
+pub struct LazyShape {
+    source: Option<ShapeSource>,
+    shape: Option<SoftwareShape>,
+}
+
+impl LazyShape {
+    pub fn new(shape: DistilledShape, bitmap_source: Option<&dyn BitmapSource>) -> Self {
+        let paths = shape
+            .paths
+            .into_iter()
+            .map(|path| match path {
+                DrawPath::Fill { style, commands } => {
+                    // Bitmap fills refer to characters, which can only be
+                    // looked up while the shape is being registered.
+                    let bitmap = match style {
+                        FillStyle::Bitmap { id, .. } => {
+                            bitmap_source.and_then(|bitmap_source| bitmap_source.bitmap_handle(*id))
+                        }
+                        _ => None,
+                    };
+                    SourcePath::Fill {
+                        style: style.clone(),
+                        bitmap,
+                        commands,
+                    }
+                }
+                DrawPath::Stroke {
+                    style, commands, ..
+                } => SourcePath::Stroke {
+                    style: style.clone(),
+                    commands,
+                },
+            })
+            .collect();
+
+        Self {
+            source: Some(ShapeSource { paths }),
+            shape: None,
+        }
+    }
+
+    /// Prepares the shape for rasterization, if it hasn't been already.
+    pub fn prepare(&mut self) -> &SoftwareShape {
+        let source = &mut self.source;
+        self.shape
+            .get_or_insert_with(|| SoftwareShape::new(source.take().unwrap_or_default()))
+    }
+}
+
+/// The outlines of a shape, as they were registered.
+#[derive(Debug, Default)]
+struct ShapeSource {
+    paths: Vec<SourcePath>,
+}
+
+#[derive(Debug)]
+enum SourcePath {
+    Fill {
+        style: FillStyle,
+        bitmap: Option<BitmapHandle>,
+        commands: Vec<DrawCommand>,
+    },
+    Stroke {
+        style: LineStyle,
+        commands: Vec<DrawCommand>,
+    },
+}
+
+/// A shape kept around for software rasterization.
+#[derive(Debug, Default)]
+pub struct SoftwareShape {
+    paths: Vec<ShapePath>,
+}
+
+impl SoftwareShape {
+    fn new(source: ShapeSource) -> Self {
+        let mut paths = Vec::with_capacity(source.paths.len());
+        for path in source.paths {
+            match path {
+                SourcePath::Fill {
+                    style,
+                    bitmap,
+                    commands,
+                } => {
+                    let fill = match &style {
+                        FillStyle::Color(color) => Fill::Color(premultiply(color)),
+                        FillStyle::LinearGradient(gradient) => {
+                            match Gradient::new(gradient, GradientKind::Linear) {
+                                Some(gradient) => Fill::Gradient(gradient),
+                                None => continue,
+                            }
+                        }
+                        FillStyle::RadialGradient(gradient) => {
+                            match Gradient::new(gradient, GradientKind::Radial) {
+                                Some(gradient) => Fill::Gradient(gradient),
+                                None => continue,
+                            }
+                        }
+                        FillStyle::FocalGradient {
+                            gradient,
+                            focal_point,
+                        } => match Gradient::new(gradient, GradientKind::Focal(*focal_point)) {
+                            Some(gradient) => Fill::Gradient(gradient),
+                            None => continue,
+                        },
+                        FillStyle::Bitmap {
+                            matrix,
+                            is_smoothed,
+                            is_repeating,
+                            ..
+                        } => match (bitmap, Affine::from_fill_matrix(matrix).inverse()) {
+                            (Some(handle), Some(matrix)) => Fill::Bitmap {
+                                handle,
+                                matrix,
+                                is_smoothed: *is_smoothed,
+                                is_repeating: *is_repeating,
+                            },
+                            _ => continue,
+                        },
+                    };
+                    paths.push(ShapePath::Fill {
+                        fill,
+                        commands: convert_commands(&commands),
+                    });
+                }
+                SourcePath::Stroke { style, commands } => {
+                    let color = premultiply(&style.color);
+                    paths.push(ShapePath::Stroke {
+                        color,
+                        stroke: Stroke {
+                            // Hairlines and thinner lines are drawn one pixel wide.
+                            width: style.width.to_pixels().max(1.0),
+                            start_cap: style.start_cap,
+                            end_cap: style.end_cap,
+                            join_style: style.join_style,
+                        },
+                        commands: convert_commands(&commands),
+                    });
+                }
+            }
+        }
+        Self { paths }
+    }
+
+    /// Lists the bitmaps used by the bitmap fills of this shape.
+    pub fn bitmap_handles(&self) -> impl Iterator<Item = BitmapHandle> + '_ {
+        self.paths.iter().filter_map(|path| match path {
+            ShapePath::Fill {
+                fill: Fill::Bitmap { handle, .. },
+                ..
+            } => Some(*handle),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Debug)]
+enum ShapePath {
+    Fill {
+        fill: Fill,
+        commands: Vec<PathCommand>,
+    },
+    Stroke {
+        color: [f32; 4],
+        stroke: Stroke,
+        commands: Vec<PathCommand>,
+    },
+}
+
+/// A path command in the pixel coordinates of the shape.
+#[derive(Debug)]
+enum PathCommand {
+    Move(Point),
+    Line(Point),
+    Curve(Point, Point),
+}
+
+fn convert_commands(commands: &[DrawCommand]) -> Vec<PathCommand> {
+    commands
+        .iter()
+        .map(|command| match *command {
+            DrawCommand::MoveTo { x, y } => PathCommand::Move((x.to_pixels(), y.to_pixels())),
+            DrawCommand::LineTo { x, y } => PathCommand::Line((x.to_pixels(), y.to_pixels())),
+            DrawCommand::CurveTo { x1, y1, x2, y2 } => PathCommand::Curve(
+                (x1.to_pixels(), y1.to_pixels()),
+                (x2.to_pixels(), y2.to_pixels()),
+            ),
+        })
+        .collect()
+}
+
+/// Transforms a path by `matrix` and approximates it by polygons, one per
+/// subpath.
+fn flatten(commands: &[PathCommand], matrix: &Affine, tolerance: f64) -> Vec<Vec<Point>> {
+    let mut contours = Vec::new();
+    let mut contour: Vec<Point> = Vec::new();
+    for command in commands {
+        match *command {
+            PathCommand::Move(point) => {
+                if contour.len() > 1 {
+                    contours.push(std::mem::take(&mut contour));
+                }
+                contour.clear();
+                contour.push(matrix.transform(point));
+            }
+            PathCommand::Line(point) => {
+                if contour.is_empty() {
+                    contour.push(matrix.transform((0.0, 0.0)));
+                }
+                contour.push(matrix.transform(point));
+            }
+            PathCommand::Curve(control, anchor) => {
+                if contour.is_empty() {
+                    contour.push(matrix.transform((0.0, 0.0)));
+                }
+                let start = contour[contour.len() - 1];
+                let control = matrix.transform(control);
+                let end = matrix.transform(anchor);
+
+                // A quadratic curve strays at most |p0 - 2p1 + p2| / 4n²
+                // from its chords when split into n even steps.
+                let deviation =
+                    (start.0 - 2.0 * control.0 + end.0).hypot(start.1 - 2.0 * control.1 + end.1);
+                let steps = (deviation / (4.0 * tolerance))
+                    .sqrt()
+                    .ceil()
+                    .max(1.0)
+                    .min(256.0);
+                let steps = steps as u32;
+                for step in 1..=steps {
+                    let t = f64::from(step) / f64::from(steps);
+                    let u = 1.0 - t;
+                    contour.push((
+                        u * u * start.0 + 2.0 * u * t * control.0 + t * t * end.0,
+                        u * u * start.1 + 2.0 * u * t * control.1 + t * t * end.1,
+                    ));
+                }
+            }
+        }
+    }
+    if contour.len() > 1 {
+        contours.push(contour);
+    }
+    contours
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum FillRule {
+    EvenOdd,
+    NonZero,
+}
+
+/// A non-horizontal edge of a polygon, going down from `(x0, y0)` to
+/// `(x1, y1)`.
+#[derive(Copy, Clone, Debug)]
+struct Edge {
+    x0: f64,
+    y0: f64,
+    x1: f64,
+    y1: f64,
+    winding: i32,
+}
+
+/// Collects the edges of the given closed polygons.
+fn edges(polygons: &[Vec<Point>]) -> Vec<Edge> {
+    let mut edges = Vec::new();
+    for polygon in polygons {
+        for (i, &start) in polygon.iter().enumerate() {
+            let end = polygon[(i + 1) % polygon.len()];
+            let edge = if start.1 < end.1 {
+                Edge {
+                    x0: start.0,
+                    y0: start.1,
+                    x1: end.0,
+                    y1: end.1,
+                    winding: 1,
+                }
+            } else if start.1 > end.1 {
+                Edge {
+                    x0: end.0,
+                    y0: end.1,
+                    x1: start.0,
+                    y1: start.1,
+                    winding: -1,
+                }
+            } else {
+                continue;
+            };
+            if edge.x0.is_finite()
+                && edge.x1.is_finite()
+                && edge.y0.is_finite()
+                && edge.y1.is_finite()
+            {
+                edges.push(edge);
+            }
+        }
+    }
+    edges
+}
+
+/// Adds the span `x0..x1` of a sample row to the coverage of a row of pixels.
+fn add_span(coverage: &mut [f32], x0: f64, x1: f64, weight: f32) {
+    let x0 = x0.max(0.0);
+    let x1 = x1.min(coverage.len() as f64);
+    if x1 <= x0 {
+        return;
+    }
+
+    let first = x0.floor() as usize;
+    let last = x1.floor() as usize;
+    if first == last {
+        coverage[first] += (x1 - x0) as f32 * weight;
+        return;
+    }
+
+    coverage[first] += (first as f64 + 1.0 - x0) as f32 * weight;
+    for value in &mut coverage[first + 1..last] {
+        *value += weight;
+    }
+    if last < coverage.len() {
+        coverage[last] += (x1 - last as f64) as f32 * weight;
+    }
+}
+
+/// The shape of a line drawn along a path.
+#[derive(Debug)]
+struct Stroke {
+    width: f64,
+    start_cap: LineCapStyle,
+    end_cap: LineCapStyle,
+    join_style: LineJoinStyle,
+}
+
+impl Stroke {
+    /// Builds polygons covering the area of this stroke along a path. They
+    /// all have the same orientation, so they're filled with the non-zero
+    /// rule to get their union.
+    fn outline(&self, commands: &[PathCommand], tolerance: f64) -> Vec<Vec<Point>> {
+        let half_width = self.width / 2.0;
+        let mut polygons = Vec::new();
+
+        for mut points in flatten(commands, &Affine::IDENTITY, tolerance) {
+            points.dedup();
+            let is_closed = points.len() > 2 && points.first() == points.last();
+            if is_closed {
+                points.pop();
+            }
+
+            if points.len() == 1 {
+                // A single point still gets its caps.
+                let point = points[0];
+                self.add_cap(&mut polygons, point, (1.0, 0.0), self.end_cap, tolerance);
+                self.add_cap(&mut polygons, point, (-1.0, 0.0), self.start_cap, tolerance);
+                continue;
+            }
+
+            let len = points.len();
+            let num_segments = if is_closed { len } else { len - 1 };
+            for i in 0..num_segments {
+                let start = points[i];
+                let end = points[(i + 1) % len];
+                let (nx, ny) = normal(start, end, half_width);
+                add_polygon(
+                    &mut polygons,
+                    vec![
+                        (start.0 + nx, start.1 + ny),
+                        (end.0 + nx, end.1 + ny),
+                        (end.0 - nx, end.1 - ny),
+                        (start.0 - nx, start.1 - ny),
+                    ],
+                );
+            }
+
+            let joins = if is_closed { 0..len } else { 1..len - 1 };
+            for i in joins {
+                let previous = points[(i + len - 1) % len];
+                let next = points[(i + 1) % len];
+                self.add_join(&mut polygons, previous, points[i], next, tolerance);
+            }
+
+            if !is_closed {
+                let start = points[0];
+                let end = points[len - 1];
+                let start_direction = direction(points[1], start);
+                let end_direction = direction(points[len - 2], end);
+                self.add_cap(
+                    &mut polygons,
+                    start,
+                    start_direction,
+                    self.start_cap,
+                    tolerance,
+                );
+                self.add_cap(&mut polygons, end, end_direction, self.end_cap, tolerance);
+            }
+        }
+
+        polygons
+    }
+
+    /// Adds the join between the segments `previous..point` and
+    /// `point..next`.
+    fn add_join(
+        &self,
+        polygons: &mut Vec<Vec<Point>>,
+        previous: Point,
+        point: Point,
+        next: Point,
+        tolerance: f64,
+    ) {
+        let half_width = self.width / 2.0;
+        let limit = match self.join_style {
+            LineJoinStyle::Round => {
+                add_polygon(polygons, circle(point, half_width, tolerance));
+                return;
+            }
+            LineJoinStyle::Bevel => 0.0,
+            LineJoinStyle::Miter(limit) => f64::from(limit),
+        };
+
+        let (in_x, in_y) = direction(previous, point);
+        let (out_x, out_y) = direction(point, next);
+        let cross = in_x * out_y - in_y * out_x;
+        if cross == 0.0 {
+            return;
+        }
+
+        // The join only sticks out on the outside of the turn.
+        let side = if cross > 0.0 { -1.0 } else { 1.0 };
+        let (n0x, n0y) = normal(previous, point, half_width * side);
+        let (n1x, n1y) = normal(point, next, half_width * side);
+        let a = (point.0 + n0x, point.1 + n0y);
+        let b = (point.0 + n1x, point.1 + n1y);
+
+        // The miter tip lies along the bisector of the two normals.
+        let (bisector_x, bisector_y) = (n0x + n1x, n0y + n1y);
+        let bisector_length = bisector_x.hypot(bisector_y);
+        if bisector_length == 0.0 {
+            add_polygon(polygons, vec![point, a, b]);
+            return;
+        }
+        let (bisector_x, bisector_y) = (bisector_x / bisector_length, bisector_y / bisector_length);
+        let base = (n0x * bisector_x + n0y * bisector_y).max(f64::EPSILON);
+        let tip_distance = half_width * half_width / base;
+        let max_distance = limit * half_width;
+
+        if tip_distance <= max_distance {
+            let tip = (
+                point.0 + bisector_x * tip_distance,
+                point.1 + bisector_y * tip_distance,
+            );
+            add_polygon(polygons, vec![point, a, tip, b]);
+        } else if max_distance > base {
+            // Cut the miter off at the limit.
+            let t = (max_distance - base) / (tip_distance - base);
+            let tip = (
+                point.0 + bisector_x * tip_distance,
+                point.1 + bisector_y * tip_distance,
+            );
+            add_polygon(
+                polygons,
+                vec![
+                    point,
+                    a,
+                    (a.0 + (tip.0 - a.0) * t, a.1 + (tip.1 - a.1) * t),
+                    (b.0 + (tip.0 - b.0) * t, b.1 + (tip.1 - b.1) * t),
+                    b,
+                ],
+            );
+        } else {
+            add_polygon(polygons, vec![point, a, b]);
+        }
+    }
+
+    /// Adds a cap at the end of a line, where `(dx, dy)` points away from
+    /// the line.
+    fn add_cap(
+        &self,
+        polygons: &mut Vec<Vec<Point>>,
+        point: Point,
+        (dx, dy): Point,
+        cap: LineCapStyle,
+        tolerance: f64,
+    ) {
+        let half_width = self.width / 2.0;
+        match cap {
+            LineCapStyle::None => (),
+            LineCapStyle::Round => add_polygon(polygons, circle(point, half_width, tolerance)),
+            LineCapStyle::Square => {
+                let (dx, dy) = (dx * half_width, dy * half_width);
+                let (nx, ny) = (-dy, dx);
+                add_polygon(
+                    polygons,
+                    vec![
+                        (point.0 + nx, point.1 + ny),
+                        (point.0 + nx + dx, point.1 + ny + dy),
+                        (point.0 - nx + dx, point.1 - ny + dy),
+                        (point.0 - nx, point.1 - ny),
+                    ],
+                );
+            }
+        }
+    }
+}
+
+/// Returns the unit vector pointing from `from` to `to`.
+fn direction(from: Point, to: Point) -> Point {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let length = dx.hypot(dy);
+    if length == 0.0 {
+        (0.0, 0.0)
+    } else {
+        (dx / length, dy / length)
+    }
+}
+
+/// Returns the normal of the segment `from..to` with the given length.
+fn normal(from: Point, to: Point, length: f64) -> Point {
+    let (dx, dy) = direction(from, to);
+    (-dy * length, dx * length)
+}
+
+/// Approximates a circle by a polygon.
+fn circle(center: Point, radius: f64, tolerance: f64) -> Vec<Point> {
+    let steps = if radius > tolerance {
+        (std::f64::consts::PI / (1.0 - tolerance / radius).acos()).ceil()
+    } else {
+        0.0
+    };
+    let steps = steps.max(8.0).min(256.0) as u32;
+    (0..steps)
+        .map(|i| {
+            let angle = f64::from(i) * std::f64::consts::PI * 2.0 / f64::from(steps);
+            (
+                center.0 + radius * angle.cos(),
+                center.1 + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+/// Adds a polygon to a stroke outline, making sure it winds the same way as
+/// the others.
+fn add_polygon(polygons: &mut Vec<Vec<Point>>, mut polygon: Vec<Point>) {
+    let mut area = 0.0;
+    for (i, &(x0, y0)) in polygon.iter().enumerate() {
+        let (x1, y1) = polygon[(i + 1) % polygon.len()];
+        area += x0 * y1 - x1 * y0;
+    }
+    if area < 0.0 {
+        polygon.reverse();
+    }
+    polygons.push(polygon);
+}
+
+#[derive(Debug)]
+enum Fill {
+    /// A premultiplied color.
+    Color([f32; 4]),
+    Gradient(Gradient),
+    Bitmap {
+        handle: BitmapHandle,
+        /// Maps the shape's coordinates to texels.
+        matrix: Affine,
+        is_smoothed: bool,
+        is_repeating: bool,
+    },
+}
+
+#[derive(Copy, Clone, Debug)]
+enum GradientKind {
+    Linear,
+    Radial,
+    Focal(f32),
+}
+
+#[derive(Debug)]
+struct Gradient {
+    kind: GradientKind,
+    /// Maps the shape's coordinates into the gradient square, which spans
+    /// `-1..1` on both axes.
+    matrix: Affine,
+    spread: GradientSpread,
+    /// The premultiplied colors along the gradient.
+    ramp: Vec<[f32; 4]>,
+}
+
+impl Gradient {
+    /// The size of the gradient square in twips.
+    const SIZE: f64 = 32768.0;
+
+    fn new(gradient: &swf::Gradient, kind: GradientKind) -> Option<Self> {
+        let matrix = Affine::from_fill_matrix(&gradient.matrix).inverse()?;
+        let scale = Affine {
+            a: 2.0 / Self::SIZE,
+            d: 2.0 / Self::SIZE,
+            ..Affine::IDENTITY
+        };
+
+        let is_linear_rgb = gradient.interpolation == GradientInterpolation::LinearRGB;
+        let stops: Vec<(f32, [f32; 4])> = gradient
+            .records
+            .iter()
+            .map(|record| {
+                let color = [
+                    f32::from(record.color.r) / 255.0,
+                    f32::from(record.color.g) / 255.0,
+                    f32::from(record.color.b) / 255.0,
+                    f32::from(record.color.a) / 255.0,
+                ];
+                let color = if is_linear_rgb {
+                    srgb_to_linear(color)
+                } else {
+                    color
+                };
+                (f32::from(record.ratio), color)
+            })
+            .collect();
+
+        let ramp = (0..256)
+            .map(|i| {
+                let t = i as f32;
+                let color = match stops.iter().position(|&(ratio, _)| t <= ratio) {
+                    Some(0) => stops[0].1,
+                    Some(n) => {
+                        let (start_ratio, start) = stops[n - 1];
+                        let (end_ratio, end) = stops[n];
+                        let amount = if end_ratio > start_ratio {
+                            (t - start_ratio) / (end_ratio - start_ratio)
+                        } else {
+                            1.0
+                        };
+                        let mut color = [0.0; 4];
+                        for (i, color) in color.iter_mut().enumerate() {
+                            *color = start[i] + (end[i] - start[i]) * amount;
+                        }
+                        color
+                    }
+                    None => stops.last().map(|&(_, color)| color).unwrap_or_default(),
+                };
+                let color = if is_linear_rgb {
+                    linear_to_srgb(color)
+                } else {
+                    color
+                };
+                let [r, g, b, a] = color;
+                [r * a * 255.0, g * a * 255.0, b * a * 255.0, a * 255.0]
+            })
+            .collect();
+
+        Some(Self {
+            kind,
+            matrix: matrix.then(&scale),
+            spread: gradient.spread,
+            ramp,
+        })
+    }
+
+    /// Returns the premultiplied color of the gradient at a point of the shape.
+    fn sample(&self, point: Point) -> [f32; 4] {
+        let (x, y) = self.matrix.transform(point);
+        let t = match self.kind {
+            GradientKind::Linear => (x + 1.0) / 2.0,
+            GradientKind::Radial => x.hypot(y),
+            GradientKind::Focal(focal_point) => {
+                let focal_point = f64::from(focal_point);
+                let (dx, dy) = (focal_point - x, -y);
+                let length = dx.hypot(dy);
+                if length == 0.0 {
+                    0.0
+                } else {
+                    let (dx, dy) = (dx / length, dy / length);
+                    length / ((1.0 - focal_point * focal_point * dy * dy).sqrt() + focal_point * dx)
+                }
+            }
+        };
+        let t = match self.spread {
+            GradientSpread::Pad => t.max(0.0).min(1.0),
+            GradientSpread::Repeat => t - t.floor(),
+            GradientSpread::Reflect => {
+                let t = t.abs() % 2.0;
+                if t > 1.0 {
+                    2.0 - t
+                } else {
+                    t
+                }
+            }
+        };
+        let index = (t * 255.0).round();
+        if index.is_nan() {
+            return self.ramp[0];
+        }
+        self.ramp[index.max(0.0).min(255.0) as usize]
+    }
+}
+
+/// An affine transform with `f64` components, in pixels.
+#[derive(Copy, Clone, Debug)]
+struct Affine {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    tx: f64,
+    ty: f64,
+}
+
+impl Affine {
+    const IDENTITY: Self = Self {
+        a: 1.0,
+        b: 0.0,
+        c: 0.0,
+        d: 1.0,
+        tx: 0.0,
+        ty: 0.0,
+    };
+
+    /// Converts the matrix of a gradient or bitmap fill, which maps its
+    /// coordinates to twips in the shape, to map them to pixels.
+    fn from_fill_matrix(matrix: &Matrix) -> Self {
+        Self {
+            a: f64::from(matrix.a) / 20.0,
+            b: f64::from(matrix.b) / 20.0,
+            c: f64::from(matrix.c) / 20.0,
+            d: f64::from(matrix.d) / 20.0,
+            tx: matrix.tx.to_pixels(),
+            ty: matrix.ty.to_pixels(),
+        }
+    }
+
+    fn transform(&self, (x, y): Point) -> Point {
+        (
+            self.a * x + self.c * y + self.tx,
+            self.b * x + self.d * y + self.ty,
+        )
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        let det = self.a * self.d - self.b * self.c;
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let a = self.d / det;
+        let b = -self.b / det;
+        let c = -self.c / det;
+        let d = self.a / det;
+        Some(Self {
+            a,
+            b,
+            c,
+            d,
+            tx: -(a * self.tx + c * self.ty),
+            ty: -(b * self.tx + d * self.ty),
+        })
+    }
+
+    /// Returns the transform applying `self` and then `other`.
+    fn then(&self, other: &Self) -> Self {
+        Self {
+            a: other.a * self.a + other.c * self.b,
+            b: other.b * self.a + other.d * self.b,
+            c: other.a * self.c + other.c * self.d,
+            d: other.b * self.c + other.d * self.d,
+            tx: other.a * self.tx + other.c * self.ty + other.tx,
+            ty: other.b * self.tx + other.d * self.ty + other.ty,
+        }
+    }
+
+    /// Returns the largest factor this transform scales lengths by.
+    fn scale(&self) -> f64 {
+        self.a.hypot(self.b).max(self.c.hypot(self.d))
+    }
+}
+
+impl From<&Matrix> for Affine {
+    fn from(matrix: &Matrix) -> Self {
+        Self {
+            a: matrix.a.into(),
+            b: matrix.b.into(),
+            c: matrix.c.into(),
+            d: matrix.d.into(),
+            tx: matrix.tx.to_pixels(),
+            ty: matrix.ty.to_pixels(),
+        }
+    }
+}
+
+struct BitmapSampler<'a> {
+    bitmap: &'a Bitmap,
+    repeating: bool,
+}
+
+impl<'a> BitmapSampler<'a> {
+    /// Returns the premultiplied color of the given texel, either wrapped
+    /// around or clamped to the edges of the bitmap.
+    fn texel(&self, x: i64, y: i64) -> [f32; 4] {
+        let width = i64::from(self.bitmap.width);
+        let height = i64::from(self.bitmap.height);
+        if width == 0 || height == 0 {
+            return [0.0; 4];
+        }
+        let (x, y) = if self.repeating {
+            (x.rem_euclid(width), y.rem_euclid(height))
+        } else {
+            (x.max(0).min(width - 1), y.max(0).min(height - 1))
+        };
+        let i = (y * width + x) as usize;
+        match &self.bitmap.data {
+            BitmapFormat::Rgb(data) => match data.get(i * 3..i * 3 + 3) {
+                Some(p) => [p[0].into(), p[1].into(), p[2].into(), 255.0],
+                None => [0.0; 4],
+            },
+            BitmapFormat::Rgba(data) => match data.get(i * 4..i * 4 + 4) {
+                Some(p) => [p[0].into(), p[1].into(), p[2].into(), p[3].into()],
+                None => [0.0; 4],
+            },
+        }
+    }
+
+    fn sample_nearest(&self, x: f64, y: f64) -> [f32; 4] {
+        self.texel(x.floor() as i64, y.floor() as i64)
+    }
+
+    fn sample_bilinear(&self, x: f64, y: f64) -> [f32; 4] {
+        let x = x - 0.5;
+        let y = y - 0.5;
+        let x0 = x.floor();
+        let y0 = y.floor();
+        let fx = (x - x0) as f32;
+        let fy = (y - y0) as f32;
+        let (x0, y0) = (x0 as i64, y0 as i64);
+
+        let top_left = self.texel(x0, y0);
+        let top_right = self.texel(x0 + 1, y0);
+        let bottom_left = self.texel(x0, y0 + 1);
+        let bottom_right = self.texel(x0 + 1, y0 + 1);
+
+        let mut out = [0.0; 4];
+        for (i, out) in out.iter_mut().enumerate() {
+            let top = top_left[i] + (top_right[i] - top_left[i]) * fx;
+            let bottom = bottom_left[i] + (bottom_right[i] - bottom_left[i]) * fx;
+            *out = top + (bottom - top) * fy;
+        }
+        out
+    }
+}
+
+/// Converts a color to premultiplied RGBA.
+fn premultiply(color: &Color) -> [f32; 4] {
+    let alpha = f32::from(color.a) / 255.0;
+    [
+        f32::from(color.r) * alpha,
+        f32::from(color.g) * alpha,
+        f32::from(color.b) * alpha,
+        f32::from(color.a),
+    ]
+}
+
+/// Applies a color transform to a premultiplied color.
+fn apply_color_transform(color: [f32; 4], color_transform: &ColorTransform) -> [f32; 4] {
+    if color_transform.is_identity() {
+        return color;
+    }
+
+    let [r, g, b, a] = color;
+    let (r, g, b) = if a > 0.0 {
+        let scale = 255.0 / a;
+        (r * scale, g * scale, b * scale)
+    } else {
+        (0.0, 0.0, 0.0)
+    };
+
+    let clamp = |value: f32| value.max(0.0).min(255.0);
+    let a = clamp(a * color_transform.a_mult + color_transform.a_add * 255.0);
+    let r = clamp(r * color_transform.r_mult + color_transform.r_add * 255.0);
+    let g = clamp(g * color_transform.g_mult + color_transform.g_add * 255.0);
+    let b = clamp(b * color_transform.b_mult + color_transform.b_add * 255.0);
+
+    let alpha = a / 255.0;
+    [r * alpha, g * alpha, b * alpha, a]
+}
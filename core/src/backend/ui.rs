@@ -1,6 +1,33 @@
+pub type Error = Box<dyn std::error::Error>;
+
 pub trait UiBackend {
     fn is_fullscreen(&self) -> bool;
+
+    /// Enters or exits fullscreen mode.
+    ///
+    /// This may fail if the platform does not allow the content to go
+    /// fullscreen, such as when a browser requires a user gesture.
+    fn set_fullscreen(&mut self, is_full: bool) -> Result<(), Error>;
+
     fn message(&self, message: &str);
+
+    /// Closes the player, as requested by the `quit` FSCommand.
+    ///
+    /// Players that can't be closed by content ignore this.
+    fn quit(&mut self) {}
+
+    /// Runs an external application, as requested by the `exec` FSCommand.
+    ///
+    /// This should fail unless the user has explicitly allowed content to
+    /// launch applications.
+    fn exec(&mut self, _command: &str) -> Result<(), Error> {
+        Err("Exec is not supported".into())
+    }
+
+    /// Sets whether all key presses should be sent to the content, rather
+    /// than some being handled by the player itself (such as Escape leaving
+    /// fullscreen mode).
+    fn set_trap_all_keys(&mut self, _trap_all_keys: bool) {}
 }
 
 /// UiBackend that does mostly nothing
@@ -16,6 +43,11 @@ impl UiBackend for NullUiBackend {
     fn is_fullscreen(&self) -> bool {
         false
     }
+
+    fn set_fullscreen(&mut self, _is_full: bool) -> Result<(), Error> {
+        Err("Fullscreen is not supported".into())
+    }
+
     fn message(&self, _message: &str) {}
 }
 
@@ -0,0 +1,5 @@
+//! Bitmap and BitmapData
+
+pub mod bitmap_data;
+pub mod filters;
+pub mod turbulence;
@@ -0,0 +1,1346 @@
+//! VM-agnostic bitmap pixel storage
+
+use crate::backend::render::software::OffscreenTarget;
+use crate::backend::render::{Bitmap, BitmapFormat, BitmapHandle, RenderBackend};
+use crate::bitmap::turbulence::Turbulence;
+use crate::bounding_box::BoundingBox;
+use crate::color_transform::ColorTransform;
+use crate::context::{RenderContext, UpdateContext};
+use crate::display_object::{DisplayObject, TDisplayObject};
+use crate::transform::{Transform, TransformStack};
+use gc_arena::Collect;
+use rand::prelude::SmallRng;
+use rand::Rng;
+use std::fmt::Formatter;
+use swf::{BlendMode, Twips};
+
+/// Feedback masks of maximal-length Galois linear feedback shift registers,
+/// indexed by the number of bits in the register.
+const LFSR_FEEDBACK_MASKS: [u32; 33] = [
+    0, 0, 0x3, 0x6, 0xC, 0x14, 0x30, 0x60, 0xB8, 0x110, 0x240, 0x500, 0x829, 0x100D, 0x2015,
+    0x6000, 0xD008, 0x12000, 0x20400, 0x40023, 0x90000, 0x140000, 0x300000, 0x420000, 0xE10000,
+    0x1200000, 0x2000023, 0x4000013, 0x9000000, 0x14000000, 0x20000029, 0x48000000, 0x80200003,
+];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Collect)]
+#[collect(no_drop)]
+pub struct Color(i32);
+
+impl Color {
+    pub fn blue(&self) -> u8 {
+        (self.0 & 0xFF) as u8
+    }
+
+    pub fn green(&self) -> u8 {
+        ((self.0 >> 8) & 0xFF) as u8
+    }
+
+    pub fn red(&self) -> u8 {
+        ((self.0 >> 16) & 0xFF) as u8
+    }
+
+    pub fn alpha(&self) -> u8 {
+        ((self.0 >> 24) & 0xFF) as u8
+    }
+
+    pub fn to_premultiplied_alpha(&self, transparency: bool) -> Color {
+        // This has some accuracy issues with some alpha values
+
+        let old_alpha = if transparency { self.alpha() } else { 255 };
+
+        let a = old_alpha as f64 / 255.0;
+
+        let r = (self.red() as f64 * a).round() as u8;
+        let g = (self.green() as f64 * a).round() as u8;
+        let b = (self.blue() as f64 * a).round() as u8;
+
+        Color::argb(old_alpha, r, g, b)
+    }
+
+    pub fn to_un_multiplied_alpha(&self) -> Color {
+        let a = self.alpha() as f64 / 255.0;
+
+        let r = (self.red() as f64 / a).round() as u8;
+        let g = (self.green() as f64 / a).round() as u8;
+        let b = (self.blue() as f64 / a).round() as u8;
+
+        Color::argb(self.alpha(), r, g, b)
+    }
+
+    pub fn argb(alpha: u8, red: u8, green: u8, blue: u8) -> Color {
+        Color(((alpha as i32) << 24) | (red as i32) << 16 | (green as i32) << 8 | (blue as i32))
+    }
+
+    pub fn with_alpha(&self, alpha: u8) -> Color {
+        Color::argb(alpha, self.red(), self.green(), self.blue())
+    }
+
+    pub fn blend_over(&self, source: &Self) -> Self {
+        let sa = source.alpha();
+
+        let r = source.red() + ((self.red() as u16 * (255 - sa as u16)) >> 8) as u8;
+        let g = source.green() + ((self.green() as u16 * (255 - sa as u16)) >> 8) as u8;
+        let b = source.blue() + ((self.blue() as u16 * (255 - sa as u16)) >> 8) as u8;
+        let a = source.alpha() + ((self.alpha() as u16 * (255 - sa as u16)) >> 8) as u8;
+        Color::argb(a, r, g, b)
+    }
+
+    /// Blends a premultiplied source color onto this one using the given
+    /// blend mode.
+    pub fn blend(&self, source: &Self, blend_mode: BlendMode) -> Self {
+        let to_floats = |c: &Color| {
+            [
+                f32::from(c.red()) / 255.0,
+                f32::from(c.green()) / 255.0,
+                f32::from(c.blue()) / 255.0,
+                f32::from(c.alpha()) / 255.0,
+            ]
+        };
+        let dst = to_floats(self);
+        let src = to_floats(source);
+        let (sa, da) = (src[3], dst[3]);
+
+        let mut out = [0.0; 4];
+        match blend_mode {
+            BlendMode::Normal | BlendMode::Layer => {
+                for (i, out) in out.iter_mut().enumerate() {
+                    *out = src[i] + dst[i] * (1.0 - sa);
+                }
+            }
+            BlendMode::Alpha => {
+                for (out, dst) in out.iter_mut().zip(&dst) {
+                    *out = dst * sa;
+                }
+            }
+            BlendMode::Erase => {
+                for (out, dst) in out.iter_mut().zip(&dst) {
+                    *out = dst * (1.0 - sa);
+                }
+            }
+            BlendMode::Invert => {
+                for (out, dst) in out.iter_mut().zip(&dst).take(3) {
+                    *out = dst * (1.0 - sa) + (da - dst) * sa;
+                }
+                out[3] = da;
+            }
+            _ => {
+                let unmultiply = |c: f32, a: f32| if a > 0.0 { c / a } else { 0.0 };
+                for (i, out) in out.iter_mut().enumerate().take(3) {
+                    let cs = unmultiply(src[i], sa);
+                    let cb = unmultiply(dst[i], da);
+                    let blended = match blend_mode {
+                        BlendMode::Multiply => cb * cs,
+                        BlendMode::Screen => cb + cs - cb * cs,
+                        BlendMode::Lighten => cb.max(cs),
+                        BlendMode::Darken => cb.min(cs),
+                        BlendMode::Difference => (cb - cs).abs(),
+                        BlendMode::Add => (cb + cs).min(1.0),
+                        BlendMode::Subtract => (cb - cs).max(0.0),
+                        BlendMode::Overlay => hard_light(cs, cb),
+                        BlendMode::HardLight => hard_light(cb, cs),
+                        _ => cs,
+                    };
+                    *out = src[i] * (1.0 - da) + dst[i] * (1.0 - sa) + blended * sa * da;
+                }
+                out[3] = sa + da - sa * da;
+            }
+        }
+
+        let to_u8 = |v: f32| (v * 255.0).round().max(0.0).min(255.0) as u8;
+        Color::argb(to_u8(out[3]), to_u8(out[0]), to_u8(out[1]), to_u8(out[2]))
+    }
+}
+
+/// The hard light blend function for a single unmultiplied channel.
+fn hard_light(backdrop: f32, source: f32) -> f32 {
+    if source <= 0.5 {
+        backdrop * 2.0 * source
+    } else {
+        let source = 2.0 * source - 1.0;
+        backdrop + source - backdrop * source
+    }
+}
+
+/// Parses the name of a blend mode, as accepted by `BitmapData.draw`.
+pub fn blend_mode_from_name(name: &str) -> Option<BlendMode> {
+    Some(match name {
+        "normal" => BlendMode::Normal,
+        "layer" => BlendMode::Layer,
+        "multiply" => BlendMode::Multiply,
+        "screen" => BlendMode::Screen,
+        "lighten" => BlendMode::Lighten,
+        "darken" => BlendMode::Darken,
+        "difference" => BlendMode::Difference,
+        "add" => BlendMode::Add,
+        "subtract" => BlendMode::Subtract,
+        "invert" => BlendMode::Invert,
+        "alpha" => BlendMode::Alpha,
+        "erase" => BlendMode::Erase,
+        "overlay" => BlendMode::Overlay,
+        "hardlight" => BlendMode::HardLight,
+        _ => return None,
+    })
+}
+
+/// Converts the numeric ID of a blend mode, as used in SWF files and AVM1.
+pub fn blend_mode_from_id(id: i32) -> Option<BlendMode> {
+    Some(match id {
+        1 => BlendMode::Normal,
+        2 => BlendMode::Layer,
+        3 => BlendMode::Multiply,
+        4 => BlendMode::Screen,
+        5 => BlendMode::Lighten,
+        6 => BlendMode::Darken,
+        7 => BlendMode::Difference,
+        8 => BlendMode::Add,
+        9 => BlendMode::Subtract,
+        10 => BlendMode::Invert,
+        11 => BlendMode::Alpha,
+        12 => BlendMode::Erase,
+        13 => BlendMode::Overlay,
+        14 => BlendMode::HardLight,
+        _ => return None,
+    })
+}
+
+/// Renders a display object and its children into a transparent layer of the
+/// given size, for use with `BitmapData::composite`.
+///
+/// The object is drawn as if it were at the root of the display list, ignoring
+/// its own transform, and then transformed by `transform`.
+pub fn render_display_object<'gc>(
+    context: &mut UpdateContext<'_, 'gc, '_>,
+    source: DisplayObject<'gc>,
+    transform: &Transform,
+    width: u32,
+    height: u32,
+) -> Result<Bitmap, Box<dyn std::error::Error>> {
+    let gc_context = context.gc_context;
+    let matrix = std::mem::take(&mut *source.matrix_mut(gc_context));
+    let color_transform = std::mem::take(&mut *source.color_transform_mut(gc_context));
+
+    let library = &*context.library;
+    let mut transform_stack = TransformStack::new();
+    transform_stack.push(transform);
+    let result = context.renderer.render_offscreen(
+        width,
+        height,
+        &mut |renderer: &mut dyn RenderBackend| {
+            let mut render_context = RenderContext {
+                renderer,
+                library,
+                transform_stack: &mut transform_stack,
+                // Culling is done in stage coordinates, which don't apply here.
+                view_bounds: BoundingBox {
+                    x_min: Twips::new(i32::MIN),
+                    y_min: Twips::new(i32::MIN),
+                    x_max: Twips::new(i32::MAX),
+                    y_max: Twips::new(i32::MAX),
+                    valid: true,
+                },
+                clip_depth_stack: vec![],
+                allow_mask: true,
+            };
+            source.render(&mut render_context);
+        },
+    );
+
+    *source.matrix_mut(gc_context) = matrix;
+    *source.color_transform_mut(gc_context) = color_transform;
+    result
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("{:#x}", self.0))
+    }
+}
+
+impl From<Color> for i32 {
+    fn from(c: Color) -> Self {
+        c.0
+    }
+}
+
+impl From<Color> for u32 {
+    fn from(c: Color) -> Self {
+        c.0 as u32
+    }
+}
+
+impl From<i32> for Color {
+    fn from(i: i32) -> Self {
+        Color(i)
+    }
+}
+
+pub struct ChannelOptions(pub u32);
+
+impl ChannelOptions {
+    pub fn alpha(&self) -> bool {
+        self.0 & 8 == 8
+    }
+    pub fn red(&self) -> bool {
+        self.0 & 1 == 1
+    }
+    pub fn green(&self) -> bool {
+        self.0 & 2 == 2
+    }
+    pub fn blue(&self) -> bool {
+        self.0 & 4 == 4
+    }
+
+    pub fn rgb() -> Self {
+        (1 | 2 | 4).into()
+    }
+}
+
+impl From<u32> for ChannelOptions {
+    fn from(v: u32) -> Self {
+        Self(v)
+    }
+}
+
+/// The comparison used by `BitmapData.threshold`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ThresholdOperation {
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl ThresholdOperation {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "<" => Some(ThresholdOperation::LessThan),
+            "<=" => Some(ThresholdOperation::LessThanOrEqual),
+            ">" => Some(ThresholdOperation::GreaterThan),
+            ">=" => Some(ThresholdOperation::GreaterThanOrEqual),
+            "==" => Some(ThresholdOperation::Equal),
+            "!=" => Some(ThresholdOperation::NotEqual),
+            _ => None,
+        }
+    }
+
+    pub fn matches(self, value: u32, threshold: u32) -> bool {
+        match self {
+            ThresholdOperation::LessThan => value < threshold,
+            ThresholdOperation::LessThanOrEqual => value <= threshold,
+            ThresholdOperation::GreaterThan => value > threshold,
+            ThresholdOperation::GreaterThanOrEqual => value >= threshold,
+            ThresholdOperation::Equal => value == threshold,
+            ThresholdOperation::NotEqual => value != threshold,
+        }
+    }
+}
+
+#[derive(Clone, Collect, Default, Debug)]
+#[collect(no_drop)]
+pub struct BitmapData {
+    /// The pixels in the bitmap, stored as a array of pre-multiplied ARGB colour values
+    pub pixels: Vec<Color>,
+    dirty: bool,
+    width: u32,
+    height: u32,
+    transparency: bool,
+
+    /// Whether changes to the pixels are being withheld from the renderer.
+    locked: bool,
+
+    bitmap_handle: Option<BitmapHandle>,
+}
+
+impl BitmapData {
+    pub fn init_pixels(&mut self, width: u32, height: u32, fill_color: i32, transparency: bool) {
+        self.width = width;
+        self.height = height;
+        self.transparency = transparency;
+        self.pixels = vec![
+            Color(fill_color).to_premultiplied_alpha(self.transparency());
+            (width * height) as usize
+        ];
+        self.dirty = true;
+    }
+
+    pub fn dispose(&mut self) {
+        self.width = 0;
+        self.height = 0;
+        self.pixels.clear();
+        self.dirty = true;
+    }
+
+    pub fn bitmap_handle(&mut self, renderer: &mut dyn RenderBackend) -> Option<BitmapHandle> {
+        if self.bitmap_handle.is_none() {
+            match renderer.register_bitmap_raw(self.width(), self.height(), self.pixels_rgba()) {
+                Ok(bitmap_handle) => self.bitmap_handle = Some(bitmap_handle),
+                Err(err) => log::warn!("Failed to register raw bitmap for BitmapData: {:?}", err),
+            }
+        }
+
+        self.bitmap_handle
+    }
+
+    pub fn transparency(&self) -> bool {
+        self.transparency
+    }
+
+    pub fn set_transparency(&mut self, transparency: bool) {
+        self.transparency = transparency;
+    }
+
+    pub fn dirty(&self) -> bool {
+        self.dirty
+    }
+
+    pub fn set_dirty(&mut self, dirty: bool) {
+        self.dirty = dirty;
+    }
+
+    /// Whether changes are currently withheld from bitmaps that display this
+    /// data, such as during `BitmapData.lock`.
+    pub fn locked(&self) -> bool {
+        self.locked
+    }
+
+    pub fn set_locked(&mut self, locked: bool) {
+        self.locked = locked;
+    }
+
+    pub fn pixels(&self) -> &[Color] {
+        &self.pixels
+    }
+
+    pub fn set_pixels(&mut self, pixels: Vec<Color>) {
+        self.pixels = pixels;
+    }
+
+    pub fn pixels_rgba(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+
+        for p in &self.pixels {
+            output.extend_from_slice(&[p.red(), p.green(), p.blue(), p.alpha()])
+        }
+
+        output
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn is_point_in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && x < self.width() as i32 && y >= 0 && y < self.height() as i32
+    }
+
+    pub fn get_pixel_raw(&self, x: u32, y: u32) -> Option<Color> {
+        if x > self.width() || y > self.height() {
+            return None;
+        }
+
+        self.pixels.get((x + y * self.width()) as usize).copied()
+    }
+
+    pub fn get_pixel32(&self, x: i32, y: i32) -> Color {
+        self.get_pixel_raw(x as u32, y as u32)
+            .map(|f| f.to_un_multiplied_alpha())
+            .unwrap_or_else(|| 0.into())
+    }
+
+    pub fn get_pixel(&self, x: i32, y: i32) -> i32 {
+        if !self.is_point_in_bounds(x, y) {
+            0
+        } else {
+            self.get_pixel32(x, y).with_alpha(0x0).into()
+        }
+    }
+
+    pub fn set_pixel(&mut self, x: u32, y: u32, color: Color) {
+        let current_alpha = self.get_pixel_raw(x, y).map(|p| p.alpha()).unwrap_or(0);
+        self.set_pixel32(x as i32, y as i32, color.with_alpha(current_alpha));
+    }
+
+    pub fn set_pixel32_raw(&mut self, x: u32, y: u32, color: Color) {
+        let width = self.width();
+        self.pixels[(x + y * width) as usize] = color;
+        self.dirty = true;
+    }
+
+    pub fn set_pixel32(&mut self, x: i32, y: i32, color: Color) {
+        if self.is_point_in_bounds(x, y) {
+            self.set_pixel32_raw(
+                x as u32,
+                y as u32,
+                color.to_premultiplied_alpha(self.transparency()),
+            )
+        }
+    }
+
+    /// Clip a rectangle to the bounds of this bitmap, returning its minimum
+    /// and maximum (exclusive) corners.
+    fn clip_rect(&self, x: i32, y: i32, width: i32, height: i32) -> (u32, u32, u32, u32) {
+        let min_x = x.max(0).min(self.width() as i32) as u32;
+        let min_y = y.max(0).min(self.height() as i32) as u32;
+        let max_x = x
+            .saturating_add(width)
+            .max(min_x as i32)
+            .min(self.width() as i32) as u32;
+        let max_y = y
+            .saturating_add(height)
+            .max(min_y as i32)
+            .min(self.height() as i32) as u32;
+
+        (min_x, min_y, max_x, max_y)
+    }
+
+    /// Read the (non-premultiplied) colors of a rectangle of pixels, row by
+    /// row.
+    ///
+    /// Any part of the rectangle outside of the bitmap is ignored.
+    pub fn get_pixels_rect(&self, x: i32, y: i32, width: i32, height: i32) -> Vec<Color> {
+        let (min_x, min_y, max_x, max_y) = self.clip_rect(x, y, width, height);
+        let mut colors = Vec::with_capacity(((max_x - min_x) * (max_y - min_y)) as usize);
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                colors.push(self.get_pixel32(x as i32, y as i32));
+            }
+        }
+
+        colors
+    }
+
+    /// Write (non-premultiplied) colors to a rectangle of pixels, row by row.
+    ///
+    /// Any part of the rectangle outside of the bitmap is ignored. Writing
+    /// stops early if there are fewer colors than pixels; the number of pixels
+    /// written is returned.
+    pub fn set_pixels_rect(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        colors: impl IntoIterator<Item = Color>,
+    ) -> usize {
+        let (min_x, min_y, max_x, max_y) = self.clip_rect(x, y, width, height);
+        let mut colors = colors.into_iter();
+        let mut written = 0;
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                match colors.next() {
+                    Some(color) => self.set_pixel32(x as i32, y as i32, color),
+                    None => return written,
+                }
+                written += 1;
+            }
+        }
+
+        written
+    }
+
+    pub fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, color: Color) {
+        for x_offset in 0..width {
+            for y_offset in 0..height {
+                self.set_pixel32((x + x_offset) as i32, (y + y_offset) as i32, color)
+            }
+        }
+    }
+
+    pub fn flood_fill(&mut self, x: u32, y: u32, replace_color: Color) {
+        let expected_color = self.get_pixel_raw(x, y).unwrap_or_else(|| 0.into());
+
+        let mut pending = Vec::new();
+        pending.push((x, y));
+
+        while !pending.is_empty() {
+            if let Some((x, y)) = pending.pop() {
+                if let Some(old_color) = self.get_pixel_raw(x, y) {
+                    if old_color == expected_color {
+                        if x > 0 {
+                            pending.push((x - 1, y));
+                        }
+                        if y > 0 {
+                            pending.push((x, y - 1));
+                        }
+                        if x < self.width() - 1 {
+                            pending.push((x + 1, y))
+                        }
+                        if y < self.height() - 1 {
+                            pending.push((x, y + 1));
+                        }
+                        self.set_pixel32_raw(x, y, replace_color);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn noise(
+        &mut self,
+        rng: &mut SmallRng,
+        _seed: u32,
+        low: u8,
+        high: u8,
+        channel_options: ChannelOptions,
+        gray_scale: bool,
+    ) {
+        for x in 0..self.width() {
+            for y in 0..self.height() {
+                let pixel_color = if gray_scale {
+                    let gray = rng.gen_range(low..high);
+                    Color::argb(
+                        if channel_options.alpha() {
+                            rng.gen_range(low..high)
+                        } else {
+                            255
+                        },
+                        gray,
+                        gray,
+                        gray,
+                    )
+                } else {
+                    Color::argb(
+                        if channel_options.alpha() {
+                            rng.gen_range(low..high)
+                        } else {
+                            255
+                        },
+                        if channel_options.red() {
+                            rng.gen_range(low..high)
+                        } else {
+                            0
+                        },
+                        if channel_options.green() {
+                            rng.gen_range(low..high)
+                        } else {
+                            0
+                        },
+                        if channel_options.blue() {
+                            rng.gen_range(low..high)
+                        } else {
+                            0
+                        },
+                    )
+                };
+
+                self.set_pixel32_raw(x, y, pixel_color);
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn perlin_noise(
+        &mut self,
+        base: (f64, f64),
+        num_octaves: usize,
+        random_seed: i32,
+        stitch: bool,
+        fractal_noise: bool,
+        channel_options: ChannelOptions,
+        grayscale: bool,
+        offsets: &[(f64, f64)],
+    ) {
+        let turbulence = Turbulence::from_seed(random_seed);
+        let frequency = |base: f64| if base != 0.0 { 1.0 / base } else { 0.0 };
+        let base_frequency = (frequency(base.0), frequency(base.1));
+        let tile = (0.0, 0.0, self.width() as f64, self.height() as f64);
+
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let point = (x as f64, y as f64);
+                let noise = |channel: usize| {
+                    let sum = turbulence.turbulence(
+                        channel,
+                        point,
+                        base_frequency,
+                        num_octaves,
+                        fractal_noise,
+                        stitch,
+                        tile,
+                        offsets,
+                    );
+                    let value = if fractal_noise {
+                        (sum * 255.0 + 255.0) / 2.0
+                    } else {
+                        sum * 255.0
+                    };
+                    value.max(0.0).min(255.0) as u8
+                };
+
+                let (red, green, blue) = if grayscale {
+                    let gray = noise(0);
+                    (gray, gray, gray)
+                } else {
+                    (
+                        if channel_options.red() { noise(0) } else { 0 },
+                        if channel_options.green() { noise(1) } else { 0 },
+                        if channel_options.blue() { noise(2) } else { 0 },
+                    )
+                };
+                let alpha = if channel_options.alpha() {
+                    noise(3)
+                } else {
+                    255
+                };
+
+                let color =
+                    Color::argb(alpha, red, green, blue).to_premultiplied_alpha(self.transparency);
+                self.set_pixel32_raw(x, y, color);
+            }
+        }
+    }
+
+    /// Clips the copy of `src_rect` from a source of the given size to
+    /// `dest_point` in this bitmap, returning the minimum corners of the
+    /// source and destination areas and the size of the copy.
+    fn clip_copy(
+        &self,
+        source_size: (u32, u32),
+        src_rect: (i32, i32, i32, i32),
+        dest_point: (i32, i32),
+    ) -> ((u32, u32), (u32, u32), (u32, u32)) {
+        let clip_axis = |src: i32, size: i32, dest: i32, src_max: u32, dest_max: u32| {
+            let (mut src, mut size, mut dest) = (i64::from(src), i64::from(size), i64::from(dest));
+            if src < 0 {
+                dest -= src;
+                size += src;
+                src = 0;
+            }
+            if dest < 0 {
+                src -= dest;
+                size += dest;
+                dest = 0;
+            }
+            let size = size
+                .min(i64::from(src_max) - src)
+                .min(i64::from(dest_max) - dest)
+                .max(0);
+            (src.max(0) as u32, dest.max(0) as u32, size as u32)
+        };
+
+        let (src_x, dest_x, width) = clip_axis(
+            src_rect.0,
+            src_rect.2,
+            dest_point.0,
+            source_size.0,
+            self.width(),
+        );
+        let (src_y, dest_y, height) = clip_axis(
+            src_rect.1,
+            src_rect.3,
+            dest_point.1,
+            source_size.1,
+            self.height(),
+        );
+
+        ((src_x, src_y), (dest_x, dest_y), (width, height))
+    }
+
+    pub fn palette_map(
+        &mut self,
+        source_bitmap: &Self,
+        src_rect: (i32, i32, i32, i32),
+        dest_point: (i32, i32),
+        channel_arrays: (&[u32; 256], &[u32; 256], &[u32; 256], &[u32; 256]),
+    ) {
+        let (red, green, blue, alpha) = channel_arrays;
+        let ((src_x, src_y), (dest_x, dest_y), (width, height)) = self.clip_copy(
+            (source_bitmap.width(), source_bitmap.height()),
+            src_rect,
+            dest_point,
+        );
+
+        for y in 0..height {
+            for x in 0..width {
+                let source_color = source_bitmap
+                    .get_pixel_raw(src_x + x, src_y + y)
+                    .unwrap()
+                    .to_un_multiplied_alpha();
+
+                let color = red[usize::from(source_color.red())]
+                    .wrapping_add(green[usize::from(source_color.green())])
+                    .wrapping_add(blue[usize::from(source_color.blue())])
+                    .wrapping_add(alpha[usize::from(source_color.alpha())]);
+
+                self.set_pixel32_raw(
+                    dest_x + x,
+                    dest_y + y,
+                    Color::from(color as i32).to_premultiplied_alpha(self.transparency),
+                );
+            }
+        }
+    }
+
+    /// Sets every pixel of `src_rect` whose masked value passes the test
+    /// against the masked `threshold` to `color`, copying the rest from the
+    /// source if `copy_source` is set. Returns the number of pixels that
+    /// passed the test.
+    #[allow(clippy::too_many_arguments)]
+    pub fn threshold(
+        &mut self,
+        source_bitmap: &Self,
+        src_rect: (i32, i32, i32, i32),
+        dest_point: (i32, i32),
+        operation: ThresholdOperation,
+        threshold: u32,
+        color: u32,
+        mask: u32,
+        copy_source: bool,
+    ) -> u32 {
+        let ((src_x, src_y), (dest_x, dest_y), (width, height)) = self.clip_copy(
+            (source_bitmap.width(), source_bitmap.height()),
+            src_rect,
+            dest_point,
+        );
+        let color = Color::from(color as i32).to_premultiplied_alpha(self.transparency);
+        let threshold = threshold & mask;
+
+        let mut count = 0;
+        for y in 0..height {
+            for x in 0..width {
+                let source_color = source_bitmap.get_pixel_raw(src_x + x, src_y + y).unwrap();
+                let value = u32::from(source_color.to_un_multiplied_alpha()) & mask;
+
+                if operation.matches(value, threshold) {
+                    self.set_pixel32_raw(dest_x + x, dest_y + y, color);
+                    count += 1;
+                } else if copy_source {
+                    let dest_color = self.get_pixel_raw(dest_x + x, dest_y + y).unwrap();
+                    let source_color = if source_bitmap.transparency && !self.transparency {
+                        dest_color.blend_over(&source_color)
+                    } else {
+                        source_color
+                    };
+                    self.set_pixel32_raw(dest_x + x, dest_y + y, source_color);
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Blends `src_rect` of the source into this bitmap, mixing each channel
+    /// by its multiplier out of 256.
+    pub fn merge(
+        &mut self,
+        source_bitmap: &Self,
+        src_rect: (i32, i32, i32, i32),
+        dest_point: (i32, i32),
+        rgba_mult: (i32, i32, i32, i32),
+    ) {
+        let ((src_x, src_y), (dest_x, dest_y), (width, height)) = self.clip_copy(
+            (source_bitmap.width(), source_bitmap.height()),
+            src_rect,
+            dest_point,
+        );
+        let clamp = |mult: i32| mult.max(0).min(256) as u16;
+        let (red_mult, green_mult, blue_mult, alpha_mult) = (
+            clamp(rgba_mult.0),
+            clamp(rgba_mult.1),
+            clamp(rgba_mult.2),
+            clamp(rgba_mult.3),
+        );
+        let mix = |source: u8, dest: u8, mult: u16| {
+            ((u16::from(source) * mult + u16::from(dest) * (256 - mult)) >> 8) as u8
+        };
+
+        for y in 0..height {
+            for x in 0..width {
+                let source_color = source_bitmap
+                    .get_pixel_raw(src_x + x, src_y + y)
+                    .unwrap()
+                    .to_un_multiplied_alpha();
+                let dest_color = self
+                    .get_pixel_raw(dest_x + x, dest_y + y)
+                    .unwrap()
+                    .to_un_multiplied_alpha();
+
+                let color = Color::argb(
+                    mix(source_color.alpha(), dest_color.alpha(), alpha_mult),
+                    mix(source_color.red(), dest_color.red(), red_mult),
+                    mix(source_color.green(), dest_color.green(), green_mult),
+                    mix(source_color.blue(), dest_color.blue(), blue_mult),
+                );
+                self.set_pixel32_raw(
+                    dest_x + x,
+                    dest_y + y,
+                    color.to_premultiplied_alpha(self.transparency),
+                );
+            }
+        }
+    }
+
+    /// Replaces `num_pixels` pixels of the destination area with the
+    /// corresponding pixels of `source_bitmap`, or with `fill_color` if there
+    /// is no separate source. Returns the seed to pass to the next call to
+    /// continue the dissolve.
+    ///
+    /// The pixels are visited in the order given by a maximal-length linear
+    /// feedback shift register, whose state is the seed. It reaches every
+    /// pixel of the area exactly once before repeating, so repeated calls
+    /// with the returned seed eventually replace the whole area. The exact
+    /// order is not known to match Flash Player's.
+    #[allow(clippy::too_many_arguments)]
+    pub fn pixel_dissolve(
+        &mut self,
+        source_bitmap: Option<&Self>,
+        src_rect: (i32, i32, i32, i32),
+        dest_point: (i32, i32),
+        random_seed: i32,
+        num_pixels: i32,
+        fill_color: u32,
+    ) -> i32 {
+        let source_size = source_bitmap
+            .map(|source| (source.width(), source.height()))
+            .unwrap_or_else(|| (self.width(), self.height()));
+        let ((src_x, src_y), (dest_x, dest_y), (width, height)) =
+            self.clip_copy(source_size, src_rect, dest_point);
+
+        let area = width * height;
+        if area == 0 {
+            return random_seed;
+        }
+
+        // The register needs more states than there are pixels, as it never
+        // reaches zero. State `n` stands for the pixel at index `n - 1`.
+        let bits = (32 - area.leading_zeros()).max(2);
+        let feedback_mask = LFSR_FEEDBACK_MASKS[bits as usize];
+        let period = u32::MAX >> (32 - bits);
+        let mut state = (random_seed as u32).wrapping_sub(1) % period + 1;
+        let fill_color = Color::from(fill_color as i32).to_premultiplied_alpha(self.transparency);
+
+        for _ in 0..(num_pixels.max(0) as u32).min(area) {
+            loop {
+                state = if state & 1 != 0 {
+                    (state >> 1) ^ feedback_mask
+                } else {
+                    state >> 1
+                };
+                if state <= area {
+                    break;
+                }
+            }
+
+            let x = (state - 1) % width;
+            let y = (state - 1) / width;
+            let color = match source_bitmap {
+                Some(source_bitmap) => {
+                    let source_color = source_bitmap.get_pixel_raw(src_x + x, src_y + y).unwrap();
+                    if source_bitmap.transparency && !self.transparency {
+                        let dest_color = self.get_pixel_raw(dest_x + x, dest_y + y).unwrap();
+                        dest_color.blend_over(&source_color)
+                    } else {
+                        source_color
+                    }
+                }
+                None => fill_color,
+            };
+            self.set_pixel32_raw(dest_x + x, dest_y + y, color);
+        }
+
+        state as i32
+    }
+
+    /// Tests whether the pixel at `point` is at least as opaque as
+    /// `alpha_threshold`.
+    pub fn hit_test_point(&self, alpha_threshold: u8, point: (i32, i32)) -> bool {
+        self.is_point_in_bounds(point.0, point.1)
+            && self
+                .get_pixel_raw(point.0 as u32, point.1 as u32)
+                .map(|color| color.alpha() >= alpha_threshold)
+                .unwrap_or(false)
+    }
+
+    /// Tests whether any pixel within `rect` is at least as opaque as
+    /// `alpha_threshold`.
+    pub fn hit_test_rectangle(&self, alpha_threshold: u8, rect: (i32, i32, i32, i32)) -> bool {
+        let (min_x, min_y, max_x, max_y) = self.clip_rect(rect.0, rect.1, rect.2, rect.3);
+        (min_y..max_y).any(|y| {
+            (min_x..max_x).any(|x| {
+                self.get_pixel_raw(x, y)
+                    .map(|color| color.alpha() >= alpha_threshold)
+                    .unwrap_or(false)
+            })
+        })
+    }
+
+    /// Tests whether this bitmap, placed at `self_point`, and `other`, placed
+    /// at `other_point`, have an overlapping pixel that is at least as opaque
+    /// as their respective thresholds.
+    pub fn hit_test_bitmapdata(
+        &self,
+        self_point: (i32, i32),
+        self_threshold: u8,
+        other: &Self,
+        other_point: (i32, i32),
+        other_threshold: u8,
+    ) -> bool {
+        let ((self_x, self_y), (other_x, other_y), (width, height)) = other.clip_copy(
+            (self.width(), self.height()),
+            (
+                other_point.0 - self_point.0,
+                other_point.1 - self_point.1,
+                other.width() as i32,
+                other.height() as i32,
+            ),
+            (0, 0),
+        );
+
+        (0..height).any(|y| {
+            (0..width).any(|x| {
+                let self_alpha = self.get_pixel_raw(self_x + x, self_y + y).unwrap().alpha();
+                let other_alpha = other
+                    .get_pixel_raw(other_x + x, other_y + y)
+                    .unwrap()
+                    .alpha();
+                self_alpha >= self_threshold && other_alpha >= other_threshold
+            })
+        })
+    }
+
+    pub fn copy_channel(
+        &mut self,
+        dest_point: (u32, u32),
+        src_rect: (u32, u32, u32, u32),
+        source_bitmap: &Self,
+        source_channel: i32,
+        dest_channel: i32,
+    ) {
+        let (min_x, min_y) = dest_point;
+        let (src_min_x, src_min_y, src_max_x, src_max_y) = src_rect;
+
+        for x in src_min_x..src_max_x.min(source_bitmap.width()) {
+            for y in src_min_y..src_max_y.min(source_bitmap.height()) {
+                if self.is_point_in_bounds((x + min_x) as i32, (y + min_y) as i32) {
+                    let original_color: u32 = self
+                        .get_pixel_raw(x + min_x, y + min_y)
+                        .unwrap_or_else(|| 0.into())
+                        .into();
+                    let source_color: u32 = source_bitmap
+                        .get_pixel_raw(x, y)
+                        .unwrap_or_else(|| 0.into())
+                        .into();
+
+                    let channel_shift: u32 = match source_channel {
+                        // Alpha
+                        8 => 24,
+                        // red
+                        1 => 16,
+                        // green
+                        2 => 8,
+                        // blue
+                        4 => 0,
+                        _ => 0,
+                    };
+
+                    let source_part = (source_color >> channel_shift) & 0xFF;
+
+                    let result_color: u32 = match dest_channel {
+                        // Alpha
+                        8 => (original_color & 0x00FFFFFF) | source_part << 24,
+                        // red
+                        1 => (original_color & 0xFF00FFFF) | source_part << 16,
+                        // green
+                        2 => (original_color & 0xFFFF00FF) | source_part << 8,
+                        // blue
+                        4 => (original_color & 0xFFFFFF00) | source_part,
+                        _ => original_color,
+                    };
+
+                    self.set_pixel32_raw(x + min_x, y + min_y, (result_color as i32).into());
+                }
+            }
+        }
+    }
+
+    pub fn color_transform(
+        &mut self,
+        min_x: u32,
+        min_y: u32,
+        end_x: u32,
+        end_y: u32,
+        color_transform: &ColorTransform,
+    ) {
+        for x in min_x..end_x.min(self.width()) {
+            for y in min_y..end_y.min(self.height()) {
+                let color = self
+                    .get_pixel_raw(x, y)
+                    .unwrap_or_else(|| 0.into())
+                    .to_un_multiplied_alpha();
+
+                let alpha = ((color.alpha() as f32 * color_transform.a_mult)
+                    + color_transform.a_add * 255.0) as u8;
+                let red = ((color.red() as f32 * color_transform.r_mult)
+                    + color_transform.r_add * 255.0) as u8;
+                let green = ((color.green() as f32 * color_transform.g_mult)
+                    + color_transform.g_add * 255.0) as u8;
+                let blue = ((color.blue() as f32 * color_transform.b_mult)
+                    + color_transform.b_add * 255.0) as u8;
+
+                self.set_pixel32_raw(
+                    x,
+                    y,
+                    Color::argb(alpha, red, green, blue)
+                        .to_premultiplied_alpha(self.transparency()),
+                )
+            }
+        }
+    }
+
+    pub fn color_bounds_rect(
+        &self,
+        find_color: bool,
+        mask: i32,
+        color: i32,
+    ) -> (u32, u32, u32, u32) {
+        let mut min_x = Option::<i32>::None;
+        let mut max_x = Option::<i32>::None;
+        let mut min_y = Option::<i32>::None;
+        let mut max_y = Option::<i32>::None;
+
+        for x in 0..self.width() {
+            for y in 0..self.height() {
+                let pixel_raw: i32 = self.get_pixel_raw(x, y).unwrap_or_else(|| 0.into()).into();
+                let color_matches = if find_color {
+                    (pixel_raw & mask) == color
+                } else {
+                    (pixel_raw & mask) != color
+                };
+
+                if color_matches {
+                    if (x as i32) < min_x.unwrap_or(self.width() as i32) {
+                        min_x = Some(x as i32)
+                    }
+                    if (x as i32) > max_x.unwrap_or(-1) {
+                        max_x = Some(x as i32 + 1)
+                    }
+
+                    if (y as i32) < min_y.unwrap_or(self.height() as i32) {
+                        min_y = Some(y as i32)
+                    }
+                    if (y as i32) > max_y.unwrap_or(-1) {
+                        max_y = Some(y as i32 + 1)
+                    }
+                }
+            }
+        }
+
+        let min_x = min_x.unwrap_or(0);
+        let min_y = min_y.unwrap_or(0);
+        let max_x = max_x.unwrap_or(0);
+        let max_y = max_y.unwrap_or(0);
+
+        let x = min_x as u32;
+        let y = min_y as u32;
+        let w = (max_x - min_x) as u32;
+        let h = (max_y - min_y) as u32;
+
+        (x, y, w, h)
+    }
+
+    pub fn copy_pixels(
+        &mut self,
+        source_bitmap: &Self,
+        src_rect: (i32, i32, i32, i32),
+        dest_point: (i32, i32),
+        alpha_source: Option<(&Self, (i32, i32), bool)>,
+    ) {
+        let (src_min_x, src_min_y, src_width, src_height) = src_rect;
+        let (dest_min_x, dest_min_y) = dest_point;
+
+        for src_y in src_min_y..(src_min_y + src_height) {
+            for src_x in src_min_x..(src_min_x + src_width) {
+                let dest_x = src_x - src_min_x + dest_min_x;
+                let dest_y = src_y - src_min_y + dest_min_y;
+
+                if !source_bitmap.is_point_in_bounds(src_x, src_y)
+                    || !self.is_point_in_bounds(dest_x, dest_y)
+                {
+                    continue;
+                }
+
+                let source_color = source_bitmap
+                    .get_pixel_raw(src_x as u32, src_y as u32)
+                    .unwrap();
+
+                let mut dest_color = self.get_pixel_raw(dest_x as u32, dest_y as u32).unwrap();
+
+                if let Some((alpha_bitmap, (alpha_min_x, alpha_min_y), merge_alpha)) = alpha_source
+                {
+                    let alpha_x = src_x - src_min_x + alpha_min_x;
+                    let alpha_y = src_y - src_min_y + alpha_min_y;
+
+                    if alpha_bitmap.transparency
+                        && !alpha_bitmap.is_point_in_bounds(alpha_x, alpha_y)
+                    {
+                        continue;
+                    }
+
+                    let final_alpha = if alpha_bitmap.transparency {
+                        let a = alpha_bitmap
+                            .get_pixel_raw(alpha_x as u32, alpha_y as u32)
+                            .unwrap()
+                            .alpha();
+
+                        if source_bitmap.transparency {
+                            ((a as u16 * source_color.alpha() as u16) >> 8) as u8
+                        } else {
+                            a
+                        }
+                    } else if source_bitmap.transparency {
+                        source_color.alpha()
+                    } else {
+                        255
+                    };
+
+                    // there could be a faster or more accurate way to do this,
+                    // (without converting to floats and back, twice),
+                    // but for now this should suffice
+                    let intermediate_color = source_color
+                        .to_un_multiplied_alpha()
+                        .with_alpha(final_alpha)
+                        .to_premultiplied_alpha(true);
+
+                    // there are some interesting conditions in the following
+                    // lines, these are a result of comparing the output in
+                    // many parameter combinations with that of Adobe's player,
+                    // and finding patterns in the differences.
+                    dest_color = if merge_alpha || !self.transparency {
+                        dest_color.blend_over(&intermediate_color)
+                    } else {
+                        intermediate_color
+                    };
+                } else {
+                    dest_color = if source_bitmap.transparency && !self.transparency {
+                        dest_color.blend_over(&source_color)
+                    } else {
+                        source_color
+                    };
+                }
+
+                self.set_pixel32_raw(dest_x as u32, dest_y as u32, dest_color);
+            }
+        }
+    }
+
+    pub fn scroll(&mut self, x: i32, y: i32) {
+        let width = self.width() as i32;
+        let height = self.height() as i32;
+
+        if (x == 0 && y == 0) || x.abs() >= width || y.abs() >= height {
+            return; // no-op
+        }
+
+        // since this is an "in-place copy", we have to iterate from bottom to top
+        // when scrolling downwards - so if y is positive
+        let reverse_y = y > 0;
+        // and if only scrolling horizontally, we have to iterate from right to left
+        // when scrolling right - so if x is positive
+        let reverse_x = y == 0 && x > 0;
+
+        // iteration ranges to use as source for the copy, from is inclusive, to is exclusive
+        let y_from = if reverse_y { height - y - 1 } else { -y };
+        let y_to = if reverse_y { -1 } else { height };
+        let dy = if reverse_y { -1 } else { 1 };
+
+        let x_from = if reverse_x {
+            // we know x > 0
+            width - x - 1
+        } else {
+            // x can be any sign
+            (-x).max(0)
+        };
+        let x_to = if reverse_x { -1 } else { width.min(width - x) };
+        let dx = if reverse_x { -1 } else { 1 };
+
+        let mut src_y = y_from;
+        while src_y != y_to {
+            let mut src_x = x_from;
+            while src_x != x_to {
+                let color = self.get_pixel_raw(src_x as u32, src_y as u32).unwrap();
+                self.set_pixel32_raw((src_x + x) as u32, (src_y + y) as u32, color);
+                src_x += dx;
+            }
+            src_y += dy;
+        }
+    }
+
+    /// Converts the pixels into a bitmap that can be handed to the renderer.
+    pub fn to_bitmap(&self) -> Bitmap {
+        Bitmap {
+            width: self.width(),
+            height: self.height(),
+            data: BitmapFormat::Rgba(self.pixels_rgba()),
+        }
+    }
+
+    /// Draws another bitmap onto this one, as done by `BitmapData.draw`.
+    pub fn draw_bitmap_data(
+        &mut self,
+        source: &Self,
+        transform: &Transform,
+        blend_mode: BlendMode,
+        clip_rect: Option<(i32, i32, i32, i32)>,
+        smoothing: bool,
+    ) {
+        let mut target = OffscreenTarget::new(self.width(), self.height());
+        target.render_bitmap(&source.to_bitmap(), transform, smoothing);
+        self.composite(&target.into_bitmap(), blend_mode, clip_rect);
+    }
+
+    /// Composites a layer the size of this bitmap over it, restricted to
+    /// `clip_rect` if given. The layer must have premultiplied alpha.
+    pub fn composite(
+        &mut self,
+        layer: &Bitmap,
+        blend_mode: BlendMode,
+        clip_rect: Option<(i32, i32, i32, i32)>,
+    ) {
+        if layer.width != self.width() || layer.height != self.height() {
+            log::warn!("BitmapData.draw: Layer size doesn't match the bitmap");
+            return;
+        }
+
+        let (min_x, min_y, max_x, max_y) = match clip_rect {
+            Some((x, y, width, height)) => (
+                x.max(0) as u32,
+                y.max(0) as u32,
+                (x + width).max(0) as u32,
+                (y + height).max(0) as u32,
+            ),
+            None => (0, 0, self.width(), self.height()),
+        };
+
+        for y in min_y..max_y.min(self.height()) {
+            for x in min_x..max_x.min(self.width()) {
+                let i = (x + y * self.width()) as usize;
+                let src = match &layer.data {
+                    BitmapFormat::Rgba(data) => {
+                        let p = &data[i * 4..i * 4 + 4];
+                        Color::argb(p[3], p[0], p[1], p[2])
+                    }
+                    BitmapFormat::Rgb(data) => {
+                        let p = &data[i * 3..i * 3 + 3];
+                        Color::argb(255, p[0], p[1], p[2])
+                    }
+                };
+                if src.alpha() == 0 && blend_mode != BlendMode::Alpha {
+                    continue;
+                }
+
+                let mut color = self.pixels[i].blend(&src, blend_mode);
+                if !self.transparency {
+                    color = color.to_un_multiplied_alpha().with_alpha(255);
+                }
+                self.set_pixel32_raw(x, y, color);
+            }
+        }
+    }
+}
@@ -0,0 +1,904 @@
+//! Software implementation of the bitmap filters.
+//!
+//! Filters are applied to a premultiplied RGBA copy of the source area,
+//! padded to the area the filter can draw into, and the result is then
+//! written back into the destination `BitmapData`.
+
+use crate::bitmap::bitmap_data::{BitmapData, Color};
+
+/// The largest blur Flash applies in either direction.
+const MAX_BLUR: f64 = 255.0;
+
+/// The largest number of blur passes Flash applies.
+const MAX_QUALITY: i32 = 15;
+
+#[derive(Clone, Debug)]
+pub struct BlurFilter {
+    pub blur_x: f64,
+    pub blur_y: f64,
+    pub quality: i32,
+}
+
+#[derive(Clone, Debug)]
+pub struct ColorMatrixFilter {
+    pub matrix: [f64; 4 * 5],
+}
+
+#[derive(Clone, Debug)]
+pub struct ConvolutionFilter {
+    pub matrix_x: u8,
+    pub matrix_y: u8,
+    pub matrix: Vec<f64>,
+    pub divisor: f64,
+    pub bias: f64,
+    pub preserve_alpha: bool,
+    pub clamp: bool,
+    pub color: u32,
+    pub alpha: f64,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DisplacementMapMode {
+    Wrap,
+    Clamp,
+    Ignore,
+    Color,
+}
+
+#[derive(Clone, Debug)]
+pub struct DisplacementMapFilter {
+    pub map_bitmap: Option<BitmapData>,
+    pub map_point: (i32, i32),
+    /// The channel of the map that displaces in each direction, as a
+    /// `BitmapDataChannel` value.
+    pub component_x: i32,
+    pub component_y: i32,
+    pub scale_x: f64,
+    pub scale_y: f64,
+    pub mode: DisplacementMapMode,
+    pub color: u32,
+    pub alpha: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct DropShadowFilter {
+    pub distance: f64,
+    pub angle: f64,
+    pub color: u32,
+    pub alpha: f64,
+    pub blur_x: f64,
+    pub blur_y: f64,
+    pub strength: f64,
+    pub quality: i32,
+    pub inner: bool,
+    pub knockout: bool,
+    pub hide_object: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct GlowFilter {
+    pub color: u32,
+    pub alpha: f64,
+    pub blur_x: f64,
+    pub blur_y: f64,
+    pub strength: f64,
+    pub quality: i32,
+    pub inner: bool,
+    pub knockout: bool,
+}
+
+/// Which side of the object's edges a bevel or gradient filter draws on.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BevelType {
+    Inner,
+    Outer,
+    Full,
+}
+
+#[derive(Clone, Debug)]
+pub struct BevelFilter {
+    pub distance: f64,
+    pub angle: f64,
+    pub highlight_color: u32,
+    pub highlight_alpha: f64,
+    pub shadow_color: u32,
+    pub shadow_alpha: f64,
+    pub blur_x: f64,
+    pub blur_y: f64,
+    pub strength: f64,
+    pub quality: i32,
+    pub type_: BevelType,
+    pub knockout: bool,
+}
+
+/// The parameters shared by `GradientGlowFilter` and `GradientBevelFilter`.
+#[derive(Clone, Debug)]
+pub struct GradientFilter {
+    pub distance: f64,
+    pub angle: f64,
+    pub colors: Vec<u32>,
+    pub alphas: Vec<f64>,
+    pub ratios: Vec<u8>,
+    pub blur_x: f64,
+    pub blur_y: f64,
+    pub strength: f64,
+    pub quality: i32,
+    pub type_: BevelType,
+    pub knockout: bool,
+}
+
+#[derive(Clone, Debug)]
+pub enum BitmapFilter {
+    Bevel(BevelFilter),
+    Blur(BlurFilter),
+    ColorMatrix(ColorMatrixFilter),
+    Convolution(ConvolutionFilter),
+    DisplacementMap(DisplacementMapFilter),
+    DropShadow(DropShadowFilter),
+    Glow(GlowFilter),
+    GradientBevel(GradientFilter),
+    GradientGlow(GradientFilter),
+}
+
+impl BitmapFilter {
+    /// Computes the area this filter draws into when applied to `rect`, as
+    /// returned by `BitmapData.generateFilterRect`.
+    pub fn filter_rect(&self, rect: (i32, i32, i32, i32)) -> (i32, i32, i32, i32) {
+        match self {
+            BitmapFilter::Blur(filter) => expand_rect(
+                rect,
+                (filter.blur_x, filter.blur_y),
+                filter.quality,
+                &[(0.0, 0.0)],
+            ),
+            BitmapFilter::DropShadow(filter) if !filter.inner => expand_rect(
+                rect,
+                (filter.blur_x, filter.blur_y),
+                filter.quality,
+                &[offset(filter.distance, filter.angle)],
+            ),
+            BitmapFilter::Glow(filter) if !filter.inner => expand_rect(
+                rect,
+                (filter.blur_x, filter.blur_y),
+                filter.quality,
+                &[(0.0, 0.0)],
+            ),
+            BitmapFilter::Bevel(BevelFilter {
+                distance,
+                angle,
+                blur_x,
+                blur_y,
+                quality,
+                type_,
+                ..
+            })
+            | BitmapFilter::GradientBevel(GradientFilter {
+                distance,
+                angle,
+                blur_x,
+                blur_y,
+                quality,
+                type_,
+                ..
+            }) if *type_ != BevelType::Inner => {
+                let (offset_x, offset_y) = offset(*distance, *angle);
+                expand_rect(
+                    rect,
+                    (*blur_x, *blur_y),
+                    *quality,
+                    &[(offset_x, offset_y), (-offset_x, -offset_y)],
+                )
+            }
+            BitmapFilter::GradientGlow(filter) if filter.type_ != BevelType::Inner => expand_rect(
+                rect,
+                (filter.blur_x, filter.blur_y),
+                filter.quality,
+                &[offset(filter.distance, filter.angle)],
+            ),
+            // Everything else only ever draws within the source.
+            _ => rect,
+        }
+    }
+
+    /// Applies this filter to `src_rect` of `source`, writing the result
+    /// into `dest` with the source rectangle's corner at `dest_point`.
+    ///
+    /// Everything within the filter rect is replaced in the destination.
+    pub fn apply(
+        &self,
+        source: &BitmapData,
+        src_rect: (i32, i32, i32, i32),
+        dest: &mut BitmapData,
+        dest_point: (i32, i32),
+    ) {
+        // Only the part of the source rect within the source has any pixels.
+        let (src_x, src_y, src_width, src_height) = src_rect;
+        let min_x = src_x.max(0);
+        let min_y = src_y.max(0);
+        let max_x = src_x.saturating_add(src_width).min(source.width() as i32);
+        let max_y = src_y.saturating_add(src_height).min(source.height() as i32);
+        if max_x <= min_x || max_y <= min_y {
+            return;
+        }
+        let dest_point = (dest_point.0 + min_x - src_x, dest_point.1 + min_y - src_y);
+        let (src_x, src_y, src_width, src_height) = (min_x, min_y, max_x - min_x, max_y - min_y);
+
+        let (area_x, area_y, area_width, area_height) =
+            self.filter_rect((src_x, src_y, src_width, src_height));
+        if area_width <= 0 || area_height <= 0 {
+            return;
+        }
+
+        // Copy the source area into the middle of the padded working image.
+        let mut image = Image::new(area_width as usize, area_height as usize);
+        for (i, pixel) in image.pixels.iter_mut().enumerate() {
+            let x = area_x + (i % area_width as usize) as i32;
+            let y = area_y + (i / area_width as usize) as i32;
+            if x >= src_x && x < src_x + src_width && y >= src_y && y < src_y + src_height {
+                let color = source.get_pixel_raw(x as u32, y as u32).unwrap();
+                *pixel = [
+                    f32::from(color.red()),
+                    f32::from(color.green()),
+                    f32::from(color.blue()),
+                    f32::from(color.alpha()),
+                ];
+            }
+        }
+
+        let image = match self {
+            BitmapFilter::Bevel(filter) => apply_bevel(&image, filter),
+            BitmapFilter::Blur(filter) => apply_blur(&image, filter),
+            BitmapFilter::ColorMatrix(filter) => apply_color_matrix(&image, filter),
+            BitmapFilter::Convolution(filter) => apply_convolution(&image, filter),
+            BitmapFilter::DisplacementMap(filter) => {
+                apply_displacement_map(&image, (area_x, area_y), filter)
+            }
+            BitmapFilter::DropShadow(filter) => apply_drop_shadow(&image, filter),
+            BitmapFilter::Glow(filter) => apply_glow(&image, filter),
+            BitmapFilter::GradientBevel(filter) => apply_gradient_bevel(&image, filter),
+            BitmapFilter::GradientGlow(filter) => apply_gradient_glow(&image, filter),
+        };
+
+        let dest_x = area_x - src_x + dest_point.0;
+        let dest_y = area_y - src_y + dest_point.1;
+        let transparency = dest.transparency();
+        for (i, pixel) in image.pixels.iter().enumerate() {
+            let x = dest_x + (i % image.width) as i32;
+            let y = dest_y + (i / image.width) as i32;
+            if !dest.is_point_in_bounds(x, y) {
+                continue;
+            }
+
+            let channel = |value: f32| value.round().max(0.0).min(255.0) as u8;
+            let color = Color::argb(
+                channel(pixel[3]),
+                channel(pixel[0]),
+                channel(pixel[1]),
+                channel(pixel[2]),
+            );
+            let color = if transparency {
+                color
+            } else {
+                color.to_un_multiplied_alpha().with_alpha(255)
+            };
+            dest.set_pixel32_raw(x as u32, y as u32, color);
+        }
+    }
+}
+
+/// Converts a filter's `distance` and `angle` (in degrees) into an offset.
+fn offset(distance: f64, angle: f64) -> (f64, f64) {
+    let angle = angle.to_radians();
+    (distance * angle.cos(), distance * angle.sin())
+}
+
+/// Pads `rect` on every side by the blur, once per pass of `quality`, then
+/// moves the padded rectangle by each of `offsets`. The result covers both
+/// the original rectangle and every moved copy.
+fn expand_rect(
+    rect: (i32, i32, i32, i32),
+    blur: (f64, f64),
+    quality: i32,
+    offsets: &[(f64, f64)],
+) -> (i32, i32, i32, i32) {
+    let (x, y, width, height) = rect;
+    let quality = f64::from(quality.max(0).min(MAX_QUALITY));
+    let pad_x = (blur.0.max(0.0).min(MAX_BLUR) / 2.0).ceil() * quality;
+    let pad_y = (blur.1.max(0.0).min(MAX_BLUR) / 2.0).ceil() * quality;
+
+    let mut min_x = f64::from(x);
+    let mut min_y = f64::from(y);
+    let mut max_x = f64::from(x) + f64::from(width);
+    let mut max_y = f64::from(y) + f64::from(height);
+    for &(offset_x, offset_y) in offsets {
+        min_x = min_x.min(f64::from(x) - pad_x + offset_x);
+        min_y = min_y.min(f64::from(y) - pad_y + offset_y);
+        max_x = max_x.max(f64::from(x) + f64::from(width) + pad_x + offset_x);
+        max_y = max_y.max(f64::from(y) + f64::from(height) + pad_y + offset_y);
+    }
+
+    let min_x = min_x.floor() as i32;
+    let min_y = min_y.floor() as i32;
+    (
+        min_x,
+        min_y,
+        max_x.ceil() as i32 - min_x,
+        max_y.ceil() as i32 - min_y,
+    )
+}
+
+/// A premultiplied RGBA image, with each channel in the range `0..=255`.
+#[derive(Clone)]
+struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<[f32; 4]>,
+}
+
+impl Image {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![[0.0; 4]; width * height],
+        }
+    }
+
+    /// Returns the pixel at the given position, or transparent black if it
+    /// lies outside of the image.
+    fn get(&self, x: isize, y: isize) -> [f32; 4] {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            [0.0; 4]
+        } else {
+            self.pixels[y as usize * self.width + x as usize]
+        }
+    }
+
+    fn channel(&self, channel: usize) -> Plane {
+        Plane {
+            width: self.width,
+            height: self.height,
+            values: self.pixels.iter().map(|pixel| pixel[channel]).collect(),
+        }
+    }
+
+    fn alpha(&self) -> Plane {
+        self.channel(3)
+    }
+
+    /// Builds an image from the mask of each pixel, colored by `shade`.
+    fn from_plane<F>(plane: &Plane, mut shade: F) -> Self
+    where
+        F: FnMut(usize, f32) -> [f32; 4],
+    {
+        Self {
+            width: plane.width,
+            height: plane.height,
+            pixels: plane
+                .values
+                .iter()
+                .enumerate()
+                .map(|(i, &value)| shade(i, value))
+                .collect(),
+        }
+    }
+}
+
+/// A single channel of an image, such as its alpha.
+#[derive(Clone)]
+struct Plane {
+    width: usize,
+    height: usize,
+    values: Vec<f32>,
+}
+
+impl Plane {
+    /// Returns the value at the given position, or 0 if it lies outside of
+    /// the plane.
+    fn get(&self, x: isize, y: isize) -> f32 {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            0.0
+        } else {
+            self.values[y as usize * self.width + x as usize]
+        }
+    }
+
+    /// Returns a copy of this plane moved by the given offset.
+    fn shifted(&self, (offset_x, offset_y): (f64, f64)) -> Self {
+        let offset_x = offset_x.round() as isize;
+        let offset_y = offset_y.round() as isize;
+        let mut shifted = self.clone();
+        for (i, value) in shifted.values.iter_mut().enumerate() {
+            let x = (i % self.width) as isize;
+            let y = (i / self.width) as isize;
+            *value = self.get(x - offset_x, y - offset_y);
+        }
+        shifted
+    }
+
+    fn invert(&mut self) {
+        for value in &mut self.values {
+            *value = 255.0 - *value;
+        }
+    }
+
+    fn scale(&mut self, strength: f64) {
+        let strength = strength.max(0.0) as f32;
+        for value in &mut self.values {
+            *value = (*value * strength).min(255.0);
+        }
+    }
+
+    /// Box blurs the plane horizontally then vertically, `quality` times.
+    fn blur(&mut self, blur_x: f64, blur_y: f64, quality: i32) {
+        let size_x = blur_x.max(1.0).min(MAX_BLUR).round() as usize;
+        let size_y = blur_y.max(1.0).min(MAX_BLUR).round() as usize;
+        let mut line = Vec::new();
+        let mut out = Vec::new();
+
+        for _ in 0..quality.max(0).min(MAX_QUALITY) {
+            if size_x > 1 {
+                for row in self.values.chunks_mut(self.width) {
+                    line.clear();
+                    line.extend_from_slice(row);
+                    box_blur_line(&line, size_x, &mut out);
+                    row.copy_from_slice(&out);
+                }
+            }
+
+            if size_y > 1 {
+                for x in 0..self.width {
+                    line.clear();
+                    line.extend(self.values.iter().skip(x).step_by(self.width));
+                    box_blur_line(&line, size_y, &mut out);
+                    for (value, &blurred) in
+                        self.values.iter_mut().skip(x).step_by(self.width).zip(&out)
+                    {
+                        *value = blurred;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Averages every value of `line` with its neighbours in a window of `size`
+/// values, treating anything past either end as 0.
+fn box_blur_line(line: &[f32], size: usize, out: &mut Vec<f32>) {
+    let before = (size - 1) / 2;
+    let scale = 1.0 / size as f32;
+
+    out.clear();
+    let mut sum: f32 = line.iter().take(size - before).sum();
+    for x in 0..line.len() {
+        out.push(sum * scale);
+        if let Some(value) = line.get(x + size - before) {
+            sum += value;
+        }
+        if x >= before {
+            sum -= line[x - before];
+        }
+    }
+}
+
+/// Returns `top` composited over `bottom`.
+fn over(top: [f32; 4], bottom: [f32; 4]) -> [f32; 4] {
+    let inverse = 1.0 - top[3] / 255.0;
+    [
+        top[0] + bottom[0] * inverse,
+        top[1] + bottom[1] * inverse,
+        top[2] + bottom[2] * inverse,
+        top[3] + bottom[3] * inverse,
+    ]
+}
+
+fn scaled(color: [f32; 4], factor: f32) -> [f32; 4] {
+    [
+        color[0] * factor,
+        color[1] * factor,
+        color[2] * factor,
+        color[3] * factor,
+    ]
+}
+
+/// Converts an RGB color and an alpha in `0.0..=1.0` into a premultiplied
+/// color.
+fn premultiplied(color: u32, alpha: f64) -> [f32; 4] {
+    let alpha = alpha.max(0.0).min(1.0) as f32;
+    [
+        ((color >> 16) & 0xFF) as f32 * alpha,
+        ((color >> 8) & 0xFF) as f32 * alpha,
+        (color & 0xFF) as f32 * alpha,
+        255.0 * alpha,
+    ]
+}
+
+fn unmultiply(color: [f32; 4]) -> [f32; 4] {
+    if color[3] <= 0.0 {
+        [0.0; 4]
+    } else {
+        let scale = 255.0 / color[3];
+        [
+            color[0] * scale,
+            color[1] * scale,
+            color[2] * scale,
+            color[3],
+        ]
+    }
+}
+
+fn premultiply(color: [f32; 4]) -> [f32; 4] {
+    let alpha = color[3].max(0.0).min(255.0);
+    let scale = alpha / 255.0;
+    [
+        color[0].max(0.0).min(255.0) * scale,
+        color[1].max(0.0).min(255.0) * scale,
+        color[2].max(0.0).min(255.0) * scale,
+        alpha,
+    ]
+}
+
+/// Composites the effect `layer` of a bevel or gradient filter with the
+/// source image, masking it to the side of the edges given by `type_`.
+fn composite_layer(image: &Image, layer: &mut Image, type_: BevelType, knockout: bool) {
+    for (layer, source) in layer.pixels.iter_mut().zip(&image.pixels) {
+        let source_alpha = source[3] / 255.0;
+        *layer = match type_ {
+            BevelType::Inner => scaled(*layer, source_alpha),
+            BevelType::Outer => scaled(*layer, 1.0 - source_alpha),
+            BevelType::Full => *layer,
+        };
+        if !knockout {
+            *layer = match type_ {
+                BevelType::Outer => over(*source, *layer),
+                BevelType::Inner | BevelType::Full => over(*layer, *source),
+            };
+        }
+    }
+}
+
+fn apply_blur(image: &Image, filter: &BlurFilter) -> Image {
+    let mut result = image.clone();
+    for channel in 0..4 {
+        let mut plane = image.channel(channel);
+        plane.blur(filter.blur_x, filter.blur_y, filter.quality);
+        for (pixel, value) in result.pixels.iter_mut().zip(plane.values) {
+            pixel[channel] = value;
+        }
+    }
+    result
+}
+
+fn apply_color_matrix(image: &Image, filter: &ColorMatrixFilter) -> Image {
+    let mut m = [0.0; 4 * 5];
+    for (m, &value) in m.iter_mut().zip(filter.matrix.iter()) {
+        *m = value as f32;
+    }
+    let mut result = image.clone();
+    for pixel in &mut result.pixels {
+        let [r, g, b, a] = unmultiply(*pixel);
+        *pixel = premultiply([
+            m[0] * r + m[1] * g + m[2] * b + m[3] * a + m[4],
+            m[5] * r + m[6] * g + m[7] * b + m[8] * a + m[9],
+            m[10] * r + m[11] * g + m[12] * b + m[13] * a + m[14],
+            m[15] * r + m[16] * g + m[17] * b + m[18] * a + m[19],
+        ]);
+    }
+    result
+}
+
+fn apply_convolution(image: &Image, filter: &ConvolutionFilter) -> Image {
+    let matrix_x = usize::from(filter.matrix_x);
+    let matrix_y = usize::from(filter.matrix_y);
+    let divisor = if filter.divisor != 0.0 {
+        filter.divisor as f32
+    } else {
+        1.0
+    };
+    let bias = filter.bias as f32;
+    let edge_color = unmultiply(premultiplied(filter.color, filter.alpha));
+
+    let sample = |x: isize, y: isize| {
+        if filter.clamp {
+            let x = x.max(0).min(image.width as isize - 1);
+            let y = y.max(0).min(image.height as isize - 1);
+            unmultiply(image.get(x, y))
+        } else if x < 0 || y < 0 || x as usize >= image.width || y as usize >= image.height {
+            edge_color
+        } else {
+            unmultiply(image.get(x, y))
+        }
+    };
+
+    let mut result = image.clone();
+    for (i, pixel) in result.pixels.iter_mut().enumerate() {
+        let x = (i % image.width) as isize;
+        let y = (i / image.width) as isize;
+
+        let mut sum = [0.0; 4];
+        for (k, &weight) in filter.matrix.iter().take(matrix_x * matrix_y).enumerate() {
+            let sample_x = x + (k % matrix_x) as isize - (matrix_x / 2) as isize;
+            let sample_y = y + (k / matrix_x) as isize - (matrix_y / 2) as isize;
+            let color = sample(sample_x, sample_y);
+            for (sum, value) in sum.iter_mut().zip(&color) {
+                *sum += weight as f32 * value;
+            }
+        }
+
+        let mut color = [
+            sum[0] / divisor + bias,
+            sum[1] / divisor + bias,
+            sum[2] / divisor + bias,
+            sum[3] / divisor + bias,
+        ];
+        if filter.preserve_alpha {
+            color[3] = pixel[3];
+        }
+        *pixel = premultiply(color);
+    }
+    result
+}
+
+fn apply_displacement_map(
+    image: &Image,
+    origin: (i32, i32),
+    filter: &DisplacementMapFilter,
+) -> Image {
+    let map = match &filter.map_bitmap {
+        Some(map) => map,
+        None => return image.clone(),
+    };
+
+    let component = |color: Color, channel: i32| match channel {
+        1 => f64::from(color.red()),
+        2 => f64::from(color.green()),
+        4 => f64::from(color.blue()),
+        8 => f64::from(color.alpha()),
+        _ => 128.0,
+    };
+    let width = image.width as isize;
+    let height = image.height as isize;
+    let edge_color = premultiplied(filter.color, filter.alpha);
+
+    let mut result = image.clone();
+    for (i, pixel) in result.pixels.iter_mut().enumerate() {
+        let x = (i % image.width) as isize;
+        let y = (i / image.width) as isize;
+
+        // Pixels outside of the map aren't displaced.
+        let map_x = origin.0 + x as i32 - filter.map_point.0;
+        let map_y = origin.1 + y as i32 - filter.map_point.1;
+        if !map.is_point_in_bounds(map_x, map_y) {
+            continue;
+        }
+
+        let map_color = map
+            .get_pixel_raw(map_x as u32, map_y as u32)
+            .unwrap()
+            .to_un_multiplied_alpha();
+        let dx = (component(map_color, filter.component_x) - 128.0) * filter.scale_x / 256.0;
+        let dy = (component(map_color, filter.component_y) - 128.0) * filter.scale_y / 256.0;
+        let source_x = x + dx.floor() as isize;
+        let source_y = y + dy.floor() as isize;
+
+        let in_bounds = source_x >= 0 && source_x < width && source_y >= 0 && source_y < height;
+        *pixel = if in_bounds {
+            image.get(source_x, source_y)
+        } else {
+            match filter.mode {
+                DisplacementMapMode::Wrap => {
+                    image.get(source_x.rem_euclid(width), source_y.rem_euclid(height))
+                }
+                DisplacementMapMode::Clamp => image.get(
+                    source_x.max(0).min(width - 1),
+                    source_y.max(0).min(height - 1),
+                ),
+                DisplacementMapMode::Ignore => image.get(x, y),
+                DisplacementMapMode::Color => edge_color,
+            }
+        };
+    }
+    result
+}
+
+/// Draws a blurred, colored copy of the source's alpha, moved by `offset`,
+/// either behind the source or, if `inner`, within it.
+#[allow(clippy::too_many_arguments)]
+fn apply_shadow(
+    image: &Image,
+    color: [f32; 4],
+    blur: (f64, f64),
+    strength: f64,
+    quality: i32,
+    offset: (f64, f64),
+    inner: bool,
+    knockout: bool,
+    hide_object: bool,
+) -> Image {
+    let mut mask = image.alpha().shifted(offset);
+    if inner {
+        mask.invert();
+    }
+    mask.blur(blur.0, blur.1, quality);
+    mask.scale(strength);
+
+    Image::from_plane(&mask, |i, value| {
+        let source = image.pixels[i];
+        let shadow = scaled(color, value / 255.0);
+        if inner {
+            let shadow = scaled(shadow, source[3] / 255.0);
+            if knockout || hide_object {
+                shadow
+            } else {
+                over(shadow, source)
+            }
+        } else if knockout {
+            scaled(shadow, 1.0 - source[3] / 255.0)
+        } else if hide_object {
+            shadow
+        } else {
+            over(source, shadow)
+        }
+    })
+}
+
+fn apply_drop_shadow(image: &Image, filter: &DropShadowFilter) -> Image {
+    apply_shadow(
+        image,
+        premultiplied(filter.color, filter.alpha),
+        (filter.blur_x, filter.blur_y),
+        filter.strength,
+        filter.quality,
+        offset(filter.distance, filter.angle),
+        filter.inner,
+        filter.knockout,
+        filter.hide_object,
+    )
+}
+
+fn apply_glow(image: &Image, filter: &GlowFilter) -> Image {
+    apply_shadow(
+        image,
+        premultiplied(filter.color, filter.alpha),
+        (filter.blur_x, filter.blur_y),
+        filter.strength,
+        filter.quality,
+        (0.0, 0.0),
+        filter.inner,
+        filter.knockout,
+        false,
+    )
+}
+
+/// Returns how much more and less opaque each pixel's blurred alpha is
+/// towards the light than away from it, as a highlight and a shadow plane.
+fn bevel_planes(
+    image: &Image,
+    blur: (f64, f64),
+    strength: f64,
+    quality: i32,
+    distance: f64,
+    angle: f64,
+) -> (Plane, Plane) {
+    let mut alpha = image.alpha();
+    alpha.blur(blur.0, blur.1, quality);
+
+    let (offset_x, offset_y) = offset(distance, angle);
+    let lit = alpha.shifted((-offset_x, -offset_y));
+    let shaded = alpha.shifted((offset_x, offset_y));
+
+    let mut highlight = alpha.clone();
+    let mut shadow = alpha;
+    for (i, (highlight_value, shadow_value)) in highlight
+        .values
+        .iter_mut()
+        .zip(shadow.values.iter_mut())
+        .enumerate()
+    {
+        let difference = lit.values[i] - shaded.values[i];
+        *highlight_value = difference.max(0.0);
+        *shadow_value = (-difference).max(0.0);
+    }
+    highlight.scale(strength);
+    shadow.scale(strength);
+    (highlight, shadow)
+}
+
+fn apply_bevel(image: &Image, filter: &BevelFilter) -> Image {
+    let (highlight, shadow) = bevel_planes(
+        image,
+        (filter.blur_x, filter.blur_y),
+        filter.strength,
+        filter.quality,
+        filter.distance,
+        filter.angle,
+    );
+    let highlight_color = premultiplied(filter.highlight_color, filter.highlight_alpha);
+    let shadow_color = premultiplied(filter.shadow_color, filter.shadow_alpha);
+
+    let mut layer = Image::from_plane(&highlight, |i, value| {
+        over(
+            scaled(highlight_color, value / 255.0),
+            scaled(shadow_color, shadow.values[i] / 255.0),
+        )
+    });
+    composite_layer(image, &mut layer, filter.type_, filter.knockout);
+    layer
+}
+
+/// Builds the 256 premultiplied colors of a gradient filter's ramp.
+fn gradient_ramp(filter: &GradientFilter) -> Vec<[f32; 4]> {
+    let stops: Vec<(f32, [f32; 4])> = filter
+        .ratios
+        .iter()
+        .zip(&filter.colors)
+        .zip(&filter.alphas)
+        .map(|((&ratio, &color), &alpha)| {
+            let mut color = premultiplied(color, 1.0);
+            color[3] = alpha.max(0.0).min(1.0) as f32 * 255.0;
+            (f32::from(ratio), color)
+        })
+        .collect();
+
+    (0..256)
+        .map(|i| {
+            let i = i as f32;
+            let color = match stops.iter().position(|&(ratio, _)| ratio >= i) {
+                None => stops.last().map(|&(_, color)| color),
+                Some(0) => stops.first().map(|&(_, color)| color),
+                Some(next) => {
+                    let (start_ratio, start) = stops[next - 1];
+                    let (end_ratio, end) = stops[next];
+                    let t = if end_ratio > start_ratio {
+                        (i - start_ratio) / (end_ratio - start_ratio)
+                    } else {
+                        0.0
+                    };
+                    let mut color = [0.0; 4];
+                    for (channel, (start, end)) in color.iter_mut().zip(start.iter().zip(&end)) {
+                        *channel = start + (end - start) * t;
+                    }
+                    Some(color)
+                }
+            };
+            color.map(premultiply).unwrap_or([0.0; 4])
+        })
+        .collect()
+}
+
+fn apply_gradient_bevel(image: &Image, filter: &GradientFilter) -> Image {
+    let (highlight, shadow) = bevel_planes(
+        image,
+        (filter.blur_x, filter.blur_y),
+        filter.strength,
+        filter.quality,
+        filter.distance,
+        filter.angle,
+    );
+    let ramp = gradient_ramp(filter);
+
+    // The highlight is drawn from the start of the ramp and the shadow from
+    // its end, with the middle of the ramp where there is neither.
+    let mut layer = Image::from_plane(&highlight, |i, value| {
+        let index = 128.0 - value / 2.0 + shadow.values[i] / 2.0;
+        ramp[index.round().max(0.0).min(255.0) as usize]
+    });
+    composite_layer(image, &mut layer, filter.type_, filter.knockout);
+    layer
+}
+
+fn apply_gradient_glow(image: &Image, filter: &GradientFilter) -> Image {
+    let mut mask = image.alpha().shifted(offset(filter.distance, filter.angle));
+    if filter.type_ == BevelType::Inner {
+        mask.invert();
+    }
+    mask.blur(filter.blur_x, filter.blur_y, filter.quality);
+    mask.scale(filter.strength);
+    let ramp = gradient_ramp(filter);
+
+    let mut layer = Image::from_plane(&mask, |_, value| {
+        ramp[value.round().max(0.0).min(255.0) as usize]
+    });
+    composite_layer(image, &mut layer, filter.type_, filter.knockout);
+    layer
+}
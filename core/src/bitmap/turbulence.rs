@@ -0,0 +1,239 @@
+//! Perlin noise generation for `BitmapData.perlinNoise`.
+//!
+//! This is a port of the reference `feTurbulence` implementation from the
+//! SVG 1.1 specification. Its output has not been checked against Flash
+//! Player's.
+
+const RAND_M: i32 = 2147483647; // 2**31 - 1
+const RAND_A: i32 = 16807; // 7**5; primitive root of m
+const RAND_Q: i32 = 127773; // m / a
+const RAND_R: i32 = 2836; // m % a
+
+const B_SIZE: usize = 0x100;
+const B_LEN: usize = B_SIZE + B_SIZE + 2;
+const BM: i64 = 0xff;
+const PERLIN_N: i64 = 0x1000;
+
+fn setup_seed(mut seed: i32) -> i32 {
+    if seed <= 0 {
+        seed = -(seed % (RAND_M - 1)) + 1;
+    }
+    if seed > RAND_M - 1 {
+        seed = RAND_M - 1;
+    }
+    seed
+}
+
+fn random(seed: i32) -> i32 {
+    let mut result = RAND_A * (seed % RAND_Q) - RAND_R * (seed / RAND_Q);
+    if result <= 0 {
+        result += RAND_M;
+    }
+    result
+}
+
+fn s_curve(t: f64) -> f64 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+/// The lattice dimensions used to make the noise tile seamlessly.
+#[derive(Copy, Clone, Debug)]
+struct StitchInfo {
+    width: i64,
+    height: i64,
+    wrap_x: i64,
+    wrap_y: i64,
+}
+
+/// A seeded noise generator.
+pub struct Turbulence {
+    lattice_selector: [usize; B_LEN],
+    gradient: [[[f64; 2]; B_LEN]; 4],
+}
+
+impl Turbulence {
+    pub fn from_seed(seed: i32) -> Self {
+        let mut lattice_selector = [0; B_LEN];
+        let mut gradient = [[[0.0; 2]; B_LEN]; 4];
+
+        let mut seed = setup_seed(seed);
+        for channel in gradient.iter_mut() {
+            for vector in channel.iter_mut().take(B_SIZE) {
+                for value in vector.iter_mut() {
+                    seed = random(seed);
+                    *value = ((seed % (B_SIZE as i32 * 2)) - B_SIZE as i32) as f64 / B_SIZE as f64;
+                }
+                let length = (vector[0] * vector[0] + vector[1] * vector[1]).sqrt();
+                if length != 0.0 {
+                    vector[0] /= length;
+                    vector[1] /= length;
+                }
+            }
+        }
+
+        for (i, selector) in lattice_selector.iter_mut().take(B_SIZE).enumerate() {
+            *selector = i;
+        }
+        for i in (1..B_SIZE).rev() {
+            seed = random(seed);
+            lattice_selector.swap(i, (seed % B_SIZE as i32) as usize);
+        }
+
+        lattice_selector.copy_within(0..B_SIZE + 2, B_SIZE);
+        for channel in gradient.iter_mut() {
+            channel.copy_within(0..B_SIZE + 2, B_SIZE);
+        }
+
+        Self {
+            lattice_selector,
+            gradient,
+        }
+    }
+
+    fn noise2(&self, color_channel: usize, vec: [f64; 2], stitch: Option<&StitchInfo>) -> f64 {
+        let t = vec[0] + PERLIN_N as f64;
+        let mut bx0 = (t as i64) & BM;
+        let mut bx1 = (bx0 + 1) & BM;
+        let rx0 = t - (t as i64) as f64;
+        let rx1 = rx0 - 1.0;
+
+        let t = vec[1] + PERLIN_N as f64;
+        let mut by0 = (t as i64) & BM;
+        let mut by1 = (by0 + 1) & BM;
+        let ry0 = t - (t as i64) as f64;
+        let ry1 = ry0 - 1.0;
+
+        // If stitching, adjust the lattice points accordingly.
+        if let Some(stitch) = stitch {
+            if bx0 >= stitch.wrap_x {
+                bx0 -= stitch.width;
+            }
+            if bx1 >= stitch.wrap_x {
+                bx1 -= stitch.width;
+            }
+            if by0 >= stitch.wrap_y {
+                by0 -= stitch.height;
+            }
+            if by1 >= stitch.wrap_y {
+                by1 -= stitch.height;
+            }
+        }
+
+        let bx0 = (bx0 & BM) as usize;
+        let bx1 = (bx1 & BM) as usize;
+        let by0 = (by0 & BM) as usize;
+        let by1 = (by1 & BM) as usize;
+
+        let i = self.lattice_selector[bx0];
+        let j = self.lattice_selector[bx1];
+        let b00 = self.lattice_selector[i + by0];
+        let b10 = self.lattice_selector[j + by0];
+        let b01 = self.lattice_selector[i + by1];
+        let b11 = self.lattice_selector[j + by1];
+
+        let gradient = &self.gradient[color_channel];
+        let sx = s_curve(rx0);
+        let sy = s_curve(ry0);
+
+        let q = gradient[b00];
+        let u = rx0 * q[0] + ry0 * q[1];
+        let q = gradient[b10];
+        let v = rx1 * q[0] + ry0 * q[1];
+        let a = lerp(sx, u, v);
+
+        let q = gradient[b01];
+        let u = rx0 * q[0] + ry1 * q[1];
+        let q = gradient[b11];
+        let v = rx1 * q[0] + ry1 * q[1];
+        let b = lerp(sx, u, v);
+
+        lerp(sy, a, b)
+    }
+
+    /// Sums `num_octaves` octaves of noise for the given channel at `point`.
+    ///
+    /// Each octave doubles the frequency and halves the amplitude of the
+    /// last, and is shifted by the corresponding entry of `offsets`, if any.
+    /// Fractal noise sums the signed noise, while turbulence sums its
+    /// absolute value. When `stitch` is set, the base frequency is adjusted
+    /// so that the noise tiles seamlessly across `tile`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn turbulence(
+        &self,
+        color_channel: usize,
+        point: (f64, f64),
+        base_frequency: (f64, f64),
+        num_octaves: usize,
+        fractal_sum: bool,
+        stitch: bool,
+        tile: (f64, f64, f64, f64),
+        offsets: &[(f64, f64)],
+    ) -> f64 {
+        let (mut base_freq_x, mut base_freq_y) = base_frequency;
+        let (tile_x, tile_y, tile_width, tile_height) = tile;
+
+        // Adjust the base frequencies if necessary for stitching.
+        let mut stitch = if stitch {
+            if base_freq_x != 0.0 {
+                let lo_freq = (tile_width * base_freq_x).floor() / tile_width;
+                let hi_freq = (tile_width * base_freq_x).ceil() / tile_width;
+                base_freq_x = if base_freq_x / lo_freq < hi_freq / base_freq_x {
+                    lo_freq
+                } else {
+                    hi_freq
+                };
+            }
+            if base_freq_y != 0.0 {
+                let lo_freq = (tile_height * base_freq_y).floor() / tile_height;
+                let hi_freq = (tile_height * base_freq_y).ceil() / tile_height;
+                base_freq_y = if base_freq_y / lo_freq < hi_freq / base_freq_y {
+                    lo_freq
+                } else {
+                    hi_freq
+                };
+            }
+
+            // Set up the initial stitch values.
+            let width = (tile_width * base_freq_x + 0.5) as i64;
+            let height = (tile_height * base_freq_y + 0.5) as i64;
+            Some(StitchInfo {
+                width,
+                height,
+                wrap_x: (tile_x * base_freq_x) as i64 + PERLIN_N + width,
+                wrap_y: (tile_y * base_freq_y) as i64 + PERLIN_N + height,
+            })
+        } else {
+            None
+        };
+
+        let mut sum = 0.0;
+        let mut ratio = 1.0;
+        for octave in 0..num_octaves {
+            let (offset_x, offset_y) = offsets.get(octave).copied().unwrap_or((0.0, 0.0));
+            let vec = [
+                (point.0 + offset_x) * base_freq_x * ratio,
+                (point.1 + offset_y) * base_freq_y * ratio,
+            ];
+
+            let noise = self.noise2(color_channel, vec, stitch.as_ref());
+            sum += if fractal_sum { noise } else { noise.abs() } / ratio;
+            ratio *= 2.0;
+
+            if let Some(stitch) = &mut stitch {
+                // Update stitch values. Subtracting PERLIN_N before the
+                // multiplication and adding it afterward simplifies to
+                // subtracting it once.
+                stitch.width *= 2;
+                stitch.wrap_x = 2 * stitch.wrap_x - PERLIN_N;
+                stitch.height *= 2;
+                stitch.wrap_y = 2 * stitch.wrap_y - PERLIN_N;
+            }
+        }
+
+        sum
+    }
+}
@@ -1,5 +1,8 @@
+use enumset::{EnumSet, EnumSetType};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 
 /// Controls whether the content is letterboxed or pillarboxed when the
 /// player's aspect ratio does not match the movie's aspect ratio.
@@ -28,3 +31,210 @@ impl Default for Letterbox {
         Letterbox::Fullscreen
     }
 }
+
+/// How the movie is scaled to fit the viewport, as with AS3's
+/// `Stage.scaleMode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "scaleMode"))]
+pub enum StageScaleMode {
+    /// The movie is stretched to fill the viewport, ignoring its aspect ratio.
+    #[cfg_attr(feature = "serde", serde(rename = "exactFit"))]
+    ExactFit,
+
+    /// The movie is scaled to cover the viewport while keeping its aspect
+    /// ratio, cropping it if necessary.
+    #[cfg_attr(feature = "serde", serde(rename = "noBorder"))]
+    NoBorder,
+
+    /// The movie is not scaled; the stage takes the size of the viewport.
+    #[cfg_attr(feature = "serde", serde(rename = "noScale"))]
+    NoScale,
+
+    /// The movie is scaled to fit inside the viewport while keeping its
+    /// aspect ratio.
+    #[cfg_attr(feature = "serde", serde(rename = "showAll"))]
+    ShowAll,
+}
+
+impl Default for StageScaleMode {
+    fn default() -> Self {
+        StageScaleMode::ShowAll
+    }
+}
+
+impl fmt::Display for StageScaleMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            StageScaleMode::ExactFit => "exactFit",
+            StageScaleMode::NoBorder => "noBorder",
+            StageScaleMode::NoScale => "noScale",
+            StageScaleMode::ShowAll => "showAll",
+        })
+    }
+}
+
+impl FromStr for StageScaleMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "exactfit" => Ok(StageScaleMode::ExactFit),
+            "noborder" => Ok(StageScaleMode::NoBorder),
+            "noscale" => Ok(StageScaleMode::NoScale),
+            "showall" => Ok(StageScaleMode::ShowAll),
+            _ => Err(()),
+        }
+    }
+}
+
+/// An edge of the viewport that the stage is aligned to, as with AS3's
+/// `Stage.align`.
+///
+/// The stage is centered along any axis that has no alignment.
+#[derive(Debug, EnumSetType)]
+pub enum StageAlign {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl StageAlign {
+    /// Parse an alignment string such as `"TL"`.
+    ///
+    /// Unknown characters are ignored, and top or left alignment take
+    /// precedence over bottom or right alignment respectively.
+    pub fn parse(s: &str) -> EnumSet<StageAlign> {
+        let s = s.to_ascii_uppercase();
+        let mut align = EnumSet::new();
+        if s.contains('T') {
+            align.insert(StageAlign::Top);
+        } else if s.contains('B') {
+            align.insert(StageAlign::Bottom);
+        }
+        if s.contains('L') {
+            align.insert(StageAlign::Left);
+        } else if s.contains('R') {
+            align.insert(StageAlign::Right);
+        }
+        align
+    }
+
+    /// Convert an alignment into its string representation, such as `"TL"`.
+    pub fn format(align: EnumSet<StageAlign>) -> String {
+        let mut s = String::with_capacity(2);
+        if align.contains(StageAlign::Top) {
+            s.push('T');
+        } else if align.contains(StageAlign::Bottom) {
+            s.push('B');
+        }
+        if align.contains(StageAlign::Left) {
+            s.push('L');
+        } else if align.contains(StageAlign::Right) {
+            s.push('R');
+        }
+        s
+    }
+}
+
+/// The rendering quality of the stage, as with AS3's `Stage.quality`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename = "quality"))]
+pub enum StageQuality {
+    #[cfg_attr(feature = "serde", serde(rename = "low"))]
+    Low,
+
+    #[cfg_attr(feature = "serde", serde(rename = "medium"))]
+    Medium,
+
+    #[cfg_attr(feature = "serde", serde(rename = "high"))]
+    High,
+
+    #[cfg_attr(feature = "serde", serde(rename = "best"))]
+    Best,
+
+    #[cfg_attr(feature = "serde", serde(rename = "8x8"))]
+    High8x8,
+
+    #[cfg_attr(feature = "serde", serde(rename = "8x8linear"))]
+    High8x8Linear,
+
+    #[cfg_attr(feature = "serde", serde(rename = "16x16"))]
+    High16x16,
+
+    #[cfg_attr(feature = "serde", serde(rename = "16x16linear"))]
+    High16x16Linear,
+}
+
+impl Default for StageQuality {
+    fn default() -> Self {
+        StageQuality::High
+    }
+}
+
+impl fmt::Display for StageQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            StageQuality::Low => "low",
+            StageQuality::Medium => "medium",
+            StageQuality::High => "high",
+            StageQuality::Best => "best",
+            StageQuality::High8x8 => "8x8",
+            StageQuality::High8x8Linear => "8x8linear",
+            StageQuality::High16x16 => "16x16",
+            StageQuality::High16x16Linear => "16x16linear",
+        })
+    }
+}
+
+impl FromStr for StageQuality {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "low" => Ok(StageQuality::Low),
+            "medium" => Ok(StageQuality::Medium),
+            "high" => Ok(StageQuality::High),
+            "best" => Ok(StageQuality::Best),
+            "8x8" => Ok(StageQuality::High8x8),
+            "8x8linear" => Ok(StageQuality::High8x8Linear),
+            "16x16" => Ok(StageQuality::High16x16),
+            "16x16linear" => Ok(StageQuality::High16x16Linear),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Whether the stage is displayed fullscreen, as with AS3's
+/// `Stage.displayState`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageDisplayState {
+    Normal,
+    FullScreen,
+    FullScreenInteractive,
+}
+
+impl fmt::Display for StageDisplayState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            StageDisplayState::Normal => "normal",
+            StageDisplayState::FullScreen => "fullScreen",
+            StageDisplayState::FullScreenInteractive => "fullScreenInteractive",
+        })
+    }
+}
+
+impl FromStr for StageDisplayState {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "normal" => Ok(StageDisplayState::Normal),
+            "fullscreen" => Ok(StageDisplayState::FullScreen),
+            "fullscreeninteractive" => Ok(StageDisplayState::FullScreenInteractive),
+            _ => Err(()),
+        }
+    }
+}
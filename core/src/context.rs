@@ -1,13 +1,15 @@
 //! Contexts and helper types passed between functions.
 
-use crate::avm1::globals::system::SystemProperties;
-use crate::avm1::{Avm1, Object as Avm1Object, Timers, Value as Avm1Value};
+use crate::audio_manager::AudioManager;
+use crate::avm1::{Avm1, Object as Avm1Object, Value as Avm1Value};
 use crate::avm2::{Avm2, Object as Avm2Object, Value as Avm2Value};
 use crate::backend::input::InputBackend;
 use crate::backend::locale::LocaleBackend;
 use crate::backend::log::LogBackend;
 use crate::backend::storage::StorageBackend;
+use crate::backend::ui::UiBackend;
 use crate::backend::{audio::AudioBackend, navigator::NavigatorBackend, render::RenderBackend};
+use crate::config::{StageAlign, StageQuality, StageScaleMode};
 use crate::display_object::EditText;
 use crate::external::ExternalInterface;
 use crate::focus_tracker::FocusTracker;
@@ -15,9 +17,12 @@ use crate::library::Library;
 use crate::loader::LoadManager;
 use crate::player::Player;
 use crate::prelude::*;
+use crate::system_properties::SystemProperties;
 use crate::tag_utils::{SwfMovie, SwfSlice};
+use crate::timer::Timers;
 use crate::transform::TransformStack;
 use core::fmt;
+use enumset::EnumSet;
 use gc_arena::{Collect, CollectionContext, MutationContext};
 use instant::Instant;
 use rand::rngs::SmallRng;
@@ -78,6 +83,9 @@ pub struct UpdateContext<'a, 'gc, 'gc_context> {
     /// The logging backend, used for trace output capturing
     pub log: &'a mut dyn LogBackend,
 
+    /// The user interface backend, used to control the player's window.
+    pub ui: &'a mut dyn UiBackend,
+
     /// The RNG, used by the AVM `RandomNumber` opcode,  `Math.random(),` and `random()`.
     pub rng: &'a mut SmallRng,
 
@@ -96,6 +104,32 @@ pub struct UpdateContext<'a, 'gc, 'gc_context> {
     /// The dimensions of the stage.
     pub stage_size: (Twips, Twips),
 
+    /// The dimensions of the viewport that the stage is displayed in, in
+    /// logical pixels.
+    ///
+    /// This excludes the display's scale factor, so HiDPI displays report
+    /// the same size as their standard counterparts.
+    pub viewport_size: (u32, u32),
+
+    /// How the stage is scaled to fit the viewport.
+    pub stage_scale_mode: &'a mut StageScaleMode,
+
+    /// Whether the stage scale mode was set by script, in which case the
+    /// `allowscale` FSCommand leaves it alone.
+    pub stage_scale_mode_set_by_script: &'a mut bool,
+
+    /// How the stage is aligned within the viewport.
+    pub stage_align: &'a mut EnumSet<StageAlign>,
+
+    /// The rendering quality of the stage.
+    pub stage_quality: &'a mut StageQuality,
+
+    /// Whether the player's full context menu is shown.
+    pub show_menu: &'a mut bool,
+
+    /// The frame rate of the movie, in frames per second.
+    pub frame_rate: &'a mut f64,
+
     /// Weak reference to the player.
     ///
     /// Recipients of an update context may upgrade the reference to ensure
@@ -123,6 +157,9 @@ pub struct UpdateContext<'a, 'gc, 'gc_context> {
     /// Timed callbacks created with `setInterval`/`setTimeout`.
     pub timers: &'a mut Timers<'gc>,
 
+    /// Sounds played by AS3 code.
+    pub audio_manager: &'a mut AudioManager<'gc>,
+
     /// The AVM1 global state.
     pub avm1: &'a mut Avm1<'gc>,
 
@@ -173,6 +210,7 @@ unsafe impl<'a, 'gc, 'gc_context> Collect for UpdateContext<'a, 'gc, 'gc_context
         self.shared_objects.trace(cc);
         self.unbound_text_fields.trace(cc);
         self.timers.trace(cc);
+        self.audio_manager.trace(cc);
         self.avm1.trace(cc);
         self.avm2.trace(cc);
         self.focus_tracker.trace(cc);
@@ -204,6 +242,7 @@ impl<'a, 'gc, 'gc_context> UpdateContext<'a, 'gc, 'gc_context> {
             renderer: self.renderer,
             locale: self.locale,
             log: self.log,
+            ui: self.ui,
             input: self.input,
             storage: self.storage,
             rng: self.rng,
@@ -212,6 +251,13 @@ impl<'a, 'gc, 'gc_context> UpdateContext<'a, 'gc, 'gc_context> {
             mouse_position: self.mouse_position,
             drag_object: self.drag_object,
             stage_size: self.stage_size,
+            viewport_size: self.viewport_size,
+            stage_scale_mode: self.stage_scale_mode,
+            stage_scale_mode_set_by_script: self.stage_scale_mode_set_by_script,
+            stage_align: self.stage_align,
+            stage_quality: self.stage_quality,
+            show_menu: self.show_menu,
+            frame_rate: self.frame_rate,
             player: self.player.clone(),
             load_manager: self.load_manager,
             system: self.system,
@@ -219,6 +265,7 @@ impl<'a, 'gc, 'gc_context> UpdateContext<'a, 'gc, 'gc_context> {
             shared_objects: self.shared_objects,
             unbound_text_fields: self.unbound_text_fields,
             timers: self.timers,
+            audio_manager: self.audio_manager,
             avm1: self.avm1,
             avm2: self.avm2,
             external_interface: self.external_interface,
@@ -1,8 +1,9 @@
 use crate::avm1::{
     Error as Avm1Error, Object as Avm1Object, TObject as Avm1TObject, Value as Avm1Value,
 };
-use crate::avm2::{TObject as Avm2TObject, Value as Avm2Value};
+use crate::avm2::{Object as Avm2Object, TObject as Avm2TObject, Value as Avm2Value};
 use crate::context::{RenderContext, UpdateContext};
+use crate::drawing::Drawing;
 use crate::player::NEWEST_PLAYER_VERSION;
 use crate::prelude::*;
 use crate::tag_utils::SwfMovie;
@@ -37,7 +38,7 @@ pub use button::Button;
 pub use edit_text::{AutoSizeMode, EditText, TextSelection};
 pub use graphic::Graphic;
 pub use morph_shape::{MorphShape, MorphShapeStatic};
-pub use movie_clip::{MovieClip, Scene};
+pub use movie_clip::{MovieClip, PendingImport, Scene};
 pub use text::Text;
 
 #[derive(Clone, Debug)]
@@ -67,6 +68,12 @@ pub struct DisplayObjectBase<'gc> {
     /// The next sibling of this display object in order of execution.
     next_sibling: Option<DisplayObject<'gc>>,
 
+    /// The display object masking this one, as set by script.
+    masker: Option<DisplayObject<'gc>>,
+
+    /// The display object this one masks, as set by script.
+    maskee: Option<DisplayObject<'gc>>,
+
     /// Bit flags for various display object properites.
     flags: EnumSet<DisplayObjectFlags>,
 }
@@ -86,7 +93,12 @@ impl<'gc> Default for DisplayObjectBase<'gc> {
             skew: 0.0,
             prev_sibling: None,
             next_sibling: None,
-            flags: DisplayObjectFlags::Visible.into(),
+            masker: None,
+            maskee: None,
+            flags: DisplayObjectFlags::Visible
+                | DisplayObjectFlags::MouseEnabled
+                | DisplayObjectFlags::MouseChildren
+                | DisplayObjectFlags::UseHandCursor,
         }
     }
 }
@@ -97,6 +109,8 @@ unsafe impl<'gc> Collect for DisplayObjectBase<'gc> {
         self.parent.trace(cc);
         self.prev_sibling.trace(cc);
         self.next_sibling.trace(cc);
+        self.masker.trace(cc);
+        self.maskee.trace(cc);
     }
 }
 
@@ -107,7 +121,10 @@ impl<'gc> DisplayObjectBase<'gc> {
         let flags_to_keep = self
             .flags
             .intersection(EnumSet::from(DisplayObjectFlags::LockRoot));
-        self.flags = DisplayObjectFlags::Visible.into();
+        self.flags = DisplayObjectFlags::Visible
+            | DisplayObjectFlags::MouseEnabled
+            | DisplayObjectFlags::MouseChildren
+            | DisplayObjectFlags::UseHandCursor;
         self.flags.insert_all(flags_to_keep);
     }
 
@@ -316,6 +333,18 @@ impl<'gc> DisplayObjectBase<'gc> {
     ) {
         self.next_sibling = node;
     }
+    fn masker(&self) -> Option<DisplayObject<'gc>> {
+        self.masker
+    }
+    fn set_masker(&mut self, _context: MutationContext<'gc, '_>, node: Option<DisplayObject<'gc>>) {
+        self.masker = node;
+    }
+    fn maskee(&self) -> Option<DisplayObject<'gc>> {
+        self.maskee
+    }
+    fn set_maskee(&mut self, _context: MutationContext<'gc, '_>, node: Option<DisplayObject<'gc>>) {
+        self.maskee = node;
+    }
     fn removed(&self) -> bool {
         self.flags.contains(DisplayObjectFlags::Removed)
     }
@@ -351,6 +380,54 @@ impl<'gc> DisplayObjectBase<'gc> {
         }
     }
 
+    fn mouse_enabled(&self) -> bool {
+        self.flags.contains(DisplayObjectFlags::MouseEnabled)
+    }
+
+    fn set_mouse_enabled(&mut self, value: bool) {
+        if value {
+            self.flags.insert(DisplayObjectFlags::MouseEnabled);
+        } else {
+            self.flags.remove(DisplayObjectFlags::MouseEnabled);
+        }
+    }
+
+    fn mouse_children(&self) -> bool {
+        self.flags.contains(DisplayObjectFlags::MouseChildren)
+    }
+
+    fn set_mouse_children(&mut self, value: bool) {
+        if value {
+            self.flags.insert(DisplayObjectFlags::MouseChildren);
+        } else {
+            self.flags.remove(DisplayObjectFlags::MouseChildren);
+        }
+    }
+
+    fn button_mode(&self) -> bool {
+        self.flags.contains(DisplayObjectFlags::ButtonMode)
+    }
+
+    fn set_button_mode(&mut self, value: bool) {
+        if value {
+            self.flags.insert(DisplayObjectFlags::ButtonMode);
+        } else {
+            self.flags.remove(DisplayObjectFlags::ButtonMode);
+        }
+    }
+
+    fn use_hand_cursor(&self) -> bool {
+        self.flags.contains(DisplayObjectFlags::UseHandCursor)
+    }
+
+    fn set_use_hand_cursor(&mut self, value: bool) {
+        if value {
+            self.flags.insert(DisplayObjectFlags::UseHandCursor);
+        } else {
+            self.flags.remove(DisplayObjectFlags::UseHandCursor);
+        }
+    }
+
     fn transformed_by_script(&self) -> bool {
         self.flags.contains(DisplayObjectFlags::TransformedByScript)
     }
@@ -430,6 +507,14 @@ pub trait TDisplayObject<'gc>:
     /// Composite DisplayObjects that only contain children should return `&Default::default()`
     fn self_bounds(&self) -> BoundingBox;
 
+    /// The untransformed inherent bounding box of this object, leaving out
+    /// the width of any strokes.
+    ///
+    /// Objects that can't have strokes use their `self_bounds`.
+    fn self_edge_bounds(&self) -> BoundingBox {
+        self.self_bounds()
+    }
+
     /// The untransformed bounding box of this object including children.
     fn bounds(&self) -> BoundingBox {
         self.bounds_with_transform(&Matrix::default())
@@ -462,6 +547,21 @@ pub trait TDisplayObject<'gc>:
         bounds
     }
 
+    /// Gets the bounds of this object and all children, leaving out the width
+    /// of any strokes, transformed by a given matrix.
+    fn edge_bounds_with_transform(&self, matrix: &Matrix) -> BoundingBox {
+        let mut bounds = self.self_edge_bounds().transform(matrix);
+
+        if let Some(ctr) = self.as_container() {
+            for child in ctr.iter_execution_list() {
+                let matrix = *matrix * *child.matrix();
+                bounds.union(&child.edge_bounds_with_transform(&matrix));
+            }
+        }
+
+        bounds
+    }
+
     fn place_frame(&self) -> u16;
     fn set_place_frame(&self, context: MutationContext<'gc, '_>, frame: u16);
 
@@ -695,6 +795,53 @@ pub trait TDisplayObject<'gc>:
     fn next_sibling(&self) -> Option<DisplayObject<'gc>>;
     fn set_next_sibling(&self, context: MutationContext<'gc, '_>, node: Option<DisplayObject<'gc>>);
 
+    /// The display object masking this one, as set by `setMask`.
+    fn masker(&self) -> Option<DisplayObject<'gc>>;
+
+    /// Sets the display object masking this one.
+    ///
+    /// If `remove_old_link` is set, the previous masker stops masking this
+    /// object.
+    fn set_masker(
+        &self,
+        context: MutationContext<'gc, '_>,
+        node: Option<DisplayObject<'gc>>,
+        remove_old_link: bool,
+    );
+
+    /// The display object this one masks, as set by `setMask`.
+    ///
+    /// Objects used as masks are not rendered themselves.
+    fn maskee(&self) -> Option<DisplayObject<'gc>>;
+
+    /// Sets the display object this one masks.
+    ///
+    /// If `remove_old_link` is set, the previous maskee stops being masked by
+    /// this object.
+    fn set_maskee(
+        &self,
+        context: MutationContext<'gc, '_>,
+        node: Option<DisplayObject<'gc>>,
+        remove_old_link: bool,
+    );
+
+    /// Tests if a given stage position point lies within the mask set on this
+    /// object by script. Objects without such a mask always pass.
+    ///
+    /// Points outside of the mask cannot hit this object or its children.
+    fn hit_test_masker(
+        &self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        pos: (Twips, Twips),
+    ) -> bool {
+        match self.masker() {
+            Some(masker) => {
+                masker.hit_test_shape(context, pos) && masker.hit_test_masker(context, pos)
+            }
+            None => true,
+        }
+    }
+
     /// Get another level by level name.
     ///
     /// Since levels don't have instance names, this function instead parses
@@ -721,6 +868,11 @@ pub trait TDisplayObject<'gc>:
         None
     }
     fn removed(&self) -> bool;
+
+    /// Sets whether this display object has been removed from the display
+    /// list.
+    ///
+    /// Removing an object also breaks any mask links set on it by `setMask`.
     fn set_removed(&self, context: MutationContext<'gc, '_>, value: bool);
 
     /// Whether this display object is visible.
@@ -741,6 +893,42 @@ pub trait TDisplayObject<'gc>:
     /// Returned by the `_lockroot` ActionScript property.
     fn set_lock_root(&self, context: MutationContext<'gc, '_>, value: bool);
 
+    /// Whether this display object may be the target of mouse events.
+    /// Returned by the `mouseEnabled` ActionScript property.
+    fn mouse_enabled(&self) -> bool;
+
+    /// Sets whether this display object may be the target of mouse events.
+    /// Returned by the `mouseEnabled` ActionScript property.
+    fn set_mouse_enabled(&self, context: MutationContext<'gc, '_>, value: bool);
+
+    /// Whether the children of this display object may be the target of
+    /// mouse events.
+    /// Returned by the `mouseChildren` ActionScript property.
+    fn mouse_children(&self) -> bool;
+
+    /// Sets whether the children of this display object may be the target of
+    /// mouse events.
+    /// Returned by the `mouseChildren` ActionScript property.
+    fn set_mouse_children(&self, context: MutationContext<'gc, '_>, value: bool);
+
+    /// Whether this display object behaves like a button under the mouse.
+    /// Returned by the `buttonMode` ActionScript property.
+    fn button_mode(&self) -> bool;
+
+    /// Sets whether this display object behaves like a button under the mouse.
+    /// Returned by the `buttonMode` ActionScript property.
+    fn set_button_mode(&self, context: MutationContext<'gc, '_>, value: bool);
+
+    /// Whether a hand cursor is shown over this display object when it is in
+    /// button mode.
+    /// Returned by the `useHandCursor` ActionScript property.
+    fn use_hand_cursor(&self) -> bool;
+
+    /// Sets whether a hand cursor is shown over this display object when it
+    /// is in button mode.
+    /// Returned by the `useHandCursor` ActionScript property.
+    fn set_use_hand_cursor(&self, context: MutationContext<'gc, '_>, value: bool);
+
     /// Whether this display object has been transformed by ActionScript.
     /// When this flag is set, changes from SWF `PlaceObject` tags are ignored.
     fn transformed_by_script(&self) -> bool;
@@ -811,6 +999,9 @@ pub trait TDisplayObject<'gc>:
         self.set_removed(context.gc_context, true);
     }
 
+    fn as_bitmap(&self) -> Option<Bitmap<'gc>> {
+        None
+    }
     fn as_button(&self) -> Option<Button<'gc>> {
         None
     }
@@ -827,6 +1018,12 @@ pub trait TDisplayObject<'gc>:
         None
     }
 
+    /// Get this display object's dynamic drawing, if it can be drawn on with
+    /// the drawing API.
+    fn as_drawing(&self, _gc_context: MutationContext<'gc, '_>) -> Option<RefMut<'_, Drawing>> {
+        None
+    }
+
     fn apply_place_object(
         &self,
         gc_context: MutationContext<'gc, '_>,
@@ -898,6 +1095,11 @@ pub trait TDisplayObject<'gc>:
         Avm2Value::Undefined // todo: see above
     }
 
+    /// Set the AVM2 object that represents this display object.
+    ///
+    /// Objects that do not support AVM2 ignore this.
+    fn set_object2(&self, _mc: MutationContext<'gc, '_>, _to: Avm2Object<'gc>) {}
+
     /// Tests if a given stage position point intersects with the world bounds of this object.
     fn hit_test_bounds(&self, pos: (Twips, Twips)) -> bool {
         self.world_bounds().contains(pos)
@@ -928,6 +1130,20 @@ pub trait TDisplayObject<'gc>:
         None
     }
 
+    /// Find the interactive object at a given stage position that should
+    /// receive AVM2 mouse events.
+    ///
+    /// Objects that cannot receive mouse events themselves never report a
+    /// hit; their containers test them with `hit_test_shape` instead.
+    fn mouse_pick_avm2(
+        &self,
+        _context: &mut UpdateContext<'_, 'gc, '_>,
+        _self_node: DisplayObject<'gc>,
+        _pos: (Twips, Twips),
+    ) -> Avm2MousePick<'gc> {
+        Avm2MousePick::Miss
+    }
+
     fn post_instantiation(
         &self,
         context: &mut UpdateContext<'_, 'gc, '_>,
@@ -1170,10 +1386,46 @@ macro_rules! impl_display_object_sansbounds {
         ) {
             self.0.write(context).$field.set_next_sibling(context, node);
         }
+        fn masker(&self) -> Option<DisplayObject<'gc>> {
+            self.0.read().$field.masker()
+        }
+        fn set_masker(
+            &self,
+            context: gc_arena::MutationContext<'gc, '_>,
+            node: Option<DisplayObject<'gc>>,
+            remove_old_link: bool,
+        ) {
+            if remove_old_link {
+                if let Some(old_masker) = self.masker() {
+                    old_masker.set_maskee(context, None, false);
+                }
+            }
+            self.0.write(context).$field.set_masker(context, node);
+        }
+        fn maskee(&self) -> Option<DisplayObject<'gc>> {
+            self.0.read().$field.maskee()
+        }
+        fn set_maskee(
+            &self,
+            context: gc_arena::MutationContext<'gc, '_>,
+            node: Option<DisplayObject<'gc>>,
+            remove_old_link: bool,
+        ) {
+            if remove_old_link {
+                if let Some(old_maskee) = self.maskee() {
+                    old_maskee.set_masker(context, None, false);
+                }
+            }
+            self.0.write(context).$field.set_maskee(context, node);
+        }
         fn removed(&self) -> bool {
             self.0.read().$field.removed()
         }
         fn set_removed(&self, context: gc_arena::MutationContext<'gc, '_>, value: bool) {
+            if value {
+                self.set_masker(context, None, true);
+                self.set_maskee(context, None, true);
+            }
             self.0.write(context).$field.set_removed(value)
         }
         fn visible(&self) -> bool {
@@ -1188,6 +1440,30 @@ macro_rules! impl_display_object_sansbounds {
         fn set_lock_root(&self, context: gc_arena::MutationContext<'gc, '_>, value: bool) {
             self.0.write(context).$field.set_lock_root(value);
         }
+        fn mouse_enabled(&self) -> bool {
+            self.0.read().$field.mouse_enabled()
+        }
+        fn set_mouse_enabled(&self, context: gc_arena::MutationContext<'gc, '_>, value: bool) {
+            self.0.write(context).$field.set_mouse_enabled(value);
+        }
+        fn mouse_children(&self) -> bool {
+            self.0.read().$field.mouse_children()
+        }
+        fn set_mouse_children(&self, context: gc_arena::MutationContext<'gc, '_>, value: bool) {
+            self.0.write(context).$field.set_mouse_children(value);
+        }
+        fn button_mode(&self) -> bool {
+            self.0.read().$field.button_mode()
+        }
+        fn set_button_mode(&self, context: gc_arena::MutationContext<'gc, '_>, value: bool) {
+            self.0.write(context).$field.set_button_mode(value);
+        }
+        fn use_hand_cursor(&self) -> bool {
+            self.0.read().$field.use_hand_cursor()
+        }
+        fn set_use_hand_cursor(&self, context: gc_arena::MutationContext<'gc, '_>, value: bool) {
+            self.0.write(context).$field.set_use_hand_cursor(value);
+        }
         fn transformed_by_script(&self) -> bool {
             self.0.read().$field.transformed_by_script()
         }
@@ -1264,6 +1540,111 @@ impl<'gc> DisplayObject<'gc> {
     pub fn ptr_eq(a: DisplayObject<'gc>, b: DisplayObject<'gc>) -> bool {
         a.as_ptr() == b.as_ptr()
     }
+
+    /// Renders this object, clipped to the mask set on it by script, if any.
+    ///
+    /// Objects that are used as a mask by script are not rendered on their
+    /// own. A mask that is itself masked only clips where its own mask does.
+    /// As with clip depth masks, masks inside of a mask become part of the
+    /// outer mask instead.
+    pub fn render_with_mask(self, context: &mut RenderContext<'_, 'gc>) {
+        if self.maskee().is_some() {
+            return;
+        }
+
+        // Each mask in the chain is pushed as a nested mask, which clips to
+        // the intersection of all of them.
+        let mut maskers = vec![];
+        if context.allow_mask {
+            let mut masker = self.masker();
+            while let Some(object) = masker.filter(|masker| masker.allow_as_mask()) {
+                maskers.push(object);
+                masker = object.masker();
+            }
+        }
+
+        // The masks may live anywhere in the display list, so render them
+        // with their own transforms relative to our parent.
+        let parent_matrix = |object: DisplayObject<'gc>| {
+            object
+                .parent()
+                .map(|parent| parent.local_to_global_matrix())
+                .unwrap_or_default()
+        };
+        let mut matrix = parent_matrix(self);
+        matrix.invert();
+        let mask_transforms: Vec<_> = maskers
+            .iter()
+            .map(|&masker| Transform {
+                matrix: matrix * parent_matrix(masker),
+                ..Default::default()
+            })
+            .collect();
+
+        let allow_mask = context.allow_mask;
+        for (masker, mask_transform) in maskers.iter().zip(&mask_transforms) {
+            context.renderer.push_mask();
+            context.allow_mask = false;
+            context.transform_stack.push(mask_transform);
+            masker.render(context);
+            context.transform_stack.pop();
+            context.allow_mask = allow_mask;
+            context.renderer.activate_mask();
+        }
+
+        self.render(context);
+
+        for (masker, mask_transform) in maskers.iter().zip(&mask_transforms).rev() {
+            context.renderer.deactivate_mask();
+            context.allow_mask = false;
+            context.transform_stack.push(mask_transform);
+            masker.render(context);
+            context.transform_stack.pop();
+            context.allow_mask = allow_mask;
+            context.renderer.pop_mask();
+        }
+    }
+}
+
+/// The result of picking an interactive object for AVM2 mouse events.
+#[derive(Clone, Copy, Debug)]
+pub enum Avm2MousePick<'gc> {
+    /// The given object should receive the event.
+    Hit(DisplayObject<'gc>),
+
+    /// The point is over this object, but it may not receive mouse events.
+    /// The event goes to the nearest container that may, unless an object
+    /// beneath takes it first.
+    PropagateToParent,
+
+    /// The point is not over this object.
+    Miss,
+}
+
+impl<'gc> Avm2MousePick<'gc> {
+    /// Adjust a pick made inside a container for that container's
+    /// `mouseEnabled` and `mouseChildren` settings.
+    pub fn combine_with_parent(self, parent: DisplayObject<'gc>) -> Self {
+        match self {
+            Avm2MousePick::Hit(_) if parent.mouse_children() => self,
+            Avm2MousePick::Hit(_) | Avm2MousePick::PropagateToParent => {
+                if parent.mouse_enabled() {
+                    Avm2MousePick::Hit(parent)
+                } else {
+                    Avm2MousePick::PropagateToParent
+                }
+            }
+            Avm2MousePick::Miss => self,
+        }
+    }
+
+    /// The object that should receive the event, if any.
+    pub fn hit(self) -> Option<DisplayObject<'gc>> {
+        match self {
+            Avm2MousePick::Hit(target) => Some(target),
+            _ => None,
+        }
+    }
 }
 
 /// Bit flags used by `DisplayObject`.
@@ -1295,4 +1676,18 @@ enum DisplayObjectFlags {
     /// Whether this object has `_lockroot` set to true, in which case
     /// it becomes the _root of itself and of any children
     LockRoot,
+
+    /// Whether this object may be the target of mouse events (`mouseEnabled` property).
+    MouseEnabled,
+
+    /// Whether this object's children may be the target of mouse events
+    /// (`mouseChildren` property).
+    MouseChildren,
+
+    /// Whether this object behaves like a button (`buttonMode` property).
+    ButtonMode,
+
+    /// Whether a hand cursor is shown while this object is in button mode
+    /// (`useHandCursor` property).
+    UseHandCursor,
 }
@@ -1,11 +1,12 @@
 //! Bitmap display object
 
+use crate::avm2::{Object as Avm2Object, Value as Avm2Value};
 use crate::backend::render::BitmapHandle;
 use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::{DisplayObjectBase, TDisplayObject};
 use crate::prelude::*;
 use crate::types::{Degrees, Percent};
-use gc_arena::{Collect, Gc, GcCell};
+use gc_arena::{Collect, Gc, GcCell, MutationContext};
 
 /// A Bitmap display object is a raw bitamp on the stage.
 /// This can only be instanitated on the display list in SWFv9 AVM2 files.
@@ -23,8 +24,9 @@ pub struct Bitmap<'gc>(GcCell<'gc, BitmapData<'gc>>);
 pub struct BitmapData<'gc> {
     base: DisplayObjectBase<'gc>,
     static_data: Gc<'gc, BitmapStatic>,
-    bitmap_data: Option<GcCell<'gc, crate::avm1::object::bitmap_data::BitmapData>>,
+    bitmap_data: Option<GcCell<'gc, crate::bitmap::bitmap_data::BitmapData>>,
     smoothing: bool,
+    avm2_object: Option<Avm2Object<'gc>>,
 }
 
 impl<'gc> Bitmap<'gc> {
@@ -34,7 +36,7 @@ impl<'gc> Bitmap<'gc> {
         bitmap_handle: BitmapHandle,
         width: u16,
         height: u16,
-        bitmap_data: Option<GcCell<'gc, crate::avm1::object::bitmap_data::BitmapData>>,
+        bitmap_data: Option<GcCell<'gc, crate::bitmap::bitmap_data::BitmapData>>,
         smoothing: bool,
     ) -> Self {
         Bitmap(GcCell::allocate(
@@ -45,17 +47,49 @@ impl<'gc> Bitmap<'gc> {
                     context.gc_context,
                     BitmapStatic {
                         id,
-                        bitmap_handle,
+                        bitmap_handle: Some(bitmap_handle),
                         width,
                         height,
                     },
                 ),
                 bitmap_data,
                 smoothing,
+                avm2_object: None,
             },
         ))
     }
 
+    /// Construct an AVM2 `Bitmap` displaying the given bitmap data, if any.
+    pub fn new_with_avm2(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        avm2_object: Avm2Object<'gc>,
+        bitmap_data: Option<GcCell<'gc, crate::bitmap::bitmap_data::BitmapData>>,
+        smoothing: bool,
+    ) -> Self {
+        let bitmap = Bitmap(GcCell::allocate(
+            context.gc_context,
+            BitmapData {
+                base: Default::default(),
+                static_data: Gc::allocate(
+                    context.gc_context,
+                    BitmapStatic {
+                        id: 0,
+                        bitmap_handle: None,
+                        width: 0,
+                        height: 0,
+                    },
+                ),
+                bitmap_data: None,
+                smoothing,
+                avm2_object: Some(avm2_object),
+            },
+        ));
+
+        bitmap.set_bitmap_data(context, bitmap_data);
+
+        bitmap
+    }
+
     pub fn new(
         context: &mut UpdateContext<'_, 'gc, '_>,
         id: CharacterId,
@@ -66,11 +100,54 @@ impl<'gc> Bitmap<'gc> {
         Self::new_with_bitmap_data(context, id, bitmap_handle, width, height, None, true)
     }
 
-    #[allow(dead_code)]
-    pub fn bitmap_handle(self) -> BitmapHandle {
+    pub fn bitmap_handle(self) -> Option<BitmapHandle> {
         self.0.read().static_data.bitmap_handle
     }
 
+    /// The bitmap data displayed by this bitmap, if it was created from one.
+    pub fn bitmap_data(self) -> Option<GcCell<'gc, crate::bitmap::bitmap_data::BitmapData>> {
+        self.0.read().bitmap_data
+    }
+
+    /// Display different bitmap data, or nothing at all.
+    pub fn set_bitmap_data(
+        self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        bitmap_data: Option<GcCell<'gc, crate::bitmap::bitmap_data::BitmapData>>,
+    ) {
+        let id = self.0.read().static_data.id;
+        let (bitmap_handle, width, height) = if let Some(bitmap_data) = bitmap_data {
+            let mut bd = bitmap_data.write(context.gc_context);
+            (
+                bd.bitmap_handle(context.renderer),
+                bd.width() as u16,
+                bd.height() as u16,
+            )
+        } else {
+            (None, 0, 0)
+        };
+
+        let mut write = self.0.write(context.gc_context);
+        write.static_data = Gc::allocate(
+            context.gc_context,
+            BitmapStatic {
+                id,
+                bitmap_handle,
+                width,
+                height,
+            },
+        );
+        write.bitmap_data = bitmap_data;
+    }
+
+    pub fn smoothing(self) -> bool {
+        self.0.read().smoothing
+    }
+
+    pub fn set_smoothing(self, gc_context: MutationContext<'gc, '_>, smoothing: bool) {
+        self.0.write(gc_context).smoothing = smoothing;
+    }
+
     pub fn width(self) -> u16 {
         self.0.read().static_data.width
     }
@@ -97,12 +174,32 @@ impl<'gc> TDisplayObject<'gc> for Bitmap<'gc> {
         }
     }
 
+    fn as_bitmap(&self) -> Option<Bitmap<'gc>> {
+        Some(*self)
+    }
+
+    fn object2(&self) -> Avm2Value<'gc> {
+        self.0
+            .read()
+            .avm2_object
+            .map(Avm2Value::from)
+            .unwrap_or(Avm2Value::Undefined)
+    }
+
+    fn set_object2(&self, mc: MutationContext<'gc, '_>, to: Avm2Object<'gc>) {
+        self.0.write(mc).avm2_object = Some(to);
+    }
+
     fn run_frame(&self, context: &mut UpdateContext<'_, 'gc, '_>) {
         if let Some(bitmap_data) = &self.0.read().bitmap_data {
             let bd = bitmap_data.read();
-            if bd.dirty() {
+            if let (true, false, Some(bitmap_handle)) = (
+                bd.dirty(),
+                bd.locked(),
+                self.0.read().static_data.bitmap_handle,
+            ) {
                 let _ = context.renderer.update_texture(
-                    self.0.read().static_data.bitmap_handle,
+                    bitmap_handle,
                     bd.width(),
                     bd.height(),
                     bd.pixels_rgba(),
@@ -122,11 +219,13 @@ impl<'gc> TDisplayObject<'gc> for Bitmap<'gc> {
         context.transform_stack.push(&*self.transform());
 
         let bitmap_data = self.0.read();
-        context.renderer.render_bitmap(
-            bitmap_data.static_data.bitmap_handle,
-            context.transform_stack.transform(),
-            bitmap_data.smoothing,
-        );
+        if let Some(bitmap_handle) = bitmap_data.static_data.bitmap_handle {
+            context.renderer.render_bitmap(
+                bitmap_handle,
+                context.transform_stack.transform(),
+                bitmap_data.smoothing,
+            );
+        }
 
         context.transform_stack.pop();
     }
@@ -137,7 +236,7 @@ impl<'gc> TDisplayObject<'gc> for Bitmap<'gc> {
 #[collect(no_drop)]
 struct BitmapStatic {
     id: CharacterId,
-    bitmap_handle: BitmapHandle,
+    bitmap_handle: Option<BitmapHandle>,
     width: u16,
     height: u16,
 }
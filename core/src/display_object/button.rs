@@ -315,7 +315,7 @@ impl<'gc> TDisplayObject<'gc> for Button<'gc> {
         point: (Twips, Twips),
     ) -> bool {
         for child in self.iter_execution_list() {
-            if child.hit_test_shape(context, point) {
+            if child.hit_test_masker(context, point) && child.hit_test_shape(context, point) {
                 return true;
             }
         }
@@ -330,7 +330,7 @@ impl<'gc> TDisplayObject<'gc> for Button<'gc> {
         point: (Twips, Twips),
     ) -> Option<DisplayObject<'gc>> {
         // The button is hovered if the mouse is over any child nodes.
-        if self.visible() {
+        if self.visible() && self.maskee().is_none() && self.hit_test_masker(context, point) {
             for child in self.iter_render_list().rev() {
                 let result = child.mouse_pick(context, child, point);
                 if result.is_some() {
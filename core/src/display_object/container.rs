@@ -221,7 +221,7 @@ pub trait TDisplayObjectContainer<'gc>:
                 context.renderer.activate_mask();
             } else if child.visible() {
                 // Normal child.
-                child.render(context);
+                child.render_with_mask(context);
             }
         }
 
@@ -1,9 +1,14 @@
 //! `EditText` display object and support code.
 use crate::avm1::activation::{Activation, ActivationIdentifier};
 use crate::avm1::{Avm1, AvmString, Object, StageObject, TObject, Value};
+use crate::avm2::{
+    Activation as Avm2Activation, Avm2, Error as Avm2Error, Namespace as Avm2Namespace,
+    Object as Avm2Object, QName as Avm2QName, StageObject as Avm2StageObject,
+    TObject as Avm2TObject, Value as Avm2Value,
+};
 use crate::backend::input::MouseCursor;
 use crate::context::{RenderContext, UpdateContext};
-use crate::display_object::{DisplayObjectBase, TDisplayObject};
+use crate::display_object::{Avm2MousePick, DisplayObjectBase, TDisplayObject};
 use crate::drawing::Drawing;
 use crate::events::{ButtonKeyCode, ClipEvent, ClipEventResult, KeyCode};
 use crate::font::{round_down_to_pixel, Glyph};
@@ -14,7 +19,7 @@ use crate::string_utils;
 use crate::tag_utils::SwfMovie;
 use crate::transform::Transform;
 use crate::types::{Degrees, Percent};
-use crate::vminterface::Instantiator;
+use crate::vminterface::{AvmObject, AvmType, Instantiator};
 use crate::xml::XMLDocument;
 use chrono::Utc;
 use gc_arena::{Collect, Gc, GcCell, MutationContext};
@@ -118,8 +123,8 @@ pub struct EditTextData<'gc> {
     /// The current intrinsic bounds of the text field.
     bounds: BoundingBox,
 
-    /// The AVM1 object handle
-    object: Option<Object<'gc>>,
+    /// The AVM1 or AVM2 object handle
+    object: Option<AvmObject<'gc>>,
 
     /// The variable path that this text field is bound to (AVM1 only).
     variable: Option<String>,
@@ -133,6 +138,12 @@ pub struct EditTextData<'gc> {
     /// The selected portion of the text, or None if the text is not selected.
     selection: Option<TextSelection>,
 
+    /// The line displayed at the top of the text field, counting from 1.
+    scroll_v: usize,
+
+    /// How far the text is scrolled to the left, in pixels.
+    scroll_h: f64,
+
     /// Whether or not this EditText has the current keyboard focus
     has_focus: bool,
 }
@@ -229,6 +240,8 @@ impl<'gc> EditText<'gc> {
                 bound_stage_object: None,
                 firing_variable_binding: false,
                 selection: None,
+                scroll_v: 1,
+                scroll_h: 0.0,
                 has_focus: false,
             },
         ));
@@ -527,6 +540,95 @@ impl<'gc> EditText<'gc> {
         self.relayout(context);
     }
 
+    /// Calculate the top and bottom of each laid-out line of text.
+    fn line_bounds(self) -> Vec<(Twips, Twips)> {
+        let mut lines: Vec<(Twips, Twips)> = Vec::new();
+
+        for layout_box in self.0.read().layout.iter() {
+            let bounds = layout_box.bounds();
+            if let Some(line) = lines.iter_mut().find(|l| l.0 == bounds.offset_y()) {
+                line.1 = line.1.max(bounds.extent_y());
+            } else {
+                lines.push((bounds.offset_y(), bounds.extent_y()));
+            }
+        }
+
+        lines.sort_by_key(|l| l.0);
+        lines
+    }
+
+    /// The height of the area that text is displayed in.
+    fn visible_height(self) -> Twips {
+        self.0.read().bounds.height() - Twips::from_pixels(Self::INTERNAL_PADDING * 2.0)
+    }
+
+    /// The line displayed at the top of the text field, counting from 1.
+    pub fn scroll_v(self) -> usize {
+        self.0.read().scroll_v.min(self.max_scroll_v())
+    }
+
+    pub fn set_scroll_v(self, scroll_v: usize, context: MutationContext<'gc, '_>) {
+        let max_scroll_v = self.max_scroll_v();
+        self.0.write(context).scroll_v = scroll_v.max(1).min(max_scroll_v);
+    }
+
+    /// The highest line that can be scrolled to the top of the text field
+    /// while still filling it with text.
+    pub fn max_scroll_v(self) -> usize {
+        let lines = self.line_bounds();
+        let visible_height = self.visible_height();
+
+        if let Some(&(_, last_bottom)) = lines.last() {
+            for (i, (top, _)) in lines.iter().enumerate() {
+                if last_bottom - *top <= visible_height {
+                    return i + 1;
+                }
+            }
+
+            lines.len()
+        } else {
+            1
+        }
+    }
+
+    /// How far the text is scrolled to the left, in pixels.
+    pub fn scroll_h(self) -> f64 {
+        self.0.read().scroll_h.min(self.max_scroll_h())
+    }
+
+    pub fn set_scroll_h(self, scroll_h: f64, context: MutationContext<'gc, '_>) {
+        let max_scroll_h = self.max_scroll_h();
+        self.0.write(context).scroll_h = scroll_h.max(0.0).min(max_scroll_h);
+    }
+
+    /// The furthest the text can be scrolled to the left, in pixels.
+    pub fn max_scroll_h(self) -> f64 {
+        let edit_text = self.0.read();
+
+        if edit_text.is_word_wrap {
+            return 0.0;
+        }
+
+        let visible_width =
+            edit_text.bounds.width() - Twips::from_pixels(Self::INTERNAL_PADDING * 2.0);
+
+        (edit_text.intrinsic_bounds.width() - visible_width)
+            .to_pixels()
+            .max(0.0)
+    }
+
+    /// The offset that scrolling applies to the laid-out text.
+    fn scroll_offset(self) -> (Twips, Twips) {
+        let scroll_v = self.scroll_v();
+        let y = self
+            .line_bounds()
+            .get(scroll_v - 1)
+            .map(|l| l.0)
+            .unwrap_or_else(Twips::zero);
+
+        (Twips::from_pixels(self.scroll_h()), y)
+    }
+
     /// Construct a base text transform for a particular `EditText` span.
     ///
     /// This `text_transform` is separate from and relative to the base
@@ -1012,9 +1114,10 @@ impl<'gc> EditText<'gc> {
     pub fn screen_position_to_index(self, position: (Twips, Twips)) -> Option<usize> {
         let text = self.0.read();
         let position = self.global_to_local(position);
+        let (scroll_x, scroll_y) = self.scroll_offset();
         let position = (
-            position.0 + Twips::from_pixels(Self::INTERNAL_PADDING),
-            position.1 + Twips::from_pixels(Self::INTERNAL_PADDING),
+            position.0 + Twips::from_pixels(Self::INTERNAL_PADDING) + scroll_x,
+            position.1 + Twips::from_pixels(Self::INTERNAL_PADDING) + scroll_y,
         );
 
         for layout_box in text.layout.iter() {
@@ -1099,6 +1202,10 @@ impl<'gc> EditText<'gc> {
                     }
                 }
                 code if !(code as char).is_control() => {
+                    if !self.dispatch_text_input(character, context) {
+                        return;
+                    }
+
                     self.replace_text(
                         selection.start(),
                         selection.end(),
@@ -1116,6 +1223,12 @@ impl<'gc> EditText<'gc> {
             }
 
             if changed {
+                let object = self.0.read().object;
+                if let Some(AvmObject::Avm2(object)) = object {
+                    self.dispatch_change(object, context);
+                    return;
+                }
+
                 let globals = context.avm1.global_object_cell();
                 let swf_version = context.swf.header().version;
                 let mut activation = Activation::from_nothing(
@@ -1131,9 +1244,91 @@ impl<'gc> EditText<'gc> {
         }
     }
 
+    /// Fire `TextEvent.TEXT_INPUT` on this text field's AVM2 object before a
+    /// character is typed into it.
+    ///
+    /// Returns `false` if the event was cancelled, in which case the character
+    /// should not be inserted.
+    fn dispatch_text_input(
+        self,
+        character: char,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+    ) -> bool {
+        let object = self.0.read().object;
+        if let Some(AvmObject::Avm2(object)) = object {
+            let text_event_proto = context.avm2.prototypes().text_event;
+            let args = [
+                "textInput".into(),
+                true.into(),
+                true.into(),
+                AvmString::new(context.gc_context, character.to_string()).into(),
+            ];
+
+            match Avm2::dispatch_event(context, object, text_event_proto, &args) {
+                Ok(not_cancelled) => return not_cancelled,
+                Err(e) => {
+                    context.avm2.clear_error();
+                    log::error!("Unhandled AVM2 exception in textInput handler: {}", e);
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Fire `Event.CHANGE` on this text field's AVM2 object after the user
+    /// has edited its text.
+    fn dispatch_change(self, object: Avm2Object<'gc>, context: &mut UpdateContext<'_, 'gc, '_>) {
+        let event_proto = context.avm2.prototypes().event;
+        let args = ["change".into(), true.into(), false.into()];
+
+        if let Err(e) = Avm2::dispatch_event(context, object, event_proto, &args) {
+            context.avm2.clear_error();
+            log::error!("Unhandled AVM2 exception in change handler: {}", e);
+        }
+    }
+
+    /// Construct the AVM2 side of a text field placed by an AVM2 movie.
+    fn construct_as_avm2_object(
+        self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        display_object: DisplayObject<'gc>,
+    ) {
+        let mut constr_thing = || {
+            let mut activation = Avm2Activation::from_nothing(context.reborrow());
+            let mut proto = activation.context.avm2.prototypes().textfield;
+            let constructor = proto
+                .get_property(
+                    proto,
+                    &Avm2QName::new(Avm2Namespace::public_namespace(), "constructor"),
+                    &mut activation,
+                )?
+                .coerce_to_object(&mut activation)?;
+            let object = Avm2StageObject::for_display_object(
+                activation.context.gc_context,
+                display_object,
+                proto,
+            )
+            .into();
+
+            constructor.call(Some(object), &[], &mut activation, Some(proto))?;
+
+            Ok(object)
+        };
+        let result: Result<Avm2Object<'gc>, Avm2Error> = constr_thing();
+
+        match result {
+            Ok(object) => self.0.write(context.gc_context).object = Some(object.into()),
+            Err(e) => {
+                context.avm2.clear_error();
+                log::error!("Got {} when constructing AVM2 side of text field", e);
+            }
+        }
+    }
+
     fn initialize_as_broadcaster(&self, activation: &mut Activation<'_, 'gc, '_>) {
         let write = self.0.write(activation.context.gc_context);
-        if let Some(object) = write.object {
+        if let Some(AvmObject::Avm1(object)) = write.object {
             activation.context.avm1.broadcaster_functions().initialize(
                 activation.context.gc_context,
                 object,
@@ -1156,7 +1351,7 @@ impl<'gc> EditText<'gc> {
     }
 
     fn on_changed(&self, activation: &mut Activation<'_, 'gc, '_>) {
-        if let Some(object) = self.0.read().object {
+        if let Some(AvmObject::Avm1(object)) = self.0.read().object {
             let _ = object.call_method(
                 "broadcastMessage",
                 &["onChanged".into(), object.into()],
@@ -1196,17 +1391,6 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
         self.set_default_instance_name(context);
 
         let mut text = self.0.write(context.gc_context);
-        if text.object.is_none() {
-            let object = StageObject::for_display_object(
-                context.gc_context,
-                display_object,
-                Some(context.avm1.prototypes().text_field),
-            )
-            .into();
-
-            text.object = Some(object);
-        }
-
         text.document = text
             .document
             .as_node()
@@ -1219,6 +1403,33 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
         }
         drop(text);
 
+        let movie = self.movie().unwrap();
+        let library = context.library.library_for_movie_mut(movie);
+        if library.avm_type() == AvmType::Avm2 {
+            if self.0.read().object.is_none() {
+                self.construct_as_avm2_object(context, display_object);
+            }
+
+            if run_frame {
+                self.run_frame(context);
+            }
+
+            return;
+        }
+
+        let mut text = self.0.write(context.gc_context);
+        if text.object.is_none() {
+            let object = StageObject::for_display_object(
+                context.gc_context,
+                display_object,
+                Some(context.avm1.prototypes().text_field),
+            )
+            .into();
+
+            text.object = Some(AvmObject::Avm1(object));
+        }
+        drop(text);
+
         Avm1::run_with_stack_frame_for_display_object(
             (*self).into(),
             context.swf.version(),
@@ -1244,10 +1455,24 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
         self.0
             .read()
             .object
+            .and_then(|o| o.as_avm1_object().ok())
             .map(Value::from)
             .unwrap_or(Value::Undefined)
     }
 
+    fn object2(&self) -> Avm2Value<'gc> {
+        self.0
+            .read()
+            .object
+            .and_then(|o| o.as_avm2_object().ok())
+            .map(Avm2Value::from)
+            .unwrap_or(Avm2Value::Undefined)
+    }
+
+    fn set_object2(&self, mc: MutationContext<'gc, '_>, to: Avm2Object<'gc>) {
+        self.0.write(mc).object = Some(to.into());
+    }
+
     fn self_bounds(&self) -> BoundingBox {
         self.0.read().bounds.clone()
     }
@@ -1353,10 +1578,11 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
 
         // TODO: Where does this come from? How is this different than INTERNAL_PADDING? Does this apply to y as well?
         // If this is actually right, offset the border in `redraw_border` instead of doing an extra push.
+        let (scroll_x, scroll_y) = self.scroll_offset();
         context.transform_stack.push(&Transform {
             matrix: Matrix {
-                tx: Twips::from_pixels(Self::INTERNAL_PADDING),
-                ty: Twips::from_pixels(Self::INTERNAL_PADDING),
+                tx: Twips::from_pixels(Self::INTERNAL_PADDING) - scroll_x,
+                ty: Twips::from_pixels(Self::INTERNAL_PADDING) - scroll_y,
                 ..Default::default()
             },
             ..Default::default()
@@ -1443,13 +1669,41 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
         point: (Twips, Twips),
     ) -> Option<DisplayObject<'gc>> {
         // The button is hovered if the mouse is over any child nodes.
-        if self.visible() && self.is_selectable() && self.hit_test_shape(context, point) {
+        if self.visible()
+            && self.is_selectable()
+            && self.maskee().is_none()
+            && self.hit_test_masker(context, point)
+            && self.hit_test_shape(context, point)
+        {
             Some(self_node)
         } else {
             None
         }
     }
 
+    fn mouse_pick_avm2(
+        &self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        self_node: DisplayObject<'gc>,
+        point: (Twips, Twips),
+    ) -> Avm2MousePick<'gc> {
+        // Unlike AVM1, text fields take mouse events whether or not they are
+        // selectable.
+        if self.visible()
+            && self.maskee().is_none()
+            && self.hit_test_masker(context, point)
+            && self.hit_test_shape(context, point)
+        {
+            if self.mouse_enabled() {
+                Avm2MousePick::Hit(self_node)
+            } else {
+                Avm2MousePick::PropagateToParent
+            }
+        } else {
+            Avm2MousePick::Miss
+        }
+    }
+
     fn mouse_cursor(&self) -> MouseCursor {
         MouseCursor::IBeam
     }
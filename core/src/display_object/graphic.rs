@@ -1,10 +1,13 @@
-use crate::backend::render::ShapeHandle;
+use crate::avm2::{Object as Avm2Object, Value as Avm2Value};
+use crate::backend::render::{BitmapSource, ShapeHandle};
 use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::{DisplayObjectBase, TDisplayObject};
+use crate::drawing::Drawing;
 use crate::prelude::*;
 use crate::tag_utils::SwfMovie;
 use crate::types::{Degrees, Percent};
-use gc_arena::{Collect, GcCell};
+use gc_arena::{Collect, GcCell, MutationContext};
+use std::cell::RefMut;
 use std::sync::Arc;
 
 #[derive(Clone, Debug, Collect, Copy)]
@@ -15,6 +18,8 @@ pub struct Graphic<'gc>(GcCell<'gc, GraphicData<'gc>>);
 pub struct GraphicData<'gc> {
     base: DisplayObjectBase<'gc>,
     static_data: gc_arena::Gc<'gc, GraphicStatic>,
+    avm2_object: Option<Avm2Object<'gc>>,
+    drawing: Option<Drawing>,
 }
 
 impl<'gc> Graphic<'gc> {
@@ -27,9 +32,10 @@ impl<'gc> Graphic<'gc> {
         let static_data = GraphicStatic {
             id: swf_shape.id,
             bounds: swf_shape.shape_bounds.clone().into(),
-            render_handle: context
-                .renderer
-                .register_shape((&swf_shape).into(), library),
+            render_handle: context.renderer.register_shape(
+                (&swf_shape).into(),
+                library.map(|library| library as &dyn BitmapSource),
+            ),
             shape: swf_shape,
         };
         Graphic(GcCell::allocate(
@@ -37,6 +43,47 @@ impl<'gc> Graphic<'gc> {
             GraphicData {
                 base: Default::default(),
                 static_data: gc_arena::Gc::allocate(context.gc_context, static_data),
+                avm2_object: None,
+                drawing: None,
+            },
+        ))
+    }
+
+    /// Construct an empty `Graphic` for an AVM2 `Shape`.
+    ///
+    /// The graphic has no timeline content; everything it displays is drawn
+    /// through the drawing API.
+    pub fn new_with_avm2(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        avm2_object: Avm2Object<'gc>,
+    ) -> Self {
+        let swf_shape = swf::Shape {
+            version: 32,
+            id: 0,
+            shape_bounds: Default::default(),
+            edge_bounds: Default::default(),
+            has_fill_winding_rule: false,
+            has_non_scaling_strokes: false,
+            has_scaling_strokes: true,
+            styles: swf::ShapeStyles {
+                fill_styles: Vec::new(),
+                line_styles: Vec::new(),
+            },
+            shape: Vec::new(),
+        };
+        let static_data = GraphicStatic {
+            id: swf_shape.id,
+            bounds: Default::default(),
+            render_handle: context.renderer.register_shape((&swf_shape).into(), None),
+            shape: swf_shape,
+        };
+        Graphic(GcCell::allocate(
+            context.gc_context,
+            GraphicData {
+                base: Default::default(),
+                static_data: gc_arena::Gc::allocate(context.gc_context, static_data),
+                avm2_object: Some(avm2_object),
+                drawing: Some(Drawing::new()),
             },
         ))
     }
@@ -50,7 +97,21 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
     }
 
     fn self_bounds(&self) -> BoundingBox {
-        self.0.read().static_data.bounds.clone()
+        let read = self.0.read();
+        if let Some(drawing) = &read.drawing {
+            drawing.self_bounds()
+        } else {
+            read.static_data.bounds.clone()
+        }
+    }
+
+    fn self_edge_bounds(&self) -> BoundingBox {
+        let read = self.0.read();
+        if let Some(drawing) = &read.drawing {
+            drawing.self_edge_bounds()
+        } else {
+            read.static_data.shape.edge_bounds.clone().into()
+        }
     }
 
     fn world_bounds(&self) -> BoundingBox {
@@ -76,10 +137,15 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
 
         context.transform_stack.push(&*self.transform());
 
-        context.renderer.render_shape(
-            self.0.read().static_data.render_handle,
-            context.transform_stack.transform(),
-        );
+        let read = self.0.read();
+        if let Some(drawing) = &read.drawing {
+            drawing.render(context);
+        } else {
+            context.renderer.render_shape(
+                read.static_data.render_handle,
+                context.transform_stack.transform(),
+            );
+        }
 
         context.transform_stack.pop();
     }
@@ -93,18 +159,47 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
         if self.world_bounds().contains(point) {
             let local_matrix = self.global_to_local_matrix();
             let point = local_matrix * point;
-            let shape = &self.0.read().static_data.shape;
-            crate::shape_utils::shape_hit_test(shape, point, &local_matrix)
+            let read = self.0.read();
+            if let Some(drawing) = &read.drawing {
+                drawing.hit_test(point, &local_matrix)
+            } else {
+                crate::shape_utils::shape_hit_test(&read.static_data.shape, point, &local_matrix)
+            }
         } else {
             false
         }
     }
+
+    fn as_drawing(&self, gc_context: MutationContext<'gc, '_>) -> Option<RefMut<'_, Drawing>> {
+        // TODO: The timeline shape should be copied into the drawing so that
+        // scripts draw on top of it, rather than replacing it.
+        let mut write = self.0.write(gc_context);
+        if write.drawing.is_none() {
+            write.drawing = Some(Drawing::new());
+        }
+
+        Some(RefMut::map(write, |m| m.drawing.as_mut().unwrap()))
+    }
+
+    fn object2(&self) -> Avm2Value<'gc> {
+        self.0
+            .read()
+            .avm2_object
+            .map(Avm2Value::from)
+            .unwrap_or(Avm2Value::Undefined)
+    }
+
+    fn set_object2(&self, mc: MutationContext<'gc, '_>, to: Avm2Object<'gc>) {
+        self.0.write(mc).avm2_object = Some(to);
+    }
 }
 
 unsafe impl<'gc> gc_arena::Collect for GraphicData<'gc> {
     fn trace(&self, cc: gc_arena::CollectionContext) {
         self.base.trace(cc);
         self.static_data.trace(cc);
+        self.avm2_object.trace(cc);
+        self.drawing.trace(cc);
     }
 }
 
@@ -1,10 +1,11 @@
-use crate::backend::render::ShapeHandle;
+use crate::backend::render::{BitmapSource, ShapeHandle};
 use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::{DisplayObjectBase, TDisplayObject};
 use crate::prelude::*;
 use crate::tag_utils::SwfMovie;
 use crate::types::{Degrees, Percent};
 use gc_arena::{Collect, Gc, GcCell, MutationContext};
+use std::cell::RefCell;
 use std::sync::Arc;
 use swf::Twips;
 
@@ -41,6 +42,11 @@ impl<'gc> MorphShape<'gc> {
     pub fn set_ratio(&mut self, gc_context: MutationContext<'gc, '_>, ratio: u16) {
         self.0.write(gc_context).ratio = ratio;
     }
+
+    /// Precalculate the shape of this morph shape at a given ratio.
+    pub fn register_ratio(self, context: &mut UpdateContext<'_, 'gc, '_>, ratio: u16) {
+        self.0.read().static_data.register_ratio(context, ratio);
+    }
 }
 
 impl<'gc> TDisplayObject<'gc> for MorphShape<'gc> {
@@ -61,7 +67,7 @@ impl<'gc> TDisplayObject<'gc> for MorphShape<'gc> {
     fn render(&self, context: &mut RenderContext) {
         context.transform_stack.push(&*self.transform());
 
-        if let Some(frame) = self.0.read().static_data.frames.get(&self.ratio()) {
+        if let Some(frame) = self.0.read().static_data.frames.borrow().get(&self.ratio()) {
             context
                 .renderer
                 .render_shape(frame.shape_handle, context.transform_stack.transform());
@@ -74,7 +80,7 @@ impl<'gc> TDisplayObject<'gc> for MorphShape<'gc> {
 
     fn self_bounds(&self) -> BoundingBox {
         // TODO: Use the bounds of the current ratio.
-        if let Some(frame) = self.0.read().static_data.frames.get(&self.ratio()) {
+        if let Some(frame) = self.0.read().static_data.frames.borrow().get(&self.ratio()) {
             frame.bounds.clone()
         } else {
             BoundingBox::default()
@@ -87,7 +93,7 @@ impl<'gc> TDisplayObject<'gc> for MorphShape<'gc> {
         point: (Twips, Twips),
     ) -> bool {
         if self.world_bounds().contains(point) {
-            if let Some(frame) = self.0.read().static_data.frames.get(&self.ratio()) {
+            if let Some(frame) = self.0.read().static_data.frames.borrow().get(&self.ratio()) {
                 let local_matrix = self.global_to_local_matrix();
                 let point = local_matrix * point;
                 return crate::shape_utils::shape_hit_test(&frame.shape, point, &local_matrix);
@@ -121,7 +127,7 @@ pub struct MorphShapeStatic {
     id: CharacterId,
     start: swf::MorphShape,
     end: swf::MorphShape,
-    frames: fnv::FnvHashMap<u16, Frame>,
+    frames: RefCell<fnv::FnvHashMap<u16, Frame>>,
     movie: Arc<SwfMovie>,
 }
 
@@ -131,11 +137,11 @@ impl MorphShapeStatic {
         swf_tag: &swf::DefineMorphShape,
         movie: Arc<SwfMovie>,
     ) -> Self {
-        let mut morph_shape = Self {
+        let morph_shape = Self {
             id: swf_tag.id,
             start: swf_tag.start.clone(),
             end: swf_tag.end.clone(),
-            frames: fnv::FnvHashMap::default(),
+            frames: RefCell::new(fnv::FnvHashMap::default()),
             movie,
         };
         // Pre-register the start and end states.
@@ -144,8 +150,8 @@ impl MorphShapeStatic {
         morph_shape
     }
 
-    pub fn register_ratio(&mut self, context: &mut UpdateContext<'_, '_, '_>, ratio: u16) {
-        if self.frames.contains_key(&ratio) {
+    pub fn register_ratio(&self, context: &mut UpdateContext<'_, '_, '_>, ratio: u16) {
+        if self.frames.borrow().contains_key(&ratio) {
             // Already registered.
             return;
         }
@@ -278,11 +284,14 @@ impl MorphShapeStatic {
         };
 
         let frame = Frame {
-            shape_handle: context.renderer.register_shape((&shape).into(), library),
+            shape_handle: context.renderer.register_shape(
+                (&shape).into(),
+                library.map(|library| library as &dyn BitmapSource),
+            ),
             shape,
             bounds: bounds.into(),
         };
-        self.frames.insert(ratio, frame);
+        self.frames.borrow_mut().insert(ratio, frame);
     }
 
     fn update_pos(x: &mut Twips, y: &mut Twips, record: &swf::ShapeRecord) {
@@ -8,13 +8,16 @@ use crate::avm2::{
     StageObject as Avm2StageObject, TObject as Avm2TObject, Value as Avm2Value,
 };
 use crate::backend::audio::AudioStreamHandle;
+use crate::backend::input::MouseCursor;
+use crate::backend::navigator::{url_from_relative_url, RequestOptions};
 
 use crate::avm1::activation::{Activation as Avm1Activation, ActivationIdentifier};
 use crate::character::Character;
 use crate::context::{ActionType, RenderContext, UpdateContext};
 use crate::display_object::container::{ChildContainer, TDisplayObjectContainer};
 use crate::display_object::{
-    Bitmap, Button, DisplayObjectBase, EditText, Graphic, MorphShapeStatic, TDisplayObject, Text,
+    Avm2MousePick, Bitmap, Button, DisplayObjectBase, EditText, Graphic, MorphShape,
+    MorphShapeStatic, TDisplayObject, Text,
 };
 use crate::drawing::Drawing;
 use crate::events::{ButtonKeyCode, ClipEvent, ClipEventResult};
@@ -27,15 +30,28 @@ use crate::vminterface::{AvmObject, AvmType, Instantiator};
 use enumset::{EnumSet, EnumSetType};
 use gc_arena::{Collect, Gc, GcCell, MutationContext};
 use smallvec::SmallVec;
-use std::cell::{Ref, RefCell};
+use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::rc::Rc;
 use std::sync::Arc;
 use swf::read::SwfRead;
 use swf::{FillStyle, FrameLabelData, LineStyle};
 
 type FrameNumber = u16;
 
+/// An `ImportAssets` load that a timeline is waiting on.
+///
+/// The timeline stops waiting when this is dropped, which happens whenever
+/// the load finishes, whether it succeeded, failed or was cancelled.
+pub struct PendingImport(Rc<Cell<u16>>);
+
+impl Drop for PendingImport {
+    fn drop(&mut self) {
+        self.0.set(self.0.get().saturating_sub(1));
+    }
+}
+
 /// A movie clip is a display object with its own timeline that runs independently of the root timeline.
 /// The SWF19 spec calls this "Sprite" and the SWF tag defines it is "DefineSprite".
 /// However, in AVM2, Sprite is a separate display object, and MovieClip is a subclass of Sprite.
@@ -63,6 +79,38 @@ pub struct MovieClipData<'gc> {
     is_focusable: bool,
     has_focus: bool,
     enabled: bool,
+
+    /// The number of `ImportAssets` loads started by this timeline that
+    /// haven't finished yet.
+    pending_imports: Rc<Cell<u16>>,
+
+    /// The first frame that imports assets, which can't play until all
+    /// pending imports have finished.
+    imports_frame: FrameNumber,
+
+    /// A goto to a frame that was waiting on pending imports, which runs as
+    /// soon as they have finished.
+    queued_goto: Option<FrameNumber>,
+
+    /// How far preloading of this clip's tags has got.
+    preload_progress: PreloadProgress,
+}
+
+/// The progress of preloading a clip's tags, which may take several frames.
+#[derive(Clone, Debug, Default)]
+struct PreloadProgress {
+    /// The position of the first tag that hasn't been preloaded yet.
+    tag_pos: u64,
+
+    /// The number of frames whose tags have all been preloaded.
+    frames_loaded: FrameNumber,
+
+    /// The IDs of the morph shapes placed at each depth so far, whose ratios
+    /// are registered as later frames modify them.
+    morph_shape_ids: fnv::FnvHashMap<Depth, CharacterId>,
+
+    /// Whether every tag has been preloaded.
+    complete: bool,
 }
 
 unsafe impl<'gc> Collect for MovieClipData<'gc> {
@@ -99,6 +147,14 @@ impl<'gc> MovieClip<'gc> {
                 is_focusable: false,
                 has_focus: false,
                 enabled: true,
+                pending_imports: Rc::new(Cell::new(0)),
+                imports_frame: 0,
+                queued_goto: None,
+                // Empty clips have nothing to preload.
+                preload_progress: PreloadProgress {
+                    complete: true,
+                    ..Default::default()
+                },
             },
         ))
     }
@@ -108,15 +164,24 @@ impl<'gc> MovieClip<'gc> {
         id: CharacterId,
         swf: SwfSlice,
         num_frames: u16,
+    ) -> Self {
+        Self::with_static_data(gc_context, MovieClipStatic::with_data(id, swf, num_frames))
+    }
+
+    /// Construct a movie clip that represents an entire movie.
+    pub fn from_movie(gc_context: MutationContext<'gc, '_>, movie: Arc<SwfMovie>) -> Self {
+        Self::with_static_data(gc_context, MovieClipStatic::main_timeline(movie))
+    }
+
+    fn with_static_data(
+        gc_context: MutationContext<'gc, '_>,
+        static_data: MovieClipStatic,
     ) -> Self {
         MovieClip(GcCell::allocate(
             gc_context,
             MovieClipData {
                 base: Default::default(),
-                static_data: Gc::allocate(
-                    gc_context,
-                    MovieClipStatic::with_data(id, swf, num_frames),
-                ),
+                static_data: Gc::allocate(gc_context, static_data),
                 tag_stream_pos: 0,
                 current_frame: 0,
                 audio_stream: None,
@@ -131,20 +196,14 @@ impl<'gc> MovieClip<'gc> {
                 is_focusable: false,
                 has_focus: false,
                 enabled: true,
+                pending_imports: Rc::new(Cell::new(0)),
+                imports_frame: 0,
+                queued_goto: None,
+                preload_progress: PreloadProgress::default(),
             },
         ))
     }
 
-    /// Construct a movie clip that represents an entire movie.
-    pub fn from_movie(gc_context: MutationContext<'gc, '_>, movie: Arc<SwfMovie>) -> Self {
-        Self::new_with_data(
-            gc_context,
-            0,
-            movie.clone().into(),
-            movie.header().num_frames,
-        )
-    }
-
     /// Replace the current MovieClip with a completely new SwfMovie.
     ///
     /// Playback will start at position zero, any existing streamed audio will
@@ -160,249 +219,293 @@ impl<'gc> MovieClip<'gc> {
             .replace_with_movie(gc_context, movie)
     }
 
-    pub fn preload(
-        self,
-        context: &mut UpdateContext<'_, 'gc, '_>,
-        morph_shapes: &mut fnv::FnvHashMap<CharacterId, MorphShapeStatic>,
-    ) {
+    /// Preload all of this clip's tags that have loaded but haven't been
+    /// preloaded yet.
+    ///
+    /// While a movie is loading, this should be called whenever more of it
+    /// arrives. Frames only count as loaded once all of their tags have been
+    /// preloaded.
+    ///
+    /// Returns whether the whole clip has been preloaded.
+    pub fn preload(self, context: &mut UpdateContext<'_, 'gc, '_>) -> bool {
         use swf::TagCode;
+        if self.0.read().preload_progress.complete {
+            return true;
+        }
+
         // TODO: Re-creating static data because preload step occurs after construction.
         // Should be able to hoist this up somewhere, or use MaybeUninit.
         let mut static_data = (&*self.0.read().static_data).clone();
-        let data = self.0.read().static_data.swf.clone();
-        let mut reader = data.read_from(self.0.read().tag_stream_pos);
-        let mut cur_frame = 1;
-        let mut ids = fnv::FnvHashMap::default();
+
+        // A movie's main timeline spans all of its data, which grows as the
+        // movie loads. Clips defined by the movie are always loaded in full.
+        let is_main_timeline = static_data.is_main_timeline;
+        let is_loading = is_main_timeline && !static_data.swf.movie.is_loaded();
+        if is_main_timeline {
+            // Tags are sliced out of the clip's own data, so the clip has to
+            // see the same snapshot of the movie as the reader below.
+            static_data.swf = SwfSlice::from(static_data.swf.movie.clone());
+            self.0.write(context.gc_context).static_data =
+                Gc::allocate(context.gc_context, static_data.clone());
+        }
+
+        let data = static_data.swf.clone();
+        let mut progress = std::mem::take(&mut self.0.write(context.gc_context).preload_progress);
+        let mut reader = data.read_from(progress.tag_pos);
+        let mut cur_frame = progress.frames_loaded + 1;
         let version = reader.version();
-        let tag_callback = |reader: &mut SwfStream<&[u8]>, tag_code, tag_len| {
-            let data = *reader.get_inner().get_ref();
-            let tag_pos = reader.get_inner().position() as usize;
-            let tag_slice = data
-                .get(tag_pos..tag_pos + tag_len)
-                .ok_or("Unexpected end of tag")?;
-            let reader = &mut SwfStream::new(std::io::Cursor::new(tag_slice), version);
-            match tag_code {
-                TagCode::FileAttributes => {
-                    let attributes = reader.read_file_attributes()?;
-                    let avm_type = if attributes.is_action_script_3 {
-                        log::warn!("This SWF contains ActionScript 3 which is not yet supported by Ruffle. The movie may not work as intended.");
-                        AvmType::Avm2
-                    } else {
-                        AvmType::Avm1
-                    };
+        loop {
+            if is_loading
+                && !tag_utils::is_frame_loaded(data.data(), reader.get_ref().position(), version)
+            {
+                break;
+            }
 
-                    let movie = self.movie().unwrap();
-                    let library = context.library.library_for_movie_mut(movie);
-                    if let Err(e) = library.check_avm_type(avm_type) {
-                        log::warn!("{}", e);
-                    }
+            let mut reached_end = false;
+            let tag_callback = |reader: &mut SwfStream<&[u8]>, tag_code, tag_len| {
+                let data = *reader.get_inner().get_ref();
+                let tag_pos = reader.get_inner().position() as usize;
+                let tag_slice = data
+                    .get(tag_pos..tag_pos + tag_len)
+                    .ok_or("Unexpected end of tag")?;
+                let reader = &mut SwfStream::new(std::io::Cursor::new(tag_slice), version);
+                match tag_code {
+                    TagCode::FileAttributes => {
+                        let attributes = reader.read_file_attributes()?;
+                        let avm_type = if attributes.is_action_script_3 {
+                            log::warn!("This SWF contains ActionScript 3 which is not yet supported by Ruffle. The movie may not work as intended.");
+                            AvmType::Avm2
+                        } else {
+                            AvmType::Avm1
+                        };
 
-                    Ok(())
-                }
-                TagCode::DefineBits => self
-                    .0
-                    .write(context.gc_context)
-                    .define_bits(context, reader, tag_len),
-                TagCode::DefineBitsJpeg2 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_bits_jpeg_2(context, reader, tag_len),
-                TagCode::DefineBitsJpeg3 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_bits_jpeg_3(context, reader, tag_len),
-                TagCode::DefineBitsJpeg4 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_bits_jpeg_4(context, reader, tag_len),
-                TagCode::DefineBitsLossless => self
-                    .0
-                    .write(context.gc_context)
-                    .define_bits_lossless(context, reader, 1),
-                TagCode::DefineBitsLossless2 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_bits_lossless(context, reader, 2),
-                TagCode::DefineButton => self
-                    .0
-                    .write(context.gc_context)
-                    .define_button_1(context, reader),
-                TagCode::DefineButton2 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_button_2(context, reader),
-                TagCode::DefineButtonCxform => self
-                    .0
-                    .write(context.gc_context)
-                    .define_button_cxform(context, reader, tag_len),
-                TagCode::DefineButtonSound => self
-                    .0
-                    .write(context.gc_context)
-                    .define_button_sound(context, reader),
-                TagCode::DefineEditText => self
-                    .0
-                    .write(context.gc_context)
-                    .define_edit_text(context, reader),
-                TagCode::DefineFont => self
-                    .0
-                    .write(context.gc_context)
-                    .define_font_1(context, reader),
-                TagCode::DefineFont2 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_font_2(context, reader),
-                TagCode::DefineFont3 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_font_3(context, reader),
-                TagCode::DefineFont4 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_font_4(context, reader),
-                TagCode::DefineMorphShape => self.0.write(context.gc_context).define_morph_shape(
-                    context,
-                    reader,
-                    morph_shapes,
-                    1,
-                ),
-                TagCode::DefineMorphShape2 => self.0.write(context.gc_context).define_morph_shape(
-                    context,
-                    reader,
-                    morph_shapes,
-                    2,
-                ),
-                TagCode::DefineShape => self
-                    .0
-                    .write(context.gc_context)
-                    .define_shape(context, reader, 1),
-                TagCode::DefineShape2 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_shape(context, reader, 2),
-                TagCode::DefineShape3 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_shape(context, reader, 3),
-                TagCode::DefineShape4 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_shape(context, reader, 4),
-                TagCode::DefineSound => self
-                    .0
-                    .write(context.gc_context)
-                    .define_sound(context, reader),
-                TagCode::DefineSprite => self.0.write(context.gc_context).define_sprite(
-                    context,
-                    reader,
-                    tag_len,
-                    morph_shapes,
-                ),
-                TagCode::DefineText => self
-                    .0
-                    .write(context.gc_context)
-                    .define_text(context, reader, 1),
-                TagCode::DefineText2 => self
-                    .0
-                    .write(context.gc_context)
-                    .define_text(context, reader, 2),
-                TagCode::DoInitAction => self.do_init_action(context, reader, tag_len),
-                TagCode::DoAbc => self.do_abc(context, reader, tag_len),
-                TagCode::SymbolClass => self.symbol_class(context, reader),
-                TagCode::DefineSceneAndFrameLabelData => {
-                    self.scene_and_frame_labels(reader, &mut static_data)
-                }
-                TagCode::ExportAssets => self
-                    .0
-                    .write(context.gc_context)
-                    .export_assets(context, reader),
-                TagCode::FrameLabel => self.0.write(context.gc_context).frame_label(
-                    context,
-                    reader,
-                    tag_len,
-                    cur_frame,
-                    &mut static_data,
-                ),
-                TagCode::JpegTables => self
-                    .0
-                    .write(context.gc_context)
-                    .jpeg_tables(context, reader, tag_len),
-                TagCode::PlaceObject => self.0.write(context.gc_context).preload_place_object(
-                    context,
-                    reader,
-                    tag_len,
-                    &mut ids,
-                    morph_shapes,
-                    1,
-                ),
-                TagCode::PlaceObject2 => self.0.write(context.gc_context).preload_place_object(
-                    context,
-                    reader,
-                    tag_len,
-                    &mut ids,
-                    morph_shapes,
-                    2,
-                ),
-                TagCode::PlaceObject3 => self.0.write(context.gc_context).preload_place_object(
-                    context,
-                    reader,
-                    tag_len,
-                    &mut ids,
-                    morph_shapes,
-                    3,
-                ),
-                TagCode::PlaceObject4 => self.0.write(context.gc_context).preload_place_object(
-                    context,
-                    reader,
-                    tag_len,
-                    &mut ids,
-                    morph_shapes,
-                    4,
-                ),
-                TagCode::RemoveObject => self
-                    .0
-                    .write(context.gc_context)
-                    .preload_remove_object(context, reader, &mut ids, 1),
-                TagCode::RemoveObject2 => self
-                    .0
-                    .write(context.gc_context)
-                    .preload_remove_object(context, reader, &mut ids, 2),
-                TagCode::ShowFrame => self.0.write(context.gc_context).preload_show_frame(
-                    context,
-                    reader,
-                    &mut cur_frame,
-                ),
-                TagCode::ScriptLimits => self
-                    .0
-                    .write(context.gc_context)
-                    .script_limits(reader, context.avm1),
-                TagCode::SoundStreamHead => self
-                    .0
-                    .write(context.gc_context)
-                    .preload_sound_stream_head(context, reader, cur_frame, &mut static_data, 1),
-                TagCode::SoundStreamHead2 => self
-                    .0
-                    .write(context.gc_context)
-                    .preload_sound_stream_head(context, reader, cur_frame, &mut static_data, 2),
-                TagCode::SoundStreamBlock => {
-                    self.0.write(context.gc_context).preload_sound_stream_block(
+                        let movie = self.movie().unwrap();
+                        let library = context.library.library_for_movie_mut(movie);
+                        if let Err(e) = library.check_avm_type(avm_type) {
+                            log::warn!("{}", e);
+                        }
+
+                        Ok(())
+                    }
+                    TagCode::DefineBits => self
+                        .0
+                        .write(context.gc_context)
+                        .define_bits(context, reader, tag_len),
+                    TagCode::DefineBitsJpeg2 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_bits_jpeg_2(context, reader, tag_len),
+                    TagCode::DefineBitsJpeg3 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_bits_jpeg_3(context, reader, tag_len),
+                    TagCode::DefineBitsJpeg4 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_bits_jpeg_4(context, reader, tag_len),
+                    TagCode::DefineBitsLossless => self
+                        .0
+                        .write(context.gc_context)
+                        .define_bits_lossless(context, reader, 1),
+                    TagCode::DefineBitsLossless2 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_bits_lossless(context, reader, 2),
+                    TagCode::DefineButton => self
+                        .0
+                        .write(context.gc_context)
+                        .define_button_1(context, reader),
+                    TagCode::DefineButton2 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_button_2(context, reader),
+                    TagCode::DefineButtonCxform => self
+                        .0
+                        .write(context.gc_context)
+                        .define_button_cxform(context, reader, tag_len),
+                    TagCode::DefineButtonSound => self
+                        .0
+                        .write(context.gc_context)
+                        .define_button_sound(context, reader),
+                    TagCode::DefineEditText => self
+                        .0
+                        .write(context.gc_context)
+                        .define_edit_text(context, reader),
+                    TagCode::DefineFont => self
+                        .0
+                        .write(context.gc_context)
+                        .define_font_1(context, reader),
+                    TagCode::DefineFont2 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_font_2(context, reader),
+                    TagCode::DefineFont3 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_font_3(context, reader),
+                    TagCode::DefineFont4 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_font_4(context, reader),
+                    TagCode::DefineMorphShape => self
+                        .0
+                        .write(context.gc_context)
+                        .define_morph_shape(context, reader, 1),
+                    TagCode::DefineMorphShape2 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_morph_shape(context, reader, 2),
+                    TagCode::DefineShape => self
+                        .0
+                        .write(context.gc_context)
+                        .define_shape(context, reader, 1),
+                    TagCode::DefineShape2 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_shape(context, reader, 2),
+                    TagCode::DefineShape3 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_shape(context, reader, 3),
+                    TagCode::DefineShape4 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_shape(context, reader, 4),
+                    TagCode::DefineSound => self
+                        .0
+                        .write(context.gc_context)
+                        .define_sound(context, reader),
+                    TagCode::DefineSprite => self
+                        .0
+                        .write(context.gc_context)
+                        .define_sprite(context, reader, tag_len),
+                    TagCode::DefineText => self
+                        .0
+                        .write(context.gc_context)
+                        .define_text(context, reader, 1),
+                    TagCode::DefineText2 => self
+                        .0
+                        .write(context.gc_context)
+                        .define_text(context, reader, 2),
+                    TagCode::DoInitAction => self.do_init_action(context, reader, tag_len),
+                    TagCode::DoAbc => self.do_abc(context, reader, tag_len),
+                    TagCode::SymbolClass => self.symbol_class(context, reader),
+                    TagCode::DefineSceneAndFrameLabelData => {
+                        self.scene_and_frame_labels(reader, &mut static_data)
+                    }
+                    TagCode::ExportAssets => self
+                        .0
+                        .write(context.gc_context)
+                        .export_assets(context, reader),
+                    TagCode::ImportAssets => self.import_assets(context, reader, cur_frame, 1),
+                    TagCode::ImportAssets2 => self.import_assets(context, reader, cur_frame, 2),
+                    TagCode::FrameLabel => self.0.write(context.gc_context).frame_label(
                         context,
                         reader,
+                        tag_len,
                         cur_frame,
                         &mut static_data,
+                    ),
+                    TagCode::JpegTables => self
+                        .0
+                        .write(context.gc_context)
+                        .jpeg_tables(context, reader, tag_len),
+                    TagCode::PlaceObject => self.0.write(context.gc_context).preload_place_object(
+                        context,
+                        reader,
                         tag_len,
-                    )
+                        &mut progress.morph_shape_ids,
+                        1,
+                    ),
+                    TagCode::PlaceObject2 => self.0.write(context.gc_context).preload_place_object(
+                        context,
+                        reader,
+                        tag_len,
+                        &mut progress.morph_shape_ids,
+                        2,
+                    ),
+                    TagCode::PlaceObject3 => self.0.write(context.gc_context).preload_place_object(
+                        context,
+                        reader,
+                        tag_len,
+                        &mut progress.morph_shape_ids,
+                        3,
+                    ),
+                    TagCode::PlaceObject4 => self.0.write(context.gc_context).preload_place_object(
+                        context,
+                        reader,
+                        tag_len,
+                        &mut progress.morph_shape_ids,
+                        4,
+                    ),
+                    TagCode::RemoveObject => self
+                        .0
+                        .write(context.gc_context)
+                        .preload_remove_object(context, reader, &mut progress.morph_shape_ids, 1),
+                    TagCode::RemoveObject2 => self
+                        .0
+                        .write(context.gc_context)
+                        .preload_remove_object(context, reader, &mut progress.morph_shape_ids, 2),
+                    TagCode::ShowFrame => self.0.write(context.gc_context).preload_show_frame(
+                        context,
+                        reader,
+                        &mut cur_frame,
+                    ),
+                    TagCode::ScriptLimits => self
+                        .0
+                        .write(context.gc_context)
+                        .script_limits(reader, context.avm1),
+                    TagCode::SoundStreamHead => self
+                        .0
+                        .write(context.gc_context)
+                        .preload_sound_stream_head(context, reader, cur_frame, &mut static_data, 1),
+                    TagCode::SoundStreamHead2 => self
+                        .0
+                        .write(context.gc_context)
+                        .preload_sound_stream_head(context, reader, cur_frame, &mut static_data, 2),
+                    TagCode::SoundStreamBlock => {
+                        self.0.write(context.gc_context).preload_sound_stream_block(
+                            context,
+                            reader,
+                            cur_frame,
+                            &mut static_data,
+                            tag_len,
+                        )
+                    }
+                    TagCode::End => {
+                        reached_end = true;
+                        Ok(())
+                    }
+                    _ => Ok(()),
                 }
-                _ => Ok(()),
+            };
+            let result = tag_utils::decode_tags(&mut reader, tag_callback, TagCode::ShowFrame);
+            if result.is_err() || reached_end {
+                // Instances of this clip are copied from it, so don't keep
+                // anything around that they won't need.
+                progress = PreloadProgress {
+                    complete: true,
+                    ..Default::default()
+                };
+                break;
             }
-        };
-        let _ = tag_utils::decode_tags(&mut reader, tag_callback, TagCode::End);
-        self.0.write(context.gc_context).static_data =
-            Gc::allocate(context.gc_context, static_data);
+
+            progress.tag_pos = reader.get_ref().position();
+            progress.frames_loaded = cur_frame - 1;
+        }
+
+        let complete = progress.complete;
+        let mut mc = self.0.write(context.gc_context);
+        mc.static_data = Gc::allocate(context.gc_context, static_data);
+        mc.preload_progress = progress;
+        drop(mc);
 
         // Finalize audio stream.
-        if self.0.read().static_data.audio_stream_info.is_some() {
+        if complete && self.0.read().static_data.audio_stream_info.is_some() {
             context.audio.preload_sound_stream_end(self.0.read().id());
         }
+
+        complete
     }
 
     #[inline]
@@ -450,6 +553,63 @@ impl<'gc> MovieClip<'gc> {
         Ok(())
     }
 
+    #[inline]
+    fn import_assets(
+        self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        reader: &mut SwfStream<&[u8]>,
+        cur_frame: FrameNumber,
+        version: u8,
+    ) -> DecodeResult {
+        let (url, imports) = if version == 1 {
+            reader.read_import_assets()?
+        } else {
+            reader.read_import_assets_2()?
+        };
+
+        let player = match context.player.clone() {
+            Some(player) => player,
+            None => {
+                log::warn!(
+                    "Unable to import assets from {}: No player to load into",
+                    url
+                );
+                return Ok(());
+            }
+        };
+
+        // Shared libraries are located relative to the importing movie.
+        let url = self
+            .movie()
+            .and_then(|movie| movie.url().map(str::to_owned))
+            .and_then(|base| url_from_relative_url(&base, &url).ok())
+            .map(String::from)
+            .unwrap_or(url);
+
+        let mc = self.0.read();
+        let pending_imports = mc.pending_imports.clone();
+        drop(mc);
+
+        if pending_imports.get() == 0 {
+            self.0.write(context.gc_context).imports_frame = cur_frame;
+        }
+        pending_imports.set(pending_imports.get() + 1);
+
+        let fetch = context.navigator.fetch(&url, RequestOptions::get());
+        let process = context.load_manager.load_import_assets(
+            player,
+            self,
+            PendingImport(pending_imports),
+            fetch,
+            url,
+            imports,
+        );
+
+        context.navigator.spawn_future(process);
+
+        Ok(())
+    }
+
     #[inline]
     fn do_abc(
         self,
@@ -489,6 +649,7 @@ impl<'gc> MovieClip<'gc> {
             })?;
 
         if let Err(e) = Avm2::load_abc(slice, &name, is_lazy_initialize, context, domain) {
+            context.avm2.clear_error();
             log::warn!("Error loading ABC file: {}", e);
         }
 
@@ -507,6 +668,7 @@ impl<'gc> MovieClip<'gc> {
         let mut activation = Avm2Activation::from_nothing(context.reborrow());
 
         let num_symbols = reader.read_u16()?;
+        let mut has_document_class = false;
 
         for _ in 0..num_symbols {
             let id = reader.read_u16()?;
@@ -534,9 +696,24 @@ impl<'gc> MovieClip<'gc> {
                         if id == 0 {
                             //TODO: This assumes only the root movie has `SymbolClass` tags.
                             self.set_avm2_constructor(activation.context.gc_context, Some(proto));
-                            self.construct_as_avm2_object(&mut activation.context, self.into());
+                            has_document_class = true;
                         } else if let Some(Character::MovieClip(mc)) = library.character_by_id(id) {
                             mc.set_avm2_constructor(activation.context.gc_context, Some(proto))
+                        } else if let Some(Character::Bitmap(bitmap)) = library.character_by_id(id)
+                        {
+                            let bitmap = Character::Bitmap(*bitmap);
+                            activation
+                                .context
+                                .library
+                                .avm2_class_registry_mut()
+                                .set_class_symbol(proto, bitmap);
+                        } else if let Some(Character::Sound(sound)) = library.character_by_id(id) {
+                            let sound = Character::Sound(*sound);
+                            activation
+                                .context
+                                .library
+                                .avm2_class_registry_mut()
+                                .set_class_symbol(proto, sound);
                         } else {
                             log::warn!(
                                 "Symbol class {} cannot be assigned to invalid character id {}",
@@ -545,15 +722,24 @@ impl<'gc> MovieClip<'gc> {
                             );
                         }
                     }
-                    Err(e) => log::warn!(
-                        "Got AVM2 error {} when attempting to assign symbol class {}",
-                        e,
-                        class_name
-                    ),
+                    Err(e) => {
+                        activation.context.avm2.clear_error();
+                        log::warn!(
+                            "Got AVM2 error {} when attempting to assign symbol class {}",
+                            e,
+                            class_name
+                        );
+                    }
                 }
             }
         }
 
+        // The document class may use any of the other symbols, so it is only
+        // constructed once they have all been bound to their classes.
+        if has_document_class {
+            self.construct_as_avm2_object(&mut activation.context, self.into());
+        }
+
         Ok(())
     }
 
@@ -826,9 +1012,39 @@ impl<'gc> MovieClip<'gc> {
         self.0.read().static_data.total_frames
     }
 
+    /// Whether this clip has run its first frame since its movie was last
+    /// replaced.
+    pub fn initialized(self) -> bool {
+        self.0.read().initialized()
+    }
+
     pub fn frames_loaded(self) -> FrameNumber {
-        // TODO(Herschel): root needs to progressively stream in frames.
-        self.0.read().static_data.total_frames
+        self.0.read().frames_loaded()
+    }
+
+    /// The number of bytes of this clip's movie that have been loaded.
+    pub fn loaded_bytes(self) -> u32 {
+        self.0.read().movie().loaded_length()
+    }
+
+    /// Run a goto that was waiting for imports to finish loading, once they
+    /// have. Returns whether a goto was run.
+    fn run_queued_goto(self, context: &mut UpdateContext<'_, 'gc, '_>) -> bool {
+        let queued_goto = {
+            let mut mc = self.0.write(context.gc_context);
+            if mc.pending_imports.get() == 0 {
+                mc.queued_goto.take()
+            } else {
+                None
+            }
+        };
+
+        if let Some(frame) = queued_goto {
+            self.run_goto(self.into(), context, frame);
+            true
+        } else {
+            false
+        }
     }
 
     pub fn set_avm2_constructor(
@@ -973,6 +1189,16 @@ impl<'gc> MovieClip<'gc> {
         mc.drawing.draw_command(command);
     }
 
+    /// Test if a stage position hits this clip's own drawing, ignoring its
+    /// children.
+    pub fn hit_test_drawing(self, point: (Twips, Twips)) -> bool {
+        let local_matrix = self.global_to_local_matrix();
+        self.0
+            .read()
+            .drawing
+            .hit_test(local_matrix * point, &local_matrix)
+    }
+
     pub fn run_clip_event(
         self,
         context: &mut crate::context::UpdateContext<'_, 'gc, '_>,
@@ -989,6 +1215,12 @@ impl<'gc> MovieClip<'gc> {
         context: &mut UpdateContext<'_, 'gc, '_>,
         run_display_actions: bool,
     ) {
+        // Wait for the next frame to load before playing it.
+        let frames_loaded = self.frames_loaded();
+        if self.current_frame() >= frames_loaded && frames_loaded < self.total_frames() {
+            return;
+        }
+
         // Advance frame number.
         if self.current_frame() < self.total_frames() {
             self.0.write(context.gc_context).current_frame += 1;
@@ -1113,6 +1345,7 @@ impl<'gc> MovieClip<'gc> {
                     );
                     let mut activation = Avm2Activation::from_nothing(context.reborrow());
                     if let Err(e) = p.init_property(p, &name, c.into(), &mut activation) {
+                        activation.context.avm2.clear_error();
                         log::error!(
                             "Got error when setting AVM2 child named \"{}\": {}",
                             &child.name(),
@@ -1148,6 +1381,13 @@ impl<'gc> MovieClip<'gc> {
         //    the goto frame, so we should instead aggregate the deltas into a final list
         //    of commands, and THEN modify the children as necessary.
 
+        // Frames that import assets can't be entered until the imports have
+        // finished loading, so go there once they have.
+        if frame > self.frames_loaded() && self.0.read().pending_imports.get() > 0 {
+            self.0.write(context.gc_context).queued_goto = Some(frame);
+            return;
+        }
+
         // This map will maintain a map of depth -> placement commands.
         // TODO: Move this to UpdateContext to avoid allocations.
         let mut goto_commands = vec![];
@@ -1198,12 +1438,11 @@ impl<'gc> MovieClip<'gc> {
         let mut index = 0;
 
         let len = mc.tag_stream_len() as u64;
-        // Sanity; let's make sure we don't seek way too far.
-        // TODO: This should be self.frames_loaded() when we implement that.
-        let clamped_frame = if frame <= mc.total_frames() {
+        // Sanity; let's make sure we don't seek past the frames loaded so far.
+        let clamped_frame = if frame <= mc.frames_loaded() {
             frame
         } else {
-            mc.total_frames()
+            mc.frames_loaded()
         };
         drop(mc);
 
@@ -1510,6 +1749,7 @@ impl<'gc> MovieClip<'gc> {
         if let Ok(object) = result {
             self.0.write(context.gc_context).object = Some(object.into());
         } else if let Err(e) = result {
+            context.avm2.clear_error();
             log::error!("Got {} when constructing AVM2 side of display object", e);
         }
     }
@@ -1641,7 +1881,7 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
         drop(mc);
 
         // Run my SWF tags.
-        if self.playing() {
+        if !self.run_queued_goto(context) && self.playing() {
             self.run_frame_internal((*self).into(), context, true);
         }
 
@@ -1665,6 +1905,10 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
         self.0.read().drawing.self_bounds()
     }
 
+    fn self_edge_bounds(&self) -> BoundingBox {
+        self.0.read().drawing.self_edge_bounds()
+    }
+
     fn hit_test_bounds(&self, point: (Twips, Twips)) -> bool {
         self.world_bounds().contains(point)
     }
@@ -1676,7 +1920,7 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
     ) -> bool {
         if self.world_bounds().contains(point) {
             for child in self.iter_execution_list() {
-                if child.hit_test_shape(context, point) {
+                if child.hit_test_masker(context, point) && child.hit_test_shape(context, point) {
                     return true;
                 }
             }
@@ -1697,7 +1941,8 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
         self_node: DisplayObject<'gc>,
         point: (Twips, Twips),
     ) -> Option<DisplayObject<'gc>> {
-        if self.visible() {
+        // Masks are not visible, and points outside our mask can't hit us.
+        if self.visible() && self.maskee().is_none() && self.hit_test_masker(context, point) {
             if self.world_bounds().contains(point) {
                 // This movieclip operates in "button mode" if it has a mouse handler,
                 // either via on(..) or via property mc.onRelease, etc.
@@ -1735,6 +1980,70 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
         None
     }
 
+    fn mouse_pick_avm2(
+        &self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        self_node: DisplayObject<'gc>,
+        point: (Twips, Twips),
+    ) -> Avm2MousePick<'gc> {
+        if !self.visible() || self.maskee().is_some() || !self.hit_test_masker(context, point) {
+            return Avm2MousePick::Miss;
+        }
+
+        // Children are tested front to back. A hit on a non-interactive child
+        // belongs to us, but an interactive child that may not take the event
+        // lets objects beneath it have it first.
+        let mut propagated = false;
+        for child in self.iter_render_list().rev() {
+            if child.as_movie_clip().is_some() || child.as_edit_text().is_some() {
+                match child.mouse_pick_avm2(context, child, point) {
+                    Avm2MousePick::Hit(target) => {
+                        return Avm2MousePick::Hit(target).combine_with_parent(self_node)
+                    }
+                    Avm2MousePick::PropagateToParent => propagated = true,
+                    Avm2MousePick::Miss => (),
+                }
+            } else if child.visible()
+                && child.maskee().is_none()
+                && child.hit_test_masker(context, point)
+                && child.hit_test_shape(context, point)
+            {
+                if self.mouse_enabled() {
+                    return Avm2MousePick::Hit(self_node);
+                }
+
+                propagated = true;
+            }
+        }
+
+        if propagated {
+            return Avm2MousePick::PropagateToParent.combine_with_parent(self_node);
+        }
+
+        if self.world_bounds().contains(point) && self.hit_test_drawing(point) {
+            return if self.mouse_enabled() {
+                Avm2MousePick::Hit(self_node)
+            } else {
+                Avm2MousePick::PropagateToParent
+            };
+        }
+
+        Avm2MousePick::Miss
+    }
+
+    fn mouse_cursor(&self) -> MouseCursor {
+        // AVM2 clips only show a hand cursor in button mode.
+        if let Avm2Value::Object(_) = self.object2() {
+            if self.button_mode() && self.use_hand_cursor() {
+                MouseCursor::Hand
+            } else {
+                MouseCursor::Arrow
+            }
+        } else {
+            MouseCursor::Hand
+        }
+    }
+
     fn handle_clip_event(
         &self,
         context: &mut UpdateContext<'_, 'gc, '_>,
@@ -1767,6 +2076,10 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
         Some(self.into())
     }
 
+    fn as_drawing(&self, gc_context: MutationContext<'gc, '_>) -> Option<RefMut<'_, Drawing>> {
+        Some(RefMut::map(self.0.write(gc_context), |s| &mut s.drawing))
+    }
+
     fn post_instantiation(
         &self,
         context: &mut UpdateContext<'_, 'gc, '_>,
@@ -1816,6 +2129,10 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
             .unwrap_or(Avm2Value::Undefined)
     }
 
+    fn set_object2(&self, mc: MutationContext<'gc, '_>, to: Avm2Object<'gc>) {
+        self.0.write(mc).object = Some(to.into());
+    }
+
     fn unload(&self, context: &mut UpdateContext<'_, 'gc, '_>) {
         for child in self.iter_execution_list() {
             child.unload(context);
@@ -1843,7 +2160,7 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
     }
 
     fn allow_as_mask(&self) -> bool {
-        !self.is_empty()
+        !self.is_empty() || !self.0.read().drawing.is_empty()
     }
 
     fn is_focusable(&self) -> bool {
@@ -1874,18 +2191,18 @@ impl<'gc> MovieClipData<'gc> {
         movie: Option<Arc<SwfMovie>>,
     ) {
         let movie = movie.unwrap_or_else(|| Arc::new(SwfMovie::empty(self.movie().version())));
-        let total_frames = movie.header().num_frames;
 
         self.base.reset_for_movie_load();
-        self.static_data = Gc::allocate(
-            gc_context,
-            MovieClipStatic::with_data(0, movie.into(), total_frames),
-        );
+        self.static_data = Gc::allocate(gc_context, MovieClipStatic::main_timeline(movie));
         self.tag_stream_pos = 0;
         self.flags = MovieClipFlags::Playing.into();
         self.current_frame = 0;
         self.audio_stream = None;
         self.container = ChildContainer::new();
+        // Imports of the previous movie no longer hold this timeline back.
+        self.pending_imports = Rc::new(Cell::new(0));
+        self.queued_goto = None;
+        self.preload_progress = PreloadProgress::default();
     }
 
     fn id(&self) -> CharacterId {
@@ -1900,6 +2217,24 @@ impl<'gc> MovieClipData<'gc> {
         self.static_data.total_frames
     }
 
+    /// The number of frames whose tags are available to play.
+    ///
+    /// Frames are available once they have been preloaded, unless they
+    /// import assets that are still loading.
+    fn frames_loaded(&self) -> FrameNumber {
+        let frames_loaded = if self.preload_progress.complete {
+            self.static_data.total_frames
+        } else {
+            self.preload_progress.frames_loaded
+        };
+
+        if self.pending_imports.get() > 0 {
+            frames_loaded.min(self.imports_frame.saturating_sub(1))
+        } else {
+            frames_loaded
+        }
+    }
+
     fn playing(&self) -> bool {
         self.flags.contains(MovieClipFlags::Playing)
     }
@@ -2126,13 +2461,15 @@ impl<'gc, 'a> MovieClipData<'gc> {
         &mut self,
         context: &mut UpdateContext<'_, 'gc, '_>,
         reader: &mut SwfStream<&'a [u8]>,
-        morph_shapes: &mut fnv::FnvHashMap<CharacterId, MorphShapeStatic>,
         version: u8,
     ) -> DecodeResult {
-        // Certain backends may have to preload morph shape frames, so defer registering until the end.
         let swf_shape = reader.read_define_morph_shape(version)?;
-        let morph_shape = MorphShapeStatic::from_swf_tag(context, &swf_shape, self.movie());
-        morph_shapes.insert(swf_shape.id, morph_shape);
+        let static_data = MorphShapeStatic::from_swf_tag(context, &swf_shape, self.movie());
+        let morph_shape = MorphShape::new(context.gc_context, static_data);
+        context
+            .library
+            .library_for_movie_mut(self.movie())
+            .register_character(swf_shape.id, Character::MorphShape(morph_shape));
         Ok(())
     }
 
@@ -2160,7 +2497,6 @@ impl<'gc, 'a> MovieClipData<'gc> {
         reader: &mut SwfStream<&'a [u8]>,
         tag_len: usize,
         ids: &mut fnv::FnvHashMap<Depth, CharacterId>,
-        morph_shapes: &mut fnv::FnvHashMap<CharacterId, MorphShapeStatic>,
         version: u8,
     ) -> DecodeResult {
         use swf::PlaceObjectAction;
@@ -2171,7 +2507,7 @@ impl<'gc, 'a> MovieClipData<'gc> {
         }?;
         match place_object.action {
             PlaceObjectAction::Place(id) => {
-                if let Some(morph_shape) = morph_shapes.get_mut(&id) {
+                if let Some(morph_shape) = self.morph_shape(context, id) {
                     ids.insert(place_object.depth.into(), id);
                     if let Some(ratio) = place_object.ratio {
                         morph_shape.register_ratio(context, ratio);
@@ -2180,7 +2516,7 @@ impl<'gc, 'a> MovieClipData<'gc> {
             }
             PlaceObjectAction::Modify => {
                 if let Some(&id) = ids.get(&place_object.depth.into()) {
-                    if let Some(morph_shape) = morph_shapes.get_mut(&id) {
+                    if let Some(morph_shape) = self.morph_shape(context, id) {
                         ids.insert(place_object.depth.into(), id);
                         if let Some(ratio) = place_object.ratio {
                             morph_shape.register_ratio(context, ratio);
@@ -2189,7 +2525,7 @@ impl<'gc, 'a> MovieClipData<'gc> {
                 }
             }
             PlaceObjectAction::Replace(id) => {
-                if let Some(morph_shape) = morph_shapes.get_mut(&id) {
+                if let Some(morph_shape) = self.morph_shape(context, id) {
                     ids.insert(place_object.depth.into(), id);
                     if let Some(ratio) = place_object.ratio {
                         morph_shape.register_ratio(context, ratio);
@@ -2203,6 +2539,22 @@ impl<'gc, 'a> MovieClipData<'gc> {
         Ok(())
     }
 
+    /// The morph shape defined in this clip's movie with the given ID, if any.
+    fn morph_shape(
+        &self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        id: CharacterId,
+    ) -> Option<MorphShape<'gc>> {
+        match context
+            .library
+            .library_for_movie(self.movie())?
+            .character_by_id(id)
+        {
+            Some(Character::MorphShape(morph_shape)) => Some(*morph_shape),
+            _ => None,
+        }
+    }
+
     #[inline]
     fn preload_sound_stream_block(
         &mut self,
@@ -2603,7 +2955,6 @@ impl<'gc, 'a> MovieClipData<'gc> {
         context: &mut UpdateContext<'_, 'gc, '_>,
         reader: &mut SwfStream<&'a [u8]>,
         tag_len: usize,
-        morph_shapes: &mut fnv::FnvHashMap<CharacterId, MorphShapeStatic>,
     ) -> DecodeResult {
         let id = reader.read_character_id()?;
         let num_frames = reader.read_u16()?;
@@ -2622,7 +2973,7 @@ impl<'gc, 'a> MovieClipData<'gc> {
             num_frames,
         );
 
-        movie_clip.preload(context, morph_shapes);
+        movie_clip.preload(context);
 
         context
             .library
@@ -2973,6 +3324,9 @@ struct MovieClipStatic {
     /// The last known symbol name under which this movie clip was exported.
     /// Used for looking up constructors registered with `Object.registerClass`.
     exported_name: RefCell<Option<String>>,
+    /// Whether this clip is the main timeline of its movie, rather than a
+    /// sprite defined by it.
+    is_main_timeline: bool,
 }
 
 impl MovieClipStatic {
@@ -2989,6 +3343,16 @@ impl MovieClipStatic {
             scene_labels: HashMap::new(),
             audio_stream_info: None,
             exported_name: RefCell::new(None),
+            is_main_timeline: false,
+        }
+    }
+
+    /// The static data of the main timeline of a movie.
+    fn main_timeline(movie: Arc<SwfMovie>) -> Self {
+        let total_frames = movie.header().num_frames;
+        Self {
+            is_main_timeline: true,
+            ..Self::with_data(0, movie.into(), total_frames)
         }
     }
 }
@@ -3141,7 +3505,6 @@ impl ClipAction {
     ) -> impl Iterator<Item = Self> {
         use swf::ClipEventFlag;
 
-        let len = other.action_data.len();
         let key_code = other.key_code;
         let movie = Arc::new(movie.from_movie_and_subdata(other.action_data, &movie));
         other.events.into_iter().map(move |event| Self {
@@ -3170,11 +3533,7 @@ impl ClipAction {
                 ClipEventFlag::ReleaseOutside => ClipEvent::ReleaseOutside,
                 ClipEventFlag::Unload => ClipEvent::Unload,
             },
-            action_data: SwfSlice {
-                movie: Arc::clone(&movie),
-                start: 0,
-                end: len,
-            },
+            action_data: SwfSlice::from(Arc::clone(&movie)),
         })
     }
 }
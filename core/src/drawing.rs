@@ -1,10 +1,10 @@
-use crate::backend::render::ShapeHandle;
+use crate::backend::render::{BitmapHandle, BitmapSource, ShapeHandle};
 use crate::bounding_box::BoundingBox;
 use crate::context::RenderContext;
 use crate::shape_utils::{DistilledShape, DrawCommand, DrawPath};
 use gc_arena::Collect;
 use std::cell::Cell;
-use swf::{FillStyle, LineStyle, Twips};
+use swf::{CharacterId, FillStyle, LineStyle, Twips};
 
 #[derive(Clone, Debug, Collect)]
 #[collect(require_static)]
@@ -17,6 +17,7 @@ pub struct Drawing {
     lines: Vec<(LineStyle, Vec<DrawCommand>)>,
     current_fill: Option<(FillStyle, Vec<DrawCommand>)>,
     current_line: Option<(LineStyle, Vec<DrawCommand>)>,
+    bitmaps: Vec<BitmapHandle>,
     cursor: (Twips, Twips),
 }
 
@@ -31,6 +32,7 @@ impl Drawing {
             lines: Vec::new(),
             current_fill: None,
             current_line: None,
+            bitmaps: Vec::new(),
             cursor: (Twips::zero(), Twips::zero()),
         }
     }
@@ -54,17 +56,35 @@ impl Drawing {
         self.dirty.set(true);
     }
 
+    /// Whether nothing has been drawn since this drawing was last cleared.
+    pub fn is_empty(&self) -> bool {
+        self.fills.is_empty()
+            && self.lines.is_empty()
+            && self.current_fill.is_none()
+            && self.current_line.is_none()
+    }
+
     pub fn clear(&mut self) {
         self.current_fill = None;
         self.current_line = None;
         self.fills.clear();
         self.lines.clear();
+        self.bitmaps.clear();
         self.edge_bounds = BoundingBox::default();
         self.shape_bounds = BoundingBox::default();
         self.dirty.set(true);
         self.cursor = (Twips::zero(), Twips::zero());
     }
 
+    /// Make a bitmap available to this drawing's bitmap fills.
+    ///
+    /// Returns the ID that fill styles should use to refer to the bitmap.
+    pub fn add_bitmap(&mut self, bitmap: BitmapHandle) -> CharacterId {
+        let id = self.bitmaps.len() as CharacterId;
+        self.bitmaps.push(bitmap);
+        id
+    }
+
     pub fn set_line_style(&mut self, style: Option<LineStyle>) {
         if let Some(existing) = self.current_line.take() {
             self.lines.push(existing);
@@ -82,6 +102,25 @@ impl Drawing {
         self.dirty.set(true);
     }
 
+    /// Replace the fill used by the current line style, such as for a gradient
+    /// stroke.
+    ///
+    /// This has no effect if there is no current line style.
+    pub fn set_line_fill_style(&mut self, fill_style: FillStyle) {
+        if let Some((style, _)) = &self.current_line {
+            let style = LineStyle {
+                fill_style: Some(fill_style),
+                ..style.clone()
+            };
+            self.set_line_style(Some(style));
+        }
+    }
+
+    /// The position that the next drawing command will start from.
+    pub fn cursor(&self) -> (Twips, Twips) {
+        self.cursor
+    }
+
     pub fn draw_command(&mut self, command: DrawCommand) {
         let mut include_last = false;
         let stroke_width = if let Some((style, _)) = &self.current_line {
@@ -181,10 +220,10 @@ impl Drawing {
             };
 
             if let Some(handle) = self.render_handle.get() {
-                context.renderer.replace_shape(shape, None, handle);
+                context.renderer.replace_shape(shape, Some(self), handle);
             } else {
                 self.render_handle
-                    .set(Some(context.renderer.register_shape(shape, None)));
+                    .set(Some(context.renderer.register_shape(shape, Some(self))));
             }
         }
 
@@ -199,9 +238,14 @@ impl Drawing {
         self.shape_bounds.clone()
     }
 
+    /// The bounds of this drawing, leaving out the width of its strokes.
+    pub fn self_edge_bounds(&self) -> BoundingBox {
+        self.edge_bounds.clone()
+    }
+
     pub fn hit_test(&self, point: (Twips, Twips), local_matrix: &swf::Matrix) -> bool {
         use crate::shape_utils;
-        for path in &self.fills {
+        for path in self.fills.iter().chain(self.current_fill.iter()) {
             if shape_utils::draw_command_fill_hit_test(&path.1, point) {
                 return true;
             }
@@ -227,6 +271,12 @@ impl Drawing {
     }
 }
 
+impl BitmapSource for Drawing {
+    fn bitmap_handle(&self, id: CharacterId) -> Option<BitmapHandle> {
+        self.bitmaps.get(usize::from(id)).copied()
+    }
+}
+
 fn stretch_bounding_box(
     bounding_box: &mut BoundingBox,
     command: &DrawCommand,
@@ -235,6 +235,12 @@ pub trait ExternalInterfaceProvider {
     fn get_method(&self, name: &str) -> Option<Box<dyn ExternalInterfaceMethod>>;
 
     fn on_callback_available(&self, name: &str);
+
+    /// Called when the content sends an FSCommand that isn't handled by the
+    /// player itself. Returns whether the command was handled.
+    fn on_fs_command(&self, _command: &str, _args: &str) -> bool {
+        false
+    }
 }
 
 pub trait ExternalInterfaceMethod {
@@ -292,6 +298,14 @@ impl<'gc> ExternalInterface<'gc> {
         None
     }
 
+    /// Forward an FSCommand to the providers, stopping at the first one that
+    /// handles it.
+    pub fn invoke_fs_command(&self, command: &str, args: &str) -> bool {
+        self.providers
+            .iter()
+            .any(|provider| provider.on_fs_command(command, args))
+    }
+
     pub fn available(&self) -> bool {
         !self.providers.is_empty()
     }
@@ -1,4 +1,5 @@
 use crate::avm1::{Avm1, Value};
+use crate::avm2::{Avm2, Object as Avm2Object, Value as Avm2Value};
 use crate::context::UpdateContext;
 pub use crate::display_object::{DisplayObject, TDisplayObject};
 use gc_arena::{Collect, GcCell, MutationContext};
@@ -43,6 +44,47 @@ impl<'gc> FocusTracker<'gc> {
 
         log::info!("Focus is now on {:?}", focused_element);
 
+        let old_object = old.and_then(avm2_object);
+        let new_object = focused_element.and_then(avm2_object);
+        let related = |object: Option<Avm2Object<'gc>>| {
+            object.map(Avm2Value::from).unwrap_or(Avm2Value::Null)
+        };
+
+        if let Some(old_object) = old_object {
+            let focus_event_proto = context.avm2.prototypes().focus_event;
+            if let Err(e) = Avm2::dispatch_event(
+                context,
+                old_object,
+                focus_event_proto,
+                &[
+                    "focusOut".into(),
+                    true.into(),
+                    false.into(),
+                    related(new_object),
+                ],
+            ) {
+                context.avm2.clear_error();
+                log::error!("Unhandled AVM2 exception in focusOut handler: {}", e);
+            }
+        }
+        if let Some(new_object) = new_object {
+            let focus_event_proto = context.avm2.prototypes().focus_event;
+            if let Err(e) = Avm2::dispatch_event(
+                context,
+                new_object,
+                focus_event_proto,
+                &[
+                    "focusIn".into(),
+                    true.into(),
+                    false.into(),
+                    related(old_object),
+                ],
+            ) {
+                context.avm2.clear_error();
+                log::error!("Unhandled AVM2 exception in focusIn handler: {}", e);
+            }
+        }
+
         let level0 = context.levels.get(&0).copied().unwrap();
         Avm1::notify_system_listeners(
             level0,
@@ -57,3 +99,11 @@ impl<'gc> FocusTracker<'gc> {
         );
     }
 }
+
+/// Get the AVM2 side of a display object, if it has one.
+fn avm2_object(display_object: DisplayObject<'_>) -> Option<Avm2Object<'_>> {
+    match display_object.object2() {
+        Avm2Value::Object(object) => Some(object),
+        _ => None,
+    }
+}
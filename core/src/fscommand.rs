@@ -0,0 +1,65 @@
+//! FSCommand handling
+
+use crate::config::StageScaleMode;
+use crate::context::UpdateContext;
+use crate::system_properties::PlayerType;
+
+/// Parse an FSCommand URL.
+pub fn parse(url: &str) -> Option<&str> {
+    if url.to_lowercase().starts_with("fscommand:") {
+        Some(&url["fscommand:".len()..])
+    } else {
+        None
+    }
+}
+
+/// Handle an FSCommand, as sent by AVM1 `fscommand`/`getURL` or AVM2
+/// `flash.system.fscommand`.
+///
+/// When running as the standalone player, the commands it understands are
+/// handled here, mostly by deferring to the UI backend. All other commands,
+/// and every command in other player types, are forwarded to the host
+/// through the external interface.
+pub fn handle(context: &mut UpdateContext<'_, '_, '_>, command: &str, args: &str) {
+    let handled = context.system.player_type == PlayerType::StandAlone
+        && handle_standalone(context, command, args);
+    if !handled && !context.external_interface.invoke_fs_command(command, args) {
+        log::warn!("Unhandled FSCommand: {}", command);
+    }
+}
+
+/// Handle an FSCommand understood by the standalone player.
+///
+/// Returns `false` if the command is not one of them.
+fn handle_standalone(context: &mut UpdateContext<'_, '_, '_>, command: &str, args: &str) -> bool {
+    let enable = args.eq_ignore_ascii_case("true");
+
+    match command.to_ascii_lowercase().as_str() {
+        "quit" => context.ui.quit(),
+        "fullscreen" => {
+            if let Err(e) = context.ui.set_fullscreen(enable) {
+                log::warn!("Couldn't change fullscreen mode: {}", e);
+            }
+        }
+        "allowscale" => {
+            // A scale mode chosen by the movie itself takes precedence.
+            if !*context.stage_scale_mode_set_by_script {
+                *context.stage_scale_mode = if enable {
+                    StageScaleMode::ShowAll
+                } else {
+                    StageScaleMode::NoScale
+                };
+            }
+        }
+        "showmenu" => *context.show_menu = enable,
+        "exec" => {
+            if let Err(e) = context.ui.exec(args) {
+                log::warn!("Couldn't exec \"{}\": {}", args, e);
+            }
+        }
+        "trapallkeys" => context.ui.set_trap_all_keys(enable),
+        _ => return false,
+    }
+
+    true
+}
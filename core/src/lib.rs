@@ -17,7 +17,9 @@ extern crate downcast_rs;
 
 #[macro_use]
 mod avm1;
+mod audio_manager;
 mod avm2;
+mod bitmap;
 mod bounding_box;
 mod character;
 mod collect;
@@ -28,6 +30,7 @@ mod ecma_conversions;
 pub mod events;
 pub mod focus_tracker;
 mod font;
+mod fscommand;
 mod html;
 mod library;
 pub mod loader;
@@ -36,7 +39,9 @@ mod prelude;
 pub mod property_map;
 pub mod shape_utils;
 pub mod string_utils;
+pub mod system_properties;
 pub mod tag_utils;
+mod timer;
 mod transform;
 mod types;
 mod vminterface;
@@ -1,4 +1,5 @@
 use crate::backend::audio::SoundHandle;
+use crate::backend::render::{BitmapHandle, BitmapSource};
 use crate::character::Character;
 use crate::display_object::{Bitmap, TDisplayObject};
 use crate::font::{Font, FontDescriptor};
@@ -6,7 +7,9 @@ use crate::prelude::*;
 use crate::property_map::PropertyMap;
 use crate::tag_utils::{SwfMovie, SwfSlice};
 use crate::vminterface::AvmType;
-use crate::{avm1::function::FunctionObject, avm2::Domain as Avm2Domain};
+use crate::{
+    avm1::function::FunctionObject, avm2::Domain as Avm2Domain, avm2::Object as Avm2Object,
+};
 use gc_arena::{Collect, Gc, GcCell, MutationContext};
 use std::collections::HashMap;
 use std::sync::{Arc, Weak};
@@ -55,6 +58,30 @@ impl<'gc> Avm1ConstructorRegistry<'gc> {
     }
 }
 
+/// The mappings between AVM2 classes and the library symbols they were
+/// associated with by a `SymbolClass` tag.
+///
+/// Classes that are backed by a symbol, such as embedded bitmaps, look
+/// themselves up here when constructed to find their content.
+#[derive(Collect, Default)]
+#[collect(no_drop)]
+pub struct Avm2ClassRegistry<'gc> {
+    /// The symbol associated with each class object.
+    class_map: HashMap<Avm2Object<'gc>, Character<'gc>>,
+}
+
+impl<'gc> Avm2ClassRegistry<'gc> {
+    /// Retrieve the symbol associated with a given class object, if any.
+    pub fn class_symbol(&self, class_object: Avm2Object<'gc>) -> Option<&Character<'gc>> {
+        self.class_map.get(&class_object)
+    }
+
+    /// Associate a class object with a symbol.
+    pub fn set_class_symbol(&mut self, class_object: Avm2Object<'gc>, character: Character<'gc>) {
+        self.class_map.insert(class_object, character);
+    }
+}
+
 /// Symbol library for a single given SWF.
 #[derive(Collect)]
 #[collect(no_drop)]
@@ -277,6 +304,13 @@ impl<'gc> MovieLibrary<'gc> {
     }
 }
 
+impl<'gc> BitmapSource for MovieLibrary<'gc> {
+    fn bitmap_handle(&self, id: CharacterId) -> Option<BitmapHandle> {
+        self.get_bitmap(id)
+            .and_then(|bitmap| bitmap.bitmap_handle())
+    }
+}
+
 /// Symbol library for multiple movies.
 pub struct Library<'gc> {
     /// All the movie libraries.
@@ -287,6 +321,9 @@ pub struct Library<'gc> {
 
     constructor_registry_case_insensitive: Gc<'gc, Avm1ConstructorRegistry<'gc>>,
     constructor_registry_case_sensitive: Gc<'gc, Avm1ConstructorRegistry<'gc>>,
+
+    /// The symbols associated with AVM2 classes.
+    avm2_class_registry: Avm2ClassRegistry<'gc>,
 }
 
 unsafe impl<'gc> gc_arena::Collect for Library<'gc> {
@@ -298,6 +335,7 @@ unsafe impl<'gc> gc_arena::Collect for Library<'gc> {
         self.device_font.trace(cc);
         self.constructor_registry_case_insensitive.trace(cc);
         self.constructor_registry_case_sensitive.trace(cc);
+        self.avm2_class_registry.trace(cc);
     }
 }
 
@@ -314,6 +352,7 @@ impl<'gc> Library<'gc> {
                 gc_context,
                 Avm1ConstructorRegistry::new(true, gc_context),
             ),
+            avm2_class_registry: Default::default(),
         }
     }
 
@@ -369,6 +408,14 @@ impl<'gc> Library<'gc> {
         self.device_font = font;
     }
 
+    pub fn avm2_class_registry(&self) -> &Avm2ClassRegistry<'gc> {
+        &self.avm2_class_registry
+    }
+
+    pub fn avm2_class_registry_mut(&mut self) -> &mut Avm2ClassRegistry<'gc> {
+        &mut self.avm2_class_registry
+    }
+
     /// Gets the constructor registry to use for the given SWF version.
     /// Because SWFs v6 and v7+ use different case-sensitivity rules, Flash
     /// keeps two separate registries, one case-sensitive, the other not.
@@ -2,13 +2,25 @@
 
 use crate::avm1::activation::{Activation, ActivationIdentifier};
 use crate::avm1::{Avm1, AvmString, Object, TObject, Value};
-use crate::avm2::Domain as Avm2Domain;
-use crate::backend::navigator::OwnedFuture;
-use crate::context::{ActionQueue, ActionType};
-use crate::display_object::{DisplayObject, MorphShape, TDisplayObject};
+use crate::avm2::{
+    bitmap_data_from_pixels, set_loader_info_content, set_loader_info_progress,
+    Activation as Avm2Activation, Avm2, Domain as Avm2Domain, Error as Avm2Error,
+    Namespace as Avm2Namespace, Object as Avm2Object, QName as Avm2QName, TObject as Avm2TObject,
+    Value as Avm2Value,
+};
+use crate::backend::audio::SoundHandle;
+use crate::backend::navigator::{OwnedFuture, ResponseStream};
+use crate::backend::render::{
+    decode_define_bits_jpeg, determine_jpeg_tag_format, Bitmap as ImageBitmap, BitmapFormat,
+    JpegTagFormat,
+};
+use crate::context::{ActionQueue, ActionType, UpdateContext};
+use crate::display_object::{
+    Bitmap, DisplayObject, MovieClip, PendingImport, TDisplayObject, TDisplayObjectContainer,
+};
 use crate::player::{Player, NEWEST_PLAYER_VERSION};
 use crate::property_map::PropertyMap;
-use crate::tag_utils::SwfMovie;
+use crate::tag_utils::{SwfMovie, SwfMovieDecoder};
 use crate::vminterface::Instantiator;
 use crate::xml::XMLNode;
 use encoding_rs::UTF_8;
@@ -16,6 +28,7 @@ use gc_arena::{Collect, CollectionContext, MutationContext};
 use generational_arena::{Arena, Index};
 use std::string::FromUtf8Error;
 use std::sync::{Arc, Mutex, Weak};
+use swf::ExportedAsset;
 use thiserror::Error;
 use url::form_urlencoded;
 
@@ -41,12 +54,24 @@ pub enum Error {
     #[error("Non-XML loader spawned as XML loader")]
     NotXmlLoader,
 
+    #[error("Non-URLLoader loader spawned as URLLoader loader")]
+    NotUrlLoader,
+
+    #[error("Non-sound loader spawned as sound loader")]
+    NotSoundLoader,
+
+    #[error("Non-import assets loader spawned as import assets loader")]
+    NotImportAssetsLoader,
+
     #[error("Could not fetch movie {0}")]
     FetchError(String),
 
-    #[error("Invalid SWF")]
+    #[error("Invalid SWF: {0}")]
     InvalidSwf(#[from] crate::tag_utils::Error),
 
+    #[error("Invalid image: {0}")]
+    InvalidImage(String),
+
     #[error("Invalid XML encoding")]
     InvalidXmlEncoding(#[from] FromUtf8Error),
 
@@ -60,6 +85,9 @@ pub enum Error {
     // the GC arena). We're losing info here. How do we fix that?
     #[error("Error running avm1 script: {0}")]
     Avm1Error(String),
+
+    #[error("Error running avm2 script: {0}")]
+    Avm2Error(String),
 }
 
 pub type FormLoadHandler<'gc> =
@@ -67,6 +95,17 @@ pub type FormLoadHandler<'gc> =
 
 pub type FormErrorHandler<'gc> = fn(&mut Activation<'_, 'gc, '_>, Object<'gc>) -> Result<(), Error>;
 
+impl Error {
+    /// Report an error raised by AVM2 while handling a load.
+    ///
+    /// The load handles the error instead of passing it on to a script, so
+    /// AVM2 forgets about it.
+    fn from_avm2(avm2: &mut Avm2<'_>, error: Avm2Error) -> Self {
+        avm2.clear_error();
+        Error::Avm2Error(error.to_string())
+    }
+}
+
 impl From<crate::avm1::error::Error<'_>> for Error {
     fn from(error: crate::avm1::error::Error<'_>) -> Self {
         Error::Avm1Error(error.to_string())
@@ -116,6 +155,11 @@ impl<'gc> LoadManager<'gc> {
         self.0.get_mut(handle)
     }
 
+    /// Remove a loader whose load has finished.
+    pub fn remove_loader(&mut self, handle: Handle) {
+        self.0.remove(handle);
+    }
+
     /// Kick off the root movie load.
     ///
     /// The root movie is special because it determines a few bits of player
@@ -125,7 +169,7 @@ impl<'gc> LoadManager<'gc> {
     pub fn load_root_movie(
         &mut self,
         player: Weak<Mutex<Player>>,
-        fetch: OwnedFuture<Vec<u8>, Error>,
+        fetch: OwnedFuture<Box<dyn ResponseStream>, Error>,
         url: String,
         parameters: PropertyMap<String>,
     ) -> OwnedFuture<(), Error> {
@@ -145,7 +189,7 @@ impl<'gc> LoadManager<'gc> {
         &mut self,
         player: Weak<Mutex<Player>>,
         target_clip: DisplayObject<'gc>,
-        fetch: OwnedFuture<Vec<u8>, Error>,
+        fetch: OwnedFuture<Box<dyn ResponseStream>, Error>,
         url: String,
         target_broadcaster: Option<Object<'gc>>,
     ) -> OwnedFuture<(), Error> {
@@ -154,6 +198,8 @@ impl<'gc> LoadManager<'gc> {
             target_clip,
             target_broadcaster,
             loader_status: LoaderStatus::Pending,
+            bytes_loaded: 0,
+            bytes_total: 0,
         };
         let handle = self.add_loader(loader);
 
@@ -163,6 +209,24 @@ impl<'gc> LoadManager<'gc> {
         loader.movie_loader(player, fetch, url)
     }
 
+    /// Retrieve the progress of a movie load into the given clip.
+    ///
+    /// Yields the number of bytes received so far and the total size of the
+    /// movie, which is zero if it isn't known yet. Returns `None` if no load
+    /// into the clip is in progress.
+    pub fn movie_load_progress(&self, clip: DisplayObject<'gc>) -> Option<(usize, usize)> {
+        self.0.iter().find_map(|(_, loader)| match loader {
+            Loader::Movie {
+                target_clip,
+                loader_status: LoaderStatus::Pending,
+                bytes_loaded,
+                bytes_total,
+                ..
+            } if DisplayObject::ptr_eq(*target_clip, clip) => Some((*bytes_loaded, *bytes_total)),
+            _ => None,
+        })
+    }
+
     /// Indicates that a movie clip has initialized (ran its first frame).
     ///
     /// Interested loaders will be invoked from here.
@@ -250,6 +314,124 @@ impl<'gc> LoadManager<'gc> {
 
         loader.xml_loader(player, fetch)
     }
+
+    /// Kick off a data load into an AVM2 `URLLoader`.
+    ///
+    /// Any load already in progress for the same `URLLoader` is cancelled.
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn load_data_into_url_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        target_object: Avm2Object<'gc>,
+        fetch: OwnedFuture<Vec<u8>, Error>,
+        url: String,
+    ) -> OwnedFuture<(), Error> {
+        self.cancel_url_loader(target_object);
+
+        let loader = Loader::UrlLoader {
+            self_handle: None,
+            target_object,
+        };
+        let handle = self.add_loader(loader);
+
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.introduce_loader_handle(handle);
+
+        loader.url_loader(player, fetch, url)
+    }
+
+    /// Kick off a movie load into an AVM2 `Loader`.
+    ///
+    /// The loaded movie's code is placed into the given application domain.
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn load_movie_into_avm2_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        target_loader: Avm2Object<'gc>,
+        loader_info: Avm2Object<'gc>,
+        domain: Avm2Domain<'gc>,
+        fetch: OwnedFuture<Box<dyn ResponseStream>, Error>,
+        url: String,
+    ) -> OwnedFuture<(), Error> {
+        self.cancel_avm2_loader(target_loader);
+
+        let loader = Loader::Avm2Loader {
+            self_handle: None,
+            target_loader,
+            loader_info,
+            domain,
+            content: None,
+        };
+        let handle = self.add_loader(loader);
+
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.introduce_loader_handle(handle);
+
+        loader.avm2_loader(player, fetch, url)
+    }
+
+    /// Cancel any load in progress for an AVM2 `Loader`.
+    pub fn cancel_avm2_loader(&mut self, target: Avm2Object<'gc>) {
+        self.0.retain(|_, loader| match loader {
+            Loader::Avm2Loader { target_loader, .. } => !Avm2Object::ptr_eq(*target_loader, target),
+            _ => true,
+        });
+    }
+
+    /// Kick off a sound load into an AVM2 `Sound`.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn load_sound_into_avm2_sound(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        target_object: Avm2Object<'gc>,
+        fetch: OwnedFuture<Vec<u8>, Error>,
+        url: String,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::Sound {
+            self_handle: None,
+            target_object,
+        };
+        let handle = self.add_loader(loader);
+
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.introduce_loader_handle(handle);
+
+        loader.sound_loader(player, fetch, url)
+    }
+
+    /// Kick off a load of the assets a movie imports from a shared library.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn load_import_assets(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        importing_clip: MovieClip<'gc>,
+        pending_import: PendingImport,
+        fetch: OwnedFuture<Vec<u8>, Error>,
+        url: String,
+        imports: Vec<ExportedAsset>,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::ImportAssets {
+            self_handle: None,
+            importing_clip,
+            imports,
+        };
+        let handle = self.add_loader(loader);
+
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.introduce_loader_handle(handle);
+
+        loader.import_assets_loader(player, pending_import, fetch, url)
+    }
+
+    /// Cancel any load in progress for an AVM2 `URLLoader`.
+    pub fn cancel_url_loader(&mut self, target: Avm2Object<'gc>) {
+        self.0.retain(|_, loader| match loader {
+            Loader::UrlLoader { target_object, .. } => !Avm2Object::ptr_eq(*target_object, target),
+            _ => true,
+        });
+    }
 }
 
 impl<'gc> Default for LoadManager<'gc> {
@@ -269,6 +451,119 @@ pub enum LoaderStatus {
     Failed,
 }
 
+/// The content fetched by a movie loader.
+enum MovieLoaderContent {
+    /// A SWF movie, which replaces the target clip's movie.
+    Movie(Arc<SwfMovie>),
+
+    /// A JPEG, PNG or GIF image, which replaces the target clip's content.
+    Image(ImageBitmap),
+}
+
+/// The data received by a movie loader, decoded as it arrives.
+enum MovieLoadData {
+    /// Too little has arrived to tell whether it is a movie or an image.
+    Unknown { url: String, data: Vec<u8> },
+
+    /// A SWF movie, which can start playing before all of it has arrived.
+    Movie(SwfMovieDecoder),
+
+    /// A JPEG, PNG or GIF image, which is only decoded once all of it has
+    /// arrived.
+    Image(Vec<u8>),
+}
+
+impl MovieLoadData {
+    /// The number of bytes needed to tell movies and images apart.
+    const SIGNATURE_LENGTH: usize = 8;
+
+    fn new(url: String) -> Self {
+        Self::Unknown { url, data: vec![] }
+    }
+
+    /// Decode the next chunk of the load.
+    fn decode_chunk(&mut self, chunk: &[u8]) -> Result<(), Error> {
+        match self {
+            Self::Unknown { data, .. } => {
+                data.extend_from_slice(chunk);
+                if data.len() >= Self::SIGNATURE_LENGTH {
+                    self.determine_format()?;
+                }
+            }
+            Self::Movie(decoder) => decoder.decode_chunk(chunk)?,
+            Self::Image(data) => data.extend_from_slice(chunk),
+        }
+
+        Ok(())
+    }
+
+    /// The movie being loaded, once enough of it has arrived to start
+    /// playing it.
+    fn movie(&self) -> Option<&Arc<SwfMovie>> {
+        match self {
+            Self::Movie(decoder) => decoder.movie(),
+            _ => None,
+        }
+    }
+
+    /// Finish decoding, once everything has arrived.
+    fn finish(mut self) -> Result<MovieLoaderContent, Error> {
+        self.determine_format()?;
+        match self {
+            Self::Movie(decoder) => Ok(MovieLoaderContent::Movie(decoder.finish()?)),
+            Self::Image(data) => Ok(MovieLoaderContent::Image(
+                decode_define_bits_jpeg(&data, None)
+                    .map_err(|e| Error::InvalidImage(e.to_string()))?,
+            )),
+            Self::Unknown { .. } => unreachable!(),
+        }
+    }
+
+    fn determine_format(&mut self) -> Result<(), Error> {
+        if let Self::Unknown { url, data } = self {
+            let data = std::mem::take(data);
+            *self = match determine_jpeg_tag_format(&data) {
+                JpegTagFormat::Unknown => {
+                    let mut decoder =
+                        SwfMovieDecoder::new(Some(std::mem::take(url)), PropertyMap::new());
+                    decoder.decode_chunk(&data)?;
+                    Self::Movie(decoder)
+                }
+                _ => Self::Image(data),
+            };
+        }
+
+        Ok(())
+    }
+}
+
+/// Place a movie that is being loaded into a clip, if it hasn't been already,
+/// and preload as much of it as has arrived.
+fn preload_movie_into_clip<'gc>(
+    uc: &mut UpdateContext<'_, 'gc, '_>,
+    clip: DisplayObject<'gc>,
+    movie: &Arc<SwfMovie>,
+) {
+    let mut mc = clip
+        .as_movie_clip()
+        .expect("Attempted to load movie into not movie clip");
+
+    if !mc
+        .movie()
+        .is_some_and(|mc_movie| Arc::ptr_eq(&mc_movie, movie))
+    {
+        let domain = Avm2Domain::movie_domain(uc.gc_context, uc.avm2.global_domain());
+        uc.library
+            .library_for_movie_mut(movie.clone())
+            .set_avm2_domain(domain);
+
+        mc.replace_with_movie(uc.gc_context, Some(movie.clone()));
+        mc.post_instantiation(uc, clip, None, Instantiator::Movie, false);
+    }
+
+    mc.preload(uc);
+}
+
 /// A struct that holds garbage-collected pointers for asynchronous code.
 pub enum Loader<'gc> {
     /// Loader that is loading the root movie of a player.
@@ -298,6 +593,12 @@ pub enum Loader<'gc> {
         /// or an error has occurred (in which case we don't care about the
         /// loader anymore).
         loader_status: LoaderStatus,
+
+        /// The number of bytes of the movie received so far.
+        bytes_loaded: usize,
+
+        /// The total size of the movie in bytes, or zero if it isn't known.
+        bytes_total: usize,
     },
 
     /// Loader that is loading form data into an AVM1 object scope.
@@ -334,6 +635,57 @@ pub enum Loader<'gc> {
         /// The target node whose contents will be replaced with the parsed XML.
         target_node: XMLNode<'gc>,
     },
+
+    /// Loader that is loading data into an AVM2 `URLLoader`.
+    UrlLoader {
+        /// The handle to refer to this loader instance.
+        self_handle: Option<Handle>,
+
+        /// The target `URLLoader` to load data into.
+        target_object: Avm2Object<'gc>,
+    },
+
+    /// Loader that is loading a movie into an AVM2 `Loader`.
+    Avm2Loader {
+        /// The handle to refer to this loader instance.
+        self_handle: Option<Handle>,
+
+        /// The target `Loader` to load the movie into.
+        target_loader: Avm2Object<'gc>,
+
+        /// The `LoaderInfo` to fire events into.
+        loader_info: Avm2Object<'gc>,
+
+        /// The application domain to place the movie's code into.
+        domain: Avm2Domain<'gc>,
+
+        /// The main timeline of the movie, once enough of it has arrived to
+        /// place it into the `Loader`.
+        content: Option<DisplayObject<'gc>>,
+    },
+
+    /// Loader that is loading an MP3 file into an AVM2 `Sound`.
+    Sound {
+        /// The handle to refer to this loader instance.
+        self_handle: Option<Handle>,
+
+        /// The target `Sound` to load the sound into.
+        target_object: Avm2Object<'gc>,
+    },
+
+    /// Loader that is loading a shared library to import assets from.
+    ImportAssets {
+        /// The handle to refer to this loader instance.
+        self_handle: Option<Handle>,
+
+        /// The main timeline of the movie importing the assets.
+        importing_clip: MovieClip<'gc>,
+
+        /// The imported assets, as the character IDs they are registered
+        /// under in the importing movie and the names the library exports
+        /// them as.
+        imports: Vec<ExportedAsset>,
+    },
 }
 
 unsafe impl<'gc> Collect for Loader<'gc> {
@@ -351,6 +703,21 @@ unsafe impl<'gc> Collect for Loader<'gc> {
             Loader::Form { target_object, .. } => target_object.trace(cc),
             Loader::LoadVars { target_object, .. } => target_object.trace(cc),
             Loader::XML { target_node, .. } => target_node.trace(cc),
+            Loader::UrlLoader { target_object, .. } => target_object.trace(cc),
+            Loader::Avm2Loader {
+                target_loader,
+                loader_info,
+                domain,
+                content,
+                ..
+            } => {
+                target_loader.trace(cc);
+                loader_info.trace(cc);
+                domain.trace(cc);
+                content.trace(cc);
+            }
+            Loader::Sound { target_object, .. } => target_object.trace(cc),
+            Loader::ImportAssets { importing_clip, .. } => importing_clip.trace(cc),
         }
     }
 }
@@ -367,6 +734,10 @@ impl<'gc> Loader<'gc> {
             Loader::Form { self_handle, .. } => *self_handle = Some(handle),
             Loader::LoadVars { self_handle, .. } => *self_handle = Some(handle),
             Loader::XML { self_handle, .. } => *self_handle = Some(handle),
+            Loader::UrlLoader { self_handle, .. } => *self_handle = Some(handle),
+            Loader::Avm2Loader { self_handle, .. } => *self_handle = Some(handle),
+            Loader::Sound { self_handle, .. } => *self_handle = Some(handle),
+            Loader::ImportAssets { self_handle, .. } => *self_handle = Some(handle),
         }
     }
 
@@ -374,7 +745,7 @@ impl<'gc> Loader<'gc> {
     pub fn root_movie_loader(
         &mut self,
         player: Weak<Mutex<Player>>,
-        fetch: OwnedFuture<Vec<u8>, Error>,
+        fetch: OwnedFuture<Box<dyn ResponseStream>, Error>,
         mut url: String,
         parameters: PropertyMap<String>,
     ) -> OwnedFuture<(), Error> {
@@ -399,19 +770,37 @@ impl<'gc> Loader<'gc> {
                     Ok(())
                 })?;
 
-            let data = (fetch.await)
-                .and_then(|data| Ok((data.len(), SwfMovie::from_data(&data, Some(url.clone()))?)));
-
-            if let Ok((_length, mut movie)) = data {
-                for (key, value) in parameters.iter() {
-                    movie.parameters_mut().insert(key, value.to_owned(), false);
+            // The movie starts playing as soon as its header has loaded, and
+            // the rest of it is preloaded as it arrives.
+            let mut is_root_movie_set = false;
+            let mut preload_root_movie = |movie: &Arc<SwfMovie>| {
+                let mut player = player.lock().expect("Could not lock player!!");
+                if !is_root_movie_set {
+                    player.set_root_movie(movie.clone());
+                    is_root_movie_set = true;
+                    return;
                 }
-                player.lock().unwrap().set_root_movie(Arc::new(movie));
 
-                Ok(())
-            } else {
-                Err(Error::FetchError(url))
+                player.update(|uc| {
+                    if let Some(root) = uc.levels.get(&0).and_then(|root| root.as_movie_clip()) {
+                        if root.movie().is_some_and(|root| Arc::ptr_eq(&root, movie)) {
+                            root.preload(uc);
+                        }
+                    }
+                });
+            };
+
+            let mut stream = fetch.await?;
+            let mut decoder = SwfMovieDecoder::new(Some(url), parameters);
+            while let Some(chunk) = stream.next_chunk().await? {
+                decoder.decode_chunk(&chunk)?;
+                if let Some(movie) = decoder.movie() {
+                    preload_root_movie(movie);
+                }
             }
+
+            preload_root_movie(&decoder.finish()?);
+            Ok(())
         })
     }
 
@@ -425,7 +814,7 @@ impl<'gc> Loader<'gc> {
     pub fn movie_loader(
         &mut self,
         player: Weak<Mutex<Player>>,
-        fetch: OwnedFuture<Vec<u8>, Error>,
+        fetch: OwnedFuture<Box<dyn ResponseStream>, Error>,
         mut url: String,
     ) -> OwnedFuture<(), Error> {
         let handle = match self {
@@ -474,21 +863,69 @@ impl<'gc> Loader<'gc> {
                     Ok(())
                 })?;
 
-            let data = (fetch.await)
-                .and_then(|data| Ok((data.len(), SwfMovie::from_data(&data, Some(url.clone()))?)));
-            if let Ok((length, movie)) = data {
-                let movie = Arc::new(movie);
+            let data = async {
+                let mut stream = fetch.await?;
+                let total = stream.total_length().unwrap_or(0);
+                let mut data = MovieLoadData::new(url.clone());
+                let mut loaded = 0;
+
+                while let Some(chunk) = stream.next_chunk().await? {
+                    data.decode_chunk(&chunk)?;
+                    loaded += chunk.len();
+
+                    player.lock().expect("Could not lock player!!").update(
+                        |uc| -> Result<(), Error> {
+                            let (clip, broadcaster) = match uc.load_manager.get_loader_mut(handle) {
+                                Some(Loader::Movie {
+                                    target_clip,
+                                    target_broadcaster,
+                                    bytes_loaded,
+                                    bytes_total,
+                                    ..
+                                }) => {
+                                    *bytes_loaded = loaded;
+                                    *bytes_total = total;
+                                    (*target_clip, *target_broadcaster)
+                                }
+                                None => return Err(Error::Cancelled),
+                                _ => unreachable!(),
+                            };
+
+                            // Movies start playing as soon as their first
+                            // frames have arrived.
+                            if let Some(movie) = data.movie() {
+                                preload_movie_into_clip(uc, clip, movie);
+                            }
+
+                            if let Some(broadcaster) = broadcaster {
+                                Avm1::run_stack_frame_for_method(
+                                    clip,
+                                    broadcaster,
+                                    NEWEST_PLAYER_VERSION,
+                                    uc,
+                                    "broadcastMessage",
+                                    &[
+                                        "onLoadProgress".into(),
+                                        Value::Object(broadcaster),
+                                        loaded.into(),
+                                        total.into(),
+                                    ],
+                                );
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
 
+                Ok::<_, Error>((loaded, data.finish()?))
+            }
+            .await;
+            if let Ok((length, content)) = data {
                 player
                     .lock()
                     .expect("Could not lock player!!")
                     .update(|uc| {
-                        let domain =
-                            Avm2Domain::movie_domain(uc.gc_context, uc.avm2.global_domain());
-                        uc.library
-                            .library_for_movie_mut(movie.clone())
-                            .set_avm2_domain(domain);
-
                         let (clip, broadcaster) = match uc.load_manager.get_loader(handle) {
                             Some(Loader::Movie {
                                 target_clip,
@@ -499,41 +936,55 @@ impl<'gc> Loader<'gc> {
                             _ => unreachable!(),
                         };
 
-                        if let Some(broadcaster) = broadcaster {
-                            Avm1::run_stack_frame_for_method(
-                                clip,
-                                broadcaster,
-                                NEWEST_PLAYER_VERSION,
-                                uc,
-                                "broadcastMessage",
-                                &[
-                                    "onLoadProgress".into(),
-                                    Value::Object(broadcaster),
-                                    length.into(),
-                                    length.into(),
-                                ],
-                            );
-                        }
-
                         let mut mc = clip
                             .as_movie_clip()
                             .expect("Attempted to load movie into not movie clip");
 
-                        mc.replace_with_movie(uc.gc_context, Some(movie.clone()));
-                        mc.post_instantiation(uc, clip, None, Instantiator::Movie, false);
-
-                        let mut morph_shapes = fnv::FnvHashMap::default();
-                        mc.preload(uc, &mut morph_shapes);
-
-                        // Finalize morph shapes.
-                        for (id, static_data) in morph_shapes {
-                            let morph_shape = MorphShape::new(uc.gc_context, static_data);
-                            uc.library
-                                .library_for_movie_mut(movie.clone())
-                                .register_character(
-                                    id,
-                                    crate::character::Character::MorphShape(morph_shape),
-                                );
+                        match content {
+                            MovieLoaderContent::Movie(movie) => {
+                                preload_movie_into_clip(uc, clip, &movie);
+                            }
+                            MovieLoaderContent::Image(bitmap) => {
+                                // Images replace the clip's content with a single bitmap.
+                                let version = mc
+                                    .movie()
+                                    .map(|movie| movie.version())
+                                    .unwrap_or(NEWEST_PLAYER_VERSION);
+                                let movie = Arc::new(SwfMovie::from_loaded_image(
+                                    version,
+                                    url.clone(),
+                                    length,
+                                ));
+                                mc.replace_with_movie(uc.gc_context, Some(movie));
+                                mc.post_instantiation(uc, clip, None, Instantiator::Movie, false);
+
+                                // The image's movie has no tags, so this only
+                                // marks its single frame as loaded.
+                                mc.preload(uc);
+
+                                let width = bitmap.width;
+                                let height = bitmap.height;
+                                let rgba = match bitmap.data {
+                                    BitmapFormat::Rgba(rgba) => rgba,
+                                    BitmapFormat::Rgb(rgb) => {
+                                        let mut rgba = Vec::with_capacity(rgb.len() / 3 * 4);
+                                        for pixel in rgb.chunks_exact(3) {
+                                            rgba.extend_from_slice(&[
+                                                pixel[0], pixel[1], pixel[2], 255,
+                                            ]);
+                                        }
+                                        rgba
+                                    }
+                                };
+                                let bitmap_handle = uc
+                                    .renderer
+                                    .register_bitmap_raw(width, height, rgba)
+                                    .map_err(|e| Error::InvalidImage(e.to_string()))?;
+
+                                let bitmap =
+                                    Bitmap::new(uc, 0, bitmap_handle, width as u16, height as u16);
+                                mc.replace_at_depth(uc, bitmap.into(), 1);
+                            }
                         }
 
                         if let Some(broadcaster) = broadcaster {
@@ -547,7 +998,23 @@ impl<'gc> Loader<'gc> {
                             );
                         }
 
-                        if let Some(Loader::Movie { loader_status, .. }) =
+                        // A movie that started playing while it loaded has
+                        // already run its first frame, so it won't fire
+                        // another load event to finish the load on.
+                        if mc.initialized() {
+                            if let Some(broadcaster) = broadcaster {
+                                Avm1::run_stack_frame_for_method(
+                                    clip,
+                                    broadcaster,
+                                    NEWEST_PLAYER_VERSION,
+                                    uc,
+                                    "broadcastMessage",
+                                    &["onLoadInit".into(), clip.object()],
+                                );
+                            }
+
+                            uc.load_manager.remove_loader(handle);
+                        } else if let Some(Loader::Movie { loader_status, .. }) =
                             uc.load_manager.get_loader_mut(handle)
                         {
                             *loader_status = LoaderStatus::Succeeded;
@@ -848,4 +1315,730 @@ impl<'gc> Loader<'gc> {
             Ok(())
         })
     }
+
+    /// Creates a future for a `URLLoader` load call.
+    pub fn url_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        fetch: OwnedFuture<Vec<u8>, Error>,
+        url: String,
+    ) -> OwnedFuture<(), Error> {
+        let handle = match self {
+            Loader::UrlLoader { self_handle, .. } => {
+                self_handle.expect("Loader not self-introduced")
+            }
+            _ => return Box::pin(async { Err(Error::NotUrlLoader) }),
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            let data = fetch.await;
+
+            player.lock().unwrap().update(|uc| {
+                let target = match uc.load_manager.get_loader(handle) {
+                    Some(&Loader::UrlLoader { target_object, .. }) => target_object,
+                    None => return Err(Error::Cancelled),
+                    _ => return Err(Error::NotUrlLoader),
+                };
+
+                // The load is over, so there is nothing left for `close` to cancel.
+                uc.load_manager.remove_loader(handle);
+
+                let mut activation = Avm2Activation::from_nothing(uc.reborrow());
+                let result = match data {
+                    Ok(data) => url_loader_complete(&mut activation, target, &data),
+                    Err(_) => url_loader_failed(&mut activation, target, &url),
+                };
+
+                result.map_err(|e| Error::from_avm2(activation.context.avm2, e))
+            })
+        })
+    }
+
+    /// Creates a future for an AVM2 `Loader` load call.
+    pub fn avm2_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        fetch: OwnedFuture<Box<dyn ResponseStream>, Error>,
+        mut url: String,
+    ) -> OwnedFuture<(), Error> {
+        let handle = match self {
+            Loader::Avm2Loader { self_handle, .. } => {
+                self_handle.expect("Loader not self-introduced")
+            }
+            _ => return Box::pin(async { Err(Error::NotMovieLoader) }),
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            player
+                .lock()
+                .expect("Could not lock player!!")
+                .update(|uc| -> Result<(), Error> {
+                    url = uc.navigator.resolve_relative_url(&url).into_owned();
+
+                    let loader_info = match uc.load_manager.get_loader(handle) {
+                        Some(&Loader::Avm2Loader { loader_info, .. }) => loader_info,
+                        None => return Err(Error::Cancelled),
+                        _ => unreachable!(),
+                    };
+
+                    let event_proto = uc.avm2.prototypes().event;
+                    Avm2::dispatch_event(uc, loader_info, event_proto, &["open".into()])
+                        .map_err(|e| Error::from_avm2(uc.avm2, e))?;
+
+                    Ok(())
+                })?;
+
+            let data = async {
+                let mut stream = fetch.await?;
+                let total = stream.total_length().unwrap_or(0);
+                let mut data = MovieLoadData::new(url.clone());
+                let mut loaded = 0;
+
+                while let Some(chunk) = stream.next_chunk().await? {
+                    data.decode_chunk(&chunk)?;
+                    loaded += chunk.len();
+
+                    player.lock().expect("Could not lock player!!").update(
+                        |uc| -> Result<(), Error> {
+                            // Movies start playing as soon as their first
+                            // frames have arrived.
+                            if let Some(movie) = data.movie() {
+                                preload_movie_into_avm2_loader(uc, handle, movie)?;
+                            }
+
+                            avm2_loader_progress(uc, handle, loaded, total)
+                        },
+                    )?;
+                }
+
+                Ok::<_, Error>((loaded, data.finish()?))
+            }
+            .await;
+
+            player
+                .lock()
+                .expect("Could not lock player!!")
+                .update(|uc| {
+                    let (loader_info, domain) = match uc.load_manager.get_loader(handle) {
+                        Some(&Loader::Avm2Loader {
+                            loader_info,
+                            domain,
+                            ..
+                        }) => (loader_info, domain),
+                        None => return Err(Error::Cancelled),
+                        _ => unreachable!(),
+                    };
+
+                    let result = match data {
+                        Ok((length, MovieLoaderContent::Movie(movie))) => {
+                            let content = preload_movie_into_avm2_loader(uc, handle, &movie)?;
+
+                            // The load is over, so there is nothing left for `close` to cancel.
+                            uc.load_manager.remove_loader(handle);
+
+                            avm2_loader_complete(uc, loader_info, domain, content, movie, length)
+                        }
+                        Ok((length, MovieLoaderContent::Image(image))) => {
+                            let content = place_image_into_avm2_loader(uc, handle, image)?;
+                            let movie = Arc::new(SwfMovie::from_loaded_image(
+                                NEWEST_PLAYER_VERSION,
+                                url.clone(),
+                                length,
+                            ));
+
+                            uc.load_manager.remove_loader(handle);
+
+                            avm2_loader_complete(uc, loader_info, domain, content, movie, length)
+                        }
+                        Err(_) => {
+                            uc.load_manager.remove_loader(handle);
+
+                            let mut activation = Avm2Activation::from_nothing(uc.reborrow());
+                            avm2_loader_failed(&mut activation, loader_info, &url)
+                        }
+                    };
+
+                    result.map_err(|e| Error::from_avm2(uc.avm2, e))
+                })
+        })
+    }
+
+    /// Creates a future for an AVM2 `Sound` load call.
+    pub fn sound_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        fetch: OwnedFuture<Vec<u8>, Error>,
+        mut url: String,
+    ) -> OwnedFuture<(), Error> {
+        let handle = match self {
+            Loader::Sound { self_handle, .. } => self_handle.expect("Loader not self-introduced"),
+            _ => return Box::pin(async { Err(Error::NotSoundLoader) }),
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            player
+                .lock()
+                .expect("Could not lock player!!")
+                .update(|uc| -> Result<(), Error> {
+                    url = uc.navigator.resolve_relative_url(&url).into_owned();
+
+                    let target = match uc.load_manager.get_loader(handle) {
+                        Some(&Loader::Sound { target_object, .. }) => target_object,
+                        None => return Err(Error::Cancelled),
+                        _ => unreachable!(),
+                    };
+
+                    let event_proto = uc.avm2.prototypes().event;
+                    Avm2::dispatch_event(uc, target, event_proto, &["open".into()])
+                        .map_err(|e| Error::from_avm2(uc.avm2, e))?;
+
+                    Ok(())
+                })?;
+
+            let data = fetch.await;
+
+            player
+                .lock()
+                .expect("Could not lock player!!")
+                .update(|uc| {
+                    let target = match uc.load_manager.get_loader(handle) {
+                        Some(&Loader::Sound { target_object, .. }) => target_object,
+                        None => return Err(Error::Cancelled),
+                        _ => unreachable!(),
+                    };
+
+                    uc.load_manager.remove_loader(handle);
+
+                    let sound = data.ok().and_then(|data| {
+                        let length = data.len();
+
+                        match uc.audio.register_mp3(&data) {
+                            Ok(sound) => Some((sound, length)),
+                            Err(e) => {
+                                log::error!("Unable to register sound {}: {}", url, e);
+                                None
+                            }
+                        }
+                    });
+
+                    let mut activation = Avm2Activation::from_nothing(uc.reborrow());
+                    let result = match sound {
+                        Some((sound, length)) => {
+                            sound_loader_complete(&mut activation, target, sound, length)
+                        }
+                        None => sound_loader_failed(&mut activation, target, &url),
+                    };
+
+                    result.map_err(|e| Error::from_avm2(activation.context.avm2, e))
+                })
+        })
+    }
+
+    /// Construct a future for the given shared library loader.
+    ///
+    /// The importing clip waits on the load until `pending_import` is
+    /// dropped along with the future, however the load ends.
+    pub fn import_assets_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        pending_import: PendingImport,
+        fetch: OwnedFuture<Vec<u8>, Error>,
+        url: String,
+    ) -> OwnedFuture<(), Error> {
+        let handle = match self {
+            Loader::ImportAssets { self_handle, .. } => {
+                self_handle.expect("Loader not self-introduced")
+            }
+            _ => return Box::pin(async { Err(Error::NotImportAssetsLoader) }),
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            let _pending_import = pending_import;
+            let data = fetch
+                .await
+                .and_then(|data| Ok(Arc::new(SwfMovie::from_data(&data, Some(url.clone()))?)));
+
+            player
+                .lock()
+                .expect("Could not lock player!!")
+                .update(|uc| -> Result<(), Error> {
+                    let (clip, imports) = match uc.load_manager.get_loader(handle) {
+                        Some(Loader::ImportAssets {
+                            importing_clip,
+                            imports,
+                            ..
+                        }) => (*importing_clip, imports.clone()),
+                        None => return Err(Error::Cancelled),
+                        _ => unreachable!(),
+                    };
+
+                    uc.load_manager.remove_loader(handle);
+
+                    match data {
+                        Ok(library_movie) => {
+                            let domain =
+                                Avm2Domain::movie_domain(uc.gc_context, uc.avm2.global_domain());
+                            uc.library
+                                .library_for_movie_mut(library_movie.clone())
+                                .set_avm2_domain(domain);
+
+                            let library_clip =
+                                MovieClip::from_movie(uc.gc_context, library_movie.clone());
+                            library_clip.preload(uc);
+
+                            // Register the imported characters under their local IDs.
+                            if let Some(importing_movie) = clip.movie() {
+                                for import in imports {
+                                    let character = uc
+                                        .library
+                                        .library_for_movie_mut(library_movie.clone())
+                                        .character_by_export_name(&import.name)
+                                        .cloned();
+
+                                    if let Some(character) = character {
+                                        let library = uc
+                                            .library
+                                            .library_for_movie_mut(importing_movie.clone());
+                                        library.register_character(import.id, character);
+                                        library.register_export(import.id, &import.name);
+                                    } else {
+                                        log::warn!(
+                                            "Shared library {} does not export {}",
+                                            url,
+                                            import.name
+                                        );
+                                    }
+                                }
+                            }
+                        }
+                        Err(e) => log::error!("Unable to load shared library {}: {}", url, e),
+                    }
+
+                    Ok(())
+                })
+        })
+    }
+}
+
+/// Deliver a successfully loaded response to a `URLLoader`.
+///
+/// The response is converted according to the loader's `dataFormat`.
+fn url_loader_complete<'gc>(
+    activation: &mut Avm2Activation<'_, 'gc, '_>,
+    mut target: Avm2Object<'gc>,
+    data: &[u8],
+) -> Result<(), Avm2Error> {
+    let event_proto = activation.context.avm2.prototypes().event;
+    let progress_event_proto = activation.context.avm2.prototypes().progress_event;
+    let url_variables_proto = activation.context.avm2.prototypes().url_variables;
+    let bytearray_proto = activation.context.avm2.prototypes().bytearray;
+    let length = data.len() as u32;
+
+    Avm2::dispatch_event(
+        &mut activation.context,
+        target,
+        event_proto,
+        &["open".into()],
+    )?;
+
+    target.set_property(
+        target,
+        &Avm2QName::new(Avm2Namespace::public_namespace(), "bytesLoaded"),
+        length.into(),
+        activation,
+    )?;
+    target.set_property(
+        target,
+        &Avm2QName::new(Avm2Namespace::public_namespace(), "bytesTotal"),
+        length.into(),
+        activation,
+    )?;
+    Avm2::dispatch_event(
+        &mut activation.context,
+        target,
+        progress_event_proto,
+        &[
+            "progress".into(),
+            false.into(),
+            false.into(),
+            length.into(),
+            length.into(),
+        ],
+    )?;
+
+    let data_format = target
+        .get_property(
+            target,
+            &Avm2QName::new(Avm2Namespace::public_namespace(), "dataFormat"),
+            activation,
+        )?
+        .coerce_to_string(activation)?;
+    let text: Avm2Value<'gc> =
+        AvmString::new(activation.context.gc_context, UTF_8.decode(data).0).into();
+    let data = match &*data_format {
+        "variables" => {
+            Avm2Object::construct_from_proto(activation, url_variables_proto, &[text])?.into()
+        }
+        "binary" => {
+            let bytearray = Avm2Object::construct_from_proto(activation, bytearray_proto, &[])?;
+            if let Some(mut storage) = bytearray.as_bytearray_mut(activation.context.gc_context) {
+                storage.write_bytes(data);
+                storage.set_position(0);
+            }
+
+            bytearray.into()
+        }
+        _ => text,
+    };
+
+    target.set_property(
+        target,
+        &Avm2QName::new(Avm2Namespace::public_namespace(), "data"),
+        data,
+        activation,
+    )?;
+    Avm2::dispatch_event(
+        &mut activation.context,
+        target,
+        event_proto,
+        &["complete".into()],
+    )?;
+
+    Ok(())
+}
+
+/// Report a failed load to a `URLLoader`.
+fn url_loader_failed<'gc>(
+    activation: &mut Avm2Activation<'_, 'gc, '_>,
+    target: Avm2Object<'gc>,
+    url: &str,
+) -> Result<(), Avm2Error> {
+    let http_status_event_proto = activation.context.avm2.prototypes().http_status_event;
+    let io_error_event_proto = activation.context.avm2.prototypes().io_error_event;
+
+    // Simulate 404 HTTP status, as the AVM1 loaders do.
+    Avm2::dispatch_event(
+        &mut activation.context,
+        target,
+        http_status_event_proto,
+        &["httpStatus".into(), false.into(), false.into(), 404.into()],
+    )?;
+
+    let text = AvmString::new(
+        activation.context.gc_context,
+        format!("Error #2032: Stream Error. URL: {}", url),
+    );
+    Avm2::dispatch_event(
+        &mut activation.context,
+        target,
+        io_error_event_proto,
+        &[
+            "ioError".into(),
+            false.into(),
+            false.into(),
+            text.into(),
+            2032.into(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Place a movie that is being loaded into an AVM2 `Loader`, if it hasn't been
+/// already, and preload as much of it as has arrived.
+///
+/// The movie's code runs in the loader's application domain. Returns the
+/// movie's main timeline.
+fn preload_movie_into_avm2_loader<'gc>(
+    uc: &mut UpdateContext<'_, 'gc, '_>,
+    handle: Handle,
+    movie: &Arc<SwfMovie>,
+) -> Result<DisplayObject<'gc>, Error> {
+    let (target_loader, domain, content) = match uc.load_manager.get_loader(handle) {
+        Some(&Loader::Avm2Loader {
+            target_loader,
+            domain,
+            content,
+            ..
+        }) => (target_loader, domain, content),
+        None => return Err(Error::Cancelled),
+        _ => unreachable!(),
+    };
+
+    if let Some(clip) = content {
+        clip.as_movie_clip().unwrap().preload(uc);
+        return Ok(clip);
+    }
+
+    uc.library
+        .library_for_movie_mut(movie.clone())
+        .set_avm2_domain(domain);
+
+    let clip: DisplayObject<'gc> = MovieClip::from_movie(uc.gc_context, movie.clone()).into();
+    clip.post_instantiation(uc, clip, None, Instantiator::Movie, false);
+    clip.as_movie_clip().unwrap().preload(uc);
+
+    if let Some(mut ctr) = target_loader
+        .as_display_object()
+        .and_then(|target| target.as_container())
+    {
+        ctr.insert_at_index(uc, clip, 0);
+        clip.set_placed_by_script(uc.gc_context, true);
+    }
+
+    if let Some(Loader::Avm2Loader { content, .. }) = uc.load_manager.get_loader_mut(handle) {
+        *content = Some(clip);
+    }
+
+    Ok(clip)
+}
+
+/// Place a loaded image into an AVM2 `Loader` as a `Bitmap`.
+///
+/// Returns the bitmap, which becomes the loader's content.
+fn place_image_into_avm2_loader<'gc>(
+    uc: &mut UpdateContext<'_, 'gc, '_>,
+    handle: Handle,
+    image: ImageBitmap,
+) -> Result<DisplayObject<'gc>, Error> {
+    let target_loader = match uc.load_manager.get_loader(handle) {
+        Some(&Loader::Avm2Loader { target_loader, .. }) => target_loader,
+        None => return Err(Error::Cancelled),
+        _ => unreachable!(),
+    };
+
+    let mut activation = Avm2Activation::from_nothing(uc.reborrow());
+    let bitmap = avm2_bitmap_from_image(&mut activation, image)
+        .map_err(|e| Error::from_avm2(activation.context.avm2, e))?;
+
+    if let Some(mut ctr) = target_loader
+        .as_display_object()
+        .and_then(|target| target.as_container())
+    {
+        ctr.insert_at_index(uc, bitmap, 0);
+        bitmap.set_placed_by_script(uc.gc_context, true);
+    }
+
+    if let Some(Loader::Avm2Loader { content, .. }) = uc.load_manager.get_loader_mut(handle) {
+        *content = Some(bitmap);
+    }
+
+    Ok(bitmap)
+}
+
+/// Construct an AVM2 `Bitmap` displaying a loaded image.
+fn avm2_bitmap_from_image<'gc>(
+    activation: &mut Avm2Activation<'_, 'gc, '_>,
+    image: ImageBitmap,
+) -> Result<DisplayObject<'gc>, Avm2Error> {
+    let bitmap_data_proto = activation.context.avm2.prototypes().bitmap_data;
+    let bitmap_data = bitmap_data_proto.construct(activation, &[])?;
+    let pixels = bitmap_data_from_pixels(activation, image);
+    bitmap_data.init_bitmap_data(activation.context.gc_context, pixels);
+
+    let mut bitmap_proto = activation.context.avm2.prototypes().bitmap;
+    let constructor = bitmap_proto
+        .get_property(
+            bitmap_proto,
+            &Avm2QName::new(Avm2Namespace::public_namespace(), "constructor"),
+            activation,
+        )?
+        .coerce_to_object(activation)?;
+    let args = [bitmap_data.into()];
+    let bitmap = bitmap_proto.construct(activation, &args)?;
+    constructor.call(Some(bitmap), &args, activation, Some(bitmap_proto))?;
+
+    Ok(bitmap
+        .as_display_object()
+        .expect("Bitmap constructor creates its display object"))
+}
+
+/// Report how much of a load into an AVM2 `Loader` has arrived to its
+/// `LoaderInfo`, which fires `progress`.
+fn avm2_loader_progress<'gc>(
+    uc: &mut UpdateContext<'_, 'gc, '_>,
+    handle: Handle,
+    loaded: usize,
+    total: usize,
+) -> Result<(), Error> {
+    let loader_info = match uc.load_manager.get_loader(handle) {
+        Some(&Loader::Avm2Loader { loader_info, .. }) => loader_info,
+        None => return Err(Error::Cancelled),
+        _ => unreachable!(),
+    };
+
+    let progress_event_proto = uc.avm2.prototypes().progress_event;
+    let mut activation = Avm2Activation::from_nothing(uc.reborrow());
+
+    set_loader_info_progress(&mut activation, loader_info, loaded, total)
+        .and_then(|_| {
+            Avm2::dispatch_event(
+                &mut activation.context,
+                loader_info,
+                progress_event_proto,
+                &[
+                    "progress".into(),
+                    false.into(),
+                    false.into(),
+                    (loaded as u32).into(),
+                    (total as u32).into(),
+                ],
+            )
+        })
+        .map(|_| ())
+        .map_err(|e| Error::from_avm2(activation.context.avm2, e))
+}
+
+/// Finish a successful movie load into an AVM2 `Loader`.
+///
+/// The movie's `LoaderInfo` fires `init` and `complete`. Its progress is set
+/// to the full length of the load, in case the server didn't report it.
+fn avm2_loader_complete<'gc>(
+    uc: &mut UpdateContext<'_, 'gc, '_>,
+    loader_info: Avm2Object<'gc>,
+    domain: Avm2Domain<'gc>,
+    clip: DisplayObject<'gc>,
+    movie: Arc<SwfMovie>,
+    length: usize,
+) -> Result<(), Avm2Error> {
+    // Movies that don't run AVM2 code, such as AVM1 movies, have no content
+    // object that AVM2 can see.
+    let content = match clip.object2() {
+        Avm2Value::Undefined => Avm2Value::Null,
+        content => content,
+    };
+
+    let event_proto = uc.avm2.prototypes().event;
+    let mut activation = Avm2Activation::from_nothing(uc.reborrow());
+
+    set_loader_info_progress(&mut activation, loader_info, length, length)?;
+    set_loader_info_content(
+        &mut activation,
+        loader_info,
+        movie.url().unwrap_or_default(),
+        content,
+        Some(domain),
+        movie.parameters(),
+    )?;
+    Avm2::dispatch_event(
+        &mut activation.context,
+        loader_info,
+        event_proto,
+        &["init".into()],
+    )?;
+    Avm2::dispatch_event(
+        &mut activation.context,
+        loader_info,
+        event_proto,
+        &["complete".into()],
+    )?;
+
+    Ok(())
+}
+
+/// Report a failed load to an AVM2 `Loader`'s `LoaderInfo`.
+fn avm2_loader_failed<'gc>(
+    activation: &mut Avm2Activation<'_, 'gc, '_>,
+    loader_info: Avm2Object<'gc>,
+    url: &str,
+) -> Result<(), Avm2Error> {
+    let io_error_event_proto = activation.context.avm2.prototypes().io_error_event;
+    let text = AvmString::new(
+        activation.context.gc_context,
+        format!("Error #2035: URL Not Found. URL: {}", url),
+    );
+
+    Avm2::dispatch_event(
+        &mut activation.context,
+        loader_info,
+        io_error_event_proto,
+        &[
+            "ioError".into(),
+            false.into(),
+            false.into(),
+            text.into(),
+            2035.into(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Attach a successfully loaded sound to an AVM2 `Sound` and fire its load
+/// events.
+fn sound_loader_complete<'gc>(
+    activation: &mut Avm2Activation<'_, 'gc, '_>,
+    target: Avm2Object<'gc>,
+    sound: SoundHandle,
+    length: usize,
+) -> Result<(), Avm2Error> {
+    target.set_sound(activation.context.gc_context, sound);
+
+    let progress_event_proto = activation.context.avm2.prototypes().progress_event;
+    let event_proto = activation.context.avm2.prototypes().event;
+
+    Avm2::dispatch_event(
+        &mut activation.context,
+        target,
+        progress_event_proto,
+        &[
+            "progress".into(),
+            false.into(),
+            false.into(),
+            (length as u32).into(),
+            (length as u32).into(),
+        ],
+    )?;
+    Avm2::dispatch_event(
+        &mut activation.context,
+        target,
+        event_proto,
+        &["complete".into()],
+    )?;
+
+    Ok(())
+}
+
+/// Report a failed load to an AVM2 `Sound`.
+fn sound_loader_failed<'gc>(
+    activation: &mut Avm2Activation<'_, 'gc, '_>,
+    target: Avm2Object<'gc>,
+    url: &str,
+) -> Result<(), Avm2Error> {
+    let io_error_event_proto = activation.context.avm2.prototypes().io_error_event;
+    let text = AvmString::new(
+        activation.context.gc_context,
+        format!("Error #2032: Stream Error. URL: {}", url),
+    );
+
+    Avm2::dispatch_event(
+        &mut activation.context,
+        target,
+        io_error_event_proto,
+        &[
+            "ioError".into(),
+            false.into(),
+            false.into(),
+            text.into(),
+            2032.into(),
+        ],
+    )?;
+
+    Ok(())
 }
@@ -1,17 +1,17 @@
+use crate::audio_manager::AudioManager;
 use crate::avm1::activation::{Activation, ActivationIdentifier};
 use crate::avm1::debug::VariableDumper;
-use crate::avm1::globals::system::SystemProperties;
 use crate::avm1::object::Object;
-use crate::avm1::{Avm1, AvmString, ScriptObject, TObject, Timers, Value};
-use crate::avm2::{Avm2, Domain as Avm2Domain};
+use crate::avm1::{Avm1, AvmString, ScriptObject, TObject, Value};
+use crate::avm2::{Avm2, Domain as Avm2Domain, Value as Avm2Value};
 use crate::backend::input::{InputBackend, MouseCursor};
 use crate::backend::locale::LocaleBackend;
 use crate::backend::navigator::{NavigatorBackend, RequestOptions};
 use crate::backend::storage::StorageBackend;
 use crate::backend::{audio::AudioBackend, log::LogBackend, render::RenderBackend, ui::UiBackend};
-use crate::config::Letterbox;
+use crate::config::{Letterbox, StageAlign, StageQuality, StageScaleMode};
 use crate::context::{ActionQueue, ActionType, RenderContext, UpdateContext};
-use crate::display_object::{EditText, MorphShape, MovieClip};
+use crate::display_object::{EditText, MovieClip};
 use crate::events::{ButtonKeyCode, ClipEvent, ClipEventResult, KeyCode, PlayerEvent};
 use crate::external::Value as ExternalValue;
 use crate::external::{ExternalInterface, ExternalInterfaceProvider};
@@ -20,7 +20,9 @@ use crate::library::Library;
 use crate::loader::LoadManager;
 use crate::prelude::*;
 use crate::property_map::PropertyMap;
+use crate::system_properties::SystemProperties;
 use crate::tag_utils::SwfMovie;
+use crate::timer::Timers;
 use crate::transform::TransformStack;
 use crate::vminterface::{AvmType, Instantiator};
 use enumset::EnumSet;
@@ -81,6 +83,9 @@ struct GcRootData<'gc> {
     /// Timed callbacks created with `setInterval`/`setTimeout`.
     timers: Timers<'gc>,
 
+    /// Sounds played by AS3 code.
+    audio_manager: AudioManager<'gc>,
+
     /// External interface for (for example) JavaScript <-> ActionScript interaction
     external_interface: ExternalInterface<'gc>,
 
@@ -105,6 +110,7 @@ impl<'gc> GcRootData<'gc> {
         &mut HashMap<String, Object<'gc>>,
         &mut Vec<EditText<'gc>>,
         &mut Timers<'gc>,
+        &mut AudioManager<'gc>,
         &mut ExternalInterface<'gc>,
     ) {
         (
@@ -118,6 +124,7 @@ impl<'gc> GcRootData<'gc> {
             &mut self.shared_objects,
             &mut self.unbound_text_fields,
             &mut self.timers,
+            &mut self.audio_manager,
             &mut self.external_interface,
         )
     }
@@ -187,9 +194,19 @@ pub struct Player {
 
     viewport_width: u32,
     viewport_height: u32,
+
+    /// The number of physical viewport pixels per logical pixel, such as 2.0
+    /// on HiDPI displays.
+    viewport_scale_factor: f64,
+
     movie_width: u32,
     movie_height: u32,
     letterbox: Letterbox,
+    scale_mode: StageScaleMode,
+    scale_mode_set_by_script: bool,
+    stage_align: EnumSet<StageAlign>,
+    quality: StageQuality,
+    show_menu: bool,
 
     mouse_pos: (Twips, Twips),
     is_mouse_down: bool,
@@ -271,6 +288,7 @@ impl Player {
                         shared_objects: HashMap::new(),
                         unbound_text_fields: Vec::new(),
                         timers: Timers::new(),
+                        audio_manager: AudioManager::new(),
                         external_interface: ExternalInterface::new(),
                         focus_tracker: FocusTracker::new(gc_context),
                     },
@@ -285,7 +303,13 @@ impl Player {
             movie_height,
             viewport_width: movie_width,
             viewport_height: movie_height,
+            viewport_scale_factor: 1.0,
             letterbox: Letterbox::Fullscreen,
+            scale_mode: StageScaleMode::default(),
+            scale_mode_set_by_script: false,
+            stage_align: EnumSet::empty(),
+            quality: StageQuality::default(),
+            show_menu: true,
 
             mouse_pos: (Twips::new(0), Twips::new(0)),
             is_mouse_down: false,
@@ -339,7 +363,9 @@ impl Player {
     /// off.
     pub fn fetch_root_movie(&mut self, movie_url: &str, parameters: PropertyMap<String>) {
         self.mutate_with_update_context(|context| {
-            let fetch = context.navigator.fetch(movie_url, RequestOptions::get());
+            let fetch = context
+                .navigator
+                .fetch_stream(movie_url, RequestOptions::get());
             let process = context.load_manager.load_root_movie(
                 context.player.clone().unwrap(),
                 fetch,
@@ -421,7 +447,7 @@ impl Player {
             let version_string = activation
                 .context
                 .system
-                .get_version_string(activation.context.avm1);
+                .get_version_string(activation.context.player_version);
             object.define_value(
                 activation.context.gc_context,
                 "$version",
@@ -478,6 +504,7 @@ impl Player {
             }
 
             self.update_timers(dt);
+            self.update_sounds();
             self.audio.tick();
         }
     }
@@ -517,6 +544,31 @@ impl Player {
         self.is_playing = v;
     }
 
+    pub fn player_version(&self) -> u8 {
+        self.player_version
+    }
+
+    /// Set the Flash Player version reported to content.
+    ///
+    /// This should be set before the root movie is loaded, as the movie's
+    /// `$version` variable is only set on load.
+    pub fn set_player_version(&mut self, player_version: u8) {
+        self.player_version = player_version;
+        self.mutate_with_update_context(|context| {
+            context.avm1.set_player_version(player_version);
+        });
+    }
+
+    /// The capabilities profile reported to content through
+    /// `System.capabilities`.
+    pub fn system_properties(&self) -> &SystemProperties {
+        &self.system
+    }
+
+    pub fn system_properties_mut(&mut self) -> &mut SystemProperties {
+        &mut self.system
+    }
+
     pub fn needs_render(&self) -> bool {
         self.needs_render
     }
@@ -538,8 +590,33 @@ impl Player {
     }
 
     fn should_letterbox(&self) -> bool {
-        self.letterbox == Letterbox::On
-            || (self.letterbox == Letterbox::Fullscreen && self.user_interface.is_fullscreen())
+        // Unscaled stages fill the whole viewport, so there is nothing to
+        // letterbox.
+        self.scale_mode != StageScaleMode::NoScale
+            && (self.letterbox == Letterbox::On
+                || (self.letterbox == Letterbox::Fullscreen && self.user_interface.is_fullscreen()))
+    }
+
+    pub fn scale_mode(&self) -> StageScaleMode {
+        self.scale_mode
+    }
+
+    pub fn set_scale_mode(&mut self, scale_mode: StageScaleMode) {
+        self.scale_mode = scale_mode;
+        self.build_matrices();
+    }
+
+    pub fn show_menu(&self) -> bool {
+        self.show_menu
+    }
+
+    pub fn stage_align(&self) -> EnumSet<StageAlign> {
+        self.stage_align
+    }
+
+    pub fn set_stage_align(&mut self, stage_align: EnumSet<StageAlign>) {
+        self.stage_align = stage_align;
+        self.build_matrices();
     }
 
     pub fn warn_on_unsupported_content(&self) -> bool {
@@ -562,10 +639,48 @@ impl Player {
         (self.viewport_width, self.viewport_height)
     }
 
-    pub fn set_viewport_dimensions(&mut self, width: u32, height: u32) {
+    /// Set the size of the viewport in physical pixels, along with the
+    /// number of physical pixels per logical pixel of the display.
+    pub fn set_viewport_dimensions(&mut self, width: u32, height: u32, scale_factor: f64) {
+        let is_resized = (width, height) != (self.viewport_width, self.viewport_height)
+            || (scale_factor - self.viewport_scale_factor).abs() >= std::f64::EPSILON;
         self.viewport_width = width;
         self.viewport_height = height;
+        self.viewport_scale_factor = scale_factor;
         self.build_matrices();
+
+        // Unscaled stages take on the size of the viewport, so content is
+        // told about the change.
+        if is_resized && self.scale_mode == StageScaleMode::NoScale {
+            self.mutate_with_update_context(|context| {
+                if Self::is_avm2_root(context) {
+                    if let Some(stage) = context.avm2.stage() {
+                        let event_proto = context.avm2.prototypes().event;
+                        if let Err(e) =
+                            Avm2::dispatch_event(context, stage, event_proto, &["resize".into()])
+                        {
+                            context.avm2.clear_error();
+                            log::error!(
+                                "Encountered AVM2 error when dispatching resize event: {}",
+                                e
+                            );
+                        }
+                    }
+                } else if let Some(root) = context.levels.get(&0).copied() {
+                    context.action_queue.queue_actions(
+                        root,
+                        ActionType::NotifyListeners {
+                            listener: "Stage",
+                            method: "onResize",
+                            args: vec![],
+                        },
+                        false,
+                    );
+                }
+
+                Self::run_actions(context);
+            });
+        }
     }
 
     pub fn handle_event(&mut self, event: PlayerEvent) {
@@ -767,6 +882,10 @@ impl Player {
                 _ => (),
             }
 
+            if Self::is_avm2_root(context) {
+                Self::dispatch_avm2_input_event(context, event, is_mouse_down);
+            }
+
             Self::run_actions(context);
         });
         self.is_mouse_down = is_mouse_down;
@@ -815,18 +934,13 @@ impl Player {
         let mut new_cursor = self.mouse_cursor;
         let hover_changed = self.mutate_with_update_context(|context| {
             // Check hovered object.
-            let mut new_hovered = None;
-            for (_depth, level) in context.levels.clone().iter().rev() {
-                if new_hovered.is_none() {
-                    new_hovered = level.mouse_pick(context, *level, (mouse_pos.0, mouse_pos.1));
-                } else {
-                    break;
-                }
-            }
+            let new_hovered = Self::mouse_pick(context, mouse_pos);
 
             let cur_hovered = context.mouse_hovered_object;
 
             if cur_hovered.map(|d| d.as_ptr()) != new_hovered.map(|d| d.as_ptr()) {
+                Self::dispatch_avm2_roll_events(context, cur_hovered, new_hovered);
+
                 // RollOut of previous node.
                 if let Some(node) = cur_hovered {
                     if !node.removed() {
@@ -859,29 +973,247 @@ impl Player {
         hover_changed
     }
 
-    /// Preload the first movie in the player.
+    /// Find the object under a given stage position that should receive mouse
+    /// events.
+    ///
+    /// AVM2 levels pick any interactive object; AVM1 levels only pick objects
+    /// that act as buttons.
+    fn mouse_pick<'gc>(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        pos: (Twips, Twips),
+    ) -> Option<DisplayObject<'gc>> {
+        for (_depth, level) in context.levels.clone().iter().rev() {
+            let picked = if let Avm2Value::Object(_) = level.object2() {
+                level.mouse_pick_avm2(context, *level, pos).hit()
+            } else {
+                level.mouse_pick(context, *level, pos)
+            };
+
+            if picked.is_some() {
+                return picked;
+            }
+        }
+
+        None
+    }
+
+    /// Whether the root movie is an AVM2 movie, and so should be sent AVM2
+    /// input events.
+    fn is_avm2_root(context: &UpdateContext<'_, '_, '_>) -> bool {
+        matches!(
+            context.levels.get(&0).map(|root| root.object2()),
+            Some(Avm2Value::Object(_))
+        )
+    }
+
+    /// Fire the AVM2 mouse and keyboard events for a player event.
+    fn dispatch_avm2_input_event<'gc>(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        event: PlayerEvent,
+        button_down: bool,
+    ) {
+        let mouse_pos = *context.mouse_position;
+
+        match event {
+            PlayerEvent::MouseDown { .. } => {
+                let target = context.mouse_hovered_object;
+                Self::dispatch_avm2_mouse_event(context, "mouseDown", target, None, true, 0);
+            }
+            PlayerEvent::MouseUp { .. } => {
+                // The hovered object is held while the mouse is down, so it's
+                // still the object that was pressed.
+                let pressed = context.mouse_hovered_object;
+                let target = Self::mouse_pick(context, mouse_pos);
+                Self::dispatch_avm2_mouse_event(context, "mouseUp", target, None, false, 0);
+                if pressed.map(|d| d.as_ptr()) == target.map(|d| d.as_ptr()) {
+                    Self::dispatch_avm2_mouse_event(context, "click", target, None, false, 0);
+                }
+            }
+            PlayerEvent::MouseMove { .. } => {
+                let target = Self::mouse_pick(context, mouse_pos);
+                Self::dispatch_avm2_mouse_event(context, "mouseMove", target, None, button_down, 0);
+            }
+            PlayerEvent::MouseWheel { delta } => {
+                let target = Self::mouse_pick(context, mouse_pos);
+                Self::dispatch_avm2_mouse_event(
+                    context,
+                    "mouseWheel",
+                    target,
+                    None,
+                    button_down,
+                    delta.lines() as i32,
+                );
+            }
+            PlayerEvent::KeyDown { key_code } => {
+                Self::dispatch_avm2_keyboard_event(context, "keyDown", key_code);
+            }
+            PlayerEvent::KeyUp { key_code } => {
+                Self::dispatch_avm2_keyboard_event(context, "keyUp", key_code);
+            }
+            _ => (),
+        }
+    }
+
+    /// Fire an AVM2 `MouseEvent` on a display object.
+    ///
+    /// This must only be called for AVM2 movies. A `target` of `None` fires
+    /// the event on the stage. Targets without an AVM2 object receive no
+    /// event. `rollOut` and `rollOver` do not bubble; all other mouse events
+    /// do.
+    fn dispatch_avm2_mouse_event<'gc>(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        event_type: &'static str,
+        target: Option<DisplayObject<'gc>>,
+        related: Option<DisplayObject<'gc>>,
+        button_down: bool,
+        delta: i32,
+    ) {
+        let target_object = match target.map(|target| target.object2()) {
+            Some(Avm2Value::Object(object)) => object,
+            Some(_) => return,
+            None => match context.avm2.stage() {
+                Some(stage) => stage,
+                None => return,
+            },
+        };
+        let related_object = match related.map(|related| related.object2()) {
+            Some(Avm2Value::Object(object)) => object.into(),
+            _ => Avm2Value::Null,
+        };
+        let (local_x, local_y) = match target {
+            Some(target) => target.global_to_local(*context.mouse_position),
+            None => *context.mouse_position,
+        };
+        let bubbles = !matches!(event_type, "rollOut" | "rollOver");
+        let mouse_event_proto = context.avm2.prototypes().mouse_event;
+        let args = [
+            event_type.into(),
+            bubbles.into(),
+            false.into(),
+            local_x.to_pixels().into(),
+            local_y.to_pixels().into(),
+            related_object,
+            context.input.is_key_down(KeyCode::Control).into(),
+            context.input.is_key_down(KeyCode::Alt).into(),
+            context.input.is_key_down(KeyCode::Shift).into(),
+            button_down.into(),
+            delta.into(),
+        ];
+
+        if let Err(e) = Avm2::dispatch_event(context, target_object, mouse_event_proto, &args) {
+            context.avm2.clear_error();
+            log::error!("Unhandled AVM2 exception in {} handler: {}", event_type, e);
+        }
+    }
+
+    /// Fire the AVM2 events for the mouse moving from one object to another.
+    ///
+    /// `mouseOut` and `mouseOver` are fired on the objects themselves and
+    /// bubble. `rollOut` and `rollOver` are fired on the objects and on each
+    /// of their ancestors that the mouse has left or entered.
+    fn dispatch_avm2_roll_events<'gc>(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        old: Option<DisplayObject<'gc>>,
+        new: Option<DisplayObject<'gc>>,
+    ) {
+        if !Self::is_avm2_root(context) {
+            return;
+        }
+
+        let old = old.filter(|old| !old.removed());
+        let ancestry = |node: Option<DisplayObject<'gc>>| {
+            let mut ancestry = Vec::new();
+            let mut node = node;
+            while let Some(n) = node {
+                ancestry.push(n);
+                node = n.parent();
+            }
+            ancestry
+        };
+        let old_ancestry = ancestry(old);
+        let new_ancestry = ancestry(new);
+        let contains = |ancestry: &[DisplayObject<'gc>], node: DisplayObject<'gc>| {
+            ancestry
+                .iter()
+                .any(|other| DisplayObject::ptr_eq(*other, node))
+        };
+
+        if old.is_some() {
+            Self::dispatch_avm2_mouse_event(context, "mouseOut", old, new, false, 0);
+            for node in old_ancestry.iter() {
+                if !contains(&new_ancestry, *node) {
+                    Self::dispatch_avm2_mouse_event(context, "rollOut", Some(*node), new, false, 0);
+                }
+            }
+        }
+
+        if new.is_some() {
+            Self::dispatch_avm2_mouse_event(context, "mouseOver", new, old, false, 0);
+            for node in new_ancestry.iter().rev() {
+                if !contains(&old_ancestry, *node) {
+                    Self::dispatch_avm2_mouse_event(
+                        context,
+                        "rollOver",
+                        Some(*node),
+                        old,
+                        false,
+                        0,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Fire an AVM2 `KeyboardEvent` on the focused object, or on the stage if
+    /// nothing has focus.
+    fn dispatch_avm2_keyboard_event<'gc>(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        event_type: &'static str,
+        key_code: KeyCode,
+    ) {
+        let target_object = match context.focus_tracker.get().map(|focus| focus.object2()) {
+            Some(Avm2Value::Object(object)) => object,
+            _ => match context.avm2.stage() {
+                Some(stage) => stage,
+                None => return,
+            },
+        };
+        let char_code = context.input.last_key_char().map(|c| c as u32).unwrap_or(0);
+        let keyboard_event_proto = context.avm2.prototypes().keyboard_event;
+        let args = [
+            event_type.into(),
+            true.into(),
+            false.into(),
+            char_code.into(),
+            (key_code as u32).into(),
+            0.into(),
+            context.input.is_key_down(KeyCode::Control).into(),
+            context.input.is_key_down(KeyCode::Alt).into(),
+            context.input.is_key_down(KeyCode::Shift).into(),
+        ];
+
+        if let Err(e) = Avm2::dispatch_event(context, target_object, keyboard_event_proto, &args) {
+            context.avm2.clear_error();
+            log::error!("Unhandled AVM2 exception in {} handler: {}", event_type, e);
+        }
+    }
+
+    /// Start preloading the first movie in the player.
     ///
     /// This should only be called once. Further movie loads should preload the
-    /// specific `MovieClip` referenced.
+    /// specific `MovieClip` referenced, as should the rest of the first movie
+    /// when more of it loads.
     fn preload(&mut self) {
         let mut is_action_script_3 = false;
         self.mutate_with_update_context(|context| {
-            let mut morph_shapes = fnv::FnvHashMap::default();
             let root = *context.levels.get(&0).expect("root level");
-            root.as_movie_clip()
-                .unwrap()
-                .preload(context, &mut morph_shapes);
+            root.as_movie_clip().unwrap().preload(context);
 
             let lib = context
                 .library
                 .library_for_movie_mut(root.as_movie_clip().unwrap().movie().unwrap());
 
             is_action_script_3 = lib.avm_type() == AvmType::Avm2;
-            // Finalize morph shapes.
-            for (id, static_data) in morph_shapes {
-                let morph_shape = MorphShape::new(context.gc_context, static_data);
-                lib.register_character(id, crate::character::Character::MorphShape(morph_shape));
-            }
         });
         if is_action_script_3 && self.warn_on_unsupported_content {
             self.user_interface.message("This SWF contains ActionScript 3 which is not yet supported by Ruffle. The movie may not work as intended.");
@@ -930,7 +1262,7 @@ impl Player {
             };
 
             for (_depth, level) in root_data.levels.iter() {
-                level.render(&mut render_context);
+                level.render_with_mask(&mut render_context);
             }
         });
         transform_stack.pop();
@@ -1092,6 +1424,7 @@ impl Player {
                     if let Err(e) =
                         Avm2::run_stack_frame_for_callable(callable, reciever, &args[..], context)
                     {
+                        context.avm2.clear_error();
                         log::error!("Unhandled AVM2 exception in event handler: {}", e);
                     }
                 }
@@ -1104,28 +1437,58 @@ impl Player {
         let (movie_width, movie_height) = (self.movie_width as f32, self.movie_height as f32);
         let (viewport_width, viewport_height) =
             (self.viewport_width as f32, self.viewport_height as f32);
-        let movie_aspect = movie_width / movie_height;
-        let viewport_aspect = viewport_width / viewport_height;
-        let (scale, margin_width, margin_height) = if viewport_aspect > movie_aspect {
-            let scale = viewport_height / movie_height;
-            (scale, (viewport_width - movie_width * scale) / 2.0, 0.0)
+        let (scale_x, scale_y) = match self.scale_mode {
+            StageScaleMode::ShowAll => {
+                let scale = (viewport_width / movie_width).min(viewport_height / movie_height);
+                (scale, scale)
+            }
+            StageScaleMode::NoBorder => {
+                let scale = (viewport_width / movie_width).max(viewport_height / movie_height);
+                (scale, scale)
+            }
+            StageScaleMode::ExactFit => {
+                (viewport_width / movie_width, viewport_height / movie_height)
+            }
+            StageScaleMode::NoScale => {
+                // Unscaled content is still drawn at its logical size on
+                // HiDPI displays.
+                let scale = self.viewport_scale_factor as f32;
+                (scale, scale)
+            }
+        };
+
+        // Position the movie within the viewport according to the stage
+        // alignment, centering it along any unaligned axis.
+        let extra_width = viewport_width - movie_width * scale_x;
+        let extra_height = viewport_height - movie_height * scale_y;
+        let margin_left = if self.stage_align.contains(StageAlign::Left) {
+            0.0
+        } else if self.stage_align.contains(StageAlign::Right) {
+            extra_width
+        } else {
+            extra_width / 2.0
+        };
+        let margin_top = if self.stage_align.contains(StageAlign::Top) {
+            0.0
+        } else if self.stage_align.contains(StageAlign::Bottom) {
+            extra_height
         } else {
-            let scale = viewport_width / movie_width;
-            (scale, 0.0, (viewport_height - movie_height * scale) / 2.0)
+            extra_height / 2.0
         };
+
         self.view_matrix = Matrix {
-            a: scale,
+            a: scale_x,
             b: 0.0,
             c: 0.0,
-            d: scale,
-            tx: Twips::from_pixels(margin_width.into()),
-            ty: Twips::from_pixels(margin_height.into()),
+            d: scale_y,
+            tx: Twips::from_pixels(margin_left.into()),
+            ty: Twips::from_pixels(margin_top.into()),
         };
         self.inverse_view_matrix = self.view_matrix;
         self.inverse_view_matrix.invert();
 
         self.view_bounds = if self.should_letterbox() {
-            // No letterbox: movie area
+            // Letterbox: movie area
             BoundingBox {
                 x_min: Twips::new(0),
                 y_min: Twips::new(0),
@@ -1135,13 +1498,11 @@ impl Player {
             }
         } else {
             // No letterbox: full visible stage area
-            let margin_width = f64::from(margin_width / scale);
-            let margin_height = f64::from(margin_height / scale);
             BoundingBox {
-                x_min: Twips::from_pixels(-margin_width),
-                y_min: Twips::from_pixels(-margin_height),
-                x_max: Twips::from_pixels(f64::from(self.movie_width) + margin_width),
-                y_max: Twips::from_pixels(f64::from(self.movie_height) + margin_height),
+                x_min: Twips::from_pixels(f64::from(-margin_left / scale_x)),
+                y_min: Twips::from_pixels(f64::from(-margin_top / scale_y)),
+                x_max: Twips::from_pixels(f64::from((viewport_width - margin_left) / scale_x)),
+                y_max: Twips::from_pixels(f64::from((viewport_height - margin_top) / scale_y)),
                 valid: true,
             }
         };
@@ -1153,6 +1514,8 @@ impl Player {
     where
         F: for<'a, 'gc> FnOnce(&mut UpdateContext<'a, 'gc, '_>) -> R,
     {
+        let old_stage_layout = (self.scale_mode, self.stage_align);
+
         // We have to do this piecewise borrowing of fields before the closure to avoid
         // completely borrowing `self`.
         let (
@@ -1173,10 +1536,18 @@ impl Player {
             storage,
             locale,
             logging,
+            user_interface,
             needs_render,
             max_execution_duration,
             current_frame,
             time_offset,
+            viewport_size,
+            scale_mode,
+            scale_mode_set_by_script,
+            stage_align,
+            quality,
+            show_menu,
+            frame_rate,
         ) = (
             self.player_version,
             &self.swf,
@@ -1195,13 +1566,24 @@ impl Player {
             self.storage.deref_mut(),
             self.locale.deref_mut(),
             self.log.deref_mut(),
+            self.user_interface.deref_mut(),
             &mut self.needs_render,
             self.max_execution_duration,
             &mut self.current_frame,
             &mut self.time_offset,
+            (
+                (f64::from(self.viewport_width) / self.viewport_scale_factor) as u32,
+                (f64::from(self.viewport_height) / self.viewport_scale_factor) as u32,
+            ),
+            &mut self.scale_mode,
+            &mut self.scale_mode_set_by_script,
+            &mut self.stage_align,
+            &mut self.quality,
+            &mut self.show_menu,
+            &mut self.frame_rate,
         );
 
-        self.gc_arena.mutate(|gc_context, gc_root| {
+        let ret = self.gc_arena.mutate(|gc_context, gc_root| {
             let mut root_data = gc_root.0.write(gc_context);
             let mouse_hovered_object = root_data.mouse_hovered_object;
             let focus_tracker = root_data.focus_tracker;
@@ -1216,6 +1598,7 @@ impl Player {
                 shared_objects,
                 unbound_text_fields,
                 timers,
+                audio_manager,
                 external_interface,
             ) = root_data.update_context_params();
 
@@ -1236,6 +1619,13 @@ impl Player {
                 mouse_position,
                 drag_object,
                 stage_size: (stage_width, stage_height),
+                viewport_size,
+                stage_scale_mode: scale_mode,
+                stage_scale_mode_set_by_script: scale_mode_set_by_script,
+                stage_align,
+                stage_quality: quality,
+                show_menu,
+                frame_rate,
                 player,
                 load_manager,
                 system: system_properties,
@@ -1243,9 +1633,11 @@ impl Player {
                 storage,
                 locale,
                 log: logging,
+                ui: user_interface,
                 shared_objects,
                 unbound_text_fields,
                 timers,
+                audio_manager,
                 needs_render,
                 avm1,
                 avm2,
@@ -1269,7 +1661,14 @@ impl Player {
             root_data.mouse_hovered_object = update_context.mouse_hovered_object;
 
             ret
-        })
+        });
+
+        // Scripts may have changed how the stage is laid out in the viewport.
+        if (self.scale_mode, self.stage_align) != old_stage_layout {
+            self.build_matrices();
+        }
+
+        ret
     }
 
     /// Loads font data from the given buffer.
@@ -1311,11 +1710,74 @@ impl Player {
         self.update_roll_over();
 
         // GC
-        self.gc_arena.collect_debt();
+        self.collect_garbage();
 
         rval
     }
 
+    /// Run the incremental garbage collector, releasing weakly-held AS3
+    /// objects along the way.
+    ///
+    /// A sweep must never be left half-done while weak references exist, as
+    /// scripts could then get hold of an object that is about to be freed;
+    /// see `avm2::weak` for details.
+    fn collect_garbage(&mut self) {
+        let (is_requested, sweeping) = self.gc_arena.mutate(|gc_context, gc_root| {
+            let root_data = gc_root.0.read();
+            let weak_references = root_data.avm2.weak_references();
+
+            (
+                weak_references.is_collection_requested(),
+                weak_references.prepare_collection(gc_context),
+            )
+        });
+
+        // The collector only takes on debt for new allocations while it is
+        // awake, so right after `prepare_collection` allocated, any debt
+        // means that a cycle is underway.
+        if is_requested {
+            // The cycle that is underway may have marked objects that have
+            // since become unreachable, so finish it before running a fresh
+            // one.
+            if self.gc_arena.allocation_debt() > 0.0 {
+                self.gc_arena.collect_all();
+            }
+            self.gc_arena.collect_all();
+        } else if let Some(sweeping) = sweeping {
+            self.gc_arena.collect_debt();
+
+            if !sweeping.get() {
+                return;
+            }
+
+            self.gc_arena.mutate(|gc_context, gc_root| {
+                gc_root
+                    .0
+                    .read()
+                    .avm2
+                    .weak_references()
+                    .prepare_collection(gc_context);
+            });
+            if self.gc_arena.allocation_debt() > 0.0 {
+                // This only finishes the sweep, rather than running a whole
+                // new cycle.
+                self.gc_arena.collect_all();
+            }
+        } else {
+            self.gc_arena.collect_debt();
+            return;
+        }
+
+        self.gc_arena.mutate(|gc_context, gc_root| {
+            gc_root
+                .0
+                .write(gc_context)
+                .avm2
+                .weak_references_mut()
+                .finish_collection(gc_context);
+        });
+    }
+
     pub fn flush_shared_objects(&mut self) {
         self.update(|context| {
             let mut activation =
@@ -1334,6 +1796,15 @@ impl Player {
             self.mutate_with_update_context(|context| Timers::update_timers(context, dt));
     }
 
+    /// Update all sounds played by AS3, firing `soundComplete` on any that
+    /// have finished.
+    pub fn update_sounds(&mut self) {
+        // `AudioManager::update_sounds` is bound to a single `'gc` lifetime,
+        // so it can't be passed directly where any `'gc` is expected.
+        #[allow(clippy::redundant_closure)]
+        self.mutate_with_update_context(|context| AudioManager::update_sounds(context));
+    }
+
     /// Returns whether this player consumes mouse wheel events.
     /// Used by web to prevent scrolling.
     pub fn should_prevent_scrolling(&mut self) -> bool {
@@ -1377,47 +1848,59 @@ impl Player {
         let viewport_width = self.viewport_width as f32;
         let viewport_height = self.viewport_height as f32;
 
-        let margin_width = self.view_matrix.tx.to_pixels() as f32;
-        let margin_height = self.view_matrix.ty.to_pixels() as f32;
-        if margin_height > 0.0 {
+        // The movie area may be offset from the center of the viewport, so
+        // each margin is drawn separately.
+        let margin_left = self.view_matrix.tx.to_pixels() as f32;
+        let margin_top = self.view_matrix.ty.to_pixels() as f32;
+        let margin_right =
+            viewport_width - margin_left - self.movie_width as f32 * self.view_matrix.a;
+        let margin_bottom =
+            viewport_height - margin_top - self.movie_height as f32 * self.view_matrix.d;
+
+        if margin_top > 0.0 {
             self.renderer.draw_rect(
                 black.clone(),
                 &Matrix::create_box(
                     viewport_width,
-                    margin_height,
+                    margin_top,
                     0.0,
                     Twips::default(),
                     Twips::default(),
                 ),
             );
+        }
+        if margin_bottom > 0.0 {
             self.renderer.draw_rect(
-                black,
+                black.clone(),
                 &Matrix::create_box(
                     viewport_width,
-                    margin_height,
+                    margin_bottom,
                     0.0,
                     Twips::default(),
-                    Twips::from_pixels((viewport_height - margin_height) as f64),
+                    Twips::from_pixels((viewport_height - margin_bottom) as f64),
                 ),
             );
-        } else if margin_width > 0.0 {
+        }
+        if margin_left > 0.0 {
             self.renderer.draw_rect(
                 black.clone(),
                 &Matrix::create_box(
-                    margin_width,
+                    margin_left,
                     viewport_height,
                     0.0,
                     Twips::default(),
                     Twips::default(),
                 ),
             );
+        }
+        if margin_right > 0.0 {
             self.renderer.draw_rect(
                 black,
                 &Matrix::create_box(
-                    margin_width,
+                    margin_right,
                     viewport_height,
                     0.0,
-                    Twips::from_pixels((viewport_width - margin_width) as f64),
+                    Twips::from_pixels((viewport_width - margin_right) as f64),
                     Twips::default(),
                 ),
             );
@@ -1,9 +1,14 @@
 use crate::backend::navigator::url_from_relative_path;
 use crate::property_map::PropertyMap;
+use flate2::write::ZlibDecoder;
 use gc_arena::Collect;
 use std::convert::TryInto;
+use std::fmt;
+use std::io::Write;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use swf::read::SwfRead;
 use swf::{Header, TagCode};
 
 pub type Error = Box<dyn std::error::Error>;
@@ -12,6 +17,9 @@ pub type SwfStream<R> = swf::read::Reader<std::io::Cursor<R>>;
 
 /// An open, fully parsed SWF movie ready to play back, either in a Player or a
 /// MovieClip.
+///
+/// A movie may still be loading, in which case its data grows as more of it is
+/// decoded by a `SwfMovieDecoder`.
 #[derive(Debug, Clone, Collect)]
 #[collect(require_static)]
 pub struct SwfMovie {
@@ -19,7 +27,12 @@ pub struct SwfMovie {
     header: Header,
 
     /// Uncompressed SWF data.
-    data: Vec<u8>,
+    data: MovieData,
+
+    /// The number of bytes before `data` in the uncompressed SWF. This is
+    /// only known for movies decoded by a `SwfMovieDecoder`, and is used to
+    /// report their progress while they load.
+    header_length: u32,
 
     /// The URL the SWF was downloaded from.
     url: Option<String>,
@@ -40,12 +53,23 @@ impl SwfMovie {
                 frame_rate: 1.0,
                 num_frames: 0,
             },
-            data: vec![],
+            data: MovieData::from(vec![]),
+            header_length: 0,
             url: None,
             parameters: PropertyMap::new(),
         }
     }
 
+    /// Construct an empty, single-frame movie standing in for an image that
+    /// was loaded into a clip, such as by `loadMovie`.
+    pub fn from_loaded_image(swf_version: u8, url: String, length: usize) -> Self {
+        let mut movie = Self::empty(swf_version);
+        movie.header.num_frames = 1;
+        movie.header.uncompressed_length = length as u32;
+        movie.url = Some(url);
+        movie
+    }
+
     /// Construct a movie from an existing movie with any particular data on
     /// it.
     ///
@@ -54,7 +78,8 @@ impl SwfMovie {
     pub fn from_movie_and_subdata(&self, data: Vec<u8>, source: &SwfMovie) -> Self {
         Self {
             header: self.header.clone(),
-            data,
+            data: MovieData::from(data),
+            header_length: self.header_length,
             url: source.url.clone(),
             parameters: source.parameters.clone(),
         }
@@ -89,7 +114,8 @@ impl SwfMovie {
 
         Ok(Self {
             header,
-            data,
+            data: MovieData::from(data),
+            header_length: 0,
             url,
             parameters: PropertyMap::new(),
         })
@@ -104,8 +130,28 @@ impl SwfMovie {
         self.header.version
     }
 
-    pub fn data(&self) -> &[u8] {
-        &self.data
+    /// A snapshot of the uncompressed SWF data that has been loaded so far.
+    ///
+    /// The snapshot doesn't grow as more of the movie loads.
+    pub fn data(&self) -> MovieBytes {
+        self.data.snapshot()
+    }
+
+    /// Whether all of the movie's data has been loaded.
+    pub fn is_loaded(&self) -> bool {
+        self.data.is_complete()
+    }
+
+    /// The number of bytes of the uncompressed movie, including its header,
+    /// that have been loaded so far.
+    pub fn loaded_length(&self) -> u32 {
+        if self.is_loaded() {
+            return self.header.uncompressed_length;
+        }
+
+        // The header may understate the length of the movie.
+        let loaded = self.header_length as usize + self.data.len();
+        loaded.min(self.header.uncompressed_length as usize) as u32
     }
 
     pub fn width(&self) -> u32 {
@@ -130,6 +176,366 @@ impl SwfMovie {
     }
 }
 
+/// The uncompressed data of a movie, which grows as the movie loads.
+///
+/// Readers take a snapshot of the data loaded so far, which never changes.
+/// The data is appended to a buffer that is allocated up front using the
+/// length given in the movie's header, so loading never moves or copies the
+/// data that readers can see. The data is only moved to a bigger buffer if
+/// the header understates the length of the movie, and snapshots that were
+/// taken before that keep the old buffer alive.
+struct MovieData {
+    /// The data that has been loaded so far.
+    loaded: RwLock<MovieBytes>,
+
+    /// Whether the movie has finished loading.
+    complete: AtomicBool,
+}
+
+impl MovieData {
+    /// Allocate room for a movie that is yet to be loaded.
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            loaded: RwLock::new(MovieBytes::from(Vec::with_capacity(capacity))),
+            complete: AtomicBool::new(false),
+        }
+    }
+
+    /// Take a snapshot of the data loaded so far.
+    fn snapshot(&self) -> MovieBytes {
+        self.loaded.read().unwrap().clone()
+    }
+
+    fn len(&self) -> usize {
+        self.loaded.read().unwrap().len
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete.load(Ordering::Acquire)
+    }
+
+    /// Append newly loaded data.
+    fn append(&self, data: &[u8]) {
+        let mut loaded = self.loaded.write().unwrap();
+        if loaded.buffer.append(loaded.len, data) {
+            loaded.len += data.len();
+        } else {
+            // Doubling the capacity keeps the cost of moving the data linear
+            // in the length of the movie.
+            let capacity = (loaded.len + data.len()).max(loaded.buffer.capacity * 2);
+            let mut grown = Vec::with_capacity(capacity);
+            grown.extend_from_slice(&loaded);
+            grown.extend_from_slice(data);
+            *loaded = MovieBytes::from(grown);
+        }
+    }
+
+    fn set_complete(&self) {
+        self.complete.store(true, Ordering::Release);
+    }
+}
+
+impl From<Vec<u8>> for MovieData {
+    /// Hold the data of a movie that has been loaded in full.
+    fn from(data: Vec<u8>) -> Self {
+        Self {
+            loaded: RwLock::new(MovieBytes::from(data)),
+            complete: AtomicBool::new(true),
+        }
+    }
+}
+
+impl Clone for MovieData {
+    fn clone(&self) -> Self {
+        Self {
+            loaded: RwLock::new(self.snapshot()),
+            complete: AtomicBool::new(self.is_complete()),
+        }
+    }
+}
+
+impl fmt::Debug for MovieData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MovieData")
+            .field("len", &self.len())
+            .field("complete", &self.is_complete())
+            .finish()
+    }
+}
+
+/// A snapshot of the uncompressed data of a movie.
+#[derive(Clone, Collect)]
+#[collect(require_static)]
+pub struct MovieBytes {
+    buffer: Arc<MovieBuffer>,
+
+    /// The number of bytes at the start of the buffer that belong to this
+    /// snapshot. All of them have been written, and none of them are ever
+    /// written again.
+    len: usize,
+}
+
+impl From<Vec<u8>> for MovieBytes {
+    fn from(data: Vec<u8>) -> Self {
+        let len = data.len();
+        Self {
+            buffer: Arc::new(MovieBuffer::from(data)),
+            len,
+        }
+    }
+}
+
+impl std::ops::Deref for MovieBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY: The first `len` bytes of the buffer have been written, and
+        // `MovieBuffer::append` never writes to them again.
+        unsafe { std::slice::from_raw_parts(self.buffer.ptr, self.len) }
+    }
+}
+
+impl fmt::Debug for MovieBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MovieBytes")
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+/// A fixed-size allocation that movie data is appended to.
+///
+/// Bytes are only ever written once, past the end of everything written
+/// before, so the bytes that have already been written can be read while more
+/// are appended.
+struct MovieBuffer {
+    ptr: *mut u8,
+    capacity: usize,
+
+    /// The number of bytes at the start of the buffer that have been claimed
+    /// by an append.
+    claimed: AtomicUsize,
+}
+
+impl MovieBuffer {
+    /// Write `data` right after the first `len` bytes of the buffer.
+    ///
+    /// Returns false, without writing anything, if there isn't enough room or
+    /// if something else has already been appended after the first `len`
+    /// bytes.
+    fn append(&self, len: usize, data: &[u8]) -> bool {
+        if self.capacity - len < data.len()
+            || self
+                .claimed
+                .compare_exchange(len, len + data.len(), Ordering::AcqRel, Ordering::Acquire)
+                .is_err()
+        {
+            return false;
+        }
+
+        // SAFETY: The bytes from `len` onwards were unclaimed, and so have
+        // never been handed out to readers. Claiming them makes sure that no
+        // other append writes to them.
+        unsafe {
+            std::ptr::copy_nonoverlapping(data.as_ptr(), self.ptr.add(len), data.len());
+        }
+
+        true
+    }
+}
+
+impl From<Vec<u8>> for MovieBuffer {
+    /// Take over the allocation of a `Vec`, including its spare capacity.
+    fn from(data: Vec<u8>) -> Self {
+        let mut data = std::mem::ManuallyDrop::new(data);
+        Self {
+            ptr: data.as_mut_ptr(),
+            capacity: data.capacity(),
+            claimed: AtomicUsize::new(data.len()),
+        }
+    }
+}
+
+impl Drop for MovieBuffer {
+    fn drop(&mut self) {
+        // SAFETY: The allocation came from a `Vec` with this capacity, and
+        // `u8` needs no dropping.
+        unsafe {
+            drop(Vec::from_raw_parts(self.ptr, 0, self.capacity));
+        }
+    }
+}
+
+// SAFETY: The buffer owns its allocation, and appends to it from different
+// threads never write to the same bytes, as each claims its bytes atomically.
+unsafe impl Send for MovieBuffer {}
+unsafe impl Sync for MovieBuffer {}
+
+/// Decodes an SWF as it is downloaded, so that the movie can start playing
+/// before all of it has arrived.
+///
+/// Uncompressed and zlib compressed movies are available as soon as their
+/// header has been decoded, and their data grows with every chunk decoded
+/// after that. LZMA compressed movies are only available once they have been
+/// received in full.
+pub struct SwfMovieDecoder {
+    /// The URL the SWF is being downloaded from.
+    url: Option<String>,
+
+    /// The parameters to give the movie.
+    parameters: PropertyMap<String>,
+
+    /// The signature, version and uncompressed length at the start of the
+    /// SWF, which are never compressed.
+    signature: Vec<u8>,
+
+    /// Decompresses the rest of a zlib compressed SWF.
+    zlib: Option<ZlibDecoder<Vec<u8>>>,
+
+    /// Data that hasn't been added to the movie yet, either because the
+    /// movie's header hasn't been decoded or because it is LZMA compressed.
+    pending: Vec<u8>,
+
+    /// The movie, once its header has been decoded.
+    movie: Option<Arc<SwfMovie>>,
+}
+
+impl SwfMovieDecoder {
+    /// The number of bytes at the start of an SWF that are never compressed.
+    const SIGNATURE_LENGTH: usize = 8;
+
+    pub fn new(url: Option<String>, parameters: PropertyMap<String>) -> Self {
+        Self {
+            url,
+            parameters,
+            signature: Vec::with_capacity(Self::SIGNATURE_LENGTH),
+            zlib: None,
+            pending: vec![],
+            movie: None,
+        }
+    }
+
+    /// The movie being decoded, once its header has been decoded.
+    pub fn movie(&self) -> Option<&Arc<SwfMovie>> {
+        self.movie.as_ref()
+    }
+
+    /// Decode the next chunk of the SWF.
+    pub fn decode_chunk(&mut self, mut chunk: &[u8]) -> Result<(), Error> {
+        if self.signature.len() < Self::SIGNATURE_LENGTH {
+            let count = chunk
+                .len()
+                .min(Self::SIGNATURE_LENGTH - self.signature.len());
+            self.signature.extend_from_slice(&chunk[..count]);
+            chunk = &chunk[count..];
+            if self.signature.len() < Self::SIGNATURE_LENGTH {
+                return Ok(());
+            }
+
+            if self.compression()? == swf::Compression::Zlib {
+                self.zlib = Some(ZlibDecoder::new(vec![]));
+            }
+        }
+
+        if let Some(zlib) = &mut self.zlib {
+            // Anything after the end of the zlib stream isn't part of the movie.
+            while !chunk.is_empty() {
+                match zlib.write(chunk)? {
+                    0 => break,
+                    count => chunk = &chunk[count..],
+                }
+            }
+            zlib.flush()?;
+            self.pending.append(zlib.get_mut());
+        } else {
+            self.pending.extend_from_slice(chunk);
+        }
+
+        if self.compression()? != swf::Compression::Lzma {
+            self.decode_pending()?;
+        }
+
+        Ok(())
+    }
+
+    /// Finish decoding, once the whole SWF has been received.
+    pub fn finish(mut self) -> Result<Arc<SwfMovie>, Error> {
+        if self.compression()? == swf::Compression::Lzma {
+            let mut data = std::mem::take(&mut self.signature);
+            data.append(&mut self.pending);
+            let mut movie = SwfMovie::from_data(&data, self.url)?;
+            movie.parameters = self.parameters;
+            return Ok(Arc::new(movie));
+        }
+
+        if let Some(zlib) = self.zlib.take() {
+            let mut rest = zlib
+                .finish()
+                .map_err(|e| format!("Error decompressing SWF, may be corrupt: {}", e))?;
+            self.pending.append(&mut rest);
+            self.decode_pending()?;
+        }
+
+        let movie = self.movie.ok_or("Unexpected end of SWF header")?;
+        movie.data.set_complete();
+        Ok(movie)
+    }
+
+    fn compression(&self) -> Result<swf::Compression, Error> {
+        Ok(swf::read::Reader::read_compression_type(
+            &self.signature[..],
+        )?)
+    }
+
+    /// Add the data decoded so far to the movie, creating it once its header
+    /// has been decoded.
+    fn decode_pending(&mut self) -> Result<(), Error> {
+        let movie = match &self.movie {
+            Some(movie) => movie,
+            None => {
+                let version = self.signature[3];
+                let uncompressed_length =
+                    u32::from_le_bytes(self.signature[4..8].try_into().unwrap());
+                let mut reader = swf::read::Reader::new(&self.pending[..], version);
+                let header = match (
+                    reader.read_rectangle(),
+                    reader.read_fixed8(),
+                    reader.read_u16(),
+                ) {
+                    (Ok(stage_size), Ok(frame_rate), Ok(num_frames)) => Header {
+                        compression: self.compression()?,
+                        version,
+                        uncompressed_length,
+                        stage_size,
+                        frame_rate,
+                        num_frames,
+                    },
+                    // Wait for the rest of the header.
+                    _ => return Ok(()),
+                };
+
+                let header_length = self.pending.len() - reader.get_ref().len();
+                self.pending.drain(..header_length);
+                let data_length = (uncompressed_length as usize)
+                    .saturating_sub(Self::SIGNATURE_LENGTH + header_length);
+                self.movie = Some(Arc::new(SwfMovie {
+                    header,
+                    data: MovieData::with_capacity(data_length),
+                    header_length: (Self::SIGNATURE_LENGTH + header_length) as u32,
+                    url: self.url.clone(),
+                    parameters: std::mem::take(&mut self.parameters),
+                }));
+                self.movie.as_ref().unwrap()
+            }
+        };
+
+        movie.data.append(&self.pending);
+        self.pending.clear();
+
+        Ok(())
+    }
+}
+
 /// A shared-ownership reference to some portion of an SWF datastream.
 #[derive(Debug, Clone, Collect)]
 #[collect(no_drop)]
@@ -137,16 +543,21 @@ pub struct SwfSlice {
     pub movie: Arc<SwfMovie>,
     pub start: usize,
     pub end: usize,
+
+    /// The snapshot of the movie's data that this slice refers to.
+    data: MovieBytes,
 }
 
 impl From<Arc<SwfMovie>> for SwfSlice {
     fn from(movie: Arc<SwfMovie>) -> Self {
-        let end = movie.data().len();
+        let data = movie.data();
+        let end = data.len();
 
         Self {
             movie,
             start: 0,
             end,
+            data,
         }
     }
 }
@@ -154,7 +565,7 @@ impl From<Arc<SwfMovie>> for SwfSlice {
 impl AsRef<[u8]> for SwfSlice {
     #[inline]
     fn as_ref(&self) -> &[u8] {
-        &self.movie.data()[self.start..self.end]
+        &self.data[self.start..self.end]
     }
 }
 
@@ -163,6 +574,7 @@ impl SwfSlice {
     #[inline]
     pub fn empty(movie: Arc<SwfMovie>) -> Self {
         Self {
+            data: movie.data(),
             movie,
             start: 0,
             end: 0,
@@ -174,13 +586,7 @@ impl SwfSlice {
     /// This is used primarily for converting owned data back into a slice: we
     /// reattach the SWF data to a fresh movie and return a new slice into it.
     pub fn owned_subslice(&self, data: Vec<u8>, source: &SwfMovie) -> Self {
-        let len = data.len();
-
-        Self {
-            movie: Arc::new(self.movie.from_movie_and_subdata(data, source)),
-            start: 0,
-            end: len,
-        }
+        Self::from(Arc::new(self.movie.from_movie_and_subdata(data, source)))
     }
 
     /// Construct a new SwfSlice from a regular slice.
@@ -188,7 +594,7 @@ impl SwfSlice {
     /// This function returns None if the given slice is not a subslice of the
     /// current slice.
     pub fn to_subslice(&self, slice: &[u8]) -> Option<SwfSlice> {
-        let self_pval = self.movie.data().as_ptr() as usize;
+        let self_pval = self.data.as_ptr() as usize;
         let slice_pval = slice.as_ptr() as usize;
 
         if (self_pval + self.start) <= slice_pval && slice_pval < (self_pval + self.end) {
@@ -196,6 +602,7 @@ impl SwfSlice {
                 movie: self.movie.clone(),
                 start: slice_pval - self_pval,
                 end: (slice_pval - self_pval) + slice.len(),
+                data: self.data.clone(),
             })
         } else {
             None
@@ -207,8 +614,8 @@ impl SwfSlice {
     /// This function allows subslices outside the current slice to be formed,
     /// as long as they are valid subslices of the movie itself.
     pub fn to_unbounded_subslice(&self, slice: &[u8]) -> Option<SwfSlice> {
-        let self_pval = self.movie.data().as_ptr() as usize;
-        let self_len = self.movie.data().len();
+        let self_pval = self.data.as_ptr() as usize;
+        let self_len = self.data.len();
         let slice_pval = slice.as_ptr() as usize;
 
         if self_pval <= slice_pval && slice_pval < (self_pval + self_len) {
@@ -216,6 +623,7 @@ impl SwfSlice {
                 movie: self.movie.clone(),
                 start: slice_pval - self_pval,
                 end: (slice_pval - self_pval) + slice.len(),
+                data: self.data.clone(),
             })
         } else {
             None
@@ -233,23 +641,24 @@ impl SwfSlice {
     /// movie, or the given reader refers to a different underlying movie, this
     /// function returns None.
     pub fn resize_to_reader(&self, reader: &mut SwfStream<&[u8]>, size: usize) -> Option<SwfSlice> {
-        if self.movie.data().as_ptr() as usize <= reader.get_ref().get_ref().as_ptr() as usize
+        if self.data.as_ptr() as usize <= reader.get_ref().get_ref().as_ptr() as usize
             && (reader.get_ref().get_ref().as_ptr() as usize)
-                < self.movie.data().as_ptr() as usize + self.movie.data().len()
+                < self.data.as_ptr() as usize + self.data.len()
         {
             let outer_offset =
-                reader.get_ref().get_ref().as_ptr() as usize - self.movie.data().as_ptr() as usize;
+                reader.get_ref().get_ref().as_ptr() as usize - self.data.as_ptr() as usize;
             let inner_offset = reader.get_ref().position() as usize;
             let new_start = outer_offset + inner_offset;
             let new_end = outer_offset + inner_offset + size;
 
-            let len = self.movie.data().len();
+            let len = self.data.len();
 
             if new_start < len && new_end < len {
                 Some(SwfSlice {
                     movie: self.movie.clone(),
                     start: new_start,
                     end: new_end,
+                    data: self.data.clone(),
                 })
             } else {
                 None
@@ -270,7 +679,7 @@ impl SwfSlice {
         let new_end = self.start + end;
 
         if new_start <= new_end {
-            self.to_subslice(&self.movie.data().get(new_start..new_end)?)
+            self.to_subslice(self.data.get(new_start..new_end)?)
         } else {
             None
         }
@@ -278,7 +687,32 @@ impl SwfSlice {
 
     /// Convert the SwfSlice into a standard data slice.
     pub fn data(&self) -> &[u8] {
-        &self.movie.data()[self.start..self.end]
+        &self.data[self.start..self.end]
+    }
+
+    /// The whole of the movie data that this slice is part of.
+    ///
+    /// Slices of this can be turned back into `SwfSlice`s.
+    pub fn movie_data(&self) -> &[u8] {
+        &self.data[..]
+    }
+
+    /// Construct a new SwfSlice from a start and an end relative to the
+    /// start of the movie, rather than the start of this slice.
+    ///
+    /// This function yields None if the range is invalid or extends past the
+    /// data this slice can see.
+    pub fn to_movie_range(&self, start: usize, end: usize) -> Option<SwfSlice> {
+        if start <= end && end <= self.data.len() {
+            Some(SwfSlice {
+                movie: self.movie.clone(),
+                start,
+                end,
+                data: self.data.clone(),
+            })
+        } else {
+            None
+        }
     }
 
     /// Get the version of the SWF this data comes from.
@@ -296,6 +730,29 @@ impl SwfSlice {
     }
 }
 
+/// Whether all of the tags of the frame starting at `pos` have been loaded, up
+/// to the `ShowFrame` or `End` tag that ends it.
+pub fn is_frame_loaded(data: &[u8], pos: u64, version: u8) -> bool {
+    let mut cursor = std::io::Cursor::new(data);
+    cursor.set_position(pos);
+    let mut reader = swf::read::Reader::new(cursor, version);
+    loop {
+        let (tag_code, tag_len) = match reader.read_tag_code_and_length() {
+            Ok(tag) => tag,
+            Err(_) => return false,
+        };
+        let end_pos = reader.get_ref().position() + tag_len as u64;
+        if end_pos > data.len() as u64 {
+            return false;
+        }
+
+        match TagCode::from_u16(tag_code) {
+            Some(TagCode::ShowFrame) | Some(TagCode::End) => return true,
+            _ => reader.get_mut().set_position(end_pos),
+        }
+    }
+}
+
 pub fn decode_tags<'a, R, F>(
     reader: &'a mut SwfStream<R>,
     mut tag_callback: F,
@@ -318,7 +775,8 @@ where
                 log::error!("Error running definition tag: {:?}, got {}", tag, e);
             }
 
-            if stop_tag == tag {
+            // Nothing past the End tag belongs to the tag stream.
+            if stop_tag == tag || tag == TagCode::End {
                 reader.get_mut().seek(SeekFrom::Start(end_pos))?;
                 break;
             }
@@ -331,3 +789,103 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode an SWF a few bytes at a time, checking that the movie is
+    /// available before all of it has arrived and that its data only grows.
+    fn decode_in_chunks(swf_data: &[u8]) -> Arc<SwfMovie> {
+        let mut decoder = SwfMovieDecoder::new(None, PropertyMap::new());
+        let mut loaded = 0;
+        for chunk in swf_data.chunks(7) {
+            decoder.decode_chunk(chunk).unwrap();
+            if let Some(movie) = decoder.movie() {
+                assert!(!movie.is_loaded());
+                assert!(movie.data().len() >= loaded);
+                loaded = movie.data().len();
+            }
+        }
+
+        assert!(loaded > 0);
+        decoder.finish().unwrap()
+    }
+
+    fn assert_decodes_in_chunks(path: &str) {
+        let swf_data = std::fs::read(path).unwrap();
+        let expected = SwfMovie::from_data(&swf_data, None).unwrap();
+        let movie = decode_in_chunks(&swf_data);
+
+        assert!(movie.is_loaded());
+        assert_eq!(movie.header().num_frames, expected.header().num_frames);
+        assert_eq!(movie.data()[..], expected.data()[..]);
+        assert_eq!(movie.loaded_length(), expected.header().uncompressed_length);
+    }
+
+    #[test]
+    fn decode_uncompressed_swf_in_chunks() {
+        assert_decodes_in_chunks("tests/swfs/avm1/streamed_movie/child.swf");
+    }
+
+    #[test]
+    fn decode_zlib_swf_in_chunks() {
+        assert_decodes_in_chunks("tests/swfs/avm1/preload_frames/test.swf");
+    }
+
+    #[test]
+    fn decoding_chunks_never_moves_loaded_data() {
+        let swf_data = std::fs::read("tests/swfs/avm1/preload_frames/test.swf").unwrap();
+        let expected = SwfMovie::from_data(&swf_data, None).unwrap();
+
+        let mut decoder = SwfMovieDecoder::new(None, PropertyMap::new());
+        let mut snapshots = vec![];
+        for chunk in swf_data.chunks(7) {
+            decoder.decode_chunk(chunk).unwrap();
+            if let Some(movie) = decoder.movie() {
+                snapshots.push(movie.data());
+            }
+        }
+        let movie = decoder.finish().unwrap();
+        let data = movie.data();
+
+        assert!(snapshots.len() > 10);
+        assert_eq!(data[..], expected.data()[..]);
+        for snapshot in snapshots {
+            assert_eq!(snapshot.as_ptr(), data.as_ptr());
+            assert_eq!(snapshot[..], data[..snapshot.len()]);
+        }
+    }
+
+    #[test]
+    fn decode_swf_longer_than_its_header_says() {
+        let mut swf_data = std::fs::read("tests/swfs/avm1/streamed_movie/child.swf").unwrap();
+        let length = swf_data.len() as u32 - 16;
+        swf_data[4..8].copy_from_slice(&length.to_le_bytes());
+        let expected = SwfMovie::from_data(&swf_data, None).unwrap();
+
+        let mut decoder = SwfMovieDecoder::new(None, PropertyMap::new());
+        decoder.decode_chunk(&swf_data[..32]).unwrap();
+        let snapshot = decoder.movie().unwrap().data();
+        decoder.decode_chunk(&swf_data[32..]).unwrap();
+        let movie = decoder.finish().unwrap();
+
+        assert_eq!(movie.data()[..], expected.data()[..]);
+        assert_eq!(snapshot[..], movie.data()[..snapshot.len()]);
+        assert_eq!(movie.loaded_length(), length);
+    }
+
+    #[test]
+    fn frame_is_loaded_once_its_show_frame_arrives() {
+        let swf_data = std::fs::read("tests/swfs/avm1/streamed_movie/child.swf").unwrap();
+        let mut decoder = SwfMovieDecoder::new(None, PropertyMap::new());
+        decoder.decode_chunk(&swf_data[..32]).unwrap();
+
+        let movie = decoder.movie().unwrap().clone();
+        assert_eq!(movie.loaded_length(), 32);
+        assert!(!is_frame_loaded(&movie.data(), 0, movie.version()));
+
+        decoder.decode_chunk(&swf_data[32..]).unwrap();
+        assert!(is_frame_loaded(&movie.data(), 0, movie.version()));
+    }
+}
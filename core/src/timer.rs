@@ -4,13 +4,20 @@
 //! The timers are stored in a priority queue, where we check if the nearest timer
 //! is ready to tick each frame.
 //!
-//! TODO: Could we use this for AVM2 timers as well?
+//! Both AVM1 and AVM2 timers share this queue, so that they fire in a
+//! consistent order relative to one another.
 
+use crate::avm1::activation::{
+    Activation as Avm1Activation, ActivationIdentifier as Avm1ActivationIdentifier,
+};
 use crate::avm1::object::search_prototype;
-use crate::avm1::{Activation, ActivationIdentifier, Object, TObject, Value};
+use crate::avm1::{Object as Avm1Object, TObject as Avm1TObject, Value as Avm1Value};
+use crate::avm2::{
+    Activation as Avm2Activation, Object as Avm2Object, TObject as Avm2TObject, Value as Avm2Value,
+};
 use crate::context::UpdateContext;
 use gc_arena::Collect;
-use std::collections::{binary_heap::PeekMut, BinaryHeap};
+use std::collections::BinaryHeap;
 
 /// Manages the collection of timers.
 pub struct Timers<'gc> {
@@ -22,6 +29,12 @@ pub struct Timers<'gc> {
 
     /// The current global time.
     cur_time: u64,
+
+    /// The timer whose callback is currently running, if any.
+    ///
+    /// Timers are taken out of the queue while they run, so that their
+    /// callbacks can freely add or remove timers.
+    running: Option<Timer<'gc>>,
 }
 
 impl<'gc> Timers<'gc> {
@@ -37,41 +50,23 @@ impl<'gc> Timers<'gc> {
             return None;
         }
 
-        let version = context.swf.header().version;
-        let globals = context.avm1.global_object_cell();
-        let level0 = context.levels.get(&0).copied().unwrap();
-
-        let mut activation = Activation::from_nothing(
-            context.reborrow(),
-            ActivationIdentifier::root("[Timer Callback]"),
-            version,
-            globals,
-            level0,
-        );
-
-        // TODO: `this` is undefined for non-method timer callbacks, but our VM
-        // currently doesn't allow `this` to be a Value.
-        let undefined = Value::Undefined.coerce_to_object(&mut activation);
-
         let mut tick_count = 0;
-        let cur_time = activation.context.timers.cur_time;
+        let cur_time = context.timers.cur_time;
 
         // We have to be careful because the timer list can be mutated while updating;
         // a timer callback could add more timers, clear timers, etc.
-        while activation
-            .context
+        while context
             .timers
             .peek()
             .map(|timer| timer.tick_time)
             .unwrap_or(cur_time)
             < cur_time
         {
-            let timer = activation.context.timers.peek().unwrap();
+            let timer = context.timers.pop().unwrap();
 
             // TODO: This is only really necessary because BinaryHeap lacks `remove` or `retain` on stable.
             // We can remove the timers straight away in `clearInterval` once this is stable.
             if !timer.is_alive.get() {
-                activation.context.timers.pop();
                 continue;
             }
 
@@ -79,59 +74,106 @@ impl<'gc> Timers<'gc> {
             // SANITY: Only allow so many ticks per timer per update.
             if tick_count > Self::MAX_TICKS {
                 // Reset our time to a little bit before the nearest timer.
-                let next_time = activation.context.timers.peek_mut().unwrap().tick_time;
-                activation.context.timers.cur_time = next_time.wrapping_sub(100);
+                context.timers.cur_time = timer.tick_time.wrapping_sub(100);
+                context.timers.timers.push(timer);
                 break;
             }
 
-            // TODO: Can we avoid these clones?
-            let params = timer.params.clone();
+            // TODO: Can we avoid this clone?
             let callback = timer.callback.clone();
+            context.timers.running = Some(timer);
 
-            let callback = match callback {
-                TimerCallback::Function(f) => Some((undefined, None, f)),
-                TimerCallback::Method { this, method_name } => {
-                    // Fetch the callback method from the object.
-                    if let Ok((f, base_proto)) =
-                        search_prototype(Some(this), &method_name, &mut activation, this)
-                    {
-                        let f = f.coerce_to_object(&mut activation);
-                        Some((this, base_proto, f))
-                    } else {
-                        None
-                    }
-                }
-            };
+            match callback {
+                TimerCallback::Avm1Function { func, params } => {
+                    Self::run_avm1_callback(context, |activation| {
+                        // TODO: `this` is undefined for non-method timer callbacks, but our VM
+                        // currently doesn't allow `this` to be a Value.
+                        let undefined = Avm1Value::Undefined.coerce_to_object(activation);
 
-            if let Some((this, base_proto, function)) = callback {
-                let _ = function.call(
-                    "[Timer Callback]",
-                    &mut activation,
+                        Some((undefined, None, func, params))
+                    });
+                }
+                TimerCallback::Avm1Method {
                     this,
-                    base_proto,
-                    &params,
-                );
+                    method_name,
+                    params,
+                } => {
+                    Self::run_avm1_callback(context, |activation| {
+                        // Fetch the callback method from the object.
+                        let (f, base_proto) =
+                            search_prototype(Some(this), &method_name, activation, this).ok()?;
+                        let f = f.coerce_to_object(activation);
+
+                        Some((this, base_proto, f, params))
+                    });
+                }
+                TimerCallback::Avm2Callable { callable, params } => {
+                    let mut activation = Avm2Activation::from_nothing(context.reborrow());
+
+                    if let Err(e) = callable.call(None, &params, &mut activation, None) {
+                        activation.context.avm2.clear_error();
+                        log::error!("Unhandled AVM2 error in timer callback: {}", e);
+                    }
+                }
             }
 
-            let mut timer = activation.context.timers.peek_mut().unwrap();
-            if timer.is_timeout {
-                // Timeouts only fire once.
-                drop(timer);
-                activation.context.timers.pop();
-            } else {
-                // Reset setInterval timers. `peek_mut` re-sorts the timer in the priority queue.
+            // Timeouts only fire once, and the callback may have removed this
+            // timer, in which case we don't want to reschedule it.
+            let mut timer = context.timers.running.take().unwrap();
+            if !timer.is_timeout && timer.is_alive.get() {
+                // Reset setInterval timers.
                 timer.tick_time = timer.tick_time.wrapping_add(timer.interval);
+                context.timers.timers.push(timer);
             }
         }
 
         // Return estimated time until next timer tick.
-        activation
-            .context
+        context
             .timers
             .peek()
             .map(|timer| (timer.tick_time.wrapping_sub(cur_time)) as f64 / Self::TIMER_SCALE)
     }
 
+    /// Run an AVM1 timer callback in a fresh activation.
+    ///
+    /// The `get_callback` closure resolves the callback to run against the
+    /// new activation; returning `None` skips running anything.
+    fn run_avm1_callback<'a, 'gc_context, F>(
+        context: &mut UpdateContext<'a, 'gc, 'gc_context>,
+        get_callback: F,
+    ) where
+        F: FnOnce(
+            &mut Avm1Activation<'_, 'gc, '_>,
+        ) -> Option<(
+            Avm1Object<'gc>,
+            Option<Avm1Object<'gc>>,
+            Avm1Object<'gc>,
+            Vec<Avm1Value<'gc>>,
+        )>,
+    {
+        let version = context.swf.header().version;
+        let globals = context.avm1.global_object_cell();
+        let level0 = context.levels.get(&0).copied().unwrap();
+
+        let mut activation = Avm1Activation::from_nothing(
+            context.reborrow(),
+            Avm1ActivationIdentifier::root("[Timer Callback]"),
+            version,
+            globals,
+            level0,
+        );
+
+        if let Some((this, base_proto, function, params)) = get_callback(&mut activation) {
+            let _ = function.call(
+                "[Timer Callback]",
+                &mut activation,
+                this,
+                base_proto,
+                &params,
+            );
+        }
+    }
+
     /// The minimum interval we allow for timers.
     const MIN_INTERVAL: i32 = 10;
 
@@ -147,6 +189,7 @@ impl<'gc> Timers<'gc> {
             timers: Default::default(),
             timer_counter: 0,
             cur_time: 0,
+            running: None,
         }
     }
 
@@ -160,7 +203,6 @@ impl<'gc> Timers<'gc> {
         &mut self,
         callback: TimerCallback<'gc>,
         interval: i32,
-        params: Vec<Value<'gc>>,
         is_timeout: bool,
     ) -> i32 {
         // SANITY: Set a minimum interval so we don't spam too much.
@@ -171,7 +213,6 @@ impl<'gc> Timers<'gc> {
         let timer = Timer {
             id,
             callback,
-            params,
             tick_time: self.cur_time + interval,
             interval,
             is_timeout,
@@ -182,9 +223,16 @@ impl<'gc> Timers<'gc> {
     }
 
     /// Removes a timer.
+    ///
+    /// This is safe to call from within the timer's own callback.
     pub fn remove(&mut self, id: i32) -> bool {
         // TODO: When `BinaryHeap::remove` is stable, we can remove it here directly.
-        if let Some(timer) = self.timers.iter().find(|timer| timer.id == id) {
+        if let Some(timer) = self
+            .timers
+            .iter()
+            .chain(self.running.iter())
+            .find(|timer| timer.id == id)
+        {
             timer.is_alive.set(false);
             true
         } else {
@@ -196,10 +244,6 @@ impl<'gc> Timers<'gc> {
         self.timers.peek()
     }
 
-    fn peek_mut(&mut self) -> Option<PeekMut<'_, Timer<'gc>>> {
-        self.timers.peek_mut()
-    }
-
     fn pop(&mut self) -> Option<Timer<'gc>> {
         self.timers.pop()
     }
@@ -216,6 +260,7 @@ unsafe impl<'gc> Collect for Timers<'gc> {
         for timer in &self.timers {
             timer.trace(cc);
         }
+        self.running.trace(cc);
     }
 }
 /// A timer created via `setInterval`/`setTimeout`.
@@ -230,9 +275,6 @@ struct Timer<'gc> {
     /// A callback is either a function object, or a parent object with a method name.
     callback: TimerCallback<'gc>,
 
-    /// The parameters to pass to the callback function.
-    params: Vec<Value<'gc>>,
-
     /// The time when this timer should fire.
     tick_time: u64,
 
@@ -270,12 +312,22 @@ impl Ord for Timer<'_> {
 }
 
 /// A callback fired by a `setInterval`/`setTimeout` timer.
+///
+/// Each callback carries the parameters to pass to it when fired.
 #[derive(Debug, Collect, Clone)]
 #[collect(no_drop)]
 pub enum TimerCallback<'gc> {
-    Function(Object<'gc>),
-    Method {
-        this: Object<'gc>,
+    Avm1Function {
+        func: Avm1Object<'gc>,
+        params: Vec<Avm1Value<'gc>>,
+    },
+    Avm1Method {
+        this: Avm1Object<'gc>,
         method_name: String,
+        params: Vec<Avm1Value<'gc>>,
+    },
+    Avm2Callable {
+        callable: Avm2Object<'gc>,
+        params: Vec<Avm2Value<'gc>>,
     },
 }
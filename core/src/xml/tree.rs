@@ -938,6 +938,11 @@ impl<'gc> XMLNode<'gc> {
         }
     }
 
+    /// Check if two `XMLNode` handles refer to the same node.
+    pub fn ptr_eq(self, other: Self) -> bool {
+        GcCell::ptr_eq(self.0, other.0)
+    }
+
     /// Check if this XML node constitutes the root of a whole document.
     pub fn is_document_root(self) -> bool {
         matches!(*self.0.read(), XMLNodeData::DocumentRoot { .. })
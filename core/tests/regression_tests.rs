@@ -5,23 +5,35 @@
 use approx::assert_relative_eq;
 use ruffle_core::backend::locale::NullLocaleBackend;
 use ruffle_core::backend::log::LogBackend;
-use ruffle_core::backend::navigator::{NullExecutor, NullNavigatorBackend};
+use ruffle_core::backend::navigator::{
+    ChunkFuture, NavigationMethod, NavigatorBackend, NullExecutor, NullNavigatorBackend,
+    OwnedFuture, RequestOptions, ResponseStream,
+};
 use ruffle_core::backend::storage::MemoryStorageBackend;
 use ruffle_core::backend::ui::NullUiBackend;
 use ruffle_core::backend::{
     audio::NullAudioBackend, input::NullInputBackend, render::NullRenderer,
 };
 use ruffle_core::context::UpdateContext;
+use ruffle_core::events::PlayerEvent;
 use ruffle_core::external::Value as ExternalValue;
 use ruffle_core::external::{ExternalInterfaceMethod, ExternalInterfaceProvider};
+use ruffle_core::indexmap::IndexMap;
+use ruffle_core::loader::Error as LoaderError;
+use ruffle_core::system_properties::{OperatingSystem, PlayerType, SystemCapabilities};
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::Player;
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::BTreeMap;
+use std::future::Future;
 use std::path::Path;
+use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
 use std::time::Duration;
+use url::Url;
 
 type Error = Box<dyn std::error::Error>;
 
@@ -325,6 +337,10 @@ swf_tests! {
     (as3_es4_method_binding, "avm2/es4_method_binding", 1),
     (as3_control_flow_bool, "avm2/control_flow_bool", 1),
     (as3_control_flow_stricteq, "avm2/control_flow_stricteq", 1),
+    (as3_set_slot, "avm2/set_slot", 1),
+    (as3_try_catch, "avm2/try_catch", 1),
+    (as3_try_catch_primitives, "avm2/try_catch_primitives", 1),
+    (as3_try_catch_native_errors, "avm2/try_catch_native_errors", 1),
     (as3_object_enumeration, "avm2/object_enumeration", 1),
     (as3_class_enumeration, "avm2/class_enumeration", 1),
     (as3_is_prototype_of, "avm2/is_prototype_of", 1),
@@ -492,6 +508,34 @@ swf_tests! {
     (as3_movieclip_dispatchevent_cancel, "avm2/movieclip_dispatchevent_cancel", 1),
     (as3_movieclip_dispatchevent_target, "avm2/movieclip_dispatchevent_target", 1),
     (as3_movieclip_dispatchevent_selfadd, "avm2/movieclip_dispatchevent_selfadd", 1),
+    (as3_vector_int, "avm2/vector_int", 1),
+    (as3_xml_basics, "avm2/xml_basics", 1),
+    (as3_dictionary_weak_keys, "avm2/dictionary_weak_keys", 3),
+    (as3_timer_events, "avm2/timer_events", 10),
+    (as3_urlloader_data_format, "avm2/urlloader_data_format", 3),
+    (as3_graphics_bitmap_fill, "avm2/graphics_bitmap_fill", 1),
+    (as3_displayobject_getrect, "avm2/displayobject_getrect", 1),
+    (as3_application_domain_get_definition, "avm2/application_domain_get_definition", 1),
+    (as3_error_stack_trace, "avm2/error_stack_trace", 1),
+    (avm1_import_assets, "avm1/import_assets", 5),
+    (avm1_set_mask_nested, "avm1/set_mask_nested", 2),
+    (avm1_preload_frames, "avm1/preload_frames", 5),
+    (avm1_bitmap_data_perlin_noise, "avm1/bitmap_data_perlin_noise", 1),
+    (avm1_bitmap_data_palette_map, "avm1/bitmap_data_palette_map", 1),
+    (avm1_bitmap_data_threshold, "avm1/bitmap_data_threshold", 1),
+    (avm1_bitmap_data_merge, "avm1/bitmap_data_merge", 1),
+    (avm1_bitmap_data_pixel_dissolve, "avm1/bitmap_data_pixel_dissolve", 1),
+    (avm1_bitmap_data_hit_test, "avm1/bitmap_data_hit_test", 1),
+    (avm1_bitmap_data_generate_filter_rect, "avm1/bitmap_data_generate_filter_rect", 1),
+    (avm1_bitmap_data_draw_shapes, "avm1/bitmap_data_draw_shapes", 1),
+    (avm1_bitmap_data_draw_smoothing, "avm1/bitmap_data_draw_smoothing", 1),
+    (avm2_bitmapdata_ops, "avm2/bitmapdata_ops", 1),
+    (avm1_bitmap_data_apply_filter, "avm1/bitmap_data_apply_filter", 1),
+    (avm1_loadmovie_image, "avm1/loadmovie_image", 4),
+    (avm1_set_mask_cycle, "avm1/set_mask_cycle", 1),
+    (avm1_nan_to_string_radix, "avm1/nan_to_string_radix", 1),
+    (as3_vector_user_class_names, "avm2/vector_user_class_names", 1),
+    (as3_vector_specializations, "avm2/vector_specializations", 1),
 }
 
 // TODO: These tests have some inaccuracies currently, so we use approx_eq to test that numeric values are close enough.
@@ -587,6 +631,212 @@ fn timeout_avm1() -> Result<(), Error> {
     )
 }
 
+#[test]
+fn as3_mouse_pick() -> Result<(), Error> {
+    test_swf(
+        "tests/swfs/avm2/mouse_pick/test.swf",
+        1,
+        "tests/swfs/avm2/mouse_pick/output.txt",
+        |_| Ok(()),
+        |player| {
+            let mut player_locked = player.lock().unwrap();
+            for &(x, y) in &[
+                (25.0, 50.0),
+                (75.0, 50.0),
+                (225.0, 50.0),
+                (275.0, 50.0),
+                (25.0, 250.0),
+                (75.0, 250.0),
+                (450.0, 50.0),
+            ] {
+                player_locked
+                    .log_backend()
+                    .avm_trace(&format!("//(click at {}, {})", x, y));
+                player_locked.handle_event(PlayerEvent::MouseMove { x, y });
+                player_locked.handle_event(PlayerEvent::MouseDown { x, y });
+                player_locked.handle_event(PlayerEvent::MouseUp { x, y });
+            }
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn as3_textfield() -> Result<(), Error> {
+    test_swf(
+        "tests/swfs/avm2/textfield/test.swf",
+        1,
+        "tests/swfs/avm2/textfield/output.txt",
+        |_| Ok(()),
+        |player| {
+            let mut player_locked = player.lock().unwrap();
+            player_locked.handle_event(PlayerEvent::MouseMove { x: 10.0, y: 10.0 });
+            player_locked.handle_event(PlayerEvent::MouseDown { x: 10.0, y: 10.0 });
+            player_locked.handle_event(PlayerEvent::MouseUp { x: 10.0, y: 10.0 });
+            for &codepoint in &['a', 'x', 'b'] {
+                player_locked.handle_event(PlayerEvent::TextInput { codepoint });
+            }
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn as3_stage_properties() -> Result<(), Error> {
+    test_swf(
+        "tests/swfs/avm2/stage_properties/test.swf",
+        1,
+        "tests/swfs/avm2/stage_properties/output.txt",
+        |_| Ok(()),
+        |player| {
+            let mut player_locked = player.lock().unwrap();
+            let frame_rate = player_locked.frame_rate();
+            player_locked
+                .log_backend()
+                .avm_trace(&format!("//(player frame rate) {}", frame_rate));
+            player_locked.set_viewport_dimensions(800, 600, 1.0);
+            player_locked.set_viewport_dimensions(800, 600, 1.0);
+            player_locked.set_viewport_dimensions(1000, 500, 2.0);
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn avm1_system_capabilities_profile() -> Result<(), Error> {
+    test_swf(
+        "tests/swfs/avm1/system_capabilities_profile/test.swf",
+        1,
+        "tests/swfs/avm1/system_capabilities_profile/output.txt",
+        |player| {
+            let mut player_locked = player.lock().unwrap();
+            player_locked.set_player_version(9);
+            let system = player_locked.system_properties_mut();
+            let os: OperatingSystem = "MacOS".parse()?;
+            system.manufacturer = os.manufacturer();
+            system.os = os;
+            system.language = "fr".parse()?;
+            system.player_type = PlayerType::PlugIn;
+            system.screen_resolution = (1024, 768);
+            system.capabilities.remove(SystemCapabilities::MP3);
+            Ok(())
+        },
+        |_| Ok(()),
+    )
+}
+
+#[test]
+fn avm1_nan_to_string_radix_fp6() -> Result<(), Error> {
+    test_swf(
+        "tests/swfs/avm1/nan_to_string_radix/test.swf",
+        1,
+        "tests/swfs/avm1/nan_to_string_radix/output_fp6.txt",
+        |player| {
+            player.lock().unwrap().set_player_version(6);
+            Ok(())
+        },
+        |_| Ok(()),
+    )
+}
+
+#[test]
+fn avm1_stage_noscale_hidpi() -> Result<(), Error> {
+    test_swf(
+        "tests/swfs/avm1/stage_noscale_hidpi/test.swf",
+        1,
+        "tests/swfs/avm1/stage_noscale_hidpi/output.txt",
+        |_| Ok(()),
+        |player| {
+            let mut player_locked = player.lock().unwrap();
+            player_locked.set_viewport_dimensions(1200, 900, 2.0);
+            player_locked.handle_event(PlayerEvent::MouseMove { x: 220.0, y: 100.0 });
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn avm1_fscommand() -> Result<(), Error> {
+    test_fscommand(
+        PlayerType::StandAlone,
+        "tests/swfs/avm1/fscommand/output.txt",
+    )
+}
+
+#[test]
+fn avm1_fscommand_plugin() -> Result<(), Error> {
+    test_fscommand(
+        PlayerType::PlugIn,
+        "tests/swfs/avm1/fscommand/output_plugin.txt",
+    )
+}
+
+/// Runs the FSCommand test movie as the given type of player, tracing the
+/// commands that were forwarded to the host.
+fn test_fscommand(player_type: PlayerType, expected_output_path: &str) -> Result<(), Error> {
+    let commands = Rc::new(RefCell::new(Vec::new()));
+    test_swf(
+        "tests/swfs/avm1/fscommand/test.swf",
+        1,
+        expected_output_path,
+        |player| {
+            let mut player_locked = player.lock().unwrap();
+            player_locked.system_properties_mut().player_type = player_type;
+            player_locked.add_external_interface(Box::new(FsCommandTestProvider {
+                commands: commands.clone(),
+            }));
+            Ok(())
+        },
+        |player| {
+            let player_locked = player.lock().unwrap();
+            for command in commands.borrow().iter() {
+                player_locked
+                    .log_backend()
+                    .avm_trace(&format!("//(host received {})", command));
+            }
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn avm1_streamed_movie() -> Result<(), Error> {
+    test_streamed_swf(
+        "tests/swfs/avm1/streamed_movie/test.swf",
+        90,
+        64,
+        "tests/swfs/avm1/streamed_movie/output.txt",
+    )
+}
+
+#[test]
+fn avm2_sound() -> Result<(), Error> {
+    test_sound_swf(
+        "tests/swfs/avm2/sound/test.swf",
+        2,
+        "tests/swfs/avm2/sound/output.txt",
+    )
+}
+
+#[test]
+fn avm2_sound_sample_data() -> Result<(), Error> {
+    test_sound_swf(
+        "tests/swfs/avm2/sound_sample_data/test.swf",
+        2,
+        "tests/swfs/avm2/sound_sample_data/output.txt",
+    )
+}
+
+#[test]
+fn as3_loader_image() -> Result<(), Error> {
+    test_streamed_swf(
+        "tests/swfs/avm2/loader_image/test.swf",
+        200,
+        16,
+        "tests/swfs/avm2/loader_image/output.txt",
+    )
+}
+
 /// Wrapper around string slice that makes debug output `{:?}` to print string same way as `{}`.
 /// Used in different `assert*!` macros in combination with `pretty_assertions` crate to make
 /// test failures to show nice diffs.
@@ -631,7 +881,84 @@ fn test_swf(
         expected_output = expected_output[0..expected_output.len() - "\n".len()].to_string();
     }
 
-    let trace_log = run_swf(swf_path, num_frames, before_start, before_end)?;
+    let trace_log = run_swf(swf_path, num_frames, false, before_start, before_end)?;
+    assert_eq!(
+        trace_log, expected_output,
+        "ruffle output != flash player output"
+    );
+
+    Ok(())
+}
+
+/// Loads an SWF that plays sounds and runs it for a number of frames, updating
+/// its sounds every frame so that finished ones fire `soundComplete`.
+/// Tests that the trace output matches the given expected output.
+fn test_sound_swf(
+    swf_path: &str,
+    num_frames: u32,
+    expected_output_path: &str,
+) -> Result<(), Error> {
+    let mut expected_output = std::fs::read_to_string(expected_output_path)?.replace("\r\n", "\n");
+
+    // Strip a trailing newline if it has one.
+    if expected_output.ends_with('\n') {
+        expected_output = expected_output[0..expected_output.len() - "\n".len()].to_string();
+    }
+
+    let trace_log = run_swf(swf_path, num_frames, true, |_| Ok(()), |_| Ok(()))?;
+    assert_eq!(
+        trace_log, expected_output,
+        "ruffle output != flash player output"
+    );
+
+    Ok(())
+}
+
+/// Streams an SWF into a player `chunk_size` bytes per frame, and runs it for a
+/// number of frames. Any movies it loads are streamed the same way.
+/// Tests that the trace output matches the given expected output.
+fn test_streamed_swf(
+    swf_path: &str,
+    num_frames: u32,
+    chunk_size: usize,
+    expected_output_path: &str,
+) -> Result<(), Error> {
+    let mut expected_output = std::fs::read_to_string(expected_output_path)?.replace("\r\n", "\n");
+
+    // Strip a trailing newline if it has one.
+    if expected_output.ends_with('\n') {
+        expected_output = expected_output[0..expected_output.len() - "\n".len()].to_string();
+    }
+
+    let swf_path = Path::new(swf_path);
+    let base_path = swf_path.parent().unwrap();
+    let (mut executor, channel) = NullExecutor::new();
+    let trace_output = Rc::new(RefCell::new(Vec::new()));
+
+    let player = Player::new(
+        Box::new(NullRenderer::new()),
+        Box::new(NullAudioBackend::new()),
+        Box::new(ChunkedNavigatorBackend {
+            navigator: NullNavigatorBackend::with_base_path(base_path, channel),
+            chunk_size,
+        }),
+        Box::new(NullInputBackend::new()),
+        Box::new(MemoryStorageBackend::default()),
+        Box::new(NullLocaleBackend::new()),
+        Box::new(TestLogBackend::new(trace_output.clone())),
+        Box::new(NullUiBackend::new()),
+    )?;
+    player.lock().unwrap().fetch_root_movie(
+        &swf_path.file_name().unwrap().to_string_lossy(),
+        Default::default(),
+    );
+
+    for _ in 0..num_frames {
+        player.lock().unwrap().run_frame();
+        executor.poll_all().unwrap();
+    }
+
+    let trace_log = trace_output.borrow().join("\n");
     assert_eq!(
         trace_log, expected_output,
         "ruffle output != flash player output"
@@ -651,7 +978,7 @@ fn test_swf_approx(
     before_start: impl FnOnce(Arc<Mutex<Player>>) -> Result<(), Error>,
     before_end: impl FnOnce(Arc<Mutex<Player>>) -> Result<(), Error>,
 ) -> Result<(), Error> {
-    let trace_log = run_swf(swf_path, num_frames, before_start, before_end)?;
+    let trace_log = run_swf(swf_path, num_frames, false, before_start, before_end)?;
     let mut expected_data = std::fs::read_to_string(expected_output_path)?;
 
     // Strip a trailing newline if it has one.
@@ -694,9 +1021,11 @@ fn test_swf_approx(
 
 /// Loads an SWF and runs it through the Ruffle core for a number of frames.
 /// Tests that the trace output matches the given expected output.
+/// If `update_sounds` is set, the player's sounds are updated every frame.
 fn run_swf(
     swf_path: &str,
     num_frames: u32,
+    update_sounds: bool,
     before_start: impl FnOnce(Arc<Mutex<Player>>) -> Result<(), Error>,
     before_end: impl FnOnce(Arc<Mutex<Player>>) -> Result<(), Error>,
 ) -> Result<String, Error> {
@@ -707,7 +1036,7 @@ fn run_swf(
     let trace_output = Rc::new(RefCell::new(Vec::new()));
 
     let player = Player::new(
-        Box::new(NullRenderer),
+        Box::new(NullRenderer::new()),
         Box::new(NullAudioBackend::new()),
         Box::new(NullNavigatorBackend::with_base_path(base_path, channel)),
         Box::new(NullInputBackend::new()),
@@ -727,6 +1056,9 @@ fn run_swf(
     for _ in 0..num_frames {
         player.lock().unwrap().run_frame();
         player.lock().unwrap().update_timers(frame_time);
+        if update_sounds {
+            player.lock().unwrap().update_sounds();
+        }
         executor.poll_all().unwrap();
     }
 
@@ -763,6 +1095,131 @@ impl ExternalInterfaceTestProvider {
     }
 }
 
+/// Records the FSCommands that the player forwards to the host.
+struct FsCommandTestProvider {
+    commands: Rc<RefCell<Vec<String>>>,
+}
+
+impl ExternalInterfaceProvider for FsCommandTestProvider {
+    fn get_method(&self, _name: &str) -> Option<Box<dyn ExternalInterfaceMethod>> {
+        None
+    }
+
+    fn on_callback_available(&self, _name: &str) {}
+
+    fn on_fs_command(&self, command: &str, args: &str) -> bool {
+        self.commands
+            .borrow_mut()
+            .push(format!("{}({})", command, args));
+        true
+    }
+}
+
+/// A navigator that streams the files it fetches in fixed-size chunks.
+///
+/// Each chunk is only delivered on the poll after the one that asked for it,
+/// so fetches progress by a chunk per frame.
+struct ChunkedNavigatorBackend {
+    navigator: NullNavigatorBackend,
+    chunk_size: usize,
+}
+
+impl NavigatorBackend for ChunkedNavigatorBackend {
+    fn navigate_to_url(
+        &self,
+        url: String,
+        window: Option<String>,
+        vars_method: Option<(NavigationMethod, IndexMap<String, String>)>,
+    ) {
+        self.navigator.navigate_to_url(url, window, vars_method)
+    }
+
+    fn run_script(&self, js_code: &str) {
+        self.navigator.run_script(js_code)
+    }
+
+    fn fetch(&self, url: &str, options: RequestOptions) -> OwnedFuture<Vec<u8>, LoaderError> {
+        self.navigator.fetch(url, options)
+    }
+
+    fn fetch_stream(
+        &self,
+        url: &str,
+        options: RequestOptions,
+    ) -> OwnedFuture<Box<dyn ResponseStream>, LoaderError> {
+        let fetch = self.navigator.fetch(url, options);
+        let chunk_size = self.chunk_size;
+        Box::pin(async move {
+            let stream: Box<dyn ResponseStream> = Box::new(ChunkedResponseStream {
+                data: fetch.await?,
+                position: 0,
+                chunk_size,
+            });
+            Ok(stream)
+        })
+    }
+
+    fn time_since_launch(&mut self) -> Duration {
+        self.navigator.time_since_launch()
+    }
+
+    fn spawn_future(&mut self, future: OwnedFuture<(), LoaderError>) {
+        self.navigator.spawn_future(future)
+    }
+
+    fn resolve_relative_url<'a>(&mut self, url: &'a str) -> Cow<'a, str> {
+        self.navigator.resolve_relative_url(url)
+    }
+
+    fn pre_process_url(&self, url: Url) -> Url {
+        self.navigator.pre_process_url(url)
+    }
+}
+
+struct ChunkedResponseStream {
+    data: Vec<u8>,
+    position: usize,
+    chunk_size: usize,
+}
+
+impl ResponseStream for ChunkedResponseStream {
+    fn total_length(&self) -> Option<usize> {
+        Some(self.data.len())
+    }
+
+    fn next_chunk(&mut self) -> ChunkFuture<'_> {
+        let end = (self.position + self.chunk_size).min(self.data.len());
+        let chunk = self.data[self.position..end].to_vec();
+        self.position = end;
+
+        let chunk = Some(chunk).filter(|chunk| !chunk.is_empty());
+        Box::pin(NextPoll {
+            value: Some(Ok(chunk)),
+            polled: false,
+        })
+    }
+}
+
+/// A future that yields its value the second time it is polled.
+struct NextPoll<T> {
+    value: Option<T>,
+    polled: bool,
+}
+
+impl<T: Unpin> Future for NextPoll<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<T> {
+        if self.polled {
+            Poll::Ready(self.value.take().unwrap())
+        } else {
+            self.polled = true;
+            context.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
 fn do_trace(context: &mut UpdateContext<'_, '_, '_>, args: &[ExternalValue]) -> ExternalValue {
     context
         .log
@@ -0,0 +1,91 @@
+.flash bbox=100x100 version=8 name="test.swf"
+.action:
+    function hex(n) {
+        var hi = ((n >> 16) & 0xFFFF).toString(16);
+        var lo = (n & 0xFFFF).toString(16);
+        while (hi.length < 4) hi = "0" + hi;
+        while (lo.length < 4) lo = "0" + lo;
+        return hi + lo;
+    }
+    function dump(bd) {
+        for (var y = 0; y < bd.height; y++) {
+            var row = "";
+            for (var x = 0; x < bd.width; x++) {
+                row += (x > 0 ? " " : "") + hex(bd.getPixel32(x, y));
+            }
+            trace(row);
+        }
+    }
+    function square() {
+        var bd = new flash.display.BitmapData(7, 7, true, 0);
+        bd.fillRect(new flash.geom.Rectangle(2, 2, 3, 3), 0xFFFF0000);
+        return bd;
+    }
+    var all = new flash.geom.Rectangle(0, 0, 7, 7);
+    var origin = new flash.geom.Point(0, 0);
+
+    trace("// color matrix filter swapping red and blue");
+    var src = new flash.display.BitmapData(2, 1, true, 0);
+    src.setPixel32(0, 0, 0xFF102030);
+    src.setPixel32(1, 0, 0x80FF0000);
+    var dst = new flash.display.BitmapData(2, 1, true, 0);
+    var matrix = [0, 0, 1, 0, 0,  0, 1, 0, 0, 0,  1, 0, 0, 0, 0,  0, 0, 0, 1, 0];
+    trace(dst.applyFilter(src, src.rectangle, origin, new flash.filters.ColorMatrixFilter(matrix)));
+    dump(dst);
+
+    trace("// convolution filter with a bias");
+    src = new flash.display.BitmapData(3, 1, false, 0x102030);
+    dst = new flash.display.BitmapData(3, 1, false, 0);
+    var convolution = new flash.filters.ConvolutionFilter(3, 3, [0, 0, 0, 0, 2, 0, 0, 0, 0], 2, 16);
+    trace(dst.applyFilter(src, src.rectangle, origin, convolution));
+    dump(dst);
+
+    trace("// convolution filter averaging neighbours");
+    src = new flash.display.BitmapData(3, 3, false, 0);
+    src.setPixel(1, 1, 0x900000);
+    dst = new flash.display.BitmapData(3, 3, false, 0);
+    convolution = new flash.filters.ConvolutionFilter(3, 3, [1, 1, 1, 1, 1, 1, 1, 1, 1], 9);
+    dst.applyFilter(src, src.rectangle, origin, convolution);
+    dump(dst);
+
+    trace("// blur filter");
+    dst = new flash.display.BitmapData(7, 7, true, 0);
+    dst.applyFilter(square(), all, origin, new flash.filters.BlurFilter(3, 3, 1));
+    dump(dst);
+
+    trace("// blur filter with a source rect and destination point");
+    dst = new flash.display.BitmapData(7, 7, true, 0xFF0000FF);
+    dst.applyFilter(square(), new flash.geom.Rectangle(2, 2, 3, 3), new flash.geom.Point(3, 3), new flash.filters.BlurFilter(0, 0, 1));
+    dump(dst);
+
+    trace("// glow filter");
+    dst = new flash.display.BitmapData(7, 7, true, 0);
+    dst.applyFilter(square(), all, origin, new flash.filters.GlowFilter(0x00FF00, 1, 3, 3, 1, 1));
+    dump(dst);
+
+    trace("// inner knockout glow filter");
+    dst = new flash.display.BitmapData(7, 7, true, 0);
+    dst.applyFilter(square(), all, origin, new flash.filters.GlowFilter(0x00FF00, 1, 3, 3, 1, 1, true, true));
+    dump(dst);
+
+    trace("// drop shadow filter hiding the object");
+    dst = new flash.display.BitmapData(7, 7, true, 0);
+    dst.applyFilter(square(), all, origin, new flash.filters.DropShadowFilter(2, 45, 0x0000FF, 1, 0, 0, 1, 1, false, false, true));
+    dump(dst);
+
+    trace("// drop shadow filter");
+    dst = new flash.display.BitmapData(7, 7, true, 0);
+    dst.applyFilter(square(), all, origin, new flash.filters.DropShadowFilter(2, 45, 0x0000FF, 1, 0, 0, 1, 1));
+    dump(dst);
+
+    trace("// filter applied in place");
+    var self = square();
+    self.applyFilter(self, all, new flash.geom.Point(1, 0), new flash.filters.BlurFilter(0, 0, 1));
+    dump(self);
+
+    trace("// disposed source");
+    src = square();
+    src.dispose();
+    trace(dst.applyFilter(src, all, origin, new flash.filters.BlurFilter()));
+.end
+.end
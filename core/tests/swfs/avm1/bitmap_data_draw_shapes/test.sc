@@ -0,0 +1,150 @@
+.flash bbox=100x100 version=8 name="test.swf"
+.action:
+    function hex(n) {
+        var hi = ((n >> 16) & 0xFFFF).toString(16);
+        var lo = (n & 0xFFFF).toString(16);
+        while (hi.length < 4) hi = "0" + hi;
+        while (lo.length < 4) lo = "0" + lo;
+        return hi + lo;
+    }
+    function dump(bd) {
+        for (var y = 0; y < bd.height; y++) {
+            var row = "";
+            for (var x = 0; x < bd.width; x++) {
+                row += (x > 0 ? " " : "") + hex(bd.getPixel32(x, y));
+            }
+            trace(row);
+        }
+    }
+    function rect(mc, x, y, w, h) {
+        mc.moveTo(x, y);
+        mc.lineTo(x + w, y);
+        mc.lineTo(x + w, y + h);
+        mc.lineTo(x, y + h);
+        mc.lineTo(x, y);
+    }
+
+    trace("// solid fill");
+    var a = _root.createEmptyMovieClip("a", 1);
+    a.beginFill(0xFF0000, 100);
+    rect(a, 1, 1, 4, 3);
+    a.endFill();
+    var bd = new flash.display.BitmapData(6, 5, true, 0);
+    bd.draw(a);
+    dump(bd);
+
+    trace("// half transparent fill over an existing color");
+    var b = _root.createEmptyMovieClip("b", 2);
+    b.beginFill(0x0000FF, 50);
+    rect(b, 0, 0, 3, 2);
+    b.endFill();
+    bd.draw(b);
+    dump(bd);
+
+    trace("// fill with a hole and a matrix");
+    var c = _root.createEmptyMovieClip("c", 3);
+    c.beginFill(0x00FF00, 100);
+    rect(c, 0, 0, 3, 3);
+    rect(c, 1, 1, 1, 1);
+    c.endFill();
+    bd = new flash.display.BitmapData(6, 6, true, 0);
+    bd.draw(c, new flash.geom.Matrix(2, 0, 0, 2, 0, 0));
+    dump(bd);
+
+    trace("// half covered pixels");
+    var d = _root.createEmptyMovieClip("d", 4);
+    d.beginFill(0xFFFFFF, 100);
+    rect(d, 0.5, 0, 2, 1.5);
+    d.endFill();
+    bd = new flash.display.BitmapData(3, 2, true, 0);
+    bd.draw(d);
+    dump(bd);
+
+    trace("// color transform");
+    bd = new flash.display.BitmapData(6, 5, true, 0);
+    bd.draw(a, null, new flash.geom.ColorTransform(0, 1, 1, 0.5, 0, 255, 0, 0));
+    dump(bd);
+
+    trace("// stroke");
+    var e = _root.createEmptyMovieClip("e", 5);
+    e.lineStyle(2, 0x00FFFF, 100, false, "normal", "none", "miter");
+    e.moveTo(1, 3);
+    e.lineTo(5, 3);
+    bd = new flash.display.BitmapData(7, 6, true, 0);
+    bd.draw(e);
+    dump(bd);
+
+    trace("// stroke with square caps and a corner");
+    e.clear();
+    e.lineStyle(2, 0xFFFF00, 100, false, "normal", "square", "miter");
+    e.moveTo(2, 4);
+    e.lineTo(2, 2);
+    e.lineTo(5, 2);
+    bd = new flash.display.BitmapData(7, 6, true, 0);
+    bd.draw(e);
+    dump(bd);
+
+    trace("// linear gradient");
+    var f = _root.createEmptyMovieClip("f", 6);
+    f.beginGradientFill("linear", [0x000000, 0xFFFFFF], [100, 100], [0, 255], {matrixType: "box", x: 0, y: 0, w: 8, h: 1, r: 0});
+    rect(f, 0, 0, 8, 1);
+    f.endFill();
+    bd = new flash.display.BitmapData(8, 1, true, 0);
+    bd.draw(f);
+    dump(bd);
+
+    trace("// radial gradient");
+    f.clear();
+    f.beginGradientFill("radial", [0xFF0000, 0x0000FF], [100, 0], [0, 255], {matrixType: "box", x: 0, y: 0, w: 6, h: 6, r: 0});
+    rect(f, 0, 0, 6, 6);
+    f.endFill();
+    bd = new flash.display.BitmapData(6, 6, true, 0);
+    bd.draw(f);
+    dump(bd);
+
+    trace("// curves");
+    var g = _root.createEmptyMovieClip("g", 7);
+    g.beginFill(0x000000, 100);
+    g.moveTo(4, 0);
+    g.curveTo(8, 0, 8, 4);
+    g.curveTo(8, 8, 4, 8);
+    g.curveTo(0, 8, 0, 4);
+    g.curveTo(0, 0, 4, 0);
+    g.endFill();
+    bd = new flash.display.BitmapData(8, 8, true, 0);
+    bd.draw(g);
+    for (var y = 0; y < 8; y++) {
+        var row = "";
+        for (var x = 0; x < 8; x++) {
+            var alpha = (bd.getPixel32(x, y) >> 24) & 0xFF;
+            row += alpha > 200 ? "#" : (alpha > 0 ? "+" : ".");
+        }
+        trace(row);
+    }
+
+    trace("// masks");
+    var h = _root.createEmptyMovieClip("h", 8);
+    var maskee = h.createEmptyMovieClip("maskee", 1);
+    maskee.beginFill(0x0000FF, 100);
+    rect(maskee, 0, 0, 6, 6);
+    maskee.endFill();
+    var masker = h.createEmptyMovieClip("masker", 2);
+    masker.beginFill(0xFF0000, 100);
+    rect(masker, 1, 2, 3, 2);
+    masker.endFill();
+    maskee.setMask(masker);
+    bd = new flash.display.BitmapData(6, 6, true, 0);
+    bd.draw(h);
+    dump(bd);
+
+    trace("// nested masks");
+    var inner = h.createEmptyMovieClip("inner", 3);
+    inner.beginFill(0x000000, 100);
+    rect(inner, 2, 0, 4, 6);
+    inner.endFill();
+    masker.setMask(inner);
+    bd = new flash.display.BitmapData(6, 6, true, 0);
+    bd.draw(h);
+    dump(bd);
+.end
+.end
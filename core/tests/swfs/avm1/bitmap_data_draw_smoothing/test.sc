@@ -0,0 +1,42 @@
+.flash bbox=100x100 version=8 name="test.swf"
+.action:
+    function same(a, b) {
+        for (var y = 0; y < a.height; y++) {
+            for (var x = 0; x < a.width; x++) {
+                if (!(a.getPixel(x, y) == b.getPixel(x, y))) {
+                    return false;
+                }
+            }
+        }
+        return true;
+    }
+    function drawn(clip, smoothing) {
+        var bd = new flash.display.BitmapData(8, 4, false, 0);
+        bd.draw(clip, m, null, null, null, smoothing);
+        return bd;
+    }
+
+    // draw's smoothing only applies to BitmapData sources. Bitmaps inside
+    // a clip are smoothed according to their own setting.
+    src = new flash.display.BitmapData(2, 1, false, 0xFF0000);
+    src.setPixel(1, 0, 0x0000FF);
+    plain = _root.createEmptyMovieClip("plain", 1);
+    plain.attachBitmap(src, 1, "auto", false);
+    smooth = _root.createEmptyMovieClip("smooth", 2);
+    smooth.attachBitmap(src, 1, "auto", true);
+    m = new flash.geom.Matrix(4, 0, 0, 4, 0, 0);
+
+    trace("// unsmoothed bitmap in a clip");
+    a = drawn(plain, false);
+    b = drawn(plain, true);
+    trace(same(a, b));
+    trace(b.getPixel(3, 0).toString(16));
+    trace(b.getPixel(4, 0).toString(16));
+
+    trace("// smoothed bitmap in a clip");
+    c = drawn(smooth, false);
+    d = drawn(smooth, true);
+    trace(same(c, d));
+    trace(!(c.getPixel(3, 0) == a.getPixel(3, 0)));
+.end
+.end
@@ -0,0 +1,33 @@
+.flash bbox=100x100 version=8 name="test.swf"
+.action:
+    function hex(n) {
+        var hi = ((n >> 16) & 0xFFFF).toString(16);
+        var lo = (n & 0xFFFF).toString(16);
+        while (hi.length < 4) hi = "0" + hi;
+        while (lo.length < 4) lo = "0" + lo;
+        return hi + lo;
+    }
+    function dump(bd) {
+        for (var y = 0; y < bd.height; y++) {
+            var row = "";
+            for (var x = 0; x < bd.width; x++) {
+                row += (x > 0 ? " " : "") + hex(bd.getPixel32(x, y));
+            }
+            trace(row);
+        }
+    }
+    var bd = new flash.display.BitmapData(20, 20, true, 0);
+    var rect = new flash.geom.Rectangle(0, 0, 20, 20);
+    trace(bd.generateFilterRect(rect, new flash.filters.BlurFilter(4, 4, 1)));
+    trace(bd.generateFilterRect(rect, new flash.filters.BlurFilter(10, 2, 3)));
+    trace(bd.generateFilterRect(rect, new flash.filters.BlurFilter(0, 0, 1)));
+    trace(bd.generateFilterRect(rect, new flash.filters.GlowFilter(0xFF0000, 1, 6, 6, 2, 1, false, false)));
+    trace(bd.generateFilterRect(rect, new flash.filters.GlowFilter(0xFF0000, 1, 6, 6, 2, 1, true, false)));
+    trace(bd.generateFilterRect(rect, new flash.filters.DropShadowFilter(4, 45, 0, 1, 4, 4, 1, 1, false, false, false)));
+    trace(bd.generateFilterRect(rect, new flash.filters.DropShadowFilter(4, 45, 0, 1, 4, 4, 1, 1, true, false, false)));
+    trace(bd.generateFilterRect(rect, new flash.filters.BevelFilter(4, 45)));
+    trace(bd.generateFilterRect(new flash.geom.Rectangle(5, 5, 2, 2), new flash.filters.BlurFilter(4, 4, 1)));
+    trace(bd.generateFilterRect(rect, new flash.filters.ColorMatrixFilter()));
+    trace(bd.generateFilterRect(rect, {}));
+.end
+.end
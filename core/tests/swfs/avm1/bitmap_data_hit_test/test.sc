@@ -0,0 +1,44 @@
+.flash bbox=100x100 version=8 name="test.swf"
+.action:
+    function hex(n) {
+        var hi = ((n >> 16) & 0xFFFF).toString(16);
+        var lo = (n & 0xFFFF).toString(16);
+        while (hi.length < 4) hi = "0" + hi;
+        while (lo.length < 4) lo = "0" + lo;
+        return hi + lo;
+    }
+    function dump(bd) {
+        for (var y = 0; y < bd.height; y++) {
+            var row = "";
+            for (var x = 0; x < bd.width; x++) {
+                row += (x > 0 ? " " : "") + hex(bd.getPixel32(x, y));
+            }
+            trace(row);
+        }
+    }
+    var a = new flash.display.BitmapData(4, 4, true, 0);
+    a.fillRect(new flash.geom.Rectangle(1, 1, 2, 2), 0xFFFFFFFF);
+    a.setPixel32(0, 0, 0x40FFFFFF);
+    var b = new flash.display.BitmapData(2, 2, true, 0);
+    b.setPixel32(1, 1, 0xFF000000);
+    var origin = new flash.geom.Point(0, 0);
+    trace("// points");
+    trace(a.hitTest(origin, 0xFF, new flash.geom.Point(1, 1)));
+    trace(a.hitTest(origin, 0xFF, new flash.geom.Point(0, 0)));
+    trace(a.hitTest(origin, 0x40, new flash.geom.Point(0, 0)));
+    trace(a.hitTest(origin, 0x41, new flash.geom.Point(0, 0)));
+    trace(a.hitTest(new flash.geom.Point(10, 10), 0xFF, new flash.geom.Point(11, 11)));
+    trace(a.hitTest(origin, 0xFF, new flash.geom.Point(5, 5)));
+    trace("// rectangles");
+    trace(a.hitTest(origin, 0xFF, new flash.geom.Rectangle(0, 0, 1, 1)));
+    trace(a.hitTest(origin, 0xFF, new flash.geom.Rectangle(0, 0, 2, 2)));
+    trace(a.hitTest(origin, 0x10, new flash.geom.Rectangle(0, 0, 1, 1)));
+    trace(a.hitTest(new flash.geom.Point(5, 5), 0xFF, new flash.geom.Rectangle(6, 6, 1, 1)));
+    trace("// bitmaps");
+    trace(a.hitTest(origin, 0xFF, b, new flash.geom.Point(0, 0), 0xFF));
+    trace(a.hitTest(origin, 0xFF, b, new flash.geom.Point(2, 2), 0xFF));
+    trace(a.hitTest(origin, 0xFF, b, new flash.geom.Point(3, 3), 0xFF));
+    trace(a.hitTest(origin, 0x40, b, new flash.geom.Point(-1, -1), 0xFF));
+    trace(a.hitTest(origin, 0xFF, b, new flash.geom.Point(-1, -1), 0xFF));
+.end
+.end
@@ -0,0 +1,38 @@
+.flash bbox=100x100 version=8 name="test.swf"
+.action:
+    function hex(n) {
+        var hi = ((n >> 16) & 0xFFFF).toString(16);
+        var lo = (n & 0xFFFF).toString(16);
+        while (hi.length < 4) hi = "0" + hi;
+        while (lo.length < 4) lo = "0" + lo;
+        return hi + lo;
+    }
+    function dump(bd) {
+        for (var y = 0; y < bd.height; y++) {
+            var row = "";
+            for (var x = 0; x < bd.width; x++) {
+                row += (x > 0 ? " " : "") + hex(bd.getPixel32(x, y));
+            }
+            trace(row);
+        }
+    }
+    var src = new flash.display.BitmapData(2, 2, true, 0xFF804020);
+    src.setPixel32(1, 1, 0x40FFFFFF);
+    var dst = new flash.display.BitmapData(3, 3, true, 0xFF2040C0);
+    dst.setPixel32(2, 2, 0x00000000);
+    trace("// merge 256");
+    dst.merge(src, src.rectangle, new flash.geom.Point(0, 0), 256, 256, 256, 256);
+    dump(dst);
+    trace("// merge 128");
+    dst = new flash.display.BitmapData(3, 3, true, 0xFF2040C0);
+    dst.merge(src, src.rectangle, new flash.geom.Point(1, 1), 128, 128, 128, 128);
+    dump(dst);
+    trace("// merge per channel");
+    dst = new flash.display.BitmapData(3, 3, true, 0xFF2040C0);
+    dst.merge(src, new flash.geom.Rectangle(0, 0, 2, 1), new flash.geom.Point(1, 0), 0, 64, 192, 256);
+    dump(dst);
+    trace("// merge into itself");
+    src.merge(src, new flash.geom.Rectangle(0, 0, 1, 2), new flash.geom.Point(1, 0), 128, 128, 128, 128);
+    dump(src);
+.end
+.end
@@ -0,0 +1,47 @@
+.flash bbox=100x100 version=8 name="test.swf"
+.action:
+    function hex(n) {
+        var hi = ((n >> 16) & 0xFFFF).toString(16);
+        var lo = (n & 0xFFFF).toString(16);
+        while (hi.length < 4) hi = "0" + hi;
+        while (lo.length < 4) lo = "0" + lo;
+        return hi + lo;
+    }
+    function dump(bd) {
+        for (var y = 0; y < bd.height; y++) {
+            var row = "";
+            for (var x = 0; x < bd.width; x++) {
+                row += (x > 0 ? " " : "") + hex(bd.getPixel32(x, y));
+            }
+            trace(row);
+        }
+    }
+    var src = new flash.display.BitmapData(2, 2, true, 0);
+    src.setPixel32(0, 0, 0xFF000000);
+    src.setPixel32(1, 0, 0x80010203);
+    src.setPixel32(0, 1, 0xFFFF00FF);
+    src.setPixel32(1, 1, 0x00FFFFFF);
+    var red = new Array(256);
+    var green = new Array(256);
+    var blue = new Array(256);
+    var alpha = new Array(256);
+    for (var i = 0; i < 256; i++) {
+        red[i] = (255 - i) << 16;
+        green[i] = i << 8;
+        blue[i] = 0;
+        alpha[i] = 0xFF000000;
+    }
+    var dst = new flash.display.BitmapData(2, 2, true, 0x11223344);
+    trace("// paletteMap with all arrays");
+    dst.paletteMap(src, src.rectangle, new flash.geom.Point(0, 0), red, green, blue, alpha);
+    dump(dst);
+    trace("// paletteMap with only red");
+    dst = new flash.display.BitmapData(2, 2, true, 0x11223344);
+    dst.paletteMap(src, src.rectangle, new flash.geom.Point(0, 0), red);
+    dump(dst);
+    trace("// paletteMap into an offset point");
+    dst = new flash.display.BitmapData(3, 3, true, 0x11223344);
+    dst.paletteMap(src, new flash.geom.Rectangle(0, 0, 1, 2), new flash.geom.Point(1, 1), null, green, blue, alpha);
+    dump(dst);
+.end
+.end
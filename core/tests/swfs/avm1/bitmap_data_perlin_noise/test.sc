@@ -0,0 +1,56 @@
+.flash bbox=100x100 version=8 name="test.swf"
+.action:
+    function same(a, b) {
+        var y = 0;
+        while (y < a.height) {
+            var x = 0;
+            while (x < a.width) {
+                if (a.getPixel32(x, y) != b.getPixel32(x, y)) return false;
+                x++;
+            }
+            y++;
+        }
+        return true;
+    }
+    function gray(bd) {
+        var y = 0;
+        while (y < bd.height) {
+            var x = 0;
+            while (x < bd.width) {
+                var p = bd.getPixel(x, y);
+                if ((p >> 16) != (p & 0xFF)) return false;
+                if (((p >> 8) & 0xFF) != (p & 0xFF)) return false;
+                x++;
+            }
+            y++;
+        }
+        return true;
+    }
+    function opaque(bd) {
+        var y = 0;
+        while (y < bd.height) {
+            var x = 0;
+            while (x < bd.width) {
+                if ((bd.getPixel32(x, y) >>> 24) != 255) return false;
+                x++;
+            }
+            y++;
+        }
+        return true;
+    }
+    var a = new flash.display.BitmapData(8, 8, true, 0);
+    var b = new flash.display.BitmapData(8, 8, true, 0);
+    a.perlinNoise(6, 6, 3, 42, false, true, 7, false);
+    b.perlinNoise(6, 6, 3, 42, false, true, 7, false);
+    trace("// same seed gives the same noise");
+    trace(same(a, b));
+    b.perlinNoise(6, 6, 3, 43, false, true, 7, false);
+    trace("// different seeds give different noise");
+    trace(!same(a, b));
+    trace("// noise without the alpha channel is opaque");
+    trace(opaque(a));
+    a.perlinNoise(8, 8, 2, 7, true, true, 7, true);
+    trace("// grayscale noise has equal color channels");
+    trace(gray(a));
+.end
+.end
@@ -0,0 +1,56 @@
+.flash bbox=100x100 version=8 name="test.swf"
+.action:
+    function count(bd, color) {
+        var n = 0;
+        var y = 0;
+        while (y < bd.height) {
+            var x = 0;
+            while (x < bd.width) {
+                if (bd.getPixel32(x, y) == color) n++;
+                x++;
+            }
+            y++;
+        }
+        return n;
+    }
+    function same(a, b) {
+        var y = 0;
+        while (y < a.height) {
+            var x = 0;
+            while (x < a.width) {
+                if (a.getPixel32(x, y) != b.getPixel32(x, y)) return false;
+                x++;
+            }
+            y++;
+        }
+        return true;
+    }
+    var src = new flash.display.BitmapData(4, 4, true, 0xFFFF0000);
+    var red = src.getPixel32(0, 0);
+    var green = new flash.display.BitmapData(1, 1, true, 0xFF00FF00).getPixel32(0, 0);
+    var dst = new flash.display.BitmapData(4, 4, true, 0xFF0000FF);
+    trace("// continuing from the returned seed dissolves new pixels");
+    var seed = 17;
+    var i = 0;
+    while (i < 4) {
+        seed = dst.pixelDissolve(src, src.rectangle, new flash.geom.Point(0, 0), seed, 5);
+        trace(count(dst, red));
+        i++;
+    }
+    trace("// same seed gives the same pattern");
+    var a = new flash.display.BitmapData(4, 4, true, 0xFF0000FF);
+    var b = new flash.display.BitmapData(4, 4, true, 0xFF0000FF);
+    trace(a.pixelDissolve(src, src.rectangle, new flash.geom.Point(0, 0), 1234, 7) == b.pixelDissolve(src, src.rectangle, new flash.geom.Point(0, 0), 1234, 7));
+    trace(same(a, b));
+    trace(count(a, red));
+    trace("// dissolve into itself uses the fill color");
+    var c = new flash.display.BitmapData(4, 4, true, 0xFF0000FF);
+    c.pixelDissolve(c, c.rectangle, new flash.geom.Point(0, 0), 3, 6, 0xFF00FF00);
+    trace(count(c, green));
+    trace("// partial rectangle");
+    var d = new flash.display.BitmapData(4, 4, true, 0xFF0000FF);
+    d.pixelDissolve(src, new flash.geom.Rectangle(0, 0, 2, 2), new flash.geom.Point(1, 1), 9, 4);
+    trace(count(d, red));
+    trace(d.getPixel32(1, 1) == red && d.getPixel32(2, 1) == red && d.getPixel32(1, 2) == red && d.getPixel32(2, 2) == red);
+.end
+.end
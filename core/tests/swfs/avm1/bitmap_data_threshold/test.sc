@@ -0,0 +1,43 @@
+.flash bbox=100x100 version=8 name="test.swf"
+.action:
+    function hex(n) {
+        var hi = ((n >> 16) & 0xFFFF).toString(16);
+        var lo = (n & 0xFFFF).toString(16);
+        while (hi.length < 4) hi = "0" + hi;
+        while (lo.length < 4) lo = "0" + lo;
+        return hi + lo;
+    }
+    function dump(bd) {
+        for (var y = 0; y < bd.height; y++) {
+            var row = "";
+            for (var x = 0; x < bd.width; x++) {
+                row += (x > 0 ? " " : "") + hex(bd.getPixel32(x, y));
+            }
+            trace(row);
+        }
+    }
+    var src = new flash.display.BitmapData(3, 2, true, 0);
+    src.setPixel32(0, 0, 0xFF000000);
+    src.setPixel32(1, 0, 0xFF7F7F7F);
+    src.setPixel32(2, 0, 0xFFFFFFFF);
+    src.setPixel32(0, 1, 0x80102030);
+    src.setPixel32(1, 1, 0x00FF0000);
+    src.setPixel32(2, 1, 0xFF00FF00);
+    var ops = ["<", "<=", ">", ">=", "==", "!="];
+    for (var i = 0; i < ops.length; i++) {
+        var dst = new flash.display.BitmapData(3, 2, true, 0xFFABCDEF);
+        var count = dst.threshold(src, src.rectangle, new flash.geom.Point(0, 0), ops[i], 0x7F7F7F7F, 0xFF0000FF, 0xFFFFFFFF, false);
+        trace("// threshold " + ops[i] + " -> " + count);
+        dump(dst);
+    }
+    trace("// threshold with mask and copySource");
+    var dst = new flash.display.BitmapData(3, 2, true, 0xFFABCDEF);
+    trace(dst.threshold(src, src.rectangle, new flash.geom.Point(0, 0), ">", 0x00000080, 0x12345678, 0x000000FF, true));
+    dump(dst);
+    trace("// threshold into itself");
+    trace(src.threshold(src, src.rectangle, new flash.geom.Point(1, 0), "==", 0xFF000000, 0xFFFFFF00, 0xFF000000, true));
+    dump(src);
+    trace("// invalid operation");
+    trace(src.threshold(src, src.rectangle, new flash.geom.Point(0, 0), "~", 0, 0));
+.end
+.end
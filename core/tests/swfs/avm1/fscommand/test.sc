@@ -0,0 +1,23 @@
+.flash bbox=550x400 version=8 name="test.swf" compress
+.action:
+    trace("// fscommand(\"showmenu\", \"false\")");
+    fscommand("showmenu", "false");
+    trace(Stage.showMenu);
+    trace("// fscommand(\"allowscale\", \"false\")");
+    fscommand("allowscale", "false");
+    trace(Stage.scaleMode);
+    trace("// Stage.scaleMode = \"exactFit\"");
+    Stage.scaleMode = "exactFit";
+    trace("// fscommand(\"allowscale\", \"true\")");
+    fscommand("allowscale", "true");
+    trace(Stage.scaleMode);
+    trace("// fscommand(\"trapallkeys\", \"true\")");
+    fscommand("trapallkeys", "true");
+    trace("// fscommand(\"exec\", \"calc.exe\")");
+    fscommand("exec", "calc.exe");
+    trace("// fscommand(\"save\", \"level 3\")");
+    fscommand("save", "level 3");
+    trace("// getURL(\"FSCommand:load\", \"slot 1\")");
+    getURL("FSCommand:load", "slot 1");
+.end
+.end
@@ -0,0 +1,7 @@
+.flash bbox=200x200 version=8 name="library.swf"
+.box box 40 30 fill=blue
+.sprite square
+.put box
+.end
+.export square
+.end
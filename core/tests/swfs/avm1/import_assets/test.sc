@@ -0,0 +1,23 @@
+.flash bbox=200x200 version=8 name="test.swf"
+.action:
+    trace("frame 1");
+    trace("_framesloaded: " + _framesloaded);
+    gotoAndStop(3);
+    trace("_currentframe after goto: " + _currentframe);
+.end
+.frame 2
+.import "library.swf" square=square
+.import "missing.swf" circle=circle
+.action:
+    trace("frame 2");
+.end
+.frame 3
+.put s=square x=10 y=10
+.action:
+    trace("frame 3");
+    trace("_currentframe: " + _currentframe);
+    trace("_framesloaded: " + _framesloaded);
+    trace("s: " + s);
+    trace("s._width: " + s._width);
+.end
+.end
@@ -0,0 +1,74 @@
+.flash bbox=200x200 version=8 name="test.swf"
+.action:
+    var listener = new Object();
+    listener.onLoadStart = function(target) {
+        trace("onLoadStart");
+    };
+    listener.onLoadComplete = function(target) {
+        trace("onLoadComplete");
+    };
+    listener.onLoadInit = function(target) {
+        trace("onLoadInit: " + target);
+        trace("_width: " + target._width);
+        trace("_height: " + target._height);
+    };
+    listener.onLoadError = function(target, error) {
+        trace("onLoadError: " + error);
+    };
+
+    var loader = new MovieClipLoader();
+    loader.addListener(listener);
+
+    createEmptyMovieClip("png", 1);
+    png.loadMovie("image.png");
+
+    createEmptyMovieClip("jpg", 2);
+    jpg._x = 50;
+    loader.loadClip("image.jpg", jpg);
+
+    createEmptyMovieClip("gif", 3);
+    gif._x = 100;
+    gif._xscale = 200;
+    loader.loadClip("image.gif", gif);
+
+    createEmptyMovieClip("offset", 5);
+    offset._y = 50;
+    loader.loadClip("image_offset.gif", offset);
+
+    createEmptyMovieClip("missing", 4);
+    loader.loadClip("missing.png", missing);
+.end
+.frame 3
+.action:
+    function hex(n) {
+        var hi = ((n >> 16) & 0xFFFF).toString(16);
+        var lo = (n & 0xFFFF).toString(16);
+        while (hi.length < 4) hi = "0" + hi;
+        while (lo.length < 4) lo = "0" + lo;
+        return hi + lo;
+    }
+    function dump(bd) {
+        for (var y = 0; y < bd.height; y++) {
+            var row = "";
+            for (var x = 0; x < bd.width; x++) {
+                row += (x > 0 ? " " : "") + hex(bd.getPixel32(x, y));
+            }
+            trace(row);
+        }
+    }
+    trace("// after loading");
+    trace("png: " + png._width + "x" + png._height);
+    trace("png._framesloaded: " + png._framesloaded);
+    trace("png._totalframes: " + png._totalframes);
+    trace("png bytes: " + png.getBytesLoaded() + "/" + png.getBytesTotal());
+    trace("jpg: " + jpg._width + "x" + jpg._height);
+    trace("gif: " + gif._width + "x" + gif._height);
+    trace("gif._x: " + gif._x);
+    trace("gif bytes: " + gif.getBytesLoaded() + "/" + gif.getBytesTotal());
+    trace("offset: " + offset._width + "x" + offset._height);
+    var bd = new flash.display.BitmapData(10, 6, true, 0xFF0000FF);
+    bd.draw(offset);
+    dump(bd);
+    stop();
+.end
+.end
@@ -0,0 +1,12 @@
+.flash bbox=200x200 version=8 name="test.swf"
+.action:
+    var n = NaN;
+    trace(n.toString(2));
+    trace(n.toString(3));
+    trace(n.toString(10));
+    trace(n.toString(16));
+    var inf = Infinity;
+    trace(inf.toString(2));
+    stop();
+.end
+.end
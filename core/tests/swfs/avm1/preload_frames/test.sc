@@ -0,0 +1,20 @@
+.flash bbox=200x200 version=8 name="test.swf" compress
+.action:
+    trace("_totalframes: " + _totalframes);
+    trace("_framesloaded < _totalframes: " + (_framesloaded < _totalframes));
+    trace("getBytesLoaded() < getBytesTotal(): " + (getBytesLoaded() < getBytesTotal()));
+    stop();
+    onEnterFrame = function() {
+        if (_framesloaded == _totalframes) {
+            trace("loaded");
+            trace("getBytesLoaded() == getBytesTotal(): " + (getBytesLoaded() == getBytesTotal()));
+            delete onEnterFrame;
+            gotoAndStop(_totalframes);
+        }
+    };
+.end
+.frame 5000
+.action:
+    trace("_currentframe: " + _currentframe);
+.end
+.end
@@ -0,0 +1,38 @@
+.flash bbox=200x200 version=8 name="test.swf"
+.box abox 100 100 fill=red
+.box bbox 50 100 fill=green
+.box cbox 100 50 fill=blue
+.sprite aclip
+.put abox
+.end
+.sprite bclip
+.put bbox
+.end
+.sprite cclip
+.put cbox
+.end
+.put a=aclip
+.put b=bclip
+.put c=cclip
+.action:
+    trace("// a masked by b");
+    trace(a.setMask(b));
+    trace("// b masked by a");
+    trace(b.setMask(a));
+    trace(b.hitTest(25, 25, true));
+    trace(hitTest(25, 25, true));
+    trace(hitTest(75, 75, true));
+    trace("// b masked by c");
+    trace(b.setMask(c));
+    trace("// c masked by a");
+    trace(c.setMask(a));
+    trace(hitTest(25, 25, true));
+    trace(hitTest(25, 75, true));
+    trace(hitTest(75, 75, true));
+    trace("// a unmasked, then c masked by a");
+    a.setMask(null);
+    trace(c.setMask(a));
+    trace(hitTest(25, 25, true));
+    stop();
+.end
+.end
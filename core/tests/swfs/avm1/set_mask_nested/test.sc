@@ -0,0 +1,41 @@
+.flash bbox=200x200 version=8 name="test.swf"
+.box contentbox 100 100 fill=red
+.box tallbox 50 100 fill=green
+.box widebox 100 50 fill=blue
+.sprite contentclip
+.put contentbox
+.end
+.sprite holderclip
+.put content=contentclip
+.end
+.sprite tallclip
+.put tallbox
+.end
+.sprite wideclip
+.put widebox
+.end
+.put holder=holderclip
+.put m1=tallclip
+.put m2=wideclip
+.action:
+    holder.content.setMask(m1);
+    trace("// masked by m1");
+    trace(holder.hitTest(75, 25, true));
+    trace(holder.hitTest(25, 25, true));
+    trace(holder.hitTest(25, 75, true));
+    m1.setMask(m2);
+    trace("// m1 masked by m2");
+    trace(holder.hitTest(75, 25, true));
+    trace(holder.hitTest(25, 25, true));
+    trace(holder.hitTest(25, 75, true));
+.end
+.frame 2
+.del m1
+.action:
+    trace("// m1 removed");
+    trace(holder.hitTest(75, 25, true));
+    trace(holder.hitTest(25, 75, true));
+    trace(holder.hitTest(75, 75, true));
+    stop();
+.end
+.end
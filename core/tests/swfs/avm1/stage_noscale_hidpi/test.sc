@@ -0,0 +1,22 @@
+.flash bbox=300x200 version=8 name="test.swf" compress
+.action:
+    Stage.scaleMode = "noScale";
+    Stage.align = "TL";
+    trace("// Stage.width, Stage.height");
+    trace(Stage.width + ", " + Stage.height);
+
+    var stageListener = {};
+    stageListener.onResize = function() {
+        trace("// onResize: Stage.width, Stage.height");
+        trace(Stage.width + ", " + Stage.height);
+    };
+    Stage.addListener(stageListener);
+
+    var mouseListener = {};
+    mouseListener.onMouseMove = function() {
+        trace("// onMouseMove: _root._xmouse, _root._ymouse");
+        trace(_root._xmouse + ", " + _root._ymouse);
+    };
+    Mouse.addListener(mouseListener);
+.end
+.end
@@ -0,0 +1,10 @@
+.flash bbox=200x200 version=8 name="child.swf"
+.action:
+    trace("child frame 1");
+    stop();
+.end
+.frame 1000
+.action:
+    trace("child frame 1000");
+.end
+.end
@@ -0,0 +1,50 @@
+.flash bbox=200x200 version=8 name="test.swf"
+.action:
+    trace("_framesloaded < _totalframes: " + (_framesloaded < _totalframes));
+    trace("getBytesLoaded() < getBytesTotal(): " + (getBytesLoaded() < getBytesTotal()));
+    stop();
+
+    var partlyLoaded = false;
+    onEnterFrame = function() {
+        if (_framesloaded > 1 && _framesloaded < _totalframes) {
+            partlyLoaded = true;
+        }
+        if (_framesloaded == _totalframes) {
+            trace("played while partly loaded: " + partlyLoaded);
+            trace("getBytesLoaded() == getBytesTotal(): " + (getBytesLoaded() == getBytesTotal()));
+            delete onEnterFrame;
+            gotoAndStop(_totalframes);
+            loadChild();
+        }
+    };
+
+    function loadChild() {
+        var progressEvents = 0;
+        var childPartlyLoaded = false;
+        var listener = new Object();
+        listener.onLoadProgress = function(target, loaded, total) {
+            progressEvents++;
+            if (child._framesloaded > 0 && child._framesloaded < child._totalframes) {
+                childPartlyLoaded = true;
+            }
+        };
+        listener.onLoadComplete = function(target) {
+            trace("child onLoadComplete");
+            trace("child progress events > 1: " + (progressEvents > 1));
+            trace("child played while partly loaded: " + childPartlyLoaded);
+        };
+        listener.onLoadInit = function(target) {
+            trace("child onLoadInit: " + child._framesloaded + "/" + child._totalframes);
+        };
+
+        var loader = new MovieClipLoader();
+        loader.addListener(listener);
+        createEmptyMovieClip("child", 1);
+        loader.loadClip("child.swf", child);
+    }
+.end
+.frame 500
+.action:
+    trace("_currentframe: " + _currentframe);
+.end
+.end
@@ -0,0 +1,26 @@
+.flash bbox=100x100 version=8 name="test.swf"
+.action:
+    trace("// getVersion()");
+    trace(getVersion());
+    trace("// System.capabilities.version");
+    trace(System.capabilities.version);
+    trace("// System.capabilities.os");
+    trace(System.capabilities.os);
+    trace("// System.capabilities.manufacturer");
+    trace(System.capabilities.manufacturer);
+    trace("// System.capabilities.language");
+    trace(System.capabilities.language);
+    trace("// System.capabilities.playerType");
+    trace(System.capabilities.playerType);
+    trace("// System.capabilities.screenResolutionX");
+    trace(System.capabilities.screenResolutionX);
+    trace("// System.capabilities.screenResolutionY");
+    trace(System.capabilities.screenResolutionY);
+    trace("// System.capabilities.hasAudio");
+    trace(System.capabilities.hasAudio);
+    trace("// System.capabilities.hasMP3");
+    trace(System.capabilities.hasMP3);
+    trace("// System.capabilities.hasPrinting");
+    trace(System.capabilities.hasPrinting);
+.end
+.end
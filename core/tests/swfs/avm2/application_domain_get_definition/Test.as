@@ -0,0 +1,58 @@
+﻿package {
+	import flash.display.MovieClip;
+	import flash.system.ApplicationDomain;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var domain = ApplicationDomain.currentDomain;
+			var child = new ApplicationDomain(domain);
+
+			trace("//domain.getDefinition(\"Test\") === Test");
+			trace(domain.getDefinition("Test") === Test);
+			trace("//domain.getDefinition(\"flash.display.MovieClip\") === MovieClip");
+			trace(domain.getDefinition("flash.display.MovieClip") === MovieClip);
+			trace("//child.getDefinition(\"Test\") === Test");
+			trace(child.getDefinition("Test") === Test);
+			trace("//domain.hasDefinition(\"does.not.Exist\")");
+			trace(domain.hasDefinition("does.not.Exist"));
+
+			trace("//domain.getDefinition(\"does.not.Exist\")");
+			try {
+				domain.getDefinition("does.not.Exist");
+				trace("not thrown");
+			} catch (e:ReferenceError) {
+				trace("//e is ReferenceError");
+				trace(e is ReferenceError);
+				trace("//e is Error");
+				trace(e is Error);
+				trace("//e.errorID");
+				trace(e.errorID);
+				trace("//e.message");
+				trace(e.message);
+				trace("//e.name");
+				trace(e.name);
+			}
+
+			trace("//this.lookUp(\"Missing\")");
+			try {
+				this.lookUp("Missing");
+			} catch (e:TypeError) {
+				trace("caught as TypeError");
+			} catch (e:Error) {
+				trace("caught as Error");
+				trace(e);
+			}
+
+			trace("//throw \"thrown string\"");
+			try {
+				throw "thrown string";
+			} catch (e) {
+				trace(e);
+			}
+		}
+
+		public function lookUp(name:String):Object {
+			return ApplicationDomain.currentDomain.getDefinition(name);
+		}
+	}
+}
@@ -0,0 +1,121 @@
+﻿package {
+	import flash.display.Bitmap;
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.geom.Point;
+	import flash.geom.Rectangle;
+	import flash.utils.ByteArray;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("//var bd = new BitmapData(4, 3, true, 0x80FF0000);");
+			var bd = new BitmapData(4, 3, true, 0x80FF0000);
+			trace("//bd.width");
+			trace(bd.width);
+			trace("//bd.height");
+			trace(bd.height);
+			trace("//bd.transparent");
+			trace(bd.transparent);
+			trace("//bd.getPixel32(0, 0)");
+			trace(bd.getPixel32(0, 0));
+			trace("//bd.getPixel(0, 0)");
+			trace(bd.getPixel(0, 0));
+			trace("//bd.getPixel32(10, 10)");
+			trace(bd.getPixel32(10, 10));
+
+			trace("//bd.setPixel32(1, 1, 0xFF00FF00);");
+			bd.setPixel32(1, 1, 0xFF00FF00);
+			trace("//bd.getPixel32(1, 1)");
+			trace(bd.getPixel32(1, 1));
+			trace("//bd.setPixel(2, 1, 0x0000FF);");
+			bd.setPixel(2, 1, 0x0000FF);
+			trace("//bd.getPixel32(2, 1)");
+			trace(bd.getPixel32(2, 1));
+
+			trace("//bd.fillRect(new Rectangle(2, 0, 2, 3), 0xFFFFFFFF);");
+			bd.fillRect(new Rectangle(2, 0, 2, 3), 0xFFFFFFFF);
+			trace("//bd.getPixel32(3, 2)");
+			trace(bd.getPixel32(3, 2));
+			trace("//bd.getPixel32(1, 2)");
+			trace(bd.getPixel32(1, 2));
+
+			trace("//var opaque = new BitmapData(2, 2, false, 0x654321);");
+			var opaque = new BitmapData(2, 2, false, 0x654321);
+			trace("//opaque.transparent");
+			trace(opaque.transparent);
+			trace("//opaque.getPixel32(0, 0)");
+			trace(opaque.getPixel32(0, 0));
+			trace("//opaque.setPixel32(0, 0, 0x00ABCDEF);");
+			opaque.setPixel32(0, 0, 0x00ABCDEF);
+			trace("//opaque.getPixel32(0, 0)");
+			trace(opaque.getPixel32(0, 0));
+
+			trace("//bd.copyPixels(opaque, new Rectangle(0, 0, 2, 2), new Point(0, 1));");
+			bd.copyPixels(opaque, new Rectangle(0, 0, 2, 2), new Point(0, 1));
+			trace("//bd.getPixel32(0, 1)");
+			trace(bd.getPixel32(0, 1));
+			trace("//bd.getPixel32(1, 2)");
+			trace(bd.getPixel32(1, 2));
+			trace("//bd.getPixel32(0, 0)");
+			trace(bd.getPixel32(0, 0));
+
+			trace("//bd.lock();");
+			bd.lock();
+			trace("//bd.setPixel32(0, 0, 0xFF000000);");
+			bd.setPixel32(0, 0, 0xFF000000);
+			trace("//bd.unlock();");
+			bd.unlock();
+			trace("//bd.getPixel32(0, 0)");
+			trace(bd.getPixel32(0, 0));
+
+			trace("//var bytes = bd.getPixels(new Rectangle(0, 0, 2, 1));");
+			var bytes:ByteArray = bd.getPixels(new Rectangle(0, 0, 2, 1));
+			trace("//bytes.length");
+			trace(bytes.length);
+			bytes.position = 0;
+			trace("//bytes.readUnsignedInt()");
+			trace(bytes.readUnsignedInt());
+			trace("//bytes.readUnsignedInt()");
+			trace(bytes.readUnsignedInt());
+
+			trace("//bytes.position = 0; opaque.setPixels(new Rectangle(0, 0, 2, 1), bytes);");
+			bytes.position = 0;
+			opaque.setPixels(new Rectangle(0, 0, 2, 1), bytes);
+			trace("//opaque.getPixel32(0, 0)");
+			trace(opaque.getPixel32(0, 0));
+			trace("//opaque.getPixel32(1, 0)");
+			trace(opaque.getPixel32(1, 0));
+
+			trace("//var vector = bd.getVector(new Rectangle(2, 1, 2, 2));");
+			var vector:Vector.<uint> = bd.getVector(new Rectangle(2, 1, 2, 2));
+			trace("//vector.length");
+			trace(vector.length);
+			trace("//vector[0]");
+			trace(vector[0]);
+
+			trace("//vector[0] = 0xFF112233; opaque.setVector(new Rectangle(1, 1, 1, 1), vector);");
+			vector[0] = 0xFF112233;
+			opaque.setVector(new Rectangle(1, 1, 1, 1), vector);
+			trace("//opaque.getPixel32(1, 1)");
+			trace(opaque.getPixel32(1, 1));
+
+			trace("//var bitmap = new Bitmap(bd);");
+			var bitmap = new Bitmap(bd);
+			addChild(bitmap);
+			trace("//bitmap.bitmapData == bd");
+			trace(bitmap.bitmapData == bd);
+			trace("//bitmap.width");
+			trace(bitmap.width);
+			trace("//bitmap.height");
+			trace(bitmap.height);
+			trace("//bitmap.pixelSnapping");
+			trace(bitmap.pixelSnapping);
+			trace("//bitmap.smoothing");
+			trace(bitmap.smoothing);
+			trace("//bitmap.bitmapData = opaque;");
+			bitmap.bitmapData = opaque;
+			trace("//bitmap.width");
+			trace(bitmap.width);
+		}
+	}
+}
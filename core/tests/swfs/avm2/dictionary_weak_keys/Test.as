@@ -0,0 +1,65 @@
+﻿package {
+	import flash.display.MovieClip;
+	import flash.events.TimerEvent;
+	import flash.system.System;
+	import flash.utils.Dictionary;
+	import flash.utils.Timer;
+
+	public class Test extends MovieClip {
+		var strong;
+		var weak;
+
+		public function Test() {
+			trace("//var strong = new Dictionary();");
+			strong = new Dictionary();
+			trace("//var weak = new Dictionary(true);");
+			weak = new Dictionary(true);
+
+			addKeys();
+
+			trace("//(keys in strong)");
+			trace(countKeys(strong));
+			trace("//(keys in weak)");
+			trace(countKeys(weak));
+
+			trace("//System.gc();");
+			System.gc();
+
+			var timer = new Timer(1, 1);
+			timer.addEventListener(TimerEvent.TIMER, onTimer);
+			timer.start();
+		}
+
+		function addKeys() {
+			var strongKey = {};
+			var weakKey = {};
+
+			trace("//strong[strongKey] = \"strong value\";");
+			strong[strongKey] = "strong value";
+			trace("//weak[weakKey] = \"weak value\";");
+			weak[weakKey] = "weak value";
+
+			trace("//strong[strongKey]");
+			trace(strong[strongKey]);
+			trace("//weak[weakKey]");
+			trace(weak[weakKey]);
+			trace("//strong[{}]");
+			trace(strong[{}]);
+		}
+
+		function countKeys(dict) {
+			var count = 0;
+			for (var key in dict) {
+				count += 1;
+			}
+			return count;
+		}
+
+		function onTimer(e) {
+			trace("//(keys in strong, after collection)");
+			trace(countKeys(strong));
+			trace("//(keys in weak, after collection)");
+			trace(countKeys(weak));
+		}
+	}
+}
@@ -0,0 +1,41 @@
+﻿package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var sprite = new Sprite();
+			sprite.x = 10;
+			sprite.y = 20;
+			addChild(sprite);
+
+			trace("//sprite.graphics.lineStyle(10, 0x000000);");
+			sprite.graphics.lineStyle(10, 0x000000);
+			trace("//sprite.graphics.drawRect(0, 0, 100, 50);");
+			sprite.graphics.drawRect(0, 0, 100, 50);
+
+			trace("//sprite.getBounds(sprite)");
+			trace(sprite.getBounds(sprite));
+			trace("//sprite.getRect(sprite)");
+			trace(sprite.getRect(sprite));
+			trace("//sprite.getRect(this)");
+			trace(sprite.getRect(this));
+
+			var shape = new Shape();
+			shape.x = 200;
+			trace("//shape.graphics.lineStyle(4, 0x000000);");
+			shape.graphics.lineStyle(4, 0x000000);
+			trace("//shape.graphics.drawRect(0, 0, 20, 20);");
+			shape.graphics.drawRect(0, 0, 20, 20);
+			sprite.addChild(shape);
+
+			trace("//sprite.getBounds(sprite)");
+			trace(sprite.getBounds(sprite));
+			trace("//sprite.getRect(sprite)");
+			trace(sprite.getRect(sprite));
+			trace("//shape.getRect(shape)");
+			trace(shape.getRect(shape));
+		}
+	}
+}
@@ -0,0 +1,34 @@
+﻿package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("//new Error(\"in constructor\").getStackTrace()");
+			trace(new Error("in constructor").getStackTrace());
+			trace("//this.makeError().getStackTrace()");
+			trace(this.makeError().getStackTrace());
+			trace("//this.errorGetter.getStackTrace()");
+			trace(this.errorGetter.getStackTrace());
+
+			trace("//this.throwError()");
+			try {
+				this.throwError();
+			} catch (e:Error) {
+				trace(e);
+				trace(e.getStackTrace());
+			}
+		}
+
+		public function makeError():Error {
+			return new Error("in method", 42);
+		}
+
+		public function get errorGetter():Error {
+			return new Error("in getter");
+		}
+
+		public function throwError():void {
+			throw new Error("thrown");
+		}
+	}
+}
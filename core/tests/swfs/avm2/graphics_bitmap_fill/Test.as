@@ -0,0 +1,45 @@
+﻿package {
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.Matrix;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var bitmapData = new BitmapData(10, 10, false, 0xFF0000);
+			var sprite = new Sprite();
+			addChild(sprite);
+
+			trace("//sprite.graphics.beginBitmapFill(bitmapData);");
+			sprite.graphics.beginBitmapFill(bitmapData);
+			trace("//sprite.graphics.drawRect(0, 0, 40, 20);");
+			sprite.graphics.drawRect(0, 0, 40, 20);
+
+			trace("//sprite.width");
+			trace(sprite.width);
+			trace("//sprite.height");
+			trace(sprite.height);
+			trace("//sprite.hitTestPoint(30, 15, true)");
+			trace(sprite.hitTestPoint(30, 15, true));
+
+			trace("//sprite.graphics.beginBitmapFill(bitmapData, new Matrix(2, 0, 0, 2, 50, 0), false, true);");
+			sprite.graphics.beginBitmapFill(bitmapData, new Matrix(2, 0, 0, 2, 50, 0), false, true);
+			trace("//sprite.graphics.drawRect(50, 0, 20, 20);");
+			sprite.graphics.drawRect(50, 0, 20, 20);
+			trace("//sprite.graphics.endFill();");
+			sprite.graphics.endFill();
+
+			trace("//sprite.width");
+			trace(sprite.width);
+			trace("//sprite.hitTestPoint(60, 10, true)");
+			trace(sprite.hitTestPoint(60, 10, true));
+			trace("//sprite.hitTestPoint(45, 10, true)");
+			trace(sprite.hitTestPoint(45, 10, true));
+
+			trace("//sprite.graphics.clear();");
+			sprite.graphics.clear();
+			trace("//sprite.width");
+			trace(sprite.width);
+		}
+	}
+}
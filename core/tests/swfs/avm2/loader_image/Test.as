@@ -0,0 +1,94 @@
+﻿package {
+	import flash.display.Bitmap;
+	import flash.display.Loader;
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.IOErrorEvent;
+	import flash.events.ProgressEvent;
+	import flash.net.URLLoader;
+	import flash.net.URLLoaderDataFormat;
+	import flash.net.URLRequest;
+
+	public class Test extends MovieClip {
+		var urls:Array = ["image.png", "image.jpg", "image.gif"];
+		var url:String;
+		var loader:Loader;
+		var urlLoader:URLLoader;
+		var progressEvents:int;
+		var lastLoaded:uint;
+		var increasing:Boolean;
+
+		public function Test() {
+			loadNext();
+		}
+
+		public function loadNext() {
+			if (urls.length == 0) {
+				loadBytes("image.png");
+				return;
+			}
+
+			url = urls.shift();
+			progressEvents = 0;
+			lastLoaded = 0;
+			increasing = true;
+
+			loader = new Loader();
+			loader.contentLoaderInfo.addEventListener(ProgressEvent.PROGRESS, onProgress);
+			loader.contentLoaderInfo.addEventListener(Event.COMPLETE, onComplete);
+			loader.contentLoaderInfo.addEventListener(IOErrorEvent.IO_ERROR, onIOError);
+			loader.load(new URLRequest(url));
+		}
+
+		public function onProgress(e:ProgressEvent) {
+			progressEvents++;
+			if (e.bytesLoaded <= lastLoaded) {
+				increasing = false;
+			}
+			lastLoaded = e.bytesLoaded;
+		}
+
+		public function onComplete(e:Event) {
+			trace("// loader.load(\"" + url + "\")");
+			trace("progress events > 1: " + (progressEvents > 1));
+			trace("bytesLoaded increasing: " + increasing);
+			traceContent();
+			loadNext();
+		}
+
+		public function onIOError(e:IOErrorEvent) {
+			trace("ioError: " + e.text);
+			loadNext();
+		}
+
+		public function loadBytes(url:String) {
+			this.url = url;
+			urlLoader = new URLLoader();
+			urlLoader.dataFormat = URLLoaderDataFormat.BINARY;
+			urlLoader.addEventListener(Event.COMPLETE, onBytesLoaded);
+			urlLoader.load(new URLRequest(url));
+		}
+
+		public function onBytesLoaded(e:Event) {
+			loader = new Loader();
+			loader.contentLoaderInfo.addEventListener(Event.COMPLETE, onBytesComplete);
+			loader.contentLoaderInfo.addEventListener(IOErrorEvent.IO_ERROR, onIOError);
+			loader.loadBytes(urlLoader.data);
+		}
+
+		public function onBytesComplete(e:Event) {
+			trace("// loader.loadBytes(\"" + url + "\")");
+			traceContent();
+		}
+
+		public function traceContent() {
+			var info = loader.contentLoaderInfo;
+			trace("bytesLoaded == bytesTotal: " + (info.bytesLoaded == info.bytesTotal));
+			trace("content is Bitmap: " + (loader.content is Bitmap));
+			trace("content size: " + loader.content.width + "x" + loader.content.height);
+			trace("content has bitmapData: " + (loader.content.bitmapData != null));
+			trace("numChildren: " + loader.numChildren);
+			trace("getChildAt(0) == content: " + (loader.getChildAt(0) == loader.content));
+		}
+	}
+}
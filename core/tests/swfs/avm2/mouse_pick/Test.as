@@ -0,0 +1,85 @@
+﻿package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+	import flash.events.MouseEvent;
+	import flash.geom.Point;
+	import flash.text.TextField;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var back = box("back", 0, 0);
+			addChild(back);
+
+			trace("//front.mouseEnabled = false;");
+			var front = box("front", 50, 0);
+			front.mouseEnabled = false;
+			addChild(front);
+
+			trace("//group.mouseChildren = false;");
+			var group = new Sprite();
+			group.name = "group";
+			var shape = new Shape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawRect(200, 0, 100, 100);
+			group.addChild(shape);
+			group.addChild(box("inner", 250, 0));
+			group.mouseChildren = false;
+			addChild(group);
+
+			var passive = new Sprite();
+			passive.name = "passive";
+			passive.mouseEnabled = false;
+			var passiveShape = new Shape();
+			passiveShape.graphics.beginFill(0x00FF00);
+			passiveShape.graphics.drawRect(0, 200, 100, 100);
+			passive.addChild(passiveShape);
+			passive.addChild(box("active", 50, 200));
+			addChild(passive);
+
+			var field = new TextField();
+			field.name = "field";
+			field.x = 400;
+			field.y = 0;
+			field.width = 100;
+			field.height = 100;
+			addChild(field);
+
+			addEventListener(MouseEvent.MOUSE_DOWN, onMouseDown);
+
+			trace("//getObjectsUnderPoint(new Point(75, 50))");
+			trace(names(getObjectsUnderPoint(new Point(75, 50))));
+			trace("//getObjectsUnderPoint(new Point(275, 50))");
+			trace(names(getObjectsUnderPoint(new Point(275, 50))));
+			trace("//group.getObjectsUnderPoint(new Point(225, 50))");
+			trace(names(group.getObjectsUnderPoint(new Point(225, 50))));
+			trace("//getObjectsUnderPoint(new Point(525, 50))");
+			trace(names(getObjectsUnderPoint(new Point(525, 50))));
+		}
+
+		function onMouseDown(e) {
+			trace("//(mouseDown) e.target.name");
+			trace(e.target.name);
+		}
+
+		function box(name, x, y) {
+			var sprite = new Sprite();
+			sprite.name = name;
+			sprite.graphics.beginFill(0x0000FF);
+			sprite.graphics.drawRect(x, y, 100, 100);
+			return sprite;
+		}
+
+		function names(objects) {
+			var result = [];
+			for (var i = 0; i < objects.length; i++) {
+				if (objects[i] is Shape) {
+					result.push("(shape)");
+				} else {
+					result.push(objects[i].name);
+				}
+			}
+			return result.join(",");
+		}
+	}
+}
@@ -0,0 +1,14 @@
+﻿package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace(greeting);
+			trace(count);
+		}
+	}
+}
+
+// Script variables are initialized with setslot on the global scope.
+var greeting = "set by setslot";
+var count = 42;
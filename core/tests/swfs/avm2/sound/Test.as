@@ -0,0 +1,94 @@
+﻿package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.media.Sound;
+	import flash.media.SoundChannel;
+	import flash.media.SoundMixer;
+	import flash.media.SoundTransform;
+
+	public class Test extends MovieClip {
+		public var channel:SoundChannel;
+
+		public function Test() {
+			trace("//var transform = new SoundTransform();");
+			var transform = new SoundTransform();
+			trace("//transform.volume");
+			trace(transform.volume);
+			trace("//transform.pan");
+			trace(transform.pan);
+			trace("//transform.leftToLeft");
+			trace(transform.leftToLeft);
+			trace("//transform.rightToRight");
+			trace(transform.rightToRight);
+
+			trace("//transform = new SoundTransform(0.5, -0.5);");
+			transform = new SoundTransform(0.5, -0.5);
+			trace("//transform.volume");
+			trace(transform.volume);
+			trace("//transform.pan");
+			trace(transform.pan);
+			trace("//transform.leftToLeft");
+			trace(transform.leftToLeft);
+			trace("//transform.rightToRight");
+			trace(transform.rightToRight);
+			trace("//transform.pan = 1;");
+			transform.pan = 1;
+			trace("//transform.leftToLeft");
+			trace(transform.leftToLeft);
+			trace("//transform.rightToRight");
+			trace(transform.rightToRight);
+
+			trace("//SoundMixer.soundTransform.volume");
+			trace(SoundMixer.soundTransform.volume);
+			trace("//SoundMixer.soundTransform = new SoundTransform(0.25);");
+			SoundMixer.soundTransform = new SoundTransform(0.25);
+			trace("//SoundMixer.soundTransform.volume");
+			trace(SoundMixer.soundTransform.volume);
+
+			trace("//var empty = new Sound();");
+			var empty = new Sound();
+			trace("//empty.length");
+			trace(empty.length);
+			trace("//empty.play()");
+			trace(empty.play());
+
+			trace("//var beep = new Beep();");
+			var beep = new Beep();
+			trace("//beep is Sound");
+			trace(beep is Sound);
+			trace("//beep.length");
+			trace(beep.length);
+
+			trace("//channel = beep.play(0, 1, new SoundTransform(0.5));");
+			channel = beep.play(0, 1, new SoundTransform(0.5));
+			trace("//channel is SoundChannel");
+			trace(channel is SoundChannel);
+			trace("//channel.soundTransform.volume");
+			trace(channel.soundTransform.volume);
+			trace("//channel.soundTransform = new SoundTransform(0.75, 0.5);");
+			channel.soundTransform = new SoundTransform(0.75, 0.5);
+			trace("//channel.soundTransform.volume");
+			trace(channel.soundTransform.volume);
+			trace("//channel.soundTransform.pan");
+			trace(channel.soundTransform.pan);
+			channel.addEventListener(Event.SOUND_COMPLETE, onSoundComplete);
+
+			trace("//var stopped = beep.play();");
+			var stopped = beep.play();
+			trace("//stopped.stop();");
+			stopped.stop();
+			trace("//stopped.position");
+			trace(stopped.position);
+		}
+
+		public function onSoundComplete(event:Event) {
+			trace("//soundComplete");
+			trace(event.target == channel);
+			trace("//SoundMixer.stopAll();");
+			SoundMixer.stopAll();
+		}
+	}
+
+	public class Beep extends Sound {
+	}
+}
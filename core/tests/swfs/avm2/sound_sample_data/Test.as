@@ -0,0 +1,76 @@
+﻿package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.SampleDataEvent;
+	import flash.media.Sound;
+	import flash.media.SoundChannel;
+	import flash.media.SoundMixer;
+	import flash.utils.ByteArray;
+
+	public class Test extends MovieClip {
+		public var channel:SoundChannel;
+
+		public function Test() {
+			var beep = new Beep();
+			var bytes = new ByteArray();
+
+			trace("//beep.extract(bytes, 100, 0)");
+			trace(beep.extract(bytes, 100, 0));
+			trace("//bytes.length");
+			trace(bytes.length);
+			bytes.position = 0;
+			trace("//bytes.readFloat()");
+			trace(bytes.readFloat());
+			bytes.position = 25 * 8;
+			trace("//(frame 25) bytes.readFloat()");
+			trace(bytes.readFloat());
+			trace("//bytes.readFloat()");
+			trace(bytes.readFloat());
+
+			trace("//beep.extract(bytes, 1000)");
+			trace(beep.extract(bytes, 1000));
+			trace("//bytes.length");
+			trace(bytes.length);
+			trace("//beep.extract(bytes, 1000)");
+			trace(beep.extract(bytes, 1000));
+			trace("//beep.extract(bytes, 10, 1000)");
+			trace(beep.extract(bytes, 10, 1000));
+
+			var spectrum = new ByteArray();
+			trace("//SoundMixer.computeSpectrum(spectrum);");
+			SoundMixer.computeSpectrum(spectrum);
+			trace("//spectrum.length");
+			trace(spectrum.length);
+			trace("//SoundMixer.computeSpectrum(spectrum, true, 1);");
+			SoundMixer.computeSpectrum(spectrum, true, 1);
+			trace("//spectrum.length");
+			trace(spectrum.length);
+
+			var generated = new Sound();
+			generated.addEventListener(SampleDataEvent.SAMPLE_DATA, onSampleData);
+			trace("//channel = generated.play();");
+			channel = generated.play();
+			trace("//channel is SoundChannel");
+			trace(channel is SoundChannel);
+			channel.addEventListener(Event.SOUND_COMPLETE, onSoundComplete);
+		}
+
+		public function onSampleData(event:SampleDataEvent) {
+			trace("//sampleData");
+			trace(event.position);
+			trace(event.data.length);
+			var frames = event.position < 4096 ? 2048 : 100;
+			for (var i = 0; i < frames; i++) {
+				event.data.writeFloat(0.5);
+				event.data.writeFloat(-0.5);
+			}
+		}
+
+		public function onSoundComplete(event:Event) {
+			trace("//soundComplete");
+		}
+	}
+
+	public class Beep extends Sound {
+	}
+}
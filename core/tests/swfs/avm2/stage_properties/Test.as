@@ -0,0 +1,68 @@
+﻿package {
+	import flash.display.MovieClip;
+	import flash.display.StageAlign;
+	import flash.display.StageDisplayState;
+	import flash.display.StageQuality;
+	import flash.display.StageScaleMode;
+	import flash.events.Event;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("//stage.stageWidth");
+			trace(stage.stageWidth);
+			trace("//stage.stageHeight");
+			trace(stage.stageHeight);
+			trace("//stage.scaleMode");
+			trace(stage.scaleMode);
+			trace("//stage.align");
+			trace(stage.align);
+			trace("//stage.frameRate");
+			trace(stage.frameRate);
+			trace("//stage.quality");
+			trace(stage.quality);
+			trace("//stage.displayState");
+			trace(stage.displayState);
+
+			trace("//stage.frameRate = 12;");
+			stage.frameRate = 12;
+			trace("//stage.frameRate");
+			trace(stage.frameRate);
+
+			trace("//stage.quality = StageQuality.LOW;");
+			stage.quality = StageQuality.LOW;
+			trace("//stage.quality");
+			trace(stage.quality);
+
+			trace("//stage.align = StageAlign.TOP_LEFT;");
+			stage.align = StageAlign.TOP_LEFT;
+			trace("//stage.align");
+			trace(stage.align);
+			trace("//stage.align = \"RBT\";");
+			stage.align = "RBT";
+			trace("//stage.align");
+			trace(stage.align);
+
+			trace("//stage.scaleMode = StageScaleMode.NO_SCALE;");
+			stage.scaleMode = StageScaleMode.NO_SCALE;
+			trace("//stage.scaleMode");
+			trace(stage.scaleMode);
+			trace("//stage.stageWidth");
+			trace(stage.stageWidth);
+			trace("//stage.stageHeight");
+			trace(stage.stageHeight);
+
+			trace("//stage.displayState = StageDisplayState.NORMAL;");
+			stage.displayState = StageDisplayState.NORMAL;
+			trace("//stage.displayState");
+			trace(stage.displayState);
+
+			stage.addEventListener(Event.RESIZE, onResize);
+		}
+
+		public function onResize(event:Event) {
+			trace("//resize");
+			trace(stage.stageWidth);
+			trace(stage.stageHeight);
+		}
+	}
+}
@@ -0,0 +1,131 @@
+﻿package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.TextEvent;
+	import flash.text.TextField;
+	import flash.text.TextFieldAutoSize;
+	import flash.text.TextFieldType;
+	import flash.text.TextFormat;
+
+	public class Test extends MovieClip {
+		public var field:TextField;
+
+		public function Test() {
+			trace("//var text = new TextField();");
+			var text = new TextField();
+			trace("//text.text");
+			trace(text.text);
+			trace("//text.type");
+			trace(text.type);
+			trace("//text.autoSize");
+			trace(text.autoSize);
+			trace("//text.multiline");
+			trace(text.multiline);
+			trace("//text.wordWrap");
+			trace(text.wordWrap);
+			trace("//text.selectable");
+			trace(text.selectable);
+
+			trace("//text.text = \"Hello\";");
+			text.text = "Hello";
+			trace("//text.length");
+			trace(text.length);
+			trace("//text.appendText(\" world\");");
+			text.appendText(" world");
+			trace("//text.text");
+			trace(text.text);
+			trace("//text.replaceText(0, 5, \"Goodbye\");");
+			text.replaceText(0, 5, "Goodbye");
+			trace("//text.text");
+			trace(text.text);
+
+			trace("//text.htmlText = \"<b>Bold</b> text\";");
+			text.htmlText = "<b>Bold</b> text";
+			trace("//text.text");
+			trace(text.text);
+			trace("//text.getTextFormat(0, 4).bold");
+			trace(text.getTextFormat(0, 4).bold);
+			trace("//text.getTextFormat(5, 9).bold");
+			trace(text.getTextFormat(5, 9).bold);
+
+			trace("//var format = new TextFormat(\"Arial\", 20, 0xFF0000, false, true);");
+			var format = new TextFormat("Arial", 20, 0xFF0000, false, true);
+			trace("//format.font");
+			trace(format.font);
+			trace("//format.size");
+			trace(format.size);
+			trace("//format.color");
+			trace(format.color);
+			trace("//format.italic");
+			trace(format.italic);
+			trace("//format.underline");
+			trace(format.underline);
+			trace("//text.setTextFormat(format, 0, 4);");
+			text.setTextFormat(format, 0, 4);
+			trace("//text.getTextFormat(0, 4).size");
+			trace(text.getTextFormat(0, 4).size);
+			trace("//text.getTextFormat(0, 4).italic");
+			trace(text.getTextFormat(0, 4).italic);
+			trace("//text.getTextFormat(5, 9).size == 20");
+			trace(text.getTextFormat(5, 9).size == 20);
+
+			trace("//text.defaultTextFormat = format;");
+			text.defaultTextFormat = format;
+			trace("//text.defaultTextFormat.font");
+			trace(text.defaultTextFormat.font);
+
+			trace("//text.setSelection(1, 3);");
+			text.setSelection(1, 3);
+			trace("//text.selectionBeginIndex");
+			trace(text.selectionBeginIndex);
+			trace("//text.selectionEndIndex");
+			trace(text.selectionEndIndex);
+			trace("//text.replaceSelectedText(\"X\");");
+			text.replaceSelectedText("X");
+			trace("//text.text");
+			trace(text.text);
+
+			trace("//text.autoSize = TextFieldAutoSize.LEFT;");
+			text.autoSize = TextFieldAutoSize.LEFT;
+			trace("//text.autoSize");
+			trace(text.autoSize);
+			trace("//text.width > 0");
+			trace(text.width > 0);
+
+			trace("//text.multiline = true; text.text = \"a\\nb\\nc\\nd\";");
+			text.multiline = true;
+			text.autoSize = TextFieldAutoSize.NONE;
+			text.height = 20;
+			text.text = "a\nb\nc\nd";
+			trace("//text.maxScrollV > 1");
+			trace(text.maxScrollV > 1);
+			trace("//text.scrollV = 2;");
+			text.scrollV = 2;
+			trace("//text.scrollV");
+			trace(text.scrollV);
+
+			field = new TextField();
+			field.type = TextFieldType.INPUT;
+			field.width = 100;
+			field.height = 20;
+			trace("//field.type");
+			trace(field.type);
+			field.addEventListener(TextEvent.TEXT_INPUT, onTextInput);
+			field.addEventListener(Event.CHANGE, onChange);
+			addChild(field);
+		}
+
+		public function onTextInput(event:TextEvent) {
+			trace("//textInput");
+			trace(event.text);
+			if (event.text == "x") {
+				event.preventDefault();
+			}
+		}
+
+		public function onChange(event:Event) {
+			trace("//change");
+			trace(field.text);
+		}
+	}
+}
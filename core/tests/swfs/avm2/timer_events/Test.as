@@ -0,0 +1,45 @@
+﻿package {
+	public class Test {
+	}
+}
+
+import flash.events.TimerEvent;
+import flash.utils.Timer;
+import flash.utils.clearInterval;
+import flash.utils.setInterval;
+import flash.utils.setTimeout;
+
+trace("//var timer = new Timer(100, 3);");
+var timer = new Timer(100, 3);
+
+trace("//timer.running");
+trace(timer.running);
+
+timer.addEventListener(TimerEvent.TIMER, function (e) {
+	trace("//(timer) " + e.type + " " + timer.currentCount + " " + timer.running);
+});
+timer.addEventListener(TimerEvent.TIMER_COMPLETE, function (e) {
+	trace("//(timerComplete) " + e.type + " " + timer.currentCount + " " + timer.running);
+});
+
+trace("//timer.start();");
+timer.start();
+
+trace("//timer.running");
+trace(timer.running);
+
+var ticks = 0;
+
+trace("//var id = setInterval(function () { ... clearInterval(id); }, 60);");
+var id = setInterval(function () {
+	ticks += 1;
+	trace("//(interval) " + ticks);
+	if (ticks == 2) {
+		clearInterval(id);
+	}
+}, 60);
+
+trace("//setTimeout(function (a, b) { ... }, 30, \"a\", \"b\");");
+setTimeout(function (a, b) {
+	trace("//(timeout) " + a + " " + b);
+}, 30, "a", "b");
@@ -0,0 +1,53 @@
+﻿package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("// untyped catch");
+			try {
+				throw new Error("boom");
+			} catch (e) {
+				trace("" + e.message);
+			}
+
+			trace("// typed catches are checked in order");
+			try {
+				throw new Error("typed");
+			} catch (e:String) {
+				trace("wrong handler");
+			} catch (e:Error) {
+				trace("" + e.message);
+			}
+
+			trace("// errors propagate out of called functions");
+			try {
+				(function() {
+					throw new Error("from f");
+				})();
+			} catch (e:Error) {
+				trace("" + e.message);
+			}
+
+			trace("// rethrow to an outer handler");
+			try {
+				try {
+					throw new Error("inner");
+				} catch (e:Error) {
+					trace("" + e.message);
+					throw e;
+				}
+			} catch (e) {
+				trace("outer " + e.message);
+			}
+
+			trace("// catch scope");
+			try {
+				throw "a string";
+			} catch (e) {
+				trace(e);
+			}
+
+			trace("done");
+		}
+	}
+}
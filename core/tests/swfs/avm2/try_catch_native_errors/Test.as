@@ -0,0 +1,37 @@
+﻿package {
+	import flash.display.MovieClip;
+	import flash.utils.Timer;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("// new Timer(-1)");
+			try {
+				new Timer(-1);
+			} catch (e) {
+				trace(e.name);
+				trace(e.errorID);
+				trace(e.message);
+			}
+
+			trace("// typed catches see the error class");
+			try {
+				new Timer(-1);
+			} catch (e:String) {
+				trace("wrong handler");
+			} catch (e:Error) {
+				trace(e);
+			}
+
+			trace("// native errors propagate out of called functions");
+			try {
+				(function() {
+					new Timer(-1);
+				})();
+			} catch (e:Error) {
+				trace(e.errorID);
+			}
+
+			trace("done");
+		}
+	}
+}
@@ -0,0 +1,43 @@
+﻿package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("// throw \"x\" is caught by catch (e:String)");
+			try {
+				throw "x";
+			} catch (e:Error) {
+				trace("wrong handler");
+			} catch (e:String) {
+				trace(e);
+			}
+
+			trace("// throw 5 is caught by catch (e:int)");
+			try {
+				throw 5;
+			} catch (e:String) {
+				trace("wrong handler");
+			} catch (e:int) {
+				trace(e);
+			}
+
+			trace("// throw true is caught by catch (e:Boolean)");
+			try {
+				throw true;
+			} catch (e:Boolean) {
+				trace(e);
+			}
+
+			trace("// throw null is only caught by an untyped catch");
+			try {
+				throw null;
+			} catch (e:Object) {
+				trace("wrong handler");
+			} catch (e) {
+				trace(e);
+			}
+
+			trace("done");
+		}
+	}
+}
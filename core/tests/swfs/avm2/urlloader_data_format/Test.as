@@ -0,0 +1,51 @@
+﻿package {
+	public class Test {
+	}
+}
+
+import flash.events.Event;
+import flash.net.URLLoader;
+import flash.net.URLLoaderDataFormat;
+import flash.net.URLRequest;
+import flash.net.URLRequestHeader;
+import flash.utils.ByteArray;
+
+var request = new URLRequest("data.txt");
+request.requestHeaders = [new URLRequestHeader("X-Test", "1")];
+
+trace("//text.dataFormat = URLLoaderDataFormat.TEXT;");
+var text = new URLLoader();
+text.dataFormat = URLLoaderDataFormat.TEXT;
+text.addEventListener(Event.COMPLETE, function (e) {
+	trace("//(text complete) text.data");
+	trace(text.data);
+	trace("//text.data is String");
+	trace(text.data is String);
+});
+text.load(request);
+
+trace("//variables.dataFormat = URLLoaderDataFormat.VARIABLES;");
+var variables = new URLLoader();
+variables.dataFormat = URLLoaderDataFormat.VARIABLES;
+variables.addEventListener(Event.COMPLETE, function (e) {
+	trace("//(variables complete) variables.data.greeting");
+	trace(variables.data.greeting);
+	trace("//variables.data.name");
+	trace(variables.data.name);
+});
+variables.load(request);
+
+trace("//binary.dataFormat = URLLoaderDataFormat.BINARY;");
+var binary = new URLLoader();
+binary.dataFormat = URLLoaderDataFormat.BINARY;
+binary.addEventListener(Event.COMPLETE, function (e) {
+	trace("//(binary complete) binary.data is ByteArray");
+	trace(binary.data is ByteArray);
+	trace("//binary.data.length");
+	trace(binary.data.length);
+	trace("//binary.data.position");
+	trace(binary.data.position);
+	trace("//binary.data.readUTFBytes(8)");
+	trace(binary.data.readUTFBytes(8));
+});
+binary.load(request);
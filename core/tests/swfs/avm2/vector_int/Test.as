@@ -0,0 +1,61 @@
+﻿package {
+	public class Test {
+	}
+}
+
+trace("//var v:Vector.<int> = new Vector.<int>();");
+var v:Vector.<int> = new Vector.<int>();
+
+trace("//v.length");
+trace(v.length);
+
+trace("//v.push(1, 2, 3);");
+trace(v.push(1, 2, 3));
+
+trace("//v");
+trace(v);
+
+trace("//v[1] = \"5\";");
+v[1] = "5";
+
+trace("//v[1]");
+trace(v[1]);
+
+trace("//v.join(\"-\")");
+trace(v.join("-"));
+
+trace("//v.indexOf(3)");
+trace(v.indexOf(3));
+
+trace("//v.pop()");
+trace(v.pop());
+
+trace("//v.length = 4;");
+v.length = 4;
+
+trace("//v");
+trace(v);
+
+trace("//v.reverse()");
+trace(v.reverse());
+
+trace("//v.slice(1, 3)");
+trace(v.slice(1, 3));
+
+trace("//v.filter(function (x, i, vec) { return x > 0; })");
+trace(v.filter(function (x, i, vec) { return x > 0; }));
+
+trace("//var n:Vector.<Number> = new <Number>[1.5, 2.5];");
+var n:Vector.<Number> = new <Number>[1.5, 2.5];
+
+trace("//n[0] + n[1]");
+trace(n[0] + n[1]);
+
+trace("//v is Vector.<int>");
+trace(v is Vector.<int>);
+
+trace("//v is Vector.<Number>");
+trace(v is Vector.<Number>);
+
+trace("//n is Vector.<Number>");
+trace(n is Vector.<Number>);
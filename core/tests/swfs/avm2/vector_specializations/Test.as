@@ -0,0 +1,46 @@
+﻿package {
+	public class Test {
+	}
+}
+
+trace("//var i = new Vector.<int>();");
+var i = new Vector.<int>();
+
+trace("//var a = new Vector.<*>();");
+var a = new Vector.<*>();
+
+trace("//var s = new Vector.<String>();");
+var s = new Vector.<String>();
+
+trace("//i is Vector.<int>");
+trace(i is Vector.<int>);
+
+trace("//i is Vector.<uint>");
+trace(i is Vector.<uint>);
+
+trace("//i is Vector.<*>");
+trace(i is Vector.<*>);
+
+trace("//a is Vector.<*>");
+trace(a is Vector.<*>);
+
+trace("//a is Vector.<int>");
+trace(a is Vector.<int>);
+
+trace("//s is Vector.<String>");
+trace(s is Vector.<String>);
+
+trace("//s is Vector.<Object>");
+trace(s is Vector.<Object>);
+
+trace("//i is Object");
+trace(i is Object);
+
+trace("//i.push(1, 2, 3)");
+trace(i.push(1, 2, 3));
+
+trace("//i.join(\",\")");
+trace(i.join(","));
+
+trace("//i.length");
+trace(i.length);
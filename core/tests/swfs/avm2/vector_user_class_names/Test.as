@@ -0,0 +1,41 @@
+﻿package {
+	public class Test {
+	}
+}
+
+package fake {
+	public class Number {
+		public function Number() {
+		}
+	}
+}
+
+trace("//var v = new Vector.<fake.Number>();");
+var v = new Vector.<fake.Number>();
+
+trace("//v.length = 2;");
+v.length = 2;
+
+trace("//v[0]");
+trace(v[0]);
+
+trace("//v[1] = new fake.Number();");
+v[1] = new fake.Number();
+
+trace("//v[1]");
+trace(v[1]);
+
+trace("//var n = new Vector.<Number>();");
+var n = new Vector.<Number>();
+
+trace("//n.length = 2;");
+n.length = 2;
+
+trace("//n[0]");
+trace(n[0]);
+
+trace("//n[1] = \"1.5\";");
+n[1] = "1.5";
+
+trace("//n[1]");
+trace(n[1]);
@@ -0,0 +1,49 @@
+﻿package {
+	public class Test {
+	}
+}
+
+trace("//var x = new XML(\"<root a=\\\"1\\\"><item id=\\\"x\\\">one</item><item id=\\\"y\\\">two</item><group><item id=\\\"z\\\">three</item></group></root>\");");
+var x = new XML("<root a=\"1\"><item id=\"x\">one</item><item id=\"y\">two</item><group><item id=\"z\">three</item></group></root>");
+
+trace("//x.name()");
+trace(x.name());
+
+trace("//x.@a");
+trace(x.@a);
+
+trace("//x.item.length()");
+trace(x.item.length());
+
+trace("//x.item[1]");
+trace(x.item[1]);
+
+trace("//x.item[0].@id");
+trace(x.item[0].@id);
+
+trace("//x..item.length()");
+trace(x..item.length());
+
+trace("//x..item[2]");
+trace(x..item[2]);
+
+trace("//x.children().length()");
+trace(x.children().length());
+
+trace("//x.group.hasComplexContent()");
+trace(x.group.hasComplexContent());
+
+trace("//x.item[0].hasSimpleContent()");
+trace(x.item[0].hasSimpleContent());
+
+trace("//x.item[0].nodeKind()");
+trace(x.item[0].nodeKind());
+
+trace("//x.item[0].text()");
+trace(x.item[0].text());
+
+trace("//x.item[0].parent() === x");
+trace(x.item[0].parent() === x);
+
+trace("//x.group.toXMLString()");
+trace(x.group.toXMLString());
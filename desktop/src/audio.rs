@@ -1,12 +1,14 @@
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use generational_arena::Arena;
 use ruffle_core::backend::audio::decoders::{
-    self, AdpcmDecoder, Mp3Decoder, PcmDecoder, SeekableDecoder,
+    self, AdpcmDecoder, Mp3Decoder, PcmDecoder, SeekableDecoder, SCRIPT_SAMPLE_RATE,
 };
+use ruffle_core::backend::audio::spectrum::SPECTRUM_HISTORY_LENGTH;
 use ruffle_core::backend::audio::{
-    swf, AudioBackend, AudioStreamHandle, SoundHandle, SoundInstanceHandle,
+    swf, AudioBackend, AudioStreamHandle, SoundHandle, SoundInstanceHandle, SoundTransform,
 };
 use ruffle_core::tag_utils::SwfSlice;
+use std::collections::VecDeque;
 use std::io::Cursor;
 use std::sync::{Arc, Mutex};
 use swf::AudioCompression;
@@ -18,6 +20,7 @@ pub struct CpalAudioBackend {
     stream: Stream,
     sounds: Arena<Sound>,
     sound_instances: Arc<Mutex<Arena<SoundInstance>>>,
+    output_history: Arc<Mutex<VecDeque<[f32; 2]>>>,
 }
 
 // Because of https://github.com/RustAudio/cpal/pull/348, we have to initialize cpal on a
@@ -66,6 +69,29 @@ struct SoundInstance {
     /// If this flag is false, the sound will be cleaned up during the
     /// next loop of the sound thread.
     active: bool,
+
+    /// The volume and panning applied to this sound, if any.
+    transform: Option<SoundTransform>,
+
+    /// The sample frame that playback started from, at 44.1kHz.
+    start_sample_frame: u32,
+
+    /// Number of output sample frames that have been played.
+    position: u64,
+
+    /// The samples queued by script for a script-generated sound.
+    dynamic_buffer: Option<Arc<Mutex<DynamicSoundBuffer>>>,
+}
+
+/// The samples queued by script for a script-generated sound, as with AS3's
+/// `SampleDataEvent`.
+#[derive(Default)]
+struct DynamicSoundBuffer {
+    /// The 44.1kHz sample frames that have yet to be played.
+    samples: VecDeque<[i16; 2]>,
+
+    /// Whether the script has finished supplying samples.
+    is_final: bool,
 }
 
 impl CpalAudioBackend {
@@ -97,10 +123,12 @@ impl CpalAudioBackend {
         let config = cpal::StreamConfig::from(config);
 
         let sound_instances: Arc<Mutex<Arena<SoundInstance>>> = Arc::new(Mutex::new(Arena::new()));
+        let output_history = Arc::new(Mutex::new(VecDeque::with_capacity(SPECTRUM_HISTORY_LENGTH)));
 
         // Start the audio stream.
         let stream = {
             let sound_instances = Arc::clone(&sound_instances);
+            let output_history = Arc::clone(&output_history);
             let error_handler = move |err| log::error!("Audio stream error: {}", err);
             let output_config = config.clone();
 
@@ -110,7 +138,13 @@ impl CpalAudioBackend {
                     &config,
                     move |buffer, _| {
                         let mut sound_instances = sound_instances.lock().unwrap();
-                        Self::mix_audio::<f32>(&mut sound_instances, &output_config, buffer)
+                        let mut output_history = output_history.lock().unwrap();
+                        Self::mix_audio::<f32>(
+                            &mut sound_instances,
+                            &mut output_history,
+                            &output_config,
+                            buffer,
+                        )
                     },
                     error_handler,
                 ),
@@ -118,7 +152,13 @@ impl CpalAudioBackend {
                     &config,
                     move |buffer, _| {
                         let mut sound_instances = sound_instances.lock().unwrap();
-                        Self::mix_audio::<i16>(&mut sound_instances, &output_config, buffer)
+                        let mut output_history = output_history.lock().unwrap();
+                        Self::mix_audio::<i16>(
+                            &mut sound_instances,
+                            &mut output_history,
+                            &output_config,
+                            buffer,
+                        )
                     },
                     error_handler,
                 ),
@@ -126,7 +166,13 @@ impl CpalAudioBackend {
                     &config,
                     move |buffer, _| {
                         let mut sound_instances = sound_instances.lock().unwrap();
-                        Self::mix_audio::<u16>(&mut sound_instances, &output_config, buffer)
+                        let mut output_history = output_history.lock().unwrap();
+                        Self::mix_audio::<u16>(
+                            &mut sound_instances,
+                            &mut output_history,
+                            &output_config,
+                            buffer,
+                        )
                     },
                     error_handler,
                 ),
@@ -141,6 +187,7 @@ impl CpalAudioBackend {
             stream: Stream(stream),
             sounds: Arena::new(),
             sound_instances,
+            output_history,
         })
     }
 
@@ -261,11 +308,12 @@ impl CpalAudioBackend {
     /// and mixing in their output.
     fn mix_audio<'a, T>(
         sound_instances: &mut Arena<SoundInstance>,
+        output_history: &mut VecDeque<[f32; 2]>,
         output_format: &cpal::StreamConfig,
         mut output_buffer: &mut [T],
     ) where
         T: 'a + cpal::Sample + Default + dasp::Sample,
-        T::Signed: dasp::sample::conv::FromSample<i16>,
+        T::Signed: dasp::sample::conv::FromSample<i16> + dasp::sample::conv::ToSample<f32>,
     {
         use dasp::{
             frame::{Frame, Stereo},
@@ -281,7 +329,13 @@ impl CpalAudioBackend {
             let mut output_frame = Stereo::<T::Signed>::EQUILIBRIUM;
             for (_, sound) in sound_instances.iter_mut() {
                 if sound.active && !sound.signal.is_exhausted() {
-                    let sound_frame = sound.signal.next();
+                    let mut sound_frame = sound.signal.next();
+                    sound.position += 1;
+                    if let Some(transform) = &sound.transform {
+                        let [left, right] =
+                            transform.apply([f32::from(sound_frame[0]), f32::from(sound_frame[1])]);
+                        sound_frame = [left as i16, right as i16];
+                    }
                     let sound_frame: Stereo<T::Signed> = Frame::map(sound_frame, Sample::to_sample);
                     output_frame = output_frame.add_amp(sound_frame);
                 } else {
@@ -292,6 +346,12 @@ impl CpalAudioBackend {
             for (buf_sample, output_sample) in buf_frame.iter_mut().zip(output_frame.iter()) {
                 *buf_sample = output_sample.to_sample();
             }
+
+            // Remember recent output for `SoundMixer.computeSpectrum`.
+            if output_history.len() >= SPECTRUM_HISTORY_LENGTH {
+                output_history.pop_front();
+            }
+            output_history.push_back(Frame::map(output_frame, Sample::to_sample::<f32>));
         }
 
         // Remove all dead sounds.
@@ -347,6 +407,10 @@ impl AudioBackend for CpalAudioBackend {
             clip_id: Some(clip_id),
             signal,
             active: true,
+            transform: None,
+            start_sample_frame: 0,
+            position: 0,
+            dynamic_buffer: None,
         });
         Ok(handle)
     }
@@ -384,10 +448,97 @@ impl AudioBackend for CpalAudioBackend {
             clip_id: None,
             signal,
             active: true,
+            transform: None,
+            start_sample_frame: settings.in_sample.unwrap_or(0),
+            position: 0,
+            dynamic_buffer: None,
+        });
+        Ok(handle)
+    }
+
+    fn start_dynamic_sound(&mut self) -> Result<SoundInstanceHandle, Error> {
+        let buffer = Arc::new(Mutex::new(DynamicSoundBuffer::default()));
+        let format = swf::SoundFormat {
+            compression: AudioCompression::Uncompressed,
+            sample_rate: SCRIPT_SAMPLE_RATE as u16,
+            is_stereo: true,
+            is_16_bit: true,
+        };
+        let signal = DynamicSoundSignal(Arc::clone(&buffer));
+        let signal = Box::new(self.make_resampler(&format, signal));
+
+        let mut sound_instances = self.sound_instances.lock().unwrap();
+        let handle = sound_instances.insert(SoundInstance {
+            handle: None,
+            clip_id: None,
+            signal,
+            active: true,
+            transform: None,
+            start_sample_frame: 0,
+            position: 0,
+            dynamic_buffer: Some(buffer),
         });
         Ok(handle)
     }
 
+    fn queue_dynamic_sound_samples(
+        &mut self,
+        instance: SoundInstanceHandle,
+        samples: &[[f32; 2]],
+        is_final: bool,
+    ) {
+        let sound_instances = self.sound_instances.lock().unwrap();
+        if let Some(buffer) = sound_instances
+            .get(instance)
+            .and_then(|instance| instance.dynamic_buffer.as_ref())
+        {
+            use dasp::Sample;
+            let mut buffer = buffer.lock().unwrap();
+            buffer.samples.extend(
+                samples
+                    .iter()
+                    .map(|[left, right]| [left.to_sample::<i16>(), right.to_sample::<i16>()]),
+            );
+            buffer.is_final |= is_final;
+        }
+    }
+
+    fn dynamic_sound_buffered(&self, instance: SoundInstanceHandle) -> Option<usize> {
+        let sound_instances = self.sound_instances.lock().unwrap();
+        let instance = sound_instances.get(instance).filter(|i| i.active)?;
+        let buffer = instance.dynamic_buffer.as_ref()?;
+        let buffered = buffer.lock().unwrap().samples.len();
+        Some(buffered)
+    }
+
+    fn get_sound_samples(&mut self, sound: SoundHandle) -> Option<Vec<[f32; 2]>> {
+        let sound = self.sounds.get(sound)?;
+        match decoders::decode_to_script_samples(
+            &sound.format,
+            &sound.data,
+            sound.num_sample_frames,
+            sound.skip_sample_frames,
+        ) {
+            Ok(samples) => Some(samples),
+            Err(e) => {
+                log::error!("Unable to decode sound: {}", e);
+                None
+            }
+        }
+    }
+
+    fn get_output_samples(&self, output: &mut [[f32; 2]]) {
+        let output_history = self.output_history.lock().unwrap();
+        let skip = output_history.len().saturating_sub(output.len());
+        let start = output.len().saturating_sub(output_history.len());
+        for (frame, history) in output[start..]
+            .iter_mut()
+            .zip(output_history.iter().skip(skip))
+        {
+            *frame = *history;
+        }
+    }
+
     fn stop_sound(&mut self, sound: SoundInstanceHandle) {
         let mut sound_instances = self.sound_instances.lock().unwrap();
         sound_instances.remove(sound);
@@ -422,6 +573,26 @@ impl AudioBackend for CpalAudioBackend {
         }
     }
 
+    fn get_sound_position(&self, instance: SoundInstanceHandle) -> Option<u32> {
+        let sound_instances = self.sound_instances.lock().unwrap();
+        let instance = sound_instances.get(instance).filter(|i| i.active)?;
+
+        let start_ms = u64::from(instance.start_sample_frame) * 1000 / 44100;
+        let played_ms = instance.position * 1000 / u64::from(self.output_config.sample_rate.0);
+        Some((start_ms + played_ms) as u32)
+    }
+
+    fn set_sound_transform(&mut self, instance: SoundInstanceHandle, transform: SoundTransform) {
+        let mut sound_instances = self.sound_instances.lock().unwrap();
+        if let Some(instance) = sound_instances.get_mut(instance) {
+            instance.transform = if transform == SoundTransform::default() {
+                None
+            } else {
+                Some(transform)
+            };
+        }
+    }
+
     fn is_sound_playing_with_handle(&mut self, handle: SoundHandle) -> bool {
         let sound_instances = self.sound_instances.lock().unwrap();
         let handle = Some(handle);
@@ -545,6 +716,23 @@ impl dasp::signal::Signal for EventSoundSignal {
     }
 }
 
+/// A signal that plays the samples queued for a script-generated sound.
+struct DynamicSoundSignal(Arc<Mutex<DynamicSoundBuffer>>);
+
+impl dasp::signal::Signal for DynamicSoundSignal {
+    type Frame = [i16; 2];
+
+    fn next(&mut self) -> Self::Frame {
+        // Play silence if the script falls behind.
+        self.0.lock().unwrap().samples.pop_front().unwrap_or([0, 0])
+    }
+
+    fn is_exhausted(&self) -> bool {
+        let buffer = self.0.lock().unwrap();
+        buffer.is_final && buffer.samples.is_empty()
+    }
+}
+
 /// A signal that represents the sound envelope for an event sound.
 /// The sound signal gets multiplied by the envelope for volume/panning effects.
 struct EnvelopeSignal {
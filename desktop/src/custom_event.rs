@@ -4,4 +4,7 @@
 pub enum RuffleEvent {
     /// Indicates that one or more tasks are ready to poll on our executor.
     TaskPoll,
+
+    /// Indicates that the movie has asked the player to close.
+    Quit,
 }
@@ -28,8 +28,10 @@ use url::Url;
 
 use crate::storage::DiskStorageBackend;
 use ruffle_core::backend::log::NullLogBackend;
+use ruffle_core::system_properties::{Language, OperatingSystem, PlayerType};
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
+use std::cell::Cell;
 use std::io::Read;
 use std::rc::Rc;
 use winit::dpi::{LogicalSize, PhysicalPosition};
@@ -84,10 +86,63 @@ struct Opt {
     #[clap(long, case_insensitive = true, takes_value = false)]
     upgrade_to_https: bool,
 
+    /// The major version of Flash Player to report to movies, such as 10
+    #[clap(long, default_value = "32")]
+    player_version: u8,
+
+    /// The type of player to report to movies.
+    /// Can be "StandAlone", "External", "PlugIn" or "ActiveX".
+    /// Only the standalone player handles FSCommands such as "quit" itself.
+    #[clap(long, default_value = "StandAlone")]
+    player_type: PlayerType,
+
+    /// The operating system to report to movies, such as "Windows XP", "Linux" or "MacOS".
+    /// Defaults to the current operating system.
+    #[clap(long)]
+    os: Option<OperatingSystem>,
+
+    /// The language code to report to movies, such as "en" or "zh-CN"
+    #[clap(long, default_value = "en")]
+    language: Language,
+
+    /// The screen resolution to report to movies, such as "1920x1080".
+    /// Defaults to the resolution of the current monitor.
+    #[clap(long, parse(try_from_str = parse_screen_resolution))]
+    screen_resolution: Option<(u32, u32)>,
+
+    /// Allow movies to launch applications from the "fscommand" directory next to the movie,
+    /// using the "exec" FSCommand.
+    #[clap(long, case_insensitive = true, takes_value = false)]
+    allow_fscommand_exec: bool,
+
     #[clap(long, case_insensitive = true, takes_value = false)]
     timedemo: bool,
 }
 
+fn parse_screen_resolution(value: &str) -> Result<(u32, u32), &'static str> {
+    let mut split = value.splitn(2, 'x');
+    match (split.next(), split.next()) {
+        (Some(width), Some(height)) => width
+            .trim()
+            .parse()
+            .ok()
+            .zip(height.trim().parse().ok())
+            .ok_or("Screen resolution must be given as WIDTHxHEIGHT"),
+        _ => Err("Screen resolution must be given as WIDTHxHEIGHT"),
+    }
+}
+
+/// The operating system that Ruffle was built for, as reported to movies.
+fn current_os() -> OperatingSystem {
+    if cfg!(target_os = "windows") {
+        OperatingSystem::WindowsUnknown
+    } else if cfg!(target_os = "macos") {
+        OperatingSystem::MacOS
+    } else {
+        OperatingSystem::Linux
+    }
+}
+
 #[cfg(feature = "render_trace")]
 fn trace_path(opt: &Opt) -> Option<&Path> {
     if let Some(path) = &opt.trace_path {
@@ -227,7 +282,21 @@ fn run_player(opt: Opt) -> Result<(), Box<dyn std::error::Error>> {
     )); //TODO: actually implement this backend type
     let input = Box::new(input::WinitInputBackend::new(window.clone()));
     let storage = Box::new(DiskStorageBackend::new());
-    let user_interface = Box::new(ui::DesktopUiBackend::new(window.clone()));
+    let exec_directory = if opt.allow_fscommand_exec {
+        movie_url
+            .to_file_path()
+            .ok()
+            .and_then(|path| Some(path.parent()?.join("fscommand")))
+    } else {
+        None
+    };
+    let trap_all_keys = Rc::new(Cell::new(false));
+    let user_interface = Box::new(ui::DesktopUiBackend::new(
+        window.clone(),
+        event_loop.create_proxy(),
+        exec_directory,
+        trap_all_keys.clone(),
+    ));
     let locale = Box::new(locale::DesktopLocaleBackend::new());
     let player = Player::new(
         renderer,
@@ -241,10 +310,27 @@ fn run_player(opt: Opt) -> Result<(), Box<dyn std::error::Error>> {
     )?;
     {
         let mut player = player.lock().unwrap();
+        player.set_player_version(opt.player_version);
+        let system = player.system_properties_mut();
+        let os = opt.os.unwrap_or_else(current_os);
+        system.manufacturer = os.manufacturer();
+        system.os = os;
+        system.player_type = opt.player_type;
+        system.language = opt.language;
+        if let Some(screen_resolution) = opt.screen_resolution.or_else(|| {
+            let size = window.current_monitor()?.size();
+            Some((size.width, size.height))
+        }) {
+            system.screen_resolution = screen_resolution;
+        }
         player.set_root_movie(Arc::new(movie));
         player.set_is_playing(true); // Desktop player will auto-play.
         player.set_letterbox(Letterbox::On);
-        player.set_viewport_dimensions(viewport_size.width, viewport_size.height);
+        player.set_viewport_dimensions(
+            viewport_size.width,
+            viewport_size.height,
+            window.scale_factor(),
+        );
     }
 
     let mut mouse_pos = PhysicalPosition::new(0.0, 0.0);
@@ -292,7 +378,11 @@ fn run_player(opt: Opt) -> Result<(), Box<dyn std::error::Error>> {
                         minimized = size.width == 0 && size.height == 0;
 
                         let mut player_lock = player.lock().unwrap();
-                        player_lock.set_viewport_dimensions(size.width, size.height);
+                        player_lock.set_viewport_dimensions(
+                            size.width,
+                            size.height,
+                            window.scale_factor(),
+                        );
                         player_lock
                             .renderer_mut()
                             .set_viewport_dimensions(size.width, size.height);
@@ -362,7 +452,7 @@ fn run_player(opt: Opt) -> Result<(), Box<dyn std::error::Error>> {
                                 ..
                             },
                         ..
-                    } if modifiers.alt() => {
+                    } if modifiers.alt() && !trap_all_keys.get() => {
                         if !fullscreen_down {
                             window.set_fullscreen(match window.fullscreen() {
                                 None => Some(Fullscreen::Borderless(None)),
@@ -390,7 +480,7 @@ fn run_player(opt: Opt) -> Result<(), Box<dyn std::error::Error>> {
                                 ..
                             },
                         ..
-                    } => {
+                    } if !trap_all_keys.get() => {
                         window.set_fullscreen(None);
                     }
                     WindowEvent::KeyboardInput { .. } | WindowEvent::ReceivedCharacter(_) => {
@@ -413,6 +503,9 @@ fn run_player(opt: Opt) -> Result<(), Box<dyn std::error::Error>> {
                     .lock()
                     .expect("active executor reference")
                     .poll_all(),
+                winit::event::Event::UserEvent(RuffleEvent::Quit) => {
+                    *control_flow = ControlFlow::Exit
+                }
                 _ => (),
             }
 
@@ -474,7 +567,7 @@ fn run_timedemo(opt: Opt) -> Result<(), Box<dyn std::error::Error>> {
     player
         .lock()
         .unwrap()
-        .set_viewport_dimensions(viewport_width, viewport_height);
+        .set_viewport_dimensions(viewport_width, viewport_height, 1.0);
 
     println!("Running {}...", opt.input_path.unwrap().to_string_lossy(),);
 
@@ -1,14 +1,19 @@
 //! Navigator backend for web
 
 use crate::custom_event::RuffleEvent;
-use isahc::{config::RedirectPolicy, prelude::*, AsyncReadResponseExt, HttpClient, Request};
+use futures_lite::AsyncReadExt;
+use isahc::{
+    config::RedirectPolicy, prelude::*, AsyncBody, AsyncReadResponseExt, HttpClient, Request,
+    Response,
+};
 use ruffle_core::backend::navigator::{
-    NavigationMethod, NavigatorBackend, OwnedFuture, RequestOptions,
+    ChunkFuture, NavigationMethod, NavigatorBackend, OwnedFuture, RequestOptions, ResponseStream,
 };
 use ruffle_core::indexmap::IndexMap;
 use ruffle_core::loader::Error;
 use std::borrow::Cow;
-use std::fs;
+use std::fs::{self, File};
+use std::io::Read;
 use std::rc::Rc;
 use std::sync::mpsc::Sender;
 use std::time::{Duration, Instant};
@@ -62,6 +67,100 @@ impl ExternalNavigatorBackend {
             upgrade_to_https,
         }
     }
+
+    /// Resolve a fetched URL against the movie URL.
+    fn fetch_url(&self, url: &str) -> Result<Url, Error> {
+        // TODO: honor sandbox type (local-with-filesystem, local-with-network, remote, ...)
+        match self.movie_url.clone().join(url) {
+            Ok(url) => Ok(self.pre_process_url(url)),
+            Err(e) => Err(Error::FetchError(format!("Invalid URL {}: {}", url, e))),
+        }
+    }
+}
+
+/// The size of the chunks in which streamed responses are read.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Send an HTTP request, yielding the response once its headers arrive.
+async fn send_request(
+    client: Option<Rc<HttpClient>>,
+    url: Url,
+    options: RequestOptions,
+) -> Result<Response<AsyncBody>, Error> {
+    let client = client.ok_or(Error::NetworkUnavailable)?;
+
+    let mut request = match options.method() {
+        NavigationMethod::GET => Request::get(url.to_string()),
+        NavigationMethod::POST => Request::post(url.to_string()),
+    };
+
+    for (name, value) in options.headers() {
+        request = request.header(name.as_str(), value.as_str());
+    }
+
+    let (body_data, _) = options.body().clone().unwrap_or_default();
+    let body = request
+        .body(body_data)
+        .map_err(|e| Error::FetchError(e.to_string()))?;
+
+    client
+        .send_async(body)
+        .await
+        .map_err(|e| Error::FetchError(e.to_string()))
+}
+
+/// A `ResponseStream` that reads a local file.
+struct FileResponseStream {
+    file: File,
+    length: Option<usize>,
+}
+
+impl ResponseStream for FileResponseStream {
+    fn total_length(&self) -> Option<usize> {
+        self.length
+    }
+
+    fn next_chunk(&mut self) -> ChunkFuture<'_> {
+        Box::pin(async move {
+            let mut chunk = vec![0; CHUNK_SIZE];
+            let read = self.file.read(&mut chunk).map_err(Error::NetworkError)?;
+            if read == 0 {
+                return Ok(None);
+            }
+
+            chunk.truncate(read);
+            Ok(Some(chunk))
+        })
+    }
+}
+
+/// A `ResponseStream` that reads an HTTP response body as it arrives.
+struct HttpResponseStream {
+    body: AsyncBody,
+    length: Option<usize>,
+}
+
+impl ResponseStream for HttpResponseStream {
+    fn total_length(&self) -> Option<usize> {
+        self.length
+    }
+
+    fn next_chunk(&mut self) -> ChunkFuture<'_> {
+        Box::pin(async move {
+            let mut chunk = vec![0; CHUNK_SIZE];
+            let read = self
+                .body
+                .read(&mut chunk)
+                .await
+                .map_err(|e| Error::FetchError(e.to_string()))?;
+            if read == 0 {
+                return Ok(None);
+            }
+
+            chunk.truncate(read);
+            Ok(Some(chunk))
+        })
+    }
 }
 
 impl NavigatorBackend for ExternalNavigatorBackend {
@@ -114,17 +213,11 @@ impl NavigatorBackend for ExternalNavigatorBackend {
     fn run_script(&self, _js_code: &str) {}
 
     fn fetch(&self, url: &str, options: RequestOptions) -> OwnedFuture<Vec<u8>, Error> {
-        // TODO: honor sandbox type (local-with-filesystem, local-with-network, remote, ...)
-        let full_url = match self.movie_url.clone().join(url) {
+        let processed_url = match self.fetch_url(url) {
             Ok(url) => url,
-            Err(e) => {
-                let msg = format!("Invalid URL {}: {}", url, e);
-                return Box::pin(async move { Err(Error::FetchError(msg)) });
-            }
+            Err(e) => return Box::pin(async move { Err(e) }),
         };
 
-        let processed_url = self.pre_process_url(full_url);
-
         let client = self.client.clone();
 
         match processed_url.scheme() {
@@ -132,22 +225,7 @@ impl NavigatorBackend for ExternalNavigatorBackend {
                 fs::read(processed_url.to_file_path().unwrap()).map_err(Error::NetworkError)
             }),
             _ => Box::pin(async move {
-                let client = client.ok_or(Error::NetworkUnavailable)?;
-
-                let request = match options.method() {
-                    NavigationMethod::GET => Request::get(processed_url.to_string()),
-                    NavigationMethod::POST => Request::post(processed_url.to_string()),
-                };
-
-                let (body_data, _) = options.body().clone().unwrap_or_default();
-                let body = request
-                    .body(body_data)
-                    .map_err(|e| Error::FetchError(e.to_string()))?;
-
-                let mut response = client
-                    .send_async(body)
-                    .await
-                    .map_err(|e| Error::FetchError(e.to_string()))?;
+                let mut response = send_request(client, processed_url, options).await?;
 
                 let mut buffer = vec![];
                 response
@@ -159,6 +237,42 @@ impl NavigatorBackend for ExternalNavigatorBackend {
         }
     }
 
+    fn fetch_stream(
+        &self,
+        url: &str,
+        options: RequestOptions,
+    ) -> OwnedFuture<Box<dyn ResponseStream>, Error> {
+        let processed_url = match self.fetch_url(url) {
+            Ok(url) => url,
+            Err(e) => return Box::pin(async move { Err(e) }),
+        };
+
+        let client = self.client.clone();
+
+        match processed_url.scheme() {
+            "file" => Box::pin(async move {
+                let file = File::open(processed_url.to_file_path().unwrap())
+                    .map_err(Error::NetworkError)?;
+                let length = file.metadata().ok().map(|metadata| metadata.len() as usize);
+                let stream: Box<dyn ResponseStream> = Box::new(FileResponseStream { file, length });
+                Ok(stream)
+            }),
+            _ => Box::pin(async move {
+                let response = send_request(client, processed_url, options).await?;
+                let length = response
+                    .headers()
+                    .get("Content-Length")
+                    .and_then(|length| length.to_str().ok())
+                    .and_then(|length| length.parse().ok());
+                let stream: Box<dyn ResponseStream> = Box::new(HttpResponseStream {
+                    body: response.into_body(),
+                    length,
+                });
+                Ok(stream)
+            }),
+        }
+    }
+
     fn time_since_launch(&mut self) -> Duration {
         Instant::now().duration_since(self.start_time)
     }
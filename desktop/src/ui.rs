@@ -1,15 +1,39 @@
-use ruffle_core::backend::ui::UiBackend;
+use crate::custom_event::RuffleEvent;
+use ruffle_core::backend::ui::{Error, UiBackend};
+use std::cell::Cell;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
 use std::rc::Rc;
 use tinyfiledialogs::{message_box_ok, MessageBoxIcon};
-use winit::window::Window;
+use winit::event_loop::EventLoopProxy;
+use winit::window::{Fullscreen, Window};
 
 pub struct DesktopUiBackend {
     window: Rc<Window>,
+    event_loop: EventLoopProxy<RuffleEvent>,
+
+    /// The directory that the `exec` FSCommand may launch applications from,
+    /// or `None` if launching applications is disabled.
+    exec_directory: Option<PathBuf>,
+
+    /// Whether all key presses should be sent to the movie, as set by the
+    /// `trapallkeys` FSCommand. This is shared with the event loop.
+    trap_all_keys: Rc<Cell<bool>>,
 }
 
 impl DesktopUiBackend {
-    pub fn new(window: Rc<Window>) -> Self {
-        Self { window }
+    pub fn new(
+        window: Rc<Window>,
+        event_loop: EventLoopProxy<RuffleEvent>,
+        exec_directory: Option<PathBuf>,
+        trap_all_keys: Rc<Cell<bool>>,
+    ) -> Self {
+        Self {
+            window,
+            event_loop,
+            exec_directory,
+            trap_all_keys,
+        }
     }
 }
 
@@ -18,7 +42,44 @@ impl UiBackend for DesktopUiBackend {
         self.window.fullscreen().is_some()
     }
 
+    fn set_fullscreen(&mut self, is_full: bool) -> Result<(), Error> {
+        self.window.set_fullscreen(if is_full {
+            Some(Fullscreen::Borderless(None))
+        } else {
+            None
+        });
+        Ok(())
+    }
+
     fn message(&self, message: &str) {
         message_box_ok("Ruffle", message, MessageBoxIcon::Info)
     }
+
+    fn quit(&mut self) {
+        let _ = self.event_loop.send_event(RuffleEvent::Quit);
+    }
+
+    fn exec(&mut self, command: &str) -> Result<(), Error> {
+        let directory = self
+            .exec_directory
+            .as_ref()
+            .ok_or("Exec is disabled; run with --allow-fscommand-exec to enable it")?;
+
+        // Like the Flash Projector, only applications directly inside the
+        // `fscommand` directory may be launched.
+        let mut components = Path::new(command).components();
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(name)), None) => {
+                Command::new(directory.join(name))
+                    .current_dir(directory)
+                    .spawn()?;
+                Ok(())
+            }
+            _ => Err("Exec may only launch applications in the fscommand directory".into()),
+        }
+    }
+
+    fn set_trap_all_keys(&mut self, trap_all_keys: bool) {
+        self.trap_all_keys.set(trap_all_keys);
+    }
 }
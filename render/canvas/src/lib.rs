@@ -1,6 +1,6 @@
 use ruffle_core::backend::render::{
     swf::{self, CharacterId, GradientInterpolation, GradientSpread},
-    Bitmap, BitmapFormat, BitmapHandle, BitmapInfo, Color, JpegTagFormat, MovieLibrary,
+    Bitmap, BitmapFormat, BitmapHandle, BitmapInfo, BitmapSource, Color, JpegTagFormat,
     RenderBackend, ShapeHandle, Transform,
 };
 use ruffle_core::color_transform::ColorTransform;
@@ -422,19 +422,24 @@ impl RenderBackend for WebCanvasRenderBackend {
     fn register_shape(
         &mut self,
         shape: DistilledShape,
-        library: Option<&MovieLibrary<'_>>,
+        bitmap_source: Option<&dyn BitmapSource>,
     ) -> ShapeHandle {
         let handle = ShapeHandle(self.shapes.len());
 
         let data = swf_shape_to_canvas_commands(
             &shape,
-            library,
+            bitmap_source,
             &self.bitmaps,
             self.pixelated_property_value,
             &self.context,
         )
         .unwrap_or_else(|| {
-            swf_shape_to_svg(shape, library, &self.bitmaps, self.pixelated_property_value)
+            swf_shape_to_svg(
+                shape,
+                bitmap_source,
+                &self.bitmaps,
+                self.pixelated_property_value,
+            )
         });
 
         self.shapes.push(data);
@@ -445,18 +450,23 @@ impl RenderBackend for WebCanvasRenderBackend {
     fn replace_shape(
         &mut self,
         shape: DistilledShape,
-        library: Option<&MovieLibrary<'_>>,
+        bitmap_source: Option<&dyn BitmapSource>,
         handle: ShapeHandle,
     ) {
         let data = swf_shape_to_canvas_commands(
             &shape,
-            library,
+            bitmap_source,
             &self.bitmaps,
             self.pixelated_property_value,
             &self.context,
         )
         .unwrap_or_else(|| {
-            swf_shape_to_svg(shape, library, &self.bitmaps, self.pixelated_property_value)
+            swf_shape_to_svg(
+                shape,
+                bitmap_source,
+                &self.bitmaps,
+                self.pixelated_property_value,
+            )
         });
         self.shapes[handle.0] = data;
     }
@@ -695,6 +705,40 @@ impl RenderBackend for WebCanvasRenderBackend {
             .unwrap();
     }
 
+    fn render_offscreen(
+        &mut self,
+        width: u32,
+        height: u32,
+        commands: &mut dyn FnMut(&mut dyn RenderBackend),
+    ) -> Result<Bitmap, Error> {
+        // Render into a fresh render target the size of the output.
+        let viewport = (self.viewport_width, self.viewport_height);
+        self.viewport_width = width;
+        self.viewport_height = height;
+        self.push_render_target();
+        self.viewport_width = viewport.0;
+        self.viewport_height = viewport.1;
+
+        let deactivating_mask = self.deactivating_mask;
+        self.deactivating_mask = false;
+        commands(self);
+        self.deactivating_mask = deactivating_mask;
+
+        let (_canvas, context) = self.pop_render_target();
+        context.reset_transform().warn_on_error();
+        let image_data = context
+            .get_image_data(0.0, 0.0, width.into(), height.into())
+            .into_js_result()?;
+        let mut data = image_data.data().to_vec();
+        ruffle_core::backend::render::premultiply_alpha_rgba(&mut data[..]);
+
+        Ok(Bitmap {
+            width,
+            height,
+            data: BitmapFormat::Rgba(data),
+        })
+    }
+
     fn get_bitmap_pixels(&mut self, bitmap: BitmapHandle) -> Option<Bitmap> {
         let window = web_sys::window().unwrap();
         let document = window.document().unwrap();
@@ -782,7 +826,7 @@ impl RenderBackend for WebCanvasRenderBackend {
 #[allow(clippy::cognitive_complexity)]
 fn swf_shape_to_svg(
     shape: DistilledShape,
-    library: Option<&MovieLibrary<'_>>,
+    bitmap_source: Option<&dyn BitmapSource>,
     bitmaps: &[BitmapData],
     pixelated_property_value: &str,
 ) -> ShapeData {
@@ -1038,9 +1082,9 @@ fn swf_shape_to_svg(
                         is_smoothed,
                         is_repeating,
                     } => {
-                        if let Some(bitmap) = library
-                            .and_then(|lib| lib.get_bitmap(*id))
-                            .and_then(|bitmap| bitmaps.get(bitmap.bitmap_handle().0))
+                        if let Some(bitmap) = bitmap_source
+                            .and_then(|bitmap_source| bitmap_source.bitmap_handle(*id))
+                            .and_then(|handle| bitmaps.get(handle.0))
                         {
                             if !bitmap_defs.contains(&id) {
                                 let mut image = Image::new()
@@ -1263,7 +1307,7 @@ fn draw_commands_to_path2d(commands: &[DrawCommand], is_closed: bool) -> Path2d
 
 fn swf_shape_to_canvas_commands(
     shape: &DistilledShape,
-    library: Option<&MovieLibrary<'_>>,
+    bitmap_source: Option<&dyn BitmapSource>,
     bitmaps: &[BitmapData],
     _pixelated_property_value: &str,
     context: &CanvasRenderingContext2d,
@@ -1320,9 +1364,9 @@ fn swf_shape_to_canvas_commands(
                         is_smoothed,
                         is_repeating,
                     } => {
-                        if let Some(bitmap) = library
-                            .and_then(|lib| lib.get_bitmap(*id))
-                            .and_then(|bitmap| bitmaps.get(bitmap.bitmap_handle().0))
+                        if let Some(bitmap) = bitmap_source
+                            .and_then(|bitmap_source| bitmap_source.bitmap_handle(*id))
+                            .and_then(|handle| bitmaps.get(handle.0))
                         {
                             let image = HtmlImageElement::new_with_width_and_height(
                                 bitmap.width,
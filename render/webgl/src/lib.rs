@@ -1,6 +1,6 @@
 use ruffle_core::backend::render::swf;
 use ruffle_core::backend::render::{
-    srgb_to_linear, Bitmap, BitmapFormat, BitmapHandle, BitmapInfo, Color, MovieLibrary,
+    srgb_to_linear, Bitmap, BitmapFormat, BitmapHandle, BitmapInfo, BitmapSource, Color,
     RenderBackend, ShapeHandle, Transform,
 };
 use ruffle_core::shape_utils::DistilledShape;
@@ -182,7 +182,14 @@ impl WebGlRenderBackend {
         let gradient_program = ShaderProgram::new(&gl, &texture_vertex, &gradient_fragment)?;
 
         gl.enable(Gl::BLEND);
-        gl.blend_func(Gl::SRC_ALPHA, Gl::ONE_MINUS_SRC_ALPHA);
+        // Alpha is always blended with `ONE` so that offscreen targets end up
+        // with premultiplied alpha.
+        gl.blend_func_separate(
+            Gl::SRC_ALPHA,
+            Gl::ONE_MINUS_SRC_ALPHA,
+            Gl::ONE,
+            Gl::ONE_MINUS_SRC_ALPHA,
+        );
 
         // Necessary to load RGB textures (alignment defaults to 4).
         gl.pixel_storei(Gl::UNPACK_ALIGNMENT, 1);
@@ -467,18 +474,15 @@ impl WebGlRenderBackend {
     fn register_shape_internal(
         &mut self,
         shape: DistilledShape,
-        library: Option<&MovieLibrary<'_>>,
+        bitmap_source: Option<&dyn BitmapSource>,
     ) -> Mesh {
         use ruffle_render_common_tess::DrawType as TessDrawType;
 
         let textures = &self.textures;
         let lyon_mesh = self.shape_tessellator.tessellate_shape(shape, |id| {
-            library
-                .and_then(|lib| lib.get_bitmap(id))
-                .and_then(|bitmap| {
-                    let handle = bitmap.bitmap_handle();
-                    textures.get(handle.0).map(|texture| (texture, handle))
-                })
+            bitmap_source
+                .and_then(|bitmap_source| bitmap_source.bitmap_handle(id))
+                .and_then(|handle| textures.get(handle.0).map(|texture| (texture, handle)))
                 .map(|(texture, handle)| (texture.width, texture.height, handle))
         });
 
@@ -778,10 +782,10 @@ impl RenderBackend for WebGlRenderBackend {
     fn register_shape(
         &mut self,
         shape: DistilledShape,
-        library: Option<&MovieLibrary<'_>>,
+        bitmap_source: Option<&dyn BitmapSource>,
     ) -> ShapeHandle {
         let handle = ShapeHandle(self.meshes.len());
-        let mesh = self.register_shape_internal(shape, library);
+        let mesh = self.register_shape_internal(shape, bitmap_source);
         self.meshes.push(mesh);
         handle
     }
@@ -789,10 +793,10 @@ impl RenderBackend for WebGlRenderBackend {
     fn replace_shape(
         &mut self,
         shape: DistilledShape,
-        library: Option<&MovieLibrary<'_>>,
+        bitmap_source: Option<&dyn BitmapSource>,
         handle: ShapeHandle,
     ) {
-        let mesh = self.register_shape_internal(shape, library);
+        let mesh = self.register_shape_internal(shape, bitmap_source);
         self.meshes[handle.0] = mesh;
     }
 
@@ -1020,7 +1024,12 @@ impl RenderBackend for WebGlRenderBackend {
                 self.add_color = None;
 
                 if (src_blend, dst_blend) != self.blend_func {
-                    self.gl.blend_func(src_blend, dst_blend);
+                    self.gl.blend_func_separate(
+                        src_blend,
+                        dst_blend,
+                        Gl::ONE,
+                        Gl::ONE_MINUS_SRC_ALPHA,
+                    );
                     self.blend_func = (src_blend, dst_blend);
                 }
             }
@@ -1122,7 +1131,12 @@ impl RenderBackend for WebGlRenderBackend {
                 self.add_color = None;
 
                 if (src_blend, dst_blend) != self.blend_func {
-                    self.gl.blend_func(src_blend, dst_blend);
+                    self.gl.blend_func_separate(
+                        src_blend,
+                        dst_blend,
+                        Gl::ONE,
+                        Gl::ONE_MINUS_SRC_ALPHA,
+                    );
                     self.blend_func = (src_blend, dst_blend);
                 }
             }
@@ -1268,7 +1282,8 @@ impl RenderBackend for WebGlRenderBackend {
             self.add_color = None;
 
             if (src_blend, dst_blend) != self.blend_func {
-                self.gl.blend_func(src_blend, dst_blend);
+                self.gl
+                    .blend_func_separate(src_blend, dst_blend, Gl::ONE, Gl::ONE_MINUS_SRC_ALPHA);
                 self.blend_func = (src_blend, dst_blend);
             }
         };
@@ -1329,6 +1344,133 @@ impl RenderBackend for WebGlRenderBackend {
         self.mask_state_dirty = true;
     }
 
+    fn render_offscreen(
+        &mut self,
+        width: u32,
+        height: u32,
+        commands: &mut dyn FnMut(&mut dyn RenderBackend),
+    ) -> Result<Bitmap, Error> {
+        let gl = &self.gl;
+        let (width, height) = (width as i32, height as i32);
+
+        // Create an RGBA target with a stencil buffer for masks.
+        let framebuffer = gl
+            .create_framebuffer()
+            .ok_or("Unable to create framebuffer")?;
+        let texture = gl.create_texture().ok_or("Unable to create texture")?;
+        let stencil_renderbuffer = gl
+            .create_renderbuffer()
+            .ok_or("Unable to create renderbuffer")?;
+
+        gl.bind_texture(Gl::TEXTURE_2D, Some(&texture));
+        gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+            Gl::TEXTURE_2D,
+            0,
+            Gl::RGBA as i32,
+            width,
+            height,
+            0,
+            Gl::RGBA,
+            Gl::UNSIGNED_BYTE,
+            None,
+        )
+        .into_js_result()?;
+        gl.bind_texture(Gl::TEXTURE_2D, None);
+
+        gl.bind_renderbuffer(Gl::RENDERBUFFER, Some(&stencil_renderbuffer));
+        gl.renderbuffer_storage(Gl::RENDERBUFFER, Gl::STENCIL_INDEX8, width, height);
+        gl.check_error("renderbuffer_storage (stencil)")?;
+        gl.bind_renderbuffer(Gl::RENDERBUFFER, None);
+
+        gl.bind_framebuffer(Gl::FRAMEBUFFER, Some(&framebuffer));
+        gl.framebuffer_texture_2d(
+            Gl::FRAMEBUFFER,
+            Gl::COLOR_ATTACHMENT0,
+            Gl::TEXTURE_2D,
+            Some(&texture),
+            0,
+        );
+        gl.framebuffer_renderbuffer(
+            Gl::FRAMEBUFFER,
+            Gl::STENCIL_ATTACHMENT,
+            Gl::RENDERBUFFER,
+            Some(&stencil_renderbuffer),
+        );
+
+        let result: Result<Bitmap, Error> =
+            if gl.check_framebuffer_status(Gl::FRAMEBUFFER) == Gl::FRAMEBUFFER_COMPLETE {
+                // Swap in the state for the offscreen target, and restore the
+                // state for the stage afterwards.
+                let view_matrix = self.view_matrix;
+                let mask_state = self.mask_state;
+                let num_masks = self.num_masks;
+
+                // The rows are flipped so that `read_pixels`, which starts from
+                // the bottom of the target, returns them from the top.
+                self.view_matrix = [
+                    [2.0 / width as f32, 0.0, 0.0, 0.0],
+                    [0.0, 2.0 / height as f32, 0.0, 0.0],
+                    [0.0, 0.0, 1.0, 0.0],
+                    [-1.0, -1.0, 0.0, 1.0],
+                ];
+                self.mask_state = MaskState::NoMask;
+                self.num_masks = 0;
+                self.mask_state_dirty = true;
+                self.active_program = std::ptr::null();
+
+                self.gl.viewport(0, 0, width, height);
+                self.set_stencil_state();
+                self.gl.clear_color(0.0, 0.0, 0.0, 0.0);
+                self.gl.stencil_mask(0xff);
+                self.gl.clear(Gl::COLOR_BUFFER_BIT | Gl::STENCIL_BUFFER_BIT);
+
+                commands(self);
+
+                let mut rgba = vec![0; (width * height * 4) as usize];
+                let result = self
+                    .gl
+                    .read_pixels_with_opt_u8_array(
+                        0,
+                        0,
+                        width,
+                        height,
+                        Gl::RGBA,
+                        Gl::UNSIGNED_BYTE,
+                        Some(&mut rgba),
+                    )
+                    .into_js_result()
+                    .map_err(|error| error.into())
+                    .map(|_| Bitmap {
+                        width: width as u32,
+                        height: height as u32,
+                        data: BitmapFormat::Rgba(rgba),
+                    });
+
+                self.view_matrix = view_matrix;
+                self.mask_state = mask_state;
+                self.num_masks = num_masks;
+                self.mask_state_dirty = true;
+                self.active_program = std::ptr::null();
+                result
+            } else {
+                Err("Offscreen framebuffer is incomplete".into())
+            };
+
+        let gl = &self.gl;
+        let stage_framebuffer = self
+            .msaa_buffers
+            .as_ref()
+            .map(|msaa_buffers| &msaa_buffers.render_framebuffer);
+        gl.bind_framebuffer(Gl::FRAMEBUFFER, stage_framebuffer);
+        gl.viewport(0, 0, self.renderbuffer_width, self.renderbuffer_height);
+        gl.delete_framebuffer(Some(&framebuffer));
+        gl.delete_renderbuffer(Some(&stencil_renderbuffer));
+        gl.delete_texture(Some(&texture));
+        self.set_stencil_state();
+
+        result
+    }
+
     fn get_bitmap_pixels(&mut self, bitmap: BitmapHandle) -> Option<Bitmap> {
         self.bitmap_registry.get(&bitmap).cloned()
     }
@@ -1377,6 +1519,15 @@ impl RenderBackend for WebGlRenderBackend {
             )
             .into_js_result()?;
 
+        self.bitmap_registry.insert(
+            handle,
+            Bitmap {
+                width,
+                height,
+                data: BitmapFormat::Rgba(rgba),
+            },
+        );
+
         Ok(handle)
     }
 }
@@ -5,7 +5,7 @@ use lyon::tessellation::{
 };
 use ruffle_core::backend::render::swf::{self, FillStyle};
 use ruffle_core::backend::render::{
-    srgb_to_linear, Bitmap, BitmapFormat, BitmapHandle, BitmapInfo, Color, MovieLibrary,
+    srgb_to_linear, Bitmap, BitmapFormat, BitmapHandle, BitmapInfo, BitmapSource, Color,
     RenderBackend, ShapeHandle, Transform,
 };
 use ruffle_core::shape_utils::{DistilledShape, DrawPath};
@@ -87,7 +87,7 @@ pub struct WgpuRenderBackend<T: RenderTarget> {
     target: T,
     frame_buffer_view: wgpu::TextureView,
     depth_texture_view: wgpu::TextureView,
-    current_frame: Option<Frame<'static, T>>,
+    current_frame: Option<Frame<'static>>,
     meshes: Vec<Mesh>,
     viewport_width: f32,
     viewport_height: f32,
@@ -101,8 +101,8 @@ pub struct WgpuRenderBackend<T: RenderTarget> {
 }
 
 #[allow(dead_code)]
-struct Frame<'a, T: RenderTarget> {
-    frame_data: Box<(wgpu::CommandEncoder, T::Frame)>,
+struct Frame<'a> {
+    frame_data: Box<(wgpu::CommandEncoder, Box<dyn RenderTargetFrame>)>,
 
     // TODO: This is a self-reference to the above, so we
     // use some unsafe to cast the lifetime away. We know this
@@ -113,10 +113,10 @@ struct Frame<'a, T: RenderTarget> {
     render_pass: wgpu::RenderPass<'a>,
 }
 
-impl<'a, T: RenderTarget> Frame<'static, T> {
+impl<'a> Frame<'static> {
     // Get a reference to the render pass with the proper lifetime.
-    fn get(&mut self) -> &mut Frame<'a, T> {
-        unsafe { std::mem::transmute::<_, &mut Frame<'a, T>>(self) }
+    fn get(&mut self) -> &mut Frame<'a> {
+        unsafe { std::mem::transmute::<_, &mut Frame<'a>>(self) }
     }
 }
 
@@ -242,30 +242,8 @@ impl<T: RenderTarget> WgpuRenderBackend<T> {
             depth: 1,
         };
 
-        let frame_buffer_label = create_debug_label!("Framebuffer texture");
-        let frame_buffer = descriptors.device.create_texture(&wgpu::TextureDescriptor {
-            label: frame_buffer_label.as_deref(),
-            size: extent,
-            mip_level_count: 1,
-            sample_count: descriptors.msaa_sample_count,
-            dimension: wgpu::TextureDimension::D2,
-            format: target.format(),
-            usage: wgpu::TextureUsage::RENDER_ATTACHMENT,
-        });
-        let frame_buffer_view = frame_buffer.create_view(&Default::default());
-
-        let depth_label = create_debug_label!("Depth texture");
-        let depth_texture = descriptors.device.create_texture(&wgpu::TextureDescriptor {
-            label: depth_label.as_deref(),
-            size: extent,
-            mip_level_count: 1,
-            sample_count: descriptors.msaa_sample_count,
-            dimension: wgpu::TextureDimension::D2,
-            format: wgpu::TextureFormat::Depth24PlusStencil8,
-            usage: wgpu::TextureUsage::RENDER_ATTACHMENT,
-        });
-
-        let depth_texture_view = depth_texture.create_view(&Default::default());
+        let (frame_buffer_view, depth_texture_view) =
+            create_framebuffer_views(&descriptors, target.format(), extent);
 
         let (quad_vbo, quad_ibo, quad_tex_transforms) = create_quad_buffers(&descriptors.device);
 
@@ -337,11 +315,67 @@ impl<T: RenderTarget> WgpuRenderBackend<T> {
         self.descriptors
     }
 
+    /// Starts a render pass into the given frame, cleared to `clear`, and
+    /// makes it the current frame.
+    fn begin_render_pass(&mut self, frame_output: Box<dyn RenderTargetFrame>, clear: wgpu::Color) {
+        let label = create_debug_label!("Draw encoder");
+        let draw_encoder =
+            self.descriptors
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: label.as_deref(),
+                });
+        let mut frame_data = Box::new((draw_encoder, frame_output));
+
+        self.descriptors
+            .globals
+            .update_uniform(&self.descriptors.device, &mut frame_data.0);
+
+        let (color_attachment, resolve_target) = if self.descriptors.msaa_sample_count >= 2 {
+            (&self.frame_buffer_view, Some(frame_data.1.view()))
+        } else {
+            (frame_data.1.view(), None)
+        };
+
+        let render_pass = frame_data.0.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+                attachment: color_attachment,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(clear),
+                    store: true,
+                },
+                resolve_target,
+            }],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachmentDescriptor {
+                attachment: &self.depth_texture_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(0.0),
+                    store: true,
+                }),
+                stencil_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(0),
+                    store: true,
+                }),
+            }),
+            label: None,
+        });
+
+        // Since RenderPass holds a reference to the CommandEncoder, we cast the lifetime
+        // away to allow for the self-referencing struct. draw_encoder is boxed so its
+        // address should remain stable.
+        self.current_frame = Some(Frame {
+            render_pass: unsafe {
+                std::mem::transmute::<_, wgpu::RenderPass<'static>>(render_pass)
+            },
+            frame_data,
+        });
+    }
+
     #[allow(clippy::cognitive_complexity)]
     fn register_shape_internal(
         &mut self,
         shape: DistilledShape,
-        library: Option<&MovieLibrary<'_>>,
+        bitmap_source: Option<&dyn BitmapSource>,
     ) -> Mesh {
         use lyon::tessellation::{FillOptions, StrokeOptions};
 
@@ -578,9 +612,9 @@ impl<T: RenderTarget> WgpuRenderBackend<T> {
                             continue;
                         }
 
-                        if let Some(texture) = library
-                            .and_then(|lib| lib.get_bitmap(*id))
-                            .and_then(|bitmap| self.textures.get(bitmap.bitmap_handle().0))
+                        if let Some(texture) = bitmap_source
+                            .and_then(|bitmap_source| bitmap_source.bitmap_handle(*id))
+                            .and_then(|handle| self.textures.get(handle.0))
                         {
                             let texture_view = texture.texture.create_view(&Default::default());
 
@@ -798,43 +832,17 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
 
         self.target.resize(&self.descriptors.device, width, height);
 
-        let label = create_debug_label!("Framebuffer texture");
-        let frame_buffer = self
-            .descriptors
-            .device
-            .create_texture(&wgpu::TextureDescriptor {
-                label: label.as_deref(),
-                size: wgpu::Extent3d {
-                    width,
-                    height,
-                    depth: 1,
-                },
-                mip_level_count: 1,
-                sample_count: self.descriptors.msaa_sample_count,
-                dimension: wgpu::TextureDimension::D2,
-                format: self.target.format(),
-                usage: wgpu::TextureUsage::RENDER_ATTACHMENT,
-            });
-        self.frame_buffer_view = frame_buffer.create_view(&Default::default());
-
-        let label = create_debug_label!("Depth texture");
-        let depth_texture = self
-            .descriptors
-            .device
-            .create_texture(&wgpu::TextureDescriptor {
-                label: label.as_deref(),
-                size: wgpu::Extent3d {
-                    width,
-                    height,
-                    depth: 1,
-                },
-                mip_level_count: 1,
-                sample_count: self.descriptors.msaa_sample_count,
-                dimension: wgpu::TextureDimension::D2,
-                format: wgpu::TextureFormat::Depth24PlusStencil8,
-                usage: wgpu::TextureUsage::RENDER_ATTACHMENT,
-            });
-        self.depth_texture_view = depth_texture.create_view(&Default::default());
+        let (frame_buffer_view, depth_texture_view) = create_framebuffer_views(
+            &self.descriptors,
+            self.target.format(),
+            wgpu::Extent3d {
+                width,
+                height,
+                depth: 1,
+            },
+        );
+        self.frame_buffer_view = frame_buffer_view;
+        self.depth_texture_view = depth_texture_view;
 
         self.viewport_width = width as f32;
         self.viewport_height = height as f32;
@@ -844,10 +852,10 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
     fn register_shape(
         &mut self,
         shape: DistilledShape,
-        library: Option<&MovieLibrary<'_>>,
+        bitmap_source: Option<&dyn BitmapSource>,
     ) -> ShapeHandle {
         let handle = ShapeHandle(self.meshes.len());
-        let mesh = self.register_shape_internal(shape, library);
+        let mesh = self.register_shape_internal(shape, bitmap_source);
         self.meshes.push(mesh);
         handle
     }
@@ -855,10 +863,10 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
     fn replace_shape(
         &mut self,
         shape: DistilledShape,
-        library: Option<&MovieLibrary<'_>>,
+        bitmap_source: Option<&dyn BitmapSource>,
         handle: ShapeHandle,
     ) {
-        let mesh = self.register_shape_internal(shape, library);
+        let mesh = self.register_shape_internal(shape, bitmap_source);
         self.meshes[handle.0] = mesh;
     }
 
@@ -917,62 +925,15 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
             }
         };
 
-        let label = create_debug_label!("Draw encoder");
-        let draw_encoder =
-            self.descriptors
-                .device
-                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                    label: label.as_deref(),
-                });
-        let mut frame_data = Box::new((draw_encoder, frame_output));
-
-        self.descriptors
-            .globals
-            .update_uniform(&self.descriptors.device, &mut frame_data.0);
-
-        let (color_attachment, resolve_target) = if self.descriptors.msaa_sample_count >= 2 {
-            (&self.frame_buffer_view, Some(frame_data.1.view()))
-        } else {
-            (frame_data.1.view(), None)
-        };
-
-        let render_pass = frame_data.0.begin_render_pass(&wgpu::RenderPassDescriptor {
-            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
-                attachment: color_attachment,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color {
-                        r: f64::from(clear.r) / 255.0,
-                        g: f64::from(clear.g) / 255.0,
-                        b: f64::from(clear.b) / 255.0,
-                        a: f64::from(clear.a) / 255.0,
-                    }),
-                    store: true,
-                },
-                resolve_target,
-            }],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachmentDescriptor {
-                attachment: &self.depth_texture_view,
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(0.0),
-                    store: true,
-                }),
-                stencil_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(0),
-                    store: true,
-                }),
-            }),
-            label: None,
-        });
-
-        // Since RenderPass holds a reference to the CommandEncoder, we cast the lifetime
-        // away to allow for the self-referencing struct. draw_encoder is boxed so its
-        // address should remain stable.
-        self.current_frame = Some(Frame {
-            render_pass: unsafe {
-                std::mem::transmute::<_, wgpu::RenderPass<'static>>(render_pass)
+        self.begin_render_pass(
+            Box::new(frame_output),
+            wgpu::Color {
+                r: f64::from(clear.r) / 255.0,
+                g: f64::from(clear.g) / 255.0,
+                b: f64::from(clear.b) / 255.0,
+                a: f64::from(clear.a) / 255.0,
             },
-            frame_data,
-        });
+        );
     }
 
     fn render_bitmap(&mut self, bitmap: BitmapHandle, transform: &Transform, smoothing: bool) {
@@ -1248,6 +1209,75 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
         }
     }
 
+    fn render_offscreen(
+        &mut self,
+        width: u32,
+        height: u32,
+        commands: &mut dyn FnMut(&mut dyn RenderBackend),
+    ) -> Result<Bitmap, Error> {
+        if width == 0 || height == 0 {
+            return Ok(Bitmap {
+                width,
+                height,
+                data: BitmapFormat::Rgba(Vec::new()),
+            });
+        }
+
+        let mut target = TextureTarget::new(&self.descriptors.device, (width, height));
+        let frame_output = target
+            .get_next_texture()
+            .map_err(|e| format!("Couldn't create offscreen render target: {}", e))?;
+        let (frame_buffer_view, depth_texture_view) = create_framebuffer_views(
+            &self.descriptors,
+            target.format(),
+            wgpu::Extent3d {
+                width,
+                height,
+                depth: 1,
+            },
+        );
+
+        // Stash away the state of the main render target while drawing offscreen.
+        let frame_buffer_view = std::mem::replace(&mut self.frame_buffer_view, frame_buffer_view);
+        let depth_texture_view =
+            std::mem::replace(&mut self.depth_texture_view, depth_texture_view);
+        let current_frame = self.current_frame.take();
+        let mask_state = std::mem::replace(&mut self.mask_state, MaskState::NoMask);
+        let num_masks = std::mem::replace(&mut self.num_masks, 0);
+        self.descriptors.globals.set_resolution(width, height);
+
+        // The pipelines blend alpha with `One`, so the result is premultiplied.
+        self.begin_render_pass(Box::new(frame_output), wgpu::Color::TRANSPARENT);
+        commands(self);
+
+        if let Some(frame) = self.current_frame.take() {
+            drop(frame.render_pass);
+            let draw_encoder = frame.frame_data.0;
+            target.submit(
+                &self.descriptors.device,
+                &self.descriptors.queue,
+                vec![draw_encoder.finish()],
+            );
+        }
+        let image = target.capture(&self.descriptors.device);
+
+        self.frame_buffer_view = frame_buffer_view;
+        self.depth_texture_view = depth_texture_view;
+        self.current_frame = current_frame;
+        self.mask_state = mask_state;
+        self.num_masks = num_masks;
+        self.descriptors
+            .globals
+            .set_resolution(self.target.width(), self.target.height());
+
+        let image = image.ok_or("Unable to read back offscreen render target")?;
+        Ok(Bitmap {
+            width,
+            height,
+            data: BitmapFormat::Rgba(image.into_raw()),
+        })
+    }
+
     fn push_mask(&mut self) {
         debug_assert!(
             self.mask_state == MaskState::NoMask || self.mask_state == MaskState::DrawMaskedContent
@@ -1336,6 +1366,41 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
     }
 }
 
+/// Creates the multisampled framebuffer and the depth/stencil buffer used
+/// when rendering into a target of the given size.
+fn create_framebuffer_views(
+    descriptors: &Descriptors,
+    format: wgpu::TextureFormat,
+    extent: wgpu::Extent3d,
+) -> (wgpu::TextureView, wgpu::TextureView) {
+    let frame_buffer_label = create_debug_label!("Framebuffer texture");
+    let frame_buffer = descriptors.device.create_texture(&wgpu::TextureDescriptor {
+        label: frame_buffer_label.as_deref(),
+        size: extent,
+        mip_level_count: 1,
+        sample_count: descriptors.msaa_sample_count,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsage::RENDER_ATTACHMENT,
+    });
+
+    let depth_label = create_debug_label!("Depth texture");
+    let depth_texture = descriptors.device.create_texture(&wgpu::TextureDescriptor {
+        label: depth_label.as_deref(),
+        size: extent,
+        mip_level_count: 1,
+        sample_count: descriptors.msaa_sample_count,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Depth24PlusStencil8,
+        usage: wgpu::TextureUsage::RENDER_ATTACHMENT,
+    });
+
+    (
+        frame_buffer.create_view(&Default::default()),
+        depth_texture.create_view(&Default::default()),
+    )
+}
+
 fn create_quad_buffers(device: &wgpu::Device) -> (wgpu::Buffer, wgpu::Buffer, wgpu::Buffer) {
     let vertices = [
         GPUVertex {
@@ -101,8 +101,8 @@ impl<R: Read> Reader<R> {
     }
 
     fn read_i24(&mut self) -> Result<i32> {
-        Ok(i32::from(self.read_u8()? as i8)
-            | (i32::from(self.read_u8()? as i8) << 8)
+        Ok(i32::from(self.read_u8()?)
+            | (i32::from(self.read_u8()?) << 8)
             | (i32::from(self.read_u8()? as i8) << 16))
     }
     fn read_i32(&mut self) -> Result<i32> {
@@ -199,6 +199,18 @@ impl<R: Read> Reader<R> {
             0x1c => Multiname::MultinameLA {
                 namespace_set: self.read_index()?,
             },
+            0x1d => {
+                let base_type = self.read_index()?;
+                let count = self.read_u30()?;
+                let mut parameters = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    parameters.push(self.read_index()?);
+                }
+                Multiname::TypeName {
+                    base_type,
+                    parameters,
+                }
+            }
             _ => return Err(Error::invalid_data("Invalid multiname kind")),
         })
     }
@@ -548,6 +560,9 @@ impl<R: Read> Reader<R> {
         let op = match opcode {
             OpCode::Add => Op::Add,
             OpCode::AddI => Op::AddI,
+            OpCode::ApplyType => Op::ApplyType {
+                num_types: self.read_u30()?,
+            },
             OpCode::AsType => Op::AsType {
                 type_name: self.read_index()?,
             },
@@ -901,4 +916,34 @@ pub mod tests {
             }
         }
     }
+
+    #[test]
+    fn read_jump_offsets() {
+        let mut reader = Reader::new(&[0x10, 0xc8, 0x00, 0x00][..]);
+        assert_eq!(reader.read_op().unwrap(), Some(Op::Jump { offset: 200 }));
+
+        let mut reader = Reader::new(&[0x10, 0xf6, 0xff, 0xff][..]);
+        assert_eq!(reader.read_op().unwrap(), Some(Op::Jump { offset: -10 }));
+    }
+
+    #[test]
+    fn read_type_name() {
+        let mut reader = Reader::new(&[0x1d, 0x01, 0x01, 0x02][..]);
+        assert_eq!(
+            reader.read_multiname().unwrap(),
+            Multiname::TypeName {
+                base_type: Index::new(1),
+                parameters: vec![Index::new(2)],
+            }
+        );
+    }
+
+    #[test]
+    fn read_apply_type() {
+        let mut reader = Reader::new(&[0x53, 0x01][..]);
+        assert_eq!(
+            reader.read_op().unwrap(),
+            Some(Op::ApplyType { num_types: 1 })
+        );
+    }
 }
@@ -78,9 +78,9 @@ impl<W: Write> Writer<W> {
     #[allow(dead_code)]
     fn write_i24(&mut self, n: i32) -> Result<()> {
         // TODO: Verify n fits in 24-bits.
-        self.write_u8(((n >> 16) & 0xff) as u8)?;
-        self.write_u8(((n >> 8) & 0xff) as u8)?;
         self.write_u8((n & 0xff) as u8)?;
+        self.write_u8(((n >> 8) & 0xff) as u8)?;
+        self.write_u8(((n >> 16) & 0xff) as u8)?;
         Ok(())
     }
 
@@ -274,6 +274,17 @@ impl<W: Write> Writer<W> {
                 self.write_u8(0x1c)?;
                 self.write_index(namespace_set)?;
             }
+            Multiname::TypeName {
+                ref base_type,
+                ref parameters,
+            } => {
+                self.write_u8(0x1d)?;
+                self.write_index(base_type)?;
+                self.write_u30(parameters.len() as u32)?;
+                for param in parameters {
+                    self.write_index(param)?;
+                }
+            }
         }
         Ok(())
     }
@@ -554,6 +565,10 @@ impl<W: Write> Writer<W> {
         match *op {
             Op::Add => self.write_opcode(OpCode::Add)?,
             Op::AddI => self.write_opcode(OpCode::AddI)?,
+            Op::ApplyType { num_types } => {
+                self.write_opcode(OpCode::ApplyType)?;
+                self.write_u30(num_types)?;
+            }
             Op::AsType { ref type_name } => {
                 self.write_opcode(OpCode::AsType)?;
                 self.write_index(type_name)?;
@@ -988,4 +1003,40 @@ pub mod tests {
             }
         }
     }
+
+    #[test]
+    fn write_jump_offsets() {
+        let mut out = vec![];
+        Writer::new(&mut out)
+            .write_op(&Op::Jump { offset: 200 })
+            .unwrap();
+        assert_eq!(out, [0x10, 0xc8, 0x00, 0x00]);
+
+        let mut out = vec![];
+        Writer::new(&mut out)
+            .write_op(&Op::Jump { offset: -10 })
+            .unwrap();
+        assert_eq!(out, [0x10, 0xf6, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn write_type_name() {
+        let mut out = vec![];
+        Writer::new(&mut out)
+            .write_multiname(&Multiname::TypeName {
+                base_type: Index::new(1),
+                parameters: vec![Index::new(2)],
+            })
+            .unwrap();
+        assert_eq!(out, [0x1d, 0x01, 0x01, 0x02]);
+    }
+
+    #[test]
+    fn write_apply_type() {
+        let mut out = vec![];
+        Writer::new(&mut out)
+            .write_op(&Op::ApplyType { num_types: 1 })
+            .unwrap();
+        assert_eq!(out, [0x53, 0x01]);
+    }
 }
@@ -113,6 +113,17 @@ export class RufflePlayer extends HTMLElement {
      */
     config: Config = {};
 
+    /**
+     * A handler for FSCommands sent by the movie, such as
+     * `fscommand("save", data)`.
+     *
+     * As in the Flash Player plugin, this includes commands like `quit` and
+     * `fullscreen`, which only the standalone player handles itself.
+     *
+     * Only called if `allowScriptAccess` is enabled.
+     */
+    fsCommandHandler: ((command: string, args: string) => void) | null = null;
+
     /**
      * Constructs a new Ruffle flash player for insertion onto the page.
      */
@@ -703,6 +714,23 @@ export class RufflePlayer extends HTMLElement {
         };
     }
 
+    /**
+     * Called when the movie sends an FSCommand.
+     *
+     * @param command The name of the command.
+     * @param args The arguments of the command.
+     * @returns True if the command was handled.
+     * @internal
+     * @ignore
+     */
+    onFSCommand(command: string, args: string): boolean {
+        if (this.fsCommandHandler) {
+            this.fsCommandHandler(command, args);
+            return true;
+        }
+        return false;
+    }
+
     /**
      * Sets a trace observer on this flash player.
      *
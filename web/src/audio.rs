@@ -1,9 +1,11 @@
 use fnv::FnvHashMap;
 use generational_arena::Arena;
-use ruffle_core::backend::audio::decoders::{AdpcmDecoder, Mp3Decoder};
+use ruffle_core::backend::audio::decoders::{
+    resample_to_script_rate, AdpcmDecoder, Mp3Decoder, SCRIPT_SAMPLE_RATE,
+};
 use ruffle_core::backend::audio::swf::{self, AudioCompression};
 use ruffle_core::backend::audio::{
-    AudioBackend, AudioStreamHandle, SoundHandle, SoundInstanceHandle,
+    AudioBackend, AudioStreamHandle, SoundHandle, SoundInstanceHandle, SoundTransform,
 };
 use ruffle_web_common::JsResult;
 use std::cell::{Cell, RefCell};
@@ -86,6 +88,33 @@ struct SoundInstance {
     /// either decoded on the fly with Decoder, or pre-decoded
     /// and played with and AudioBufferSourceNode.
     instance_type: SoundInstanceType,
+
+    /// The volume and panning applied to this sound, if any.
+    transform: Option<SoundTransform>,
+
+    /// The output node of the gain nodes applying `transform`, for sounds
+    /// played with an AudioBufferSourceNode.
+    transform_node: Option<web_sys::AudioNode>,
+
+    /// The time of the audio context when this sound started playing.
+    start_time: f64,
+
+    /// The position in the sound that playback started from, in seconds.
+    start_offset: f64,
+
+    /// The samples queued by script for a script-generated sound.
+    dynamic_buffer: Option<Rc<RefCell<DynamicSoundBuffer>>>,
+}
+
+/// The samples queued by script for a script-generated sound, as with AS3's
+/// `SampleDataEvent`.
+#[derive(Default)]
+struct DynamicSoundBuffer {
+    /// The 44.1kHz sample frames that have yet to be played.
+    samples: std::collections::VecDeque<[i16; 2]>,
+
+    /// Whether the script has finished supplying samples.
+    is_final: bool,
 }
 
 /// The Drop impl ensures that the sound is stopped and remove from the audio context,
@@ -100,6 +129,9 @@ impl Drop for SoundInstance {
             let _ = buffer_source_node.set_onended(None);
             let _ = node.disconnect();
         }
+        if let Some(transform_node) = &self.transform_node {
+            let _ = transform_node.disconnect();
+        }
     }
 }
 
@@ -235,6 +267,12 @@ impl WebAudioBackend {
                         node,
                         buffer_source_node: buffer_source_node.clone(),
                     },
+                    transform: None,
+                    transform_node: None,
+                    start_time: self.context.current_time(),
+                    start_offset: f64::from(settings.and_then(|s| s.in_sample).unwrap_or(0))
+                        / 44100.0,
+                    dynamic_buffer: None,
                 };
                 let instance_handle = SOUND_INSTANCES.with(|instances| {
                     let mut instances = instances.borrow_mut();
@@ -287,34 +325,45 @@ impl WebAudioBackend {
                     handle: Some(handle),
                     format: sound.format.clone(),
                     instance_type: SoundInstanceType::Decoder(decoder),
+                    transform: None,
+                    transform_node: None,
+                    start_time: self.context.current_time(),
+                    start_offset: 0.0,
+                    dynamic_buffer: None,
                 };
-                SOUND_INSTANCES.with(|instances| {
-                    let mut instances = instances.borrow_mut();
-                    let instance_handle = instances.insert(instance);
-                    let script_processor_node = self.context.create_script_processor_with_buffer_size_and_number_of_input_channels_and_number_of_output_channels(4096, 0, if sound.format.is_stereo { 2 } else { 1 }).unwrap();
-                    let script_node = script_processor_node.clone();
-                    let closure = Closure::wrap(Box::new(move |event| {
-                            SOUND_INSTANCES.with(|instances| {
-                                let mut instances = instances.borrow_mut();
-                                let instance = instances.get_mut(instance_handle).unwrap();
-                                let complete = WebAudioBackend::update_script_processor(instance, event);
-                                if complete {
-                                    instances.remove(instance_handle);
-                                    script_node.disconnect().unwrap();
-                                }
-                            })
-                        }) as Box<dyn FnMut(web_sys::AudioProcessingEvent)>);
-                        script_processor_node.set_onaudioprocess(Some(closure.as_ref().unchecked_ref()));
-                        // TODO: This will leak memory per playing sound. Remember and properly drop the closure.
-                        closure.forget();
-
-                    instance_handle
-                })
+                self.start_script_processor(instance)
             }
         };
         Ok(handle)
     }
 
+    /// Plays a sound instance decoded on the fly using a `ScriptProcessorNode`.
+    fn start_script_processor(&self, instance: SoundInstance) -> SoundInstanceHandle {
+        let num_channels = if instance.format.is_stereo { 2 } else { 1 };
+        SOUND_INSTANCES.with(|instances| {
+            let mut instances = instances.borrow_mut();
+            let instance_handle = instances.insert(instance);
+            let script_processor_node = self.context.create_script_processor_with_buffer_size_and_number_of_input_channels_and_number_of_output_channels(4096, 0, num_channels).unwrap();
+            let script_node = script_processor_node.clone();
+            let closure = Closure::wrap(Box::new(move |event| {
+                    SOUND_INSTANCES.with(|instances| {
+                        let mut instances = instances.borrow_mut();
+                        let instance = instances.get_mut(instance_handle).unwrap();
+                        let complete = WebAudioBackend::update_script_processor(instance, event);
+                        if complete {
+                            instances.remove(instance_handle);
+                            script_node.disconnect().unwrap();
+                        }
+                    })
+                }) as Box<dyn FnMut(web_sys::AudioProcessingEvent)>);
+                script_processor_node.set_onaudioprocess(Some(closure.as_ref().unchecked_ref()));
+                // TODO: This will leak memory per playing sound. Remember and properly drop the closure.
+                closure.forget();
+
+            instance_handle
+        })
+    }
+
     /// Wires up the envelope for Flash event sounds using `ChannelSplitter`, `Gain`, and `ChannelMerger` nodes.
     fn create_sound_envelope(
         &self,
@@ -389,6 +438,47 @@ impl WebAudioBackend {
         Ok(merger)
     }
 
+    /// Wires up the volume and panning of a sound using `ChannelSplitter`,
+    /// `Gain`, and `ChannelMerger` nodes.
+    fn create_sound_transform(
+        &self,
+        node: &web_sys::AudioNode,
+        transform: &SoundTransform,
+        is_stereo: bool,
+    ) -> Result<web_sys::AudioNode, Box<dyn std::error::Error>> {
+        let splitter = self
+            .context
+            .create_channel_splitter_with_number_of_outputs(2)
+            .into_js_result()?;
+        let merger: web_sys::AudioNode = self
+            .context
+            .create_channel_merger_with_number_of_inputs(2)
+            .into_js_result()?
+            .into();
+
+        // Each input channel feeds both output channels through its own gain.
+        // Note that for mono tracks, we want to use channel 0 (left) for both the left and right.
+        let mixes = [
+            (0, 0, transform.left_to_left),
+            (0, 1, transform.left_to_right),
+            (if is_stereo { 1 } else { 0 }, 0, transform.right_to_left),
+            (if is_stereo { 1 } else { 0 }, 1, transform.right_to_right),
+        ];
+        for &(input, output, volume) in &mixes {
+            let gain = self.context.create_gain().into_js_result()?;
+            gain.gain().set_value(volume * transform.volume);
+            splitter
+                .connect_with_audio_node_and_output(&gain, input)
+                .into_js_result()?;
+            gain.connect_with_audio_node_and_output_and_input(&merger, 0, output)
+                .into_js_result()?;
+        }
+
+        node.connect_with_audio_node(&splitter).into_js_result()?;
+
+        Ok(merger)
+    }
+
     fn decompress_to_audio_buffer(
         &mut self,
         format: &swf::SoundFormat,
@@ -563,10 +653,20 @@ impl WebAudioBackend {
 
             for _ in 0..num_frames {
                 if let Some(frame) = decoder.next() {
-                    let (l, r) = (frame[0], frame[1]);
-                    left_samples.push(f32::from(l) / 32767.0);
+                    let (mut l, mut r) = (f32::from(frame[0]), f32::from(frame[1]));
+                    if let Some(transform) = &instance.transform {
+                        if instance.format.is_stereo {
+                            let [left, right] = transform.apply([l, r]);
+                            l = left;
+                            r = right;
+                        } else {
+                            // Mono sounds only have one output channel to pan.
+                            l *= transform.volume;
+                        }
+                    }
+                    left_samples.push(l / 32767.0);
                     if instance.format.is_stereo {
-                        right_samples.push(f32::from(r) / 32767.0);
+                        right_samples.push(r / 32767.0);
                     }
                 } else {
                     complete = true;
@@ -844,6 +944,150 @@ impl AudioBackend for WebAudioBackend {
             None
         }
     }
+
+    fn start_dynamic_sound(&mut self) -> Result<SoundInstanceHandle, Error> {
+        let buffer = Rc::new(RefCell::new(DynamicSoundBuffer::default()));
+        let samples = Rc::clone(&buffer);
+        let decoder: Decoder = Box::new(std::iter::from_fn(move || {
+            let mut buffer = samples.borrow_mut();
+            match buffer.samples.pop_front() {
+                Some(frame) => Some(frame),
+                // Play silence if the script falls behind.
+                None if !buffer.is_final => Some([0, 0]),
+                None => None,
+            }
+        }));
+
+        let output_sample_rate = self.context.sample_rate() as u16;
+        let decoder: Decoder = if output_sample_rate != SCRIPT_SAMPLE_RATE as u16 {
+            Box::new(resample(
+                decoder,
+                SCRIPT_SAMPLE_RATE as u16,
+                output_sample_rate,
+            ))
+        } else {
+            decoder
+        };
+
+        let instance = SoundInstance {
+            handle: None,
+            format: swf::SoundFormat {
+                compression: AudioCompression::Uncompressed,
+                sample_rate: SCRIPT_SAMPLE_RATE as u16,
+                is_stereo: true,
+                is_16_bit: true,
+            },
+            instance_type: SoundInstanceType::Decoder(decoder),
+            transform: None,
+            transform_node: None,
+            start_time: self.context.current_time(),
+            start_offset: 0.0,
+            dynamic_buffer: Some(buffer),
+        };
+        Ok(self.start_script_processor(instance))
+    }
+
+    fn queue_dynamic_sound_samples(
+        &mut self,
+        instance: SoundInstanceHandle,
+        samples: &[[f32; 2]],
+        is_final: bool,
+    ) {
+        SOUND_INSTANCES.with(|instances| {
+            let instances = instances.borrow();
+            if let Some(buffer) = instances
+                .get(instance)
+                .and_then(|instance| instance.dynamic_buffer.as_ref())
+            {
+                let mut buffer = buffer.borrow_mut();
+                buffer.samples.extend(samples.iter().map(|[left, right]| {
+                    [
+                        (left.max(-1.0).min(1.0) * 32767.0) as i16,
+                        (right.max(-1.0).min(1.0) * 32767.0) as i16,
+                    ]
+                }));
+                buffer.is_final |= is_final;
+            }
+        })
+    }
+
+    fn dynamic_sound_buffered(&self, instance: SoundInstanceHandle) -> Option<usize> {
+        SOUND_INSTANCES.with(|instances| {
+            let instances = instances.borrow();
+            let buffer = instances.get(instance)?.dynamic_buffer.as_ref()?;
+            let buffered = buffer.borrow().samples.len();
+            Some(buffered)
+        })
+    }
+
+    fn get_sound_samples(&mut self, sound: SoundHandle) -> Option<Vec<[f32; 2]>> {
+        let sound = self.sounds.get(sound)?;
+        let audio_buffer = if let SoundSource::AudioBuffer(audio_buffer) = &sound.source {
+            audio_buffer.borrow()
+        } else {
+            return None;
+        };
+        let left = audio_buffer.get_channel_data(0).ok()?;
+        let right = if audio_buffer.number_of_channels() > 1 {
+            audio_buffer.get_channel_data(1).ok()?
+        } else {
+            left.clone()
+        };
+        let frames: Vec<[f32; 2]> = left
+            .into_iter()
+            .zip(right.into_iter())
+            .map(|(left, right)| [left, right])
+            .collect();
+        Some(resample_to_script_rate(
+            &frames,
+            audio_buffer.sample_rate() as u32,
+        ))
+    }
+
+    fn get_sound_position(&self, instance: SoundInstanceHandle) -> Option<u32> {
+        let current_time = self.context.current_time();
+        SOUND_INSTANCES.with(|instances| {
+            let instances = instances.borrow();
+            let instance = instances.get(instance)?;
+            let position = current_time - instance.start_time + instance.start_offset;
+            Some((position * 1000.0) as u32)
+        })
+    }
+
+    fn set_sound_transform(&mut self, instance: SoundInstanceHandle, transform: SoundTransform) {
+        SOUND_INSTANCES.with(|instances| {
+            let mut instances = instances.borrow_mut();
+            let instance = if let Some(instance) = instances.get_mut(instance) {
+                instance
+            } else {
+                return;
+            };
+
+            instance.transform = Some(transform);
+
+            if let SoundInstanceType::AudioBuffer { node, .. } = &instance.instance_type {
+                // Rewire the sound through new gain nodes for the transform.
+                let _ = node.disconnect();
+                if let Some(transform_node) = instance.transform_node.take() {
+                    let _ = transform_node.disconnect();
+                }
+
+                match self.create_sound_transform(node, &transform, instance.format.is_stereo) {
+                    Ok(transform_node) => {
+                        transform_node
+                            .connect_with_audio_node(&self.context.destination())
+                            .warn_on_error();
+                        instance.transform_node = Some(transform_node);
+                    }
+                    Err(e) => {
+                        log::error!("Unable to apply sound transform: {}", e);
+                        node.connect_with_audio_node(&self.context.destination())
+                            .warn_on_error();
+                    }
+                }
+            }
+        })
+    }
 }
 
 #[wasm_bindgen(raw_module = "./ruffle-imports.js")]
@@ -28,6 +28,7 @@ use ruffle_core::external::{
     ExternalInterfaceMethod, ExternalInterfaceProvider, Value as ExternalValue, Value,
 };
 use ruffle_core::property_map::PropertyMap;
+use ruffle_core::system_properties::PlayerType;
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::{Color, PlayerEvent};
 use ruffle_web_common::JsResult;
@@ -97,6 +98,9 @@ extern "C" {
     #[wasm_bindgen(method, js_name = "onCallbackAvailable")]
     fn on_callback_available(this: &JavascriptPlayer, name: &str);
 
+    #[wasm_bindgen(method, js_name = "onFSCommand")]
+    fn on_fs_command(this: &JavascriptPlayer, command: &str, args: &str) -> bool;
+
     #[wasm_bindgen(method)]
     fn panic(this: &JavascriptPlayer, error: &JsError);
 
@@ -105,6 +109,15 @@ extern "C" {
 
     #[wasm_bindgen(method, getter, js_name = "isFullscreen")]
     fn is_fullscreen(this: &JavascriptPlayer) -> bool;
+
+    #[wasm_bindgen(method, getter, js_name = "fullscreenEnabled")]
+    fn fullscreen_enabled(this: &JavascriptPlayer) -> bool;
+
+    #[wasm_bindgen(method, js_name = "enterFullscreen")]
+    fn enter_fullscreen(this: &JavascriptPlayer);
+
+    #[wasm_bindgen(method, js_name = "exitFullscreen")]
+    fn exit_fullscreen(this: &JavascriptPlayer);
 }
 
 struct JavascriptInterface {
@@ -457,6 +470,7 @@ impl Ruffle {
             }
             core.set_letterbox(config.letterbox);
             core.set_warn_on_unsupported_content(config.warn_on_unsupported_content);
+            core.system_properties_mut().player_type = PlayerType::PlugIn;
         }
 
         // Create instance.
@@ -872,7 +886,11 @@ impl Ruffle {
                     canvas.set_width(viewport_width);
                     canvas.set_height(viewport_height);
 
-                    core_lock.set_viewport_dimensions(viewport_width, viewport_height);
+                    core_lock.set_viewport_dimensions(
+                        viewport_width,
+                        viewport_height,
+                        device_pixel_ratio,
+                    );
                     core_lock
                         .renderer_mut()
                         .set_viewport_dimensions(viewport_width, viewport_height);
@@ -977,6 +995,10 @@ impl ExternalInterfaceProvider for JavascriptInterface {
     fn on_callback_available(&self, name: &str) {
         self.js_player.on_callback_available(name);
     }
+
+    fn on_fs_command(&self, command: &str, args: &str) -> bool {
+        self.js_player.on_fs_command(command, args)
+    }
 }
 
 fn js_to_external_value(js: &JsValue) -> ExternalValue {
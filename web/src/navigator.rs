@@ -1,14 +1,15 @@
 //! Navigator backend for web
-use js_sys::{Array, ArrayBuffer, Uint8Array};
+use js_sys::{Array, ArrayBuffer, Function, Promise, Reflect, Uint8Array};
 use ruffle_core::backend::navigator::{
-    url_from_relative_url, NavigationMethod, NavigatorBackend, OwnedFuture, RequestOptions,
+    url_from_relative_url, BufferedResponseStream, ChunkFuture, NavigationMethod, NavigatorBackend,
+    OwnedFuture, RequestOptions, ResponseStream,
 };
 use ruffle_core::indexmap::IndexMap;
 use ruffle_core::loader::Error;
 use std::borrow::Cow;
 use std::time::Duration;
 use url::Url;
-use wasm_bindgen::JsCast;
+use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::{spawn_local, JsFuture};
 use web_sys::{window, Blob, BlobPropertyBag, Performance, Request, RequestInit, Response};
 
@@ -35,6 +36,15 @@ impl WebNavigatorBackend {
             upgrade_to_https,
         }
     }
+
+    /// Apply any URL pre-processing to a fetched URL.
+    fn fetch_url(&self, url: &str) -> String {
+        if let Ok(parsed_url) = Url::parse(url) {
+            self.pre_process_url(parsed_url).to_string()
+        } else {
+            url.to_string()
+        }
+    }
 }
 
 impl NavigatorBackend for WebNavigatorBackend {
@@ -146,66 +156,36 @@ impl NavigatorBackend for WebNavigatorBackend {
     }
 
     fn fetch(&self, url: &str, options: RequestOptions) -> OwnedFuture<Vec<u8>, Error> {
-        let url = if let Ok(parsed_url) = Url::parse(url) {
-            self.pre_process_url(parsed_url).to_string()
-        } else {
-            url.to_string()
-        };
+        let url = self.fetch_url(url);
 
         Box::pin(async move {
-            let mut init = RequestInit::new();
-
-            init.method(match options.method() {
-                NavigationMethod::GET => "GET",
-                NavigationMethod::POST => "POST",
-            });
-
-            if let Some((data, mime)) = options.body() {
-                let arraydata = ArrayBuffer::new(data.len() as u32);
-                let u8data = Uint8Array::new(&arraydata);
-
-                for (i, byte) in data.iter().enumerate() {
-                    u8data.fill(*byte, i as u32, i as u32 + 1);
-                }
-
-                let blobparts = Array::new();
-                blobparts.push(&arraydata);
-
-                let mut blobprops = BlobPropertyBag::new();
-                blobprops.type_(mime);
-
-                let datablob =
-                    Blob::new_with_buffer_source_sequence_and_options(&blobparts, &blobprops)
-                        .unwrap()
-                        .dyn_into()
-                        .unwrap();
-
-                init.body(Some(&datablob));
-            }
-
-            let request = Request::new_with_str_and_init(&url, &init)
-                .map_err(|_| Error::FetchError(format!("Unable to create request for {}", url)))?;
+            let resp = fetch_response(url, options).await?;
+            Ok(read_array_buffer(&resp).await)
+        })
+    }
 
-            let window = web_sys::window().unwrap();
-            let fetchval = JsFuture::from(window.fetch_with_request(&request)).await;
-            if fetchval.is_err() {
-                return Err(Error::NetworkError(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Could not fetch, got JS Error",
-                )));
-            }
+    fn fetch_stream(
+        &self,
+        url: &str,
+        options: RequestOptions,
+    ) -> OwnedFuture<Box<dyn ResponseStream>, Error> {
+        let url = self.fetch_url(url);
 
-            let resp: Response = fetchval.unwrap().dyn_into().unwrap();
-            let data: ArrayBuffer = JsFuture::from(resp.array_buffer().unwrap())
-                .await
-                .unwrap()
-                .dyn_into()
-                .unwrap();
-            let jsarray = Uint8Array::new(&data);
-            let mut rust_array = vec![0; jsarray.length() as usize];
-            jsarray.copy_to(&mut rust_array);
-
-            Ok(rust_array)
+        Box::pin(async move {
+            let resp = fetch_response(url, options).await?;
+            let length = resp
+                .headers()
+                .get("Content-Length")
+                .ok()
+                .flatten()
+                .and_then(|length| length.parse().ok());
+
+            // Fall back to reading the whole body if the browser can't stream it.
+            let stream: Box<dyn ResponseStream> = match body_reader(&resp) {
+                Some(reader) => Box::new(WebResponseStream { reader, length }),
+                None => Box::new(BufferedResponseStream::new(read_array_buffer(&resp).await)),
+            };
+            Ok(stream)
         })
     }
 
@@ -237,3 +217,122 @@ impl NavigatorBackend for WebNavigatorBackend {
         url
     }
 }
+
+/// Send a request with the browser's `fetch`, yielding the response once its
+/// headers arrive.
+async fn fetch_response(url: String, options: RequestOptions) -> Result<Response, Error> {
+    let mut init = RequestInit::new();
+
+    init.method(match options.method() {
+        NavigationMethod::GET => "GET",
+        NavigationMethod::POST => "POST",
+    });
+
+    if let Some((data, mime)) = options.body() {
+        let arraydata = ArrayBuffer::new(data.len() as u32);
+        let u8data = Uint8Array::new(&arraydata);
+
+        for (i, byte) in data.iter().enumerate() {
+            u8data.fill(*byte, i as u32, i as u32 + 1);
+        }
+
+        let blobparts = Array::new();
+        blobparts.push(&arraydata);
+
+        let mut blobprops = BlobPropertyBag::new();
+        blobprops.type_(mime);
+
+        let datablob = Blob::new_with_buffer_source_sequence_and_options(&blobparts, &blobprops)
+            .unwrap()
+            .dyn_into()
+            .unwrap();
+
+        init.body(Some(&datablob));
+    }
+
+    let request = Request::new_with_str_and_init(&url, &init)
+        .map_err(|_| Error::FetchError(format!("Unable to create request for {}", url)))?;
+
+    for (name, value) in options.headers() {
+        request.headers().set(name, value).map_err(|_| {
+            Error::FetchError(format!("Unable to set request header {} for {}", name, url))
+        })?;
+    }
+
+    let window = web_sys::window().unwrap();
+    let fetchval = JsFuture::from(window.fetch_with_request(&request)).await;
+    if fetchval.is_err() {
+        return Err(Error::NetworkError(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Could not fetch, got JS Error",
+        )));
+    }
+
+    Ok(fetchval.unwrap().dyn_into().unwrap())
+}
+
+/// Read the entire body of a response.
+async fn read_array_buffer(resp: &Response) -> Vec<u8> {
+    let data: ArrayBuffer = JsFuture::from(resp.array_buffer().unwrap())
+        .await
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+    let jsarray = Uint8Array::new(&data);
+    let mut rust_array = vec![0; jsarray.length() as usize];
+    jsarray.copy_to(&mut rust_array);
+    rust_array
+}
+
+/// Get a reader for the body stream of a response, if the browser supports
+/// streaming responses.
+fn body_reader(resp: &Response) -> Option<JsValue> {
+    let body = Reflect::get(resp, &"body".into()).ok()?;
+    let get_reader: Function = Reflect::get(&body, &"getReader".into())
+        .ok()?
+        .dyn_into()
+        .ok()?;
+    get_reader.call0(&body).ok()
+}
+
+/// A `ResponseStream` that reads a fetch response body as it arrives.
+struct WebResponseStream {
+    reader: JsValue,
+    length: Option<usize>,
+}
+
+impl ResponseStream for WebResponseStream {
+    fn total_length(&self) -> Option<usize> {
+        self.length
+    }
+
+    fn next_chunk(&mut self) -> ChunkFuture<'_> {
+        Box::pin(async move {
+            let read_error = || Error::FetchError("Could not read response body".to_string());
+
+            let read: Function = Reflect::get(&self.reader, &"read".into())
+                .ok()
+                .and_then(|read| read.dyn_into().ok())
+                .ok_or_else(read_error)?;
+            let promise: Promise = read
+                .call0(&self.reader)
+                .ok()
+                .and_then(|promise| promise.dyn_into().ok())
+                .ok_or_else(read_error)?;
+            let result = JsFuture::from(promise).await.map_err(|_| read_error())?;
+
+            let done = Reflect::get(&result, &"done".into()).map_err(|_| read_error())?;
+            if done.is_truthy() {
+                return Ok(None);
+            }
+
+            let chunk: Uint8Array = Reflect::get(&result, &"value".into())
+                .ok()
+                .and_then(|chunk| chunk.dyn_into().ok())
+                .ok_or_else(read_error)?;
+            let mut rust_array = vec![0; chunk.length() as usize];
+            chunk.copy_to(&mut rust_array);
+            Ok(Some(rust_array))
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use super::JavascriptPlayer;
-use ruffle_core::backend::ui::UiBackend;
+use ruffle_core::backend::ui::{Error, UiBackend};
 
 pub struct WebUiBackend {
     js_player: JavascriptPlayer,
@@ -16,7 +16,23 @@ impl UiBackend for WebUiBackend {
         self.js_player.is_fullscreen()
     }
 
+    fn set_fullscreen(&mut self, is_full: bool) -> Result<(), Error> {
+        if is_full {
+            if !self.js_player.fullscreen_enabled() {
+                return Err("Fullscreen is not enabled".into());
+            }
+            self.js_player.enter_fullscreen();
+        } else {
+            self.js_player.exit_fullscreen();
+        }
+        Ok(())
+    }
+
     fn message(&self, message: &str) {
         self.js_player.display_message(message);
     }
+
+    fn exec(&mut self, _command: &str) -> Result<(), Error> {
+        Err("Exec is not supported in the browser".into())
+    }
 }